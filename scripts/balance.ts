@@ -0,0 +1,118 @@
+/**
+ * Balance Script - What the exchange will let the wallet trade
+ *
+ * Usage:
+ *   npm run balance                          # USDC.e (collateral)
+ *   npm run balance -- --token <id>          # Shares of one token too
+ *   npm run balance -- --token <id> --asset conditional   # Token only
+ *
+ * Reports the CLOB's balance and allowance next to the on-chain balance,
+ * so a SELL rejected for "not enough balance / allowance" can be checked
+ * against the exact token_id being sold. Exits 0 unless a lookup failed.
+ */
+
+import "../src/config/load-env";
+import {
+  createClobClient,
+  getClobBalanceAllowance,
+  getTokenBalanceWithStatus,
+  getUsdcBalanceWithStatus,
+  type BalanceAssetType,
+  type ClobBalanceAllowance,
+} from "../src/lib";
+
+const logger = {
+  info: (message: string) => console.log(message),
+  warn: (message: string) => console.warn("⚠️", message),
+  error: (message: string) => console.error("❌", message),
+};
+
+function argValue(args: string[], flag: string): string | undefined {
+  const idx = args.indexOf(flag);
+  return idx >= 0 ? args[idx + 1] : undefined;
+}
+
+function formatAllowance(allowance: number): string {
+  // Anything above $1B is an unlimited (MaxUint256) approval
+  return allowance > 1e9 ? "unlimited" : allowance.toFixed(2);
+}
+
+function logClobBalance(entry: ClobBalanceAllowance, onChain?: number): void {
+  const unit = entry.assetType === "COLLATERAL" ? "USDC.e" : "shares";
+  logger.info(
+    `   CLOB:     ${entry.balance.toFixed(2)} ${unit} (allowance: ${formatAllowance(entry.allowance)})`,
+  );
+  if (onChain !== undefined) {
+    logger.info(`   On-chain: ${onChain.toFixed(2)} ${unit}`);
+  }
+}
+
+async function main() {
+  const args = process.argv.slice(2);
+  const tokenId = argValue(args, "--token");
+  const asset = argValue(args, "--asset")?.toUpperCase();
+  if (
+    asset !== undefined &&
+    asset !== "COLLATERAL" &&
+    asset !== "CONDITIONAL"
+  ) {
+    logger.error(`--asset must be collateral or conditional`);
+    process.exit(1);
+  }
+  if (asset === "CONDITIONAL" && !tokenId) {
+    logger.error(`--asset conditional needs --token <id>`);
+    process.exit(1);
+  }
+
+  // Collateral unless only the token was asked for
+  const assetTypes: BalanceAssetType[] = [];
+  if (asset !== "CONDITIONAL") assetTypes.push("COLLATERAL");
+  if (tokenId && asset !== "COLLATERAL") assetTypes.push("CONDITIONAL");
+
+  const privateKey = process.env.PRIVATE_KEY;
+  const rpcUrl = process.env.RPC_URL;
+  if (!privateKey || !rpcUrl) {
+    logger.error(`Missing PRIVATE_KEY or RPC_URL in .env`);
+    process.exit(1);
+  }
+
+  logger.info(`🔐 Authenticating...`);
+  const auth = await createClobClient(privateKey, rpcUrl);
+  if (!auth.success || !auth.client || !auth.wallet || !auth.address) {
+    logger.error(`Authentication failed: ${auth.error}`);
+    process.exit(1);
+  }
+  const { client, wallet, address } = auth;
+  logger.info(`   Funder: ${address}`);
+
+  let failed = false;
+  for (const assetType of assetTypes) {
+    logger.info(``);
+    logger.info(
+      assetType === "COLLATERAL" ? `💵 Collateral` : `🎟️ Token ${tokenId}`,
+    );
+    try {
+      const entry = await getClobBalanceAllowance(client, assetType, tokenId);
+      const onChain =
+        assetType === "COLLATERAL"
+          ? await getUsdcBalanceWithStatus(wallet, address)
+          : await getTokenBalanceWithStatus(wallet, address, tokenId!);
+      logClobBalance(entry, onChain.success ? onChain.value : undefined);
+      if (!onChain.success) {
+        logger.warn(`On-chain lookup failed: ${onChain.error}`);
+      }
+    } catch (err) {
+      failed = true;
+      logger.error(
+        `Lookup failed: ${err instanceof Error ? err.message : String(err)}`,
+      );
+    }
+  }
+
+  process.exit(failed ? 1 : 0);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
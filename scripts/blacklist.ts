@@ -0,0 +1,93 @@
+/**
+ * Blacklist Script - Manage the market blacklist
+ *
+ * Usage:
+ *   npm run blacklist -- list
+ *   npm run blacklist -- add <tokenId|conditionId> [note]
+ *   npm run blacklist -- remove <tokenId|conditionId|marketId>
+ *
+ * Edits the blacklist file (MARKET_BLACKLIST_PATH) that the bot adds to
+ * after bad outcomes and checks before every entry. A running bot picks
+ * up changes within a minute. `add` looks up the market's other IDs -
+ * and with BLACKLIST_SCOPE=event, every market of its event - so the
+ * entry matches whichever ID a strategy trades it by.
+ */
+
+import "../src/config/load-env";
+import { envStr } from "../src/config/env";
+import { withoutProfileArg } from "../src/config/profile";
+import {
+  DEFAULT_MARKET_BLACKLIST_PATH,
+  MarketBlacklist,
+  parseBlacklistScope,
+} from "../src/core/market-blacklist";
+
+function usage(): never {
+  console.log(`Usage:`);
+  console.log(`  npm run blacklist -- list`);
+  console.log(`  npm run blacklist -- add <tokenId|conditionId> [note]`);
+  console.log(`  npm run blacklist -- remove <id>`);
+  process.exit(1);
+}
+
+async function main() {
+  const [command, id, ...note] = withoutProfileArg(process.argv.slice(2));
+  const filePath = envStr(
+    "MARKET_BLACKLIST_PATH",
+    DEFAULT_MARKET_BLACKLIST_PATH,
+  );
+  if (!filePath) {
+    console.error(`❌ MARKET_BLACKLIST_PATH is empty - nothing to manage`);
+    process.exit(1);
+  }
+  const blacklist = new MarketBlacklist({
+    scope: parseBlacklistScope(envStr("BLACKLIST_SCOPE", "")),
+  });
+  blacklist.attachStateFile(filePath);
+
+  switch (command) {
+    case "list": {
+      const entries = blacklist.list();
+      console.log(`⛔ ${entries.length} blacklisted market(s) (${filePath})`);
+      for (const e of entries) {
+        console.log(
+          `   ${new Date(e.addedAt).toISOString()} ${e.reason.padEnd(16)} ${e.label.slice(0, 60)}` +
+            (e.detail ? ` (${e.detail})` : ""),
+        );
+        console.log(`      ${e.keys.length} ID(s): ${e.keys[0]}`);
+      }
+      break;
+    }
+    case "add": {
+      if (!id) usage();
+      const entry = await blacklist.blacklist(
+        id,
+        "MANUAL",
+        note.join(" ") || undefined,
+      );
+      console.log(
+        entry
+          ? `⛔ Blacklisted ${entry.label} (${entry.keys.length} ID(s))`
+          : `Already blacklisted: ${id}`,
+      );
+      break;
+    }
+    case "remove": {
+      if (!id) usage();
+      const removed = blacklist.remove(id);
+      console.log(
+        removed > 0
+          ? `✅ Removed ${removed} entry(s) matching ${id}`
+          : `Not blacklisted: ${id}`,
+      );
+      break;
+    }
+    default:
+      usage();
+  }
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
@@ -0,0 +1,76 @@
+/**
+ * Bootstrap Script - Check (and fix) everything needed to trade
+ *
+ * Usage:
+ *   npm run bootstrap                      # Full flow incl. test order
+ *   npm run bootstrap -- --no-approve      # Report missing approvals only
+ *   npm run bootstrap -- --no-test-order   # Skip the test order
+ *   npm run bootstrap -- --keep-test-order # Leave the test order resting
+ *   npm run bootstrap -- --token <id>      # Test order on this token
+ *
+ * Exits 0 when READY, 1 otherwise.
+ */
+
+import "../src/config/load-env";
+import { runBootstrap, type BootstrapStepStatus } from "../src/lib";
+
+const logger = {
+  info: (message: string) => console.log(message),
+  warn: (message: string) => console.warn("⚠️", message),
+  error: (message: string) => console.error("❌", message),
+};
+
+const STATUS_ICONS: Record<BootstrapStepStatus, string> = {
+  OK: "✅",
+  FIXED: "🔧",
+  WARN: "⚠️",
+  FAIL: "❌",
+  SKIPPED: "⏭️",
+};
+
+async function main() {
+  const args = process.argv.slice(2);
+  const tokenIdx = args.indexOf("--token");
+
+  logger.info(`━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━`);
+  logger.info(`🚀 POLYMARKET BOOTSTRAP`);
+  logger.info(`━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━`);
+
+  const privateKey = process.env.PRIVATE_KEY;
+  const rpcUrl = process.env.RPC_URL;
+  if (!privateKey || !rpcUrl) {
+    logger.error(`Missing PRIVATE_KEY or RPC_URL in .env`);
+    process.exit(1);
+  }
+
+  const report = await runBootstrap({
+    privateKey,
+    rpcUrl,
+    setApprovals: !args.includes("--no-approve"),
+    testOrder: !args.includes("--no-test-order"),
+    keepTestOrder: args.includes("--keep-test-order"),
+    testTokenId: tokenIdx >= 0 ? args[tokenIdx + 1] : undefined,
+    logger,
+  });
+
+  logger.info(``);
+  for (const step of report.steps) {
+    logger.info(
+      `${STATUS_ICONS[step.status]} ${step.step.padEnd(12)} ${step.detail}`,
+    );
+  }
+  logger.info(``);
+  logger.info(
+    report.verdict === "READY"
+      ? `🟢 READY - run 'npm start' to begin trading`
+      : `🔴 NOT_READY - fix the ❌ steps above and rerun 'npm run bootstrap'`,
+  );
+  logger.info(`━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━`);
+
+  process.exit(report.verdict === "READY" ? 0 : 1);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
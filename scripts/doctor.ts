@@ -0,0 +1,55 @@
+/**
+ * Doctor Script - What's wrong with this environment?
+ *
+ * Usage:
+ *   npm run doctor            # Fix list, worst first
+ *   npm run doctor -- --json  # The same report as JSON
+ *
+ * Checks the settings, the host (open-file limit, clock) and the funder
+ * derivation without signing or placing anything. Exits 0 when nothing
+ * is CRITICAL, 1 otherwise.
+ */
+
+import "../src/config/load-env";
+import { runDoctor, type DoctorSeverity } from "../src/lib";
+
+const SEVERITY_ICONS: Record<DoctorSeverity, string> = {
+  CRITICAL: "❌",
+  WARNING: "⚠️",
+  INFO: "ℹ️",
+};
+
+async function main() {
+  const report = await runDoctor();
+
+  if (process.argv.includes("--json")) {
+    console.log(JSON.stringify(report, null, 2));
+    process.exit(report.ok ? 0 : 1);
+  }
+
+  console.log(`━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━`);
+  console.log(`🩺 POLYMARKET DOCTOR`);
+  console.log(`━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━`);
+  if (report.findings.length === 0) {
+    console.log(`✅ Nothing to fix`);
+  }
+  report.findings.forEach((f, i) => {
+    console.log(
+      `${i + 1}. ${SEVERITY_ICONS[f.severity]} [${f.code}] ${f.message}`,
+    );
+    console.log(`   → ${f.fix}`);
+  });
+  console.log(``);
+  console.log(
+    report.ok
+      ? `🟢 No blockers - run 'npm run bootstrap' to check the wallet`
+      : `🔴 Fix the ❌ items above and rerun 'npm run doctor'`,
+  );
+  console.log(`━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━`);
+  process.exit(report.ok ? 0 : 1);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
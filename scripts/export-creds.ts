@@ -0,0 +1,68 @@
+/**
+ * Export Creds Script - Print the CLOB API credentials in use
+ *
+ * Usage:
+ *   ALLOW_CREDS_EXPORT=true npm run -s export-creds
+ *   ALLOW_CREDS_EXPORT=true npm run -s export-creds -- --creds-cache <path>
+ *
+ * Authenticates exactly like the bot (imported POLY_API_* keys when
+ * IMPORT_API_CREDS=true, then the credential cache, then derivation) and
+ * prints the resulting L2 credentials as JSON on stdout, for reuse in
+ * another client or to compare against the website. Progress goes to stderr, so the output can
+ * be redirected to a file. Refuses to run unless ALLOW_CREDS_EXPORT=true.
+ */
+
+import "../src/config/load-env";
+import { envBool } from "../src/config/env";
+import {
+  activeCreds,
+  createClobClient,
+  CREDS_EXPORT_ENV,
+} from "../src/lib";
+
+// stdout carries only the JSON
+const logger = {
+  info: (message: string) => console.error(message),
+  warn: (message: string) => console.error("⚠️", message),
+  error: (message: string) => console.error("❌", message),
+};
+
+async function main() {
+  if (!envBool(CREDS_EXPORT_ENV, false)) {
+    logger.error(
+      `Exporting credentials is off - set ${CREDS_EXPORT_ENV}=true to allow it`,
+    );
+    process.exit(1);
+  }
+  const privateKey = process.env.PRIVATE_KEY;
+  const rpcUrl = process.env.RPC_URL;
+  if (!privateKey || !rpcUrl) {
+    logger.error(`Missing PRIVATE_KEY or RPC_URL in .env`);
+    process.exit(1);
+  }
+
+  const auth = await createClobClient(privateKey, rpcUrl, logger);
+  const creds = auth.client ? activeCreds(auth.client) : null;
+  if (!auth.success || !creds) {
+    logger.error(auth.error ?? "Authenticated without API credentials");
+    process.exit(1);
+  }
+
+  console.log(
+    JSON.stringify(
+      {
+        ...creds,
+        address: auth.effectiveAddress ?? auth.address,
+        signatureType: auth.signatureType,
+        funderAddress: auth.funderAddress,
+      },
+      null,
+      2,
+    ),
+  );
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
@@ -0,0 +1,75 @@
+/**
+ * Hedge Target Script - Change hedge targets while the bot runs
+ *
+ * Usage:
+ *   npm run hedge-target -- list
+ *   npm run hedge-target -- set <marketId|tokenId|*> <ratio>
+ *   npm run hedge-target -- clear <marketId|tokenId|*>
+ *
+ * Edits the overrides file (HEDGE_TARGETS_PATH) layered on top of
+ * HEDGE_TARGETS. A running bot picks up changes within a minute and
+ * buys or sells the complement of affected positions to the new target.
+ * `clear` drops an override, so HEDGE_TARGETS applies again.
+ */
+
+import "../src/config/load-env";
+import { envStr } from "../src/config/env";
+import { withoutProfileArg } from "../src/config/profile";
+import {
+  DEFAULT_HEDGE_TARGETS_PATH,
+  HedgeTargets,
+  parseHedgeRatio,
+  parseHedgeTargets,
+} from "../src/core/hedge-targets";
+
+function usage(): never {
+  console.log(`Usage:`);
+  console.log(`  npm run hedge-target -- list`);
+  console.log(`  npm run hedge-target -- set <marketId|tokenId|*> <ratio>`);
+  console.log(`  npm run hedge-target -- clear <marketId|tokenId|*>`);
+  process.exit(1);
+}
+
+function main() {
+  const [command, key, value] = withoutProfileArg(process.argv.slice(2));
+  const filePath = envStr("HEDGE_TARGETS_PATH", DEFAULT_HEDGE_TARGETS_PATH);
+  if (!filePath) {
+    console.error(`❌ HEDGE_TARGETS_PATH is empty - nothing to manage`);
+    process.exit(1);
+  }
+  const targets = new HedgeTargets(
+    parseHedgeTargets(envStr("HEDGE_TARGETS", "")),
+  );
+  targets.attachStateFile(filePath);
+
+  switch (command) {
+    case "list": {
+      const entries = Object.entries(targets.list());
+      console.log(`🛡️ ${entries.length} hedge target(s) (${filePath})`);
+      for (const [id, ratio] of entries) {
+        console.log(`   ${(ratio * 100).toFixed(0).padStart(3)}%  ${id}`);
+      }
+      break;
+    }
+    case "set": {
+      const ratio = parseHedgeRatio(value ?? "");
+      if (!key || ratio === null) usage();
+      targets.set(key, ratio);
+      console.log(`✅ Hedge target for ${key}: ${(ratio * 100).toFixed(0)}%`);
+      break;
+    }
+    case "clear": {
+      if (!key) usage();
+      console.log(
+        targets.clear(key)
+          ? `✅ Cleared the override for ${key}`
+          : `No override for ${key}`,
+      );
+      break;
+    }
+    default:
+      usage();
+  }
+}
+
+main();
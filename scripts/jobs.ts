@@ -0,0 +1,92 @@
+/**
+ * Jobs Script - Show and change the job schedule while the bot runs
+ *
+ * Usage:
+ *   npm run jobs -- list
+ *   npm run jobs -- add "<job>@<when>"      e.g. "dust@mon-fri 04:00"
+ *   npm run jobs -- remove "<job>@<when>"
+ *
+ * Edits the schedule file (JOB_SCHEDULE_PATH) layered on top of
+ * JOB_SCHEDULE. A running bot picks up changes within a minute. `list`
+ * shows when each job last ran (and how that went) and runs next.
+ */
+
+import "../src/config/load-env";
+import { envStr } from "../src/config/env";
+import { withoutProfileArg } from "../src/config/profile";
+import {
+  DEFAULT_JOB_SCHEDULE_PATH,
+  JOB_NAMES,
+  JobScheduler,
+  parseJobSchedule,
+  parseJobSpec,
+} from "../src/core/job-scheduler";
+
+function usage(): never {
+  console.log(`Usage:`);
+  console.log(`  npm run jobs -- list`);
+  console.log(`  npm run jobs -- add "<job>@<when>"`);
+  console.log(`  npm run jobs -- remove "<job>@<when>"`);
+  console.log(`Jobs: ${JOB_NAMES.join(", ")}`);
+  console.log(`When: "every 30m", "every 2h", "04:00", "mon-fri 13:25" (UTC)`);
+  process.exit(1);
+}
+
+function formatTime(ms: number | undefined): string {
+  if (ms === undefined || !Number.isFinite(ms)) return "-";
+  return new Date(ms).toISOString().slice(0, 16).replace("T", " ");
+}
+
+function main() {
+  const [command, ...rest] = withoutProfileArg(process.argv.slice(2));
+  const filePath = envStr("JOB_SCHEDULE_PATH", DEFAULT_JOB_SCHEDULE_PATH);
+  if (!filePath) {
+    console.error(`❌ JOB_SCHEDULE_PATH is empty - nothing to manage`);
+    process.exit(1);
+  }
+  const scheduler = new JobScheduler(
+    parseJobSchedule(envStr("JOB_SCHEDULE", "")),
+  );
+  scheduler.attachStateFile(filePath);
+
+  switch (command) {
+    case "list": {
+      const jobs = scheduler.list();
+      console.log(`⏰ ${jobs.length} scheduled job(s) (${filePath}, UTC)`);
+      for (const job of jobs) {
+        const last =
+          job.lastRunAt === undefined
+            ? "never run"
+            : `last ${formatTime(job.lastRunAt)} ` +
+              `${job.ok === false ? "❌" : "✅"} ${job.lastResult ?? ""}`;
+        console.log(`   ${job.id}`);
+        console.log(`      next ${formatTime(job.nextRunAt)} | ${last}`);
+      }
+      break;
+    }
+    case "add": {
+      const spec = parseJobSpec(rest.join(" "));
+      if (!spec) usage();
+      console.log(
+        scheduler.add(spec)
+          ? `✅ Scheduled ${spec.id}`
+          : `${spec.id} is already scheduled`,
+      );
+      break;
+    }
+    case "remove": {
+      const spec = parseJobSpec(rest.join(" "));
+      if (!spec) usage();
+      console.log(
+        scheduler.remove(spec.id)
+          ? `✅ Removed ${spec.id}`
+          : `${spec.id} isn't scheduled`,
+      );
+      break;
+    }
+    default:
+      usage();
+  }
+}
+
+main();
@@ -0,0 +1,65 @@
+/**
+ * Journal Backfill Script - Import the account's trade history
+ *
+ * Usage:
+ *   npm run journal-backfill
+ *
+ * Pulls every trade the CLOB has for the account and merges it into the
+ * fill journal (FILL_JOURNAL_PATH), skipping trades already journaled.
+ * Run once after switching an account that traded elsewhere to the bot,
+ * so cost basis and realized PnL include the earlier fills. Safe to
+ * re-run - it only ever adds trades that are missing.
+ */
+
+import "../src/config/load-env";
+import { createClobClient } from "../src/lib";
+import { backfillFillJournal, getFillJournal } from "../src/core/fill-journal";
+
+const logger = {
+  info: (message: string) => console.log(message),
+  warn: (message: string) => console.warn("⚠️", message),
+  error: (message: string) => console.error("❌", message),
+};
+
+async function main() {
+  const privateKey = process.env.PRIVATE_KEY;
+  const rpcUrl = process.env.RPC_URL;
+  if (!privateKey || !rpcUrl) {
+    logger.error(`Missing PRIVATE_KEY or RPC_URL in .env`);
+    process.exit(1);
+  }
+
+  logger.info(`🔐 Authenticating...`);
+  const auth = await createClobClient(privateKey, rpcUrl);
+  if (!auth.success || !auth.client || !auth.address) {
+    logger.error(`Authentication failed: ${auth.error}`);
+    process.exit(1);
+  }
+
+  const journal = getFillJournal();
+  const before = journal.size();
+  logger.info(`📒 Journal has ${before} fill(s), fetching trade history...`);
+
+  const result = await backfillFillJournal(auth.client, journal, auth.address);
+  logger.info(
+    `   Fetched ${result.fetched} trade(s): ${result.added} added, ${result.duplicates} already journaled, ${result.skipped} without a fill for this account`,
+  );
+
+  const basis = journal.getCostBasis();
+  const positions = basis.filter((b) => b.shares > 0);
+  const realized = basis.reduce((sum, b) => sum + b.realizedPnlUsd, 0);
+  logger.info(``);
+  logger.info(`📊 Cost basis (${positions.length} open token(s))`);
+  for (const b of positions) {
+    logger.info(
+      `   ${b.tokenId.slice(0, 16)}... ${b.shares.toFixed(2)} shares @ ${(b.avgCost * 100).toFixed(1)}¢ ($${b.costBasisUsd.toFixed(2)})`,
+    );
+  }
+  logger.info(`   Realized PnL: $${realized.toFixed(2)}`);
+  process.exit(0);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
@@ -14,7 +14,7 @@
  *   LEADERBOARD_CACHE_FILE - Cache file path (default: .leaderboard-cache.json)
  */
 
-import "dotenv/config";
+import "../src/config/load-env";
 import {
   getTargetAddresses,
   getDefaultLeaderboardOptions,
@@ -0,0 +1,45 @@
+/**
+ * Quote Report Script - Which markets are worth quoting?
+ *
+ * Usage:
+ *   npm run quote-report
+ *   npm run quote-report -- --top 20
+ *
+ * Reads the fill journal (FILL_JOURNAL_PATH) and prints, per market with
+ * maker fills: spread captured vs the mid at fill time, adverse selection
+ * by the markout (QUOTE_MARKOUT_SECONDS), the net, and inventory
+ * turnover. Mids are journaled by the running bot; fills imported with
+ * journal-backfill have none and only count toward volume and turnover.
+ */
+
+import "../src/config/load-env";
+import { getFillJournal } from "../src/core/fill-journal";
+import {
+  buildQuoteReport,
+  formatQuoteReport,
+} from "../src/core/quote-report";
+
+function main() {
+  const topIndex = process.argv.indexOf("--top");
+  const top = topIndex !== -1 ? Number(process.argv[topIndex + 1]) : 10;
+
+  const reports = buildQuoteReport(getFillJournal().getFills());
+  if (reports.length === 0) {
+    console.log(`No maker fills in the journal - nothing has been quoted.`);
+    return;
+  }
+
+  const net = reports.reduce((sum, r) => sum + r.netUsd, 0);
+  const priced = reports.reduce((sum, r) => sum + r.pricedFills, 0);
+  const fills = reports.reduce((sum, r) => sum + r.makerFills, 0);
+  console.log(
+    `🎯 ${reports.length} quoted market(s), ${fills} maker fill(s) ` +
+      `(${priced} with a mid), net $${net.toFixed(2)}`,
+  );
+  console.log(``);
+  for (const line of formatQuoteReport(reports.slice(0, top))) {
+    console.log(`   ${line}`);
+  }
+}
+
+main();
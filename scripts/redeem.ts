@@ -5,7 +5,7 @@
  * Redeems all resolved positions and claims payouts
  */
 
-import "dotenv/config";
+import "../src/config/load-env";
 import {
   createClobClient,
   redeemAllPositions,
@@ -0,0 +1,87 @@
+/**
+ * Replay Fill Script - Would this order have filled, and at what price?
+ *
+ * Usage:
+ *   npm run replay-fill -- <tokenId> <time> <BUY|SELL> <price> <shares>
+ *   npm run replay-fill -- 7132... 2026-01-15T12:00:00Z BUY 0.62 50
+ *
+ * Loads the book history the bot saved to BOOK_HISTORY_PATH and runs
+ * replayFillAt() against the snapshot in force at <time> (ISO timestamp
+ * or Unix ms). Only what was recorded can be replayed - a time older than
+ * the kept snapshots reports NO_BOOK.
+ */
+
+import "../src/config/load-env";
+import { envStr } from "../src/config/env";
+import { withoutProfileArg } from "../src/config/profile";
+import { loadStateFile } from "../src/infra/persistence";
+import {
+  MarketDataStore,
+  type MarketDataHistory,
+} from "../src/lib/market-data-store";
+import { replayFillAt } from "../src/lib/replay-fill";
+
+function usage(): never {
+  console.log(`Usage:`);
+  console.log(
+    `  npm run replay-fill -- <tokenId> <time> <BUY|SELL> <price> <shares>`,
+  );
+  process.exit(1);
+}
+
+function parseTime(value: string): number {
+  return /^\d+$/.test(value) ? Number(value) : Date.parse(value);
+}
+
+function main() {
+  const args = withoutProfileArg(process.argv.slice(2));
+  if (args.length !== 5) usage();
+  const [tokenId, timeArg, sideArg] = args;
+  const at = parseTime(timeArg);
+  const side = sideArg.toUpperCase();
+  const price = Number(args[3]);
+  const shares = Number(args[4]);
+  if (!Number.isFinite(at) || (side !== "BUY" && side !== "SELL")) usage();
+  if (!(price > 0 && price < 1) || !(shares > 0)) usage();
+
+  const filePath = envStr("BOOK_HISTORY_PATH", "");
+  if (!filePath) {
+    console.error(`❌ BOOK_HISTORY_PATH is empty - no book history recorded`);
+    process.exit(1);
+  }
+  const loaded = loadStateFile<MarketDataHistory>(filePath);
+  if (!loaded.ok || !loaded.payload) {
+    console.error(
+      `❌ No book history in ${filePath}${loaded.error ? `: ${loaded.error}` : ""}`,
+    );
+    process.exit(1);
+  }
+  const store = new MarketDataStore();
+  store.importHistory(loaded.payload);
+
+  const result = replayFillAt(tokenId, at, { side, price, shares }, store);
+  console.log(
+    `🔎 ${side} ${shares} @ ${(price * 100).toFixed(1)}¢ on ${tokenId.slice(0, 16)}... at ${new Date(at).toISOString()}`,
+  );
+  console.log(`   Status: ${result.status}`);
+  if (result.bookAt === null) {
+    console.log(`   No snapshot recorded at or before that time`);
+    return;
+  }
+  console.log(`   Book at: ${new Date(result.bookAt).toISOString()}`);
+  if (result.touchPrice !== null) {
+    console.log(`   Touch: ${(result.touchPrice * 100).toFixed(1)}¢`);
+  }
+  if (result.avgPrice !== null) {
+    console.log(
+      `   Immediate fill: ${result.filledShares.toFixed(2)} shares @ ${(result.avgPrice * 100).toFixed(2)}¢ ($${result.notionalUsd.toFixed(2)})`,
+    );
+  }
+  if (result.restingShares > 0) {
+    console.log(
+      `   Resting: ${result.restingShares.toFixed(2)} shares, ${result.tradedThroughShares.toFixed(2)} printed at or through the limit later`,
+    );
+  }
+}
+
+main();
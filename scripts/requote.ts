@@ -0,0 +1,127 @@
+/**
+ * Requote Script - Refresh resting quotes with the fewest cancels and posts
+ *
+ * Usage:
+ *   npm run requote -- <quotes.json>
+ *   npm run requote -- <quotes.json> --reprice
+ *
+ * quotes.json maps token IDs to the quotes wanted on them:
+ *
+ *   {
+ *     "<tokenId>": [{ "side": "BUY", "price": 0.42, "sizeShares": 20 }],
+ *     "<otherTokenId>": []
+ *   }
+ *
+ * Runs requote(): resting orders that already match are kept, the rest are
+ * cancelled in one batch, and missing quotes are posted post-only. A token
+ * with an empty list has all of its orders pulled; tokens not listed are
+ * left alone. A new quote that would cross is rejected, or moved one tick
+ * inside the spread with --reprice.
+ *
+ * Without LIVE_TRADING=I_UNDERSTAND_THE_RISKS the diff is printed and
+ * nothing is cancelled or posted.
+ */
+
+import "../src/config/load-env";
+import { readFileSync } from "fs";
+import { withoutProfileArg } from "../src/config/profile";
+import { createClobClient, isLiveTradingEnabled } from "../src/lib";
+import {
+  computeRequoteDiff,
+  requote,
+  toRestingQuote,
+  type DesiredQuotes,
+  type OpenOrderFields,
+  type Quote,
+  type RequoteDiff,
+} from "../src/lib/requote";
+
+function usage(): never {
+  console.log(`Usage:`);
+  console.log(`  npm run requote -- <quotes.json> [--reprice]`);
+  process.exit(1);
+}
+
+function parseDesiredQuotes(raw: unknown): DesiredQuotes | null {
+  if (!raw || typeof raw !== "object" || Array.isArray(raw)) return null;
+  const desired: DesiredQuotes = {};
+  for (const [tokenId, quotes] of Object.entries(raw)) {
+    if (!Array.isArray(quotes)) return null;
+    desired[tokenId] = [];
+    for (const q of quotes) {
+      const side = String(q?.side ?? "").toUpperCase();
+      const price = Number(q?.price);
+      const sizeShares = Number(q?.sizeShares);
+      if (side !== "BUY" && side !== "SELL") return null;
+      if (!(price > 0 && price < 1) || !(sizeShares > 0)) return null;
+      desired[tokenId].push({ side, price, sizeShares });
+    }
+  }
+  return desired;
+}
+
+function formatQuote(q: Quote): string {
+  return `${q.side} ${q.sizeShares.toFixed(2)} @ ${(q.price * 100).toFixed(1)}¢ ${q.tokenId.slice(0, 16)}...`;
+}
+
+function printDiff(diff: RequoteDiff): void {
+  for (const q of diff.keep) console.log(`   KEEP   ${formatQuote(q)}`);
+  for (const q of diff.cancel) console.log(`   CANCEL ${formatQuote(q)}`);
+  for (const q of diff.post) console.log(`   POST   ${formatQuote(q)}`);
+}
+
+async function main() {
+  const args = withoutProfileArg(process.argv.slice(2));
+  const reprice = args.includes("--reprice");
+  const [filePath] = args.filter((a) => a !== "--reprice");
+  if (!filePath) usage();
+
+  let desired: DesiredQuotes | null;
+  try {
+    desired = parseDesiredQuotes(JSON.parse(readFileSync(filePath, "utf8")));
+  } catch (err) {
+    console.error(
+      `❌ Could not read ${filePath}: ${err instanceof Error ? err.message : err}`,
+    );
+    process.exit(1);
+  }
+  if (!desired) {
+    console.error(
+      `❌ ${filePath} must map token IDs to lists of { side, price, sizeShares }`,
+    );
+    process.exit(1);
+  }
+
+  const privateKey = process.env.PRIVATE_KEY;
+  const rpcUrl = process.env.RPC_URL;
+  if (!privateKey || !rpcUrl) {
+    console.error(`❌ Missing PRIVATE_KEY or RPC_URL in .env`);
+    process.exit(1);
+  }
+
+  console.log(`🔐 Authenticating...`);
+  const auth = await createClobClient(privateKey, rpcUrl);
+  if (!auth.success || !auth.client) {
+    console.error(`❌ Authentication failed: ${auth.error}`);
+    process.exit(1);
+  }
+
+  if (!isLiveTradingEnabled()) {
+    const orders = (await auth.client.getOpenOrders()) as OpenOrderFields[];
+    const diff = computeRequoteDiff(desired, orders.map(toRestingQuote));
+    console.log(`🔁 Requote diff (dry run - LIVE_TRADING is not enabled)`);
+    printDiff(diff);
+    process.exit(0);
+  }
+
+  const result = await requote(auth.client, desired, {
+    onCross: reprice ? "REPRICE" : "REJECT",
+  });
+  printDiff(result.diff);
+  process.exit(result.failed > 0 ? 1 : 0);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
@@ -0,0 +1,113 @@
+/**
+ * Scan Script - Find markets with tradeable books
+ *
+ * Usage:
+ *   npm run scan
+ *   npm run scan -- --top 100 --concurrency 12
+ *   npm run scan -- --max-spread 4 --min-depth 50 --min-price 30 --max-price 70
+ *
+ * Lists the most active markets by 24h volume, then checks their books in
+ * parallel (SCAN_CONCURRENCY or --concurrency at a time, rate-limited to
+ * the CLOB's 10 req/s). Candidates are printed as soon as their book
+ * passes, with progress along the way and a summary at the end.
+ * Read-only - no wallet or CLOB login needed.
+ */
+
+import "../src/config/load-env";
+import { ClobClient } from "@polymarket/clob-client";
+import {
+  POLYGON,
+  POLYMARKET_API,
+  VolumeScanner,
+  formatScanSummary,
+  scanMarketsConcurrently,
+  type ScanFilters,
+} from "../src/lib";
+
+const logger = {
+  info: (message: string) => console.log(message),
+  warn: (message: string) => console.warn("⚠️", message),
+  error: (message: string) => console.error("❌", message),
+};
+
+function argValue(args: string[], flag: string): string | undefined {
+  const idx = args.indexOf(flag);
+  return idx >= 0 ? args[idx + 1] : undefined;
+}
+
+function numArg(args: string[], flag: string): number | undefined {
+  const value = argValue(args, flag);
+  return value === undefined ? undefined : Number(value);
+}
+
+async function main() {
+  const args = process.argv.slice(2);
+  const top =
+    numArg(args, "--top") ?? Number(process.env.SCAN_TOP_N_MARKETS ?? 50);
+  const concurrency =
+    numArg(args, "--concurrency") ?? Number(process.env.SCAN_CONCURRENCY ?? 8);
+  const filters: ScanFilters = {
+    minPriceCents: numArg(args, "--min-price"),
+    maxPriceCents: numArg(args, "--max-price"),
+    maxSpreadCents: numArg(args, "--max-spread"),
+    minDepthUsd: numArg(args, "--min-depth"),
+  };
+  for (const key of Object.keys(filters) as Array<keyof ScanFilters>) {
+    if (filters[key] === undefined) delete filters[key];
+    else if (!Number.isFinite(filters[key])) {
+      logger.error(`Invalid value for ${key}`);
+      process.exit(1);
+    }
+  }
+
+  const scanner = new VolumeScanner({
+    scanIntervalSeconds: 0,
+    scanTopNMarkets: top,
+    scanMinVolumeUsd: Number(process.env.SCAN_MIN_VOLUME_USD ?? 10000),
+  });
+  const markets = await scanner.scanActiveMarkets();
+  if (markets.length === 0) {
+    logger.warn(`No active markets returned`);
+    process.exit(1);
+  }
+
+  logger.info(
+    `🔍 Checking ${markets.length} book(s), ${concurrency} at a time...`,
+  );
+  const client = new ClobClient(POLYMARKET_API.CLOB, POLYGON.CHAIN_ID);
+  let lastProgress = 0;
+  const summary = await scanMarketsConcurrently(
+    markets.map((m) => ({
+      tokenId: m.tokenId,
+      marketId: m.marketId,
+      question: m.question,
+    })),
+    (tokenId) => client.getOrderBook(tokenId),
+    filters,
+    {
+      concurrency,
+      onCandidate: (c) =>
+        logger.info(
+          `   ✅ ${(c.bestBid * 100).toFixed(1)}¢/${(c.bestAsk * 100).toFixed(1)}¢ spread ${c.spreadCents.toFixed(1)}¢ depth $${c.bidDepthUsd.toFixed(0)}/$${c.askDepthUsd.toFixed(0)} | ${c.question?.slice(0, 60)} (${c.tokenId.slice(0, 16)}...)`,
+        ),
+      onProgress: (p) => {
+        // Every 10% - often enough to show it's moving on a long scan
+        if (p.done === p.total || p.done - lastProgress >= p.total / 10) {
+          lastProgress = p.done;
+          logger.info(
+            `   … ${p.done}/${p.total} checked, ${p.candidates} candidate(s)`,
+          );
+        }
+      },
+    },
+  );
+
+  logger.info(``);
+  logger.info(`📊 ${formatScanSummary(summary)}`);
+  process.exit(0);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
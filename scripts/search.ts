@@ -0,0 +1,63 @@
+/**
+ * Search Script - Keyword search over active markets
+ *
+ * Usage:
+ *   npm run search -- <query...>
+ *   npm run search -- fed rate cut december --limit 5
+ *
+ * Answers from the local index the bot saves to MARKET_SEARCH_PATH. When
+ * there is no saved index, or it is older than MARKET_SEARCH_REFRESH_MIN,
+ * it is rebuilt from Gamma first. Read-only - no wallet or CLOB login
+ * needed.
+ */
+
+import "../src/config/load-env";
+import { envNum } from "../src/config/env";
+import { withoutProfileArg } from "../src/config/profile";
+import { getMarketSearchIndex } from "../src/lib/market-search";
+
+function usage(): never {
+  console.log(`Usage:`);
+  console.log(`  npm run search -- <query...> [--limit <n>]`);
+  process.exit(1);
+}
+
+async function main() {
+  const args = withoutProfileArg(process.argv.slice(2));
+  let limit = 10;
+  const limitIdx = args.indexOf("--limit");
+  if (limitIdx >= 0) {
+    limit = Number(args[limitIdx + 1]);
+    if (!Number.isInteger(limit) || limit <= 0) usage();
+    args.splice(limitIdx, 2);
+  }
+  const query = args.join(" ").trim();
+  if (!query) usage();
+
+  const index = getMarketSearchIndex();
+  const maxAgeMs = envNum("MARKET_SEARCH_REFRESH_MIN", 15) * 60 * 1000;
+  if (index.size() === 0 || index.getAge() > maxAgeMs) {
+    console.log(`🔄 Rebuilding the market index from Gamma...`);
+    if ((await index.refreshFromGamma()) === null && index.size() === 0) {
+      console.error(`❌ Could not build the market index`);
+      process.exit(1);
+    }
+  }
+
+  const hits = index.search(query, limit);
+  console.log(
+    `🔎 ${hits.length} match(es) for "${query}" (${index.size()} markets indexed)`,
+  );
+  for (const hit of hits) {
+    console.log(`   ${hit.score.toFixed(2).padStart(6)}  ${hit.question}`);
+    console.log(`           market ${hit.marketId}  ${hit.slug}`);
+    if (hit.tokenIds.length > 0) {
+      console.log(`           tokens ${hit.tokenIds.join(", ")}`);
+    }
+  }
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
@@ -0,0 +1,123 @@
+/**
+ * Set Creds Script - Use existing CLOB API keys instead of derived ones
+ *
+ * Usage:
+ *   npm run set-creds
+ *   npm run set-creds -- --creds-cache <path>
+ *   npm run set-creds < creds.txt
+ *
+ * Reads the API key, secret and passphrase from POLY_API_KEY,
+ * POLY_API_SECRET and POLY_PASSPHRASE when all three are set, otherwise
+ * one per line from stdin (prompted for on a terminal) - never from the
+ * command line, where they would end up in shell history and `ps`.
+ *
+ * Checks keys generated elsewhere (the website, py-clob-client) with one
+ * authenticated call as this PRIVATE_KEY / signature type / funder, then
+ * saves them to the credential cache (API_CREDS_CACHE_PATH or
+ * --creds-cache) so every later run starts from them instead of deriving.
+ * To keep them in .env instead, set IMPORT_API_CREDS=true with
+ * POLY_API_KEY, POLY_API_SECRET and POLY_PASSPHRASE. Exits 1 if the
+ * exchange refuses them.
+ */
+
+import "../src/config/load-env";
+import { createInterface } from "readline";
+import { withoutProfileArg } from "../src/config/profile";
+import {
+  createClobClient,
+  IMPORT_CREDS_ENV,
+  IMPORTED_CREDS_ENV,
+  resolveCredsCachePath,
+} from "../src/lib";
+
+const logger = {
+  info: (message: string) => console.log(message),
+  warn: (message: string) => console.warn("⚠️", message),
+  error: (message: string) => console.error("❌", message),
+};
+
+function usage(): never {
+  console.log(`Usage:`);
+  console.log(`  npm run set-creds [-- --creds-cache <path>]`);
+  console.log(
+    `  Key, secret and passphrase come from ${IMPORTED_CREDS_ENV.join(" / ")} or stdin, one per line`,
+  );
+  process.exit(1);
+}
+
+/** Arguments with `--creds-cache` (and its value) removed */
+function positionalArgs(argv: string[]): string[] {
+  const rest: string[] = [];
+  for (let i = 0; i < argv.length; i++) {
+    if (argv[i] === "--creds-cache") i++;
+    else if (!argv[i].startsWith("--creds-cache=")) rest.push(argv[i]);
+  }
+  return rest;
+}
+
+/**
+ * Credentials from the POLY_API_* variables, or else one per line on stdin
+ */
+async function readCreds(): Promise<string[]> {
+  const fromEnv = IMPORTED_CREDS_ENV.map(
+    (name) => process.env[name]?.trim() ?? "",
+  );
+  if (fromEnv.every(Boolean)) return fromEnv;
+
+  const interactive = process.stdin.isTTY === true;
+  const prompt = (i: number) => {
+    if (interactive) process.stderr.write(`${IMPORTED_CREDS_ENV[i]}: `);
+  };
+  const creds: string[] = [];
+  const rl = createInterface({ input: process.stdin, terminal: false });
+  prompt(0);
+  for await (const line of rl) {
+    creds.push(line.trim());
+    if (creds.length === IMPORTED_CREDS_ENV.length) break;
+    prompt(creds.length);
+  }
+  rl.close();
+  return creds;
+}
+
+async function main() {
+  if (positionalArgs(withoutProfileArg(process.argv.slice(2))).length > 0) {
+    usage();
+  }
+  const creds = await readCreds();
+  if (creds.length !== IMPORTED_CREDS_ENV.length || !creds.every(Boolean)) {
+    usage();
+  }
+
+  const cachePath = resolveCredsCachePath();
+  if (!cachePath) {
+    logger.error(
+      `No credential cache - set API_CREDS_CACHE_PATH or pass --creds-cache`,
+    );
+    process.exit(1);
+  }
+  const privateKey = process.env.PRIVATE_KEY;
+  const rpcUrl = process.env.RPC_URL;
+  if (!privateKey || !rpcUrl) {
+    logger.error(`Missing PRIVATE_KEY or RPC_URL in .env`);
+    process.exit(1);
+  }
+
+  // Imported credentials are checked, then cached for this identity
+  process.env[IMPORT_CREDS_ENV] = "true";
+  IMPORTED_CREDS_ENV.forEach((name, i) => {
+    process.env[name] = creds[i];
+  });
+  logger.info(`🔐 Checking API key ...${creds[0].slice(-6)}...`);
+  const auth = await createClobClient(privateKey, rpcUrl, logger);
+  if (!auth.success) {
+    logger.error(auth.error ?? "Authentication failed");
+    process.exit(1);
+  }
+  logger.info(`✅ Saved to ${cachePath} - later runs use these credentials`);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
@@ -0,0 +1,46 @@
+/**
+ * Signal Queue Script - Show entry signals waiting for a free position slot
+ *
+ * Usage:
+ *   npm run signal-queue
+ *
+ * Reads the queue a running bot saves to SIGNAL_QUEUE_PATH (see
+ * SIGNAL_QUEUE_MAX). Signals are entered oldest first as positions close.
+ */
+
+import "../src/config/load-env";
+import { envNum, envStr } from "../src/config/env";
+import {
+  DEFAULT_SIGNAL_QUEUE_PATH,
+  SignalQueue,
+} from "../src/core/signal-queue";
+
+function main() {
+  const filePath = envStr("SIGNAL_QUEUE_PATH", DEFAULT_SIGNAL_QUEUE_PATH);
+  if (!filePath) {
+    console.error(`❌ SIGNAL_QUEUE_PATH is empty - the queue isn't saved`);
+    process.exit(1);
+  }
+  const maxSize = envNum("SIGNAL_QUEUE_MAX", 0);
+  const queue = new SignalQueue({
+    maxSize,
+    ttlMs: envNum("SIGNAL_QUEUE_TTL_SEC", 300) * 1000,
+  });
+  queue.attachStateFile(filePath);
+
+  const now = Date.now();
+  const signals = queue.list(now);
+  const limit = maxSize > 0 ? `/${maxSize}` : " (SIGNAL_QUEUE_MAX is off)";
+  console.log(`📥 ${signals.length}${limit} queued signal(s) (${filePath})`);
+  signals.forEach((signal, i) => {
+    const waited = ((now - signal.queuedAt) / 1000).toFixed(0);
+    const left = ((signal.expiresAt - now) / 1000).toFixed(0);
+    console.log(
+      `   ${i + 1}. ${signal.tokenId} [${signal.strategy}] ` +
+        `waiting ${waited}s, expires in ${left}s` +
+        (signal.marketId ? ` | market ${signal.marketId}` : ""),
+    );
+  });
+}
+
+main();
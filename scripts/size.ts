@@ -0,0 +1,55 @@
+/**
+ * Size Script - Recommend an order size from an estimated edge
+ *
+ * Usage:
+ *   npm run size -- <edge> <price> <bankrollUsd> <bankrollFractionCap>
+ *   npm run size -- 0.05 0.42 500 0.02
+ *
+ * Runs calculateKellySize() - quarter Kelly for buying a token at `price`
+ * whose true probability is `price + edge` - capped at
+ * bankrollFractionCap of the bankroll and at MAX_TRADE_USD, the same cap
+ * the bot's entries use. Nothing is traded.
+ */
+
+import "../src/config/load-env";
+import { envNum } from "../src/config/env";
+import { withoutProfileArg } from "../src/config/profile";
+import { calculateKellySize } from "../src/core/risk";
+
+function usage(): never {
+  console.log(`Usage:`);
+  console.log(
+    `  npm run size -- <edge> <price> <bankrollUsd> <bankrollFractionCap>`,
+  );
+  process.exit(1);
+}
+
+function main() {
+  const args = withoutProfileArg(process.argv.slice(2));
+  if (args.length !== 4) usage();
+  const numbers = args.map(Number);
+  if (!numbers.every(Number.isFinite)) usage();
+  const [edge, price, bankrollUsd, bankrollFractionCap] = numbers;
+  const maxTradeUsd = envNum("MAX_TRADE_USD", 25);
+
+  const size = calculateKellySize({
+    edge,
+    price,
+    bankrollUsd,
+    bankrollFractionCap,
+    maxTradeUsd,
+  });
+  console.log(
+    `📐 Edge ${(edge * 100).toFixed(1)}pts @ ${(price * 100).toFixed(1)}¢, ` +
+      `bankroll $${bankrollUsd.toFixed(2)}`,
+  );
+  console.log(
+    `   Full Kelly ${(size.fullKellyFraction * 100).toFixed(2)}% → ` +
+      `applied ${(size.appliedFraction * 100).toFixed(2)}% ` +
+      `(cap ${(bankrollFractionCap * 100).toFixed(2)}%, ` +
+      `MAX_TRADE_USD $${maxTradeUsd.toFixed(2)})`,
+  );
+  console.log(`   Recommended size: $${size.sizeUsd.toFixed(2)}`);
+}
+
+main();
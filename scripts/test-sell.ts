@@ -8,7 +8,7 @@
  * This tests the exact sell logic that will be used in recovery mode.
  */
 
-import "dotenv/config";
+import "../src/config/load-env";
 import { Side, OrderType } from "@polymarket/clob-client";
 import type { ClobClient } from "@polymarket/clob-client";
 import {
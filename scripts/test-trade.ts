@@ -0,0 +1,72 @@
+/**
+ * Test Trade Script - Confirm the account can place and cancel orders
+ *
+ * Usage:
+ *   npm run test-trade                        # First active market
+ *   npm run test-trade -- --token <id>        # Specific token
+ *   npm run test-trade -- --distance 80       # Bid 80% below the mid
+ *   npm run test-trade -- --keep              # Don't cancel the order
+ *
+ * Places the smallest valid BUY far below the mid (it rests, never fills)
+ * and cancels it. Exits 0 on success.
+ */
+
+import "../src/config/load-env";
+import { createClobClient, placeTestTrade } from "../src/lib";
+
+const logger = {
+  info: (message: string) => console.log(message),
+  warn: (message: string) => console.warn("⚠️", message),
+  error: (message: string) => console.error("❌", message),
+};
+
+function argValue(args: string[], flag: string): string | undefined {
+  const idx = args.indexOf(flag);
+  return idx >= 0 ? args[idx + 1] : undefined;
+}
+
+async function main() {
+  const args = process.argv.slice(2);
+  const distance = argValue(args, "--distance");
+
+  const privateKey = process.env.PRIVATE_KEY;
+  const rpcUrl = process.env.RPC_URL;
+  if (!privateKey || !rpcUrl) {
+    logger.error(`Missing PRIVATE_KEY or RPC_URL in .env`);
+    process.exit(1);
+  }
+
+  logger.info(`🔐 Authenticating...`);
+  const auth = await createClobClient(privateKey, rpcUrl);
+  if (!auth.success || !auth.client) {
+    logger.error(`Authentication failed: ${auth.error}`);
+    process.exit(1);
+  }
+
+  logger.info(`🧪 Placing test order...`);
+  const result = await placeTestTrade(auth.client, {
+    tokenId: argValue(args, "--token"),
+    distancePct: distance !== undefined ? Number(distance) : undefined,
+    keepOrder: args.includes("--keep"),
+  });
+
+  if (result.tokenId) logger.info(`   Token: ${result.tokenId}`);
+  if (result.orderId) {
+    logger.info(
+      `   Order: BUY ${result.size} @ ${((result.price ?? 0) * 100).toFixed(1)}¢ (orderId=${result.orderId})`,
+    );
+    logger.info(`   Canceled: ${result.canceled ? "yes" : "no"}`);
+  }
+
+  if (result.success) {
+    logger.info(`✅ Trade permission confirmed`);
+    process.exit(0);
+  }
+  logger.error(`Test trade failed: ${result.error}`);
+  process.exit(1);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
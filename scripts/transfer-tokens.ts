@@ -0,0 +1,143 @@
+/**
+ * Transfer Tokens Script - Move outcome tokens between EOA and proxy
+ *
+ * Usage:
+ *   npm run transfer-tokens -- --token <id> --from eoa --to proxy --amount all
+ *   npm run transfer-tokens -- --token <id> --from proxy --to eoa --amount 25
+ *   npm run transfer-tokens -- --token <id> --from eoa --to 0xabc --amount 10
+ *
+ * For positions opened under the wrong signature type: shares held by the
+ * wallet the bot isn't trading from can be moved over.
+ * The proxy is POLYMARKET_PROXY_ADDRESS (or --proxy <address>).
+ *
+ * The plan is printed and must be confirmed by typing "yes" (--yes skips the
+ * prompt). Only PRIVATE_KEY and RPC_URL are needed - no CLOB login.
+ */
+
+import "../src/config/load-env";
+import * as readline from "readline";
+import { JsonRpcProvider, Wallet } from "ethers";
+import {
+  executeTokenTransfer,
+  getTokenBalanceBase,
+  planTokenTransfer,
+  type TransferSource,
+} from "../src/lib";
+
+const logger = {
+  info: (message: string) => console.log(message),
+  warn: (message: string) => console.warn("⚠️", message),
+  error: (message: string) => console.error("❌", message),
+};
+
+function argValue(args: string[], flag: string): string | undefined {
+  const idx = args.indexOf(flag);
+  return idx >= 0 ? args[idx + 1] : undefined;
+}
+
+function confirm(question: string): Promise<boolean> {
+  const rl = readline.createInterface({
+    input: process.stdin,
+    output: process.stdout,
+  });
+  return new Promise((resolve) => {
+    rl.question(question, (answer) => {
+      rl.close();
+      resolve(answer.trim().toLowerCase() === "yes");
+    });
+  });
+}
+
+async function main() {
+  const args = process.argv.slice(2);
+  const tokenId = argValue(args, "--token");
+  const from = argValue(args, "--from")?.toUpperCase();
+  const toArg = argValue(args, "--to");
+  const amountArg = argValue(args, "--amount");
+  if (
+    !tokenId ||
+    !toArg ||
+    !amountArg ||
+    (from !== "EOA" && from !== "PROXY")
+  ) {
+    logger.error(
+      `Usage: --token <id> --from eoa|proxy --to eoa|proxy|<address> --amount <shares>|all`,
+    );
+    process.exit(1);
+  }
+
+  const privateKey = process.env.PRIVATE_KEY;
+  const rpcUrl = process.env.RPC_URL;
+  if (!privateKey || !rpcUrl) {
+    logger.error(`Missing PRIVATE_KEY or RPC_URL in .env`);
+    process.exit(1);
+  }
+  const wallet = new Wallet(
+    privateKey.startsWith("0x") ? privateKey : `0x${privateKey}`,
+    new JsonRpcProvider(rpcUrl),
+  );
+  const proxy =
+    argValue(args, "--proxy") ??
+    process.env.POLYMARKET_PROXY_ADDRESS ??
+    process.env.CLOB_FUNDER_ADDRESS;
+
+  const to =
+    toArg.toLowerCase() === "eoa"
+      ? wallet.address
+      : toArg.toLowerCase() === "proxy"
+        ? proxy
+        : toArg;
+  if (!to) {
+    logger.error(`--to proxy needs POLYMARKET_PROXY_ADDRESS or --proxy`);
+    process.exit(1);
+  }
+
+  const source = from as TransferSource;
+  const fromAddress = source === "EOA" ? wallet.address : proxy;
+  const held = fromAddress
+    ? await getTokenBalanceBase(wallet, fromAddress, tokenId)
+    : 0n;
+
+  const planned = planTokenTransfer(
+    {
+      tokenId,
+      from: source,
+      to,
+      shares: amountArg.toLowerCase() === "all" ? "ALL" : Number(amountArg),
+    },
+    { eoa: wallet.address, proxy },
+    held,
+  );
+  if ("error" in planned) {
+    logger.error(planned.error);
+    process.exit(1);
+  }
+  const { plan } = planned;
+
+  logger.info(`📦 Outcome-token transfer`);
+  logger.info(`   Token:  ${plan.tokenId}`);
+  logger.info(`   From:   ${plan.from} ${plan.fromAddress}`);
+  logger.info(`   To:     ${plan.to}`);
+  logger.info(
+    `   Shares: ${plan.shares.toFixed(6)} of ${plan.heldShares.toFixed(6)} held`,
+  );
+  logger.info(``);
+
+  if (!args.includes("--yes") && !(await confirm(`Type "yes" to send: `))) {
+    logger.warn(`Cancelled - nothing sent`);
+    process.exit(1);
+  }
+
+  const result = await executeTokenTransfer(plan, wallet);
+  if (!result.success) {
+    logger.error(`Transfer failed: ${result.error}`);
+    process.exit(1);
+  }
+  logger.info(`✅ Transferred (tx ${result.txHash})`);
+  process.exit(0);
+}
+
+main().catch((error) => {
+  console.error("Fatal error:", error);
+  process.exit(1);
+});
@@ -67,6 +67,9 @@ export interface MarketSnapshot {
     | "RECOVERY";
   /** Timestamp when this snapshot was fetched (Unix ms) */
   readonly fetchedAtMs: number;
+  /** Timestamp of the underlying book data (Unix ms); older than
+   * fetchedAtMs when the snapshot came from the WS cache */
+  readonly bookUpdatedAtMs: number;
   /** Unique attempt ID for correlation logging */
   readonly attemptId: string;
   /** Human-readable reason if book is unhealthy */
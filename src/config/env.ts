@@ -6,18 +6,49 @@
  * behavior across the application.
  */
 
+/** Plain decimal: "12", "-0.5", ".25", "1e-3" */
+const DECIMAL_RE = /^[+-]?(\d+(\.\d*)?|\.\d+)(e[+-]?\d+)?$/i;
+
+/**
+ * Strictly parse a decimal string
+ *
+ * Unlike parseFloat, trailing text ("10 USDC", "5min") and locale commas
+ * ("0,5" - parseFloat reads 0) are rejected instead of silently truncated.
+ *
+ * @param value - Raw string
+ * @returns Parsed number, or null if not a plain decimal
+ */
+export function parseDecimal(value: string): number | null {
+  const trimmed = value.trim();
+  if (!DECIMAL_RE.test(trimmed)) return null;
+  const parsed = Number(trimmed);
+  return Number.isFinite(parsed) ? parsed : null;
+}
+
 /**
  * Parse a numeric environment variable with a default value
  *
+ * Values that aren't plain decimals are rejected with a warning rather
+ * than truncated (see parseDecimal).
+ *
  * @param key - Environment variable name
  * @param defaultValue - Default value if not set or invalid
  * @returns Parsed number or default
  */
 export function envNum(key: string, defaultValue: number): number {
   const value = process.env[key];
-  if (value === undefined) return defaultValue;
-  const parsed = parseFloat(value);
-  return isNaN(parsed) ? defaultValue : parsed;
+  if (value === undefined || value.trim() === "") return defaultValue;
+  const parsed = parseDecimal(value);
+  if (parsed === null) {
+    const hint = /^\s*[+-]?\d*,\d+\s*$/.test(value)
+      ? ` (use "." as the decimal separator)`
+      : "";
+    console.warn(
+      `Invalid number for ${key}: "${value}"${hint}. Using default: ${defaultValue}`,
+    );
+    return defaultValue;
+  }
+  return parsed;
 }
 
 /**
@@ -83,8 +114,8 @@ export function parseOptionalFloatWithDefault(
   // If env var is explicitly set to empty string, disable (return undefined)
   if (value === "") return undefined;
 
-  const parsed = parseFloat(value);
-  if (isNaN(parsed)) return defaultValue;
+  const parsed = parseDecimal(value);
+  if (parsed === null) return defaultValue;
 
   // Warn if value seems like a percentage (outside [0,1] range)
   if (parsed < 0 || parsed > 1) {
@@ -4,6 +4,8 @@
  * This module provides a clean API for configuration management:
  *
  * - env.ts: Environment variable parsing helpers
+ * - known-env.ts: Known setting names (typo detection)
+ * - profile.ts: Named environment files selected with --profile
  * - schema.ts: Configuration type definitions
  *
  * Usage:
@@ -13,6 +15,7 @@
 
 // Environment variable parsing helpers
 export {
+  parseDecimal,
   envNum,
   envBool,
   envStr,
@@ -22,6 +25,24 @@ export {
   parseOptionalFloatWithDefault,
 } from "./env";
 
+// Typo detection for environment settings
+export {
+  KNOWN_ENV_KEYS,
+  findEnvTypos,
+  suggestEnvKey,
+  type EnvTypo,
+} from "./known-env";
+
+// Named environment files (.env.<profile>)
+export {
+  PROFILE_ENV_KEY,
+  loadEnv,
+  parseProfileArg,
+  profileEnvPath,
+  withoutProfileArg,
+  type LoadedEnv,
+} from "./profile";
+
 // Configuration schema types
 export type {
   // Top-level config
@@ -0,0 +1,210 @@
+/**
+ * Known Environment Settings - Typo detection for env configuration
+ *
+ * A misspelled setting (MAXTRADEUSD, MIN_ENTRY_PRICE_CENT) is silently
+ * ignored and the default is used - which for sizing or price bounds can be
+ * dangerous. Every setting the bot reads is listed here; at startup, any
+ * environment variable that is close to a known setting but isn't one is
+ * reported as a config error with a suggestion:
+ *
+ *   MAXTRADEUSD - Unknown setting - did you mean MAX_TRADE_USD?
+ *
+ * Only near-misses are flagged, so unrelated variables (PATH, HOME, ...)
+ * are never reported. Add new settings to KNOWN_ENV_KEYS.
+ */
+
+// ═══════════════════════════════════════════════════════════════════════════
+// KNOWN SETTINGS
+// ═══════════════════════════════════════════════════════════════════════════
+
+// prettier-ignore
+export const KNOWN_ENV_KEYS: ReadonlySet<string> = new Set([
+  "ACK_CHECK_ENABLED", "ACK_CHECK_INTERVAL_MS", "ACK_CHECK_TIMEOUT_MS",
+  "ADDRESS_LABELS", "ALLOCATION_REBALANCE", "ALLOW_CREDS_EXPORT",
+  "API_CREDS_CACHE_PATH",
+  "ARB_LIVE_TRADING", "ARB_MAX_POSITION_USD", "AUTH_FALLBACK_ENABLED",
+  "AUTH_STATE_FILE_PATH", "BALANCE_REFRESH_INTERVAL_MS",
+  "BALANCE_WATCH_ENABLED", "BALANCE_WATCH_GRACE_SECONDS",
+  "BALANCE_WATCH_TOLERANCE_USD",
+  "BIAS_MIN_NET_USD", "BIAS_MIN_TRADES",
+  "BIAS_STALE_SECONDS", "BLACKLIST_DISPUTED", "BLACKLIST_FAILED_FILLS",
+  "BLACKLIST_FAILED_FILLS_WINDOW_MINUTES", "BLACKLIST_RESOLVED_LOSS_USD",
+  "BLACKLIST_SCOPE", "BOOK_HISTORY_PATH", "BOT_PROFILE",
+  "BUY_DEFAULT_SLIPPAGE_PCT",
+  "BUY_GTC_EXPIRATION_SECONDS", "BUY_MAX_SLIPPAGE_PCT", "BUY_ORDER_TYPE",
+  "CACHE_MAX_ENTRIES", "CHASE_BLOCK_SECONDS", "CHASE_MOVE_PCT",
+  "CHASE_WINDOW_SECONDS", "CI", "CLOB_FUNDER_ADDRESS", "CLOB_SIGNATURE_TYPE",
+  "COMPOUND_SCHEDULE", "COMPOUND_STATE_PATH", "COPY_ADDRESSES",
+  "COPY_ANY_WHALE_BUY", "COPY_DELAY_MS",
+  "COPY_MAX_DRIFT_CENTS", "COPY_SKIP_IF_MOVED_CENTS", "COPY_TARGET_RULES",
+  "CORRELATION_GROUPS", "CORRELATION_LAG_RATIO", "CORRELATION_MOVE_CENTS",
+  "CORRELATION_WINDOW_MS", "DEBUG", "DEPENDENCY_COOLDOWN_MS",
+  "DEPENDENCY_FAILURE_THRESHOLD", "DIAGNOSTIC_POST_ACTION",
+  "DIAG_BAD_BOOK_COOLDOWN_SEC", "DIAG_BOOK_MAX_ASK", "DIAG_BOOK_MAX_SPREAD",
+  "DIAG_DEAD_BOOK_ASK", "DIAG_DEAD_BOOK_BID", "DIAG_EXIT", "DIAG_FORCE_SHARES",
+  "DIAG_HEDGE_SIMULATE", "DIAG_HOLD_SECONDS", "DIAG_MAX_CANDIDATE_ATTEMPTS",
+  "DIAG_MAX_PRICE", "DIAG_MODE", "DIAG_ORDER_TIMEOUT_SEC",
+  "DIAG_WHALE_TIMEOUT_SEC", "DUST_CLEAN_INTERVAL_MIN", "DUST_MAX_USD",
+  "DYNAMIC_EV_ENABLED", "DYNAMIC_HEDGE_ENABLED",
+  "DYNAMIC_RESERVES_ENABLED", "ENTRY_COOLDOWN_SECONDS_TRANSIENT",
+  "EVENT_FILE_FILTER", "EVENT_FILE_PATH", "EVENT_HEARTBEAT_SECONDS",
+  "EVENT_SOCKET", "EVENT_SOCKET_FILTER", "EVENT_STDOUT", "EVENT_STDOUT_FILTER",
+  "EVENT_WEBHOOK_FILTER", "EVENT_WEBHOOK_URL", "EXPECTED_ADDRESS",
+  "FEED_CHECK_INTERVAL_SEC", "FEED_CHECK_SAMPLE", "FEED_DIVERGENCE_CENTS",
+  "FILL_JOURNAL_PATH", "FORCE_LIQUIDATION", "GAS_BUDGET_DAILY_POL",
+  "GITHUB_ACTIONS", "GITHUB_ERROR_REPORTER_ENABLED",
+  "GITHUB_ERROR_REPORTER_REPO", "GITHUB_ERROR_REPORTER_TOKEN",
+  "GITHUB_STEP_SUMMARY", "HEDGE_COVERAGE_WEIGHT", "HEDGE_TARGETS",
+  "HEDGE_TARGETS_PATH", "HEDGE_TARGET_MIN_USD", "IMBALANCE_TRIGGERS",
+  "IMPORT_API_CREDS", "INFURA_TIER", "JOB_SCHEDULE", "JOB_SCHEDULE_PATH",
+  "LATENCY_WINDOW_MINUTES", "LEADERBOARD_LIMIT",
+  "LIQUIDATION_MAX_SLIPPAGE_PCT", "LIQUIDATION_MODE",
+  "LIQUIDATION_POLL_INTERVAL_MS", "LIVE_TRADING", "LOG_LEVEL",
+  "MARKETDATA_BOOK_HISTORY_PER_TOKEN", "MARKETDATA_DEPTH_WINDOW_CENTS",
+  "MARKETDATA_MAX_TOKENS", "MARKETDATA_MAX_TRADES_PER_TOKEN",
+  "MARKET_ALLOW_IDS", "MARKET_ALLOW_TAGS", "MARKET_BLACKLIST_PATH",
+  "MARKET_DENY_IDS", "MARKET_DENY_TAGS", "MARKET_SEARCH_PATH",
+  "MARKET_SEARCH_REFRESH_MIN", "MAX_BUY_PRICE",
+  "MAX_ENTRY_PRICE_CENTS", "MAX_ORDERS_PER_MARKET_PER_HOUR",
+  "MAX_POSITION_USD", "MAX_RESERVE_FRACTION", "MAX_TRADE_BOOK_AGE_MS",
+  "MAX_TRADE_USD", "MEMPOOL_GAS_MULTIPLIER",
+  "MEMPOOL_MONITOR_ENABLED", "MIN_DEPTH_USD_AT_EXIT", "MIN_ENTRY_PRICE_CENTS",
+  "MIN_POL_RESERVE", "MIN_SELL_PRICE", "MIN_SPREAD_CENTS", "MIN_TRADE_USD",
+  "MISSED_OPPORTUNITY_WEIGHT", "MONITOR_ADDRESSES", "NODE_ENV",
+  "ONCHAIN_MAX_RECONNECT_ATTEMPTS", "ONCHAIN_MIN_WHALE_TRADE_USD",
+  "ONCHAIN_MONITOR_ENABLED", "ONCHAIN_RECONNECT_DELAY_MS", "OPENVPN_AUTH_PATH",
+  "OPENVPN_CONFIG", "OPENVPN_CONFIG_PATH", "OPENVPN_ENABLED",
+  "OPENVPN_EXTRA_ARGS", "OPENVPN_PASSWORD", "OPENVPN_USERNAME",
+  "ORDER_GOVERNOR_WINDOW_MINUTES", "ORDER_MAX_PRICE", "ORDER_MIN_PRICE",
+  "ORDER_RACE_MODE", "ORDER_RACE_PRIORITY", "ORDER_RACE_WINDOW_SECONDS",
+  "ORDER_SWEEP_INTERVAL_SEC", "ORDER_SWEEP_MAX_AGE_MIN",
+  "ORDER_SWEEP_MAX_SPREAD_CENTS", "ORDER_TYPE",
+  "OVERROUND_ALERT_PCT", "OVERROUND_ARB_EDGE_PCT",
+  "OVERROUND_CHECK_INTERVAL_SEC", "OVERROUND_EVENT_IDS",
+  "OVPN_CONFIG", "POLYMARKET_PROXY_ADDRESS", "POLYMARKET_SIGNATURE_TYPE",
+  "POLY_API_KEY", "POLY_API_SECRET", "POLY_PASSPHRASE", "POLY_WS_BASE_URL",
+  "POLY_WS_HOST", "POL_RESERVE_CHECK_INTERVAL_MIN", "POL_RESERVE_ENABLED",
+  "POL_RESERVE_MAX_SWAP_USD", "POL_RESERVE_MIN", "POL_RESERVE_SLIPPAGE_PCT",
+  "POL_RESERVE_TARGET", "PREFERRED_ENTRY_HIGH_CENTS",
+  "PREFERRED_ENTRY_LOW_CENTS", "PRESET", "PRICE_ALERTS", "PRICE_BAND_MIN_CENTS",
+  "PRICE_BAND_PCT", "PRICE_BAND_WINDOW_MS", "PRIVATE_KEY",
+  "PRIVATE_TX_DEFAULT", "PRIVATE_TX_RPC_URL", "QUOTE_MARKOUT_SECONDS",
+  "READ_MAX_RETRIES",
+  "READ_RETRY_BASE_MS", "READ_RETRY_MAX_MS", "READ_TIMEOUT_MS",
+  "REQUEST_CAPTURE_SIZE", "RESERVE_ADAPTATION_RATE",
+  "REST_FALLBACK_MIN_INTERVAL_MS", "RPC_URL", "SCANNER_DEDUP_WINDOW_SECONDS",
+  "SCANNER_ENABLED", "SCANNER_ENTRY_BAND_CENTS",
+  "SCANNER_LEADERBOARD_BOOST_ENABLED", "SCANNER_LEADERBOARD_WINDOW_SECONDS",
+  "SCANNER_MAX_SPREAD_CENTS", "SCANNER_MIN_DEPTH_USD",
+  "SCANNER_MIN_LEADERBOARD_TRADES", "SCANNER_PREFERRED_ENTRY_HIGH_CENTS",
+  "SCANNER_PREFERRED_ENTRY_LOW_CENTS", "SCANNER_WINDOW_SECONDS",
+  "SCAN_ACTIVE_MARKETS", "SCAN_CONCURRENCY", "SCAN_INTERVAL_SECONDS",
+  "SCAN_MIN_VOLUME_USD", "SCAN_TOP_N_MARKETS", "SELL_DEFAULT_SLIPPAGE_PCT",
+  "SELL_DEPTH_LEVELS", "SELL_GTC_EXPIRATION_SECONDS",
+  "SELL_HIGH_PRICE_SLIPPAGE_PCT", "SELL_HIGH_PRICE_THRESHOLD",
+  "SELL_LOSS_SLIPPAGE_PCT", "SELL_LOSS_THRESHOLD_PCT", "SELL_MAX_SLIPPAGE_PCT",
+  "SELL_MIN_FILL_RATIO", "SELL_MIN_LIQUIDITY_USD", "SELL_MIN_SLIPPAGE_PCT",
+  "SELL_ORDER_TYPE",
+  "SHADOW_STRATEGIES", "SIGNAL_QUEUE_MAX", "SIGNAL_QUEUE_PATH",
+  "SIGNAL_QUEUE_TTL_SEC", "SLO_SUSTAIN_SECONDS", "SLO_WRITE_P95_MS",
+  "STALE_MARKET_DEAD_HOURS", "STALE_MARKET_QUIET_MINUTES",
+  "STATE_FILE_PATH", "STRATEGY_BLACKOUTS", "STRATEGY_LIQUIDITY_FLOOR",
+  "STRATEGY_LOT_POLICY", "STRATEGY_PRESET", "STRATEGY_WEIGHTS",
+  "STRATEGY_WINDOWS_SCAN", "STRATEGY_WINDOWS_WHALE", "SUPPORT_BUNDLE_DIR",
+  "TAG_SYNC_INTERVAL_MIN",
+  "TARGET_ADDRESSES", "TELEGRAM_BOT_TOKEN", "TELEGRAM_CHAT", "TELEGRAM_CHAT_ID",
+  "TELEGRAM_SILENT", "TELEGRAM_TOKEN", "USDC_NATIVE_AUTO_SWAP",
+  "USDC_NATIVE_MIN_SWAP_USD",
+  "USDC_SWAP_ROUTER", "USDC_SWAP_SLIPPAGE_PCT", "VOL_SIZING_ENABLED",
+  "VOL_SIZING_WINDOW_MS", "VOL_TARGET_CENTS", "VPN_BYPASS_POLYMARKET_READS",
+  "VPN_BYPASS_POLYMARKET_WS", "VPN_BYPASS_RPC", "WG_CONFIG",
+  "WHALE_AUTO_PROMOTE", "WHALE_PRICE_MAX", "WHALE_PRICE_MIN",
+  "WHALE_PROMOTE_MAX", "WHALE_PROMOTE_MIN_HIT_RATE",
+  "WHALE_PROMOTE_MIN_TRADES", "WHALE_TRADE_USD", "WIREGUARD_ADDRESS",
+  "WIREGUARD_ALLOWED_IPS", "WIREGUARD_CONFIG", "WIREGUARD_DNS",
+  "WIREGUARD_ENABLED", "WIREGUARD_FORCE_RESTART", "WIREGUARD_INTERFACE_NAME",
+  "WIREGUARD_MTU", "WIREGUARD_PEER_ENDPOINT", "WIREGUARD_PEER_PRESHARED_KEY",
+  "WIREGUARD_PEER_PUBLIC_KEY", "WIREGUARD_PERSISTENT_KEEPALIVE",
+  "WIREGUARD_PRIVATE_KEY", "WS_CONNECTION_TIMEOUT_MS",
+  "WS_PING_INTERVAL_MS", "WS_PONG_TIMEOUT_MS", "WS_RECONNECT_BASE_MS",
+  "WS_RECONNECT_MAX_MS", "WS_RPC_URL", "WS_STABLE_CONNECTION_MS",
+  "WS_STALE_MS",
+]);
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPO DETECTION
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface EnvTypo {
+  key: string;
+  suggestion: string;
+}
+
+/** Case and separators don't matter: "max-trade-usd" ~ "MAX_TRADE_USD" */
+function normalizeKey(key: string): string {
+  return key.toUpperCase().replace(/[^A-Z0-9]/g, "");
+}
+
+/**
+ * Levenshtein distance, giving up once it exceeds max
+ */
+export function editDistance(a: string, b: string, max = Infinity): number {
+  if (Math.abs(a.length - b.length) > max) return max + 1;
+  let prev = Array.from({ length: b.length + 1 }, (_, j) => j);
+  for (let i = 1; i <= a.length; i++) {
+    const cur = [i];
+    let rowMin = i;
+    for (let j = 1; j <= b.length; j++) {
+      const cost = a[i - 1] === b[j - 1] ? 0 : 1;
+      cur[j] = Math.min(prev[j] + 1, cur[j - 1] + 1, prev[j - 1] + cost);
+      rowMin = Math.min(rowMin, cur[j]);
+    }
+    if (rowMin > max) return max + 1;
+    prev = cur;
+  }
+  return prev[b.length];
+}
+
+/**
+ * Closest known setting to a key, or null if none is close enough to be a
+ * plausible typo (1 edit for short names, 2 for names of 10+ characters)
+ */
+export function suggestEnvKey(
+  key: string,
+  known: ReadonlySet<string> = KNOWN_ENV_KEYS,
+): string | null {
+  const normalized = normalizeKey(key);
+  if (normalized.length < 6) return null;
+  const maxEdits = normalized.length >= 10 ? 2 : 1;
+
+  let best: string | null = null;
+  let bestDistance = maxEdits + 1;
+  for (const candidate of known) {
+    const distance = editDistance(
+      normalized,
+      normalizeKey(candidate),
+      maxEdits,
+    );
+    if (distance < bestDistance) {
+      best = candidate;
+      bestDistance = distance;
+    }
+  }
+  return best;
+}
+
+/**
+ * Environment variables that look like misspelled settings
+ */
+export function findEnvTypos(
+  env: Record<string, string | undefined>,
+  known: ReadonlySet<string> = KNOWN_ENV_KEYS,
+): EnvTypo[] {
+  const typos: EnvTypo[] = [];
+  for (const key of Object.keys(env)) {
+    if (known.has(key)) continue;
+    const suggestion = suggestEnvKey(key, known);
+    if (suggestion) typos.push({ key, suggestion });
+  }
+  return typos;
+}
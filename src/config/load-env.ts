@@ -0,0 +1,18 @@
+/**
+ * Load Env - Side-effect import in place of "dotenv/config"
+ *
+ * Loads `.env`, overlaid with the profile selected by `--profile` or
+ * BOT_PROFILE (see profile.ts). Exits when the profile can't be loaded.
+ *
+ *   import "./config/load-env";
+ */
+
+import { loadEnv } from "./profile";
+
+try {
+  const { profile, files } = loadEnv();
+  if (profile) console.log(`📁 Profile: ${profile} (${files[0]})`);
+} catch (err) {
+  console.error(`❌ ${err instanceof Error ? err.message : String(err)}`);
+  process.exit(1);
+}
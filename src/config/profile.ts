@@ -0,0 +1,118 @@
+/**
+ * Config Profiles - Named environment files (prod, paper, ...)
+ *
+ * A profile is a `.env.<name>` file next to `.env`, selected with
+ * `--profile <name>` or BOT_PROFILE=<name>. Its settings take precedence
+ * over `.env`, and the shell environment over both, so a profile only
+ * needs what differs between setups - endpoints (RPC_URL), key
+ * (PRIVATE_KEY, EXPECTED_ADDRESS), risk limits (MAX_TRADE_USD, ...) and
+ * journal path (FILL_JOURNAL_PATH):
+ *
+ *   .env.paper   PRIVATE_KEY=...  (no LIVE_TRADING - simulated orders)
+ *                FILL_JOURNAL_PATH=./data/paper-fills.jsonl
+ *   .env.prod    LIVE_TRADING=I_UNDERSTAND_THE_RISKS
+ *                FILL_JOURNAL_PATH=./data/fills.jsonl
+ *
+ *   npm run dev -- --profile paper
+ *   BOT_PROFILE=prod npm start
+ *
+ * A profile that was asked for but has no file is an error rather than
+ * a silent fall back to `.env` - that would trade the wrong setup.
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import * as dotenv from "dotenv";
+
+export const PROFILE_ENV_KEY = "BOT_PROFILE";
+
+/** Profile names become file names - keep them plain */
+const PROFILE_NAME_RE = /^[A-Za-z0-9_-]+$/;
+
+export interface LoadedEnv {
+  /** Selected profile, undefined when running on `.env` alone */
+  profile?: string;
+  /** Files read, highest precedence first */
+  files: string[];
+}
+
+/**
+ * Profile named on the command line (`--profile x` or `--profile=x`)
+ */
+export function parseProfileArg(argv: string[]): string | undefined {
+  for (let i = 0; i < argv.length; i++) {
+    const arg = argv[i];
+    if (arg === "--profile") return argv[i + 1] ?? "";
+    if (arg.startsWith("--profile=")) return arg.slice("--profile=".length);
+  }
+  return undefined;
+}
+
+/**
+ * Arguments with `--profile` removed, for scripts with positional ones
+ */
+export function withoutProfileArg(argv: string[]): string[] {
+  const rest: string[] = [];
+  for (let i = 0; i < argv.length; i++) {
+    if (argv[i] === "--profile") i++;
+    else if (!argv[i].startsWith("--profile=")) rest.push(argv[i]);
+  }
+  return rest;
+}
+
+/**
+ * Path of a profile's environment file
+ */
+export function profileEnvPath(
+  profile: string,
+  dir: string = process.cwd(),
+): string {
+  return path.join(dir, `.env.${profile}`);
+}
+
+/**
+ * Load the selected profile's file, then `.env`, into the environment
+ *
+ * Variables already set (shell, container) are never overwritten.
+ *
+ * @throws If the profile name is invalid or its file doesn't exist
+ */
+export function loadEnv(
+  options: {
+    argv?: string[];
+    env?: NodeJS.ProcessEnv;
+    dir?: string;
+  } = {},
+): LoadedEnv {
+  const env = options.env ?? process.env;
+  const dir = options.dir ?? process.cwd();
+  const profile =
+    parseProfileArg(options.argv ?? process.argv.slice(2)) ??
+    (env[PROFILE_ENV_KEY]?.trim() || undefined);
+
+  const files: string[] = [];
+  if (profile !== undefined) {
+    if (!PROFILE_NAME_RE.test(profile)) {
+      throw new Error(
+        `Invalid profile "${profile}" - use letters, digits, "-" or "_"`,
+      );
+    }
+    const profilePath = profileEnvPath(profile, dir);
+    if (!fs.existsSync(profilePath)) {
+      throw new Error(`Profile "${profile}" not found: ${profilePath}`);
+    }
+    files.push(profilePath);
+    // Child processes and later reads see which profile is active
+    env[PROFILE_ENV_KEY] = profile;
+  }
+  const basePath = path.join(dir, ".env");
+  if (fs.existsSync(basePath)) files.push(basePath);
+
+  for (const file of files) {
+    const parsed = dotenv.parse(fs.readFileSync(file));
+    for (const [key, value] of Object.entries(parsed)) {
+      if (env[key] === undefined) env[key] = value;
+    }
+  }
+  return { profile, files };
+}
@@ -0,0 +1,271 @@
+/**
+ * Balance Watcher - Alert when balances move without a journaled trade
+ *
+ * The bot holds the wallet's keys, so a balance that changes on its own is
+ * worth knowing about immediately: funds pulled by someone else, or a fill
+ * the bot never saw. Everything the bot does that moves money is journaled
+ * here (user-channel fills, live entries/exits, redemptions, gas swaps).
+ * USDC and position balances are observed as they are refreshed, and a
+ * change is flagged BALANCE_ANOMALY when no journaled activity explains it:
+ *
+ *   BALANCE_WATCH_ENABLED=true
+ *   BALANCE_WATCH_TOLERANCE_USD=1     # ignore USDC moves up to $1
+ *   BALANCE_WATCH_GRACE_SECONDS=120   # time for the journal to catch up
+ *
+ * A change is held for the grace period before alerting, since balances and
+ * fill notifications arrive on different feeds. Deposits and withdrawals
+ * made by hand alert too - that's the point of the tripwire.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface BalanceWatcherConfig {
+  enabled: boolean;
+  /** USDC changes up to this are ignored (USD) */
+  toleranceUsd: number;
+  /** Position changes up to this are ignored (shares) */
+  toleranceShares: number;
+  /** How long a change may wait for a matching journal entry (ms) */
+  graceMs: number;
+}
+
+export const DEFAULT_BALANCE_WATCHER_CONFIG: BalanceWatcherConfig = {
+  enabled: true,
+  toleranceUsd: 1,
+  toleranceShares: 0.5,
+  graceMs: 120_000,
+};
+
+export type JournalKind = "FILL" | "ENTRY" | "EXIT" | "REDEEM" | "SWAP";
+
+export interface JournalEntry {
+  kind: JournalKind;
+  /** Dedup key (e.g. trade ID) - entries seen before are ignored */
+  id?: string;
+  /** Token whose position moved (omitted = may touch any position) */
+  tokenId?: string;
+  /** When the activity happened (ms) */
+  at?: number;
+}
+
+/** Asset key for the USDC balance; positions are keyed by token ID */
+export const USDC_ASSET = "USDC";
+
+export interface BalanceAnomalyEvent {
+  event: "BALANCE_ANOMALY";
+  asset: string;
+  previous: number;
+  current: number;
+  delta: number;
+  /** When the change was first observed */
+  observedAt: string;
+  timestamp: string;
+}
+
+interface Observation {
+  value: number;
+  at: number;
+}
+
+interface PendingChange {
+  asset: string;
+  previous: number;
+  current: number;
+  /** Previous observation time - the change happened after this */
+  since: number;
+  observedAt: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BALANCE WATCHER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class BalanceWatcher {
+  private readonly config: BalanceWatcherConfig;
+  private journalEntries: (JournalEntry & { at: number })[] = [];
+  private seenIds = new Set<string>();
+  private last = new Map<string, Observation>();
+  private lastPositionsAt: number | null = null;
+  private pending: PendingChange[] = [];
+
+  constructor(
+    config: Partial<BalanceWatcherConfig> = {},
+    private readonly maxJournal = 1000,
+  ) {
+    const d = DEFAULT_BALANCE_WATCHER_CONFIG;
+    this.config = {
+      enabled: config.enabled ?? d.enabled,
+      toleranceUsd: config.toleranceUsd ?? d.toleranceUsd,
+      toleranceShares: config.toleranceShares ?? d.toleranceShares,
+      graceMs: config.graceMs ?? d.graceMs,
+    };
+  }
+
+  isEnabled(): boolean {
+    return this.config.enabled;
+  }
+
+  /**
+   * Record activity that legitimately moves balances
+   */
+  journal(entry: JournalEntry, now = Date.now()): void {
+    if (!this.config.enabled) return;
+    if (entry.id) {
+      if (this.seenIds.has(entry.id)) return;
+      this.seenIds.add(entry.id);
+      if (this.seenIds.size > this.maxJournal) {
+        const oldest = this.seenIds.values().next().value;
+        if (oldest !== undefined) this.seenIds.delete(oldest);
+      }
+    }
+    const at = Number.isFinite(entry.at) ? entry.at! : now;
+    this.journalEntries.push({ ...entry, at });
+    if (this.journalEntries.length > this.maxJournal) {
+      this.journalEntries.shift();
+    }
+  }
+
+  /**
+   * Observe the USDC balance
+   * @returns Anomalies that came due on this observation
+   */
+  observeUsdc(balance: number, now = Date.now()): BalanceAnomalyEvent[] {
+    if (!this.config.enabled || !Number.isFinite(balance)) return [];
+    const prev = this.last.get(USDC_ASSET);
+    this.observe(USDC_ASSET, prev, balance, this.config.toleranceUsd, now);
+    return this.flush(now);
+  }
+
+  /**
+   * Observe all position sizes (shares by token ID). Tokens seen before but
+   * missing now are treated as 0 - only pass a complete, successful fetch.
+   * @returns Anomalies that came due on this observation
+   */
+  observePositions(
+    sizes: Map<string, number>,
+    now = Date.now(),
+  ): BalanceAnomalyEvent[] {
+    if (!this.config.enabled) return [];
+    const tokens = new Set(sizes.keys());
+    for (const asset of this.last.keys()) {
+      if (asset !== USDC_ASSET) tokens.add(asset);
+    }
+    for (const tokenId of tokens) {
+      const shares = sizes.get(tokenId) ?? 0;
+      // A token not held at the last sweep was at 0 then (none on the first)
+      const prev =
+        this.last.get(tokenId) ??
+        (this.lastPositionsAt !== null
+          ? { value: 0, at: this.lastPositionsAt }
+          : undefined);
+      this.observe(tokenId, prev, shares, this.config.toleranceShares, now);
+      if (shares === 0) this.last.delete(tokenId);
+    }
+    this.lastPositionsAt = now;
+    return this.flush(now);
+  }
+
+  /**
+   * Compare against the previous observation (none = this is the baseline)
+   */
+  private observe(
+    asset: string,
+    prev: Observation | undefined,
+    value: number,
+    tolerance: number,
+    now: number,
+  ): void {
+    this.last.set(asset, { value, at: now });
+    if (!prev || Math.abs(value - prev.value) <= tolerance) return;
+    this.pending.push({
+      asset,
+      previous: prev.value,
+      current: value,
+      since: prev.at,
+      observedAt: now,
+    });
+  }
+
+  /**
+   * Drop explained changes and alert on ones past the grace period
+   */
+  private flush(now: number): BalanceAnomalyEvent[] {
+    const anomalies: BalanceAnomalyEvent[] = [];
+    const graceMs = this.config.graceMs;
+
+    this.pending = this.pending.filter((change) => {
+      if (this.isExplained(change)) return false;
+      if (now - change.observedAt < graceMs) return true;
+
+      const event: BalanceAnomalyEvent = {
+        event: "BALANCE_ANOMALY",
+        asset: change.asset,
+        previous: change.previous,
+        current: change.current,
+        delta: change.current - change.previous,
+        observedAt: new Date(change.observedAt).toISOString(),
+        timestamp: new Date(now).toISOString(),
+      };
+      emitEvent(event);
+      anomalies.push(event);
+      return false;
+    });
+
+    // Keep entries that could still explain a pending or future change
+    let oldest = this.lastPositionsAt ?? now;
+    for (const obs of this.last.values()) oldest = Math.min(oldest, obs.at);
+    for (const change of this.pending) {
+      oldest = Math.min(oldest, change.since);
+    }
+    const cutoff = oldest - graceMs;
+    this.journalEntries = this.journalEntries.filter((e) => e.at >= cutoff);
+
+    return anomalies;
+  }
+
+  private isExplained(change: PendingChange): boolean {
+    const from = change.since - this.config.graceMs;
+    return this.journalEntries.some(
+      (e) =>
+        e.at >= from &&
+        (change.asset === USDC_ASSET ||
+          e.tokenId === undefined ||
+          e.tokenId === change.asset),
+    );
+  }
+
+  clear(): void {
+    this.journalEntries = [];
+    this.seenIds.clear();
+    this.last.clear();
+    this.lastPositionsAt = null;
+    this.pending = [];
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: BalanceWatcher | null = null;
+
+/**
+ * Initialize the global balance watcher
+ */
+export function initBalanceWatcher(
+  config?: Partial<BalanceWatcherConfig>,
+): BalanceWatcher {
+  instance = new BalanceWatcher(config);
+  return instance;
+}
+
+/**
+ * Get the global balance watcher (null if not initialized)
+ */
+export function getBalanceWatcher(): BalanceWatcher | null {
+  return instance;
+}
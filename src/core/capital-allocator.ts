@@ -0,0 +1,238 @@
+/**
+ * Capital Allocator - Divide capital among entry strategies
+ *
+ * Each enabled strategy ("whale", "scan") gets a share of the bot's capital
+ * (effective bankroll + deployed) by configured weight:
+ *
+ *   STRATEGY_WEIGHTS="whale:0.7,scan:0.3"
+ *
+ * When rebalancing is on, weights drift toward strategies that are making
+ * money: each strategy's weight is scaled by a performance multiplier from
+ * the average return of its recent closed trades, then re-normalized.
+ *
+ *   multiplier = clamp(1 + sensitivity * avgReturn, min, max)
+ *
+ * Allocations are enforced in ExecutionEngine.processEntry - an entry is
+ * shrunk to what its strategy has left, or rejected (ALLOCATION_EXHAUSTED)
+ * when nothing is left. Strategies without a weight get nothing.
+ */
+
+import type { EntryStrategy } from "./strategy-schedule";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface CapitalAllocatorConfig {
+  /** Strategy -> configured weight (normalized internally) */
+  weights: Partial<Record<EntryStrategy, number>>;
+  /** Adjust weights by realized performance */
+  rebalance: boolean;
+  /** Closed trades per strategy kept for performance */
+  performanceWindow: number;
+  /** Closed trades needed before performance affects the weight */
+  minTradesForRebalance: number;
+  /** How strongly average return moves the multiplier */
+  sensitivity: number;
+  minMultiplier: number;
+  maxMultiplier: number;
+}
+
+export const DEFAULT_CAPITAL_ALLOCATOR_CONFIG: CapitalAllocatorConfig = {
+  weights: {},
+  rebalance: true,
+  performanceWindow: 50,
+  minTradesForRebalance: 5,
+  sensitivity: 5,
+  minMultiplier: 0.25,
+  maxMultiplier: 2,
+};
+
+export interface StrategyWeight {
+  /** Configured weight, normalized to sum to 1 */
+  baseWeight: number;
+  /** Weight after performance rebalancing (sums to 1) */
+  weight: number;
+  /** Average return of recent closed trades (null if too few) */
+  avgReturn: number | null;
+}
+
+export interface StrategyAllocation extends StrategyWeight {
+  strategy: EntryStrategy;
+  trades: number;
+  budgetUsd: number;
+  deployedUsd: number;
+  remainingUsd: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONFIG PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "whale:0.7,scan:0.3". Non-positive or unparseable weights are
+ * dropped.
+ */
+export function parseStrategyWeights(
+  spec: string,
+): Partial<Record<EntryStrategy, number>> {
+  const weights: Partial<Record<EntryStrategy, number>> = {};
+  for (const entry of spec.split(",")) {
+    const [name, value] = entry.split(":").map((s) => s.trim());
+    const weight = Number(value);
+    if ((name === "whale" || name === "scan") && weight > 0) {
+      weights[name] = weight;
+    }
+  }
+  return weights;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CAPITAL ALLOCATOR
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class CapitalAllocator {
+  private readonly config: CapitalAllocatorConfig;
+  private returns = new Map<EntryStrategy, number[]>();
+
+  constructor(config: Partial<CapitalAllocatorConfig> = {}) {
+    const d = DEFAULT_CAPITAL_ALLOCATOR_CONFIG;
+    this.config = {
+      weights: config.weights ?? d.weights,
+      rebalance: config.rebalance ?? d.rebalance,
+      performanceWindow: config.performanceWindow ?? d.performanceWindow,
+      minTradesForRebalance:
+        config.minTradesForRebalance ?? d.minTradesForRebalance,
+      sensitivity: config.sensitivity ?? d.sensitivity,
+      minMultiplier: config.minMultiplier ?? d.minMultiplier,
+      maxMultiplier: config.maxMultiplier ?? d.maxMultiplier,
+    };
+  }
+
+  /**
+   * Whether any weights are configured (no weights = allocator disabled)
+   */
+  isEnabled(): boolean {
+    return Object.keys(this.config.weights).length > 0;
+  }
+
+  /**
+   * Record a closed trade for a strategy's performance
+   */
+  recordResult(strategy: EntryStrategy, pnlUsd: number, sizeUsd: number): void {
+    if (!(sizeUsd > 0) || !Number.isFinite(pnlUsd)) return;
+
+    const series = this.returns.get(strategy) ?? [];
+    series.push(pnlUsd / sizeUsd);
+    if (series.length > this.config.performanceWindow) series.shift();
+    this.returns.set(strategy, series);
+  }
+
+  /**
+   * Current weights (base and performance-adjusted), each summing to 1
+   */
+  getWeights(): Map<EntryStrategy, StrategyWeight> {
+    const entries = Object.entries(this.config.weights) as [
+      EntryStrategy,
+      number,
+    ][];
+    const baseTotal = entries.reduce((sum, [, w]) => sum + w, 0);
+
+    const adjusted = entries.map(([strategy, w]) => {
+      const avgReturn = this.getAvgReturn(strategy);
+      const multiplier =
+        this.config.rebalance && avgReturn !== null
+          ? Math.min(
+              this.config.maxMultiplier,
+              Math.max(
+                this.config.minMultiplier,
+                1 + this.config.sensitivity * avgReturn,
+              ),
+            )
+          : 1;
+      return { strategy, base: w / baseTotal, raw: w * multiplier, avgReturn };
+    });
+    const adjustedTotal = adjusted.reduce((sum, a) => sum + a.raw, 0);
+
+    const weights = new Map<EntryStrategy, StrategyWeight>();
+    for (const a of adjusted) {
+      weights.set(a.strategy, {
+        baseWeight: a.base,
+        weight: adjustedTotal > 0 ? a.raw / adjustedTotal : 0,
+        avgReturn: a.avgReturn,
+      });
+    }
+    return weights;
+  }
+
+  /**
+   * Budgets for every weighted strategy
+   *
+   * @param capitalUsd - Capital to divide (effective bankroll + deployed)
+   * @param deployedByStrategy - USD currently deployed per strategy
+   */
+  getAllocations(
+    capitalUsd: number,
+    deployedByStrategy: Partial<Record<EntryStrategy, number>> = {},
+  ): StrategyAllocation[] {
+    return [...this.getWeights()].map(([strategy, w]) => {
+      const budgetUsd = Math.max(0, capitalUsd) * w.weight;
+      const deployedUsd = deployedByStrategy[strategy] ?? 0;
+      return {
+        strategy,
+        ...w,
+        trades: this.returns.get(strategy)?.length ?? 0,
+        budgetUsd,
+        deployedUsd,
+        remainingUsd: Math.max(0, budgetUsd - deployedUsd),
+      };
+    });
+  }
+
+  /**
+   * USD a strategy may still deploy (Infinity when the allocator is off)
+   */
+  getRemainingUsd(
+    strategy: EntryStrategy,
+    capitalUsd: number,
+    deployedByStrategy: Partial<Record<EntryStrategy, number>> = {},
+  ): number {
+    if (!this.isEnabled()) return Infinity;
+    const allocation = this.getAllocations(
+      capitalUsd,
+      deployedByStrategy,
+    ).find((a) => a.strategy === strategy);
+    return allocation?.remainingUsd ?? 0;
+  }
+
+  private getAvgReturn(strategy: EntryStrategy): number | null {
+    const series = this.returns.get(strategy);
+    if (!series || series.length < this.config.minTradesForRebalance) {
+      return null;
+    }
+    return series.reduce((sum, r) => sum + r, 0) / series.length;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: CapitalAllocator | null = null;
+
+/**
+ * Initialize the global capital allocator
+ */
+export function initCapitalAllocator(
+  config?: Partial<CapitalAllocatorConfig>,
+): CapitalAllocator {
+  instance = new CapitalAllocator(config);
+  return instance;
+}
+
+/**
+ * Get the global capital allocator (null if not initialized)
+ */
+export function getCapitalAllocator(): CapitalAllocator | null {
+  return instance;
+}
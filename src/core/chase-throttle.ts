@@ -0,0 +1,196 @@
+/**
+ * Chase Throttle - Block entries on tokens whose price is spiking
+ *
+ * When a token moves more than movePct within the window, the snipe
+ * opportunity is usually gone - buying now means chasing the spike. New
+ * entries on that token are blocked for blockMs and a CHASE_BLOCKED event
+ * is emitted:
+ *
+ *   CHASE_MOVE_PCT=10          # 10% move...
+ *   CHASE_WINDOW_SECONDS=30    # ...within 30s
+ *   CHASE_BLOCK_SECONDS=120    # blocks entries for 2 minutes
+ *
+ * Moves are measured in both directions from the window's low/high, so a
+ * crash is treated like a spike. Prices come from the trade-print stream
+ * plus the book mid seen at each entry attempt. movePct 0 disables it.
+ */
+
+import type { MarketDataStore, TradePrint } from "../lib/market-data-store";
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface ChaseThrottleConfig {
+  /** Move that triggers a block, percent of price (0 = disabled) */
+  movePct: number;
+  /** Window over which moves are measured (ms) */
+  windowMs: number;
+  /** How long entries stay blocked after a spike (ms) */
+  blockMs: number;
+}
+
+export const DEFAULT_CHASE_THROTTLE_CONFIG: ChaseThrottleConfig = {
+  movePct: 0,
+  windowMs: 30_000,
+  blockMs: 120_000,
+};
+
+export interface ChaseBlockedEvent {
+  event: "CHASE_BLOCKED";
+  tokenId: string;
+  /** Signed move that triggered the block (percent) */
+  movePct: number;
+  fromPriceCents: number;
+  toPriceCents: number;
+  windowMs: number;
+  blockedUntil: string;
+  timestamp: string;
+}
+
+interface PriceSample {
+  priceCents: number;
+  timestamp: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHASE THROTTLE
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class ChaseThrottle {
+  private readonly config: ChaseThrottleConfig;
+  private samples = new Map<string, PriceSample[]>();
+  private blockedUntil = new Map<string, number>();
+
+  constructor(
+    config: Partial<ChaseThrottleConfig> = {},
+    private readonly maxTokens = 500,
+  ) {
+    const d = DEFAULT_CHASE_THROTTLE_CONFIG;
+    this.config = {
+      movePct: config.movePct ?? d.movePct,
+      windowMs: config.windowMs ?? d.windowMs,
+      blockMs: config.blockMs ?? d.blockMs,
+    };
+  }
+
+  isEnabled(): boolean {
+    return this.config.movePct > 0;
+  }
+
+  /**
+   * Record a price observation (decimal 0-1) and block the token if it
+   * spiked
+   * @returns The event if this observation started a block
+   */
+  record(
+    tokenId: string,
+    price: number,
+    timestamp = Date.now(),
+  ): ChaseBlockedEvent | null {
+    if (!this.isEnabled() || !(price > 0)) return null;
+
+    let series = this.samples.get(tokenId);
+    if (!series) {
+      // Memory protection - drop the oldest token at capacity
+      if (this.samples.size >= this.maxTokens) {
+        const oldest = this.samples.keys().next().value;
+        if (oldest !== undefined) this.samples.delete(oldest);
+      }
+      series = [];
+      this.samples.set(tokenId, series);
+    }
+    series.push({ priceCents: price * 100, timestamp });
+
+    const cutoff = timestamp - this.config.windowMs;
+    while (series.length > 0 && series[0].timestamp < cutoff) {
+      series.shift();
+    }
+
+    // Already blocked - a continuing spike doesn't extend or re-announce it
+    if (this.getBlockRemainingMs(tokenId, timestamp) > 0) return null;
+
+    const last = series[series.length - 1].priceCents;
+    let low = series[0];
+    let high = series[0];
+    for (const s of series) {
+      if (s.priceCents < low.priceCents) low = s;
+      if (s.priceCents > high.priceCents) high = s;
+    }
+    const upPct = ((last - low.priceCents) / low.priceCents) * 100;
+    const downPct = ((high.priceCents - last) / high.priceCents) * 100;
+
+    let from: PriceSample;
+    if (upPct > this.config.movePct && upPct >= downPct) from = low;
+    else if (downPct > this.config.movePct) from = high;
+    else return null;
+
+    const blockedUntil = timestamp + this.config.blockMs;
+    this.blockedUntil.set(tokenId, blockedUntil);
+
+    const event: ChaseBlockedEvent = {
+      event: "CHASE_BLOCKED",
+      tokenId,
+      movePct: ((last - from.priceCents) / from.priceCents) * 100,
+      fromPriceCents: from.priceCents,
+      toPriceCents: last,
+      windowMs: this.config.windowMs,
+      blockedUntil: new Date(blockedUntil).toISOString(),
+      timestamp: new Date(timestamp).toISOString(),
+    };
+    emitEvent(event);
+    return event;
+  }
+
+  /**
+   * Time left on a token's block (0 when entries are allowed)
+   */
+  getBlockRemainingMs(tokenId: string, now = Date.now()): number {
+    const until = this.blockedUntil.get(tokenId);
+    if (until === undefined) return 0;
+    if (now >= until) {
+      this.blockedUntil.delete(tokenId);
+      return 0;
+    }
+    return until - now;
+  }
+
+  /**
+   * Feed the throttle from a market data store's trade prints
+   * @returns Unsubscribe function
+   */
+  attach(store: MarketDataStore): () => void {
+    return store.onTrade((trade: TradePrint) => {
+      this.record(trade.tokenId, trade.price, trade.timestamp);
+    });
+  }
+
+  clear(): void {
+    this.samples.clear();
+    this.blockedUntil.clear();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: ChaseThrottle | null = null;
+
+/**
+ * Initialize the global chase throttle
+ */
+export function initChaseThrottle(
+  config?: Partial<ChaseThrottleConfig>,
+): ChaseThrottle {
+  instance = new ChaseThrottle(config);
+  return instance;
+}
+
+/**
+ * Get the global chase throttle (null if not initialized)
+ */
+export function getChaseThrottle(): ChaseThrottle | null {
+  return instance;
+}
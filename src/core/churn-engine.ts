@@ -15,7 +15,9 @@ import {
   initTelegram,
   sendTelegram,
   redeemAllPositions,
+  redeemPosition,
   fetchRedeemablePositions,
+  type RedeemablePosition,
   capturePreVpnRouting,
   startWireguard,
   startOpenvpn,
@@ -35,7 +37,6 @@ import {
   type Position,
   OnChainMonitor,
   createOnChainMonitorConfig,
-  getOppositeTokenId,
   fetchMarketByTokenId,
   initGitHubReporter,
   getGitHubReporter,
@@ -44,6 +45,7 @@ import {
   isTelegramEnabled,
   initMarketDataFacade,
   initMarketDataStore,
+  getMarketDataStore,
   getWebSocketMarketClient,
   initWebSocketMarketClient,
   getWebSocketUserClient,
@@ -61,8 +63,59 @@ import {
   isDeadBook,
   getBestPricesFromRaw,
   normalizeRestOrderbook,
+  gtcOrderTracker,
+  type TrackedGtcOrder,
+  getAuthDiagnostics,
+  POLYMARKET_API,
+  POLYMARKET_WS,
+  POLYGON,
+  registerAddressLabel,
+  labelAddress,
+  OrderSweeper,
+  getUsdcHoldings,
+  checkUsdcHoldings,
+  formatUsdcHoldingsWarning,
+  swapNativeToBridgedUsdc,
+  DEFAULT_USDC_SWAP_ROUTER,
+  getTagTaxonomy,
+  getMarketSearchIndex,
+  fetchEventView,
+  AuthFallback,
+  alternateAuthCombos,
+  createAuthComboProbe,
+  withAuthFallback,
+  AckChecker,
+  createClientAckLookup,
+  withAckCheck,
+  initShareCheck,
+  INSUFFICIENT_SHARES,
+  scanMarketsConcurrently,
+  formatScanSummary,
+  type ConcurrentScanOptions,
+  type ScanFilters,
+  type ScanSummary,
 } from "../lib";
 
+import {
+  saveStateFile,
+  loadStateFile,
+  getStoreRegistry,
+} from "../infra/persistence";
+import {
+  initRequestCapture,
+  writeSupportBundle,
+} from "../infra/request-capture";
+import {
+  initDependencyBreakers,
+  getDependencyBreakers,
+} from "../infra/dependency-breaker";
+import {
+  initEndpointLatency,
+  getEndpointLatency,
+} from "../infra/endpoint-latency";
+import { buildStartupEvent } from "../infra/startup-event";
+import { initEventBus, getEventBus, emitEvent } from "../infra/event-bus";
+
 // Import BookResolver for unified book handling
 import {
   initBookResolver,
@@ -77,6 +130,7 @@ import {
   type DecisionEngineConfig,
   type OrderbookState,
   type MarketActivity,
+  type ManagedPosition,
   EvTracker,
   type EvTrackerConfig,
   ExecutionEngine,
@@ -85,6 +139,90 @@ import {
   type ChurnLogger,
   PositionManager,
   DynamicReserveManager,
+  initVolatilityEstimator,
+  initOrderFlowTracker,
+  initMarketStalenessIndex,
+  getMarketStalenessIndex,
+  isDelistedError,
+  formatStalenessCounts,
+  type MarketLiveness,
+  type MarketStalenessEntry,
+  initFeedValidator,
+  getFeedValidator,
+  initCorrelationDetector,
+  parseCorrelationGroups,
+  initCapitalAllocator,
+  getCapitalAllocator,
+  parseStrategyWeights,
+  type StrategyAllocation,
+  initShadowTracker,
+  getShadowTracker,
+  parseShadowStrategies,
+  initWhaleScorer,
+  getWhaleScorer,
+  type WhaleScore,
+  initCopyGuard,
+  parseCopyTargetRules,
+  initChaseThrottle,
+  initOrderRaceArbiter,
+  parseOrderRaceMode,
+  parseOrderRacePriority,
+  formatOrderRace,
+  initOrderGovernor,
+  initMarketBlacklist,
+  getMarketBlacklist,
+  parseBlacklistScope,
+  initHedgeTargets,
+  getHedgeTargets,
+  parseHedgeTargets,
+  initJobScheduler,
+  getJobScheduler,
+  parseJobSchedule,
+  type JobScheduler,
+  initSignalQueue,
+  getSignalQueue,
+  isPositionLimitReason,
+  type QueuedSignal,
+  type EntryStrategy,
+  initProfitCompounder,
+  getProfitCompounder,
+  parseCompoundSchedule,
+  type CompoundingStatus,
+  initSignalEdgeTracker,
+  getSignalEdgeTracker,
+  initBalanceWatcher,
+  getBalanceWatcher,
+  type BalanceAnomalyEvent,
+  initOverroundMonitor,
+  getOverroundMonitor,
+  type OverroundAlertEvent,
+  parsePriceAlerts,
+  getPriceAlertManager,
+  type PriceAlert,
+  type PriceAlertEvent,
+  parseImbalanceTriggers,
+  getImbalanceTriggerManager,
+  type ImbalanceTrigger,
+  type ImbalanceTriggerEvent,
+  initFillEnricher,
+  getFillEnricher,
+  type OrderFillEvent,
+  type PriceImprovementStats,
+  getFillJournal,
+  annotateMarkouts,
+  buildQuoteReport,
+  formatQuoteReport,
+  type QuoteMarketReport,
+  getTcaJournal,
+  type TcaRecord,
+  type TcaSummary,
+  initLotPolicies,
+  parseLotPolicies,
+  initLiquidityFloors,
+  parseLiquidityFloors,
+  initDecisionLog,
+  DustCleaner,
+  type DustCleanupReport,
 } from "./index";
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -177,6 +315,7 @@ export interface ChurnConfig {
   scanMinVolumeUsd: number;
   scanTopNMarkets: number;
   scanIntervalSeconds: number;
+  scanConcurrency: number;
 
   // Dynamic Reserves
   dynamicReservesEnabled: boolean;
@@ -210,6 +349,180 @@ export interface ChurnConfig {
   // Whale Price-Range Filtering
   whalePriceMin?: number;
   whalePriceMax?: number;
+
+  // State Snapshots (empty = disabled)
+  stateFilePath?: string;
+
+  // Book history for fill replay (empty = disabled)
+  bookHistoryPath?: string;
+
+  // Support Bundles (capture size 0 = disabled)
+  requestCaptureSize?: number;
+  supportBundleDir?: string;
+
+  // Volatility Sizing
+  volSizingEnabled?: boolean;
+  volTargetCents?: number;
+  volSizingWindowMs?: number;
+
+  // Dependency Breakers (gamma-api, data-api)
+  dependencyFailureThreshold?: number;
+  dependencyCooldownMs?: number;
+
+  // Endpoint Latency SLO (write-path p95, 0 = no alerts)
+  sloWriteP95Ms?: number;
+  sloSustainSeconds?: number;
+  latencyWindowMinutes?: number;
+
+  // Ack Check (ACK_NOT_VISIBLE for accepted orders never seen)
+  ackCheckEnabled?: boolean;
+  ackCheckTimeoutMs?: number;
+  ackCheckIntervalMs?: number;
+
+  // Correlated Move Detection (empty groups = disabled)
+  correlationGroups?: string;
+  correlationMoveCents?: number;
+  correlationLagRatio?: number;
+  correlationWindowMs?: number;
+
+  // Capital Allocation (empty weights = disabled)
+  strategyWeights?: string;
+  allocationRebalance?: boolean;
+
+  // Lot Policy (empty = sizes not rounded)
+  strategyLotPolicy?: string;
+
+  // Liquidity Floor (empty = depth not checked)
+  strategyLiquidityFloor?: string;
+
+  // Dust Cleaner (0 interval = on demand only)
+  dustMaxUsd?: number;
+  dustCleanIntervalMin?: number;
+
+  // Auth Fallback (off by default)
+  authFallbackEnabled?: boolean;
+
+  // Shadow Mode (strategies paper-traded alongside live trading)
+  shadowStrategies?: string;
+
+  // Whale Scoring (auto-promotion off by default)
+  whaleAutoPromote?: boolean;
+  whalePromoteMinTrades?: number;
+  whalePromoteMinHitRate?: number;
+  whalePromoteMaxWallets?: number;
+
+  // Copy Guard (0 = check disabled; per-target rules override)
+  copyDelayMs?: number;
+  copyMaxDriftCents?: number;
+  copySkipIfMovedCents?: number;
+  copyTargetRules?: string;
+
+  // Anti-Chase Throttle (0 = disabled)
+  chaseMovePct?: number;
+  chaseWindowSeconds?: number;
+  chaseBlockSeconds?: number;
+
+  // Order Race Arbitration ("off" = whale and scan entries unarbitrated)
+  orderRaceMode?: string;
+  orderRaceWindowSeconds?: number;
+  orderRacePriority?: string;
+
+  // Quote Report (markout for adverse selection on maker fills)
+  quoteMarkoutSeconds?: number;
+
+  // Order Governor (0 = no per-market order cap)
+  maxOrdersPerMarketPerHour?: number;
+  orderGovernorWindowMinutes?: number;
+
+  // Market Blacklist (markets blacklisted after bad outcomes)
+  marketBlacklistPath?: string;
+  blacklistResolvedLossUsd?: number;
+  blacklistFailedFills?: number;
+  blacklistFailedFillsWindowMinutes?: number;
+  blacklistDisputed?: boolean;
+  blacklistScope?: string;
+
+  // Hedge Targets ("<marketId|tokenId|*>:ratio;...", empty = trigger hedging)
+  hedgeTargets?: string;
+  hedgeTargetsPath?: string;
+  hedgeTargetMinUsd?: number;
+
+  // Scheduled Jobs ("<job>@<when>;...", empty = none)
+  jobSchedule?: string;
+  jobSchedulePath?: string;
+
+  // Signal Queue (entries waiting for a free position slot, 0 = off)
+  signalQueueMax?: number;
+  signalQueueTtlSec?: number;
+  signalQueuePath?: string;
+
+  // Profit Compounding ("pnl:cap" steps, empty = off)
+  compoundSchedule?: string;
+  compoundStatePath?: string;
+
+  // Market Staleness (dead/delisted markets are skipped by scans)
+  staleMarketQuietMinutes?: number;
+  staleMarketDeadHours?: number;
+
+  // Feed Validation (WS vs REST best prices, 0 interval = disabled)
+  feedCheckIntervalSec?: number;
+  feedCheckSample?: number;
+  feedDivergenceCents?: number;
+
+  // Order Sweeper (0 interval = disabled)
+  orderSweepIntervalSec?: number;
+  orderSweepMaxAgeMin?: number;
+  orderSweepMaxSpreadCents?: number;
+
+  // Tag Taxonomy sync (0 = disabled)
+  tagSyncIntervalMin?: number;
+
+  // Market Search index refresh (0 = disabled)
+  marketSearchRefreshMin?: number;
+
+  // Native USDC detection (the exchange only accepts USDC.e)
+  usdcNativeAutoSwap?: boolean;
+  usdcNativeMinSwapUsd?: number;
+  usdcSwapRouter?: string;
+  usdcSwapSlippagePct?: number;
+
+  // Balance Watcher (alerts on unexplained balance changes)
+  balanceWatchEnabled?: boolean;
+  balanceWatchToleranceUsd?: number;
+  balanceWatchGraceSeconds?: number;
+
+  // Overround Monitor (empty event IDs = disabled)
+  overroundEventIds?: string;
+  overroundAlertPct?: number;
+  overroundArbEdgePct?: number;
+  overroundCheckIntervalSec?: number;
+
+  // Price Alerts (empty = none)
+  priceAlerts?: string;
+
+  // Imbalance Triggers (empty = none)
+  imbalanceTriggers?: string;
+
+  // Event Sinks (stdout on by default; empty file/webhook/socket = disabled)
+  eventStdout?: boolean;
+  eventStdoutFilter?: string;
+  eventFilePath?: string;
+  eventFileFilter?: string;
+  eventWebhookUrl?: string;
+  eventWebhookFilter?: string;
+  eventSocket?: string;
+  eventSocketFilter?: string;
+  /** Heartbeat event interval (0 = none) */
+  eventHeartbeatSeconds?: number;
+}
+
+/** Payload persisted by saveState() / loadState() */
+interface EngineStateSnapshot {
+  positions: ManagedPosition[];
+  gtcOrders: TrackedGtcOrder[];
+  priceAlerts?: PriceAlert[];
+  imbalanceTriggers?: ImbalanceTrigger[];
+  tca?: TcaRecord[];
 }
 
 /** Structured result from fetchTokenMarketData */
@@ -272,6 +585,8 @@ export class ChurnEngine {
   private bookResolver: BookResolver | null = null;
 
   private client: ClobClient | null = null;
+  private orderSweeper: OrderSweeper | null = null;
+  private dustCleaner: DustCleaner | null = null;
   private wallet: any = null;
   private address: string = "";
 
@@ -280,6 +595,15 @@ export class ChurnEngine {
   private lastRedeemTime = 0;
   private lastSummaryTime = 0;
   private lastPolCheckTime = 0;
+  private lastOrderSweepTime = 0;
+  private lastUsdcCheckTime = 0;
+  private lastBlacklistCheckTime = 0;
+  private lastTagSyncTime = 0;
+  private lastMarketSearchRefreshTime = 0;
+  private lastOverroundCheckTime = 0;
+  private lastFillPollTime = Date.now();
+  private lastDustCleanTime = Date.now();
+  private lastFeedCheckTime = Date.now();
   private lastScanTime = 0;
   private liquidationMode = false;
   private recentlySoldPositions = new Map<string, number>();
@@ -303,6 +627,9 @@ export class ChurnEngine {
   private marketIdInFlightRequests = new Map<string, Promise<string | null>>();
 
   private readonly REDEEM_INTERVAL_MS = 10 * 60 * 1000;
+  private readonly USDC_CHECK_INTERVAL_MS = 30 * 60 * 1000;
+  private readonly BLACKLIST_CHECK_INTERVAL_MS = 60 * 1000;
+  private readonly FILL_POLL_INTERVAL_MS = 30 * 1000;
   private readonly SUMMARY_INTERVAL_MS = 5 * 60 * 1000;
 
   // Diagnostics
@@ -426,6 +753,10 @@ export class ChurnEngine {
       minReserveUsd: this.config.minReserveUsd,
       cooldownSecondsPerToken: this.config.cooldownSecondsPerToken,
       copyAnyWhaleBuy: this.config.copyAnyWhaleBuy,
+      volSizingEnabled: this.config.volSizingEnabled,
+      volTargetCents: this.config.volTargetCents,
+      volSizingWindowMs: this.config.volSizingWindowMs,
+      hedgeTargetMinUsd: this.config.hedgeTargetMinUsd,
     };
     this.executionEngine = new ExecutionEngine(
       executionEngineConfig,
@@ -436,6 +767,102 @@ export class ChurnEngine {
       this.logger,
     );
 
+    // Fan structured events out to stdout/file/webhook/socket sinks
+    const eventBus = initEventBus({
+      stdout: this.config.eventStdout,
+      stdoutFilter: this.config.eventStdoutFilter,
+      filePath: this.config.eventFilePath,
+      fileFilter: this.config.eventFileFilter,
+      webhookUrl: this.config.eventWebhookUrl,
+      webhookFilter: this.config.eventWebhookFilter,
+      socket: this.config.eventSocket,
+      socketFilter: this.config.eventSocketFilter,
+      heartbeatMs: (this.config.eventHeartbeatSeconds ?? 0) * 1000,
+    });
+    console.log(
+      `📣 Event sinks: ${eventBus.getSinkNames().join(", ") || "none"}`,
+    );
+
+    // Capture recent API calls for support bundles
+    initRequestCapture(this.config.requestCaptureSize ?? 0);
+
+    // Fail fast on gamma-api/data-api outages instead of waiting on timeouts
+    initDependencyBreakers({
+      failureThreshold: this.config.dependencyFailureThreshold,
+      cooldownMs: this.config.dependencyCooldownMs,
+    });
+
+    // Per-endpoint latency percentiles; SLO_BREACH on slow order writes
+    const endpointLatency = initEndpointLatency({
+      writeP95Ms: this.config.sloWriteP95Ms ?? 1500,
+      sustainMs: (this.config.sloSustainSeconds ?? 120) * 1000,
+      windowMs: (this.config.latencyWindowMinutes ?? 10) * 60_000,
+    });
+    endpointLatency.onSloEvent((e) => {
+      const seconds = Math.round(e.sustainedMs / 1000);
+      const message =
+        e.event === "SLO_BREACH"
+          ? `${e.endpoint} p95 ${e.p95Ms}ms > ${e.thresholdMs}ms for ${seconds}s - check the VPN/route`
+          : `${e.endpoint} p95 back to ${e.p95Ms}ms after ${seconds}s`;
+      const breach = e.event === "SLO_BREACH";
+      console.warn(`${breach ? "🐢" : "✅"} [${e.event}] ${message}`);
+      if (isTelegramEnabled()) {
+        sendTelegram(
+          breach ? "🐢 Slow Order Path" : "✅ Order Path Recovered",
+          message,
+        ).catch(() => {});
+      }
+    });
+
+    // Split capital across entry strategies (STRATEGY_WEIGHTS)
+    initCapitalAllocator({
+      weights: parseStrategyWeights(this.config.strategyWeights ?? ""),
+      rebalance: this.config.allocationRebalance,
+    });
+
+    // Round entry sizes per strategy (STRATEGY_LOT_POLICY)
+    initLotPolicies(parseLotPolicies(this.config.strategyLotPolicy ?? ""));
+
+    // Require book depth relative to entry size (STRATEGY_LIQUIDITY_FLOOR)
+    initLiquidityFloors(
+      parseLiquidityFloors(this.config.strategyLiquidityFloor ?? ""),
+    );
+
+    // Sequenced DECISION events for every order accepted or rejected
+    initDecisionLog();
+
+    // Paper-trade shadow strategies while the rest trade live
+    const shadowStrategies = parseShadowStrategies(
+      this.config.shadowStrategies ?? "",
+    );
+    initShadowTracker(shadowStrategies);
+    if (shadowStrategies.length > 0) {
+      console.log(
+        `🧪 Shadow mode: ${shadowStrategies.join(", ")} (paper orders, live signals)`,
+      );
+    }
+
+    // Realized edge vs entry mid per signal (status log)
+    initSignalEdgeTracker();
+
+    // Score whales by the results of the trades we followed
+    initWhaleScorer({
+      autoPromote: this.config.whaleAutoPromote,
+      minTrades: this.config.whalePromoteMinTrades,
+      minHitRate: this.config.whalePromoteMinHitRate,
+      maxPromoted: this.config.whalePromoteMaxWallets,
+    });
+
+    // Don't chase whale fills that already moved the book
+    initCopyGuard(
+      {
+        delayMs: this.config.copyDelayMs,
+        maxDriftCents: this.config.copyMaxDriftCents,
+        maxMoveCents: this.config.copySkipIfMovedCents,
+      },
+      parseCopyTargetRules(this.config.copyTargetRules ?? ""),
+    );
+
     // Initialize latency monitor
     this.latencyMonitor = initLatencyMonitor({
       rpcUrl: this.config.rpcUrl,
@@ -507,6 +934,12 @@ export class ChurnEngine {
       }
     });
 
+    // Persist armed protections whenever a position opens, hedges or closes
+    // so a crash between status intervals can't lose them
+    this.positionManager.onTransition(() => {
+      this.saveState();
+    });
+
     // Log bias changes
     this.biasAccumulator.onBiasChange((e) => {
       console.log(
@@ -620,7 +1053,98 @@ export class ChurnEngine {
     this.client = auth.client;
     this.wallet = auth.wallet;
     this.address = auth.address!;
+
+    // The root cause of most "401 / balance 0" reports
+    if (auth.funderDiagnostic) {
+      console.warn(
+        `⚠️ [${auth.funderDiagnostic.code}] ${auth.funderDiagnostic.message}`,
+      );
+      emitEvent({
+        event: auth.funderDiagnostic.code,
+        timestamp: new Date().toISOString(),
+        ...auth.funderDiagnostic,
+      });
+    }
+
+    // Re-sign auth-rejected orders with an alternate signature type
+    // (AUTH_FALLBACK_ENABLED) - probed once here, cached for the session
+    if (this.config.authFallbackEnabled) {
+      const configured = {
+        signatureType: auth.signatureType ?? 0,
+        funderAddress: auth.funderAddress,
+      };
+      const fallback = new AuthFallback(
+        configured,
+        createAuthComboProbe(auth.wallet),
+      );
+      const proxyAddress =
+        process.env.POLYMARKET_PROXY_ADDRESS ??
+        process.env.CLOB_FUNDER_ADDRESS;
+      const combo = await fallback.probe(
+        alternateAuthCombos(configured, proxyAddress?.toLowerCase()),
+        this.logger,
+      );
+      if (combo) this.client = withAuthFallback(auth.client, fallback);
+    }
+    // Look every accepted order up afterwards (ACK_CHECK_ENABLED) - one
+    // the exchange never shows is flagged ACK_NOT_VISIBLE
+    if (this.config.ackCheckEnabled) {
+      const ackChecker = new AckChecker(createClientAckLookup(auth.client), {
+        timeoutMs: this.config.ackCheckTimeoutMs,
+        intervalMs: this.config.ackCheckIntervalMs,
+      });
+      ackChecker.onNotVisible((e) => {
+        if (isTelegramEnabled()) {
+          sendTelegram(
+            "👻 Order Not Visible",
+            `${e.side ?? "Order"} ${e.orderId.slice(0, 12)}... was accepted but not found after ${Math.round(e.elapsedMs / 1000)}s - check open orders and fills`,
+          ).catch(() => {});
+        }
+      });
+      this.client = withAckCheck(this.client, ackChecker);
+    }
     this.executionEngine.setClient(this.client);
+    // On-chain holdings back the pre-SELL share check
+    initShareCheck({ wallet: auth.wallet, address: this.address });
+
+    // Cancel resting orders that went stale (ORDER_SWEEP_INTERVAL_SEC, or
+    // a scheduled cancel-stale job)
+    this.orderSweeper = new OrderSweeper(this.client, {
+      maxAgeMs: (this.config.orderSweepMaxAgeMin ?? 60) * 60 * 1000,
+      maxSpreadCents: this.config.orderSweepMaxSpreadCents,
+    });
+    if ((this.config.orderSweepIntervalSec ?? 0) > 0) {
+      console.log(
+        `🧹 Order sweeper: every ${this.config.orderSweepIntervalSec}s (max age ${this.config.orderSweepMaxAgeMin ?? 60}m)`,
+      );
+    }
+
+    // Sell or redeem positions below DUST_MAX_USD (on demand, or every
+    // DUST_CLEAN_INTERVAL_MIN)
+    this.dustCleaner = new DustCleaner(
+      {
+        fetchPositions: () => getPositions(this.address, true),
+        fetchRedeemable: () => fetchRedeemablePositions(this.address),
+        sell: (position) =>
+          smartSell(this.client!, position, {
+            maxSlippagePct: this.config.liquidationMaxSlippagePct,
+            forceSell: true,
+            logger: this.logger,
+          }),
+        redeem: (conditionId) =>
+          redeemPosition(conditionId, this.wallet, this.address, this.logger),
+      },
+      { maxValueUsd: this.config.dustMaxUsd },
+    );
+
+    // Show our own wallets by name in logs (address = proxy in proxy mode)
+    registerAddressLabel(this.address, "own:wallet");
+    if (auth.wallet.address.toLowerCase() !== this.address) {
+      registerAddressLabel(auth.wallet.address, "own:signer");
+    }
+
+    // One structured line with the effective environment (secrets redacted)
+    this.emitStartupEvent(githubReporter.isEnabled());
 
     // ═══════════════════════════════════════════════════════════════════════
     // INITIALIZE BALANCE CACHE (RPC throttling to reduce Infura calls)
@@ -635,9 +1159,219 @@ export class ChurnEngine {
     // INITIALIZE WEBSOCKET MARKET DATA LAYER
     // ═══════════════════════════════════════════════════════════════════════
     // Initialize market data store and facade for real-time orderbook streaming
-    initMarketDataStore();
+    const marketDataStore = initMarketDataStore();
     this.marketDataFacade = initMarketDataFacade(this.client);
 
+    // Feed rolling volatility (indicators + vol sizing) from trade prints
+    initVolatilityEstimator().attach(marketDataStore);
+    // Feed order-flow imbalance (early direction signal) from trade prints
+    initOrderFlowTracker().attach(marketDataStore);
+    // Track last trade / quote changes to spot dead and delisted markets
+    initMarketStalenessIndex({
+      quietAfterMs: (this.config.staleMarketQuietMinutes ?? 60) * 60_000,
+      deadAfterMs: (this.config.staleMarketDeadHours ?? 6) * 60 * 60_000,
+    }).attach(marketDataStore);
+    // Catch a wedged WS feed by comparing sampled quotes against REST
+    const feedValidator = initFeedValidator(
+      marketDataStore,
+      (tokenId) => this.client!.getOrderBook(tokenId),
+      {
+        sampleSize: this.config.feedCheckSample,
+        toleranceCents: this.config.feedDivergenceCents,
+      },
+    );
+    feedValidator.onDivergence((e) => {
+      if (isTelegramEnabled()) {
+        sendTelegram(
+          "📡 Feed Divergence",
+          `${e.tokenId.slice(0, 12)}... WS ${e.wsBid}/${e.wsAsk} vs REST ${e.restBid}/${e.restAsk} (${e.diffCents}¢) - the WebSocket feed may be wedged`,
+        ).catch(() => {});
+      }
+    });
+
+    // Block entries on tokens that just spiked (CHASE_MOVE_PCT)
+    const chaseThrottle = initChaseThrottle({
+      movePct: this.config.chaseMovePct,
+      windowMs: (this.config.chaseWindowSeconds ?? 30) * 1000,
+      blockMs: (this.config.chaseBlockSeconds ?? 120) * 1000,
+    });
+    if (chaseThrottle.isEnabled()) {
+      chaseThrottle.attach(marketDataStore);
+    }
+
+    // Arbitrate whale vs scan entries on the same market (ORDER_RACE_MODE)
+    initOrderRaceArbiter({
+      mode: parseOrderRaceMode(this.config.orderRaceMode ?? ""),
+      windowMs: (this.config.orderRaceWindowSeconds ?? 10) * 1000,
+      priority: parseOrderRacePriority(this.config.orderRacePriority ?? ""),
+    }).onRace((race) => {
+      if (race.resolution === "BOTH_CANCELLED" && isTelegramEnabled()) {
+        sendTelegram(
+          "🏁 Order Race",
+          `Cancelled both entries: ${formatOrderRace(race)}\n` +
+            `Token: ${race.incoming.tokenId.slice(0, 16)}...`,
+        ).catch(() => {});
+      }
+    });
+
+    // Cap orders per market so a flapping signal can't churn it
+    // (MAX_ORDERS_PER_MARKET_PER_HOUR)
+    initOrderGovernor({
+      maxOrders: this.config.maxOrdersPerMarketPerHour ?? 0,
+      windowMs: (this.config.orderGovernorWindowMinutes ?? 60) * 60_000,
+    });
+
+    // Stay out of markets that went badly (BLACKLIST_*), persisted
+    const blacklist = initMarketBlacklist(
+      {
+        resolvedLossUsd: this.config.blacklistResolvedLossUsd ?? 50,
+        maxFailedFills: this.config.blacklistFailedFills ?? 3,
+        failedFillWindowMs:
+          (this.config.blacklistFailedFillsWindowMinutes ?? 60) * 60_000,
+        disputed: this.config.blacklistDisputed ?? true,
+        scope: parseBlacklistScope(this.config.blacklistScope ?? ""),
+      },
+      this.config.marketBlacklistPath,
+    );
+    if (blacklist.list().length > 0) {
+      console.log(`⛔ ${blacklist.list().length} market(s) blacklisted`);
+    }
+    blacklist.onBlacklisted((entry) => {
+      if (isTelegramEnabled()) {
+        sendTelegram(
+          "⛔ Market Blacklisted",
+          `${entry.label}\nReason: ${entry.reason}` +
+            (entry.detail ? ` (${entry.detail})` : ""),
+        ).catch(() => {});
+      }
+    });
+
+    // Keep a set fraction of positions hedged (HEDGE_TARGETS), with
+    // runtime overrides from the hedge-target script
+    const hedgeTargets = initHedgeTargets(
+      parseHedgeTargets(this.config.hedgeTargets ?? ""),
+      this.config.hedgeTargetsPath,
+    );
+    const targetCount = Object.keys(hedgeTargets.list()).length;
+    if (targetCount > 0) {
+      console.log(`🛡️ ${targetCount} hedge target(s) set`);
+    }
+
+    // Housekeeping jobs at set times (JOB_SCHEDULE), with runtime changes
+    // from the jobs script
+    const jobs = initJobScheduler(
+      parseJobSchedule(this.config.jobSchedule ?? ""),
+      this.config.jobSchedulePath,
+    );
+    this.registerJobs(jobs);
+    this.executionEngine.setHedgeRebalanceScheduled(jobs.hasJob("rebalance"));
+    if (jobs.list().length > 0) {
+      console.log(`⏰ ${jobs.list().length} job(s) scheduled`);
+    }
+
+    // Hold entry signals while every position slot is taken
+    // (SIGNAL_QUEUE_MAX), persisted across restarts
+    const signalQueue = initSignalQueue(
+      {
+        maxSize: this.config.signalQueueMax ?? 0,
+        ttlMs: (this.config.signalQueueTtlSec ?? 300) * 1000,
+      },
+      this.config.signalQueuePath,
+    );
+    if (signalQueue.isEnabled()) {
+      console.log(
+        `📥 Signal queue: up to ${this.config.signalQueueMax} signal(s) for ${this.config.signalQueueTtlSec ?? 300}s (${signalQueue.size()} restored)`,
+      );
+    }
+
+    // Realized P&L steps the per-trade cap (COMPOUND_SCHEDULE), persisted
+    const compounder = initProfitCompounder(
+      {
+        baseCapUsd: this.config.maxTradeUsd,
+        steps: parseCompoundSchedule(this.config.compoundSchedule ?? ""),
+      },
+      this.config.compoundStatePath,
+    );
+    if (compounder.isEnabled()) {
+      const { capUsd, realizedPnlUsd } = compounder.getStatus();
+      console.log(
+        `📈 Compounding: trade cap $${capUsd.toFixed(2)} (realized P&L $${realizedPnlUsd.toFixed(2)})`,
+      );
+    }
+
+    // Tripwire for balance changes the bot didn't make (BALANCE_WATCH_*)
+    initBalanceWatcher({
+      enabled: this.config.balanceWatchEnabled,
+      toleranceUsd: this.config.balanceWatchToleranceUsd,
+      graceMs: (this.config.balanceWatchGraceSeconds ?? 120) * 1000,
+    });
+
+    // Implied-probability checks on neg-risk events (OVERROUND_EVENT_IDS)
+    const overroundEventIds = (this.config.overroundEventIds ?? "")
+      .split(",")
+      .map((id) => id.trim())
+      .filter(Boolean);
+    if (overroundEventIds.length > 0) {
+      initOverroundMonitor({
+        eventIds: overroundEventIds,
+        alertPct: this.config.overroundAlertPct,
+        arbEdgePct: this.config.overroundArbEdgePct,
+      });
+      console.log(`⚖️ Overround monitor: ${overroundEventIds.length} event(s)`);
+    }
+
+    // Per-token PRICE_ALERT subscriptions (PRICE_ALERTS); fired state is
+    // restored from STATE_FILE_PATH by loadState()
+    const priceAlerts = parsePriceAlerts(this.config.priceAlerts ?? "");
+    for (const spec of priceAlerts) getPriceAlertManager().add(spec);
+    if (priceAlerts.length > 0) {
+      console.log(`🔔 Price alerts: ${priceAlerts.length} configured`);
+    }
+
+    // IMBALANCE_TRIGGER on a sustained lopsided book (IMBALANCE_TRIGGERS),
+    // fed by every streamed book; fired state is restored by loadState()
+    const imbalanceTriggers = parseImbalanceTriggers(
+      this.config.imbalanceTriggers ?? "",
+    );
+    if (imbalanceTriggers.length > 0) {
+      const triggers = getImbalanceTriggerManager();
+      for (const spec of imbalanceTriggers) triggers.add(spec);
+      triggers.attach(getMarketDataStore());
+      triggers.onTrigger((e) => this.alertImbalance(e));
+      console.log(
+        `⚖️ Imbalance triggers: ${imbalanceTriggers.length} configured`,
+      );
+    }
+
+    // ORDER_FILL events enriched with the placing order's strategy and
+    // intended price; running positions start from the bot's live holdings
+    initFillEnricher({
+      positionSource: (tokenId) =>
+        this.positionManager
+          .getPositionsByToken(tokenId)
+          .filter((p) => !p.shadow)
+          .reduce(
+            (sum, p) => sum + p.entrySizeUsd / (p.entryPriceCents / 100),
+            0,
+          ),
+    });
+
+    // Cross-market correlated move detection (CORRELATION_GROUPS)
+    const correlationGroups = parseCorrelationGroups(
+      this.config.correlationGroups ?? "",
+    );
+    if (correlationGroups.length > 0) {
+      initCorrelationDetector(correlationGroups, {
+        moveCents: this.config.correlationMoveCents,
+        lagRatio: this.config.correlationLagRatio,
+        windowMs: this.config.correlationWindowMs,
+        cooldownMs: this.config.correlationWindowMs,
+      }).attach(marketDataStore);
+      console.log(
+        `🔗 Correlation detector: ${correlationGroups.length} group(s) (${correlationGroups.map((g) => g.name).join(", ")})`,
+      );
+    }
+
     // Initialize BookResolver for unified book handling (whale + scan flows)
     this.bookResolver = initBookResolver(this.client);
 
@@ -702,6 +1436,10 @@ export class ChurnEngine {
     await this.processRedemptions();
     this.lastRedeemTime = Date.now(); // Reset timer after startup redemption
 
+    // Funds sent as native USDC show on-chain but not on the CLOB
+    await this.checkNativeUsdc();
+    this.lastUsdcCheckTime = Date.now();
+
     // Get balances AFTER redemption (initial fetch via cache)
     const { usdc: usdcBalance, pol: polBalance } =
       await this.balanceCache!.getBalances();
@@ -713,15 +1451,25 @@ export class ChurnEngine {
     // ═══════════════════════════════════════════════════════════════════════
     let existingPositions: Position[] = [];
     let positionValue = 0;
+    let positionsFetched = false;
     try {
       existingPositions = await getPositions(this.address, true);
       positionValue = existingPositions.reduce((sum, p) => sum + p.value, 0);
+      positionsFetched = true;
     } catch (err) {
       console.warn(
         `⚠️ Could not fetch existing positions: ${err instanceof Error ? err.message : err}`,
       );
     }
 
+    // Re-arm protections (TP / hedge / stop) saved by the last run, but only
+    // for positions the wallet still holds
+    this.loadState(
+      positionsFetched
+        ? new Set(existingPositions.map((p) => p.tokenId))
+        : undefined,
+    );
+
     console.log("");
     console.log(
       `💰 Balance: $${usdcBalance.toFixed(2)} USDC | ${polBalance.toFixed(4)} POL`,
@@ -1092,6 +1840,45 @@ export class ChurnEngine {
     }
   }
 
+  /**
+   * Emit the STARTUP event - wallet, signing mode, endpoints, subsystems
+   */
+  private emitStartupEvent(githubReporterEnabled: boolean): void {
+    const signerAddress = this.wallet!.address;
+    const authInfo = getAuthDiagnostics(signerAddress, this.address);
+
+    const event = buildStartupEvent({
+      signerAddress,
+      effectiveAddress: this.address,
+      signatureType: authInfo.signatureType,
+      signatureTypeLabel: authInfo.signatureTypeLabel,
+      chainId: POLYGON.CHAIN_ID,
+      endpoints: {
+        clob: POLYMARKET_API.CLOB,
+        data: POLYMARKET_API.DATA,
+        gamma: POLYMARKET_API.GAMMA,
+        ws: POLYMARKET_WS.HOST,
+        rpc: this.config.rpcUrl,
+      },
+      subsystems: {
+        liveTrading: this.config.liveTradingEnabled,
+        telegram: isTelegramEnabled(),
+        githubReporter: githubReporterEnabled,
+        onchainMonitor: this.config.onchainMonitorEnabled,
+        marketScanner: this.config.scanActiveMarkets,
+        dynamicReserves: this.config.dynamicReservesEnabled,
+        polReserve: this.config.polReserveEnabled,
+        volSizing: this.config.volSizingEnabled ?? false,
+        correlationDetector: !!this.config.correlationGroups,
+        requestCapture: (this.config.requestCaptureSize ?? 0) > 0,
+        stateFile: !!this.config.stateFilePath,
+      },
+      eventSinks: getEventBus()?.getSinkNames(),
+    });
+
+    emitEvent(event);
+  }
+
   /**
    * Setup VPN if configured
    */
@@ -1428,7 +2215,8 @@ export class ChurnEngine {
             // This also helps avoid rate limiting when there's a genuine issue.
             if (
               result.reason === "INSUFFICIENT_BALANCE" ||
-              result.reason === "INSUFFICIENT_ALLOWANCE"
+              result.reason === "INSUFFICIENT_ALLOWANCE" ||
+              result.reason === INSUFFICIENT_SHARES
             ) {
               console.log(
                 `   ⏳ Adding to cooldown (likely already sold, waiting for API update)`,
@@ -1584,7 +2372,11 @@ export class ChurnEngine {
     // ─────────────────────────────────────────────────────────────────
     let scannedOpportunities: string[] = [];
     if (this.config.scanActiveMarkets) {
-      scannedOpportunities = this.volumeScanner.getActiveTokenIds();
+      // Dead and delisted markets would only be fetched to be rejected
+      const staleness = getMarketStalenessIndex();
+      scannedOpportunities = this.volumeScanner
+        .getActiveTokenIds()
+        .filter((tokenId) => !staleness?.isExcluded(tokenId));
     }
 
     // ─────────────────────────────────────────────────────────────────
@@ -1593,6 +2385,11 @@ export class ChurnEngine {
     const evAllowed = this.evTracker.isTradingAllowed();
     const activeBiases = this.biasAccumulator.getActiveBiases();
 
+    // Signals queued while every position slot was taken go first
+    if (evAllowed.allowed) {
+      await this.drainSignalQueue(usdcBalance);
+    }
+
     // Debug: show all active biases before filtering
     if (this.deps.isDebugEnabled() && activeBiases.length > 0) {
       this.deps.debug(
@@ -1777,6 +2574,12 @@ export class ChurnEngine {
                 console.log(
                   `❌ [Entry] FAILED: ${bias.tokenId.slice(0, 12)}... - ${result.reason}`,
                 );
+                this.queueSignal(
+                  bias.tokenId,
+                  marketData.marketId,
+                  "whale",
+                  result.reason,
+                );
 
                 // Add to cooldown if failed due to price/liquidity issues (not bankroll)
                 // This prevents spamming the same failing token repeatedly
@@ -1963,6 +2766,12 @@ export class ChurnEngine {
                   this.trackFailureReason(
                     `SCAN: ${result.reason || "unknown"}`,
                   );
+                  this.queueSignal(
+                    tokenId,
+                    marketData.marketId,
+                    "scan",
+                    result.reason,
+                  );
                   // Add to cooldown if failed due to price/liquidity issues
                   // TASK 5: Only cooldown for TRANSIENT errors
                   if (this.deps.shouldCooldownOnFailure(result.reason)) {
@@ -2073,6 +2882,16 @@ export class ChurnEngine {
       }
       // Cleanup expired cooldown entries
       this.marketDataCooldownManager.cleanup();
+
+      // Bounded lookup caches (markets, tick sizes, market status)
+      const cacheMetrics = getStoreRegistry().getMetrics();
+      if (cacheMetrics.totalEntries > 0) {
+        const parts = Object.entries(cacheMetrics.byStore).map(
+          ([name, m]) =>
+            `${name}: ${m.entryCount} (${(m.hitRatio * 100).toFixed(0)}% hit, ${m.evictions} evicted)`,
+        );
+        console.log(`🗄️ [Cache Summary] ${parts.join(" | ")}`);
+      }
     }
 
     // Send startup diagnostic report after 60 seconds
@@ -2100,9 +2919,140 @@ export class ChurnEngine {
       this.lastPolCheckTime = now;
     }
 
-    // Status update
+    // Blacklist: pick up edits from the blacklist script, check disputes
+    // (and edits from the hedge-target and jobs scripts)
+    if (now - this.lastBlacklistCheckTime >= this.BLACKLIST_CHECK_INTERVAL_MS) {
+      this.lastBlacklistCheckTime = now;
+      getMarketBlacklist()?.refresh();
+      getHedgeTargets()?.refresh();
+      getJobScheduler()?.refresh();
+      this.blacklistDisputedMarkets().catch((err) => {
+        console.warn(
+          `⚠️ [Blacklist] Dispute check failed: ${err instanceof Error ? err.message : err}`,
+        );
+      });
+    }
+
+    // Write-path SLO: also notices a breach ending while no orders go out
+    getEndpointLatency()?.evaluate(now);
+
+    // Native USDC the exchange can't use
+    if (now - this.lastUsdcCheckTime >= this.USDC_CHECK_INTERVAL_MS) {
+      await this.checkNativeUsdc();
+      this.lastUsdcCheckTime = now;
+    }
+
+    // Sweep stale resting orders in the background
+    const sweepInterval = (this.config.orderSweepIntervalSec ?? 0) * 1000;
+    if (
+      this.orderSweeper &&
+      sweepInterval > 0 &&
+      now - this.lastOrderSweepTime >= sweepInterval
+    ) {
+      this.lastOrderSweepTime = now;
+      this.orderSweeper.sweep().catch((err) => {
+        console.warn(
+          `⚠️ [Sweeper] Sweep failed: ${err instanceof Error ? err.message : err}`,
+        );
+      });
+    }
+
+    // Cross-check streamed quotes against REST in the background
+    const feedInterval = (this.config.feedCheckIntervalSec ?? 0) * 1000;
+    if (feedInterval > 0 && now - this.lastFeedCheckTime >= feedInterval) {
+      this.lastFeedCheckTime = now;
+      getFeedValidator()
+        ?.check()
+        .catch((err) => {
+          console.warn(
+            `⚠️ [Feed] Check failed: ${err instanceof Error ? err.message : err}`,
+          );
+        });
+    }
+
+    // Clear out dust positions in the background
+    const dustInterval = (this.config.dustCleanIntervalMin ?? 0) * 60 * 1000;
+    if (dustInterval > 0 && now - this.lastDustCleanTime >= dustInterval) {
+      this.lastDustCleanTime = now;
+      this.cleanupDust().catch((err) => {
+        console.warn(
+          `⚠️ [Dust] Cleanup failed: ${err instanceof Error ? err.message : err}`,
+        );
+      });
+    }
+
+    // Scheduled jobs (JOB_SCHEDULE) in the background
+    const jobs = getJobScheduler();
+    if (jobs) {
+      this.executionEngine.setHedgeRebalanceScheduled(jobs.hasJob("rebalance"));
+      jobs.tick(now);
+    }
+
+    // Refresh tag IDs/slugs for the market allow/deny lists
+    const tagSyncInterval = (this.config.tagSyncIntervalMin ?? 0) * 60 * 1000;
+    if (tagSyncInterval > 0 && now - this.lastTagSyncTime >= tagSyncInterval) {
+      this.lastTagSyncTime = now;
+      getTagTaxonomy()
+        .syncFromGamma()
+        .then((tags) => {
+          if (tags !== null) {
+            console.log(`🏷️ Tag taxonomy synced (${tags} tags)`);
+          }
+        });
+    }
+
+    // Rebuild the local market search index (npm run search)
+    const searchInterval =
+      (this.config.marketSearchRefreshMin ?? 0) * 60 * 1000;
+    if (
+      searchInterval > 0 &&
+      now - this.lastMarketSearchRefreshTime >= searchInterval
+    ) {
+      this.lastMarketSearchRefreshTime = now;
+      getMarketSearchIndex()
+        .refreshFromGamma()
+        .then((markets) => {
+          if (markets !== null) {
+            this.deps.debug(`[MarketSearch] Indexed ${markets} market(s)`);
+          }
+        });
+    }
+
+    // Implied-probability sums on watched events, in the background
+    const overroundInterval =
+      (this.config.overroundCheckIntervalSec ?? 60) * 1000;
+    if (
+      getOverroundMonitor() &&
+      now - this.lastOverroundCheckTime >= overroundInterval
+    ) {
+      this.lastOverroundCheckTime = now;
+      this.checkOverround().catch((err) => {
+        console.warn(
+          `⚠️ [Overround] Check failed: ${err instanceof Error ? err.message : err}`,
+        );
+      });
+    }
+
+    // Per-token price alerts
+    await this.checkPriceAlerts();
+    // Imbalance triggers whose hold ended between books
+    this.checkImbalanceTriggers();
+
+    // Enriched ORDER_FILL events (polling our trades while the user
+    // WebSocket is down)
+    await this.enrichFills(now);
+    // Mid at the markout after each maker fill (quote report)
+    this.markOutQuotes(now);
+
+    // Tripwire: balance moved without a journaled trade
+    this.watchBalances(usdcBalance);
+
+    // Status update (and periodic state snapshot)
     if (now - this.lastSummaryTime >= this.SUMMARY_INTERVAL_MS) {
+      this.promoteWhales();
       await this.logStatus(usdcBalance, effectiveBankroll, polBalance);
+      this.saveState();
+      this.saveBookHistory();
       this.lastSummaryTime = now;
     }
 
@@ -2128,6 +3078,14 @@ export class ChurnEngine {
     let actualPositions: Position[] = [];
     try {
       actualPositions = await getPositions(this.address, true);
+      // Only a successful fetch is complete enough to diff positions against
+      const watcher = getBalanceWatcher();
+      if (watcher?.isEnabled()) {
+        const sizes = new Map<string, number>(
+          actualPositions.map((p) => [p.tokenId, p.size]),
+        );
+        this.alertBalanceAnomalies(watcher.observePositions(sizes));
+      }
     } catch {
       // Continue with empty if fetch fails
     }
@@ -2166,6 +3124,10 @@ export class ChurnEngine {
     console.log(
       `   📈 Positions: ${positionDisplay} | Trades: ${metrics.totalTrades} | 🐋 Following: ${trackedWallets}`,
     );
+    const queuedSignals = getSignalQueue()?.size() ?? 0;
+    if (queuedSignals > 0) {
+      console.log(`   📥 Queued signals: ${queuedSignals} (positions full)`);
+    }
     console.log(
       `   🎯 Win: ${winPct}% | EV: ${evSign}${metrics.evCents.toFixed(1)}¢ | P&L: ${pnlSign}$${metrics.totalPnlUsd.toFixed(2)}`,
     );
@@ -2196,6 +3158,18 @@ export class ChurnEngine {
       );
     }
 
+    // Show the compounded trade cap if compounding is on
+    const risk = this.getRiskStatus();
+    if (risk.enabled) {
+      const step = risk.nextStep;
+      const next = step
+        ? ` | next $${step.capUsd.toFixed(2)} at ${step.pnlUsd >= 0 ? "+" : "-"}$${Math.abs(step.pnlUsd).toFixed(2)}`
+        : "";
+      console.log(
+        `   📈 Trade cap: $${risk.capUsd.toFixed(2)} (base $${risk.baseCapUsd.toFixed(2)}, realized $${risk.realizedPnlUsd.toFixed(2)})${next}`,
+      );
+    }
+
     // Show dynamic reserves status if enabled
     if (this.config.dynamicReservesEnabled) {
       const missedInfo =
@@ -2219,47 +3193,169 @@ export class ChurnEngine {
       `   ${networkEmoji} Network: ${networkHealth.status.toUpperCase()} | RPC: ${networkHealth.rpcLatencyMs.toFixed(0)}ms | API: ${networkHealth.apiLatencyMs.toFixed(0)}ms | Slippage: ${networkHealth.recommendedSlippagePct.toFixed(1)}%`,
     );
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // DIAGNOSTIC: Show on-chain vs API detection stats
-    // ═══════════════════════════════════════════════════════════════════════════
-    if (this.onchainMonitor) {
-      const onchainStats = this.onchainMonitor.getStats();
-      const onchainEmoji = onchainStats.connected ? "🟢" : "🔴";
-      console.log(
-        `   ${onchainEmoji} On-chain: ${onchainStats.connected ? "CONNECTED" : "DISCONNECTED"} | Events: ${onchainStats.eventsReceived} | Whales loaded: ${onchainStats.trackedWallets}`,
-      );
+    // Show order write endpoints (and anything slower) by percentile
+    const sloMs = this.config.sloWriteP95Ms ?? 1500;
+    const slowest = (getEndpointLatency()?.getStats() ?? []).filter(
+      (e) => e.write || (sloMs > 0 && e.p95Ms > sloMs),
+    );
+    if (slowest.length > 0) {
+      const breaches = new Set(getEndpointLatency()?.getBreaches() ?? []);
+      console.log(`   ⏱️ Endpoint latency (p50/p95/p99):`);
+      for (const e of slowest.slice(0, 5)) {
+        console.log(
+          `      ${breaches.has(e.endpoint) ? "🐢 " : ""}${e.endpoint} | ${e.p50Ms.toFixed(0)}/${e.p95Ms.toFixed(0)}/${e.p99Ms.toFixed(0)}ms | ${e.count} req${e.errorRate > 0 ? ` | ${(e.errorRate * 100).toFixed(0)}% failed` : ""}`,
+        );
+      }
     }
 
-    // Show entry pipeline diagnostics
-    const entrySuccessRate =
-      this.diagnostics.entryAttempts > 0
-        ? (
-            (this.diagnostics.entrySuccesses / this.diagnostics.entryAttempts) *
-            100
-          ).toFixed(0)
-        : "0";
-    console.log(
-      `   📊 Diagnostics: API trades detected: ${this.diagnostics.whaleTradesDetected} | Entry attempts: ${this.diagnostics.entryAttempts} (${entrySuccessRate}% success) | OB failures: ${this.diagnostics.orderbookFetchFailures}`,
+    // Show degraded read-only dependencies (screening/positions paused)
+    const degraded = (getDependencyBreakers()?.getStatus() ?? []).filter(
+      (d) => d.state !== "CLOSED",
     );
+    if (degraded.length > 0) {
+      const summary = degraded
+        .map((d) => `${d.name} ${d.state} (${d.consecutiveFailures} failures)`)
+        .join(" | ");
+      console.log(`   🔴 Dependencies DEGRADED: ${summary}`);
+    }
 
-    // TASK 6: Display enhanced funnel counters showing where signals die
-    const biasStats = this.biasAccumulator.getFunnelStats();
-    const funnel = this.diagnostics.funnel;
-    console.log(
-      `   🔬 Funnel: Ingested: ${biasStats.tradesIngested} | ` +
-        `PriceFilter: -${biasStats.tradesFilteredByPrice} | ` +
-        `Tokens: ${biasStats.uniqueTokensWithTrades} | ` +
-        `Eligible: ${funnel.eligibleSignals}`,
-    );
-    // Show rejection breakdown if any rejections occurred
-    const totalRejections =
-      funnel.biasesRejectedStale +
-      funnel.biasesRejectedTrades +
-      funnel.biasesRejectedFlow +
-      funnel.biasesSkippedCooldown;
-    if (totalRejections > 0) {
-      console.log(
-        `   📉 Rejections: Stale: ${funnel.biasesRejectedStale} | ` +
+    // Show per-strategy capital allocations
+    const allocations = this.getAllocations(effectiveBankroll);
+    if (allocations.length > 0) {
+      const summary = allocations
+        .map(
+          (a) =>
+            `${a.strategy} ${(a.weight * 100).toFixed(0)}% $${a.deployedUsd.toFixed(2)}/$${a.budgetUsd.toFixed(2)}`,
+        )
+        .join(" | ");
+      console.log(`   📊 Allocations: ${summary}`);
+    }
+
+    // Compare shadow strategies against live trading
+    const shadowReport = this.getShadowReport();
+    if (shadowReport.length > 0) {
+      console.log(`   🧪 Shadow vs live:`);
+      for (const line of shadowReport) {
+        console.log(`      ${line}`);
+      }
+    }
+
+    // Which entry signal actually makes money after spread/slippage
+    const edgeReport = getSignalEdgeTracker()?.formatReport() ?? [];
+    if (edgeReport.length > 0) {
+      console.log(`   🎯 Realized edge by signal:`);
+      for (const line of edgeReport) {
+        console.log(`      ${line}`);
+      }
+    }
+
+    // Execution cost per strategy over the last 24h
+    const tca = this.getTcaSummary(Date.now() - 24 * 60 * 60 * 1000);
+    if (tca.length > 0) {
+      console.log(`   🧾 Execution cost (24h):`);
+      for (const t of tca) {
+        console.log(
+          `      ${t.strategy} | ${t.count} fills | $${t.notionalUsd.toFixed(2)} | slip ${t.avgSlippageBps.toFixed(0)}bps | fees $${t.feesUsd.toFixed(2)} | ${(t.avgDurationMs / 1000).toFixed(1)}s avg`,
+        );
+      }
+    }
+
+    // Fill vs submission quote per strategy and order type (FOK vs GTC)
+    const improvement = this.getPriceImprovement();
+    if (improvement.length > 0) {
+      console.log(`   💹 Price improvement vs quote:`);
+      for (const p of improvement) {
+        const sign = p.improvementUsd >= 0 ? "+" : "-";
+        console.log(
+          `      ${p.strategy} ${p.orderType} | ${p.fills} fills | ${sign}$${Math.abs(p.improvementUsd).toFixed(2)} | ${p.avgImprovementCents >= 0 ? "+" : ""}${p.avgImprovementCents.toFixed(2)}¢/share`,
+        );
+      }
+    }
+
+    // Maker fills: did quoting earn the spread or get picked off?
+    const quotes = this.getQuoteReport();
+    if (quotes.length > 0) {
+      console.log(`   🎯 Quoted markets (maker fills):`);
+      for (const line of formatQuoteReport(quotes.slice(0, 5))) {
+        console.log(`      ${line}`);
+      }
+    }
+
+    // Markets that stopped trading or lost their orderbook (skipped by scans)
+    const staleness = getMarketStalenessIndex();
+    const stale = this.getStalenessReport(["DELISTED", "DEAD"]);
+    if (staleness && stale.length > 0) {
+      console.log(
+        `   🧊 Markets: ${formatStalenessCounts(staleness.getCounts())}`,
+      );
+      for (const m of stale.slice(0, 5)) {
+        const lastTrade = m.lastTradeAt
+          ? `${((Date.now() - m.lastTradeAt) / 3_600_000).toFixed(1)}h ago`
+          : "never seen";
+        console.log(
+          `      ${m.tokenId.slice(0, 16)}... ${m.status} | last trade ${lastTrade} | ${m.quoteUpdatesPerHour} quote updates/h${m.delistedReason ? ` | ${m.delistedReason.slice(0, 40)}` : ""}`,
+        );
+      }
+    }
+
+    // Whales ranked by how following them has worked out
+    const whaleRank = this.getWhaleRank(5);
+    if (whaleRank.length > 0) {
+      console.log(`   🐋 Whale rank:`);
+      whaleRank.forEach((w, i) => {
+        const sign = w.avgPnlUsd >= 0 ? "+" : "-";
+        console.log(
+          `      ${i + 1}. ${labelAddress(w.wallet)} | ${w.trades} trades | ${(w.hitRate * 100).toFixed(0)}% hit | avg ${sign}$${Math.abs(w.avgPnlUsd).toFixed(2)} (${(w.avgReturn * 100).toFixed(1)}%)${w.promoted ? " 📌" : ""}`,
+        );
+      });
+      emitEvent({
+        event: "WHALE_RANK",
+        timestamp: new Date().toISOString(),
+        whales: whaleRank,
+      });
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // DIAGNOSTIC: Show on-chain vs API detection stats
+    // ═══════════════════════════════════════════════════════════════════════════
+    if (this.onchainMonitor) {
+      const onchainStats = this.onchainMonitor.getStats();
+      const onchainEmoji = onchainStats.connected ? "🟢" : "🔴";
+      console.log(
+        `   ${onchainEmoji} On-chain: ${onchainStats.connected ? "CONNECTED" : "DISCONNECTED"} | Events: ${onchainStats.eventsReceived} | Whales loaded: ${onchainStats.trackedWallets}`,
+      );
+    }
+
+    // Show entry pipeline diagnostics
+    const entrySuccessRate =
+      this.diagnostics.entryAttempts > 0
+        ? (
+            (this.diagnostics.entrySuccesses / this.diagnostics.entryAttempts) *
+            100
+          ).toFixed(0)
+        : "0";
+    console.log(
+      `   📊 Diagnostics: API trades detected: ${this.diagnostics.whaleTradesDetected} | Entry attempts: ${this.diagnostics.entryAttempts} (${entrySuccessRate}% success) | OB failures: ${this.diagnostics.orderbookFetchFailures}`,
+    );
+
+    // TASK 6: Display enhanced funnel counters showing where signals die
+    const biasStats = this.biasAccumulator.getFunnelStats();
+    const funnel = this.diagnostics.funnel;
+    console.log(
+      `   🔬 Funnel: Ingested: ${biasStats.tradesIngested} | ` +
+        `PriceFilter: -${biasStats.tradesFilteredByPrice} | ` +
+        `Tokens: ${biasStats.uniqueTokensWithTrades} | ` +
+        `Eligible: ${funnel.eligibleSignals}`,
+    );
+    // Show rejection breakdown if any rejections occurred
+    const totalRejections =
+      funnel.biasesRejectedStale +
+      funnel.biasesRejectedTrades +
+      funnel.biasesRejectedFlow +
+      funnel.biasesSkippedCooldown;
+    if (totalRejections > 0) {
+      console.log(
+        `   📉 Rejections: Stale: ${funnel.biasesRejectedStale} | ` +
           `Trades<Min: ${funnel.biasesRejectedTrades} | ` +
           `Flow<Min: ${funnel.biasesRejectedFlow} | ` +
           `Cooldown: ${funnel.biasesSkippedCooldown}`,
@@ -2456,6 +3552,9 @@ export class ChurnEngine {
           }
 
           // Unsubscribe from tokens no longer needed (cleanup old subscriptions)
+          for (const id of getImbalanceTriggerManager().getWatchedTokens()) {
+            neededTokens.add(id);
+          }
           const toUnsubscribe = Array.from(currentSubs).filter(
             (id) => !neededTokens.has(id),
           );
@@ -2584,6 +3683,7 @@ export class ChurnEngine {
     );
 
     if (result?.success) {
+      getBalanceWatcher()?.journal({ kind: "SWAP" });
       console.log(
         `⛽ Refilled! Swapped $${result.usdcSwapped?.toFixed(2)} → ${result.polReceived?.toFixed(2)} POL`,
       );
@@ -2994,6 +4094,16 @@ export class ChurnEngine {
           };
         }
 
+        // Refuse to trade off a book that is too old or crossed
+        const bookCheck = this.marketDataFacade.checkBookTradable(tokenId);
+        if (!bookCheck.ok) {
+          return {
+            ok: false,
+            reason: bookCheck.reason,
+            detail: bookCheck.detail,
+          };
+        }
+
         // Log book source for debugging
         const bookSource = state.source || "UNKNOWN";
         this.deps.debug(
@@ -3218,6 +4328,7 @@ export class ChurnEngine {
         // Classify the error (case-insensitive, check most specific first)
         // NO_ORDERBOOK: specific Polymarket error for closed/settled markets
         if (errorMsgLower.includes("no orderbook")) {
+          getMarketStalenessIndex()?.markDelisted(tokenId, errorMsg);
           return { ok: false, reason: "NO_ORDERBOOK", detail: errorMsg };
         }
         // NOT_FOUND: 404 or generic "not found" errors
@@ -3225,6 +4336,9 @@ export class ChurnEngine {
           errorMsgLower.includes("404") ||
           errorMsgLower.includes("not found")
         ) {
+          if (isDelistedError(errorMsg)) {
+            getMarketStalenessIndex()?.markDelisted(tokenId, errorMsg);
+          }
           return { ok: false, reason: "NOT_FOUND", detail: errorMsg };
         }
         // RATE_LIMIT: rate limiting errors
@@ -3467,6 +4581,8 @@ export class ChurnEngine {
       if (redeemable.length === 0) return;
 
       console.log(`🎁 Found ${redeemable.length} position(s) to redeem`);
+      await this.blacklistBadResolutions(redeemable);
+      this.recordResolvedPnl(redeemable);
 
       const result = await redeemAllPositions(
         this.wallet,
@@ -3475,6 +4591,7 @@ export class ChurnEngine {
       );
 
       if (result.redeemed > 0) {
+        getBalanceWatcher()?.journal({ kind: "REDEEM" });
         console.log(
           `🎁 Redeemed ${result.redeemed} position(s) worth $${result.totalValue.toFixed(2)}`,
         );
@@ -3492,6 +4609,124 @@ export class ChurnEngine {
     }
   }
 
+  /**
+   * Blacklist markets that resolved against a big position
+   * (BLACKLIST_RESOLVED_LOSS_USD)
+   */
+  private async blacklistBadResolutions(
+    redeemable: RedeemablePosition[],
+  ): Promise<void> {
+    const blacklist = getMarketBlacklist();
+    if (!blacklist) return;
+    for (const pos of redeemable) {
+      const costUsd = pos.costUsd ?? 0;
+      if (!blacklist.isBadResolution(costUsd, pos.value)) continue;
+      const entry = await blacklist.blacklist(
+        pos.conditionId,
+        "RESOLVED_AGAINST",
+        `lost $${(costUsd - pos.value).toFixed(2)}`,
+      );
+      if (entry) {
+        console.log(
+          `⛔ [Blacklist] ${entry.label.slice(0, 50)}: resolved against us (${entry.detail})`,
+        );
+      }
+    }
+  }
+
+  /**
+   * Count resolutions toward profit compounding (once per position,
+   * however often the redeem is retried)
+   */
+  private recordResolvedPnl(redeemable: RedeemablePosition[]): void {
+    const compounder = getProfitCompounder();
+    if (!compounder?.isEnabled()) return;
+    for (const pos of redeemable) {
+      if (pos.costUsd === undefined) continue;
+      compounder.recordRealized(
+        pos.value - pos.costUsd,
+        `${pos.conditionId}:${pos.outcome}`,
+      );
+    }
+  }
+
+  /**
+   * Blacklist markets of open positions whose resolution was disputed
+   * (BLACKLIST_DISPUTED)
+   */
+  private async blacklistDisputedMarkets(): Promise<void> {
+    const blacklist = getMarketBlacklist();
+    if (!blacklist?.blacklistsDisputes()) return;
+    const tokenIds = new Set(
+      this.positionManager.getOpenPositions().map((p) => p.tokenId),
+    );
+    for (const tokenId of tokenIds) {
+      if (blacklist.isBlacklisted(tokenId)) continue;
+      const market = await fetchMarketByTokenId(tokenId);
+      if (!market?.resolutionDisputed) continue;
+      const entry = await blacklist.blacklist(
+        tokenId,
+        "DISPUTED",
+        "UMA resolution disputed",
+      );
+      if (entry) {
+        console.log(
+          `⛔ [Blacklist] ${entry.label.slice(0, 50)}: resolution disputed`,
+        );
+      }
+    }
+  }
+
+  /**
+   * Warn when the funder holds native USDC (not accepted by the exchange)
+   * and swap it to USDC.e when USDC_NATIVE_AUTO_SWAP is on
+   */
+  private async checkNativeUsdc(): Promise<void> {
+    try {
+      const holdings = await getUsdcHoldings(this.wallet, this.address);
+      const minSwapUsd = this.config.usdcNativeMinSwapUsd ?? 5;
+      const issue = checkUsdcHoldings(holdings, minSwapUsd);
+      if (!issue) return;
+
+      console.warn(`⚠️ [USDC] ${formatUsdcHoldingsWarning(holdings, issue)}`);
+
+      if (!this.config.usdcNativeAutoSwap) return;
+      if (!this.config.liveTradingEnabled) {
+        console.log("💱 Skipping native USDC swap (simulation mode)");
+        return;
+      }
+
+      const result = await swapNativeToBridgedUsdc(
+        this.wallet,
+        holdings.native,
+        {
+          router: this.config.usdcSwapRouter || DEFAULT_USDC_SWAP_ROUTER,
+          slippagePct: this.config.usdcSwapSlippagePct ?? 0.5,
+        },
+        this.logger,
+      );
+      if (!result.success) {
+        console.warn(`⚠️ [USDC] Native USDC swap failed: ${result.error}`);
+        return;
+      }
+
+      getBalanceWatcher()?.journal({ kind: "SWAP" });
+      await this.balanceCache?.forceRefresh();
+      console.log(
+        `💱 Swapped $${holdings.native.toFixed(2)} native USDC → $${result.amountOut?.toFixed(2)} USDC.e`,
+      );
+      if (isTelegramEnabled()) {
+        await sendTelegram(
+          "💱 USDC Converted",
+          `Swapped $${holdings.native.toFixed(2)} native USDC to $${result.amountOut?.toFixed(2)} USDC.e (tradeable on the exchange)`,
+        ).catch(() => {});
+      }
+    } catch (err) {
+      const msg = err instanceof Error ? err.message : String(err);
+      console.warn(`⚠️ USDC holdings check error: ${msg}`);
+    }
+  }
+
   /**
    * Stop the engine
    */
@@ -3499,6 +4734,10 @@ export class ChurnEngine {
     this.running = false;
     console.log("\n🛑 Stopping...");
 
+    // Persist armed state before shutting down
+    this.saveState();
+    this.saveBookHistory();
+
     // Stop latency monitoring
     this.latencyMonitor.stop();
 
@@ -3514,6 +4753,146 @@ export class ChurnEngine {
     }
   }
 
+  /**
+   * Write a support bundle (recent API calls, redacted config, diag trace)
+   */
+  writeSupportBundle(): string | null {
+    const filePath = writeSupportBundle(
+      this.config.supportBundleDir || "./data",
+      this.config,
+    );
+    if (filePath) {
+      console.log(`📦 Support bundle written to: ${filePath}`);
+    }
+    return filePath;
+  }
+
+  /**
+   * Save open positions and tracked GTC orders to STATE_FILE_PATH
+   */
+  saveState(): boolean {
+    const filePath = this.config.stateFilePath;
+    if (!filePath) return false;
+
+    const snapshot: EngineStateSnapshot = {
+      positions: this.positionManager.exportState(),
+      gtcOrders: gtcOrderTracker.exportOrders(),
+      priceAlerts: getPriceAlertManager().exportState(),
+      imbalanceTriggers: getImbalanceTriggerManager().exportState(),
+      tca: getTcaJournal().exportState(),
+    };
+    const result = saveStateFile(filePath, snapshot);
+    if (!result.ok) {
+      console.warn(`⚠️ [State] Failed to save ${filePath}: ${result.error}`);
+      return false;
+    }
+    this.deps.debug(
+      `[State] Saved ${snapshot.positions.length} position(s), ${snapshot.gtcOrders.length} GTC order(s) to ${filePath}`,
+    );
+    return true;
+  }
+
+  /**
+   * Save the recorded books and trade prints to BOOK_HISTORY_PATH, for
+   * replaying fills with: npm run replay-fill
+   */
+  saveBookHistory(): boolean {
+    const filePath = this.config.bookHistoryPath;
+    if (!filePath) return false;
+
+    const result = saveStateFile(
+      filePath,
+      getMarketDataStore().exportHistory(),
+    );
+    if (!result.ok) {
+      console.warn(`⚠️ [State] Failed to save ${filePath}: ${result.error}`);
+      return false;
+    }
+    return true;
+  }
+
+  /**
+   * Restore open positions and tracked GTC orders from STATE_FILE_PATH
+   *
+   * @param heldTokenIds - Tokens currently held in the wallet. When provided,
+   *   saved positions are reconciled against it and only still-held ones are
+   *   re-armed. Omit when the wallet positions could not be fetched.
+   */
+  loadState(heldTokenIds?: Set<string>): boolean {
+    const filePath = this.config.stateFilePath;
+    if (!filePath) return false;
+
+    const result = loadStateFile<EngineStateSnapshot>(filePath);
+    if (!result.ok) {
+      console.warn(`⚠️ [State] Failed to load ${filePath}: ${result.error}`);
+      return false;
+    }
+    if (!result.payload) return false;
+
+    const saved = result.payload.positions ?? [];
+    const rearmed = this.positionManager.importState(saved, heldTokenIds);
+    const orders = gtcOrderTracker.importOrders(result.payload.gtcOrders ?? []);
+    // Only alerts still configured - removing one from PRICE_ALERTS drops it
+    const alertManager = getPriceAlertManager();
+    const configuredAlerts = new Set(alertManager.list().map((a) => a.id));
+    alertManager.importState(
+      (result.payload.priceAlerts ?? []).filter((a) =>
+        configuredAlerts.has(a.id),
+      ),
+    );
+    // Only triggers still configured are restored
+    getImbalanceTriggerManager().importState(
+      result.payload.imbalanceTriggers ?? [],
+    );
+    getTcaJournal().importState(result.payload.tca ?? []);
+    const dropped =
+      saved.filter((p) => p.state !== "CLOSED").length - rearmed.length;
+    const ageMin = result.savedAt
+      ? Math.round((Date.now() - result.savedAt) / 60000)
+      : 0;
+
+    console.log(
+      `💾 [State] Restored ${rearmed.length} position(s), ${orders} GTC order(s) from ${filePath} (saved ${ageMin}m ago)`,
+    );
+    if (dropped > 0) {
+      console.log(
+        `💾 [State] Dropped ${dropped} saved position(s) no longer held in wallet`,
+      );
+    }
+    if (!heldTokenIds && rearmed.length > 0) {
+      console.warn(
+        `⚠️ [State] Wallet positions unavailable - re-armed without reconciliation`,
+      );
+    }
+
+    if (rearmed.length > 0) {
+      console.log(
+        JSON.stringify({
+          event: "PROTECTIONS_REARMED",
+          timestamp: new Date().toISOString(),
+          reconciled: !!heldTokenIds,
+          dropped,
+          protections: rearmed.map((p) => ({
+            positionId: p.id,
+            tokenId: p.tokenId,
+            side: p.side,
+            state: p.state,
+            entryPriceCents: p.entryPriceCents,
+            takeProfitPriceCents: p.takeProfitPriceCents,
+            hedgeTriggerPriceCents: p.hedgeTriggerPriceCents,
+            hardExitPriceCents: p.hardExitPriceCents,
+          })),
+        }),
+      );
+      for (const p of rearmed) {
+        console.log(
+          `🛡️ [State] Re-armed ${p.side} ${p.tokenId.slice(0, 12)}... | TP ${p.takeProfitPriceCents.toFixed(0)}¢ | Hedge ${p.hedgeTriggerPriceCents.toFixed(0)}¢ | Stop ${p.hardExitPriceCents.toFixed(0)}¢`,
+        );
+      }
+    }
+    return true;
+  }
+
   /**
    * Sleep helper
    */
@@ -3542,6 +4921,7 @@ export class ChurnEngine {
       logger: this.logger,
       waitForWhaleSignal: this.waitForWhaleSignalDiag.bind(this),
       runMarketScan: this.runMarketScanDiag.bind(this),
+      getScanCandidates: this.getScanCandidatesDiag.bind(this),
       getMarketData: this.getMarketDataDiag.bind(this),
     };
   }
@@ -3634,6 +5014,77 @@ export class ChurnEngine {
     };
   }
 
+  /**
+   * Check the books of the scanner's active markets in parallel
+   * (SCAN_CONCURRENCY fetches in flight), streaming candidates as found
+   */
+  async scanMarkets(
+    filters: ScanFilters = {},
+    options: Pick<ConcurrentScanOptions, "onCandidate" | "onProgress"> = {},
+  ): Promise<ScanSummary> {
+    const client = this.client;
+    if (!client) {
+      throw new Error("scanMarkets() called before initialization");
+    }
+    const staleness = getMarketStalenessIndex();
+    const markets = (await this.volumeScanner.scanActiveMarkets()).filter(
+      (m) => !staleness?.isExcluded(m.tokenId),
+    );
+    const summary = await scanMarketsConcurrently(
+      markets.map((m) => ({
+        tokenId: m.tokenId,
+        marketId: m.marketId,
+        question: m.question,
+      })),
+      async (tokenId) => {
+        try {
+          const book = await client.getOrderBook(tokenId);
+          const { bestBid, bestAsk } = getBestPricesFromRaw(book);
+          if (bestBid !== null && bestAsk !== null) {
+            staleness?.observeQuote(tokenId, bestBid, bestAsk);
+          }
+          return book;
+        } catch (err) {
+          const msg = err instanceof Error ? err.message : String(err);
+          if (isDelistedError(msg)) staleness?.markDelisted(tokenId, msg);
+          throw err;
+        }
+      },
+      filters,
+      { ...options, concurrency: this.config.scanConcurrency },
+    );
+    console.log(`🔍 [Scan] ${formatScanSummary(summary)}`);
+    return summary;
+  }
+
+  /**
+   * Scan candidates with healthy books, tightest spread first (for
+   * diagnostic mode)
+   */
+  private async getScanCandidatesDiag(): Promise<
+    Array<{
+      tokenId: string;
+      marketId?: string;
+      outcomeLabel?: string;
+      price?: number;
+    }>
+  > {
+    if (!this.config.scanActiveMarkets) {
+      console.log("⚠️ Market scanner is disabled (SCAN_ACTIVE_MARKETS=false)");
+      return [];
+    }
+
+    const summary = await this.scanMarkets();
+    return [...summary.candidates]
+      .sort((a, b) => a.spreadCents - b.spreadCents)
+      .map((c) => ({
+        tokenId: c.tokenId,
+        marketId: c.marketId,
+        outcomeLabel: "YES", // Volume scanner tracks the YES token
+        price: c.midPriceCents / 100,
+      }));
+  }
+
   /**
    * Get market data for a token (for diagnostic mode)
    */
@@ -3660,6 +5111,490 @@ export class ChurnEngine {
     };
   }
 
+  /**
+   * Per-strategy capital allocations (empty when STRATEGY_WEIGHTS is unset)
+   *
+   * @param effectiveBankroll - Spendable USDC after reserves
+   */
+  getAllocations(effectiveBankroll: number): StrategyAllocation[] {
+    const allocator = getCapitalAllocator();
+    if (!allocator?.isEnabled()) return [];
+    return allocator.getAllocations(
+      effectiveBankroll + this.positionManager.getTotalDeployedUsd(),
+      this.positionManager.getDeployedUsdByStrategy(),
+    );
+  }
+
+  /**
+   * Effective per-trade cap and the realized P&L behind it (the cap is
+   * MAX_TRADE_USD unless COMPOUND_SCHEDULE is set)
+   */
+  getRiskStatus(): CompoundingStatus {
+    return (
+      getProfitCompounder()?.getStatus() ?? {
+        enabled: false,
+        realizedPnlUsd: 0,
+        baseCapUsd: this.config.maxTradeUsd,
+        capUsd: this.config.maxTradeUsd,
+        nextStep: null,
+      }
+    );
+  }
+
+  /**
+   * Sell or redeem every wallet position worth less than DUST_MAX_USD,
+   * except tokens the bot is managing
+   * @returns The cleanup report, or null if it couldn't run
+   */
+  async cleanupDust(): Promise<DustCleanupReport | null> {
+    if (!this.dustCleaner) return null;
+    if (!this.config.liveTradingEnabled) {
+      console.log("🧹 [Dust] Skipping cleanup (simulation mode)");
+      return null;
+    }
+
+    const managed = new Set(
+      this.positionManager.getOpenPositions().map((p) => p.tokenId),
+    );
+    const report = await this.dustCleaner.cleanup(managed);
+    if (!report || report.items.length === 0) return report;
+
+    if (report.sold > 0) getBalanceWatcher()?.journal({ kind: "EXIT" });
+    if (report.redeemed > 0) getBalanceWatcher()?.journal({ kind: "REDEEM" });
+    invalidatePositions();
+    this.balanceCache?.forceRefresh().catch(() => {});
+
+    const summary = `${report.sold} sold, ${report.redeemed} redeemed, ${report.failed} failed - recovered $${report.recoveredUsd.toFixed(2)}`;
+    console.log(`🧹 [Dust] ${summary}`);
+    for (const item of report.items.filter((i) => i.action === "FAILED")) {
+      console.log(
+        `   ↳ ${item.id.slice(0, 16)}... ($${item.valueUsd.toFixed(2)}): ${item.reason ?? "unknown"}`,
+      );
+    }
+    if (isTelegramEnabled() && report.recoveredUsd > 0) {
+      sendTelegram("🧹 Dust Cleanup", summary).catch(() => {});
+    }
+    return report;
+  }
+
+  /**
+   * Queue an entry the position limit rejected (SIGNAL_QUEUE_MAX)
+   */
+  private queueSignal(
+    tokenId: string,
+    marketId: string | undefined,
+    strategy: EntryStrategy,
+    reason: string | undefined,
+  ): void {
+    if (!isPositionLimitReason(reason)) return;
+    const queue = getSignalQueue();
+    if (queue?.enqueue({ tokenId, marketId, strategy })) {
+      console.log(
+        `📥 [Queue] ${tokenId.slice(0, 12)}... waiting for a free slot (${queue.size()} queued)`,
+      );
+    }
+  }
+
+  /**
+   * Enter queued signals (SIGNAL_QUEUE_MAX), oldest first, one per free
+   * position slot. Each is checked again like a new signal.
+   */
+  private async drainSignalQueue(usdcBalance: number): Promise<void> {
+    const queue = getSignalQueue();
+    if (!queue?.isEnabled() || queue.size() === 0) return;
+    const held = this.positionManager
+      .getOpenPositions()
+      .filter((p) => !p.shadow).length;
+
+    // Signals beaten to a slot go back together, keeping their order
+    const beaten: QueuedSignal[] = [];
+    for (const signal of queue.take(this.config.maxOpenPositionsTotal - held)) {
+      const label = `${signal.tokenId.slice(0, 12)}...`;
+      const waitedSec = ((Date.now() - signal.queuedAt) / 1000).toFixed(0);
+      try {
+        const fetchResult = await this.fetchTokenMarketDataWithReason(
+          signal.tokenId,
+        );
+        if (!fetchResult.ok) {
+          console.log(
+            `❌ [Queue] ${label} dropped after ${waitedSec}s - no market data (${fetchResult.reason})`,
+          );
+          continue;
+        }
+        const result = await this.executionEngine.processEntry(
+          signal.tokenId,
+          fetchResult.data,
+          usdcBalance,
+          signal.strategy === "scan",
+        );
+        if (result.success) {
+          this.diagnostics.entrySuccesses++;
+          console.log(
+            `✅ [Queue] SUCCESS: Entered queued ${signal.strategy} signal ${label} after ${waitedSec}s`,
+          );
+        } else if (isPositionLimitReason(result.reason)) {
+          beaten.push(signal);
+        } else {
+          console.log(
+            `❌ [Queue] ${label} dropped after ${waitedSec}s - ${result.reason}`,
+          );
+        }
+      } catch (err) {
+        console.warn(
+          `⚠️ [Queue] Entry failed for ${label}: ${err instanceof Error ? err.message : err}`,
+        );
+      }
+    }
+    queue.restore(beaten);
+  }
+
+  /**
+   * What each scheduled job (JOB_SCHEDULE) does
+   */
+  private registerJobs(jobs: JobScheduler): void {
+    jobs.setHandler("rebalance", async () => {
+      const targets = getHedgeTargets();
+      const count = this.positionManager
+        .getOpenPositions()
+        .filter((p) => targets?.targetFor(p) !== undefined).length;
+      this.executionEngine.requestHedgeRebalance();
+      return `${count} position(s) with a hedge target queued`;
+    });
+    jobs.setHandler("dust", async () => {
+      const report = await this.cleanupDust();
+      if (!report) return "skipped";
+      return `${report.items.length} dust position(s), $${report.recoveredUsd.toFixed(2)} recovered`;
+    });
+    jobs.setHandler("cancel-stale", async () => {
+      const result = await this.orderSweeper?.sweep();
+      if (!result) return "skipped (sweep already running)";
+      return `${result.cancelled}/${result.scanned} order(s) cancelled`;
+    });
+    jobs.setHandler("report", () => this.sendDailyReport());
+  }
+
+  /**
+   * The "report" job: P&L, trades, open positions and the last day's
+   * execution costs, to the log and Telegram
+   */
+  private async sendDailyReport(): Promise<string> {
+    const metrics = this.evTracker.getMetrics();
+    const open = this.positionManager.getOpenPositions();
+    const deployedUsd = open.reduce((sum, p) => sum + p.entrySizeUsd, 0);
+    const pnlSign = metrics.totalPnlUsd >= 0 ? "+" : "";
+
+    const lines = [
+      `P&L: ${pnlSign}$${metrics.totalPnlUsd.toFixed(2)} | Trades: ${metrics.totalTrades} | Win: ${(metrics.winRate * 100).toFixed(0)}%`,
+      `Open positions: ${open.length} ($${deployedUsd.toFixed(2)} deployed)`,
+    ];
+    for (const tca of this.getTcaSummary(Date.now() - 24 * 60 * 60 * 1000)) {
+      lines.push(
+        `${tca.strategy}: ${tca.count} execution(s), $${tca.notionalUsd.toFixed(2)}, slippage ${tca.avgSlippageBps.toFixed(1)}bps, fees $${tca.feesUsd.toFixed(2)}`,
+      );
+    }
+
+    console.log(`📅 [Report] ${lines.join("\n   ")}`);
+    if (isTelegramEnabled()) {
+      await sendTelegram("📅 Daily Report", lines.join("\n"));
+    }
+    return lines[0];
+  }
+
+  /**
+   * Per-strategy execution cost (TCA) for executions completed since
+   * `since`, most notional first
+   */
+  getTcaSummary(since?: number): TcaSummary[] {
+    return getTcaJournal().summarize({ since });
+  }
+
+  /**
+   * Fill price vs the best quote at submission, per strategy and order
+   * type (positive = filled better than quoted)
+   */
+  getPriceImprovement(): PriceImprovementStats[] {
+    return getFillEnricher()?.getPriceImprovement() ?? [];
+  }
+
+  /**
+   * Spread capture, adverse selection and inventory turnover per quoted
+   * market (maker fills), best net first
+   */
+  getQuoteReport(): QuoteMarketReport[] {
+    return buildQuoteReport(getFillJournal().getFills());
+  }
+
+  /**
+   * Per-market last trade, quote update rate and liveness, most stale first
+   *
+   * @param statuses - Only these statuses (default: all)
+   */
+  getStalenessReport(statuses?: MarketLiveness[]): MarketStalenessEntry[] {
+    return getMarketStalenessIndex()?.getReport(statuses) ?? [];
+  }
+
+  /**
+   * Whale wallets ranked by the results of the trades we followed (best
+   * first)
+   */
+  getWhaleRank(limit?: number): WhaleScore[] {
+    return getWhaleScorer()?.getRanking(limit) ?? [];
+  }
+
+  /**
+   * Pin whales that clear the score thresholds into the copy list
+   * (WHALE_AUTO_PROMOTE, capped by WHALE_PROMOTE_MAX)
+   */
+  /**
+   * Journal user-channel fills and check the USDC balance for changes no
+   * journaled activity explains (BALANCE_WATCH_*)
+   */
+  private watchBalances(usdcBalance: number): void {
+    const watcher = getBalanceWatcher();
+    if (!watcher?.isEnabled()) return;
+
+    const store = getWebSocketUserClient().getOrderStore();
+    for (const trade of store.getRecentTrades(100)) {
+      watcher.journal({
+        kind: "FILL",
+        id: trade.tradeId,
+        tokenId: trade.tokenId,
+        at: trade.timestamp,
+      });
+    }
+    this.alertBalanceAnomalies(watcher.observeUsdc(usdcBalance));
+  }
+
+  private alertBalanceAnomalies(anomalies: BalanceAnomalyEvent[]): void {
+    for (const a of anomalies) {
+      const isUsdc = a.asset === "USDC";
+      const asset = isUsdc ? "USDC" : `token ${a.asset.slice(0, 16)}...`;
+      const fmt = (v: number) => (isUsdc ? `$${v.toFixed(2)}` : v.toFixed(2));
+      const sign = a.delta >= 0 ? "+" : "-";
+      const change = `${fmt(a.previous)} → ${fmt(a.current)} (${sign}${fmt(Math.abs(a.delta))})`;
+      console.warn(
+        `🚨 [BalanceWatch] ${asset} changed with no journaled trade: ${change}`,
+      );
+      if (isTelegramEnabled()) {
+        sendTelegram(
+          "🚨 Unexplained Balance Change",
+          `${asset}: ${change}\nNo fill, exit, redemption or swap explains it - check the wallet.`,
+        ).catch(() => {});
+      }
+    }
+  }
+
+  private async checkOverround(): Promise<void> {
+    const monitor = getOverroundMonitor();
+    if (!monitor) return;
+    for (const eventId of monitor.getEventIds()) {
+      const view = await fetchEventView(eventId);
+      if (!view) continue;
+      const alert = monitor.evaluate(view);
+      if (alert) this.alertOverround(alert);
+    }
+  }
+
+  private alertOverround(alert: OverroundAlertEvent): void {
+    const fmt = (v: number | null) => (v === null ? "n/a" : v.toFixed(3));
+    const sums = `asks ${fmt(alert.sumAsks)} | bids ${fmt(alert.sumBids)} | mids ${fmt(alert.sumMids)}`;
+    console.warn(`⚖️ [Overround] ${alert.flag} on "${alert.title}": ${sums}`);
+    if (isTelegramEnabled()) {
+      sendTelegram(
+        `⚖️ ${alert.flag}`,
+        `${alert.title} (event ${alert.eventId})\n${sums}`,
+      ).catch(() => {});
+    }
+  }
+
+  private async checkPriceAlerts(): Promise<void> {
+    const manager = getPriceAlertManager();
+    const tokens = manager.getWatchedTokens();
+    if (tokens.length === 0 || !this.marketDataFacade) return;
+
+    let fired = false;
+    for (const tokenId of tokens) {
+      const state = await this.marketDataFacade.getOrderbookState(tokenId);
+      if (!state) continue;
+      for (const alert of manager.check(tokenId, state.midPriceCents / 100)) {
+        this.alertPrice(alert);
+        fired = true;
+      }
+    }
+    // Persist right away so a fired one-shot alert stays done
+    if (fired) this.saveState();
+  }
+
+  private checkImbalanceTriggers(): void {
+    const triggers = getImbalanceTriggerManager();
+    const tokens = triggers.getWatchedTokens();
+    if (tokens.length === 0) return;
+
+    // Triggers only see books that stream in
+    try {
+      const wsClient = getWebSocketMarketClient();
+      if (wsClient.isConnected()) {
+        const subscribed = new Set(wsClient.getSubscriptions());
+        const missing = tokens.filter((id) => !subscribed.has(id));
+        if (missing.length > 0) wsClient.subscribe(missing);
+      }
+    } catch {
+      // WebSocket not available yet - retried next cycle
+    }
+    triggers.tick();
+  }
+
+  private alertImbalance(e: ImbalanceTriggerEvent): void {
+    const heavy = e.side === "BID" ? e.bidSize : e.askSize;
+    const light = e.side === "BID" ? e.askSize : e.bidSize;
+    const msg = `${e.tokenId.slice(0, 16)}... ${e.side} ${heavy.toFixed(0)} vs ${light.toFixed(0)} shares near ${(e.mid * 100).toFixed(1)}¢ (${e.ratio ?? "∞"}x >= ${e.threshold}x for ${e.heldMs}ms)`;
+    console.log(`⚖️ [ImbalanceTrigger] ${msg}`);
+    if (isTelegramEnabled()) {
+      sendTelegram("⚖️ Imbalance Trigger", msg).catch(() => {});
+    }
+    // Persist right away so a fired one-shot trigger stays done
+    this.saveState();
+  }
+
+  private alertPrice(alert: PriceAlertEvent): void {
+    const dir = alert.direction === "ABOVE" ? "above" : "below";
+    const msg = `${alert.tokenId.slice(0, 16)}... at ${(alert.price * 100).toFixed(1)}¢ (${dir} ${(alert.level * 100).toFixed(1)}¢)`;
+    console.log(`🔔 [PriceAlert] ${msg}`);
+    if (isTelegramEnabled()) {
+      sendTelegram("🔔 Price Alert", msg).catch(() => {});
+    }
+  }
+
+  private async enrichFills(now: number): Promise<void> {
+    const enricher = getFillEnricher();
+    if (!enricher) return;
+
+    const wsUserClient = getWebSocketUserClient();
+    for (const trade of wsUserClient.getOrderStore().getRecentTrades(100)) {
+      const fill = enricher.record({
+        tradeId: trade.tradeId,
+        orderIds: [trade.orderId, trade.makerOrderId ?? ""].filter(Boolean),
+        tokenId: trade.tokenId,
+        side: trade.side,
+        price: trade.price,
+        shares: trade.size,
+        timestamp: trade.timestamp,
+        source: "WS",
+      });
+      if (fill) {
+        // Matched on the maker order ID = our order rested
+        const liquidity = !fill.orderId
+          ? undefined
+          : fill.orderId === trade.makerOrderId
+            ? "MAKER"
+            : "TAKER";
+        this.journalFill(fill, liquidity);
+      }
+    }
+
+    if (
+      wsUserClient.isConnected() ||
+      !this.client ||
+      !this.config.liveTradingEnabled ||
+      now - this.lastFillPollTime < this.FILL_POLL_INTERVAL_MS
+    ) {
+      return;
+    }
+    const after = Math.floor(this.lastFillPollTime / 1000);
+    this.lastFillPollTime = now;
+    try {
+      const trades = await this.client.getTrades({ after: String(after) });
+      for (const trade of trades) {
+        const fill = enricher.record({
+          tradeId: trade.id,
+          orderIds: [
+            trade.taker_order_id,
+            ...(trade.maker_orders ?? []).map((m) => m.order_id),
+          ],
+          tokenId: trade.asset_id,
+          side: String(trade.side) === "BUY" ? "BUY" : "SELL",
+          price: parseFloat(trade.price),
+          shares: parseFloat(trade.size),
+          timestamp: Number(trade.match_time) * 1000,
+          source: "POLL",
+        });
+        if (fill) {
+          const liquidity =
+            String(trade.trader_side) === "MAKER" ? "MAKER" : "TAKER";
+          this.journalFill(fill, liquidity);
+        }
+      }
+    } catch (err) {
+      console.warn(
+        `⚠️ [Fills] Trade poll failed: ${err instanceof Error ? err.message : err}`,
+      );
+    }
+  }
+
+  private journalFill(
+    fill: OrderFillEvent,
+    liquidity?: "MAKER" | "TAKER",
+  ): void {
+    this.logFill(fill);
+    // The mid as the fill arrives is the quote report's spread reference
+    const midAtFill = getMarketDataStore().get(fill.tokenId)?.mid;
+    getFillJournal().add({
+      tradeId: fill.tradeId,
+      tokenId: fill.tokenId,
+      side: fill.side,
+      price: fill.price,
+      shares: fill.shares,
+      timestamp: Date.parse(fill.timestamp),
+      source: fill.source,
+      ...(liquidity ? { liquidity } : {}),
+      ...(midAtFill !== undefined ? { midAtFill } : {}),
+    });
+  }
+
+  private markOutQuotes(now: number): void {
+    const store = getMarketDataStore();
+    annotateMarkouts(
+      getFillJournal(),
+      (tokenId, at) => {
+        const book = store.getOrderbookAt(tokenId, at);
+        if (!book?.bids.length || !book.asks.length) return null;
+        return (book.bids[0].price + book.asks[0].price) / 2;
+      },
+      (this.config.quoteMarkoutSeconds ?? 60) * 1000,
+      now,
+    );
+  }
+
+  private logFill(fill: OrderFillEvent): void {
+    const slippage =
+      fill.slippageCents !== null
+        ? `, ${fill.slippageCents >= 0 ? "+" : ""}${fill.slippageCents.toFixed(1)}¢ vs intended`
+        : "";
+    console.log(
+      `💰 [Fill] ${fill.strategy ?? "untracked"} ${fill.side} ${fill.shares.toFixed(2)} @ ${(fill.price * 100).toFixed(1)}¢${slippage} → holding ${fill.positionShares.toFixed(2)} (${fill.tokenId.slice(0, 16)}...)`,
+    );
+  }
+
+  private promoteWhales(): void {
+    const promoted = getWhaleScorer()?.promote() ?? [];
+    for (const wallet of promoted) {
+      this.biasAccumulator.pinWallet(wallet);
+      const score = getWhaleScorer()?.getScore(wallet);
+      console.log(
+        `📌 [WhaleRank] Promoted ${labelAddress(wallet)} to copy list (${score?.trades ?? 0} trades, ${((score?.hitRate ?? 0) * 100).toFixed(0)}% hit)`,
+      );
+    }
+  }
+
+  /**
+   * Shadow vs live performance lines (empty when no strategy is in shadow)
+   */
+  getShadowReport(): string[] {
+    const tracker = getShadowTracker();
+    if (!tracker?.isEnabled()) return [];
+    return tracker.formatReport();
+  }
+
   /**
    * Get the CLOB client (for diagnostic mode)
    */
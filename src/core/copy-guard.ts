@@ -0,0 +1,196 @@
+/**
+ * Copy Guard - Don't buy the top of a whale's own price impact
+ *
+ * A large whale buy moves the book; copying it instantly often means paying
+ * the price the whale just pushed up. Before a whale entry is sent, the
+ * whale's most recent fill on the token is checked against:
+ *
+ * - delay:  wait this long after the whale's fill before copying (the
+ *           entry is retried on later cycles while the bias stays active)
+ * - drift:  max cents our entry price may sit above the whale's fill
+ * - moved:  skip while the book mid has already moved this many cents
+ *           above the whale's fill
+ *
+ * Defaults apply to every whale; individual targets can override them:
+ *
+ *   COPY_DELAY_MS=2000
+ *   COPY_MAX_DRIFT_CENTS=2
+ *   COPY_SKIP_IF_MOVED_CENTS=3
+ *   COPY_TARGET_RULES="0xabc...:delay=0,drift=1;0xdef...:moved=5"
+ *
+ * A value of 0 disables that check.
+ */
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface CopyRule {
+  /** Minimum time after the whale's fill before copying (ms) */
+  delayMs: number;
+  /** Max cents our entry price may exceed the whale's fill */
+  maxDriftCents: number;
+  /** Skip when the mid is this many cents above the whale's fill */
+  maxMoveCents: number;
+}
+
+export const DEFAULT_COPY_RULE: CopyRule = {
+  delayMs: 0,
+  maxDriftCents: 0,
+  maxMoveCents: 0,
+};
+
+/** The whale fill an entry would copy */
+export interface WhaleFill {
+  wallet: string;
+  /** Fill price in [0,1] (undefined when the API didn't report one) */
+  price?: number;
+  timestamp: number;
+}
+
+const RULE_KEYS: Record<string, keyof CopyRule> = {
+  delay: "delayMs",
+  drift: "maxDriftCents",
+  moved: "maxMoveCents",
+};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONFIG PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "0xabc:delay=0,drift=1;0xdef:moved=5" into lowercase wallet ->
+ * rule overrides. Unknown keys and non-numeric values are skipped.
+ */
+export function parseCopyTargetRules(
+  spec: string,
+): Map<string, Partial<CopyRule>> {
+  const rules = new Map<string, Partial<CopyRule>>();
+
+  for (const entry of spec.split(";")) {
+    const sep = entry.indexOf(":");
+    if (sep === -1) continue;
+
+    const wallet = entry.slice(0, sep).trim().toLowerCase();
+    if (!/^0x[0-9a-f]{40}$/.test(wallet)) continue;
+
+    const rule: Partial<CopyRule> = {};
+    for (const pair of entry.slice(sep + 1).split(",")) {
+      const [rawKey, rawValue] = pair.split("=");
+      const key = RULE_KEYS[rawKey?.trim().toLowerCase() ?? ""];
+      const value = Number(rawValue?.trim());
+      if (key && rawValue?.trim() && Number.isFinite(value) && value >= 0) {
+        rule[key] = value;
+      }
+    }
+    if (Object.keys(rule).length > 0) rules.set(wallet, rule);
+  }
+
+  return rules;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// COPY GUARD
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class CopyGuard {
+  private readonly defaults: CopyRule;
+
+  constructor(
+    defaults: Partial<CopyRule> = {},
+    private readonly targets: Map<string, Partial<CopyRule>> = new Map(),
+  ) {
+    const d = DEFAULT_COPY_RULE;
+    this.defaults = {
+      delayMs: defaults.delayMs ?? d.delayMs,
+      maxDriftCents: defaults.maxDriftCents ?? d.maxDriftCents,
+      maxMoveCents: defaults.maxMoveCents ?? d.maxMoveCents,
+    };
+  }
+
+  /**
+   * Whether any check is configured (defaults or per-target)
+   */
+  isEnabled(): boolean {
+    const { delayMs, maxDriftCents, maxMoveCents } = this.defaults;
+    return (
+      delayMs > 0 ||
+      maxDriftCents > 0 ||
+      maxMoveCents > 0 ||
+      this.targets.size > 0
+    );
+  }
+
+  /**
+   * Effective rule for a whale (target overrides on top of defaults)
+   */
+  getRule(wallet: string): CopyRule {
+    const override = this.targets.get(wallet.toLowerCase()) ?? {};
+    return {
+      delayMs: override.delayMs ?? this.defaults.delayMs,
+      maxDriftCents: override.maxDriftCents ?? this.defaults.maxDriftCents,
+      maxMoveCents: override.maxMoveCents ?? this.defaults.maxMoveCents,
+    };
+  }
+
+  /**
+   * Check a whale copy before it is sent
+   *
+   * @param fill - The whale fill being copied
+   * @param entryPriceCents - Price our entry would pay
+   * @param midPriceCents - Current book mid
+   * @returns Block reason, or null if the copy may go ahead
+   */
+  check(
+    fill: WhaleFill,
+    entryPriceCents: number,
+    midPriceCents: number,
+    now = Date.now(),
+  ): string | null {
+    const rule = this.getRule(fill.wallet);
+
+    const waitMs = fill.timestamp + rule.delayMs - now;
+    if (rule.delayMs > 0 && waitMs > 0) {
+      return `COPY_DELAY (${(waitMs / 1000).toFixed(1)}s left)`;
+    }
+
+    if (fill.price === undefined || fill.price <= 0) return null;
+    const whaleCents = fill.price * 100;
+
+    const driftCents = entryPriceCents - whaleCents;
+    if (rule.maxDriftCents > 0 && driftCents > rule.maxDriftCents) {
+      return `COPY_DRIFT (${entryPriceCents.toFixed(1)}¢ vs whale ${whaleCents.toFixed(1)}¢ > ${rule.maxDriftCents}¢)`;
+    }
+
+    const movedCents = midPriceCents - whaleCents;
+    if (rule.maxMoveCents > 0 && movedCents > rule.maxMoveCents) {
+      return `COPY_BOOK_MOVED (mid ${midPriceCents.toFixed(1)}¢ vs whale ${whaleCents.toFixed(1)}¢ > ${rule.maxMoveCents}¢)`;
+    }
+
+    return null;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: CopyGuard | null = null;
+
+/**
+ * Initialize the global copy guard
+ */
+export function initCopyGuard(
+  defaults?: Partial<CopyRule>,
+  targets?: Map<string, Partial<CopyRule>>,
+): CopyGuard {
+  instance = new CopyGuard(defaults, targets);
+  return instance;
+}
+
+/**
+ * Get the global copy guard (null if not initialized)
+ */
+export function getCopyGuard(): CopyGuard | null {
+  return instance;
+}
@@ -0,0 +1,299 @@
+/**
+ * Correlation Detector - Cross-market correlated move detection
+ *
+ * Related markets tend to move together: the outcomes of one event (which
+ * move inversely), or "Fed cut in March" vs "Fed cut in 2025". When one leg
+ * moves sharply and the others haven't caught up yet, the laggards are a
+ * relative-value entry candidate.
+ *
+ * Groups are configured as token IDs; a leading "-" marks a leg that moves
+ * inversely to the group (e.g. the other outcome of the same event):
+ *
+ *   CORRELATION_GROUPS="fed:tokA,tokB;election:tokC,-tokD"
+ *
+ * A CORRELATED_MOVE event is emitted (JSON log + listeners) when a leg moves
+ * at least moveCents within the window while another leg has moved less than
+ * lagRatio of that in the expected direction. Prices come from the
+ * trade-print stream.
+ */
+
+import type { MarketDataStore, TradePrint } from "../lib/market-data-store";
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface CorrelationLeg {
+  tokenId: string;
+  /** Leg moves opposite to the group (e.g. complementary outcome) */
+  inverse: boolean;
+}
+
+export interface CorrelationGroup {
+  name: string;
+  legs: CorrelationLeg[];
+}
+
+export interface CorrelationDetectorConfig {
+  /** Window over which moves are measured (ms) */
+  windowMs: number;
+  /** Minimum leader move to trigger (cents) */
+  moveCents: number;
+  /** A leg lags if it moved less than this fraction of the leader's move */
+  lagRatio: number;
+  /** Minimum time between events for the same group + leader (ms) */
+  cooldownMs: number;
+}
+
+export const DEFAULT_CORRELATION_CONFIG: CorrelationDetectorConfig = {
+  windowMs: 60_000,
+  moveCents: 5,
+  lagRatio: 0.3,
+  cooldownMs: 60_000,
+};
+
+export interface LaggingLeg {
+  tokenId: string;
+  /** Actual move in the window (cents, signed) */
+  moveCents: number;
+  /** Move the leg would show if it tracked the leader (cents, signed) */
+  expectedMoveCents: number;
+}
+
+export interface CorrelatedMoveEvent {
+  event: "CORRELATED_MOVE";
+  group: string;
+  leaderTokenId: string;
+  leaderMoveCents: number;
+  laggards: LaggingLeg[];
+  windowMs: number;
+  timestamp: string;
+}
+
+export type CorrelatedMoveListener = (event: CorrelatedMoveEvent) => void;
+
+interface PriceSample {
+  priceCents: number;
+  timestamp: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONFIG PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "name:tokA,-tokB;name2:tokC,tokD" into groups. Groups with fewer
+ * than two legs are dropped.
+ */
+export function parseCorrelationGroups(spec: string): CorrelationGroup[] {
+  const groups: CorrelationGroup[] = [];
+
+  for (const entry of spec.split(";")) {
+    const sep = entry.indexOf(":");
+    if (sep === -1) continue;
+
+    const name = entry.slice(0, sep).trim();
+    const legs = entry
+      .slice(sep + 1)
+      .split(",")
+      .map((raw) => raw.trim())
+      .filter((raw) => raw.length > 0 && raw !== "-")
+      .map((raw) => ({
+        tokenId: raw.startsWith("-") ? raw.slice(1) : raw,
+        inverse: raw.startsWith("-"),
+      }));
+
+    if (name && legs.length >= 2) groups.push({ name, legs });
+  }
+
+  return groups;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CORRELATION DETECTOR
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class CorrelationDetector {
+  private samples = new Map<string, PriceSample[]>();
+  private groupsByToken = new Map<string, CorrelationGroup[]>();
+  private lastEventAt = new Map<string, number>();
+  private listeners = new Set<CorrelatedMoveListener>();
+  private readonly config: CorrelationDetectorConfig;
+
+  constructor(
+    readonly groups: CorrelationGroup[],
+    config: Partial<CorrelationDetectorConfig> = {},
+  ) {
+    const d = DEFAULT_CORRELATION_CONFIG;
+    this.config = {
+      windowMs: config.windowMs ?? d.windowMs,
+      moveCents: config.moveCents ?? d.moveCents,
+      lagRatio: config.lagRatio ?? d.lagRatio,
+      cooldownMs: config.cooldownMs ?? d.cooldownMs,
+    };
+    for (const group of groups) {
+      for (const leg of group.legs) {
+        const list = this.groupsByToken.get(leg.tokenId) ?? [];
+        list.push(group);
+        this.groupsByToken.set(leg.tokenId, list);
+      }
+    }
+  }
+
+  /**
+   * Record a price observation (decimal 0-1) and check the token's groups
+   * @returns Events emitted by this observation
+   */
+  record(
+    tokenId: string,
+    price: number,
+    timestamp = Date.now(),
+  ): CorrelatedMoveEvent[] {
+    const groups = this.groupsByToken.get(tokenId);
+    if (!groups) return [];
+
+    let series = this.samples.get(tokenId);
+    if (!series) {
+      series = [];
+      this.samples.set(tokenId, series);
+    }
+    series.push({ priceCents: price * 100, timestamp });
+
+    // Keep one sample at/before the window start as the move baseline
+    const cutoff = timestamp - this.config.windowMs;
+    while (series.length > 1 && series[1].timestamp <= cutoff) {
+      series.shift();
+    }
+
+    const events: CorrelatedMoveEvent[] = [];
+    for (const group of groups) {
+      const event = this.checkGroup(group, tokenId, timestamp);
+      if (event) events.push(event);
+    }
+    return events;
+  }
+
+  /**
+   * Price change over the window in cents (null if the price is unknown)
+   */
+  getMoveCents(tokenId: string, now = Date.now()): number | null {
+    const series = this.samples.get(tokenId);
+    if (!series || series.length === 0) return null;
+
+    const cutoff = now - this.config.windowMs;
+    let baseline = series[0];
+    for (const sample of series) {
+      if (sample.timestamp > cutoff) break;
+      baseline = sample;
+    }
+    return series[series.length - 1].priceCents - baseline.priceCents;
+  }
+
+  /**
+   * Subscribe to correlated move events
+   * @returns Unsubscribe function
+   */
+  onMove(listener: CorrelatedMoveListener): () => void {
+    this.listeners.add(listener);
+    return () => this.listeners.delete(listener);
+  }
+
+  /**
+   * Feed the detector from a market data store's trade prints
+   * @returns Unsubscribe function
+   */
+  attach(store: MarketDataStore): () => void {
+    return store.onTrade((trade: TradePrint) => {
+      this.record(trade.tokenId, trade.price, trade.timestamp);
+    });
+  }
+
+  clear(): void {
+    this.samples.clear();
+    this.lastEventAt.clear();
+  }
+
+  private checkGroup(
+    group: CorrelationGroup,
+    leaderTokenId: string,
+    now: number,
+  ): CorrelatedMoveEvent | null {
+    const leaderMove = this.getMoveCents(leaderTokenId, now);
+    if (leaderMove === null || Math.abs(leaderMove) < this.config.moveCents) {
+      return null;
+    }
+
+    const key = `${group.name}:${leaderTokenId}`;
+    const last = this.lastEventAt.get(key);
+    if (last !== undefined && now - last < this.config.cooldownMs) return null;
+
+    const leaderLeg = group.legs.find((l) => l.tokenId === leaderTokenId)!;
+    const laggards: LaggingLeg[] = [];
+
+    for (const leg of group.legs) {
+      if (leg.tokenId === leaderTokenId) continue;
+      const move = this.getMoveCents(leg.tokenId, now);
+      if (move === null) continue; // no price yet - can't tell if it lags
+
+      const expected =
+        leg.inverse === leaderLeg.inverse ? leaderMove : -leaderMove;
+      // Progress toward the expected move, as a fraction of it
+      const followed = move / expected;
+      if (followed < this.config.lagRatio) {
+        laggards.push({
+          tokenId: leg.tokenId,
+          moveCents: move,
+          expectedMoveCents: expected,
+        });
+      }
+    }
+
+    if (laggards.length === 0) return null;
+
+    this.lastEventAt.set(key, now);
+    const event: CorrelatedMoveEvent = {
+      event: "CORRELATED_MOVE",
+      group: group.name,
+      leaderTokenId,
+      leaderMoveCents: leaderMove,
+      laggards,
+      windowMs: this.config.windowMs,
+      timestamp: new Date(now).toISOString(),
+    };
+
+    emitEvent(event);
+    for (const listener of this.listeners) {
+      try {
+        listener(event);
+      } catch {
+        // A bad listener must not break the trade stream
+      }
+    }
+    return event;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: CorrelationDetector | null = null;
+
+/**
+ * Initialize the global correlation detector
+ */
+export function initCorrelationDetector(
+  groups: CorrelationGroup[],
+  config?: Partial<CorrelationDetectorConfig>,
+): CorrelationDetector {
+  instance = new CorrelationDetector(groups, config);
+  return instance;
+}
+
+/**
+ * Get the global correlation detector (null if not initialized)
+ */
+export function getCorrelationDetector(): CorrelationDetector | null {
+  return instance;
+}
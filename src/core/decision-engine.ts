@@ -111,6 +111,14 @@ export interface ManagedPosition {
   entryPriceCents: number;
   entrySizeUsd: number;
   entryTime: number;
+  /** Age of the book data the entry was priced from (ms) */
+  entryQuoteAgeMs?: number;
+  /** Entry strategy that opened the position ("whale" / "scan") */
+  strategy?: string;
+  /** Paper position of a shadow strategy (never sent to the CLOB) */
+  shadow?: boolean;
+  /** Whale wallets whose buys the entry followed (whale scoring) */
+  whaleWallets?: string[];
 
   // Current
   currentPriceCents: number;
@@ -274,6 +282,8 @@ export class DecisionEngine {
     currentPositions: ManagedPosition[];
     effectiveBankroll: number;
     totalDeployedUsd: number;
+    /** Per-trade cap overriding maxTradeUsd (profit compounding) */
+    maxTradeUsd?: number;
   }): EntryDecision {
     const checks: EntryDecision["checks"] = {
       bias: { passed: false, value: params.bias },
@@ -388,7 +398,10 @@ export class DecisionEngine {
     }
 
     // Calculate size
-    const sizeUsd = this.calculateSize(params.effectiveBankroll);
+    const sizeUsd = this.calculateSize(
+      params.effectiveBankroll,
+      params.maxTradeUsd ?? this.config.maxTradeUsd,
+    );
 
     return {
       allowed: true,
@@ -495,7 +508,10 @@ export class DecisionEngine {
   /**
    * Calculate trade size
    */
-  private calculateSize(effectiveBankroll: number): number {
+  private calculateSize(
+    effectiveBankroll: number,
+    maxTradeUsd: number,
+  ): number {
     const fractionalSize = effectiveBankroll * this.config.tradeFraction;
     // Apply both min and max bounds:
     // - First ensure we don't go below minTradeUsd (for small bankrolls)
@@ -503,7 +519,7 @@ export class DecisionEngine {
     // If effectiveBankroll is too small to meet minTradeUsd, use fractionalSize
     // to avoid over-leveraging (can't trade more than we can afford)
     const withMinimum = Math.max(fractionalSize, Math.min(this.config.minTradeUsd, effectiveBankroll));
-    return Math.min(withMinimum, this.config.maxTradeUsd);
+    return Math.min(withMinimum, maxTradeUsd);
   }
 
   /**
@@ -0,0 +1,158 @@
+/**
+ * Decision Log - One sequenced DECISION event per order decision
+ *
+ * The fill, TCA and risk events say what happened; none of them say why
+ * an order was (or wasn't) sent. Every accepted or rejected entry, exit
+ * and hedge emits a DECISION event carrying:
+ *
+ * - seq: strictly increasing within the run (runId), so events can be
+ *   replayed in the order the bot decided them even if sinks reorder them
+ * - the signal that drove it (whale bias, exit trigger, ...)
+ * - the book it was decided on: top of book plus a hash of the levels
+ *   held for the token, so two decisions on the same book are visibly so
+ * - each gate it went through (schedule, risk guard, allocation, ...)
+ *   and whether it passed, ending at the first that failed
+ *
+ * Events go through the event bus; route them to a file for post-trade
+ * reconstruction with EVENT_FILE_PATH + EVENT_FILE_FILTER=DECISION.
+ */
+
+import { createHash } from "crypto";
+import { emitEvent } from "../infra/event-bus";
+import {
+  getMarketDataStore,
+  type OrderbookLevel,
+} from "../lib/market-data-store";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type DecisionAction = "ENTRY" | "EXIT" | "HEDGE";
+
+export interface DecisionCheck {
+  name: string;
+  passed: boolean;
+  detail?: string;
+}
+
+export interface DecisionBook {
+  bestBidCents: number;
+  bestAskCents: number;
+  spreadCents: number;
+  source?: string;
+}
+
+export interface DecisionInput {
+  action: DecisionAction;
+  accepted: boolean;
+  reason?: string;
+  tokenId: string;
+  marketId?: string;
+  strategy?: string;
+  side?: string;
+  /** What drove the decision (bias, exit trigger, ...) */
+  signal?: Record<string, unknown>;
+  book?: DecisionBook;
+  checks: DecisionCheck[];
+  sizeUsd?: number;
+  priceCents?: number;
+}
+
+export interface DecisionEvent extends DecisionInput {
+  event: "DECISION";
+  seq: number;
+  runId: string;
+  /** Hash of the book levels held for the token (null = none held) */
+  bookHash: string | null;
+  timestamp: string;
+}
+
+/** Levels per side that go into the book hash */
+const HASHED_LEVELS = 10;
+
+/**
+ * Short, stable hash of a book's top levels
+ */
+export function hashBook(
+  bids: OrderbookLevel[],
+  asks: OrderbookLevel[],
+): string {
+  const side = (levels: OrderbookLevel[]) =>
+    levels
+      .slice(0, HASHED_LEVELS)
+      .map((l) => `${l.price}@${l.size}`)
+      .join(",");
+  return createHash("sha256")
+    .update(`${side(bids)}|${side(asks)}`)
+    .digest("hex")
+    .slice(0, 16);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DECISION LOG
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class DecisionLog {
+  private seq = 0;
+  private recent: DecisionEvent[] = [];
+
+  constructor(
+    readonly runId = new Date().toISOString(),
+    private readonly maxRecent = 200,
+  ) {}
+
+  /**
+   * Sequence, emit and keep a decision
+   */
+  record(input: DecisionInput, now = Date.now()): DecisionEvent {
+    const book = getMarketDataStore().getOrderbook(input.tokenId);
+    const event: DecisionEvent = {
+      event: "DECISION",
+      seq: ++this.seq,
+      runId: this.runId,
+      ...input,
+      bookHash: book ? hashBook(book.bids, book.asks) : null,
+      timestamp: new Date(now).toISOString(),
+    };
+    this.recent.push(event);
+    if (this.recent.length > this.maxRecent) this.recent.shift();
+    emitEvent(event);
+    return event;
+  }
+
+  /**
+   * Last sequence number issued (0 = none yet)
+   */
+  getSeq(): number {
+    return this.seq;
+  }
+
+  /**
+   * Most recent decisions, oldest first
+   */
+  getRecent(limit = this.maxRecent): DecisionEvent[] {
+    return this.recent.slice(-limit);
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: DecisionLog | null = null;
+
+/**
+ * Initialize the global decision log
+ */
+export function initDecisionLog(): DecisionLog {
+  instance = new DecisionLog();
+  return instance;
+}
+
+/**
+ * Get the global decision log (null if not initialized)
+ */
+export function getDecisionLog(): DecisionLog | null {
+  return instance;
+}
@@ -0,0 +1,172 @@
+/**
+ * Dust Cleaner - Clear out positions too small to matter
+ *
+ * Partial fills and partial exits leave tiny positions behind: a few cents
+ * of a token the bot no longer manages, cluttering the wallet and the
+ * position API. The cleaner finds wallet positions worth less than
+ * DUST_MAX_USD and:
+ *
+ * - REDEEMED: resolved markets are redeemed on-chain
+ * - SOLD:     live markets are sold with a marketable (forced FOK) order
+ * - FAILED:   the sell or redemption was rejected - usually a position
+ *             below the exchange's minimum order size, or an empty book
+ *
+ * Tokens the bot is actively managing are left alone. A cleanup can be run
+ * on demand (ChurnEngine.cleanupDust) or on a schedule:
+ *
+ *   DUST_MAX_USD=1               # positions below this are dust
+ *   DUST_CLEAN_INTERVAL_MIN=0    # 0 = on demand only
+ *
+ * Each run emits a DUST_CLEANUP event with the USDC recovered.
+ */
+
+import type { Position } from "../models";
+import type { RedeemablePosition } from "../lib/redeem";
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface DustCleanerConfig {
+  /** Positions worth less than this (USD) are dust */
+  maxValueUsd: number;
+}
+
+export const DEFAULT_DUST_CLEANER_CONFIG: DustCleanerConfig = {
+  maxValueUsd: 1,
+};
+
+/** Wallet access the cleaner needs (injected by the engine) */
+export interface DustCleanerDeps {
+  fetchPositions: () => Promise<Position[]>;
+  fetchRedeemable: () => Promise<RedeemablePosition[]>;
+  sell: (
+    position: Position,
+  ) => Promise<{ success: boolean; filledUsd?: number; reason?: string }>;
+  redeem: (
+    conditionId: string,
+  ) => Promise<{ success: boolean; error?: string }>;
+}
+
+export type DustAction = "SOLD" | "REDEEMED" | "FAILED";
+
+export interface DustCleanupItem {
+  /** Token ID (sells) or condition ID (redemptions) */
+  id: string;
+  action: DustAction;
+  valueUsd: number;
+  recoveredUsd: number;
+  reason?: string;
+}
+
+export interface DustCleanupReport {
+  items: DustCleanupItem[];
+  recoveredUsd: number;
+  sold: number;
+  redeemed: number;
+  failed: number;
+}
+
+export interface DustCleanupEvent extends DustCleanupReport {
+  event: "DUST_CLEANUP";
+  timestamp: string;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DUST CLEANER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Positions worth more than nothing but less than the dust threshold
+ */
+export function findDustPositions(
+  positions: Position[],
+  maxValueUsd: number,
+  exclude: Set<string> = new Set(),
+): Position[] {
+  return positions.filter(
+    (p) => p.size > 0 && p.value < maxValueUsd && !exclude.has(p.tokenId),
+  );
+}
+
+export class DustCleaner {
+  private readonly config: DustCleanerConfig;
+  private running = false;
+
+  constructor(
+    private readonly deps: DustCleanerDeps,
+    config: Partial<DustCleanerConfig> = {},
+  ) {
+    const d = DEFAULT_DUST_CLEANER_CONFIG;
+    this.config = {
+      maxValueUsd: config.maxValueUsd ?? d.maxValueUsd,
+    };
+  }
+
+  /**
+   * Redeem or sell every dust position. Overlapping calls are skipped
+   * (returns null).
+   *
+   * @param exclude - Token IDs the bot is managing (never touched)
+   */
+  async cleanup(
+    exclude: Set<string> = new Set(),
+  ): Promise<DustCleanupReport | null> {
+    if (this.running) return null;
+    this.running = true;
+    try {
+      const items: DustCleanupItem[] = [];
+
+      // Resolved markets first - redeeming pays out without a counterparty
+      const redeemable = await this.deps.fetchRedeemable();
+      for (const pos of redeemable) {
+        if (pos.value >= this.config.maxValueUsd) continue;
+        const result = await this.deps.redeem(pos.conditionId);
+        items.push({
+          id: pos.conditionId,
+          action: result.success ? "REDEEMED" : "FAILED",
+          valueUsd: pos.value,
+          recoveredUsd: result.success ? pos.value : 0,
+          reason: result.error,
+        });
+      }
+
+      const positions = await this.deps.fetchPositions();
+      const dust = findDustPositions(
+        positions,
+        this.config.maxValueUsd,
+        exclude,
+      );
+      for (const pos of dust) {
+        const result = await this.deps.sell(pos);
+        items.push({
+          id: pos.tokenId,
+          action: result.success ? "SOLD" : "FAILED",
+          valueUsd: pos.value,
+          recoveredUsd: result.success ? (result.filledUsd ?? pos.value) : 0,
+          reason: result.reason,
+        });
+      }
+
+      const report: DustCleanupReport = {
+        items,
+        recoveredUsd: items.reduce((sum, i) => sum + i.recoveredUsd, 0),
+        sold: items.filter((i) => i.action === "SOLD").length,
+        redeemed: items.filter((i) => i.action === "REDEEMED").length,
+        failed: items.filter((i) => i.action === "FAILED").length,
+      };
+      if (items.length > 0) {
+        const event: DustCleanupEvent = {
+          event: "DUST_CLEANUP",
+          ...report,
+          timestamp: new Date().toISOString(),
+        };
+        emitEvent(event);
+      }
+      return report;
+    } finally {
+      this.running = false;
+    }
+  }
+}
@@ -33,6 +33,8 @@ export interface TradeResult {
   pnlCents: number; // Per share
   pnlUsd: number;
   isWin: boolean;
+  /** Round-trip fees per share already deducted from pnlCents */
+  feesCents?: number;
 }
 
 /**
@@ -100,6 +102,9 @@ export function calculatePnlUsd(
 
 /**
  * Create a trade result record
+ *
+ * feesCents is the per-share trading fee for the round trip (per-market
+ * taker rate); it is deducted from P&L so EV reflects fee-paying markets.
  */
 export function createTradeResult(
   tokenId: string,
@@ -107,8 +112,10 @@ export function createTradeResult(
   entryPriceCents: number,
   exitPriceCents: number,
   sizeUsd: number,
+  feesCents: number = 0,
 ): TradeResult {
-  const pnlCents = calculatePnlCents(side, entryPriceCents, exitPriceCents);
+  const pnlCents =
+    calculatePnlCents(side, entryPriceCents, exitPriceCents) - feesCents;
   const pnlUsd = calculatePnlUsd(pnlCents, sizeUsd, entryPriceCents);
   return {
     tokenId,
@@ -119,6 +126,7 @@ export function createTradeResult(
     timestamp: Date.now(),
     pnlCents,
     pnlUsd,
+    feesCents,
     // Note: Break-even trades (pnlCents === 0) are classified as losses.
     // This is intentional - we want to incentivize actual wins, not break-evens.
     isWin: pnlCents > 0,
@@ -10,7 +10,7 @@
 import type { ClobClient } from "@polymarket/clob-client";
 import { getBalanceCache } from "../lib/balance";
 import { invalidatePositions } from "../lib/positions";
-import { getOppositeTokenId, getMarketTokenPair } from "../lib/market";
+import { getComplementQuote, getMarketTokenPair } from "../lib/market";
 import { reportError } from "../infra/github-reporter";
 import { getLatencyMonitor } from "../infra/latency-monitor";
 import {
@@ -29,8 +29,53 @@ import {
   isSnapshotHealthy,
   assertSnapshotIntegrity,
   generateAttemptId,
+  getQuoteAgeMs,
 } from "../lib/market-snapshot";
+import { POLYMARKET_WS } from "../lib/constants";
+import type { MarketSnapshot } from "../book/types";
 import { EvTracker, createTradeResult } from "./ev-tracker";
+import { scaleSizeForVolatility } from "./risk";
+import { getVolatilityEstimator } from "./volatility";
+import { getOrderFlowTracker } from "./order-flow";
+import {
+  getScheduleBlockReason,
+  type EntryStrategy,
+} from "./strategy-schedule";
+import { getCapitalAllocator } from "./capital-allocator";
+import { getShadowTracker } from "./shadow-mode";
+import { getWhaleScorer } from "./whale-scorer";
+import { getCopyGuard, type WhaleFill } from "./copy-guard";
+import { getChaseThrottle } from "./chase-throttle";
+import { getSignalEdgeTracker } from "./signal-edge";
+import { getBalanceWatcher } from "./balance-watcher";
+import { getFillEnricher, type FillSide } from "./fill-enricher";
+import { getTcaJournal } from "./tca";
+import { applyLotPolicy, getLotPolicy } from "./lot-policy";
+import {
+  BELOW_LIQUIDITY_FLOOR,
+  checkLiquidityFloor,
+  formatLiquidityFloorCheck,
+  getLiquidityFloor,
+} from "./liquidity-floor";
+import { formatOrderRace, getOrderRaceArbiter } from "./order-race";
+import { getDecisionLog, type DecisionCheck } from "./decision-log";
+import { getOrderGovernor } from "./order-governor";
+import { getMarketBlacklist } from "./market-blacklist";
+import {
+  getHedgeTargets,
+  planHedgeAdjustment,
+  type HedgeAdjustment,
+} from "./hedge-targets";
+import { getProfitCompounder } from "./profit-compounder";
+import { emitEvent } from "../infra/event-bus";
+import { checkMarketAllowed } from "../lib/order";
+import { getMarketDataStore } from "../lib/market-data-store";
+import { convertOrderSize, marketOrderAmount } from "../lib/order-size";
+import {
+  getFeeRates,
+  getCachedFeeRates,
+  estimateRoundTripFeeCents,
+} from "../lib/fee-rates";
 import {
   DecisionEngine,
   type EvMetrics,
@@ -55,6 +100,8 @@ export interface ExecutionResult {
   filledPriceCents?: number;
   reason?: string;
   pending?: boolean; // True if order is GTC and waiting for fill
+  /** Age of the book data the order was priced from (ms) */
+  quoteAgeMs?: number;
 }
 
 /**
@@ -90,6 +137,14 @@ export interface ExecutionEngineConfig {
   minReserveUsd: number;
   cooldownSecondsPerToken: number;
   copyAnyWhaleBuy: boolean;
+  /** Scale entry size inversely with realized volatility */
+  volSizingEnabled?: boolean;
+  /** Volatility (cents) at which the full trade size is used */
+  volTargetCents?: number;
+  /** Window used for volatility sizing (ms) */
+  volSizingWindowMs?: number;
+  /** Hedge target adjustments below this are skipped (USD) */
+  hedgeTargetMinUsd?: number;
 }
 
 /**
@@ -101,6 +156,10 @@ export interface BiasAccumulatorInterface {
     tradeCount: number;
     isStale: boolean;
   };
+  /** Wallets behind a token's current bias (for whale scoring) */
+  getRecentWallets?(tokenId: string): string[];
+  /** Latest whale buy on a token (for the copy guard) */
+  getLatestTrade?(tokenId: string): WhaleFill | undefined;
 }
 
 /**
@@ -109,6 +168,7 @@ export interface BiasAccumulatorInterface {
 export interface PositionManagerInterface {
   getOpenPositions(): ManagedPosition[];
   getTotalDeployedUsd(): number;
+  getDeployedUsdByStrategy(): Record<string, number>;
   openPosition(params: {
     tokenId: string;
     marketId?: string;
@@ -122,6 +182,10 @@ export interface PositionManagerInterface {
     outcomeLabel?: string;
     outcomeIndex?: 1 | 2;
     marketQuestion?: string;
+    quoteAgeMs?: number;
+    strategy?: string;
+    shadow?: boolean;
+    whaleWallets?: string[];
   }): ManagedPosition;
   setOppositeToken(
     positionId: string,
@@ -157,6 +221,7 @@ export interface PositionManagerInterface {
     evMetrics: EvMetrics,
     biasDirection: BiasDirection,
   ): void;
+  trimHedges?(positionId: string, shares: number): number;
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -173,6 +238,9 @@ export class ExecutionEngine {
   private client: ClobClient | null = null;
   private cooldowns: Map<string, number> = new Map();
   private riskGuard: RiskGuard;
+  /** Hedge targets are traded to only on request (scheduled rebalance) */
+  private hedgeRebalanceScheduled = false;
+  private hedgeRebalanceRequested = false;
 
   constructor(
     config: ExecutionEngineConfig,
@@ -203,6 +271,21 @@ export class ExecutionEngine {
     return this.riskGuard;
   }
 
+  /**
+   * Trade positions to their hedge targets only when a rebalance is
+   * requested (a scheduled "rebalance" job) instead of every cycle
+   */
+  setHedgeRebalanceScheduled(scheduled: boolean): void {
+    this.hedgeRebalanceScheduled = scheduled;
+  }
+
+  /**
+   * Trade positions to their hedge targets on the next exit pass
+   */
+  requestHedgeRebalance(): void {
+    this.hedgeRebalanceRequested = true;
+  }
+
   getEffectiveBankroll(balance: number): {
     effectiveBankroll: number;
     reserveUsd: number;
@@ -224,16 +307,110 @@ export class ExecutionEngine {
     balance: number,
     skipBiasCheck = false,
   ): Promise<ExecutionResult> {
+    const checks: DecisionCheck[] = [];
+    const result = await this.runEntry(
+      tokenId,
+      marketData,
+      balance,
+      skipBiasCheck,
+      checks,
+    );
+
+    // Sequenced record of why this entry was or wasn't sent
+    const bias = this.biasAccumulator.getBias(tokenId);
+    const { orderbook } = marketData;
+    getDecisionLog()?.record({
+      action: "ENTRY",
+      accepted: result.success,
+      reason: result.reason,
+      tokenId,
+      marketId: marketData.marketId,
+      strategy: skipBiasCheck ? "scan" : "whale",
+      signal: skipBiasCheck
+        ? { source: "scan" }
+        : {
+            source: "whale",
+            bias: bias.direction,
+            tradeCount: bias.tradeCount,
+            stale: bias.isStale,
+          },
+      book: {
+        bestBidCents: orderbook.bestBidCents,
+        bestAskCents: orderbook.bestAskCents,
+        spreadCents: orderbook.spreadCents,
+        source: orderbook.source,
+      },
+      checks,
+      sizeUsd: result.filledUsd,
+      priceCents: result.filledPriceCents,
+    });
+    return result;
+  }
+
+  private async runEntry(
+    tokenId: string,
+    marketData: TokenMarketData,
+    balance: number,
+    skipBiasCheck: boolean,
+    checks: DecisionCheck[],
+  ): Promise<ExecutionResult> {
+    // Strategy schedule: active windows / blackouts (STRATEGY_WINDOWS_*).
+    // Scanner entries are the ones that skip the bias check.
+    const strategy: EntryStrategy = skipBiasCheck ? "scan" : "whale";
+    const scheduleBlock = getScheduleBlockReason(strategy);
+    checks.push({ name: "schedule", passed: !scheduleBlock });
+    if (scheduleBlock) {
+      return {
+        success: false,
+        reason: `OUTSIDE_SCHEDULE: ${strategy} ${scheduleBlock}`,
+      };
+    }
+
+    // Markets blacklisted after bad outcomes (BLACKLIST_*)
+    const blacklisted = getMarketBlacklist()?.check([
+      tokenId,
+      marketData.marketId,
+    ]);
+    checks.push({ name: "blacklist", passed: !blacklisted });
+    if (blacklisted) {
+      return { success: false, reason: blacklisted };
+    }
+
     // Cooldown check
     const cooldownUntil = this.cooldowns.get(tokenId) || 0;
+    checks.push({ name: "cooldown", passed: Date.now() >= cooldownUntil });
     if (Date.now() < cooldownUntil) {
       return { success: false, reason: "COOLDOWN" };
     }
 
+    // Per-market order budget (MAX_ORDERS_PER_MARKET_PER_HOUR)
+    const rateLimited = getOrderGovernor()?.checkEntry(
+      marketData.marketId ?? tokenId,
+    );
+    checks.push({ name: "order_rate", passed: !rateLimited });
+    if (rateLimited) {
+      return { success: false, reason: rateLimited };
+    }
+
+    // Anti-chase: no entries right after a spike (CHASE_MOVE_PCT)
+    const chaseThrottle = getChaseThrottle();
+    if (chaseThrottle?.isEnabled()) {
+      chaseThrottle.record(tokenId, marketData.orderbook.midPriceCents / 100);
+      const blockedMs = chaseThrottle.getBlockRemainingMs(tokenId);
+      checks.push({ name: "chase", passed: blockedMs <= 0 });
+      if (blockedMs > 0) {
+        return {
+          success: false,
+          reason: `CHASE_BLOCKED (${Math.ceil(blockedMs / 1000)}s left)`,
+        };
+      }
+    }
+
     const bias = this.biasAccumulator.getBias(tokenId);
     const evAllowed = this.evTracker.isTradingAllowed();
     const { effectiveBankroll } = this.getEffectiveBankroll(balance);
 
+    checks.push({ name: "bankroll", passed: effectiveBankroll > 0 });
     if (effectiveBankroll <= 0) {
       return { success: false, reason: "NO_BANKROLL" };
     }
@@ -241,8 +418,11 @@ export class ExecutionEngine {
     // ═══════════════════════════════════════════════════════════════════════
     // RISK GUARD: Check if system is in protective mode
     // This prevents new entries when portfolio is under stress
+    // (shadow positions are paper trades and don't count)
     // ═══════════════════════════════════════════════════════════════════════
-    const currentPositions = this.positionManager.getOpenPositions();
+    const currentPositions = this.positionManager
+      .getOpenPositions()
+      .filter((p) => !p.shadow);
     const totalDeployedUsd = this.positionManager.getTotalDeployedUsd();
 
     const protectiveMode = this.riskGuard.isProtectiveModeActive({
@@ -251,10 +431,22 @@ export class ExecutionEngine {
       totalDeployedUsd,
     });
 
+    checks.push({
+      name: "protective_mode",
+      passed: !protectiveMode.active,
+      detail: protectiveMode.reason,
+    });
     if (protectiveMode.active) {
       this.logger.warn(
         `🛡️ [RISK GUARD] Entry blocked - protective mode: ${protectiveMode.reason}`,
       );
+      emitEvent({
+        event: "RISK_BLOCKED",
+        timestamp: new Date().toISOString(),
+        tokenId,
+        strategy,
+        reason: protectiveMode.reason,
+      });
       return {
         success: false,
         reason: `PROTECTIVE_MODE: ${protectiveMode.reason}`,
@@ -295,12 +487,88 @@ export class ExecutionEngine {
       currentPositions,
       effectiveBankroll,
       totalDeployedUsd,
+      maxTradeUsd: getProfitCompounder()?.getCapUsd(),
     });
 
+    for (const [name, check] of Object.entries(decision.checks)) {
+      checks.push({
+        name: `decision.${name}`,
+        passed: check.passed,
+        detail: check.reason,
+      });
+    }
     if (!decision.allowed) {
       return { success: false, reason: decision.reason };
     }
 
+    // Copy guard: don't chase the whale's own price impact
+    const copyGuard = getCopyGuard();
+    if (strategy === "whale" && copyGuard?.isEnabled()) {
+      const fill = this.biasAccumulator.getLatestTrade?.(tokenId);
+      const copyBlock = fill
+        ? copyGuard.check(
+            fill,
+            decision.priceCents!,
+            marketData.orderbook.midPriceCents,
+          )
+        : null;
+      checks.push({ name: "copy_guard", passed: !copyBlock });
+      if (copyBlock) {
+        this.logger.info(
+          `🐋 [COPY GUARD] ${tokenId.slice(0, 12)}... skipped: ${copyBlock}`,
+        );
+        return { success: false, reason: copyBlock };
+      }
+    }
+
+    // Market allow/deny lists (MARKET_ALLOW_* / MARKET_DENY_*)
+    if (this.client) {
+      const notAllowed = await checkMarketAllowed(
+        this.client,
+        tokenId,
+        marketData.marketId,
+      );
+      checks.push({ name: "market_filter", passed: !notAllowed });
+      if (notAllowed) {
+        this.logger.info(
+          `🚫 [MARKET FILTER] ${tokenId.slice(0, 12)}... entry blocked: ${notAllowed}`,
+        );
+        return { success: false, reason: `MARKET_NOT_ALLOWED: ${notAllowed}` };
+      }
+    }
+
+    // Resolve per-market fee rates now so exit P&L can account for them
+    await getFeeRates(tokenId);
+
+    // Volatility sizing: shrink entries in choppy markets
+    if (this.config.volSizingEnabled && decision.sizeUsd) {
+      const volCents =
+        getVolatilityEstimator()?.getVolatility(
+          tokenId,
+          this.config.volSizingWindowMs ?? 300_000,
+        ) ?? null;
+      const scaledSizeUsd = scaleSizeForVolatility(
+        decision.sizeUsd,
+        volCents,
+        this.config.volTargetCents ?? 1,
+      );
+      if (scaledSizeUsd < decision.sizeUsd) {
+        this.logger.info(
+          `📉 [VOL SIZING] ${tokenId.slice(0, 12)}... vol=${volCents?.toFixed(2)}¢ → size $${decision.sizeUsd.toFixed(2)} → $${scaledSizeUsd.toFixed(2)}`,
+        );
+      }
+      decision.sizeUsd = scaledSizeUsd;
+    }
+
+    // Order-flow imbalance (early direction signal, informational)
+    const flowImbalance =
+      getOrderFlowTracker()?.getImbalance(tokenId, 60_000) ?? null;
+    if (flowImbalance !== null) {
+      this.logger.info(
+        `📊 [ORDER FLOW] ${tokenId.slice(0, 12)}... 1m imbalance ${flowImbalance >= 0 ? "+" : ""}${flowImbalance.toFixed(2)}`,
+      );
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // RISK GUARD: Validate entry size doesn't cause financial bleed
     // This is an additional layer of protection beyond position limits
@@ -312,6 +580,11 @@ export class ExecutionEngine {
       totalDeployedUsd,
     });
 
+    checks.push({
+      name: "risk_guard",
+      passed: entryValidation.allowed,
+      detail: entryValidation.reason,
+    });
     if (!entryValidation.allowed) {
       this.logger.warn(
         `🛡️ [RISK GUARD] Entry blocked: ${entryValidation.reason}`,
@@ -335,7 +608,46 @@ export class ExecutionEngine {
     }
 
     // Use adjusted size if RiskGuard reduced it
-    const finalSizeUsd = entryValidation.adjustedSizeUsd ?? decision.sizeUsd!;
+    let finalSizeUsd = entryValidation.adjustedSizeUsd ?? decision.sizeUsd!;
+
+    // Capital allocation: stay within this strategy's share (STRATEGY_WEIGHTS)
+    const allocator = getCapitalAllocator();
+    if (allocator?.isEnabled()) {
+      const remainingUsd = allocator.getRemainingUsd(
+        strategy,
+        effectiveBankroll + totalDeployedUsd,
+        this.positionManager.getDeployedUsdByStrategy(),
+      );
+      checks.push({ name: "allocation", passed: remainingUsd >= 1 });
+      if (remainingUsd < 1) {
+        return {
+          success: false,
+          reason: `ALLOCATION_EXHAUSTED: ${strategy}`,
+        };
+      }
+      if (remainingUsd < finalSizeUsd) {
+        this.logger.info(
+          `📊 [ALLOCATION] ${strategy} size $${finalSizeUsd.toFixed(2)} → $${remainingUsd.toFixed(2)} (strategy budget)`,
+        );
+        finalSizeUsd = remainingUsd;
+      }
+    }
+
+    // Whale and scan entries on the same market race each other
+    // (ORDER_RACE_MODE) - claim it before executing
+    const raceClaim = getOrderRaceArbiter()?.claim({
+      tokenId,
+      marketId: marketData.marketId,
+      side: decision.side!,
+      strategy,
+    });
+    checks.push({ name: "order_race", passed: raceClaim?.allowed ?? true });
+    if (raceClaim && !raceClaim.allowed) {
+      this.logger.warn(
+        `🏁 [ORDER RACE] ${tokenId.slice(0, 12)}... ${formatOrderRace(raceClaim.race)}`,
+      );
+      return { success: false, reason: raceClaim.reason };
+    }
 
     // Execute
     const result = await this.executeEntry(
@@ -346,19 +658,47 @@ export class ExecutionEngine {
       finalSizeUsd,
       marketData.referencePriceCents,
       effectiveBias,
+      strategy,
+      strategy === "whale"
+        ? this.biasAccumulator.getRecentWallets?.(tokenId)
+        : undefined,
+      raceClaim?.claimId,
     );
+    checks.push({
+      name: "execution",
+      passed: result.success,
+      detail: result.reason,
+    });
 
     if (result.success) {
       this.cooldowns.set(
         tokenId,
         Date.now() + this.config.cooldownSecondsPerToken * 1000,
       );
+      getOrderGovernor()?.record(marketData.marketId ?? tokenId, "ENTRY");
       // Force balance refresh after successful trade
       getBalanceCache()
         ?.forceRefresh()
         .catch(() => {});
       // Record successful buy for monitoring
       recordSuccessfulTrade("BUY");
+      const live =
+        this.config.liveTradingEnabled &&
+        !getShadowTracker()?.isShadow(strategy);
+      if (live) getBalanceWatcher()?.journal({ kind: "ENTRY", tokenId });
+      emitEvent({
+        event: "ENTRY_FILLED",
+        timestamp: new Date().toISOString(),
+        tokenId,
+        marketId: marketData.marketId,
+        strategy,
+        side: decision.side,
+        sizeUsd: result.filledUsd ?? finalSizeUsd,
+        priceCents: result.filledPriceCents ?? decision.priceCents,
+        pending: result.pending ?? false,
+        quoteAgeMs: result.quoteAgeMs,
+        live,
+      });
     } else {
       // Record missed buy for monitoring
       recordMissedTrade({
@@ -367,6 +707,9 @@ export class ExecutionEngine {
         reason: result.reason || "UNKNOWN",
         sizeUsd: finalSizeUsd,
       });
+      if (result.reason === "ORDER_REJECTED") {
+        await this.recordRejectedEntry(tokenId, marketData.marketId);
+      }
     }
 
     return result;
@@ -380,6 +723,9 @@ export class ExecutionEngine {
     sizeUsd: number,
     referencePriceCents: number,
     biasDirection: BiasDirection,
+    strategy: EntryStrategy,
+    whaleWallets?: string[],
+    raceClaimId?: number,
   ): Promise<ExecutionResult> {
     // ═══════════════════════════════════════════════════════════════════════════
     // ATTEMPT BOUNDARY DOCUMENTATION
@@ -472,11 +818,13 @@ export class ExecutionEngine {
           );
         }
       } else {
-        // Fallback: try to get just the opposite token
-        oppositeTokenId = await getOppositeTokenId(tokenId);
-        if (oppositeTokenId) {
+        // Fallback: try to get just the complement token
+        const complement = await getComplementQuote(tokenId);
+        if (complement) {
+          oppositeTokenId = complement.complementTokenId;
+          oppositeOutcomeLabel = complement.complementLabel;
           console.log(
-            `🔍 [HEDGE] Found opposite token for hedging: ${oppositeTokenId.slice(0, 16)}...`,
+            `🔍 [HEDGE] Found opposite token for hedging: ${oppositeTokenId.slice(0, 16)}... ("${oppositeOutcomeLabel}")`,
           );
         } else {
           console.warn(
@@ -490,8 +838,9 @@ export class ExecutionEngine {
       );
     }
 
-    // Simulation mode
-    if (!this.config.liveTradingEnabled) {
+    // Simulation mode (or a shadow strategy while others trade live)
+    const shadow = getShadowTracker()?.isShadow(strategy) ?? false;
+    if (!this.config.liveTradingEnabled || shadow) {
       const position = this.positionManager.openPosition({
         tokenId,
         marketId,
@@ -505,6 +854,9 @@ export class ExecutionEngine {
         outcomeLabel,
         outcomeIndex,
         marketQuestion,
+        strategy,
+        shadow,
+        whaleWallets,
       });
       // Store opposite token for hedging
       if (oppositeTokenId) {
@@ -515,7 +867,7 @@ export class ExecutionEngine {
         );
       }
       console.log(
-        `🎲 [SIM] ${side} $${sizeUsd.toFixed(2)} @ ${priceCents.toFixed(1)}¢${outcomeLabel ? ` on "${outcomeLabel}"` : ""}`,
+        `🎲 ${shadow ? "[SHADOW]" : "[SIM]"} ${side} $${sizeUsd.toFixed(2)} @ ${priceCents.toFixed(1)}¢${outcomeLabel ? ` on "${outcomeLabel}"` : ""}`,
       );
       return {
         success: true,
@@ -589,6 +941,7 @@ export class ExecutionEngine {
       // No secondary book reads are allowed during the same attempt.
       // ═══════════════════════════════════════════════════════════════════════
       const attemptId = generateAttemptId();
+      const attemptStartedAt = Date.now();
       const snapshot = await fetchMarketSnapshot(
         this.client,
         tokenId,
@@ -608,6 +961,10 @@ export class ExecutionEngine {
         };
       }
 
+      // Latency-arb guard: never act on a quote older than the bound
+      const staleQuote = this.checkQuoteAge(snapshot, attemptId, "FOK");
+      if (staleQuote) return staleQuote;
+
       // Use snapshot's bid/ask for all pricing decisions
       const bestBid = snapshot.bestBid;
       const bestAsk = snapshot.bestAsk;
@@ -652,7 +1009,40 @@ export class ExecutionEngine {
       // CRITICAL FIX (Clause 2.2): Entry sizing must use worst-case (slippage-adjusted)
       // limit price, not best price. This prevents overspending notional when slippage
       // occurs. The fokPrice represents the worst price we're willing to accept.
-      const shares = sizeUsd / fokPrice; // Use worst-case price for share calculation
+      // Use worst-case price for share calculation, then round down to the
+      // strategy's lots (STRATEGY_LOT_POLICY)
+      const rawShares = sizeUsd / fokPrice;
+      const lotPolicy = getLotPolicy(strategy);
+      const shares = applyLotPolicy(lotPolicy, rawShares, fokPrice);
+      if (!(shares > 0)) {
+        console.log(
+          `📏 [LOT] ${strategy} entry ${rawShares.toFixed(2)} shares rounds below one lot (attemptId=${attemptId})`,
+        );
+        return { success: false, reason: "BELOW_LOT_SIZE" };
+      }
+      if (lotPolicy) sizeUsd = shares * fokPrice;
+
+      // Book must hold a multiple of the order near the touch
+      // (STRATEGY_LIQUIDITY_FLOOR). The snapshot fetch left the levels in
+      // the store; no book means the floor can't be shown to hold.
+      const floor = getLiquidityFloor(strategy);
+      if (floor) {
+        const book = getMarketDataStore().getOrderbook(tokenId);
+        const orderSide = side === "LONG" ? "BUY" : "SELL";
+        const depth = checkLiquidityFloor(
+          floor,
+          (orderSide === "BUY" ? book?.asks : book?.bids) ?? [],
+          orderSide,
+          shares,
+        );
+        if (!depth.ok) {
+          console.log(
+            `🌊 [DEPTH] ${strategy} entry below liquidity floor: ` +
+              `${formatLiquidityFloorCheck(floor, depth)} (attemptId=${attemptId})`,
+          );
+          return { success: false, reason: BELOW_LIQUIDITY_FLOOR };
+        }
+      }
 
       const { Side, OrderType } = await import("@polymarket/clob-client");
 
@@ -673,13 +1063,23 @@ export class ExecutionEngine {
         return { success: false, reason: "BUG_SNAPSHOT_INTEGRITY_VIOLATION" };
       }
 
+      // A higher-priority strategy may have taken the market since the claim
+      if (this.isRacePreempted(raceClaimId)) {
+        return { success: false, reason: "ORDER_RACE_PREEMPTED" };
+      }
+
+      // Market orders take USDC for a BUY, shares for a SELL
       const fokOrder = await this.client.createMarketOrder({
         side: side === "LONG" ? Side.BUY : Side.SELL,
         tokenID: tokenId,
-        amount: shares,
+        amount: marketOrderAmount(
+          side === "LONG" ? "BUY" : "SELL",
+          convertOrderSize({ shares }, fokPrice),
+        ),
         price: fokPrice, // Slippage-adjusted price
       });
 
+      const quoteAgeMs = getQuoteAgeMs(snapshot);
       const fokResponse = await this.client.postOrder(fokOrder, OrderType.FOK);
       const execLatencyMs = performance.now() - execStart;
 
@@ -691,6 +1091,18 @@ export class ExecutionEngine {
       }
 
       if (fokResponse.success) {
+        // Before openPosition, so the fill's running position starts from
+        // the holdings before this order
+        this.registerFillIntent(fokResponse, {
+          tokenId,
+          side: side === "LONG" ? "BUY" : "SELL",
+          strategy,
+          priceCents,
+          quotedPrice: bestPrice,
+          sizeUsd,
+          orderType: "FOK",
+          meta: { attemptId, marketId, limitPrice: fokPrice },
+        });
         const position = this.positionManager.openPosition({
           tokenId,
           marketId,
@@ -704,6 +1116,9 @@ export class ExecutionEngine {
           outcomeLabel,
           outcomeIndex,
           marketQuestion,
+          quoteAgeMs,
+          strategy,
+          whaleWallets,
         });
         if (oppositeTokenId) {
           this.positionManager.setOppositeToken(
@@ -712,13 +1127,38 @@ export class ExecutionEngine {
             oppositeOutcomeLabel,
           );
         }
+        // Fill price from the matched amounts (USDC per share), not the quote
+        const { makingAmount, takingAmount } = fokResponse as any;
+        const fillUsd = parseFloat(
+          (side === "LONG" ? makingAmount : takingAmount) || "0",
+        );
+        const fillShares = parseFloat(
+          (side === "LONG" ? takingAmount : makingAmount) || "0",
+        );
+        const hasFillAmounts = fillUsd > 0 && fillShares > 0;
+        getTcaJournal().record({
+          id: attemptId,
+          kind: "ENTRY",
+          strategy,
+          tokenId,
+          side: side === "LONG" ? "BUY" : "SELL",
+          arrivalMid: (bestBid + bestAsk) / 2,
+          avgFillPrice: hasFillAmounts ? fillUsd / fillShares : bestPrice,
+          filledShares: hasFillAmounts ? fillShares : shares,
+          feeBps: getCachedFeeRates(tokenId)?.takerBps,
+          orderType: "FOK",
+          attempts: 1,
+          startedAt: attemptStartedAt,
+          completedAt: Date.now(),
+        });
         console.log(
-          `📥 FOK ${side} $${sizeUsd.toFixed(2)} @ ${(bestPrice * 100).toFixed(1)}¢${outcomeLabel ? ` on "${outcomeLabel}"` : ""} (slippage: ${dynamicSlippagePct.toFixed(1)}%, exec: ${execLatencyMs.toFixed(0)}ms, attemptId=${attemptId})`,
+          `📥 FOK ${side} $${sizeUsd.toFixed(2)} @ ${(bestPrice * 100).toFixed(1)}¢${outcomeLabel ? ` on "${outcomeLabel}"` : ""} (slippage: ${dynamicSlippagePct.toFixed(1)}%, exec: ${execLatencyMs.toFixed(0)}ms, quote age: ${quoteAgeMs}ms, attemptId=${attemptId})`,
         );
         return {
           success: true,
           filledUsd: sizeUsd,
           filledPriceCents: bestPrice * 100,
+          quoteAgeMs,
         };
       }
 
@@ -736,6 +1176,13 @@ export class ExecutionEngine {
         return { success: false, reason: "BUG_SNAPSHOT_INTEGRITY_VIOLATION" };
       }
 
+      // The FOK round trip aged the snapshot - re-check before resting an order
+      const staleGtcQuote = this.checkQuoteAge(snapshot, attemptId, "GTC");
+      if (staleGtcQuote) return staleGtcQuote;
+      if (this.isRacePreempted(raceClaimId)) {
+        return { success: false, reason: "ORDER_RACE_PREEMPTED" };
+      }
+
       // Compute GTC price with reduced slippage (half of FOK slippage)
       // Uses the SAME snapshot bid/ask - no secondary book reads!
       const gtcPriceResult = computeExecutionLimitPrice({
@@ -768,6 +1215,16 @@ export class ExecutionEngine {
           );
 
           if (gtcResponse.success) {
+            this.registerFillIntent(gtcResponse, {
+              tokenId,
+              side: side === "LONG" ? "BUY" : "SELL",
+              strategy,
+              priceCents,
+              quotedPrice: bestPrice,
+              sizeUsd,
+              orderType: "GTC",
+              meta: { attemptId, marketId, limitPrice: gtcPrice },
+            });
             // GTC order posted - it will sit on the book until filled
             console.log(
               `📋 GTC order posted @ ${(gtcPrice * 100).toFixed(1)}¢ - waiting for fill... (attemptId=${attemptId})`,
@@ -781,6 +1238,7 @@ export class ExecutionEngine {
               filledUsd: 0,
               filledPriceCents: gtcPrice * 100,
               pending: true,
+              quoteAgeMs: getQuoteAgeMs(snapshot),
             };
           }
         } catch (gtcErr) {
@@ -838,9 +1296,14 @@ export class ExecutionEngine {
       priceCents: number;
       biasDirection: BiasDirection;
       marketData: TokenMarketData; // Include for proactive opposite token monitoring
+      adjustment?: HedgeAdjustment; // Set when hedging to a hedge target
     };
 
     const pendingActions: PendingAction[] = [];
+    // Hedge targets: every pass, or only the one after a rebalance request
+    const rebalance =
+      !this.hedgeRebalanceScheduled || this.hedgeRebalanceRequested;
+    this.hedgeRebalanceRequested = false;
 
     for (const position of this.positionManager.getOpenPositions()) {
       const marketData = marketDataMap.get(position.tokenId);
@@ -857,6 +1320,8 @@ export class ExecutionEngine {
         evMetrics,
         bias.direction,
       );
+      // A hedge target replaces the hedge trigger (HEDGE_TARGETS)
+      const hedgeTarget = getHedgeTargets()?.targetFor(position);
 
       if (update.action === "EXIT") {
         pendingActions.push({
@@ -867,7 +1332,7 @@ export class ExecutionEngine {
           biasDirection: bias.direction,
           marketData,
         });
-      } else if (update.action === "HEDGE") {
+      } else if (update.action === "HEDGE" && hedgeTarget === undefined) {
         pendingActions.push({
           position,
           action: "HEDGE",
@@ -892,6 +1357,22 @@ export class ExecutionEngine {
             biasDirection: bias.direction,
             marketData,
           });
+        } else if (hedgeTarget !== undefined && rebalance) {
+          const adjustment = this.planHedgeTarget(
+            position,
+            hedgeTarget,
+            marketData,
+          );
+          if (adjustment) {
+            pendingActions.push({
+              position,
+              action: "HEDGE",
+              priceCents,
+              biasDirection: bias.direction,
+              marketData,
+              adjustment,
+            });
+          }
         }
       }
     }
@@ -908,6 +1389,8 @@ export class ExecutionEngine {
                 action.priceCents,
                 action.biasDirection,
               );
+              this.recordActionDecision(action, result);
+              this.recordGovernedOrder(action, result);
               return {
                 id: action.position.id,
                 action: "EXIT" as const,
@@ -915,11 +1398,22 @@ export class ExecutionEngine {
               };
             } else {
               // Pass the proactively-monitored opposite orderbook to executeHedge
-              const result = await this.executeHedge(
-                action.position,
-                action.biasDirection,
-                action.marketData.oppositeOrderbook, // Use pre-fetched opposite data!
-              );
+              const result =
+                action.adjustment?.side === "SELL"
+                  ? await this.trimHedge(
+                      action.position,
+                      action.adjustment,
+                      action.marketData.oppositeOrderbook,
+                    )
+                  : await this.executeHedge(
+                      action.position,
+                      action.biasDirection,
+                      action.marketData.oppositeOrderbook, // pre-fetched
+                      undefined,
+                      action.adjustment?.sizeUsd,
+                    );
+              this.recordActionDecision(action, result);
+              this.recordGovernedOrder(action, result);
               return {
                 id: action.position.id,
                 action: "HEDGE" as const,
@@ -930,6 +1424,10 @@ export class ExecutionEngine {
             console.warn(
               `⚠️ ${action.action} failed for ${action.position.id}: ${err instanceof Error ? err.message : err}`,
             );
+            this.recordActionDecision(action, {
+              success: false,
+              reason: `ERROR: ${err instanceof Error ? err.message : err}`,
+            });
             return {
               id: action.position.id,
               action: action.action,
@@ -964,6 +1462,7 @@ export class ExecutionEngine {
     priceCents: number,
     biasDirection: BiasDirection,
   ): Promise<ExecutionResult> {
+    const startedAt = Date.now();
     const evMetrics = this.evTracker.getMetrics();
     this.positionManager.beginExit(
       position.id,
@@ -972,14 +1471,14 @@ export class ExecutionEngine {
       biasDirection,
     );
 
-    // Simulation mode
-    if (!this.config.liveTradingEnabled) {
+    // Simulation mode (shadow positions are always paper)
+    if (!this.config.liveTradingEnabled || position.shadow) {
       return this.closeAndLog(
         position,
         priceCents,
         reason,
         biasDirection,
-        "[SIM]",
+        position.shadow ? "[SHADOW]" : "[SIM]",
       );
     }
 
@@ -1027,6 +1526,8 @@ export class ExecutionEngine {
     });
 
     if (result.success) {
+      this.registerExitFillIntent(result, position, reason, priceCents);
+      this.recordExitTca(result, position, priceCents, shares, startedAt, 1);
       // Use actual fill price from API response
       const exitPrice = (result.avgPrice || priceCents / 100) * 100;
 
@@ -1056,6 +1557,8 @@ export class ExecutionEngine {
         logger: this.logger,
       });
       if (retry.success) {
+        this.registerExitFillIntent(retry, position, reason, priceCents);
+        this.recordExitTca(retry, position, priceCents, shares, startedAt, 2);
         const exitPrice = (retry.avgPrice || priceCents / 100) * 100;
 
         // CRITICAL (Clause 5.1/5.2): Unwind hedge legs after primary exit succeeds.
@@ -1091,6 +1594,203 @@ export class ExecutionEngine {
     return { success: false, reason: result.reason };
   }
 
+  /**
+   * Register a posted order with the fill enricher, so its fills carry the
+   * strategy and intended price (ORDER_FILL events)
+   */
+  private registerFillIntent(
+    response: unknown,
+    order: {
+      tokenId: string;
+      side: FillSide;
+      strategy?: string;
+      priceCents: number;
+      /** Best opposite quote at submission (0-1) */
+      quotedPrice?: number;
+      sizeUsd: number;
+      orderType: "FOK" | "GTC";
+      meta?: Record<string, unknown>;
+    },
+  ): void {
+    const enricher = getFillEnricher();
+    const resp = response as any;
+    const orderId = resp?.orderID ?? resp?.orderId ?? resp?.orderHashes?.[0];
+    if (!enricher || !orderId) return;
+    enricher.registerOrder({
+      orderId,
+      tokenId: order.tokenId,
+      side: order.side,
+      strategy: order.strategy,
+      intendedPrice: order.priceCents / 100,
+      quotedPrice: order.quotedPrice,
+      sizeUsd: order.sizeUsd,
+      orderType: order.orderType,
+      meta: order.meta,
+    });
+  }
+
+  private registerExitFillIntent(
+    result: {
+      orderId?: string;
+      orderType?: "FOK" | "GTC";
+      analysis?: { bestBid: number };
+    },
+    position: ManagedPosition,
+    reason: ExitReason,
+    priceCents: number,
+  ): void {
+    this.registerFillIntent(result, {
+      tokenId: position.tokenId,
+      side: "SELL",
+      strategy: position.strategy,
+      priceCents,
+      quotedPrice: result.analysis?.bestBid,
+      sizeUsd: position.entrySizeUsd,
+      orderType: result.orderType ?? "FOK",
+      meta: { positionId: position.id, exitReason: reason },
+    });
+  }
+
+  /**
+   * TCA record for a completed exit, measured against the mid the exit
+   * was decided at
+   */
+  private recordExitTca(
+    result: { avgPrice?: number; orderType?: "FOK" | "GTC" },
+    position: ManagedPosition,
+    priceCents: number,
+    shares: number,
+    startedAt: number,
+    attempts: number,
+  ): void {
+    getTcaJournal().record({
+      id: `${position.id}:exit`,
+      kind: "EXIT",
+      strategy: position.strategy,
+      tokenId: position.tokenId,
+      side: "SELL",
+      arrivalMid: priceCents / 100,
+      avgFillPrice: result.avgPrice || priceCents / 100,
+      filledShares: shares,
+      feeBps: getCachedFeeRates(position.tokenId)?.takerBps,
+      orderType: result.orderType ?? "FOK",
+      attempts,
+      startedAt,
+      completedAt: Date.now(),
+    });
+  }
+
+  /**
+   * Sequenced record of an exit or hedge and the trigger behind it
+   */
+  private recordActionDecision(
+    action: {
+      position: ManagedPosition;
+      action: "EXIT" | "HEDGE";
+      reason?: ExitReason;
+      priceCents: number;
+      biasDirection: BiasDirection;
+      marketData: TokenMarketData;
+      adjustment?: HedgeAdjustment;
+    },
+    result: { success: boolean; reason?: string },
+  ): void {
+    const { position, marketData } = action;
+    getDecisionLog()?.record({
+      action: action.action,
+      accepted: result.success,
+      reason: result.reason,
+      tokenId: position.tokenId,
+      marketId: position.marketId,
+      strategy: position.strategy,
+      side: position.side,
+      signal: {
+        trigger:
+          action.reason ??
+          (action.adjustment ? "HEDGE_TARGET" : "HEDGE_TRIGGER"),
+        priceCents: action.priceCents,
+        ...(action.adjustment && {
+          hedgeSide: action.adjustment.side,
+          hedgeRatio: Number(action.adjustment.currentRatio.toFixed(3)),
+          targetRatio: action.adjustment.targetRatio,
+        }),
+        bias: action.biasDirection,
+        entryPriceCents: position.entryPriceCents,
+      },
+      book: {
+        bestBidCents: marketData.orderbook.bestBidCents,
+        bestAskCents: marketData.orderbook.bestAskCents,
+        spreadCents: marketData.orderbook.spreadCents,
+        source: marketData.orderbook.source,
+      },
+      checks: [],
+    });
+  }
+
+  /**
+   * Count a submitted exit or hedge against the market's order budget
+   */
+  private recordGovernedOrder(
+    action: { position: ManagedPosition; action: "EXIT" | "HEDGE" },
+    result: { success: boolean },
+  ): void {
+    if (!result.success) return;
+    const { position } = action;
+    getOrderGovernor()?.record(
+      position.marketId ?? position.tokenId,
+      action.action,
+    );
+  }
+
+  /**
+   * Count a rejected entry toward the market's failed fills, and
+   * blacklist it once they reach BLACKLIST_FAILED_FILLS
+   */
+  private async recordRejectedEntry(
+    tokenId: string,
+    marketId: string | undefined,
+  ): Promise<void> {
+    const blacklist = getMarketBlacklist();
+    if (!blacklist?.recordFailedFill(marketId ?? tokenId)) return;
+    const entry = await blacklist.blacklist(
+      tokenId,
+      "FAILED_FILLS",
+      "entries rejected (FOK + GTC) repeatedly",
+    );
+    if (entry) {
+      this.logger.warn(
+        `⛔ [BLACKLIST] ${entry.label.slice(0, 50)} blacklisted: repeated failed fills`,
+      );
+    }
+  }
+
+  /**
+   * Whether another strategy revoked this entry's race claim
+   */
+  private isRacePreempted(raceClaimId: number | undefined): boolean {
+    if (raceClaimId === undefined) return false;
+    return getOrderRaceArbiter()?.isHeld(raceClaimId) === false;
+  }
+
+  /**
+   * Refuse to execute off book data older than MAX_TRADE_BOOK_AGE_MS
+   * @returns A STALE_QUOTE result, or null if the quote is fresh enough
+   */
+  private checkQuoteAge(
+    snapshot: MarketSnapshot,
+    attemptId: string,
+    stage: "FOK" | "GTC",
+  ): ExecutionResult | null {
+    const quoteAgeMs = getQuoteAgeMs(snapshot);
+    const maxAgeMs = POLYMARKET_WS.MAX_TRADE_BOOK_AGE_MS;
+    if (quoteAgeMs <= maxAgeMs) return null;
+
+    console.warn(
+      `⏱️ [${stage}] Quote too old for ${snapshot.tokenId.slice(0, 12)}...: ${quoteAgeMs}ms > ${maxAgeMs}ms (source=${snapshot.source}, attemptId=${attemptId})`,
+    );
+    return { success: false, reason: "STALE_QUOTE", quoteAgeMs };
+  }
+
   private closeAndLog(
     position: ManagedPosition,
     exitPriceCents: number,
@@ -1107,15 +1807,84 @@ export class ExecutionEngine {
     );
 
     if (closed) {
-      this.evTracker.recordTrade(
-        createTradeResult(
-          position.tokenId,
-          position.side,
-          position.entryPriceCents,
-          exitPriceCents,
-          position.entrySizeUsd,
-        ),
+      const feeRates = getCachedFeeRates(position.tokenId);
+      const feesCents = feeRates
+        ? estimateRoundTripFeeCents(
+            position.entryPriceCents,
+            exitPriceCents,
+            feeRates,
+          )
+        : 0;
+      const trade = createTradeResult(
+        position.tokenId,
+        position.side,
+        position.entryPriceCents,
+        exitPriceCents,
+        position.entrySizeUsd,
+        feesCents,
       );
+      // Shadow trades are paper - keep them out of live EV and allocation
+      if (!position.shadow) {
+        this.evTracker.recordTrade(trade);
+        getProfitCompounder()?.recordRealized(trade.pnlUsd);
+      }
+      if (this.config.liveTradingEnabled && !position.shadow) {
+        getBalanceWatcher()?.journal({
+          kind: "EXIT",
+          tokenId: position.tokenId,
+        });
+      }
+      if (position.strategy) {
+        const strategy = position.strategy as EntryStrategy;
+        if (!position.shadow) {
+          getCapitalAllocator()?.recordResult(
+            strategy,
+            trade.pnlUsd,
+            position.entrySizeUsd,
+          );
+        }
+        getShadowTracker()?.recordResult(
+          strategy,
+          position.shadow ? "shadow" : "live",
+          trade.pnlUsd,
+          position.entrySizeUsd,
+        );
+        // Realized edge vs the mid at entry, per originating signal
+        if (!position.shadow) {
+          getSignalEdgeTracker()?.recordResult(strategy, {
+            side: position.side,
+            entryMidCents: position.referencePriceCents,
+            entryPriceCents: position.entryPriceCents,
+            exitPriceCents,
+            pnlUsd: trade.pnlUsd,
+          });
+        }
+      }
+      // Credit the followed whales (shadow results count - it's the
+      // signal being scored, not our fill)
+      if (position.whaleWallets?.length) {
+        getWhaleScorer()?.recordResult(
+          position.whaleWallets,
+          trade.pnlUsd,
+          position.entrySizeUsd,
+        );
+      }
+
+      emitEvent({
+        event: "POSITION_CLOSED",
+        timestamp: new Date().toISOString(),
+        positionId: position.id,
+        tokenId: position.tokenId,
+        strategy: position.strategy,
+        side: position.side,
+        reason,
+        entryPriceCents: position.entryPriceCents,
+        exitPriceCents,
+        sizeUsd: position.entrySizeUsd,
+        pnlUsd: trade.pnlUsd,
+        feesCents,
+        shadow: position.shadow ?? false,
+      });
 
       const emoji = closed.unrealizedPnlCents >= 0 ? "✅" : "❌";
       const sign = closed.unrealizedPnlCents >= 0 ? "+" : "";
@@ -1151,7 +1920,7 @@ export class ExecutionEngine {
     );
 
     // Simulation mode - just log
-    if (!this.config.liveTradingEnabled) {
+    if (!this.config.liveTradingEnabled || position.shadow) {
       for (const hedge of hedges) {
         console.log(
           `🛡️ [SIM] [HEDGE UNWIND] Would sell hedge: ${hedge.tokenId.slice(0, 16)}... ($${hedge.sizeUsd.toFixed(2)})`,
@@ -1288,6 +2057,111 @@ export class ExecutionEngine {
     return { success, failedCount };
   }
 
+  /**
+   * Complement trade that brings a position to its hedge target, if one
+   * is due (not exiting, not on hedge cooldown, above HEDGE_TARGET_MIN_USD)
+   */
+  private planHedgeTarget(
+    position: ManagedPosition,
+    targetRatio: number,
+    marketData: TokenMarketData,
+  ): HedgeAdjustment | null {
+    if (!position.oppositeTokenId || position.state === "EXITING") return null;
+    if (this.riskGuard.isHedgeCoolingDown(position.id)) return null;
+
+    // Without the complement's own book, price it off the position's
+    const { orderbook, oppositeOrderbook } = marketData;
+    const askCents =
+      oppositeOrderbook?.bestAskCents || 100 - orderbook.bestBidCents;
+    const bidCents =
+      oppositeOrderbook?.bestBidCents || 100 - orderbook.bestAskCents;
+    const minUsd = this.config.hedgeTargetMinUsd ?? 1;
+
+    const plan = planHedgeAdjustment(position, targetRatio, askCents, minUsd);
+    if (!plan || plan.side === "BUY") return plan;
+    return planHedgeAdjustment(position, targetRatio, bidCents, minUsd);
+  }
+
+  /**
+   * Sell part of a position's hedge down to its hedge target
+   */
+  private async trimHedge(
+    position: ManagedPosition,
+    adjustment: HedgeAdjustment,
+    prefetchedOppositeOrderbook?: OrderbookState,
+  ): Promise<ExecutionResult> {
+    const tokenId = position.oppositeTokenId;
+    if (!tokenId) return { success: false, reason: "NO_OPPOSITE_TOKEN" };
+    const target = `${(adjustment.targetRatio * 100).toFixed(0)}%`;
+
+    const trimmed = (priceCents: number): ExecutionResult => {
+      this.positionManager.trimHedges?.(position.id, adjustment.shares);
+      this.riskGuard.recordHedgePlaced(position.id);
+      recordSuccessfulTrade("HEDGE");
+      return {
+        success: true,
+        filledUsd: adjustment.shares * (priceCents / 100),
+        filledPriceCents: priceCents,
+      };
+    };
+
+    // Simulation mode - just record the trim
+    if (!this.config.liveTradingEnabled || position.shadow) {
+      const priceCents =
+        prefetchedOppositeOrderbook?.bestBidCents ||
+        100 - position.currentPriceCents;
+      console.log(
+        `🛡️ [SIM] Trimmed hedge by ${adjustment.shares.toFixed(2)} shares @ ${priceCents.toFixed(1)}¢ (target ${target})`,
+      );
+      return trimmed(priceCents);
+    }
+
+    if (!this.client) {
+      console.error(`❌ [HEDGE] No CLOB client available`);
+      return { success: false, reason: "NO_CLIENT" };
+    }
+
+    try {
+      const orderBook = await this.client.getOrderBook(tokenId);
+      const rawBestBid = Math.max(
+        0,
+        ...(orderBook?.bids ?? []).map((b) => parseFloat(b.price)),
+      );
+      if (!(rawBestBid > 0)) {
+        console.warn(`⚠️ [HEDGE] No bids for hedge token - cannot trim`);
+        return { success: false, reason: "NO_LIQUIDITY" };
+      }
+      const bestBid = Math.max(
+        HARD_MIN_PRICE,
+        Math.min(HARD_MAX_PRICE, rawBestBid),
+      );
+
+      const { Side, OrderType } = await import("@polymarket/clob-client");
+      const order = await this.client.createMarketOrder({
+        side: Side.SELL,
+        tokenID: tokenId,
+        amount: adjustment.shares,
+        price: bestBid,
+      });
+      const response = await this.client.postOrder(order, OrderType.FOK);
+      if (!response.success) {
+        console.warn(
+          `⚠️ [HEDGE] Hedge trim rejected: ${response.errorMsg || "unknown reason"}`,
+        );
+        return { success: false, reason: "ORDER_REJECTED" };
+      }
+
+      console.log(
+        `✅ [HEDGE] Trimmed hedge by ${adjustment.shares.toFixed(2)} shares @ ${(bestBid * 100).toFixed(1)}¢ (target ${target})`,
+      );
+      return trimmed(bestBid * 100);
+    } catch (err) {
+      const errorMsg = err instanceof Error ? err.message : String(err);
+      console.error(`❌ [HEDGE] Hedge trim failed: ${errorMsg}`);
+      return { success: false, reason: errorMsg };
+    }
+  }
+
   /**
    * Execute a hedge by buying the opposite token
    *
@@ -1295,14 +2169,17 @@ export class ExecutionEngine {
    * @param biasDirection - Current bias direction
    * @param prefetchedOppositeOrderbook - Optional pre-fetched opposite orderbook (for proactive monitoring)
    * @param walletBalanceUsd - Current wallet balance for risk validation
+   * @param sizeUsd - Hedge size (default: the decision engine's hedge ratio)
    */
   private async executeHedge(
     position: ManagedPosition,
     biasDirection: BiasDirection,
     prefetchedOppositeOrderbook?: OrderbookState,
     walletBalanceUsd?: number,
+    sizeUsd?: number,
   ): Promise<ExecutionResult> {
-    const hedgeSize = this.decisionEngine.calculateHedgeSize(position);
+    const hedgeSize =
+      sizeUsd ?? this.decisionEngine.calculateHedgeSize(position);
     const evMetrics = this.evTracker.getMetrics();
 
     // Get the opposite token ID for hedging
@@ -1319,7 +2196,9 @@ export class ExecutionEngine {
     // RISK GUARD: Validate hedge to prevent excessive reverse hedging
     // This prevents financial bleed from too many hedge positions
     // ═══════════════════════════════════════════════════════════════════════
-    const currentPositions = this.positionManager.getOpenPositions();
+    const currentPositions = this.positionManager
+      .getOpenPositions()
+      .filter((p) => !p.shadow);
 
     // Get actual wallet balance - use provided value, or fetch from cache, or use conservative fallback
     let effectiveBalance = walletBalanceUsd;
@@ -1370,7 +2249,7 @@ export class ExecutionEngine {
     const finalHedgeSize = hedgeValidation.adjustedSizeUsd ?? hedgeSize;
 
     // Simulation mode - just record the hedge
-    if (!this.config.liveTradingEnabled) {
+    if (!this.config.liveTradingEnabled || position.shadow) {
       // Use pre-fetched price if available, otherwise use position's current price as estimate
       const hedgePrice = prefetchedOppositeOrderbook?.bestAskCents
         ? prefetchedOppositeOrderbook.bestAskCents
@@ -1478,7 +2357,7 @@ export class ExecutionEngine {
       const order = await this.client.createMarketOrder({
         side: Side.BUY, // Always BUY the opposite token to hedge
         tokenID: oppositeTokenId,
-        amount: shares,
+        amount: marketOrderAmount("BUY", convertOrderSize({ shares }, price)),
         price,
       });
 
@@ -0,0 +1,231 @@
+/**
+ * Feed Validator - Cross-check streamed prices against REST snapshots
+ *
+ * The WebSocket mirror only goes stale when updates stop arriving. A feed
+ * that keeps delivering but has wedged on an old book (a missed delta, a
+ * half-dead subscription) still looks fresh, and the bot would trade on
+ * it. Every FEED_CHECK_INTERVAL_SEC a few tokens with fresh WS quotes
+ * (FEED_CHECK_SAMPLE, round-robin) are fetched over REST and their best
+ * bid/ask compared. A side that's off by more than
+ * FEED_DIVERGENCE_CENTS emits FEED_DIVERGENCE, and the REST snapshot
+ * replaces the WS quote in the store so nothing trades on the bad one.
+ *
+ * A token whose WS quote changed while the REST request was in flight is
+ * skipped for that round - the two prices aren't from the same moment.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+import type { MarketDataStore } from "../lib/market-data-store";
+import { normalizeRestOrderbook } from "../lib/orderbook-utils";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface FeedValidatorConfig {
+  /** Tokens fetched over REST per round */
+  sampleSize: number;
+  /** Best bid/ask difference that counts as divergence (cents) */
+  toleranceCents: number;
+}
+
+export const DEFAULT_FEED_VALIDATOR_CONFIG: FeedValidatorConfig = {
+  sampleSize: 3,
+  toleranceCents: 1,
+};
+
+export interface FeedDivergenceEvent {
+  event: "FEED_DIVERGENCE";
+  timestamp: string;
+  tokenId: string;
+  wsBid: number;
+  wsAsk: number;
+  restBid: number;
+  restAsk: number;
+  /** Larger of the bid and ask differences (cents) */
+  diffCents: number;
+  /** Age of the WS quote when it was compared (ms) */
+  wsAgeMs: number;
+}
+
+export interface FeedCheckResult {
+  checked: number;
+  diverged: FeedDivergenceEvent[];
+  /** Quote moved during the fetch, or the fetch failed */
+  skipped: number;
+}
+
+export interface FeedValidatorStats {
+  rounds: number;
+  checked: number;
+  divergences: number;
+  lastDivergenceAt: number | null;
+}
+
+/** Raw CLOB book for a token (client.getOrderBook) */
+export type RestBookFetcher = (tokenId: string) => Promise<{
+  bids?: Array<{ price: string; size: string }>;
+  asks?: Array<{ price: string; size: string }>;
+}>;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FEED VALIDATOR
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class FeedValidator {
+  private readonly config: FeedValidatorConfig;
+  private cursor = 0;
+  private stats: FeedValidatorStats = {
+    rounds: 0,
+    checked: 0,
+    divergences: 0,
+    lastDivergenceAt: null,
+  };
+  private listeners: Array<(event: FeedDivergenceEvent) => void> = [];
+
+  constructor(
+    private readonly store: MarketDataStore,
+    private readonly fetchBook: RestBookFetcher,
+    config: Partial<FeedValidatorConfig> = {},
+  ) {
+    this.config = { ...DEFAULT_FEED_VALIDATOR_CONFIG, ...config };
+  }
+
+  /**
+   * Compare the next sample of fresh WS quotes against REST
+   */
+  async check(): Promise<FeedCheckResult> {
+    const result: FeedCheckResult = { checked: 0, diverged: [], skipped: 0 };
+    this.stats.rounds++;
+
+    for (const tokenId of this.nextSample()) {
+      const before = this.store.get(tokenId);
+      if (before?.source !== "WS") continue;
+
+      let rest: ReturnType<typeof normalizeRestOrderbook> | null = null;
+      try {
+        const book = normalizeRestOrderbook(await this.fetchBook(tokenId));
+        if (book.bids.length > 0 && book.asks.length > 0) rest = book;
+      } catch {
+        // A failed fetch says nothing about the feed
+      }
+
+      const after = this.store.get(tokenId);
+      if (
+        !rest ||
+        after?.source !== "WS" ||
+        after.bestBid !== before.bestBid ||
+        after.bestAsk !== before.bestAsk
+      ) {
+        result.skipped++;
+        continue;
+      }
+
+      result.checked++;
+      this.stats.checked++;
+      const restBid = rest.bids[0].price;
+      const restAsk = rest.asks[0].price;
+      const diffCents =
+        Math.max(
+          Math.abs(after.bestBid - restBid),
+          Math.abs(after.bestAsk - restAsk),
+        ) * 100;
+      // Rounded so float noise (0.51 - 0.5) doesn't cross the tolerance
+      if (Number(diffCents.toFixed(6)) <= this.config.toleranceCents) {
+        continue;
+      }
+
+      const event: FeedDivergenceEvent = {
+        event: "FEED_DIVERGENCE",
+        timestamp: new Date().toISOString(),
+        tokenId,
+        wsBid: after.bestBid,
+        wsAsk: after.bestAsk,
+        restBid,
+        restAsk,
+        diffCents: Number(diffCents.toFixed(2)),
+        wsAgeMs: this.store.getAge(tokenId),
+      };
+      result.diverged.push(event);
+      // Trade on the REST book until the WS feed sends a new one
+      this.store.updateFromRest(tokenId, rest.bids, rest.asks);
+      this.notify(event);
+    }
+    return result;
+  }
+
+  getStats(): FeedValidatorStats {
+    return { ...this.stats };
+  }
+
+  /**
+   * Subscribe to FEED_DIVERGENCE
+   * @returns Unsubscribe function
+   */
+  onDivergence(listener: (event: FeedDivergenceEvent) => void): () => void {
+    this.listeners.push(listener);
+    return () => {
+      this.listeners = this.listeners.filter((l) => l !== listener);
+    };
+  }
+
+  /**
+   * Tokens with a fresh quote, continuing where the last round stopped
+   */
+  private nextSample(): string[] {
+    const candidates = this.store
+      .getTrackedTokens()
+      .filter((tokenId) => !this.store.isStale(tokenId));
+    if (candidates.length === 0) return [];
+
+    const count = Math.min(this.config.sampleSize, candidates.length);
+    const sample: string[] = [];
+    for (let i = 0; i < count; i++) {
+      sample.push(candidates[(this.cursor + i) % candidates.length]);
+    }
+    this.cursor = (this.cursor + count) % candidates.length;
+    return sample;
+  }
+
+  private notify(event: FeedDivergenceEvent): void {
+    this.stats.divergences++;
+    this.stats.lastDivergenceAt = Date.now();
+    emitEvent(event);
+    console.warn(
+      `📡 [FEED_DIVERGENCE] ${event.tokenId.slice(0, 12)}... WS ${event.wsBid}/${event.wsAsk} vs REST ${event.restBid}/${event.restAsk} (${event.diffCents}¢) - using REST`,
+    );
+
+    for (const listener of this.listeners) {
+      try {
+        listener(event);
+      } catch {
+        // A failing listener must not break the check
+      }
+    }
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: FeedValidator | null = null;
+
+/**
+ * Initialize the global feed validator
+ */
+export function initFeedValidator(
+  store: MarketDataStore,
+  fetchBook: RestBookFetcher,
+  config?: Partial<FeedValidatorConfig>,
+): FeedValidator {
+  instance = new FeedValidator(store, fetchBook, config);
+  return instance;
+}
+
+/**
+ * Get the global feed validator (null if not initialized)
+ */
+export function getFeedValidator(): FeedValidator | null {
+  return instance;
+}
@@ -0,0 +1,322 @@
+/**
+ * Fill Enricher - ORDER_FILL events that carry their own context
+ *
+ * A raw fill (user WebSocket trade, or a polled trade while the socket is
+ * down) only says token, side, price and size. Consumers that want to know
+ * which strategy placed the order, what price it was aiming for, or what
+ * the bot now holds would each have to join that themselves.
+ *
+ * Order placement registers an intent (order ID, strategy, intended price,
+ * free-form metadata); each fill is matched to its intent and emitted as a
+ * single ORDER_FILL event with:
+ *
+ * - strategy, order type and the placing code path's metadata
+ * - intended vs actual price, and the adverse slippage in cents
+ * - price improvement vs the best quote when the order was submitted
+ * - the running position in the token after the fill
+ *
+ * Fills are matched by order ID (taker or maker side), falling back to the
+ * latest intent on the same token and side. Duplicate trade IDs (the same
+ * trade seen on the socket and by polling) are emitted once.
+ *
+ * Price improvement is totalled per strategy and order type, to show
+ * whether FOK or marketable GTC orders fill better than the quote they
+ * were sent against.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type FillSide = "BUY" | "SELL";
+
+export interface OrderIntent {
+  orderId: string;
+  tokenId: string;
+  side: FillSide;
+  strategy?: string;
+  /** Price the order was meant to fill at (0-1) */
+  intendedPrice: number;
+  /** Best opposite quote when submitted - ask for a BUY, bid for a SELL */
+  quotedPrice?: number;
+  sizeUsd?: number;
+  orderType?: "FOK" | "GTC";
+  /** Context from the code path that placed the order */
+  meta?: Record<string, unknown>;
+  placedAt: number;
+}
+
+export interface RawFill {
+  tradeId: string;
+  /** Order IDs on the trade that may be ours (taker and maker) */
+  orderIds: string[];
+  tokenId: string;
+  side: FillSide;
+  /** Fill price (0-1) */
+  price: number;
+  shares: number;
+  timestamp: number;
+  source: "WS" | "POLL";
+}
+
+export interface OrderFillEvent {
+  event: "ORDER_FILL";
+  tradeId: string;
+  orderId: string | null;
+  matchedBy: "ORDER_ID" | "TOKEN" | "NONE";
+  source: RawFill["source"];
+  tokenId: string;
+  side: FillSide;
+  strategy: string | null;
+  orderType: OrderIntent["orderType"] | null;
+  meta: Record<string, unknown> | null;
+  price: number;
+  shares: number;
+  notionalUsd: number;
+  intendedPrice: number | null;
+  /** Fill vs intended price in cents - positive = worse than intended */
+  slippageCents: number | null;
+  quotedPrice: number | null;
+  /** Fill vs quoted price in cents - positive = better than the quote */
+  priceImprovementCents: number | null;
+  /** Shares held in the token after this fill */
+  positionShares: number;
+  timestamp: string;
+}
+
+export interface PriceImprovementStats {
+  strategy: string;
+  orderType: string;
+  fills: number;
+  shares: number;
+  /** Total improvement in USD - negative = filled worse than quoted */
+  improvementUsd: number;
+  /** Share-weighted average improvement per share */
+  avgImprovementCents: number;
+}
+
+export interface FillEnricherConfig {
+  /** Intents kept for matching (oldest dropped first) */
+  maxIntents: number;
+  /** Intents older than this no longer match */
+  intentTtlMs: number;
+  /** Trade IDs remembered for de-duplication */
+  maxSeenTrades: number;
+  /** Current holdings, used to seed a token's running position */
+  positionSource?: (tokenId: string) => number;
+}
+
+export const DEFAULT_FILL_ENRICHER_CONFIG: FillEnricherConfig = {
+  maxIntents: 500,
+  intentTtlMs: 24 * 60 * 60 * 1000,
+  maxSeenTrades: 5000,
+};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FILL ENRICHER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class FillEnricher {
+  private readonly config: FillEnricherConfig;
+  private intents = new Map<string, OrderIntent>();
+  private seenTrades = new Set<string>();
+  private positions = new Map<string, number>();
+  private improvement = new Map<string, PriceImprovementStats>();
+
+  constructor(config: Partial<FillEnricherConfig> = {}) {
+    const d = DEFAULT_FILL_ENRICHER_CONFIG;
+    this.config = {
+      maxIntents: config.maxIntents ?? d.maxIntents,
+      intentTtlMs: config.intentTtlMs ?? d.intentTtlMs,
+      maxSeenTrades: config.maxSeenTrades ?? d.maxSeenTrades,
+      positionSource: config.positionSource,
+    };
+  }
+
+  /**
+   * Record what an order was placed for. Call before the placement updates
+   * the position source, so the token's running position starts from the
+   * holdings before this order.
+   */
+  registerOrder(
+    intent: Omit<OrderIntent, "placedAt"> & { placedAt?: number },
+  ): void {
+    if (!intent.orderId) return;
+    this.seedPosition(intent.tokenId);
+    this.intents.delete(intent.orderId);
+    this.intents.set(intent.orderId, {
+      ...intent,
+      placedAt: intent.placedAt ?? Date.now(),
+    });
+    while (this.intents.size > this.config.maxIntents) {
+      this.intents.delete(this.intents.keys().next().value!);
+    }
+  }
+
+  getIntent(orderId: string): OrderIntent | null {
+    return this.intents.get(orderId) ?? null;
+  }
+
+  /**
+   * Enrich and emit a fill
+   * @returns The ORDER_FILL event, or null for an already-seen trade
+   */
+  record(fill: RawFill): OrderFillEvent | null {
+    if (!fill.tradeId || this.seenTrades.has(fill.tradeId)) return null;
+    this.seenTrades.add(fill.tradeId);
+    while (this.seenTrades.size > this.config.maxSeenTrades) {
+      this.seenTrades.delete(this.seenTrades.values().next().value!);
+    }
+
+    const [intent, matchedBy] = this.match(fill);
+    // A maker fill reports the taker's side - ours is the intent's
+    const side = intent?.side ?? fill.side;
+
+    const held = this.seedPosition(fill.tokenId);
+    const delta = side === "BUY" ? fill.shares : -fill.shares;
+    const positionShares = Math.max(0, held + delta);
+    this.positions.set(fill.tokenId, positionShares);
+
+    let slippageCents: number | null = null;
+    if (intent) {
+      const diff = (fill.price - intent.intendedPrice) * 100;
+      slippageCents = side === "BUY" ? diff : -diff;
+    }
+
+    let priceImprovementCents: number | null = null;
+    if (intent?.quotedPrice !== undefined) {
+      const diff = (intent.quotedPrice - fill.price) * 100;
+      priceImprovementCents = side === "BUY" ? diff : -diff;
+      this.addImprovement(intent, fill.shares, priceImprovementCents);
+    }
+
+    const event: OrderFillEvent = {
+      event: "ORDER_FILL",
+      tradeId: fill.tradeId,
+      orderId: intent?.orderId ?? fill.orderIds[0] ?? null,
+      matchedBy,
+      source: fill.source,
+      tokenId: fill.tokenId,
+      side,
+      strategy: intent?.strategy ?? null,
+      orderType: intent?.orderType ?? null,
+      meta: intent?.meta ?? null,
+      price: fill.price,
+      shares: fill.shares,
+      notionalUsd: fill.price * fill.shares,
+      intendedPrice: intent?.intendedPrice ?? null,
+      slippageCents,
+      quotedPrice: intent?.quotedPrice ?? null,
+      priceImprovementCents,
+      positionShares,
+      timestamp: new Date(fill.timestamp).toISOString(),
+    };
+    emitEvent(event);
+    return event;
+  }
+
+  /**
+   * Running position in a token (null before it has been seen)
+   */
+  getPosition(tokenId: string): number | null {
+    return this.positions.get(tokenId) ?? null;
+  }
+
+  /**
+   * Price improvement vs the submission quote per strategy and order type,
+   * most improvement first
+   */
+  getPriceImprovement(): PriceImprovementStats[] {
+    return [...this.improvement.values()]
+      .map((s) => ({ ...s }))
+      .sort((a, b) => b.improvementUsd - a.improvementUsd);
+  }
+
+  clear(): void {
+    this.intents.clear();
+    this.seenTrades.clear();
+    this.positions.clear();
+    this.improvement.clear();
+  }
+
+  private addImprovement(
+    intent: OrderIntent,
+    shares: number,
+    improvementCents: number,
+  ): void {
+    const strategy = intent.strategy ?? "untracked";
+    const orderType = intent.orderType ?? "unknown";
+    const key = `${strategy}|${orderType}`;
+    const stats = this.improvement.get(key) ?? {
+      strategy,
+      orderType,
+      fills: 0,
+      shares: 0,
+      improvementUsd: 0,
+      avgImprovementCents: 0,
+    };
+    stats.fills++;
+    stats.shares += shares;
+    stats.improvementUsd += (improvementCents / 100) * shares;
+    stats.avgImprovementCents =
+      stats.shares > 0 ? (stats.improvementUsd / stats.shares) * 100 : 0;
+    this.improvement.set(key, stats);
+  }
+
+  private match(
+    fill: RawFill,
+  ): [OrderIntent | null, OrderFillEvent["matchedBy"]] {
+    const cutoff = fill.timestamp - this.config.intentTtlMs;
+    for (const orderId of fill.orderIds) {
+      const intent = this.intents.get(orderId);
+      if (intent && intent.placedAt >= cutoff) return [intent, "ORDER_ID"];
+    }
+
+    let latest: OrderIntent | null = null;
+    for (const intent of this.intents.values()) {
+      if (
+        intent.tokenId === fill.tokenId &&
+        intent.side === fill.side &&
+        intent.placedAt >= cutoff &&
+        (!latest || intent.placedAt >= latest.placedAt)
+      ) {
+        latest = intent;
+      }
+    }
+    return latest ? [latest, "TOKEN"] : [null, "NONE"];
+  }
+
+  private seedPosition(tokenId: string): number {
+    let held = this.positions.get(tokenId);
+    if (held === undefined) {
+      held = this.config.positionSource?.(tokenId) ?? 0;
+      this.positions.set(tokenId, held);
+    }
+    return held;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: FillEnricher | null = null;
+
+/**
+ * Initialize the global fill enricher
+ */
+export function initFillEnricher(
+  config?: Partial<FillEnricherConfig>,
+): FillEnricher {
+  instance = new FillEnricher(config);
+  return instance;
+}
+
+/**
+ * Get the global fill enricher (null if not initialized)
+ */
+export function getFillEnricher(): FillEnricher | null {
+  return instance;
+}
@@ -0,0 +1,335 @@
+/**
+ * Fill Journal - Every fill on the account, for cost basis and PnL
+ *
+ * Fills seen live (ORDER_FILL, from the user socket or trade polling) are
+ * appended as they happen. An account that traded before the bot was
+ * running - or while it was down - is missing those fills, so its cost
+ * basis and realized PnL are wrong. backfillFillJournal() pulls the full
+ * trade history from the CLOB and merges it in:
+ *
+ * - one entry per trade ID, so a trade seen live and again in the history
+ *   is counted once
+ * - on trades where the account was the maker, only its own maker orders
+ *   count (at their price and matched size, on their side)
+ *
+ * Cost basis is average cost per token: a BUY adds shares at its price, a
+ * SELL realizes (price - average cost) per share it sells.
+ *
+ * The journal is saved to FILL_JOURNAL_PATH (default
+ * ./data/fill-journal.json, empty disables) after every change:
+ *
+ *   npm run journal-backfill
+ */
+
+import type { ClobClient } from "@polymarket/clob-client";
+import { envStr } from "../config/env";
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface JournalFill {
+  tradeId: string;
+  tokenId: string;
+  /** The account's side of the trade */
+  side: "BUY" | "SELL";
+  /** Fill price (0-1) */
+  price: number;
+  shares: number;
+  timestamp: number;
+  source: "WS" | "POLL" | "BACKFILL";
+  /** Whether the account's order rested (MAKER) or crossed (TAKER) */
+  liquidity?: "MAKER" | "TAKER";
+  /** Token mid when the fill arrived (0-1, live fills only) */
+  midAtFill?: number;
+  /** Token mid at the quote markout after the fill (see quote-report) */
+  midAfter?: number;
+}
+
+export interface FillMergeResult {
+  added: number;
+  duplicates: number;
+}
+
+export interface TokenCostBasis {
+  tokenId: string;
+  shares: number;
+  /** Average cost per share (0-1) */
+  avgCost: number;
+  costBasisUsd: number;
+  realizedPnlUsd: number;
+  fills: number;
+}
+
+/** Fields of a CLOB trade the backfill uses */
+export interface ClobTradeFields {
+  id: string;
+  asset_id: string;
+  side: string;
+  price: string;
+  size: string;
+  match_time: string;
+  trader_side?: "TAKER" | "MAKER";
+  maker_orders?: Array<{
+    maker_address: string;
+    matched_amount: string;
+    price: string;
+    side: string;
+    asset_id: string;
+  }>;
+}
+
+export interface BackfillResult extends FillMergeResult {
+  /** Trades returned by the CLOB */
+  fetched: number;
+  /** Trades with no fill for the account (maker side, other makers) */
+  skipped: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONVERSION
+// ═══════════════════════════════════════════════════════════════════════════
+
+function toSide(side: string): "BUY" | "SELL" {
+  return side.toUpperCase() === "SELL" ? "SELL" : "BUY";
+}
+
+/**
+ * The account's fill in a CLOB trade, or null if it has none.
+ *
+ * As taker the trade's own side, price and size are the account's. As
+ * maker, the trade lists every maker order it matched; the account's are
+ * the ones from `address`, combined at their volume-weighted price.
+ */
+export function tradeToFill(
+  trade: ClobTradeFields,
+  address: string,
+): JournalFill | null {
+  const timestamp = Number(trade.match_time) * 1000;
+  if (trade.trader_side !== "MAKER") {
+    return {
+      tradeId: trade.id,
+      tokenId: trade.asset_id,
+      side: toSide(trade.side),
+      price: Number(trade.price),
+      shares: Number(trade.size),
+      timestamp,
+      source: "BACKFILL",
+      liquidity: "TAKER",
+    };
+  }
+
+  const own = (trade.maker_orders ?? []).filter(
+    (m) => m.maker_address.toLowerCase() === address.toLowerCase(),
+  );
+  const shares = own.reduce((s, m) => s + Number(m.matched_amount), 0);
+  if (own.length === 0 || shares <= 0) return null;
+  const notional = own.reduce(
+    (s, m) => s + Number(m.matched_amount) * Number(m.price),
+    0,
+  );
+  return {
+    tradeId: trade.id,
+    tokenId: own[0].asset_id,
+    side: toSide(own[0].side),
+    price: notional / shares,
+    shares,
+    timestamp,
+    source: "BACKFILL",
+    liquidity: "MAKER",
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FILL JOURNAL
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class FillJournal {
+  private fills = new Map<string, JournalFill>();
+  private filePath: string | null = null;
+
+  /**
+   * Add one fill (ignored if its trade is already journaled)
+   * @returns Whether it was added
+   */
+  add(fill: JournalFill): boolean {
+    const added = this.insert(fill);
+    if (added) this.save();
+    return added;
+  }
+
+  /**
+   * Add many fills, saving once
+   */
+  merge(fills: JournalFill[]): FillMergeResult {
+    const result: FillMergeResult = { added: 0, duplicates: 0 };
+    for (const fill of fills) {
+      if (this.insert(fill)) result.added++;
+      else result.duplicates++;
+    }
+    if (result.added > 0) this.save();
+    return result;
+  }
+
+  /**
+   * Record markout mids on journaled fills, saving once
+   * @returns Fills updated
+   */
+  setMarkouts(markouts: Array<{ tradeId: string; midAfter: number }>): number {
+    let updated = 0;
+    for (const { tradeId, midAfter } of markouts) {
+      const fill = this.fills.get(tradeId);
+      if (!fill || !Number.isFinite(midAfter)) continue;
+      fill.midAfter = midAfter;
+      updated++;
+    }
+    if (updated > 0) this.save();
+    return updated;
+  }
+
+  /**
+   * Journaled fills, oldest first
+   */
+  getFills(tokenId?: string): JournalFill[] {
+    return [...this.fills.values()]
+      .filter((f) => tokenId === undefined || f.tokenId === tokenId)
+      .sort((a, b) => a.timestamp - b.timestamp);
+  }
+
+  /**
+   * Average-cost position and realized PnL per token
+   */
+  getCostBasis(): TokenCostBasis[] {
+    const byToken = new Map<string, TokenCostBasis>();
+    for (const fill of this.getFills()) {
+      const basis = byToken.get(fill.tokenId) ?? {
+        tokenId: fill.tokenId,
+        shares: 0,
+        avgCost: 0,
+        costBasisUsd: 0,
+        realizedPnlUsd: 0,
+        fills: 0,
+      };
+      basis.fills++;
+      if (fill.side === "BUY") {
+        basis.shares += fill.shares;
+        basis.costBasisUsd += fill.shares * fill.price;
+      } else {
+        // Shares sold beyond the journaled holdings (history older than the
+        // API keeps, transfers in) have no known cost - skip them
+        const sold = Math.min(fill.shares, basis.shares);
+        basis.realizedPnlUsd += sold * (fill.price - basis.avgCost);
+        basis.costBasisUsd -= sold * basis.avgCost;
+        basis.shares -= sold;
+      }
+      basis.avgCost = basis.shares > 0 ? basis.costBasisUsd / basis.shares : 0;
+      byToken.set(fill.tokenId, basis);
+    }
+    return [...byToken.values()];
+  }
+
+  size(): number {
+    return this.fills.size;
+  }
+
+  exportState(): JournalFill[] {
+    return this.getFills();
+  }
+
+  /**
+   * Restore saved fills (merged with any added since start)
+   */
+  importState(saved: JournalFill[]): number {
+    let restored = 0;
+    for (const fill of saved) {
+      if (fill?.tradeId && fill.tokenId && this.insert(fill)) restored++;
+    }
+    return restored;
+  }
+
+  /**
+   * Restore from `filePath` and save there after every change from now on
+   */
+  attachStateFile(filePath: string): void {
+    this.filePath = filePath;
+    const result = loadStateFile<JournalFill[]>(filePath);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [FillJournal] Failed to load ${filePath}: ${result.error}`,
+      );
+      return;
+    }
+    if (result.payload) this.importState(result.payload);
+  }
+
+  private insert(fill: JournalFill): boolean {
+    if (!fill.tradeId || this.fills.has(fill.tradeId)) return false;
+    if (!(fill.shares > 0) || !Number.isFinite(fill.price)) return false;
+    this.fills.set(fill.tradeId, { ...fill });
+    return true;
+  }
+
+  private save(): void {
+    if (!this.filePath) return;
+    const result = saveStateFile(this.filePath, this.exportState());
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [FillJournal] Failed to save ${this.filePath}: ${result.error}`,
+      );
+    }
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BACKFILL
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Pull the account's full trade history and merge it into the journal
+ *
+ * @param address - Funder address (proxy in proxy mode), to pick out the
+ *   account's maker orders
+ */
+export async function backfillFillJournal(
+  client: ClobClient,
+  journal: FillJournal,
+  address: string,
+): Promise<BackfillResult> {
+  // only_first_page=false follows next_cursor through every page
+  const trades = (await client.getTrades(
+    undefined,
+    false,
+  )) as unknown as ClobTradeFields[];
+
+  const fills: JournalFill[] = [];
+  for (const trade of trades) {
+    const fill = tradeToFill(trade, address);
+    if (fill) fills.push(fill);
+  }
+
+  return {
+    fetched: trades.length,
+    skipped: trades.length - fills.length,
+    ...journal.merge(fills),
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: FillJournal | null = null;
+
+/**
+ * Get the global fill journal (created on first use, restored from
+ * FILL_JOURNAL_PATH)
+ */
+export function getFillJournal(): FillJournal {
+  if (!instance) {
+    instance = new FillJournal();
+    const filePath = envStr("FILL_JOURNAL_PATH", "./data/fill-journal.json");
+    if (filePath) instance.attachStateFile(filePath);
+  }
+  return instance;
+}
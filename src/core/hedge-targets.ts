@@ -0,0 +1,253 @@
+/**
+ * Hedge Targets - Keep a set fraction of a position hedged
+ *
+ * Trigger hedging (HEDGE_TRIGGER_CENTS) buys the complement only once the
+ * price has moved against a position. A hedge target instead holds the
+ * complement at a fixed fraction of the position from the first fill on,
+ * and tops it up or trims it as that drifts:
+ *
+ *   HEDGE_TARGETS="<marketId|tokenId>:0.3;*:0.1"
+ *
+ * 0.3 keeps complement shares at 30% of the position's shares, i.e. a net
+ * delta of 70%; `*` applies to every market without its own target, and
+ * 0 turns hedging off for a market. Positions with a target are hedged to
+ * it instead of by the trigger.
+ *
+ *   HEDGE_TARGET_MIN_USD=1   # smaller adjustments are left alone
+ *
+ * Targets can be changed while the bot runs. Overrides are saved to
+ * HEDGE_TARGETS_PATH (default ./data/hedge-targets.json), which the bot
+ * re-reads when it changes on disk:
+ *
+ *   npm run hedge-target -- list
+ *   npm run hedge-target -- set <marketId|tokenId|*> <ratio>
+ *   npm run hedge-target -- clear <marketId|tokenId|*>
+ */
+
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+import type { ManagedPosition } from "./decision-engine";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** Hedge ratio per market ID / token ID ("*" = every market) */
+export type HedgeTargetMap = Record<string, number>;
+
+export interface HedgeAdjustment {
+  /** BUY tops the complement up, SELL trims it */
+  side: "BUY" | "SELL";
+  /** Complement shares to trade */
+  shares: number;
+  /** Value of those shares at the complement price */
+  sizeUsd: number;
+  targetRatio: number;
+  /** Complement shares / position shares before the adjustment */
+  currentRatio: number;
+}
+
+export const DEFAULT_HEDGE_TARGETS_PATH = "./data/hedge-targets.json";
+export const WILDCARD_TARGET = "*";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING & MATH
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse a hedge ratio (0-1)
+ * @returns null if it isn't one
+ */
+export function parseHedgeRatio(value: string): number | null {
+  const ratio = Number(value.trim());
+  return value.trim() !== "" && ratio >= 0 && ratio <= 1 ? ratio : null;
+}
+
+/**
+ * Parse HEDGE_TARGETS ("<marketId|tokenId>:0.3;*:0.1").
+ * Entries without a ratio between 0 and 1 are skipped.
+ */
+export function parseHedgeTargets(spec: string): HedgeTargetMap {
+  const targets: HedgeTargetMap = {};
+  for (const entry of spec.split(";")) {
+    const sep = entry.lastIndexOf(":");
+    if (sep === -1) continue;
+    const key = entry.slice(0, sep).trim();
+    const ratio = parseHedgeRatio(entry.slice(sep + 1));
+    if (key && ratio !== null) targets[key] = ratio;
+  }
+  return targets;
+}
+
+/**
+ * Shares held in a position and in its hedge legs
+ *
+ * Like the hedge unwind, leg shares are estimated from their entry price.
+ */
+export function hedgeExposure(position: ManagedPosition): {
+  positionShares: number;
+  hedgedShares: number;
+} {
+  const sharesOf = (sizeUsd: number, priceCents: number) =>
+    priceCents > 0 ? sizeUsd / (priceCents / 100) : 0;
+  return {
+    positionShares: sharesOf(position.entrySizeUsd, position.entryPriceCents),
+    hedgedShares: position.hedges.reduce(
+      (sum, h) => sum + sharesOf(h.sizeUsd, h.entryPriceCents),
+      0,
+    ),
+  };
+}
+
+/**
+ * Complement trade that brings a position to its hedge target
+ *
+ * @param complementPriceCents - Price the complement trades at (ask to
+ *   buy, bid to sell)
+ * @param minOrderUsd - Smaller adjustments are skipped
+ * @returns null when the position is close enough to its target
+ */
+export function planHedgeAdjustment(
+  position: ManagedPosition,
+  targetRatio: number,
+  complementPriceCents: number,
+  minOrderUsd: number,
+): HedgeAdjustment | null {
+  const { positionShares, hedgedShares } = hedgeExposure(position);
+  if (positionShares <= 0 || complementPriceCents <= 0) return null;
+
+  const diff = targetRatio * positionShares - hedgedShares;
+  const shares = Math.abs(diff);
+  const sizeUsd = shares * (complementPriceCents / 100);
+  if (sizeUsd < minOrderUsd || sizeUsd <= 0) return null;
+
+  return {
+    side: diff > 0 ? "BUY" : "SELL",
+    shares,
+    sizeUsd,
+    targetRatio,
+    currentRatio: hedgedShares / positionShares,
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// HEDGE TARGETS
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class HedgeTargets {
+  /** Runtime overrides (saved to the state file) */
+  private overrides: HedgeTargetMap = {};
+  private filePath: string | null = null;
+  private savedAt: number | undefined;
+
+  constructor(private readonly configured: HedgeTargetMap = {}) {}
+
+  /**
+   * Target for a position: its market's, else its token's, else "*"
+   * @returns undefined when the position has no target
+   */
+  targetFor(
+    position: Pick<ManagedPosition, "marketId" | "tokenId">,
+  ): number | undefined {
+    const targets = this.list();
+    for (const key of [position.marketId, position.tokenId]) {
+      if (key && targets[key] !== undefined) return targets[key];
+    }
+    return targets[WILDCARD_TARGET];
+  }
+
+  /**
+   * Effective targets (overrides on top of HEDGE_TARGETS)
+   */
+  list(): HedgeTargetMap {
+    return { ...this.configured, ...this.overrides };
+  }
+
+  /**
+   * Override the target for a market, token or "*"
+   */
+  set(key: string, ratio: number): void {
+    this.overrides[key] = Math.min(1, Math.max(0, ratio));
+    this.save();
+  }
+
+  /**
+   * Drop an override (HEDGE_TARGETS applies again)
+   * @returns Whether there was one
+   */
+  clear(key: string): boolean {
+    if (this.overrides[key] === undefined) return false;
+    delete this.overrides[key];
+    this.save();
+    return true;
+  }
+
+  /**
+   * Restore overrides from `filePath` and save there after every change
+   */
+  attachStateFile(filePath: string): void {
+    this.filePath = filePath;
+    this.refresh();
+  }
+
+  /**
+   * Re-read the state file if it was changed by someone else (e.g. the
+   * hedge-target script)
+   */
+  refresh(): void {
+    if (!this.filePath) return;
+    const result = loadStateFile<HedgeTargetMap>(this.filePath);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [HedgeTargets] Failed to load ${this.filePath}: ${result.error}`,
+      );
+      return;
+    }
+    if (!result.payload || result.savedAt === this.savedAt) return;
+    this.savedAt = result.savedAt;
+    this.overrides = {};
+    for (const [key, ratio] of Object.entries(result.payload)) {
+      if (typeof ratio === "number" && ratio >= 0 && ratio <= 1) {
+        this.overrides[key] = ratio;
+      }
+    }
+  }
+
+  private save(): void {
+    if (!this.filePath) return;
+    const result = saveStateFile(this.filePath, this.overrides);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [HedgeTargets] Failed to save ${this.filePath}: ${result.error}`,
+      );
+      return;
+    }
+    this.savedAt = result.savedAt;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: HedgeTargets | null = null;
+
+/**
+ * Initialize the global hedge targets
+ *
+ * @param filePath - Where overrides are persisted (empty = memory only)
+ */
+export function initHedgeTargets(
+  configured: HedgeTargetMap = {},
+  filePath = DEFAULT_HEDGE_TARGETS_PATH,
+): HedgeTargets {
+  instance = new HedgeTargets(configured);
+  if (filePath) instance.attachStateFile(filePath);
+  return instance;
+}
+
+/**
+ * Get the global hedge targets (null if not initialized)
+ */
+export function getHedgeTargets(): HedgeTargets | null {
+  return instance;
+}
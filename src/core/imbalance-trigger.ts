@@ -0,0 +1,367 @@
+/**
+ * Imbalance Triggers - Fire on a lopsided book before the price moves
+ *
+ * Price alerts fire once the mid has moved, which for a momentum snipe is
+ * too late. An imbalance trigger watches the resting size near the mid
+ * instead: when one side outweighs the other by a ratio and keeps doing
+ * so for a hold time, it fires an IMBALANCE_TRIGGER event.
+ *
+ *   IMBALANCE_TRIGGERS="<tokenId>:bid=5,band=2,hold=1500;<tokenId>:ask=4"
+ *
+ * - bid=5    bid size >= 5x ask size (ask=N: the other way round)
+ * - band=2   only levels within 2¢ of the mid count (default 2)
+ * - hold=1500  the ratio must hold for 1500 ms (default 1000)
+ * - repeat   re-arm once the imbalance is gone (default: fire once)
+ *
+ * Books come from the market data store as they stream in, size-only
+ * changes included. Like price alerts, firings go to the event sinks and
+ * Telegram so an external bridge can place the order, and fired state is
+ * saved with the engine state.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+import type {
+  MarketDataStore,
+  OrderbookLevel,
+  OrderbookSnapshot,
+} from "../lib/market-data-store";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type ImbalanceSide = "BID" | "ASK";
+
+export interface ImbalanceTriggerSpec {
+  tokenId: string;
+  /** Side that must be heavier */
+  side: ImbalanceSide;
+  /** Heavy side size / light side size that fires */
+  ratio: number;
+  /** Levels within this many cents of the mid count */
+  bandCents: number;
+  /** How long the ratio must hold (ms) */
+  holdMs: number;
+  /** Re-arm once the imbalance is gone */
+  repeat: boolean;
+}
+
+export interface ImbalanceTrigger extends ImbalanceTriggerSpec {
+  /** Stable ID derived from the spec */
+  id: string;
+  /** Armed = the next sustained imbalance fires */
+  armed: boolean;
+  /** One-shot trigger that has fired */
+  done: boolean;
+  /** When the current imbalance started (not persisted) */
+  since?: number;
+  lastFiredAt?: number;
+}
+
+export interface BookImbalance {
+  bidSize: number;
+  askSize: number;
+  mid: number;
+}
+
+export interface ImbalanceTriggerEvent {
+  event: "IMBALANCE_TRIGGER";
+  triggerId: string;
+  tokenId: string;
+  side: ImbalanceSide;
+  /** Heavy side size / light side size when it fired (null = the light
+   *  side was empty) */
+  ratio: number | null;
+  threshold: number;
+  bidSize: number;
+  askSize: number;
+  mid: number;
+  heldMs: number;
+  repeat: boolean;
+  timestamp: string;
+}
+
+const DEFAULT_BAND_CENTS = 2;
+const DEFAULT_HOLD_MS = 1000;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse IMBALANCE_TRIGGERS ("<tokenId>:bid=5,band=2,hold=1500,repeat;...").
+ * Entries without a valid ratio (> 1) are skipped.
+ */
+export function parseImbalanceTriggers(spec: string): ImbalanceTriggerSpec[] {
+  const triggers: ImbalanceTriggerSpec[] = [];
+
+  for (const entry of spec.split(";")) {
+    const sep = entry.indexOf(":");
+    if (sep === -1) continue;
+    const tokenId = entry.slice(0, sep).trim();
+    if (!tokenId) continue;
+
+    let side: ImbalanceSide | null = null;
+    let ratio = NaN;
+    let bandCents = DEFAULT_BAND_CENTS;
+    let holdMs = DEFAULT_HOLD_MS;
+    let repeat = false;
+    for (const part of entry.slice(sep + 1).split(",")) {
+      const [rawKey, rawValue] = part.split("=");
+      const key = rawKey?.trim().toLowerCase();
+      const value = Number(rawValue?.trim());
+      if (key === "repeat") repeat = true;
+      else if (key === "bid" || key === "ask") {
+        side = key === "bid" ? "BID" : "ASK";
+        ratio = value;
+      } else if (key === "band" && value > 0) bandCents = value;
+      else if (key === "hold" && value >= 0) holdMs = value;
+    }
+    if (side && Number.isFinite(ratio) && ratio > 1) {
+      triggers.push({ tokenId, side, ratio, bandCents, holdMs, repeat });
+    }
+  }
+
+  return triggers;
+}
+
+/**
+ * Stable ID for a trigger spec
+ */
+export function imbalanceTriggerId(spec: ImbalanceTriggerSpec): string {
+  return [
+    spec.tokenId,
+    spec.side,
+    spec.ratio,
+    spec.bandCents,
+    spec.holdMs,
+    spec.repeat ? "r" : "",
+  ].join(":");
+}
+
+/**
+ * Resting size on each side within bandCents of the mid
+ * @returns null without both a bid and an ask
+ */
+export function measureImbalance(
+  bids: OrderbookLevel[],
+  asks: OrderbookLevel[],
+  bandCents: number,
+): BookImbalance | null {
+  const bestBid = Math.max(...bids.map((l) => l.price));
+  const bestAsk = Math.min(...asks.map((l) => l.price));
+  if (!Number.isFinite(bestBid) || !Number.isFinite(bestAsk)) return null;
+
+  const mid = (bestBid + bestAsk) / 2;
+  // A hair of slack so a level exactly on the band edge counts
+  const band = bandCents / 100 + 1e-9;
+  const within = (levels: OrderbookLevel[]) =>
+    levels
+      .filter((l) => Math.abs(l.price - mid) <= band)
+      .reduce((sum, l) => sum + l.size, 0);
+  return { bidSize: within(bids), askSize: within(asks), mid };
+}
+
+function heavyRatio(side: ImbalanceSide, m: BookImbalance): number {
+  const [heavy, light] =
+    side === "BID" ? [m.bidSize, m.askSize] : [m.askSize, m.bidSize];
+  if (heavy <= 0) return 0;
+  return light > 0 ? heavy / light : Infinity;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// IMBALANCE TRIGGER MANAGER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class ImbalanceTriggerManager {
+  private triggers = new Map<string, ImbalanceTrigger>();
+  /** Latest measurement per trigger, for holds that end between books */
+  private latest = new Map<string, BookImbalance>();
+  private listeners: Array<(event: ImbalanceTriggerEvent) => void> = [];
+
+  /**
+   * Add a trigger unless one with the same spec exists
+   */
+  add(spec: ImbalanceTriggerSpec): ImbalanceTrigger {
+    const id = imbalanceTriggerId(spec);
+    const existing = this.triggers.get(id);
+    if (existing) return existing;
+
+    const trigger: ImbalanceTrigger = {
+      ...spec,
+      id,
+      armed: true,
+      done: false,
+    };
+    this.triggers.set(id, trigger);
+    return trigger;
+  }
+
+  remove(id: string): boolean {
+    this.latest.delete(id);
+    return this.triggers.delete(id);
+  }
+
+  list(): ImbalanceTrigger[] {
+    return [...this.triggers.values()];
+  }
+
+  /**
+   * Tokens with a trigger that can still fire (their books must stream)
+   */
+  getWatchedTokens(): string[] {
+    const tokens = new Set<string>();
+    for (const trigger of this.triggers.values()) {
+      if (!trigger.done) tokens.add(trigger.tokenId);
+    }
+    return [...tokens];
+  }
+
+  /**
+   * Check a token's book
+   * @returns Triggers that fired
+   */
+  observe(
+    tokenId: string,
+    bids: OrderbookLevel[],
+    asks: OrderbookLevel[],
+    now = Date.now(),
+  ): ImbalanceTriggerEvent[] {
+    const fired: ImbalanceTriggerEvent[] = [];
+    for (const trigger of this.triggers.values()) {
+      if (trigger.tokenId !== tokenId || trigger.done) continue;
+      const m = measureImbalance(bids, asks, trigger.bandCents);
+      if (m) this.latest.set(trigger.id, m);
+      const event = this.evaluate(trigger, m, now);
+      if (event) fired.push(event);
+    }
+    return fired;
+  }
+
+  /**
+   * Fire triggers whose hold ended while no new book arrived
+   * @returns Triggers that fired
+   */
+  tick(now = Date.now()): ImbalanceTriggerEvent[] {
+    const fired: ImbalanceTriggerEvent[] = [];
+    for (const trigger of this.triggers.values()) {
+      if (trigger.done || trigger.since === undefined) continue;
+      const m = this.latest.get(trigger.id) ?? null;
+      const event = this.evaluate(trigger, m, now);
+      if (event) fired.push(event);
+    }
+    return fired;
+  }
+
+  /**
+   * Subscribe to IMBALANCE_TRIGGER
+   * @returns Unsubscribe function
+   */
+  onTrigger(listener: (event: ImbalanceTriggerEvent) => void): () => void {
+    this.listeners.push(listener);
+    return () => {
+      this.listeners = this.listeners.filter((l) => l !== listener);
+    };
+  }
+
+  /**
+   * Feed the triggers from a market data store's books
+   * @returns Unsubscribe function
+   */
+  attach(store: MarketDataStore): () => void {
+    return store.onBook((book: OrderbookSnapshot) => {
+      this.observe(book.tokenId, book.bids, book.asks, book.timestamp);
+    });
+  }
+
+  /**
+   * Fired state to persist with the engine state
+   */
+  exportState(): ImbalanceTrigger[] {
+    return this.list().map((trigger) => ({ ...trigger, since: undefined }));
+  }
+
+  /**
+   * Restore fired state for triggers that are still configured
+   * @returns Number of triggers restored
+   */
+  importState(saved: ImbalanceTrigger[]): number {
+    let restored = 0;
+    for (const s of saved) {
+      const trigger = this.triggers.get(s?.id);
+      if (!trigger) continue;
+      trigger.armed = s.armed;
+      trigger.done = s.done;
+      trigger.lastFiredAt = s.lastFiredAt;
+      restored++;
+    }
+    return restored;
+  }
+
+  clear(): void {
+    this.triggers.clear();
+    this.latest.clear();
+  }
+
+  private evaluate(
+    trigger: ImbalanceTrigger,
+    m: BookImbalance | null,
+    now: number,
+  ): ImbalanceTriggerEvent | null {
+    const ratio = m ? heavyRatio(trigger.side, m) : 0;
+    if (!m || ratio < trigger.ratio) {
+      // Imbalance gone - a repeating trigger may fire again
+      trigger.since = undefined;
+      trigger.armed = true;
+      return null;
+    }
+
+    trigger.since ??= now;
+    const heldMs = now - trigger.since;
+    if (!trigger.armed || heldMs < trigger.holdMs) return null;
+
+    trigger.armed = false;
+    trigger.done = !trigger.repeat;
+    trigger.lastFiredAt = now;
+
+    const event: ImbalanceTriggerEvent = {
+      event: "IMBALANCE_TRIGGER",
+      triggerId: trigger.id,
+      tokenId: trigger.tokenId,
+      side: trigger.side,
+      ratio: Number.isFinite(ratio) ? Number(ratio.toFixed(2)) : null,
+      threshold: trigger.ratio,
+      bidSize: m.bidSize,
+      askSize: m.askSize,
+      mid: m.mid,
+      heldMs,
+      repeat: trigger.repeat,
+      timestamp: new Date(now).toISOString(),
+    };
+    emitEvent(event);
+    for (const listener of this.listeners) {
+      try {
+        listener(event);
+      } catch {
+        // A failing listener must not break book handling
+      }
+    }
+    return event;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: ImbalanceTriggerManager | null = null;
+
+/**
+ * Get the global imbalance trigger manager
+ */
+export function getImbalanceTriggerManager(): ImbalanceTriggerManager {
+  if (!instance) {
+    instance = new ImbalanceTriggerManager();
+  }
+  return instance;
+}
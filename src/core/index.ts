@@ -9,6 +9,7 @@
  * - Risk management utilities (risk.ts)
  * - Decision engine for trade evaluation (decision-engine.ts)
  * - EV tracker for performance monitoring (ev-tracker.ts)
+ * - Rolling volatility per token (volatility.ts)
  */
 
 // Strategy interface and types
@@ -25,6 +26,10 @@ export {
   type RiskParams,
   calculateEffectiveBankroll,
   calculateTradeSize,
+  scaleSizeForVolatility,
+  calculateKellySize,
+  type KellySizeParams,
+  type KellySizeResult,
   checkPositionLimits,
   calculateDeployedCapital,
   checkDeploymentLimits,
@@ -173,3 +178,396 @@ export {
   type EntryValidation,
   type HedgeValidation,
 } from "./risk-guard";
+
+// Volatility - rolling realized volatility per token (indicators + sizing)
+export {
+  VolatilityEstimator,
+  DEFAULT_VOLATILITY_WINDOWS_MS,
+  initVolatilityEstimator,
+  getVolatilityEstimator,
+  type VolatilityWindow,
+  type VolatilityIndicators,
+} from "./volatility";
+
+// Order flow - rolling buy vs sell aggressor imbalance per token
+export {
+  OrderFlowTracker,
+  DEFAULT_ORDER_FLOW_WINDOWS_MS,
+  initOrderFlowTracker,
+  getOrderFlowTracker,
+  type OrderFlowWindow,
+  type OrderFlowIndicators,
+} from "./order-flow";
+
+// Market staleness - dead and delisted markets, excluded from scans
+export {
+  MarketStalenessIndex,
+  DEFAULT_MARKET_STALENESS_CONFIG,
+  initMarketStalenessIndex,
+  getMarketStalenessIndex,
+  isDelistedError,
+  formatStalenessCounts,
+  type MarketLiveness,
+  type MarketStalenessConfig,
+  type MarketStalenessEntry,
+} from "./market-staleness";
+
+// Feed validation - streamed quotes cross-checked against REST
+export {
+  FeedValidator,
+  DEFAULT_FEED_VALIDATOR_CONFIG,
+  initFeedValidator,
+  getFeedValidator,
+  type FeedValidatorConfig,
+  type FeedDivergenceEvent,
+  type FeedCheckResult,
+  type FeedValidatorStats,
+  type RestBookFetcher,
+} from "./feed-validator";
+
+// Correlation - lagging legs of related markets after a sharp move
+export {
+  CorrelationDetector,
+  DEFAULT_CORRELATION_CONFIG,
+  parseCorrelationGroups,
+  initCorrelationDetector,
+  getCorrelationDetector,
+  type CorrelationLeg,
+  type CorrelationGroup,
+  type CorrelationDetectorConfig,
+  type CorrelatedMoveEvent,
+  type CorrelatedMoveListener,
+  type LaggingLeg,
+} from "./correlation-detector";
+
+// Strategy schedule - per-strategy UTC windows and blackout periods
+export {
+  ENTRY_STRATEGIES,
+  parseTimeWindows,
+  parseBlackouts,
+  loadStrategyScheduleConfig,
+  getScheduleBlockReason,
+  getStrategyScheduleConfig,
+  setStrategyScheduleConfig,
+  type EntryStrategy,
+  type TimeWindow,
+  type BlackoutPeriod,
+  type StrategyScheduleConfig,
+} from "./strategy-schedule";
+
+// Capital allocation - per-strategy budgets, rebalanced by performance
+export {
+  CapitalAllocator,
+  DEFAULT_CAPITAL_ALLOCATOR_CONFIG,
+  parseStrategyWeights,
+  initCapitalAllocator,
+  getCapitalAllocator,
+  type CapitalAllocatorConfig,
+  type StrategyWeight,
+  type StrategyAllocation,
+} from "./capital-allocator";
+
+// Shadow mode - paper-trade strategies and compare against live
+export {
+  ShadowTracker,
+  parseShadowStrategies,
+  initShadowTracker,
+  getShadowTracker,
+  type TradeMode,
+  type ShadowReportRow,
+} from "./shadow-mode";
+
+// Whale scoring - rank followed whales and promote high scorers
+export {
+  WhaleScorer,
+  DEFAULT_WHALE_SCORER_CONFIG,
+  initWhaleScorer,
+  getWhaleScorer,
+  type WhaleScorerConfig,
+  type WhaleScore,
+} from "./whale-scorer";
+
+// Copy guard - copy delay and drift limits against whale fills
+export {
+  CopyGuard,
+  DEFAULT_COPY_RULE,
+  parseCopyTargetRules,
+  initCopyGuard,
+  getCopyGuard,
+  type CopyRule,
+  type WhaleFill,
+} from "./copy-guard";
+
+// Anti-chase throttle - block entries on spiking tokens
+export {
+  ChaseThrottle,
+  DEFAULT_CHASE_THROTTLE_CONFIG,
+  initChaseThrottle,
+  getChaseThrottle,
+  type ChaseThrottleConfig,
+  type ChaseBlockedEvent,
+} from "./chase-throttle";
+
+// Decision log - sequenced record of why each order was or wasn't sent
+export {
+  DecisionLog,
+  hashBook,
+  initDecisionLog,
+  getDecisionLog,
+  type DecisionAction,
+  type DecisionCheck,
+  type DecisionBook,
+  type DecisionInput,
+  type DecisionEvent,
+} from "./decision-log";
+
+// Order governor - per-market order cap over a rolling window
+export {
+  OrderGovernor,
+  DEFAULT_ORDER_GOVERNOR_CONFIG,
+  initOrderGovernor,
+  getOrderGovernor,
+  type OrderGovernorConfig,
+  type GovernedOrderKind,
+} from "./order-governor";
+
+// Market blacklist - persisted, added to after bad outcomes
+export {
+  MarketBlacklist,
+  DEFAULT_MARKET_BLACKLIST_CONFIG,
+  DEFAULT_MARKET_BLACKLIST_PATH,
+  parseBlacklistScope,
+  resolveBlacklistKeys,
+  initMarketBlacklist,
+  getMarketBlacklist,
+  type BlacklistReason,
+  type BlacklistScope,
+  type BlacklistEntry,
+  type MarketBlacklistConfig,
+  type MarketBlacklistedEvent,
+} from "./market-blacklist";
+
+// Hedge targets - keep a set fraction of positions hedged
+export {
+  HedgeTargets,
+  DEFAULT_HEDGE_TARGETS_PATH,
+  WILDCARD_TARGET,
+  parseHedgeRatio,
+  parseHedgeTargets,
+  hedgeExposure,
+  planHedgeAdjustment,
+  initHedgeTargets,
+  getHedgeTargets,
+  type HedgeTargetMap,
+  type HedgeAdjustment,
+} from "./hedge-targets";
+
+// Job scheduler - rebalance, dust, stale orders and reports at set times
+export {
+  JobScheduler,
+  DEFAULT_JOB_SCHEDULE_PATH,
+  JOB_NAMES,
+  parseJobSpec,
+  parseJobSchedule,
+  nextJobRun,
+  initJobScheduler,
+  getJobScheduler,
+  type JobName,
+  type JobTiming,
+  type JobSpec,
+  type JobRun,
+  type ScheduledJob,
+  type JobHandler,
+  type JobRunEvent,
+} from "./job-scheduler";
+
+// Signal queue - entries waiting for a free position slot
+export {
+  SignalQueue,
+  DEFAULT_SIGNAL_QUEUE_PATH,
+  isPositionLimitReason,
+  initSignalQueue,
+  getSignalQueue,
+  type SignalQueueConfig,
+  type QueuedSignal,
+  type SignalQueueAction,
+  type SignalQueueEvent,
+} from "./signal-queue";
+
+// Profit compounding - realized P&L steps the per-trade cap
+export {
+  ProfitCompounder,
+  DEFAULT_COMPOUND_STATE_PATH,
+  parseCompoundSchedule,
+  initProfitCompounder,
+  getProfitCompounder,
+  type CompoundStep,
+  type ProfitCompounderConfig,
+  type CompoundingStatus,
+  type CompoundCapChangedEvent,
+} from "./profit-compounder";
+
+// Order race arbiter - whale vs scan entries on the same market
+export {
+  OrderRaceArbiter,
+  DEFAULT_ORDER_RACE_CONFIG,
+  parseOrderRaceMode,
+  parseOrderRacePriority,
+  formatOrderRace,
+  initOrderRaceArbiter,
+  getOrderRaceArbiter,
+  type OrderRaceMode,
+  type OrderRaceConfig,
+  type RaceIntent,
+  type OrderRaceKind,
+  type OrderRaceResolution,
+  type OrderRaceEvent,
+  type OrderClaimResult,
+} from "./order-race";
+
+// Signal edge - realized edge vs entry mid per entry signal
+export {
+  SignalEdgeTracker,
+  realizedEdgeCents,
+  entryCostCents,
+  initSignalEdgeTracker,
+  getSignalEdgeTracker,
+  type SignalEdgeRow,
+  type SignalTradeResult,
+} from "./signal-edge";
+
+// Balance watcher - alert on balance changes no journaled trade explains
+export {
+  BalanceWatcher,
+  DEFAULT_BALANCE_WATCHER_CONFIG,
+  USDC_ASSET,
+  initBalanceWatcher,
+  getBalanceWatcher,
+  type BalanceWatcherConfig,
+  type JournalEntry,
+  type JournalKind,
+  type BalanceAnomalyEvent,
+} from "./balance-watcher";
+
+// Overround monitor - neg-risk events whose YES prices don't sum to 1
+export {
+  OverroundMonitor,
+  DEFAULT_OVERROUND_MONITOR_CONFIG,
+  getOverroundFlag,
+  initOverroundMonitor,
+  getOverroundMonitor,
+  type OverroundMonitorConfig,
+  type OverroundFlag,
+  type OverroundReport,
+  type OverroundAlertEvent,
+} from "./overround-monitor";
+
+// Price alerts - one-shot or repeating PRICE_ALERT events per token
+export {
+  PriceAlertManager,
+  parsePriceAlerts,
+  priceAlertId,
+  getPriceAlertManager,
+  type PriceAlert,
+  type PriceAlertSpec,
+  type PriceAlertEvent,
+} from "./price-alerts";
+
+// Imbalance triggers - IMBALANCE_TRIGGER on a sustained lopsided book
+export {
+  ImbalanceTriggerManager,
+  parseImbalanceTriggers,
+  imbalanceTriggerId,
+  measureImbalance,
+  getImbalanceTriggerManager,
+  type ImbalanceSide,
+  type ImbalanceTriggerSpec,
+  type ImbalanceTrigger,
+  type BookImbalance,
+  type ImbalanceTriggerEvent,
+} from "./imbalance-trigger";
+
+// Fill enricher - ORDER_FILL events with strategy, intended price, position
+export {
+  FillEnricher,
+  DEFAULT_FILL_ENRICHER_CONFIG,
+  initFillEnricher,
+  getFillEnricher,
+  type FillSide,
+  type OrderIntent,
+  type RawFill,
+  type OrderFillEvent,
+  type FillEnricherConfig,
+  type PriceImprovementStats,
+} from "./fill-enricher";
+
+// Trade cost analysis - arrival-mid slippage, fees and timing per execution
+export {
+  TcaJournal,
+  buildTcaRecord,
+  getTcaJournal,
+  type TcaKind,
+  type TcaInput,
+  type TcaRecord,
+  type TcaQuery,
+  type TcaSummary,
+  type TcaRecordEvent,
+} from "./tca";
+
+// Fill journal - every account fill, history backfill, average cost basis
+export {
+  FillJournal,
+  tradeToFill,
+  backfillFillJournal,
+  getFillJournal,
+  type JournalFill,
+  type FillMergeResult,
+  type TokenCostBasis,
+  type ClobTradeFields,
+  type BackfillResult,
+} from "./fill-journal";
+
+// Quote report - spread capture vs adverse selection on maker fills
+export {
+  buildQuoteReport,
+  annotateMarkouts,
+  formatQuoteReport,
+  DEFAULT_QUOTE_MARKOUT_MS,
+  type QuoteMarketReport,
+} from "./quote-report";
+
+// Lot policy - per-strategy rounding of entry sizes
+export {
+  parseLotPolicies,
+  applyLotPolicy,
+  initLotPolicies,
+  getLotPolicy,
+  type LotPolicy,
+  type LotPolicies,
+} from "./lot-policy";
+
+// Liquidity floor - per-strategy book depth relative to order size
+export {
+  BELOW_LIQUIDITY_FLOOR,
+  parseLiquidityFloors,
+  checkLiquidityFloor,
+  formatLiquidityFloorCheck,
+  initLiquidityFloors,
+  getLiquidityFloor,
+  type LiquidityFloor,
+  type LiquidityFloors,
+  type LiquidityFloorCheck,
+} from "./liquidity-floor";
+
+// Dust cleaner - sell or redeem positions too small to matter
+export {
+  DustCleaner,
+  DEFAULT_DUST_CLEANER_CONFIG,
+  findDustPositions,
+  type DustCleanerConfig,
+  type DustCleanerDeps,
+  type DustAction,
+  type DustCleanupItem,
+  type DustCleanupReport,
+  type DustCleanupEvent,
+} from "./dust-cleaner";
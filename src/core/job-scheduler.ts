@@ -0,0 +1,380 @@
+/**
+ * Job Scheduler - Run housekeeping jobs at set times
+ *
+ * Interval checks in the cycle (ORDER_SWEEP_INTERVAL_SEC,
+ * DUST_CLEAN_INTERVAL_MIN...) run every N minutes from whenever the bot
+ * started. Jobs here run at fixed UTC times, or on an interval, from one
+ * schedule:
+ *
+ *   JOB_SCHEDULE="rebalance@every 30m; dust@daily 04:00;
+ *                 cancel-stale@mon-fri 13:25; report@21:00"
+ *
+ * Entries are `<job>@<when>`, `;`-separated. `when` is "every <n>m|h", or
+ * a UTC time with optional days as in STRATEGY_WINDOWS_* ("mon-fri 13:25",
+ * "sat+sun 09:00", "21:00" = every day). Jobs:
+ *
+ *   rebalance     trade positions to their hedge targets (HEDGE_TARGETS).
+ *                 While a rebalance job is scheduled, targets are only
+ *                 traded to when it runs instead of every cycle.
+ *   dust          sell or redeem dust positions (as DUST_CLEAN_INTERVAL_MIN)
+ *   cancel-stale  cancel stale resting orders (as ORDER_SWEEP_INTERVAL_SEC)
+ *   report        log the day's P&L, trades and positions, and send them
+ *                 to Telegram
+ *
+ * Times that passed while the bot was down are not caught up: after a
+ * restart each job is next due at its first time after startup (or after
+ * its last run, if later). Each run emits a JOB_RUN event.
+ *
+ * The schedule can be changed while the bot runs. Changes (and when each
+ * job last ran) are saved to JOB_SCHEDULE_PATH (default ./data/jobs.json),
+ * which the bot re-reads when it changes on disk:
+ *
+ *   npm run jobs -- list
+ *   npm run jobs -- add "<job>@<when>"
+ *   npm run jobs -- remove "<job>@<when>"
+ */
+
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+import { emitEvent } from "../infra/event-bus";
+import { ALL_DAYS, parseDays, parseMinute } from "./strategy-schedule";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type JobName = "rebalance" | "dust" | "cancel-stale" | "report";
+
+export const JOB_NAMES: JobName[] = [
+  "rebalance",
+  "dust",
+  "cancel-stale",
+  "report",
+];
+
+export type JobTiming =
+  | { kind: "every"; intervalMs: number }
+  /** At `minute` (after UTC midnight) on `days` (0 = Sunday) */
+  | { kind: "at"; days: number[]; minute: number };
+
+export interface JobSpec {
+  /** Normalized "<job>@<when>" - also how a job is removed */
+  id: string;
+  job: JobName;
+  timing: JobTiming;
+}
+
+export interface JobRun {
+  lastRunAt?: number;
+  /** Handler summary, or the error it failed with */
+  lastResult?: string;
+  ok?: boolean;
+  runs: number;
+}
+
+export interface ScheduledJob extends JobSpec, JobRun {
+  nextRunAt: number;
+  running: boolean;
+}
+
+/** Runs a job; the returned text is its result summary */
+export type JobHandler = () => Promise<string | void>;
+
+export interface JobRunEvent {
+  event: "JOB_RUN";
+  jobId: string;
+  job: JobName;
+  ok: boolean;
+  result: string;
+  durationMs: number;
+  timestamp: string;
+}
+
+interface JobState {
+  /** Jobs added at runtime (ids) */
+  added: string[];
+  /** JOB_SCHEDULE jobs removed at runtime (ids) */
+  removed: string[];
+  runs: Record<string, JobRun>;
+}
+
+export const DEFAULT_JOB_SCHEDULE_PATH = "./data/jobs.json";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING & TIMING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse one "<job>@<when>" entry
+ * @returns null if it isn't one
+ */
+export function parseJobSpec(entry: string): JobSpec | null {
+  const sep = entry.indexOf("@");
+  if (sep === -1) return null;
+  const job = entry.slice(0, sep).trim().toLowerCase() as JobName;
+  const when = entry
+    .slice(sep + 1)
+    .trim()
+    .toLowerCase()
+    .replace(/\s+/g, " ");
+  if (!JOB_NAMES.includes(job) || !when) return null;
+  const id = `${job}@${when}`;
+
+  const every = /^every (\d+) ?(m|h)$/.exec(when);
+  if (every) {
+    const minutes = Number(every[1]) * (every[2] === "h" ? 60 : 1);
+    if (minutes < 1) return null;
+    return { id, job, timing: { kind: "every", intervalMs: minutes * 60000 } };
+  }
+
+  const parts = when.split(" ");
+  const minute = parseMinute(parts.pop()!);
+  const days = parts.length > 0 ? parseDays(parts.join("")) : ALL_DAYS;
+  if (!days || minute === null || minute >= 24 * 60) return null;
+  return { id, job, timing: { kind: "at", days, minute } };
+}
+
+/**
+ * Parse JOB_SCHEDULE. Invalid entries are skipped with a warning.
+ */
+export function parseJobSchedule(spec: string): JobSpec[] {
+  const jobs: JobSpec[] = [];
+  for (const raw of spec.split(";")) {
+    if (!raw.trim()) continue;
+    const job = parseJobSpec(raw);
+    if (!job) {
+      console.warn(`⚠️ [Jobs] Ignoring invalid job "${raw.trim()}"`);
+      continue;
+    }
+    if (!jobs.some((j) => j.id === job.id)) jobs.push(job);
+  }
+  return jobs;
+}
+
+/**
+ * First time a job is due after `after`
+ */
+export function nextJobRun(timing: JobTiming, after: number): number {
+  if (timing.kind === "every") return after + timing.intervalMs;
+
+  const midnight = Math.floor(after / DAY_MS) * DAY_MS;
+  for (let d = 0; d <= 7; d++) {
+    const at = midnight + d * DAY_MS + timing.minute * 60000;
+    const day = new Date(at).getUTCDay();
+    if (at > after && timing.days.includes(day)) return at;
+  }
+  return Infinity;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// JOB SCHEDULER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class JobScheduler {
+  private added: JobSpec[] = [];
+  private removed = new Set<string>();
+  private runs = new Map<string, JobRun>();
+  private running = new Set<string>();
+  private handlers = new Map<JobName, JobHandler>();
+  private filePath: string | null = null;
+  private savedAt: number | undefined;
+
+  /**
+   * @param startedAt - Jobs are first due after this, whenever they last
+   *   ran (missed times aren't caught up)
+   */
+  constructor(
+    private readonly configured: JobSpec[] = [],
+    private readonly startedAt = Date.now(),
+  ) {}
+
+  /**
+   * Set what a job does (jobs without a handler are never run)
+   */
+  setHandler(job: JobName, handler: JobHandler): void {
+    this.handlers.set(job, handler);
+  }
+
+  /**
+   * Effective schedule (JOB_SCHEDULE plus runtime changes), soonest first
+   */
+  list(): ScheduledJob[] {
+    return [...this.configured, ...this.added]
+      .filter((spec) => !this.removed.has(spec.id))
+      .map((spec) => {
+        const run = this.runs.get(spec.id) ?? { runs: 0 };
+        const after = Math.max(run.lastRunAt ?? 0, this.startedAt);
+        return {
+          ...spec,
+          ...run,
+          nextRunAt: nextJobRun(spec.timing, after),
+          running: this.running.has(spec.id),
+        };
+      })
+      .sort((a, b) => a.nextRunAt - b.nextRunAt);
+  }
+
+  /**
+   * Whether any scheduled entry runs `job`
+   */
+  hasJob(job: JobName): boolean {
+    return this.list().some((j) => j.job === job);
+  }
+
+  /**
+   * Add a job to the schedule
+   * @returns false if it's already scheduled
+   */
+  add(spec: JobSpec): boolean {
+    if (this.list().some((j) => j.id === spec.id)) return false;
+    this.removed.delete(spec.id);
+    if (!this.configured.some((j) => j.id === spec.id)) this.added.push(spec);
+    this.save();
+    return true;
+  }
+
+  /**
+   * Take a job off the schedule
+   * @returns Whether it was scheduled
+   */
+  remove(id: string): boolean {
+    if (!this.list().some((j) => j.id === id)) return false;
+    const before = this.added.length;
+    this.added = this.added.filter((j) => j.id !== id);
+    if (this.added.length === before) this.removed.add(id);
+    this.save();
+    return true;
+  }
+
+  /**
+   * Start every due job that isn't already running
+   * @returns The jobs' runs (resolve when they finish, never reject)
+   */
+  tick(now = Date.now()): Promise<void>[] {
+    const due = this.list().filter(
+      (j) => j.nextRunAt <= now && !j.running && this.handlers.has(j.job),
+    );
+    return due.map((job) => this.run(job, now));
+  }
+
+  private async run(job: ScheduledJob, now: number): Promise<void> {
+    const handler = this.handlers.get(job.job)!;
+    // Next run counts from the due time, however long this one takes
+    this.running.add(job.id);
+    this.runs.set(job.id, {
+      ...this.runs.get(job.id),
+      lastRunAt: now,
+      runs: job.runs + 1,
+    });
+
+    let ok = true;
+    let result: string;
+    try {
+      result = (await handler()) || "done";
+    } catch (err) {
+      ok = false;
+      result = err instanceof Error ? err.message : String(err);
+    }
+    this.running.delete(job.id);
+
+    const run = this.runs.get(job.id);
+    if (run) this.runs.set(job.id, { ...run, ok, lastResult: result });
+    console.log(`⏰ [Jobs] ${job.id}: ${ok ? "" : "failed - "}${result}`);
+    emitEvent<JobRunEvent>({
+      event: "JOB_RUN",
+      jobId: job.id,
+      job: job.job,
+      ok,
+      result,
+      durationMs: Date.now() - now,
+      timestamp: new Date().toISOString(),
+    });
+    // Don't overwrite schedule changes made since the last refresh
+    this.refresh();
+    this.save();
+  }
+
+  /**
+   * Restore runtime changes and run history from `filePath`, and save
+   * there after every change and run
+   */
+  attachStateFile(filePath: string): void {
+    this.filePath = filePath;
+    this.refresh();
+  }
+
+  /**
+   * Re-read the state file if it was changed by someone else (e.g. the
+   * jobs script)
+   */
+  refresh(): void {
+    if (!this.filePath) return;
+    const result = loadStateFile<JobState>(this.filePath);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [Jobs] Failed to load ${this.filePath}: ${result.error}`,
+      );
+      return;
+    }
+    if (!result.payload || result.savedAt === this.savedAt) return;
+    this.savedAt = result.savedAt;
+    const { added = [], removed = [], runs = {} } = result.payload;
+
+    this.added = added
+      .map(parseJobSpec)
+      .filter((spec): spec is JobSpec => spec !== null);
+    this.removed = new Set(removed);
+    // Keep whichever record of a job's last run is newer
+    for (const [id, saved] of Object.entries(runs)) {
+      const current = this.runs.get(id);
+      if ((saved.lastRunAt ?? 0) > (current?.lastRunAt ?? 0)) {
+        this.runs.set(id, saved);
+      }
+    }
+  }
+
+  private save(): void {
+    if (!this.filePath) return;
+    const result = saveStateFile<JobState>(this.filePath, {
+      added: this.added.map((j) => j.id),
+      removed: [...this.removed],
+      runs: Object.fromEntries(this.runs),
+    });
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [Jobs] Failed to save ${this.filePath}: ${result.error}`,
+      );
+      return;
+    }
+    this.savedAt = result.savedAt;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: JobScheduler | null = null;
+
+/**
+ * Initialize the global job scheduler
+ *
+ * @param filePath - Where runtime changes are persisted (empty = memory
+ *   only)
+ */
+export function initJobScheduler(
+  configured: JobSpec[] = [],
+  filePath = DEFAULT_JOB_SCHEDULE_PATH,
+): JobScheduler {
+  instance = new JobScheduler(configured);
+  if (filePath) instance.attachStateFile(filePath);
+  return instance;
+}
+
+/**
+ * Get the global job scheduler (null if not initialized)
+ */
+export function getJobScheduler(): JobScheduler | null {
+  return instance;
+}
@@ -0,0 +1,166 @@
+/**
+ * Liquidity Floor - Per-strategy book depth required for an order's size
+ *
+ * A book can look healthy at the top (tight spread, not dust) and still be
+ * far too thin for the order about to hit it: 3 shares at the ask in front
+ * of a 40-share FOK. Each strategy can require depth relative to its own
+ * order size near the touch:
+ *
+ *   STRATEGY_LIQUIDITY_FLOOR="whale:mult=5,within=2;scan:mult=3,within=1"
+ *
+ * - mult=N: at least N x the order's shares on the side it trades against
+ * - within=C: counting only levels within C cents of the best price
+ *   (default 2)
+ *
+ * The floor is checked centrally before any entry order is submitted; an
+ * entry that fails it is rejected with BELOW_LIQUIDITY_FLOOR and the
+ * shortfall is logged. Strategies without a floor are not checked.
+ */
+
+import type { OrderbookLevel } from "../lib/market-data-store";
+import { ENTRY_STRATEGIES, type EntryStrategy } from "./strategy-schedule";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface LiquidityFloor {
+  /** Required depth as a multiple of the order's shares */
+  sizeMultiple: number;
+  /** Depth counted within this many cents of the best price */
+  withinCents: number;
+}
+
+export type LiquidityFloors = Partial<Record<EntryStrategy, LiquidityFloor>>;
+
+export interface LiquidityFloorCheck {
+  ok: boolean;
+  /** Shares within the window on the side the order trades against */
+  availableShares: number;
+  requiredShares: number;
+  /** Price the window was measured from (0-1) */
+  bestPrice: number | null;
+}
+
+export const BELOW_LIQUIDITY_FLOOR = "BELOW_LIQUIDITY_FLOOR";
+
+const DEFAULT_WITHIN_CENTS = 2;
+
+// Guards against float error (a level at exactly best + 2¢ being excluded)
+const EPSILON = 1e-9;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "whale:mult=5,within=2;scan:mult=3". Unknown strategies and
+ * entries without a positive mult are dropped.
+ */
+export function parseLiquidityFloors(spec: string): LiquidityFloors {
+  const floors: LiquidityFloors = {};
+
+  for (const entry of spec.split(";")) {
+    const sep = entry.indexOf(":");
+    if (sep === -1) continue;
+    const name = entry.slice(0, sep).trim() as EntryStrategy;
+    if (!ENTRY_STRATEGIES.includes(name)) continue;
+
+    let sizeMultiple = 0;
+    let withinCents = DEFAULT_WITHIN_CENTS;
+    for (const part of entry.slice(sep + 1).split(",")) {
+      const [rawKey, rawValue] = part.split("=");
+      const key = rawKey?.trim().toLowerCase();
+      const value = Number(rawValue?.trim());
+      if (key === "mult" && value > 0) sizeMultiple = value;
+      else if (key === "within" && value >= 0) withinCents = value;
+    }
+    if (sizeMultiple > 0) floors[name] = { sizeMultiple, withinCents };
+  }
+
+  return floors;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHECK
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Check an order against a floor
+ *
+ * @param levels - The side the order trades against (asks for a BUY, bids
+ *   for a SELL), in any order
+ * @param side - Order side
+ * @param shares - Order size in shares
+ */
+export function checkLiquidityFloor(
+  floor: LiquidityFloor,
+  levels: OrderbookLevel[],
+  side: "BUY" | "SELL",
+  shares: number,
+): LiquidityFloorCheck {
+  const requiredShares = shares * floor.sizeMultiple;
+  const prices = levels.filter((l) => l.size > 0).map((l) => l.price);
+  if (prices.length === 0) {
+    return { ok: false, availableShares: 0, requiredShares, bestPrice: null };
+  }
+
+  const bestPrice = side === "BUY" ? Math.min(...prices) : Math.max(...prices);
+  const window = floor.withinCents / 100 + EPSILON;
+  const availableShares = levels
+    .filter((l) =>
+      side === "BUY"
+        ? l.price <= bestPrice + window
+        : l.price >= bestPrice - window,
+    )
+    .reduce((sum, l) => sum + l.size, 0);
+
+  return {
+    ok: availableShares >= requiredShares,
+    availableShares,
+    requiredShares,
+    bestPrice,
+  };
+}
+
+/**
+ * One-line description for logs, e.g.
+ * "12.0 shares within 2¢ of 45.0¢, need 50.0 (5x 10.0)"
+ */
+export function formatLiquidityFloorCheck(
+  floor: LiquidityFloor,
+  check: LiquidityFloorCheck,
+): string {
+  const from =
+    check.bestPrice === null
+      ? "an empty side"
+      : `${(check.bestPrice * 100).toFixed(1)}¢`;
+  const shares = check.requiredShares / floor.sizeMultiple;
+  return (
+    `${check.availableShares.toFixed(1)} shares within ${floor.withinCents}¢ ` +
+    `of ${from}, need ${check.requiredShares.toFixed(1)} ` +
+    `(${floor.sizeMultiple}x ${shares.toFixed(1)})`
+  );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let floors: LiquidityFloors = {};
+
+/**
+ * Set the global per-strategy liquidity floors
+ */
+export function initLiquidityFloors(config: LiquidityFloors): void {
+  floors = { ...config };
+}
+
+/**
+ * Liquidity floor for a strategy (undefined = depth is not checked)
+ */
+export function getLiquidityFloor(
+  strategy: EntryStrategy,
+): LiquidityFloor | undefined {
+  return floors[strategy];
+}
@@ -0,0 +1,121 @@
+/**
+ * Lot Policy - Per-strategy rounding of entry sizes
+ *
+ * Sizes fall out of bankroll math as fractions ($7.3418, 14.6836 shares),
+ * and fractional positions leave dust behind after partial exits that is
+ * below the minimum order size and annoying to clear. Each strategy can
+ * declare how its entries are rounded:
+ *
+ *   STRATEGY_LOT_POLICY="whale:shares=1;scan:usd=5"
+ *
+ * - shares=N: whole multiples of N shares
+ * - usd=N: notional in $N steps
+ *
+ * Both may be given (the $ step is applied first). Sizes are always
+ * rounded down, so a policy never spends more than the sizing allowed; an
+ * entry that rounds to zero is rejected (BELOW_LOT_SIZE).
+ */
+
+import { ENTRY_STRATEGIES, type EntryStrategy } from "./strategy-schedule";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface LotPolicy {
+  /** Round shares down to a multiple of this */
+  shareStep?: number;
+  /** Round notional (USD) down to a multiple of this */
+  usdStep?: number;
+}
+
+export type LotPolicies = Partial<Record<EntryStrategy, LotPolicy>>;
+
+// Guards against float error (14.999999 shares rounding down to 14)
+const EPSILON = 1e-9;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "whale:shares=1;scan:usd=5". Unknown strategies and non-positive
+ * steps are dropped.
+ */
+export function parseLotPolicies(spec: string): LotPolicies {
+  const policies: LotPolicies = {};
+
+  for (const entry of spec.split(";")) {
+    const sep = entry.indexOf(":");
+    if (sep === -1) continue;
+    const name = entry.slice(0, sep).trim() as EntryStrategy;
+    if (!ENTRY_STRATEGIES.includes(name)) continue;
+
+    const policy: LotPolicy = {};
+    for (const part of entry.slice(sep + 1).split(",")) {
+      const [rawKey, rawValue] = part.split("=");
+      const key = rawKey?.trim().toLowerCase();
+      const value = Number(rawValue?.trim());
+      if (!(value > 0)) continue;
+      if (key === "shares") policy.shareStep = value;
+      else if (key === "usd") policy.usdStep = value;
+    }
+    if (policy.shareStep !== undefined || policy.usdStep !== undefined) {
+      policies[name] = policy;
+    }
+  }
+
+  return policies;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ROUNDING
+// ═══════════════════════════════════════════════════════════════════════════
+
+function roundDown(value: number, step: number): number {
+  return Math.floor(value / step + EPSILON) * step;
+}
+
+/**
+ * Round an order's shares down to the policy's lots
+ *
+ * @param shares - Unrounded order size
+ * @param price - Order price (0-1), for the $ step
+ * @returns Rounded shares (0 = below one lot)
+ */
+export function applyLotPolicy(
+  policy: LotPolicy | undefined,
+  shares: number,
+  price: number,
+): number {
+  if (!policy || !(shares > 0) || !(price > 0)) return shares;
+
+  let rounded = shares;
+  if (policy.usdStep) {
+    rounded = roundDown(rounded * price, policy.usdStep) / price;
+  }
+  if (policy.shareStep) {
+    rounded = roundDown(rounded, policy.shareStep);
+  }
+  return rounded;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let policies: LotPolicies = {};
+
+/**
+ * Set the global per-strategy lot policies
+ */
+export function initLotPolicies(config: LotPolicies): void {
+  policies = { ...config };
+}
+
+/**
+ * Lot policy for a strategy (undefined = sizes are not rounded)
+ */
+export function getLotPolicy(strategy: EntryStrategy): LotPolicy | undefined {
+  return policies[strategy];
+}
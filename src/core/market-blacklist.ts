@@ -0,0 +1,352 @@
+/**
+ * Market Blacklist - Stay out of markets that already went badly
+ *
+ * Some markets are worth never touching again: one that resolved against
+ * a big position, one where entries keep getting rejected, one whose
+ * resolution was disputed. The blacklist is added to automatically on
+ * those outcomes and checked before every entry, whatever the strategy:
+ *
+ *   BLACKLIST_RESOLVED_LOSS_USD=50     # resolved against a position that
+ *                                      # lost at least this (0 = off)
+ *   BLACKLIST_FAILED_FILLS=3           # rejected entries (FOK + GTC) on a
+ *   BLACKLIST_FAILED_FILLS_WINDOW_MINUTES=60   # market in the window (0 = off)
+ *   BLACKLIST_DISPUTED=true            # UMA resolution disputed
+ *   BLACKLIST_SCOPE=event              # market | event (every market of
+ *                                      # the event)
+ *
+ * An entry matches on any of the market's token IDs, condition ID and
+ * Gamma market ID. A market that resolved is closed already, so it's
+ * event scope that keeps a loss from repeating on its sibling markets.
+ *
+ * The blacklist is saved to MARKET_BLACKLIST_PATH (default
+ * ./data/market-blacklist.json) and re-read by the running bot when it
+ * changes on disk, so it can be managed while the bot runs:
+ *
+ *   npm run blacklist -- list
+ *   npm run blacklist -- add <tokenId|conditionId> [note]
+ *   npm run blacklist -- remove <tokenId|conditionId|marketId>
+ */
+
+import { emitEvent } from "../infra/event-bus";
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+import { fetchEventView } from "../lib/event-view";
+import {
+  fetchMarketByConditionId,
+  fetchMarketByTokenId,
+} from "../lib/market";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type BlacklistReason =
+  | "RESOLVED_AGAINST"
+  | "FAILED_FILLS"
+  | "DISPUTED"
+  | "MANUAL";
+
+export type BlacklistScope = "market" | "event";
+
+export interface BlacklistEntry {
+  /** Token IDs, condition IDs and market IDs the entry matches on */
+  keys: string[];
+  /** Market question (or the ID it was added by) */
+  label: string;
+  reason: BlacklistReason;
+  detail?: string;
+  addedAt: number;
+}
+
+export interface MarketBlacklistConfig {
+  /** Loss on a resolution that blacklists the market (USD, 0 = off) */
+  resolvedLossUsd: number;
+  /** Rejected entries within the window that blacklist it (0 = off) */
+  maxFailedFills: number;
+  failedFillWindowMs: number;
+  /** Blacklist markets whose resolution was disputed */
+  disputed: boolean;
+  scope: BlacklistScope;
+}
+
+export const DEFAULT_MARKET_BLACKLIST_CONFIG: MarketBlacklistConfig = {
+  resolvedLossUsd: 50,
+  maxFailedFills: 3,
+  failedFillWindowMs: 60 * 60_000,
+  disputed: true,
+  scope: "event",
+};
+
+export const DEFAULT_MARKET_BLACKLIST_PATH = "./data/market-blacklist.json";
+
+export interface MarketBlacklistedEvent extends BlacklistEntry {
+  event: "MARKET_BLACKLISTED";
+  timestamp: string;
+}
+
+/**
+ * Parse "market" / "event" (anything else = the default)
+ */
+export function parseBlacklistScope(value: string): BlacklistScope {
+  const scope = value.trim().toLowerCase();
+  return scope === "market" || scope === "event"
+    ? scope
+    : DEFAULT_MARKET_BLACKLIST_CONFIG.scope;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MARKET BLACKLIST
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class MarketBlacklist {
+  private readonly config: MarketBlacklistConfig;
+  private entries: BlacklistEntry[] = [];
+  private failedFills = new Map<string, number[]>();
+  private listeners = new Set<(entry: BlacklistEntry) => void>();
+  private filePath: string | null = null;
+  private savedAt: number | undefined;
+
+  constructor(config: Partial<MarketBlacklistConfig> = {}) {
+    this.config = { ...DEFAULT_MARKET_BLACKLIST_CONFIG, ...config };
+  }
+
+  /**
+   * Why an entry on any of these IDs is blocked, or null if it isn't
+   */
+  check(ids: Array<string | undefined>): string | null {
+    const entry = this.find(ids);
+    return entry ? `BLACKLISTED: ${entry.reason}` : null;
+  }
+
+  isBlacklisted(id: string): boolean {
+    return this.find([id]) !== null;
+  }
+
+  /**
+   * Blacklist a market by its IDs (merged into an existing entry that
+   * shares one of them)
+   * @returns The new entry, or null if it was already blacklisted
+   */
+  add(
+    keys: string[],
+    reason: BlacklistReason,
+    label = keys[0],
+    detail?: string,
+    now = Date.now(),
+  ): BlacklistEntry | null {
+    const ids = keys.filter(Boolean);
+    if (ids.length === 0) return null;
+    const existing = this.find(ids);
+    if (existing) {
+      existing.keys = [...new Set([...existing.keys, ...ids])];
+      this.save();
+      return null;
+    }
+
+    const entry: BlacklistEntry = {
+      keys: [...new Set(ids)],
+      label,
+      reason,
+      detail,
+      addedAt: now,
+    };
+    this.entries.push(entry);
+    this.save();
+    const event: MarketBlacklistedEvent = {
+      event: "MARKET_BLACKLISTED",
+      ...entry,
+      timestamp: new Date(now).toISOString(),
+    };
+    emitEvent(event);
+    for (const listener of this.listeners) listener(entry);
+    return entry;
+  }
+
+  /**
+   * Blacklist a market, first looking up the rest of its IDs (and, in
+   * event scope, every market of its event)
+   */
+  async blacklist(
+    id: string,
+    reason: BlacklistReason,
+    detail?: string,
+  ): Promise<BlacklistEntry | null> {
+    if (this.isBlacklisted(id)) return null;
+    const { keys, label } = await resolveBlacklistKeys(id, this.config.scope);
+    return this.add(keys, reason, label, detail);
+  }
+
+  /**
+   * Take the entries matching an ID off the blacklist
+   * @returns Entries removed
+   */
+  remove(id: string): number {
+    const before = this.entries.length;
+    this.entries = this.entries.filter((e) => !e.keys.includes(id));
+    const removed = before - this.entries.length;
+    if (removed > 0) this.save();
+    return removed;
+  }
+
+  /**
+   * Count a rejected entry against a market
+   * @returns Whether it reached BLACKLIST_FAILED_FILLS within the window
+   */
+  recordFailedFill(market: string, now = Date.now()): boolean {
+    if (this.config.maxFailedFills <= 0) return false;
+    const cutoff = now - this.config.failedFillWindowMs;
+    const stamps = (this.failedFills.get(market) ?? []).filter(
+      (t) => t > cutoff,
+    );
+    stamps.push(now);
+    if (stamps.length < this.config.maxFailedFills) {
+      this.failedFills.set(market, stamps);
+      return false;
+    }
+    this.failedFills.delete(market);
+    return true;
+  }
+
+  /**
+   * Whether a resolution lost enough to blacklist the market
+   */
+  isBadResolution(costUsd: number, valueUsd: number): boolean {
+    return (
+      this.config.resolvedLossUsd > 0 &&
+      costUsd - valueUsd >= this.config.resolvedLossUsd
+    );
+  }
+
+  blacklistsDisputes(): boolean {
+    return this.config.disputed;
+  }
+
+  /**
+   * Subscribe to markets being blacklisted
+   * @returns Unsubscribe function
+   */
+  onBlacklisted(listener: (entry: BlacklistEntry) => void): () => void {
+    this.listeners.add(listener);
+    return () => {
+      this.listeners.delete(listener);
+    };
+  }
+
+  /**
+   * Blacklisted markets, oldest first
+   */
+  list(): BlacklistEntry[] {
+    return [...this.entries];
+  }
+
+  /**
+   * Restore from `filePath` and save there after every change from now on
+   */
+  attachStateFile(filePath: string): void {
+    this.filePath = filePath;
+    this.refresh();
+  }
+
+  /**
+   * Re-read the state file if it was changed by someone else (e.g. the
+   * blacklist script)
+   */
+  refresh(): void {
+    if (!this.filePath) return;
+    const result = loadStateFile<BlacklistEntry[]>(this.filePath);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [Blacklist] Failed to load ${this.filePath}: ${result.error}`,
+      );
+      return;
+    }
+    if (!result.payload || result.savedAt === this.savedAt) return;
+    this.savedAt = result.savedAt;
+    this.entries = result.payload.filter(
+      (e) => Array.isArray(e?.keys) && e.keys.length > 0,
+    );
+  }
+
+  private find(ids: Array<string | undefined>): BlacklistEntry | null {
+    for (const id of ids) {
+      if (!id) continue;
+      const entry = this.entries.find((e) => e.keys.includes(id));
+      if (entry) return entry;
+    }
+    return null;
+  }
+
+  private save(): void {
+    if (!this.filePath) return;
+    const result = saveStateFile(this.filePath, this.entries);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [Blacklist] Failed to save ${this.filePath}: ${result.error}`,
+      );
+      return;
+    }
+    this.savedAt = result.savedAt;
+  }
+}
+
+/**
+ * Every ID of the market behind `id` - and in event scope of every market
+ * of its event - so the entry matches whichever one a strategy has
+ *
+ * @param id - Token ID or condition ID (0x...); anything else (e.g. a
+ *   Gamma market ID) can't be looked up and is used as is
+ */
+export async function resolveBlacklistKeys(
+  id: string,
+  scope: BlacklistScope,
+): Promise<{ keys: string[]; label: string }> {
+  const market = id.startsWith("0x")
+    ? await fetchMarketByConditionId(id)
+    : /^\d{20,}$/.test(id)
+      ? await fetchMarketByTokenId(id)
+      : null;
+  if (!market) return { keys: [id], label: id };
+
+  const keys = [
+    id,
+    market.conditionId,
+    market.marketId,
+    ...market.tokens.map((t) => t.tokenId),
+  ];
+  if (scope === "event" && market.eventId) {
+    const event = await fetchEventView(market.eventId);
+    for (const m of event?.markets ?? []) {
+      keys.push(m.conditionId, m.yesTokenId, m.noTokenId);
+    }
+  }
+  return {
+    keys: [...new Set(keys.filter(Boolean))],
+    label: market.question ?? id,
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: MarketBlacklist | null = null;
+
+/**
+ * Initialize the global market blacklist
+ *
+ * @param filePath - Where it's persisted (empty = memory only)
+ */
+export function initMarketBlacklist(
+  config?: Partial<MarketBlacklistConfig>,
+  filePath = DEFAULT_MARKET_BLACKLIST_PATH,
+): MarketBlacklist {
+  instance = new MarketBlacklist(config);
+  if (filePath) instance.attachStateFile(filePath);
+  return instance;
+}
+
+/**
+ * Get the global market blacklist (null if not initialized)
+ */
+export function getMarketBlacklist(): MarketBlacklist | null {
+  return instance;
+}
@@ -0,0 +1,296 @@
+/**
+ * Market Staleness Index - Spot dead and delisted markets
+ *
+ * A market can keep showing up in the volume scan long after it stopped
+ * trading: the book sits untouched, no prints arrive, and every scan pays a
+ * book fetch to reject it again. This index keeps, per token:
+ *
+ * - the last trade print (WS last_trade_price)
+ * - every top-of-book change (WS or REST), for a quote-updates-per-hour rate
+ * - "no orderbook" / 404 responses, which mean the market was delisted
+ *
+ * and classifies the token:
+ *
+ *   ACTIVE    traded or requoted within STALE_MARKET_QUIET_MINUTES
+ *   QUIET     no trade or quote change for that long
+ *   DEAD      no trade or quote change for STALE_MARKET_DEAD_HOURS
+ *   DELISTED  the CLOB has no orderbook for it (cleared by a live book)
+ *
+ * DEAD and DELISTED tokens are excluded from scans. A token the index has
+ * never seen is never excluded, and a 0 threshold disables that status.
+ */
+
+import type {
+  MarketDataStore,
+  TokenMarketData,
+  TradePrint,
+} from "../lib/market-data-store";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type MarketLiveness = "ACTIVE" | "QUIET" | "DEAD" | "DELISTED";
+
+export interface MarketStalenessConfig {
+  /** No trade or quote change for this long = QUIET (default 60 min,
+   *  0 = never) */
+  quietAfterMs: number;
+  /** No trade or quote change for this long = DEAD (default 6 h, 0 = never) */
+  deadAfterMs: number;
+}
+
+export const DEFAULT_MARKET_STALENESS_CONFIG: MarketStalenessConfig = {
+  quietAfterMs: 60 * 60_000,
+  deadAfterMs: 6 * 60 * 60_000,
+};
+
+export interface MarketStalenessEntry {
+  tokenId: string;
+  status: MarketLiveness;
+  firstSeenAt: number;
+  lastTradeAt: number | null;
+  lastQuoteChangeAt: number | null;
+  /** Top-of-book changes over the last hour */
+  quoteUpdatesPerHour: number;
+  /** Why the token is DELISTED */
+  delistedReason?: string;
+}
+
+interface TokenActivity {
+  firstSeenAt: number;
+  lastTradeAt: number | null;
+  lastQuoteChangeAt: number | null;
+  lastBid: number | null;
+  lastAsk: number | null;
+  /** Quote change times within the last hour */
+  quoteChanges: number[];
+  delistedReason: string | null;
+}
+
+const HOUR_MS = 60 * 60_000;
+
+const STATUS_ORDER: Record<MarketLiveness, number> = {
+  DELISTED: 0,
+  DEAD: 1,
+  QUIET: 2,
+  ACTIVE: 3,
+};
+
+/**
+ * Whether a book fetch error means the CLOB no longer lists the token
+ */
+export function isDelistedError(message: string): boolean {
+  const lower = message.toLowerCase();
+  return lower.includes("no orderbook") || lower.includes("404");
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MARKET STALENESS INDEX
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class MarketStalenessIndex {
+  private activity = new Map<string, TokenActivity>();
+  private readonly config: MarketStalenessConfig;
+
+  constructor(
+    config: Partial<MarketStalenessConfig> = {},
+    private readonly maxTokens = 2000,
+  ) {
+    this.config = { ...DEFAULT_MARKET_STALENESS_CONFIG, ...config };
+  }
+
+  /**
+   * Record a trade print
+   */
+  observeTrade(tokenId: string, timestamp = Date.now()): void {
+    const a = this.track(tokenId, timestamp);
+    a.lastTradeAt = Math.max(a.lastTradeAt ?? 0, timestamp);
+  }
+
+  /**
+   * Record a top-of-book snapshot; only a changed best bid or ask counts
+   * as a quote update. A live book clears a DELISTED flag.
+   */
+  observeQuote(
+    tokenId: string,
+    bestBid: number,
+    bestAsk: number,
+    timestamp = Date.now(),
+  ): void {
+    const a = this.track(tokenId, timestamp);
+    a.delistedReason = null;
+    if (a.lastBid === bestBid && a.lastAsk === bestAsk) return;
+
+    // The first snapshot is a baseline, not an update
+    if (a.lastBid !== null) {
+      a.lastQuoteChangeAt = timestamp;
+      a.quoteChanges.push(timestamp);
+      const cutoff = timestamp - HOUR_MS;
+      while (a.quoteChanges.length > 0 && a.quoteChanges[0] < cutoff) {
+        a.quoteChanges.shift();
+      }
+    }
+    a.lastBid = bestBid;
+    a.lastAsk = bestAsk;
+  }
+
+  /**
+   * Flag a token the CLOB has no orderbook for
+   */
+  markDelisted(tokenId: string, reason: string, timestamp = Date.now()): void {
+    this.track(tokenId, timestamp).delistedReason = reason;
+  }
+
+  getStatus(tokenId: string, now = Date.now()): MarketLiveness | null {
+    const a = this.activity.get(tokenId);
+    return a ? this.classify(a, now) : null;
+  }
+
+  /**
+   * Whether scans should skip the token (DEAD or DELISTED)
+   */
+  isExcluded(tokenId: string, now = Date.now()): boolean {
+    const status = this.getStatus(tokenId, now);
+    return status === "DEAD" || status === "DELISTED";
+  }
+
+  /**
+   * Every tracked token, most stale first
+   *
+   * @param statuses - Only these statuses (default: all)
+   */
+  getReport(
+    statuses?: MarketLiveness[],
+    now = Date.now(),
+  ): MarketStalenessEntry[] {
+    const entries: MarketStalenessEntry[] = [];
+    const hourAgo = now - HOUR_MS;
+    for (const [tokenId, a] of this.activity) {
+      const status = this.classify(a, now);
+      if (statuses && !statuses.includes(status)) continue;
+      entries.push({
+        tokenId,
+        status,
+        firstSeenAt: a.firstSeenAt,
+        lastTradeAt: a.lastTradeAt,
+        lastQuoteChangeAt: a.lastQuoteChangeAt,
+        quoteUpdatesPerHour: a.quoteChanges.filter((t) => t >= hourAgo).length,
+        ...(a.delistedReason ? { delistedReason: a.delistedReason } : {}),
+      });
+    }
+    return entries.sort(
+      (x, y) =>
+        STATUS_ORDER[x.status] - STATUS_ORDER[y.status] ||
+        lastActivity(x) - lastActivity(y),
+    );
+  }
+
+  /**
+   * Token count per status
+   */
+  getCounts(now = Date.now()): Record<MarketLiveness, number> {
+    const counts = { ACTIVE: 0, QUIET: 0, DEAD: 0, DELISTED: 0 };
+    for (const a of this.activity.values()) counts[this.classify(a, now)]++;
+    return counts;
+  }
+
+  /**
+   * Feed the index from a market data store's trade prints and quotes
+   * @returns Unsubscribe function
+   */
+  attach(store: MarketDataStore): () => void {
+    const offTrade = store.onTrade((trade: TradePrint) =>
+      this.observeTrade(trade.tokenId, trade.timestamp),
+    );
+    const offQuote = store.onQuote((data: TokenMarketData) =>
+      this.observeQuote(
+        data.tokenId,
+        data.bestBid,
+        data.bestAsk,
+        data.updatedAt,
+      ),
+    );
+    return () => {
+      offTrade();
+      offQuote();
+    };
+  }
+
+  clear(): void {
+    this.activity.clear();
+  }
+
+  private classify(a: TokenActivity, now: number): MarketLiveness {
+    if (a.delistedReason) return "DELISTED";
+    const idleMs = now - Math.max(a.firstSeenAt, lastActivity(a));
+    const { deadAfterMs, quietAfterMs } = this.config;
+    if (deadAfterMs > 0 && idleMs >= deadAfterMs) return "DEAD";
+    if (quietAfterMs > 0 && idleMs >= quietAfterMs) return "QUIET";
+    return "ACTIVE";
+  }
+
+  private track(tokenId: string, timestamp: number): TokenActivity {
+    let a = this.activity.get(tokenId);
+    if (!a) {
+      // Memory protection - drop the oldest token at capacity
+      if (this.activity.size >= this.maxTokens) {
+        const oldest = this.activity.keys().next().value;
+        if (oldest !== undefined) this.activity.delete(oldest);
+      }
+      a = {
+        firstSeenAt: timestamp,
+        lastTradeAt: null,
+        lastQuoteChangeAt: null,
+        lastBid: null,
+        lastAsk: null,
+        quoteChanges: [],
+        delistedReason: null,
+      };
+      this.activity.set(tokenId, a);
+    }
+    return a;
+  }
+}
+
+function lastActivity(a: {
+  lastTradeAt: number | null;
+  lastQuoteChangeAt: number | null;
+}): number {
+  return Math.max(a.lastTradeAt ?? 0, a.lastQuoteChangeAt ?? 0);
+}
+
+/**
+ * Status breakdown for logs, e.g. "12 active, 3 quiet, 2 dead, 1 delisted"
+ */
+export function formatStalenessCounts(
+  counts: Record<MarketLiveness, number>,
+): string {
+  return (
+    `${counts.ACTIVE} active, ${counts.QUIET} quiet, ` +
+    `${counts.DEAD} dead, ${counts.DELISTED} delisted`
+  );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: MarketStalenessIndex | null = null;
+
+/**
+ * Initialize the global market staleness index
+ */
+export function initMarketStalenessIndex(
+  config?: Partial<MarketStalenessConfig>,
+): MarketStalenessIndex {
+  instance = new MarketStalenessIndex(config);
+  return instance;
+}
+
+/**
+ * Get the global market staleness index (null if not initialized)
+ */
+export function getMarketStalenessIndex(): MarketStalenessIndex | null {
+  return instance;
+}
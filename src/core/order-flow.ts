@@ -0,0 +1,184 @@
+/**
+ * Order Flow Tracker - Rolling buy vs sell initiated volume imbalance
+ *
+ * Aggressive buyers lifting the ask (or sellers hitting the bid) tend to
+ * lead the midpoint. This tracker keeps the trade-print stream per token and
+ * reports the imbalance of aggressor volume over several rolling windows:
+ *
+ *   imbalance = (buyVolume - sellVolume) / (buyVolume + sellVolume)
+ *
+ * +1 means every print was buyer-initiated, -1 every print seller-initiated.
+ * Volume is USD notional (size * price) so cheap tokens don't dominate.
+ *
+ * Used for:
+ * - Indicators (getIndicators) for logging/strategy decisions
+ * - An early direction signal for entries, before the midpoint moves
+ */
+
+import type { MarketDataStore, TradePrint } from "../lib/market-data-store";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** Default windows: 30 s, 1 min, 5 min */
+export const DEFAULT_ORDER_FLOW_WINDOWS_MS = [30_000, 60_000, 300_000];
+
+/** Minimum prints in a window before imbalance is reported */
+const MIN_PRINTS = 3;
+
+export interface OrderFlowWindow {
+  windowMs: number;
+  /** Imbalance in [-1, 1] (null if too few prints) */
+  imbalance: number | null;
+  buyVolumeUsd: number;
+  sellVolumeUsd: number;
+  prints: number;
+}
+
+export interface OrderFlowIndicators {
+  tokenId: string;
+  windows: OrderFlowWindow[];
+}
+
+interface FlowSample {
+  side: "BUY" | "SELL";
+  notionalUsd: number;
+  timestamp: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ORDER FLOW TRACKER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class OrderFlowTracker {
+  private samples = new Map<string, FlowSample[]>();
+  private readonly windowsMs: number[];
+  private readonly maxWindowMs: number;
+
+  constructor(
+    windowsMs: number[] = DEFAULT_ORDER_FLOW_WINDOWS_MS,
+    private readonly maxTokens = 500,
+  ) {
+    this.windowsMs = [...windowsMs].sort((a, b) => a - b);
+    this.maxWindowMs = this.windowsMs[this.windowsMs.length - 1] ?? 0;
+  }
+
+  /**
+   * Record a trade print (price decimal 0-1, size in shares)
+   */
+  record(trade: TradePrint): void {
+    const notionalUsd = trade.size * trade.price;
+    if (!Number.isFinite(notionalUsd) || notionalUsd <= 0) return;
+
+    let series = this.samples.get(trade.tokenId);
+    if (!series) {
+      // Memory protection - drop the oldest token at capacity
+      if (this.samples.size >= this.maxTokens) {
+        const oldest = this.samples.keys().next().value;
+        if (oldest !== undefined) this.samples.delete(oldest);
+      }
+      series = [];
+      this.samples.set(trade.tokenId, series);
+    }
+
+    series.push({ side: trade.side, notionalUsd, timestamp: trade.timestamp });
+
+    // Prune samples older than the largest window
+    const cutoff = trade.timestamp - this.maxWindowMs;
+    while (series.length > 0 && series[0].timestamp < cutoff) {
+      series.shift();
+    }
+  }
+
+  /**
+   * Aggressor volume and imbalance over one window
+   */
+  getWindow(
+    tokenId: string,
+    windowMs: number,
+    now = Date.now(),
+  ): OrderFlowWindow {
+    const cutoff = now - windowMs;
+    const inWindow = (this.samples.get(tokenId) ?? []).filter(
+      (s) => s.timestamp >= cutoff,
+    );
+
+    let buyVolumeUsd = 0;
+    let sellVolumeUsd = 0;
+    for (const s of inWindow) {
+      if (s.side === "BUY") buyVolumeUsd += s.notionalUsd;
+      else sellVolumeUsd += s.notionalUsd;
+    }
+
+    const total = buyVolumeUsd + sellVolumeUsd;
+    const imbalance =
+      inWindow.length >= MIN_PRINTS && total > 0
+        ? (buyVolumeUsd - sellVolumeUsd) / total
+        : null;
+
+    return {
+      windowMs,
+      imbalance,
+      buyVolumeUsd,
+      sellVolumeUsd,
+      prints: inWindow.length,
+    };
+  }
+
+  /**
+   * Imbalance in [-1, 1] over a window, or null if too few prints
+   */
+  getImbalance(
+    tokenId: string,
+    windowMs: number,
+    now = Date.now(),
+  ): number | null {
+    return this.getWindow(tokenId, windowMs, now).imbalance;
+  }
+
+  /**
+   * Imbalance for every configured window
+   */
+  getIndicators(tokenId: string, now = Date.now()): OrderFlowIndicators {
+    return {
+      tokenId,
+      windows: this.windowsMs.map((windowMs) =>
+        this.getWindow(tokenId, windowMs, now),
+      ),
+    };
+  }
+
+  /**
+   * Feed the tracker from a market data store's trade prints
+   * @returns Unsubscribe function
+   */
+  attach(store: MarketDataStore): () => void {
+    return store.onTrade((trade: TradePrint) => this.record(trade));
+  }
+
+  clear(): void {
+    this.samples.clear();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: OrderFlowTracker | null = null;
+
+/**
+ * Initialize the global order flow tracker
+ */
+export function initOrderFlowTracker(windowsMs?: number[]): OrderFlowTracker {
+  instance = new OrderFlowTracker(windowsMs);
+  return instance;
+}
+
+/**
+ * Get the global order flow tracker (null if not initialized)
+ */
+export function getOrderFlowTracker(): OrderFlowTracker | null {
+  return instance;
+}
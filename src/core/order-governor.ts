@@ -0,0 +1,145 @@
+/**
+ * Order Governor - Cap order submissions per market per window
+ *
+ * The HTTP rate limiters stop the bot from hammering the API, not from
+ * trading the same market over and over. A signal that flaps around its
+ * threshold can enter, exit and re-enter one market dozens of times an
+ * hour, paying fees and spread on each round trip. The governor counts
+ * submitted orders per market over a rolling window:
+ *
+ *   MAX_ORDERS_PER_MARKET_PER_HOUR=6     # 0 = unlimited
+ *   ORDER_GOVERNOR_WINDOW_MINUTES=60
+ *
+ * Entries, exits and hedges all count toward the cap, but only entries
+ * are blocked by it - a position can always be closed or hedged. Markets
+ * are keyed by marketId, so both outcome tokens share one budget.
+ */
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface OrderGovernorConfig {
+  /** Orders per market per window (0 = unlimited) */
+  maxOrders: number;
+  /** Rolling window (ms) */
+  windowMs: number;
+}
+
+export const DEFAULT_ORDER_GOVERNOR_CONFIG: OrderGovernorConfig = {
+  maxOrders: 0,
+  windowMs: 60 * 60_000,
+};
+
+export type GovernedOrderKind = "ENTRY" | "EXIT" | "HEDGE";
+
+interface OrderStamp {
+  kind: GovernedOrderKind;
+  at: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ORDER GOVERNOR
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class OrderGovernor {
+  private readonly config: OrderGovernorConfig;
+  private orders = new Map<string, OrderStamp[]>();
+
+  constructor(
+    config: Partial<OrderGovernorConfig> = {},
+    private readonly maxMarkets = 1000,
+  ) {
+    this.config = { ...DEFAULT_ORDER_GOVERNOR_CONFIG, ...config };
+  }
+
+  isEnabled(): boolean {
+    return this.config.maxOrders > 0;
+  }
+
+  /**
+   * Count a submitted order against its market
+   */
+  record(market: string, kind: GovernedOrderKind, now = Date.now()): void {
+    if (!this.isEnabled()) return;
+    let stamps = this.orders.get(market);
+    if (!stamps) {
+      // Memory protection - drop the oldest market at capacity
+      if (this.orders.size >= this.maxMarkets) {
+        const oldest = this.orders.keys().next().value;
+        if (oldest !== undefined) this.orders.delete(oldest);
+      }
+      stamps = [];
+      this.orders.set(market, stamps);
+    }
+    stamps.push({ kind, at: now });
+    this.prune(stamps, now);
+  }
+
+  /**
+   * Orders counted against a market within the window
+   */
+  getCount(market: string, now = Date.now()): number {
+    const stamps = this.orders.get(market);
+    if (!stamps) return 0;
+    this.prune(stamps, now);
+    return stamps.length;
+  }
+
+  /**
+   * Why a new entry on the market is blocked, or null if it isn't
+   */
+  checkEntry(market: string, now = Date.now()): string | null {
+    if (!this.isEnabled()) return null;
+    const count = this.getCount(market, now);
+    if (count < this.config.maxOrders) return null;
+    const minutes = Math.round(this.config.windowMs / 60_000);
+    return `ORDER_RATE_LIMITED: ${count} orders in ${minutes}m`;
+  }
+
+  /**
+   * Markets with orders in the window, busiest first
+   */
+  getBusiestMarkets(
+    limit = 10,
+    now = Date.now(),
+  ): Array<{ market: string; count: number }> {
+    return [...this.orders.keys()]
+      .map((market) => ({ market, count: this.getCount(market, now) }))
+      .filter((m) => m.count > 0)
+      .sort((a, b) => b.count - a.count)
+      .slice(0, limit);
+  }
+
+  clear(): void {
+    this.orders.clear();
+  }
+
+  private prune(stamps: OrderStamp[], now: number): void {
+    const cutoff = now - this.config.windowMs;
+    while (stamps.length > 0 && stamps[0].at <= cutoff) stamps.shift();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: OrderGovernor | null = null;
+
+/**
+ * Initialize the global order governor
+ */
+export function initOrderGovernor(
+  config?: Partial<OrderGovernorConfig>,
+): OrderGovernor {
+  instance = new OrderGovernor(config);
+  return instance;
+}
+
+/**
+ * Get the global order governor (null if not initialized)
+ */
+export function getOrderGovernor(): OrderGovernor | null {
+  return instance;
+}
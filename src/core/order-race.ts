@@ -0,0 +1,281 @@
+/**
+ * Order Race Arbiter - Stop the whale and scan paths fighting each other
+ *
+ * Both strategies run entries on their own schedule, and nothing ties
+ * them together: the whale path can buy YES on a market while the scanner
+ * buys NO on it a second later, or both can buy the same token and double
+ * the intended exposure. Each entry claims its token just before execution;
+ * a claim by ANOTHER strategy within the window is a race:
+ *
+ *   SAME_TOKEN     same token, same side
+ *   OPPOSITE_SIDE  same token on the other side, or the other outcome
+ *                  token of the same market
+ *
+ * and is arbitrated per ORDER_RACE_MODE:
+ *
+ *   priority  the strategy listed first in ORDER_RACE_PRIORITY wins; the
+ *             loser is rejected (ORDER_RACE_LOST) or, if it already holds
+ *             the claim, preempted before its order is submitted
+ *   cancel    both are cancelled and the race is alerted
+ *   off       no arbitration
+ *
+ * An order that was already submitted can't be pulled back - preemption
+ * only stops orders still between claim and submission. Every race emits
+ * an ORDER_RACE event.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+import { ENTRY_STRATEGIES, type EntryStrategy } from "./strategy-schedule";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type OrderRaceMode = "off" | "priority" | "cancel";
+
+export interface OrderRaceConfig {
+  mode: OrderRaceMode;
+  /** Claims older than this no longer race (ms) */
+  windowMs: number;
+  /** Highest priority first; unlisted strategies rank last */
+  priority: EntryStrategy[];
+}
+
+export const DEFAULT_ORDER_RACE_CONFIG: OrderRaceConfig = {
+  mode: "priority",
+  windowMs: 10_000,
+  priority: ["whale", "scan"],
+};
+
+export interface RaceIntent {
+  tokenId: string;
+  marketId?: string;
+  side: "LONG" | "SHORT";
+  strategy: EntryStrategy;
+}
+
+export type OrderRaceKind = "SAME_TOKEN" | "OPPOSITE_SIDE";
+
+export type OrderRaceResolution =
+  /** The incoming entry lost and was rejected */
+  | "INCOMING_REJECTED"
+  /** The incoming entry won and the earlier claim was revoked */
+  | "EXISTING_PREEMPTED"
+  /** Both were cancelled (cancel mode) */
+  | "BOTH_CANCELLED";
+
+export interface OrderRaceEvent {
+  event: "ORDER_RACE";
+  kind: OrderRaceKind;
+  resolution: OrderRaceResolution;
+  incoming: RaceIntent;
+  existing: RaceIntent;
+  /** Time between the two claims (ms) */
+  gapMs: number;
+  timestamp: string;
+}
+
+export type OrderClaimResult =
+  | { allowed: true; claimId: number }
+  | { allowed: false; reason: string; race: OrderRaceEvent };
+
+interface Claim {
+  id: number;
+  intent: RaceIntent;
+  at: number;
+  revoked: boolean;
+}
+
+const MAX_RECENT_RACES = 50;
+
+/**
+ * Parse "off" / "priority" / "cancel" (anything else = the default)
+ */
+export function parseOrderRaceMode(value: string): OrderRaceMode {
+  const mode = value.trim().toLowerCase();
+  return mode === "off" || mode === "cancel" || mode === "priority"
+    ? mode
+    : DEFAULT_ORDER_RACE_CONFIG.mode;
+}
+
+/**
+ * Parse "whale,scan" into a priority list, dropping unknown names
+ */
+export function parseOrderRacePriority(spec: string): EntryStrategy[] {
+  const names = spec
+    .split(",")
+    .map((s) => s.trim())
+    .filter((s): s is EntryStrategy =>
+      ENTRY_STRATEGIES.includes(s as EntryStrategy),
+    );
+  return names.length > 0
+    ? [...new Set(names)]
+    : [...DEFAULT_ORDER_RACE_CONFIG.priority];
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ORDER RACE ARBITER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class OrderRaceArbiter {
+  private readonly config: OrderRaceConfig;
+  private claims: Claim[] = [];
+  private recent: OrderRaceEvent[] = [];
+  private listeners = new Set<(race: OrderRaceEvent) => void>();
+  private nextId = 1;
+
+  constructor(config: Partial<OrderRaceConfig> = {}) {
+    this.config = { ...DEFAULT_ORDER_RACE_CONFIG, ...config };
+  }
+
+  isEnabled(): boolean {
+    return this.config.mode !== "off";
+  }
+
+  /**
+   * Claim a token for an entry about to execute
+   *
+   * Call just before execution; hold on to the claimId and check
+   * isHeld() right before submitting the order.
+   */
+  claim(intent: RaceIntent, now = Date.now()): OrderClaimResult {
+    this.prune(now);
+    const claimId = this.nextId++;
+    if (!this.isEnabled()) return { allowed: true, claimId };
+
+    for (const existing of this.claims) {
+      if (existing.revoked || existing.intent.strategy === intent.strategy) {
+        continue;
+      }
+      const kind = raceKind(intent, existing.intent);
+      if (!kind) continue;
+
+      let resolution: OrderRaceResolution;
+      if (this.config.mode === "cancel") {
+        existing.revoked = true;
+        resolution = "BOTH_CANCELLED";
+      } else if (this.outranks(intent.strategy, existing.intent.strategy)) {
+        existing.revoked = true;
+        resolution = "EXISTING_PREEMPTED";
+      } else {
+        resolution = "INCOMING_REJECTED";
+      }
+
+      const race = this.record({
+        event: "ORDER_RACE",
+        kind,
+        resolution,
+        incoming: intent,
+        existing: existing.intent,
+        gapMs: now - existing.at,
+        timestamp: new Date(now).toISOString(),
+      });
+      if (resolution !== "EXISTING_PREEMPTED") {
+        const reason =
+          resolution === "BOTH_CANCELLED"
+            ? `ORDER_RACE_CANCELLED: ${kind} with ${existing.intent.strategy}`
+            : `ORDER_RACE_LOST: ${kind} to ${existing.intent.strategy}`;
+        return { allowed: false, reason, race };
+      }
+    }
+
+    this.claims.push({ id: claimId, intent, at: now, revoked: false });
+    return { allowed: true, claimId };
+  }
+
+  /**
+   * Whether a claim still stands (false once another strategy preempted
+   * or cancelled it)
+   */
+  isHeld(claimId: number): boolean {
+    const claim = this.claims.find((c) => c.id === claimId);
+    return !claim?.revoked;
+  }
+
+  /**
+   * Subscribe to races (e.g. to alert on cancellations)
+   * @returns Unsubscribe function
+   */
+  onRace(listener: (race: OrderRaceEvent) => void): () => void {
+    this.listeners.add(listener);
+    return () => {
+      this.listeners.delete(listener);
+    };
+  }
+
+  /**
+   * Races seen recently, newest last
+   */
+  getRecentRaces(): OrderRaceEvent[] {
+    return [...this.recent];
+  }
+
+  clear(): void {
+    this.claims = [];
+    this.recent = [];
+  }
+
+  private outranks(a: EntryStrategy, b: EntryStrategy): boolean {
+    const rank = (s: EntryStrategy) => {
+      const i = this.config.priority.indexOf(s);
+      return i === -1 ? this.config.priority.length : i;
+    };
+    return rank(a) < rank(b);
+  }
+
+  private record(race: OrderRaceEvent): OrderRaceEvent {
+    this.recent.push(race);
+    if (this.recent.length > MAX_RECENT_RACES) this.recent.shift();
+    emitEvent(race);
+    for (const listener of this.listeners) listener(race);
+    return race;
+  }
+
+  private prune(now: number): void {
+    const cutoff = now - this.config.windowMs;
+    this.claims = this.claims.filter((c) => c.at >= cutoff);
+  }
+}
+
+function raceKind(a: RaceIntent, b: RaceIntent): OrderRaceKind | null {
+  if (a.tokenId === b.tokenId) {
+    return a.side === b.side ? "SAME_TOKEN" : "OPPOSITE_SIDE";
+  }
+  // The other outcome token of the same market is the opposite side
+  if (a.marketId && a.marketId === b.marketId) return "OPPOSITE_SIDE";
+  return null;
+}
+
+/**
+ * One-line description for logs, e.g.
+ * "OPPOSITE_SIDE: scan vs whale 1.2s apart → INCOMING_REJECTED"
+ */
+export function formatOrderRace(race: OrderRaceEvent): string {
+  return (
+    `${race.kind}: ${race.incoming.strategy} vs ${race.existing.strategy} ` +
+    `${(race.gapMs / 1000).toFixed(1)}s apart → ${race.resolution}`
+  );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: OrderRaceArbiter | null = null;
+
+/**
+ * Initialize the global order race arbiter
+ */
+export function initOrderRaceArbiter(
+  config?: Partial<OrderRaceConfig>,
+): OrderRaceArbiter {
+  instance = new OrderRaceArbiter(config);
+  return instance;
+}
+
+/**
+ * Get the global order race arbiter (null if not initialized)
+ */
+export function getOrderRaceArbiter(): OrderRaceArbiter | null {
+  return instance;
+}
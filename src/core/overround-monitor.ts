@@ -0,0 +1,195 @@
+/**
+ * Overround Monitor - Implied-probability normalization across outcomes
+ *
+ * Exactly one market of a neg-risk event resolves YES, so its YES prices
+ * should sum to 1.0. The deviation (overround when above, underround when
+ * below) flags either arbitrage or stale pricing:
+ *
+ * - ARB_BUY_ALL: YES asks sum below 1 - every outcome can be bought for less
+ *   than the guaranteed $1 payout
+ * - ARB_SELL_ALL: YES bids sum above 1 - every outcome can be sold for more
+ * - OVERROUND / UNDERROUND: mids drift past the threshold without a
+ *   tradeable edge, usually a stale or thin leg
+ *
+ * Watched events are configured by Gamma event ID:
+ *
+ *   OVERROUND_EVENT_IDS=903,12345
+ *   OVERROUND_ALERT_PCT=3            # mid sum deviation to alert on
+ *   OVERROUND_ARB_EDGE_PCT=0.5       # min edge for the ARB_* flags
+ *   OVERROUND_CHECK_INTERVAL_SEC=60
+ *
+ * An OVERROUND_ALERT event is emitted when an event's flag changes to a new
+ * non-null value; it re-arms once the event is back within the thresholds.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+import type { EventView } from "../lib/event-view";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface OverroundMonitorConfig {
+  /** Gamma event IDs to watch */
+  eventIds: string[];
+  /** Alert when the YES mid sum deviates from 1 by this much (%) */
+  alertPct: number;
+  /** Minimum edge for ARB_BUY_ALL / ARB_SELL_ALL (%) */
+  arbEdgePct: number;
+}
+
+export const DEFAULT_OVERROUND_MONITOR_CONFIG: OverroundMonitorConfig = {
+  eventIds: [],
+  alertPct: 3,
+  arbEdgePct: 0.5,
+};
+
+export type OverroundFlag =
+  | "ARB_BUY_ALL"
+  | "ARB_SELL_ALL"
+  | "OVERROUND"
+  | "UNDERROUND";
+
+export interface OverroundReport {
+  eventId: string;
+  title: string;
+  markets: number;
+  sumAsks: number | null;
+  sumBids: number | null;
+  sumMids: number | null;
+  /** (sumMids - 1) as a percentage (null when a leg has no price) */
+  overroundPct: number | null;
+  flag: OverroundFlag | null;
+  checkedAt: number;
+}
+
+export interface OverroundAlertEvent {
+  event: "OVERROUND_ALERT";
+  eventId: string;
+  title: string;
+  flag: OverroundFlag;
+  sumAsks: number | null;
+  sumBids: number | null;
+  sumMids: number | null;
+  overroundPct: number | null;
+  timestamp: string;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REPORT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Classify an event view against the thresholds. Only neg-risk events with
+ * at least two priced legs are flagged - other events' outcomes aren't
+ * mutually exclusive, so their prices needn't sum to 1.
+ */
+export function getOverroundFlag(
+  view: EventView,
+  config: Pick<OverroundMonitorConfig, "alertPct" | "arbEdgePct">,
+): OverroundFlag | null {
+  if (!view.negRisk || view.markets.length < 2) return null;
+
+  const edge = config.arbEdgePct / 100;
+  if (view.sumAsks !== null && view.sumAsks < 1 - edge) return "ARB_BUY_ALL";
+  if (view.sumBids !== null && view.sumBids > 1 + edge) return "ARB_SELL_ALL";
+
+  if (view.sumMids === null) return null;
+  const deviation = view.sumMids - 1;
+  if (deviation > config.alertPct / 100) return "OVERROUND";
+  if (deviation < -config.alertPct / 100) return "UNDERROUND";
+  return null;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// OVERROUND MONITOR
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class OverroundMonitor {
+  private readonly config: OverroundMonitorConfig;
+  private reports = new Map<string, OverroundReport>();
+
+  constructor(config: Partial<OverroundMonitorConfig> = {}) {
+    const d = DEFAULT_OVERROUND_MONITOR_CONFIG;
+    this.config = {
+      eventIds: config.eventIds ?? d.eventIds,
+      alertPct: config.alertPct ?? d.alertPct,
+      arbEdgePct: config.arbEdgePct ?? d.arbEdgePct,
+    };
+  }
+
+  getEventIds(): string[] {
+    return this.config.eventIds;
+  }
+
+  /**
+   * Record a fresh view of an event
+   * @returns The alert, when the event's flag changed to a new non-null value
+   */
+  evaluate(view: EventView, now = Date.now()): OverroundAlertEvent | null {
+    const flag = getOverroundFlag(view, this.config);
+    const previous = this.reports.get(view.eventId);
+    const report: OverroundReport = {
+      eventId: view.eventId,
+      title: view.title,
+      markets: view.markets.length,
+      sumAsks: view.sumAsks,
+      sumBids: view.sumBids,
+      sumMids: view.sumMids,
+      overroundPct: view.sumMids !== null ? (view.sumMids - 1) * 100 : null,
+      flag,
+      checkedAt: now,
+    };
+    this.reports.set(view.eventId, report);
+
+    if (!flag || previous?.flag === flag) return null;
+
+    const alert: OverroundAlertEvent = {
+      event: "OVERROUND_ALERT",
+      eventId: report.eventId,
+      title: report.title,
+      flag,
+      sumAsks: report.sumAsks,
+      sumBids: report.sumBids,
+      sumMids: report.sumMids,
+      overroundPct: report.overroundPct,
+      timestamp: new Date(now).toISOString(),
+    };
+    emitEvent(alert);
+    return alert;
+  }
+
+  /**
+   * Latest report per watched event
+   */
+  getReports(): OverroundReport[] {
+    return [...this.reports.values()];
+  }
+
+  clear(): void {
+    this.reports.clear();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: OverroundMonitor | null = null;
+
+/**
+ * Initialize the global overround monitor
+ */
+export function initOverroundMonitor(
+  config?: Partial<OverroundMonitorConfig>,
+): OverroundMonitor {
+  instance = new OverroundMonitor(config);
+  return instance;
+}
+
+/**
+ * Get the global overround monitor (null if not initialized)
+ */
+export function getOverroundMonitor(): OverroundMonitor | null {
+  return instance;
+}
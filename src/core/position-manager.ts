@@ -17,7 +17,7 @@ import type {
   ManagedPosition,
 } from "./decision-engine";
 
-import { getOppositeTokenId } from "../lib/market";
+import { getComplementQuote } from "../lib/market";
 import type { Position } from "../models";
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -69,6 +69,14 @@ export class PositionManager {
     outcomeLabel?: string;
     outcomeIndex?: 1 | 2;
     marketQuestion?: string;
+    // Age of the book data the entry was priced from (post-trade analysis)
+    quoteAgeMs?: number;
+    // Entry strategy (capital allocation / per-strategy performance)
+    strategy?: string;
+    // Shadow strategy paper position (excluded from deployed capital)
+    shadow?: boolean;
+    // Whale wallets the entry followed (whale scoring)
+    whaleWallets?: string[];
   }): ManagedPosition {
     const id = `${params.tokenId}-${Date.now()}`;
     const now = Date.now();
@@ -104,6 +112,10 @@ export class PositionManager {
       entryPriceCents: params.entryPriceCents,
       entrySizeUsd: params.sizeUsd,
       entryTime: now,
+      entryQuoteAgeMs: params.quoteAgeMs,
+      strategy: params.strategy,
+      shadow: params.shadow,
+      whaleWallets: params.whaleWallets,
       currentPriceCents: params.entryPriceCents,
       unrealizedPnlCents: 0,
       unrealizedPnlUsd: 0,
@@ -130,7 +142,7 @@ export class PositionManager {
 
   /**
    * Set the opposite token ID for a position (for hedging)
-   * This should be called after opening a position with the result of getOppositeTokenId()
+   * This should be called after opening a position with the result of getComplementQuote()
    */
   setOppositeToken(
     positionId: string,
@@ -233,13 +245,18 @@ export class PositionManager {
 
     this.positions.set(id, position);
 
-    // Fetch opposite token ID for hedging capability
+    // Fetch the complement token for hedging capability
     try {
-      const oppositeTokenId = await getOppositeTokenId(pos.tokenId);
-      if (oppositeTokenId) {
-        position.oppositeTokenId = oppositeTokenId;
+      const complement = await getComplementQuote(pos.tokenId);
+      if (complement) {
+        position.oppositeTokenId = complement.complementTokenId;
+        position.oppositeOutcomeLabel = complement.complementLabel;
+        const hedgePrice =
+          complement.complementPrice !== null
+            ? ` @ ${(complement.complementPrice * 100).toFixed(0)}¢`
+            : "";
         console.log(
-          `📋 Registered external position: ${pos.outcome} @ ${entryPriceCents.toFixed(0)}¢ (P&L: ${pos.pnlPct >= 0 ? "+" : ""}${pos.pnlPct.toFixed(1)}%) [hedge-ready]`,
+          `📋 Registered external position: ${pos.outcome} @ ${entryPriceCents.toFixed(0)}¢ (P&L: ${pos.pnlPct >= 0 ? "+" : ""}${pos.pnlPct.toFixed(1)}%) [hedge-ready: "${complement.complementLabel}"${hedgePrice}]`,
         );
       } else {
         console.log(
@@ -386,6 +403,38 @@ export class PositionManager {
     }
   }
 
+  /**
+   * Take shares off a position's hedge legs, newest first (a hedge target
+   * was lowered or the position shrank)
+   * @returns USD cost basis taken off
+   */
+  trimHedges(positionId: string, shares: number): number {
+    const position = this.positions.get(positionId);
+    if (!position) return 0;
+
+    let remaining = shares;
+    let trimmedUsd = 0;
+    for (let i = position.hedges.length - 1; i >= 0 && remaining > 0; i--) {
+      const leg = position.hedges[i];
+      const price = leg.entryPriceCents / 100;
+      const legShares = price > 0 ? leg.sizeUsd / price : 0;
+      const taken = Math.min(legShares, remaining);
+      remaining -= taken;
+      trimmedUsd += taken * price;
+      leg.sizeUsd -= taken * price;
+      // Float dust from the share estimate shouldn't leave empty legs
+      if (legShares - taken < 1e-6) position.hedges.splice(i, 1);
+    }
+
+    if (position.entrySizeUsd > 0) {
+      position.totalHedgeRatio = Math.max(
+        0,
+        position.totalHedgeRatio - trimmedUsd / position.entrySizeUsd,
+      );
+    }
+    return trimmedUsd;
+  }
+
   /**
    * Begin exit process
    */
@@ -524,7 +573,22 @@ export class PositionManager {
    * Get total deployed USD
    */
   getTotalDeployedUsd(): number {
-    return this.getOpenPositions().reduce((sum, p) => sum + p.entrySizeUsd, 0);
+    // Shadow positions are paper trades - no real capital is deployed
+    return this.getOpenPositions()
+      .filter((p) => !p.shadow)
+      .reduce((sum, p) => sum + p.entrySizeUsd, 0);
+  }
+
+  /**
+   * Get deployed USD per entry strategy
+   */
+  getDeployedUsdByStrategy(): Record<string, number> {
+    const deployed: Record<string, number> = {};
+    for (const p of this.getOpenPositions()) {
+      if (!p.strategy) continue;
+      deployed[p.strategy] = (deployed[p.strategy] ?? 0) + p.entrySizeUsd;
+    }
+    return deployed;
   }
 
   /**
@@ -544,6 +608,51 @@ export class PositionManager {
     return pruned;
   }
 
+  /**
+   * Export open positions for persistence (state snapshots)
+   * Closed positions are dropped - they carry no armed protection.
+   */
+  exportState(): ManagedPosition[] {
+    return this.getOpenPositions().map((p) => ({
+      ...p,
+      hedges: p.hedges.map((h) => ({ ...h })),
+      // Transition history is for notifications/logging only and can be large
+      transitions: [],
+    }));
+  }
+
+  /**
+   * Restore positions from a state snapshot
+   * Positions whose token is already tracked are skipped. When `heldTokenIds`
+   * is provided, positions whose token is no longer held in the wallet (sold
+   * or redeemed while the bot was down) are dropped instead of re-armed.
+   *
+   * @returns The re-armed positions
+   */
+  importState(
+    positions: ManagedPosition[],
+    heldTokenIds?: Set<string>,
+  ): ManagedPosition[] {
+    const rearmed: ManagedPosition[] = [];
+    for (const pos of positions) {
+      if (pos.state === "CLOSED") continue;
+      if (heldTokenIds && !heldTokenIds.has(pos.tokenId)) continue;
+      if (this.getPositionsByToken(pos.tokenId).length > 0) continue;
+      const restored: ManagedPosition = {
+        ...pos,
+        // A position that was mid-exit is re-armed as open so the exit
+        // conditions are evaluated again instead of being stuck EXITING
+        state: pos.state === "EXITING" ? "OPEN" : pos.state,
+        hedges: pos.hedges ?? [],
+        transitions: [],
+        lastUpdateTime: Date.now(),
+      };
+      this.positions.set(pos.id, restored);
+      rearmed.push(restored);
+    }
+    return rearmed;
+  }
+
   /**
    * Clear all positions (for testing)
    */
@@ -0,0 +1,246 @@
+/**
+ * Price Alerts - Per-token PRICE_ALERT events for semi-manual trading
+ *
+ * An alert watches one token's mid price and fires when it goes above or
+ * below a level. One-shot alerts fire once and stay done; repeating alerts
+ * re-arm once the price is back inside the band. Alerts are seeded from
+ * the environment and saved with the engine state, so a fired one-shot
+ * alert does not fire again after a restart:
+ *
+ *   PRICE_ALERTS="<tokenId>:above=0.6,below=0.3,repeat;<tokenId>:below=0.2"
+ *
+ * Each firing emits a PRICE_ALERT event (event sinks) and a Telegram
+ * message, so an external bridge can act on it.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface PriceAlert {
+  /** Stable ID derived from the spec (re-seeding the same spec is a no-op) */
+  id: string;
+  tokenId: string;
+  /** Fire when the price goes at or above this (0-1) */
+  above?: number;
+  /** Fire when the price goes at or below this (0-1) */
+  below?: number;
+  /** Re-arm after the price returns inside the band */
+  repeat: boolean;
+  /** Armed = the next crossing fires */
+  armed: boolean;
+  /** One-shot alert that has fired */
+  done: boolean;
+  lastFiredAt?: number;
+}
+
+export type PriceAlertSpec = Pick<
+  PriceAlert,
+  "tokenId" | "above" | "below" | "repeat"
+>;
+
+export interface PriceAlertEvent {
+  event: "PRICE_ALERT";
+  alertId: string;
+  tokenId: string;
+  direction: "ABOVE" | "BELOW";
+  level: number;
+  price: number;
+  repeat: boolean;
+  timestamp: string;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+function isPrice(value: number | undefined): value is number {
+  return (
+    value !== undefined && Number.isFinite(value) && value > 0 && value < 1
+  );
+}
+
+/**
+ * Parse PRICE_ALERTS ("<tokenId>:above=0.6,below=0.3,repeat;...").
+ * Entries without a valid level are skipped.
+ */
+export function parsePriceAlerts(spec: string): PriceAlertSpec[] {
+  const alerts: PriceAlertSpec[] = [];
+
+  for (const entry of spec.split(";")) {
+    const sep = entry.indexOf(":");
+    if (sep === -1) continue;
+    const tokenId = entry.slice(0, sep).trim();
+    if (!tokenId) continue;
+
+    const alert: PriceAlertSpec = { tokenId, repeat: false };
+    for (const part of entry.slice(sep + 1).split(",")) {
+      const [rawKey, rawValue] = part.split("=");
+      const key = rawKey?.trim().toLowerCase();
+      const value = Number(rawValue?.trim());
+      if (key === "repeat") alert.repeat = true;
+      else if (key === "above" && isPrice(value)) alert.above = value;
+      else if (key === "below" && isPrice(value)) alert.below = value;
+    }
+    if (alert.above !== undefined || alert.below !== undefined) {
+      alerts.push(alert);
+    }
+  }
+
+  return alerts;
+}
+
+/**
+ * Stable ID for an alert spec
+ */
+export function priceAlertId(spec: PriceAlertSpec): string {
+  return [
+    spec.tokenId,
+    spec.above ?? "",
+    spec.below ?? "",
+    spec.repeat ? "r" : "",
+  ].join(":");
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRICE ALERT MANAGER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class PriceAlertManager {
+  private alerts = new Map<string, PriceAlert>();
+
+  /**
+   * Add an alert unless one with the same spec exists
+   * @returns The (new or existing) alert, or null if it has no valid level
+   */
+  add(spec: PriceAlertSpec): PriceAlert | null {
+    if (!isPrice(spec.above) && !isPrice(spec.below)) return null;
+    const id = priceAlertId(spec);
+    const existing = this.alerts.get(id);
+    if (existing) return existing;
+
+    const alert: PriceAlert = {
+      id,
+      tokenId: spec.tokenId,
+      above: isPrice(spec.above) ? spec.above : undefined,
+      below: isPrice(spec.below) ? spec.below : undefined,
+      repeat: spec.repeat,
+      armed: true,
+      done: false,
+    };
+    this.alerts.set(id, alert);
+    return alert;
+  }
+
+  remove(id: string): boolean {
+    return this.alerts.delete(id);
+  }
+
+  /**
+   * All alerts, including one-shots that have fired
+   */
+  list(): PriceAlert[] {
+    return [...this.alerts.values()];
+  }
+
+  /**
+   * Tokens with an alert that can still fire
+   */
+  getWatchedTokens(): string[] {
+    const tokens = new Set<string>();
+    for (const alert of this.alerts.values()) {
+      if (!alert.done) tokens.add(alert.tokenId);
+    }
+    return [...tokens];
+  }
+
+  /**
+   * Check a token's current price
+   * @returns Alerts that fired
+   */
+  check(tokenId: string, price: number, now = Date.now()): PriceAlertEvent[] {
+    if (!Number.isFinite(price)) return [];
+    const fired: PriceAlertEvent[] = [];
+
+    for (const alert of this.alerts.values()) {
+      if (alert.tokenId !== tokenId || alert.done) continue;
+
+      const hit: ["ABOVE" | "BELOW", number] | null =
+        alert.above !== undefined && price >= alert.above
+          ? ["ABOVE", alert.above]
+          : alert.below !== undefined && price <= alert.below
+            ? ["BELOW", alert.below]
+            : null;
+
+      if (!hit) {
+        // Back inside the band - a repeating alert may fire again
+        alert.armed = true;
+        continue;
+      }
+      if (!alert.armed) continue;
+
+      alert.armed = false;
+      alert.done = !alert.repeat;
+      alert.lastFiredAt = now;
+
+      const event: PriceAlertEvent = {
+        event: "PRICE_ALERT",
+        alertId: alert.id,
+        tokenId,
+        direction: hit[0],
+        level: hit[1],
+        price,
+        repeat: alert.repeat,
+        timestamp: new Date(now).toISOString(),
+      };
+      emitEvent(event);
+      fired.push(event);
+    }
+
+    return fired;
+  }
+
+  /**
+   * Alerts to persist with the engine state
+   */
+  exportState(): PriceAlert[] {
+    return this.list().map((alert) => ({ ...alert }));
+  }
+
+  /**
+   * Restore persisted alerts (replacing any with the same ID)
+   * @returns Number of alerts restored
+   */
+  importState(saved: PriceAlert[]): number {
+    let restored = 0;
+    for (const alert of saved) {
+      if (!alert?.id || !alert.tokenId) continue;
+      if (!isPrice(alert.above) && !isPrice(alert.below)) continue;
+      this.alerts.set(alert.id, { ...alert });
+      restored++;
+    }
+    return restored;
+  }
+
+  clear(): void {
+    this.alerts.clear();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: PriceAlertManager | null = null;
+
+/**
+ * Get the global price alert manager
+ */
+export function getPriceAlertManager(): PriceAlertManager {
+  if (!instance) {
+    instance = new PriceAlertManager();
+  }
+  return instance;
+}
@@ -0,0 +1,241 @@
+/**
+ * Profit Compounder - Let realized profit raise the per-trade cap
+ *
+ * With a schedule set, the per-trade notional cap (MAX_TRADE_USD) follows
+ * cumulative realized P&L through "pnl:cap" steps:
+ *
+ *   COMPOUND_SCHEDULE="-100:10,-50:15,100:35,250:50,500:75"
+ *
+ * The cap is the one of the highest step at or below realized P&L, with
+ * MAX_TRADE_USD as the implicit step at 0. Profit raises the cap one step
+ * at a time and never past the last step - the schedule is the budget;
+ * losses walk it back down, below MAX_TRADE_USD when the schedule has
+ * negative steps. Below the lowest step, the lowest cap applies.
+ *
+ * Realized P&L counts live exits and resolutions (shadow trades
+ * excluded). It's saved to COMPOUND_STATE_PATH so a restart keeps
+ * the cap where profits took it.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface CompoundStep {
+  /** Realized P&L at which the step starts (USD, may be negative) */
+  pnlUsd: number;
+  /** Per-trade cap from there on (USD) */
+  capUsd: number;
+}
+
+export interface ProfitCompounderConfig {
+  /** Cap at 0 realized P&L (MAX_TRADE_USD) */
+  baseCapUsd: number;
+  /** Configured steps (empty = compounding off) */
+  steps: CompoundStep[];
+}
+
+export interface CompoundingStatus {
+  enabled: boolean;
+  realizedPnlUsd: number;
+  baseCapUsd: number;
+  /** Cap entries are sized against now */
+  capUsd: number;
+  /** Step that raises the cap next (null at the top of the schedule) */
+  nextStep: CompoundStep | null;
+}
+
+export interface CompoundCapChangedEvent {
+  event: "COMPOUND_CAP_CHANGED";
+  timestamp: string;
+  previousCapUsd: number;
+  capUsd: number;
+  realizedPnlUsd: number;
+}
+
+export const DEFAULT_COMPOUND_STATE_PATH = "./data/compounding.json";
+
+/** Resolution keys remembered so a retried redeem isn't counted twice */
+const MAX_RECORDED_KEYS = 500;
+
+interface CompoundingState {
+  realizedPnlUsd: number;
+  recordedKeys: string[];
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "-50:15,100:35,250:50". Steps with a non-positive cap or that
+ * don't parse are dropped.
+ */
+export function parseCompoundSchedule(spec: string): CompoundStep[] {
+  const steps: CompoundStep[] = [];
+  for (const entry of spec.split(",")) {
+    if (!entry.trim()) continue;
+    const [pnl, cap] = entry.split(":").map((s) => Number(s.trim()));
+    if (Number.isFinite(pnl) && cap > 0) {
+      steps.push({ pnlUsd: pnl, capUsd: cap });
+    }
+  }
+  return steps.sort((a, b) => a.pnlUsd - b.pnlUsd);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROFIT COMPOUNDER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class ProfitCompounder {
+  private readonly baseCapUsd: number;
+  /** Configured steps plus the implicit 0:baseCapUsd, ascending */
+  private readonly steps: CompoundStep[];
+  private readonly enabled: boolean;
+  private realizedPnlUsd = 0;
+  private recordedKeys: string[] = [];
+  private filePath: string | null = null;
+
+  constructor(config: ProfitCompounderConfig) {
+    this.baseCapUsd = config.baseCapUsd;
+    this.enabled = config.steps.length > 0;
+    const steps = config.steps.filter((s) => s.pnlUsd !== 0);
+    const zero = config.steps.find((s) => s.pnlUsd === 0);
+    steps.push(zero ?? { pnlUsd: 0, capUsd: config.baseCapUsd });
+    this.steps = steps.sort((a, b) => a.pnlUsd - b.pnlUsd);
+  }
+
+  /**
+   * Whether a schedule is configured
+   */
+  isEnabled(): boolean {
+    return this.enabled;
+  }
+
+  /**
+   * Per-trade cap for the current realized P&L
+   */
+  getCapUsd(): number {
+    if (!this.enabled) return this.baseCapUsd;
+    let cap = this.steps[0].capUsd;
+    for (const step of this.steps) {
+      if (step.pnlUsd > this.realizedPnlUsd) break;
+      cap = step.capUsd;
+    }
+    return cap;
+  }
+
+  /**
+   * Add realized P&L (an exit or a resolution)
+   *
+   * @param key - Identifies a resolution, so it's counted once however
+   *   often the redeem is retried
+   * @returns Whether it was counted
+   */
+  recordRealized(pnlUsd: number, key?: string): boolean {
+    if (!this.enabled || !Number.isFinite(pnlUsd)) return false;
+    if (key) {
+      if (this.recordedKeys.includes(key)) return false;
+      this.recordedKeys.push(key);
+      if (this.recordedKeys.length > MAX_RECORDED_KEYS) {
+        this.recordedKeys.shift();
+      }
+    }
+
+    const previousCap = this.getCapUsd();
+    this.realizedPnlUsd += pnlUsd;
+    const capUsd = this.getCapUsd();
+    this.save();
+
+    if (capUsd !== previousCap) {
+      console.log(
+        `📈 [Compounding] Trade cap $${previousCap.toFixed(2)} → $${capUsd.toFixed(2)} (realized P&L $${this.realizedPnlUsd.toFixed(2)})`,
+      );
+      const event: CompoundCapChangedEvent = {
+        event: "COMPOUND_CAP_CHANGED",
+        timestamp: new Date().toISOString(),
+        previousCapUsd: previousCap,
+        capUsd,
+        realizedPnlUsd: this.realizedPnlUsd,
+      };
+      emitEvent(event);
+    }
+    return true;
+  }
+
+  /**
+   * Effective cap and where it goes next
+   */
+  getStatus(): CompoundingStatus {
+    return {
+      enabled: this.enabled,
+      realizedPnlUsd: this.realizedPnlUsd,
+      baseCapUsd: this.baseCapUsd,
+      capUsd: this.getCapUsd(),
+      nextStep: this.enabled
+        ? (this.steps.find((s) => s.pnlUsd > this.realizedPnlUsd) ?? null)
+        : null,
+    };
+  }
+
+  /**
+   * Persist realized P&L to a state file, restoring what's saved there
+   */
+  attachStateFile(filePath: string): void {
+    this.filePath = filePath;
+    const result = loadStateFile<CompoundingState>(filePath);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [Compounding] Failed to load ${filePath}: ${result.error}`,
+      );
+      return;
+    }
+    const state = result.payload;
+    if (!state || !Number.isFinite(state.realizedPnlUsd)) return;
+    this.realizedPnlUsd = state.realizedPnlUsd;
+    this.recordedKeys = Array.isArray(state.recordedKeys)
+      ? state.recordedKeys.slice(-MAX_RECORDED_KEYS)
+      : [];
+  }
+
+  private save(): void {
+    if (!this.filePath) return;
+    const result = saveStateFile<CompoundingState>(this.filePath, {
+      realizedPnlUsd: this.realizedPnlUsd,
+      recordedKeys: this.recordedKeys,
+    });
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [Compounding] Failed to save ${this.filePath}: ${result.error}`,
+      );
+    }
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: ProfitCompounder | null = null;
+
+/**
+ * Initialize the global profit compounder
+ */
+export function initProfitCompounder(
+  config: ProfitCompounderConfig,
+  filePath?: string,
+): ProfitCompounder {
+  instance = new ProfitCompounder(config);
+  if (filePath && instance.isEnabled()) instance.attachStateFile(filePath);
+  return instance;
+}
+
+/**
+ * Get the global profit compounder (null if not initialized)
+ */
+export function getProfitCompounder(): ProfitCompounder | null {
+  return instance;
+}
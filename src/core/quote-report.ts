@@ -0,0 +1,189 @@
+/**
+ * Quote Report - Is quoting a market worth it?
+ *
+ * Resting post-only quotes (see requoteTokens) earn the spread when they
+ * fill, and lose it back when they fill right before the price moves
+ * through them. Per market, from the account's maker fills in the fill
+ * journal:
+ *
+ * - spread capture: how far inside the mid each fill was
+ *     BUY: (mid - price) x shares    SELL: (price - mid) x shares
+ * - adverse selection: how far the mid moved against the fill by the
+ *   markout (QUOTE_MARKOUT_SECONDS later)
+ *     BUY: (mid - mid later) x shares   SELL: (mid later - mid) x shares
+ * - net = capture - adverse selection
+ * - inventory turnover: maker shares traded / average absolute inventory,
+ *   i.e. how often the quoted inventory was cycled rather than carried
+ *
+ * The mid at each fill is journaled as the fill arrives and the markout
+ * mid is filled in once the markout has passed (annotateMarkouts), so the
+ * report also works offline from the journal:
+ *
+ *   npm run quote-report
+ *
+ * Backfilled fills have no mid and only count toward volume and turnover.
+ */
+
+import type { FillJournal, JournalFill } from "./fill-journal";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface QuoteMarketReport {
+  tokenId: string;
+  makerFills: number;
+  sharesBought: number;
+  sharesSold: number;
+  volumeUsd: number;
+  /** Spread earned vs the mid at fill time (USD) */
+  captureUsd: number;
+  /** Mid move against the fills by the markout (USD, positive = lost) */
+  adverseUsd: number;
+  /** captureUsd - adverseUsd */
+  netUsd: number;
+  /** Per share over the fills each was measured on (cents) */
+  captureCentsPerShare: number | null;
+  adverseCentsPerShare: number | null;
+  /** Maker shares traded / average absolute inventory (null = no
+   *  inventory was ever carried) */
+  turnover: number | null;
+  /** Shares held after the last fill (all fills, not just maker) */
+  inventoryShares: number;
+  /** Maker fills with a mid at fill time / with a markout mid */
+  pricedFills: number;
+  markedFills: number;
+}
+
+export const DEFAULT_QUOTE_MARKOUT_MS = 60_000;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REPORT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Per-market quoting report, best net first. Tokens without maker fills
+ * are left out.
+ */
+export function buildQuoteReport(fills: JournalFill[]): QuoteMarketReport[] {
+  const byToken = new Map<string, JournalFill[]>();
+  for (const fill of fills) {
+    const list = byToken.get(fill.tokenId) ?? [];
+    list.push(fill);
+    byToken.set(fill.tokenId, list);
+  }
+
+  const reports: QuoteMarketReport[] = [];
+  for (const [tokenId, tokenFills] of byToken) {
+    if (!tokenFills.some((f) => f.liquidity === "MAKER")) continue;
+    reports.push(reportToken(tokenId, tokenFills));
+  }
+  return reports.sort((a, b) => b.netUsd - a.netUsd);
+}
+
+function reportToken(tokenId: string, fills: JournalFill[]): QuoteMarketReport {
+  const r: QuoteMarketReport = {
+    tokenId,
+    makerFills: 0,
+    sharesBought: 0,
+    sharesSold: 0,
+    volumeUsd: 0,
+    captureUsd: 0,
+    adverseUsd: 0,
+    netUsd: 0,
+    captureCentsPerShare: null,
+    adverseCentsPerShare: null,
+    turnover: null,
+    inventoryShares: 0,
+    pricedFills: 0,
+    markedFills: 0,
+  };
+  let pricedShares = 0;
+  let markedShares = 0;
+  let inventorySum = 0;
+
+  const sorted = [...fills].sort((a, b) => a.timestamp - b.timestamp);
+  for (const fill of sorted) {
+    const sign = fill.side === "BUY" ? 1 : -1;
+    r.inventoryShares += sign * fill.shares;
+    inventorySum += Math.abs(r.inventoryShares);
+    if (fill.liquidity !== "MAKER") continue;
+
+    r.makerFills++;
+    r.volumeUsd += fill.shares * fill.price;
+    if (fill.side === "BUY") r.sharesBought += fill.shares;
+    else r.sharesSold += fill.shares;
+
+    if (fill.midAtFill === undefined) continue;
+    r.pricedFills++;
+    pricedShares += fill.shares;
+    r.captureUsd += sign * (fill.midAtFill - fill.price) * fill.shares;
+
+    if (fill.midAfter === undefined) continue;
+    r.markedFills++;
+    markedShares += fill.shares;
+    r.adverseUsd += sign * (fill.midAtFill - fill.midAfter) * fill.shares;
+  }
+
+  r.netUsd = r.captureUsd - r.adverseUsd;
+  if (pricedShares > 0) {
+    r.captureCentsPerShare = (r.captureUsd / pricedShares) * 100;
+  }
+  if (markedShares > 0) {
+    r.adverseCentsPerShare = (r.adverseUsd / markedShares) * 100;
+  }
+  const avgInventory = inventorySum / sorted.length;
+  if (avgInventory > 0) {
+    r.turnover = (r.sharesBought + r.sharesSold) / avgInventory;
+  }
+  return r;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MARKOUTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Fill in the markout mid of maker fills whose markout has passed
+ *
+ * @param midAt - Mid (0-1) of a token at a point in time, or null if
+ *   unknown (e.g. no book history that far back)
+ * @returns Fills annotated
+ */
+export function annotateMarkouts(
+  journal: FillJournal,
+  midAt: (tokenId: string, at: number) => number | null,
+  markoutMs = DEFAULT_QUOTE_MARKOUT_MS,
+  now = Date.now(),
+): number {
+  const markouts: Array<{ tradeId: string; midAfter: number }> = [];
+  for (const fill of journal.getFills()) {
+    if (
+      fill.liquidity !== "MAKER" ||
+      fill.midAtFill === undefined ||
+      fill.midAfter !== undefined ||
+      fill.timestamp + markoutMs > now
+    ) {
+      continue;
+    }
+    const mid = midAt(fill.tokenId, fill.timestamp + markoutMs);
+    if (mid !== null) markouts.push({ tradeId: fill.tradeId, midAfter: mid });
+  }
+  return journal.setMarkouts(markouts);
+}
+
+/**
+ * Report rows for logs and the quote-report script
+ */
+export function formatQuoteReport(reports: QuoteMarketReport[]): string[] {
+  const cents = (v: number | null) =>
+    v === null ? "n/a" : `${v >= 0 ? "+" : ""}${v.toFixed(2)}¢`;
+  const usd = (v: number) => `${v >= 0 ? "+" : "-"}$${Math.abs(v).toFixed(2)}`;
+  return reports.map(
+    (r) =>
+      `${r.tokenId.slice(0, 16)}... | ${r.makerFills} fills | ` +
+      `$${r.volumeUsd.toFixed(2)} | capture ${cents(r.captureCentsPerShare)}` +
+      ` | adverse ${cents(r.adverseCentsPerShare)} | net ${usd(r.netUsd)} | ` +
+      `turnover ${r.turnover === null ? "n/a" : r.turnover.toFixed(1) + "x"}`,
+  );
+}
@@ -371,6 +371,13 @@ export class RiskGuard {
     );
   }
 
+  /**
+   * Whether a hedge on this position is still on cooldown
+   */
+  isHedgeCoolingDown(positionId: string, now = Date.now()): boolean {
+    return now < (this.hedgeCooldowns.get(positionId) || 0);
+  }
+
   /**
    * Get comprehensive portfolio health status.
    * Use this for monitoring and alerting.
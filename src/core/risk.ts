@@ -68,6 +68,101 @@ export function calculateTradeSize(
   return Math.min(withMinimum, params.maxTradeUsd);
 }
 
+/**
+ * Inputs for Kelly sizing of a binary outcome token
+ */
+export interface KellySizeParams {
+  /** Estimated edge: true probability minus price (0.05 = 5 points) */
+  edge: number;
+  /** Current price of the token (0-1) */
+  price: number;
+  /** Bankroll available for sizing (USD) */
+  bankrollUsd: number;
+  /** Fraction of full Kelly to use (default 0.25 = quarter Kelly) */
+  kellyFraction?: number;
+  /** Hard cap on the fraction of bankroll per trade */
+  bankrollFractionCap: number;
+  /** Hard cap on trade size (USD) */
+  maxTradeUsd?: number;
+}
+
+export interface KellySizeResult {
+  /** Full Kelly fraction of bankroll (0 if no edge) */
+  fullKellyFraction: number;
+  /** Fraction actually used after kellyFraction and caps */
+  appliedFraction: number;
+  /** Recommended order size (USD) */
+  sizeUsd: number;
+}
+
+/**
+ * Kelly-style size for buying a binary token at `price`
+ *
+ * A token bought at p pays 1 if it resolves true. With win probability
+ * q = p + edge, full Kelly is f* = (q - p) / (1 - p) = edge / (1 - p).
+ * Full Kelly is far too aggressive for noisy edge estimates, so the result
+ * is scaled by kellyFraction and capped by bankrollFractionCap/maxTradeUsd.
+ * `npm run size` runs it from the command line.
+ *
+ * Pure function - no I/O, no config lookups.
+ */
+export function calculateKellySize(params: KellySizeParams): KellySizeResult {
+  const { edge, price, bankrollUsd, bankrollFractionCap } = params;
+  const kellyFraction = params.kellyFraction ?? 0.25;
+
+  const none: KellySizeResult = {
+    fullKellyFraction: 0,
+    appliedFraction: 0,
+    sizeUsd: 0,
+  };
+  if (
+    !Number.isFinite(edge) ||
+    !Number.isFinite(price) ||
+    !Number.isFinite(bankrollUsd) ||
+    edge <= 0 ||
+    price <= 0 ||
+    price >= 1 ||
+    bankrollUsd <= 0
+  ) {
+    return none;
+  }
+
+  const fullKellyFraction = Math.min(1, edge / (1 - price));
+  const appliedFraction = Math.max(
+    0,
+    Math.min(fullKellyFraction * kellyFraction, bankrollFractionCap),
+  );
+
+  let sizeUsd = bankrollUsd * appliedFraction;
+  if (params.maxTradeUsd !== undefined) {
+    sizeUsd = Math.min(sizeUsd, params.maxTradeUsd);
+  }
+
+  return { fullKellyFraction, appliedFraction, sizeUsd };
+}
+
+/**
+ * Scale a trade size inversely with realized volatility
+ *
+ * scale = targetVolCents / volatilityCents, clamped to [minScale, maxScale].
+ * maxScale defaults to 1 so calm markets never size above the normal
+ * (already capped) trade size. Unknown volatility leaves the size unchanged.
+ */
+export function scaleSizeForVolatility(
+  baseSizeUsd: number,
+  volatilityCents: number | null,
+  targetVolCents: number,
+  minScale = 0.25,
+  maxScale = 1,
+): number {
+  if (volatilityCents === null || volatilityCents <= 0) return baseSizeUsd;
+  const scale = Math.min(
+    maxScale,
+    Math.max(minScale, targetVolCents / volatilityCents),
+  );
+  return baseSizeUsd * scale;
+}
+
 /**
  * Check if a new position would exceed risk limits
  */
@@ -0,0 +1,194 @@
+/**
+ * Shadow Mode - Paper-trade a strategy alongside live trading
+ *
+ * Strategies listed in SHADOW_STRATEGIES see the same live signals and run
+ * through the same entry pipeline, but their orders go to the simulation
+ * (paper) path instead of the CLOB while every other strategy trades live:
+ *
+ *   SHADOW_STRATEGIES="scan"
+ *
+ * Shadow positions never touch real funds: they don't count toward deployed
+ * capital or the risk guard, and their results stay out of the live EV
+ * tracker. Closed trades are recorded here per strategy and mode so new
+ * snipe logic can be compared against what is trading live before it is
+ * promoted.
+ */
+
+import { ENTRY_STRATEGIES, type EntryStrategy } from "./strategy-schedule";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type TradeMode = "shadow" | "live";
+
+export interface ShadowReportRow {
+  strategy: EntryStrategy;
+  mode: TradeMode;
+  trades: number;
+  wins: number;
+  /** Fraction of trades with positive P&L (0 when no trades) */
+  winRate: number;
+  pnlUsd: number;
+  sizeUsd: number;
+  /** P&L as a fraction of capital traded (0 when no trades) */
+  returnPct: number;
+}
+
+interface ModeStats {
+  trades: number;
+  wins: number;
+  pnlUsd: number;
+  sizeUsd: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONFIG PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "scan" / "whale,scan". Unknown strategy names are skipped with a
+ * warning.
+ */
+export function parseShadowStrategies(spec: string): EntryStrategy[] {
+  const strategies: EntryStrategy[] = [];
+  for (const raw of spec.split(",")) {
+    const name = raw.trim().toLowerCase();
+    if (!name) continue;
+    if (!ENTRY_STRATEGIES.includes(name as EntryStrategy)) {
+      console.warn(`⚠️ [Shadow] Ignoring unknown strategy "${raw.trim()}"`);
+      continue;
+    }
+    if (!strategies.includes(name as EntryStrategy)) {
+      strategies.push(name as EntryStrategy);
+    }
+  }
+  return strategies;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SHADOW TRACKER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class ShadowTracker {
+  private readonly shadowStrategies: Set<EntryStrategy>;
+  private stats = new Map<string, ModeStats>();
+
+  constructor(shadowStrategies: EntryStrategy[] = []) {
+    this.shadowStrategies = new Set(shadowStrategies);
+  }
+
+  /**
+   * Whether any strategy runs in shadow
+   */
+  isEnabled(): boolean {
+    return this.shadowStrategies.size > 0;
+  }
+
+  /**
+   * Whether a strategy's orders go to the paper engine
+   */
+  isShadow(strategy: EntryStrategy): boolean {
+    return this.shadowStrategies.has(strategy);
+  }
+
+  getShadowStrategies(): EntryStrategy[] {
+    return [...this.shadowStrategies];
+  }
+
+  /**
+   * Record a closed trade
+   */
+  recordResult(
+    strategy: EntryStrategy,
+    mode: TradeMode,
+    pnlUsd: number,
+    sizeUsd: number,
+  ): void {
+    if (!Number.isFinite(pnlUsd) || !Number.isFinite(sizeUsd)) return;
+
+    const key = `${strategy}:${mode}`;
+    const s = this.stats.get(key) ?? {
+      trades: 0,
+      wins: 0,
+      pnlUsd: 0,
+      sizeUsd: 0,
+    };
+    s.trades++;
+    if (pnlUsd > 0) s.wins++;
+    s.pnlUsd += pnlUsd;
+    s.sizeUsd += sizeUsd;
+    this.stats.set(key, s);
+  }
+
+  /**
+   * Shadow vs live performance per strategy (only strategy/mode pairs that
+   * have traded, plus every configured shadow strategy)
+   */
+  getReport(): ShadowReportRow[] {
+    const rows: ShadowReportRow[] = [];
+    for (const strategy of ENTRY_STRATEGIES) {
+      for (const mode of ["shadow", "live"] as TradeMode[]) {
+        const s = this.stats.get(`${strategy}:${mode}`);
+        const configured = mode === "shadow" && this.isShadow(strategy);
+        if (!s && !configured) continue;
+
+        const trades = s?.trades ?? 0;
+        const sizeUsd = s?.sizeUsd ?? 0;
+        rows.push({
+          strategy,
+          mode,
+          trades,
+          wins: s?.wins ?? 0,
+          winRate: trades > 0 ? (s?.wins ?? 0) / trades : 0,
+          pnlUsd: s?.pnlUsd ?? 0,
+          sizeUsd,
+          returnPct: sizeUsd > 0 ? (s?.pnlUsd ?? 0) / sizeUsd : 0,
+        });
+      }
+    }
+    return rows;
+  }
+
+  /**
+   * One line per report row, for status logs
+   */
+  formatReport(): string[] {
+    return this.getReport().map((r) => {
+      const sign = r.pnlUsd >= 0 ? "+" : "-";
+      return (
+        `${r.strategy} ${r.mode}: ${r.trades} trades | ` +
+        `${(r.winRate * 100).toFixed(0)}% win | ` +
+        `${sign}$${Math.abs(r.pnlUsd).toFixed(2)} ` +
+        `(${(r.returnPct * 100).toFixed(1)}%)`
+      );
+    });
+  }
+
+  clear(): void {
+    this.stats.clear();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: ShadowTracker | null = null;
+
+/**
+ * Initialize the global shadow tracker
+ */
+export function initShadowTracker(
+  shadowStrategies?: EntryStrategy[],
+): ShadowTracker {
+  instance = new ShadowTracker(shadowStrategies);
+  return instance;
+}
+
+/**
+ * Get the global shadow tracker (null if not initialized)
+ */
+export function getShadowTracker(): ShadowTracker | null {
+  return instance;
+}
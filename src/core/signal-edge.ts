@@ -0,0 +1,156 @@
+/**
+ * Signal Edge - Realized edge per entry signal
+ *
+ * Every entry is tagged with the signal that produced it (the entry
+ * strategy: "whale" copies, "scan" scanner picks). When a live position
+ * closes, its result is measured against the book mid at entry:
+ *
+ *   edge = exit - entry mid   (LONG; reversed for SHORT)
+ *   cost = entry - entry mid  (what the fill paid over fair value)
+ *
+ * Averaged per signal in the status log, this shows which snipe source
+ * actually makes money once spread and slippage are paid - a signal with a
+ * good win rate but negative edge is only being carried by the mid moving.
+ */
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface SignalEdgeRow {
+  signal: string;
+  trades: number;
+  /** Fraction of trades with positive P&L */
+  winRate: number;
+  /** Average exit vs entry mid, in the position's favor (cents) */
+  avgEdgeCents: number;
+  /** Average entry price paid over the entry mid (cents) */
+  avgEntryCostCents: number;
+  pnlUsd: number;
+}
+
+export interface SignalTradeResult {
+  side: "LONG" | "SHORT";
+  entryMidCents: number;
+  entryPriceCents: number;
+  exitPriceCents: number;
+  pnlUsd: number;
+}
+
+interface SignalStats {
+  trades: number;
+  wins: number;
+  edgeCents: number;
+  entryCostCents: number;
+  pnlUsd: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// HELPERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Realized edge of a trade vs the mid at entry (cents, positive = good)
+ */
+export function realizedEdgeCents(trade: SignalTradeResult): number {
+  const move = trade.exitPriceCents - trade.entryMidCents;
+  return trade.side === "LONG" ? move : -move;
+}
+
+/**
+ * Price paid over the mid at entry (cents, positive = paid up)
+ */
+export function entryCostCents(trade: SignalTradeResult): number {
+  const cost = trade.entryPriceCents - trade.entryMidCents;
+  return trade.side === "LONG" ? cost : -cost;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SIGNAL EDGE TRACKER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class SignalEdgeTracker {
+  private stats = new Map<string, SignalStats>();
+
+  /**
+   * Record a closed trade for its originating signal
+   */
+  recordResult(signal: string, trade: SignalTradeResult): void {
+    if (!(trade.entryMidCents > 0)) return;
+    const edge = realizedEdgeCents(trade);
+    const cost = entryCostCents(trade);
+    if (!Number.isFinite(edge) || !Number.isFinite(trade.pnlUsd)) return;
+
+    const s = this.stats.get(signal) ?? {
+      trades: 0,
+      wins: 0,
+      edgeCents: 0,
+      entryCostCents: 0,
+      pnlUsd: 0,
+    };
+    s.trades++;
+    if (trade.pnlUsd > 0) s.wins++;
+    s.edgeCents += edge;
+    s.entryCostCents += cost;
+    s.pnlUsd += trade.pnlUsd;
+    this.stats.set(signal, s);
+  }
+
+  /**
+   * Per-signal edge, best average edge first
+   */
+  getReport(): SignalEdgeRow[] {
+    return [...this.stats.entries()]
+      .map(([signal, s]) => ({
+        signal,
+        trades: s.trades,
+        winRate: s.wins / s.trades,
+        avgEdgeCents: s.edgeCents / s.trades,
+        avgEntryCostCents: s.entryCostCents / s.trades,
+        pnlUsd: s.pnlUsd,
+      }))
+      .sort((a, b) => b.avgEdgeCents - a.avgEdgeCents);
+  }
+
+  /**
+   * One line per signal, for status logs
+   */
+  formatReport(): string[] {
+    return this.getReport().map((r) => {
+      const edgeSign = r.avgEdgeCents >= 0 ? "+" : "";
+      const pnlSign = r.pnlUsd >= 0 ? "+" : "-";
+      return (
+        `${r.signal}: ${r.trades} trades | ` +
+        `${(r.winRate * 100).toFixed(0)}% win | ` +
+        `edge ${edgeSign}${r.avgEdgeCents.toFixed(2)}¢ vs mid | ` +
+        `cost ${r.avgEntryCostCents.toFixed(2)}¢ | ` +
+        `${pnlSign}$${Math.abs(r.pnlUsd).toFixed(2)}`
+      );
+    });
+  }
+
+  clear(): void {
+    this.stats.clear();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: SignalEdgeTracker | null = null;
+
+/**
+ * Initialize the global signal edge tracker
+ */
+export function initSignalEdgeTracker(): SignalEdgeTracker {
+  instance = new SignalEdgeTracker();
+  return instance;
+}
+
+/**
+ * Get the global signal edge tracker (null if not initialized)
+ */
+export function getSignalEdgeTracker(): SignalEdgeTracker | null {
+  return instance;
+}
@@ -0,0 +1,233 @@
+/**
+ * Signal Queue - Hold entry signals while every position slot is taken
+ *
+ * Once the bot holds its maximum number of positions, each further entry
+ * signal is rejected ("Max positions") and lost, even if a slot frees up a
+ * minute later. With a queue, those signals wait instead:
+ *
+ *   SIGNAL_QUEUE_MAX=10         # signals held at most (0 = off)
+ *   SIGNAL_QUEUE_TTL_SEC=300    # a signal older than this is dropped
+ *
+ * When positions close, the oldest queued signals are entered first, one
+ * per free slot, ahead of new signals. Each is re-checked like a fresh one
+ * (bias, price, bankroll...), so a signal that went stale while waiting is
+ * rejected rather than followed. A token that signals again while queued
+ * keeps its place and gets a fresh expiry.
+ *
+ * The queue is saved to SIGNAL_QUEUE_PATH (default
+ * ./data/signal-queue.json), so it survives a restart and can be viewed
+ * while the bot runs:
+ *
+ *   npm run signal-queue
+ */
+
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+import { emitEvent } from "../infra/event-bus";
+import type { EntryStrategy } from "./strategy-schedule";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface SignalQueueConfig {
+  /** Signals held at most (0 = off) */
+  maxSize: number;
+  /** How long a signal waits before it's dropped */
+  ttlMs: number;
+}
+
+export interface QueuedSignal {
+  tokenId: string;
+  marketId?: string;
+  strategy: EntryStrategy;
+  queuedAt: number;
+  expiresAt: number;
+}
+
+export type SignalQueueAction = "QUEUED" | "DEQUEUED" | "EXPIRED" | "DROPPED";
+
+export interface SignalQueueEvent {
+  event: "SIGNAL_QUEUE";
+  action: SignalQueueAction;
+  tokenId: string;
+  strategy: EntryStrategy;
+  /** Time since the signal was queued */
+  waitedMs: number;
+  queueLength: number;
+  timestamp: string;
+}
+
+export const DEFAULT_SIGNAL_QUEUE_PATH = "./data/signal-queue.json";
+
+/**
+ * Whether an entry was rejected only because every position slot is taken
+ * (DecisionEngine's max-positions check)
+ */
+export function isPositionLimitReason(reason: string | undefined): boolean {
+  return reason?.startsWith("Max positions (") ?? false;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SIGNAL QUEUE
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class SignalQueue {
+  /** Oldest first */
+  private signals: QueuedSignal[] = [];
+  private filePath: string | null = null;
+
+  constructor(private readonly config: SignalQueueConfig) {}
+
+  isEnabled(): boolean {
+    return this.config.maxSize > 0;
+  }
+
+  /**
+   * Queue a signal rejected by the position limit. A token already queued
+   * keeps its place with a fresh expiry.
+   * @returns false if the queue is off or full
+   */
+  enqueue(
+    signal: Pick<QueuedSignal, "tokenId" | "marketId" | "strategy">,
+    now = Date.now(),
+  ): boolean {
+    if (!this.isEnabled()) return false;
+    this.expire(now);
+
+    const expiresAt = now + this.config.ttlMs;
+    const queued = this.signals.find((s) => s.tokenId === signal.tokenId);
+    if (queued) {
+      queued.expiresAt = expiresAt;
+      this.save();
+      return true;
+    }
+
+    const entry = { ...signal, queuedAt: now, expiresAt };
+    if (this.signals.length >= this.config.maxSize) {
+      this.notify("DROPPED", entry, now);
+      return false;
+    }
+    this.signals.push(entry);
+    this.notify("QUEUED", entry, now);
+    this.save();
+    return true;
+  }
+
+  /**
+   * Take up to `slots` of the oldest unexpired signals off the queue
+   */
+  take(slots: number, now = Date.now()): QueuedSignal[] {
+    this.expire(now);
+    if (slots <= 0 || this.signals.length === 0) return [];
+    const taken = this.signals.splice(0, slots);
+    for (const signal of taken) this.notify("DEQUEUED", signal, now);
+    this.save();
+    return taken;
+  }
+
+  /**
+   * Put taken signals back at the front, in the order given (e.g. other
+   * entries took the slots first). Restore a drain's signals in one call
+   * so they stay oldest first.
+   */
+  restore(signals: QueuedSignal[], now = Date.now()): void {
+    const batch = signals.filter(
+      (signal) =>
+        signal.expiresAt > now &&
+        !this.signals.some((s) => s.tokenId === signal.tokenId),
+    );
+    if (batch.length === 0) return;
+    this.signals.unshift(...batch);
+    this.save();
+  }
+
+  /**
+   * Queued signals that haven't expired, oldest first
+   */
+  list(now = Date.now()): QueuedSignal[] {
+    return this.signals.filter((s) => s.expiresAt > now);
+  }
+
+  size(now = Date.now()): number {
+    return this.list(now).length;
+  }
+
+  /**
+   * Restore the queue from `filePath` and save there after every change
+   */
+  attachStateFile(filePath: string, now = Date.now()): void {
+    this.filePath = filePath;
+    const result = loadStateFile<QueuedSignal[]>(filePath);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [SignalQueue] Failed to load ${filePath}: ${result.error}`,
+      );
+      return;
+    }
+    const saved = Array.isArray(result.payload) ? result.payload : [];
+    this.signals = saved.filter(
+      (s) => typeof s?.tokenId === "string" && s.expiresAt > now,
+    );
+  }
+
+  private expire(now: number): void {
+    const expired = this.signals.filter((s) => s.expiresAt <= now);
+    if (expired.length === 0) return;
+    this.signals = this.signals.filter((s) => s.expiresAt > now);
+    for (const signal of expired) this.notify("EXPIRED", signal, now);
+    this.save();
+  }
+
+  private notify(
+    action: SignalQueueAction,
+    signal: QueuedSignal,
+    now: number,
+  ): void {
+    emitEvent<SignalQueueEvent>({
+      event: "SIGNAL_QUEUE",
+      action,
+      tokenId: signal.tokenId,
+      strategy: signal.strategy,
+      waitedMs: now - signal.queuedAt,
+      queueLength: this.signals.length,
+      timestamp: new Date(now).toISOString(),
+    });
+  }
+
+  private save(): void {
+    if (!this.filePath) return;
+    const result = saveStateFile(this.filePath, this.signals);
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [SignalQueue] Failed to save ${this.filePath}: ${result.error}`,
+      );
+    }
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: SignalQueue | null = null;
+
+/**
+ * Initialize the global signal queue
+ *
+ * @param filePath - Where the queue is persisted (empty = memory only)
+ */
+export function initSignalQueue(
+  config: SignalQueueConfig,
+  filePath = DEFAULT_SIGNAL_QUEUE_PATH,
+): SignalQueue {
+  instance = new SignalQueue(config);
+  if (filePath && instance.isEnabled()) instance.attachStateFile(filePath);
+  return instance;
+}
+
+/**
+ * Get the global signal queue (null if not initialized)
+ */
+export function getSignalQueue(): SignalQueue | null {
+  return instance;
+}
@@ -22,8 +22,19 @@ import { OrderType, Side } from "@polymarket/clob-client";
 import { SELL, ORDER } from "../lib/constants";
 import type { Position, OrderResult, Logger } from "../lib/types";
 import { isLiveTradingEnabled } from "../lib/auth";
-import { isCloudflareBlock, formatErrorForLog } from "../infra/error-handling";
+import {
+  isCloudflareBlock,
+  formatErrorForLog,
+  parseClobError,
+  clobErrorFields,
+  CLOB_POST_ORDER_ENDPOINT,
+} from "../infra/error-handling";
 import { HARD_MIN_PRICE, HARD_MAX_PRICE } from "../lib/price-safety";
+import {
+  checkSellableShares,
+  formatShareShortfall,
+  INSUFFICIENT_SHARES,
+} from "../lib/share-check";
 
 // ============================================================================
 // TYPES
@@ -377,10 +388,13 @@ async function fastSellAtBestBid(
         orderType: "FOK",
       };
     } else {
-      const errorMsg =
-        (response as any)?.errorMsg || (response as any)?.error || "unknown";
-      logger?.warn?.(`[FAST_SELL] ❌ Failed: ${errorMsg}`);
-      return { success: false, reason: errorMsg };
+      const error = parseClobError(response, CLOB_POST_ORDER_ENDPOINT);
+      logger?.warn?.(`[FAST_SELL] ❌ Failed: ${error.errorDetail}`);
+      return {
+        success: false,
+        reason: error.errorDetail,
+        ...clobErrorFields(error),
+      };
     }
   } catch (err) {
     const errorMessage = err instanceof Error ? err.message : String(err);
@@ -444,6 +458,23 @@ export async function smartSell(
     return fastSellAtBestBid(client, position, logger);
   }
 
+  // Don't post a SELL for shares the funder doesn't hold
+  const shareCheck = await checkSellableShares(
+    client,
+    position.tokenId,
+    sharesToSell,
+  );
+  if (!shareCheck.ok) {
+    const detail = formatShareShortfall(shareCheck, sharesToSell);
+    logger?.warn?.(`Sell rejected: ${INSUFFICIENT_SHARES} (${detail})`);
+    return {
+      success: false,
+      reason: INSUFFICIENT_SHARES,
+      detail,
+      heldShares: shareCheck.heldShares,
+    };
+  }
+
   try {
     // STEP 1: Get orderbook
     let orderBook;
@@ -637,77 +668,63 @@ export async function smartSell(
         orderType,
       };
     } else {
-      // Extract error message from various CLOB response structures
-      const errorMsg =
-        respAny?.errorMsg ||
-        respAny?.data?.error ||
-        respAny?.error ||
-        "Unknown error";
-      logger?.warn?.(`⚠️ Sell failed: ${errorMsg}`);
+      // Extract error fields from the various CLOB response structures
+      const error = parseClobError(response, CLOB_POST_ORDER_ENDPOINT);
+      const { errorDetail } = error;
+      const failure = { success: false, analysis, ...clobErrorFields(error) };
+      logger?.warn?.(`⚠️ Sell failed: ${errorDetail}`);
 
       // Handle specific error cases - distinguish between balance and allowance issues
-      const lowerError = errorMsg.toLowerCase();
+      const lowerError = errorDetail.toLowerCase();
       if (
         lowerError.includes("not enough allowance") ||
         lowerError.includes("insufficient allowance")
       ) {
-        return { success: false, reason: "INSUFFICIENT_ALLOWANCE", analysis };
+        return { ...failure, reason: "INSUFFICIENT_ALLOWANCE" };
       }
       if (
         lowerError.includes("not enough balance") ||
         lowerError.includes("insufficient balance") ||
         lowerError.includes("insufficient")
       ) {
-        return { success: false, reason: "INSUFFICIENT_BALANCE", analysis };
+        return { ...failure, reason: "INSUFFICIENT_BALANCE" };
       }
       if (lowerError.includes("fok") && lowerError.includes("not filled")) {
-        return { success: false, reason: "FOK_NOT_FILLED", analysis };
+        return { ...failure, reason: "FOK_NOT_FILLED" };
       }
 
-      return {
-        success: false,
-        reason: formatErrorForLog(errorMsg),
-        analysis,
-      };
+      return { ...failure, reason: formatErrorForLog(errorDetail) };
     }
   } catch (err) {
     if (isCloudflareBlock(err)) {
       return { success: false, reason: "CLOUDFLARE_BLOCKED" };
     }
 
-    // Extract clean error message from CLOB exception structures
-    const errAny = err as any;
-    let cleanMsg: string;
-    if (errAny?.response?.data?.error) {
-      cleanMsg = String(errAny.response.data.error);
-    } else if (errAny?.data?.error) {
-      cleanMsg = String(errAny.data.error);
-    } else if (err instanceof Error) {
-      cleanMsg = err.message;
-    } else {
-      cleanMsg = String(err);
-    }
+    // Extract error fields from CLOB exception structures
+    const error = parseClobError(err, CLOB_POST_ORDER_ENDPOINT);
+    const { errorDetail } = error;
+    const failure = { success: false, ...clobErrorFields(error) };
 
-    logger?.error?.(`❌ Sell error: ${cleanMsg}`);
+    logger?.error?.(`❌ Sell error: ${errorDetail}`);
 
     // Check for specific error types and return appropriate reason codes
     // Distinguish between balance and allowance issues for better debugging
-    const lowerMsg = cleanMsg.toLowerCase();
+    const lowerMsg = errorDetail.toLowerCase();
     if (
       lowerMsg.includes("not enough allowance") ||
       lowerMsg.includes("insufficient allowance")
     ) {
-      return { success: false, reason: "INSUFFICIENT_ALLOWANCE" };
+      return { ...failure, reason: "INSUFFICIENT_ALLOWANCE" };
     }
     if (
       lowerMsg.includes("not enough balance") ||
       lowerMsg.includes("insufficient balance") ||
       lowerMsg.includes("insufficient")
     ) {
-      return { success: false, reason: "INSUFFICIENT_BALANCE" };
+      return { ...failure, reason: "INSUFFICIENT_BALANCE" };
     }
 
-    return { success: false, reason: formatErrorForLog(cleanMsg) };
+    return { ...failure, reason: formatErrorForLog(errorDetail) };
   }
 }
 
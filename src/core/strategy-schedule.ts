@@ -0,0 +1,235 @@
+/**
+ * Strategy Schedule - Per-strategy active windows and blackout periods
+ *
+ * Each entry strategy ("whale" copy entries, "scan" scanner entries) can be
+ * limited to UTC trading windows, and all or some strategies can be blacked
+ * out around scheduled events (FOMC, CPI, debates...). The check runs inside
+ * ExecutionEngine.processEntry, so no caller can open a position outside the
+ * intended hours. Exits are never scheduled - a held position must always
+ * be sellable.
+ *
+ * Windows (comma-separated, UTC, end before start wraps past midnight):
+ *   STRATEGY_WINDOWS_WHALE="mon-fri 13:30-20:00, sat 15:00-18:00"
+ *   STRATEGY_WINDOWS_SCAN="22:00-02:00"           (every day)
+ * Blackouts (comma-separated ISO intervals, optional "strategy@" scope):
+ *   STRATEGY_BLACKOUTS="2026-10-28T17:45Z/2026-10-28T19:00Z,
+ *                       scan@2026-11-04T00:00Z/2026-11-05T00:00Z"
+ *
+ * A strategy with no windows configured is always active.
+ */
+
+import { envStr } from "../config/env";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** Entry strategies the engine runs */
+export type EntryStrategy = "whale" | "scan";
+
+export const ENTRY_STRATEGIES: EntryStrategy[] = ["whale", "scan"];
+
+export interface TimeWindow {
+  /** UTC days the window applies to (0 = Sunday) */
+  days: number[];
+  /** Start, minutes after UTC midnight */
+  startMinute: number;
+  /** End (exclusive), minutes after UTC midnight; < start wraps midnight */
+  endMinute: number;
+}
+
+export interface BlackoutPeriod {
+  startMs: number;
+  endMs: number;
+  /** Strategies affected (undefined = all) */
+  strategies?: string[];
+}
+
+export interface StrategyScheduleConfig {
+  windows: Partial<Record<string, TimeWindow[]>>;
+  blackouts: BlackoutPeriod[];
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+const DAY_NAMES = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+export const ALL_DAYS = [0, 1, 2, 3, 4, 5, 6];
+
+/**
+ * Parse "mon-fri", "mon+wed" or "daily" into UTC days (0 = Sunday)
+ * @returns null if it isn't one
+ */
+export function parseDays(spec: string): number[] | null {
+  if (spec === "daily") return ALL_DAYS;
+  const days = new Set<number>();
+  for (const part of spec.split("+")) {
+    const [from, to] = part.split("-").map((d) => DAY_NAMES.indexOf(d));
+    if (from === -1 || to === -1) return null;
+    if (to === undefined) {
+      days.add(from);
+      continue;
+    }
+    for (let d = from; ; d = (d + 1) % 7) {
+      days.add(d);
+      if (d === to) break;
+    }
+  }
+  return [...days].sort((a, b) => a - b);
+}
+
+/**
+ * Parse "HH:MM" into minutes after midnight (null if it isn't one)
+ */
+export function parseMinute(hhmm: string): number | null {
+  const match = /^(\d{1,2}):(\d{2})$/.exec(hhmm);
+  if (!match) return null;
+  const minute = Number(match[1]) * 60 + Number(match[2]);
+  return Number(match[2]) < 60 && minute <= 24 * 60 ? minute : null;
+}
+
+/**
+ * Parse "mon-fri 13:30-20:00, sat 10:00-14:00" into windows. Days are
+ * optional ("13:30-20:00" = every day); "mon+wed" lists single days.
+ * Invalid entries are skipped with a warning.
+ */
+export function parseTimeWindows(spec: string): TimeWindow[] {
+  const windows: TimeWindow[] = [];
+
+  for (const raw of spec.split(",")) {
+    const entry = raw.trim().toLowerCase();
+    if (!entry) continue;
+
+    const parts = entry.split(/\s+/);
+    const range = parts.pop()!;
+    const days = parts.length > 0 ? parseDays(parts.join("")) : ALL_DAYS;
+    const [start, end] = range.split("-").map(parseMinute);
+
+    if (!days || start == null || end == null || start === end) {
+      console.warn(`⚠️ [Schedule] Ignoring invalid window "${raw.trim()}"`);
+      continue;
+    }
+    windows.push({ days, startMinute: start, endMinute: end });
+  }
+
+  return windows;
+}
+
+/**
+ * Parse "[strategy@]<ISO start>/<ISO end>" intervals (comma-separated).
+ * Invalid entries are skipped with a warning.
+ */
+export function parseBlackouts(spec: string): BlackoutPeriod[] {
+  const blackouts: BlackoutPeriod[] = [];
+
+  for (const raw of spec.split(",")) {
+    const entry = raw.trim();
+    if (!entry) continue;
+
+    const at = entry.indexOf("@");
+    const scope = at === -1 ? undefined : entry.slice(0, at).toLowerCase();
+    const [start, end] = entry.slice(at + 1).split("/");
+    const startMs = Date.parse(start ?? "");
+    const endMs = Date.parse(end ?? "");
+
+    if (!Number.isFinite(startMs) || !Number.isFinite(endMs)) {
+      console.warn(`⚠️ [Schedule] Ignoring invalid blackout "${entry}"`);
+      continue;
+    }
+    blackouts.push({
+      startMs,
+      endMs,
+      strategies: scope ? scope.split("+") : undefined,
+    });
+  }
+
+  return blackouts;
+}
+
+/**
+ * Load windows and blackouts from the environment
+ */
+export function loadStrategyScheduleConfig(): StrategyScheduleConfig {
+  const windows: StrategyScheduleConfig["windows"] = {};
+  for (const strategy of ENTRY_STRATEGIES) {
+    const spec = envStr(`STRATEGY_WINDOWS_${strategy.toUpperCase()}`, "");
+    const parsed = parseTimeWindows(spec);
+    if (parsed.length > 0) windows[strategy] = parsed;
+  }
+  return {
+    windows,
+    blackouts: parseBlackouts(envStr("STRATEGY_BLACKOUTS", "")),
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SCHEDULE CHECK
+// ═══════════════════════════════════════════════════════════════════════════
+
+function isInWindow(window: TimeWindow, now: Date): boolean {
+  const minute = now.getUTCHours() * 60 + now.getUTCMinutes();
+  const day = now.getUTCDay();
+
+  if (window.startMinute < window.endMinute) {
+    return (
+      window.days.includes(day) &&
+      minute >= window.startMinute &&
+      minute < window.endMinute
+    );
+  }
+  // Wraps midnight: the part after midnight belongs to the previous day
+  const previousDay = (day + 6) % 7;
+  return (
+    (window.days.includes(day) && minute >= window.startMinute) ||
+    (window.days.includes(previousDay) && minute < window.endMinute)
+  );
+}
+
+/**
+ * Why a strategy may not open positions right now, or null if it may
+ */
+export function getScheduleBlockReason(
+  strategy: string,
+  config: StrategyScheduleConfig = activeConfig,
+  now: number = Date.now(),
+): string | null {
+  const blackout = config.blackouts.find(
+    (b) =>
+      now >= b.startMs &&
+      now < b.endMs &&
+      (!b.strategies || b.strategies.includes(strategy)),
+  );
+  if (blackout) {
+    return `blackout until ${new Date(blackout.endMs).toISOString()}`;
+  }
+
+  const windows = config.windows[strategy];
+  if (windows && !windows.some((w) => isInWindow(w, new Date(now)))) {
+    return "outside active window";
+  }
+
+  return null;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ACTIVE CONFIG
+// ═══════════════════════════════════════════════════════════════════════════
+
+let activeConfig: StrategyScheduleConfig = loadStrategyScheduleConfig();
+
+/**
+ * Get the active schedule config
+ */
+export function getStrategyScheduleConfig(): StrategyScheduleConfig {
+  return activeConfig;
+}
+
+/**
+ * Replace the active schedule config
+ */
+export function setStrategyScheduleConfig(
+  config: StrategyScheduleConfig,
+): void {
+  activeConfig = config;
+}
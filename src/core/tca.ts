@@ -0,0 +1,221 @@
+/**
+ * Trade Cost Analysis - One TCA record per completed execution
+ *
+ * Every completed entry or exit (a parent order: the FOK/GTC combo for an
+ * entry, the smart-sell plus any retry for an exit) is recorded with:
+ *
+ * - arrival mid: the book mid when the execution started
+ * - average fill price and filled shares
+ * - slippage vs the arrival mid, in cents and bps (positive = cost)
+ * - estimated taker fees
+ * - time from start to completion
+ *
+ * Records are kept in memory, saved with the engine state
+ * (STATE_FILE_PATH) and emitted as TCA_RECORD events. Query them by
+ * strategy, token or kind, or summarize per strategy to tune slippage and
+ * order-type settings - copy trades are the "whale" strategy.
+ */
+
+import { emitEvent } from "../infra/event-bus";
+import { estimateFeeCents } from "../lib/fee-rates";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type TcaKind = "ENTRY" | "EXIT";
+
+export interface TcaInput {
+  id: string;
+  kind: TcaKind;
+  strategy?: string;
+  tokenId: string;
+  side: "BUY" | "SELL";
+  /** Book mid when the execution started (0-1) */
+  arrivalMid: number;
+  /** Average fill price (0-1) */
+  avgFillPrice: number;
+  filledShares: number;
+  /** Taker fee rate the fills paid */
+  feeBps?: number;
+  orderType?: "FOK" | "GTC";
+  /** Orders posted to complete the execution */
+  attempts?: number;
+  startedAt: number;
+  completedAt: number;
+}
+
+export interface TcaRecord extends TcaInput {
+  notionalUsd: number;
+  /** Fill vs arrival mid in cents - positive = paid away */
+  slippageCents: number;
+  slippageBps: number;
+  feesUsd: number;
+  durationMs: number;
+}
+
+export interface TcaQuery {
+  strategy?: string;
+  tokenId?: string;
+  kind?: TcaKind;
+  /** Only records completed at or after this time */
+  since?: number;
+  limit?: number;
+}
+
+export interface TcaSummary {
+  strategy: string;
+  count: number;
+  notionalUsd: number;
+  /** Notional-weighted average slippage */
+  avgSlippageBps: number;
+  feesUsd: number;
+  avgDurationMs: number;
+}
+
+export interface TcaRecordEvent extends TcaRecord {
+  event: "TCA_RECORD";
+  timestamp: string;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RECORD
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Derive slippage, fees and duration for a completed execution
+ */
+export function buildTcaRecord(input: TcaInput): TcaRecord {
+  const diff = (input.avgFillPrice - input.arrivalMid) * 100;
+  const slippageCents = input.side === "BUY" ? diff : -diff;
+  const feePerShareCents = estimateFeeCents(
+    input.avgFillPrice * 100,
+    input.feeBps ?? 0,
+  );
+  return {
+    ...input,
+    notionalUsd: input.avgFillPrice * input.filledShares,
+    slippageCents,
+    slippageBps:
+      input.arrivalMid > 0 ? (slippageCents / 100 / input.arrivalMid) * 1e4 : 0,
+    feesUsd: (feePerShareCents / 100) * input.filledShares,
+    durationMs: Math.max(0, input.completedAt - input.startedAt),
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TCA JOURNAL
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class TcaJournal {
+  private records: TcaRecord[] = [];
+
+  constructor(private readonly maxRecords = 1000) {}
+
+  /**
+   * Record a completed execution and emit a TCA_RECORD event
+   */
+  record(input: TcaInput): TcaRecord {
+    const record = buildTcaRecord(input);
+    this.records.push(record);
+    if (this.records.length > this.maxRecords) {
+      this.records.splice(0, this.records.length - this.maxRecords);
+    }
+    const event: TcaRecordEvent = {
+      event: "TCA_RECORD",
+      timestamp: new Date(record.completedAt).toISOString(),
+      ...record,
+    };
+    emitEvent(event);
+    return record;
+  }
+
+  /**
+   * Matching records, most recent first
+   */
+  query(query: TcaQuery = {}): TcaRecord[] {
+    const matches = this.records.filter(
+      (r) =>
+        (query.strategy === undefined || r.strategy === query.strategy) &&
+        (query.tokenId === undefined || r.tokenId === query.tokenId) &&
+        (query.kind === undefined || r.kind === query.kind) &&
+        (query.since === undefined || r.completedAt >= query.since),
+    );
+    return matches.reverse().slice(0, query.limit ?? matches.length);
+  }
+
+  /**
+   * Per-strategy totals over the matching records, most notional first
+   */
+  summarize(query: Omit<TcaQuery, "limit"> = {}): TcaSummary[] {
+    const groups = new Map<string, TcaRecord[]>();
+    for (const record of this.query(query)) {
+      const strategy = record.strategy ?? "unknown";
+      const group = groups.get(strategy) ?? [];
+      group.push(record);
+      groups.set(strategy, group);
+    }
+
+    return [...groups]
+      .map(([strategy, records]) => {
+        const notionalUsd = records.reduce((s, r) => s + r.notionalUsd, 0);
+        const weighted = records.reduce(
+          (s, r) => s + r.slippageBps * r.notionalUsd,
+          0,
+        );
+        return {
+          strategy,
+          count: records.length,
+          notionalUsd,
+          avgSlippageBps: notionalUsd > 0 ? weighted / notionalUsd : 0,
+          feesUsd: records.reduce((s, r) => s + r.feesUsd, 0),
+          avgDurationMs:
+            records.reduce((s, r) => s + r.durationMs, 0) / records.length,
+        };
+      })
+      .sort((a, b) => b.notionalUsd - a.notionalUsd);
+  }
+
+  /**
+   * Records to persist with the engine state
+   */
+  exportState(): TcaRecord[] {
+    return this.records.map((record) => ({ ...record }));
+  }
+
+  /**
+   * Restore persisted records (appended before any recorded since start)
+   * @returns Number of records restored
+   */
+  importState(saved: TcaRecord[]): number {
+    const valid = saved.filter(
+      (r) => r?.id && r.tokenId && Number.isFinite(r.completedAt),
+    );
+    this.records = [...valid, ...this.records].slice(-this.maxRecords);
+    return valid.length;
+  }
+
+  size(): number {
+    return this.records.length;
+  }
+
+  clear(): void {
+    this.records = [];
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: TcaJournal | null = null;
+
+/**
+ * Get the global TCA journal
+ */
+export function getTcaJournal(): TcaJournal {
+  if (!instance) {
+    instance = new TcaJournal();
+  }
+  return instance;
+}
@@ -0,0 +1,160 @@
+/**
+ * Volatility Estimator - Rolling realized volatility per token
+ *
+ * Computes realized volatility from the trade-print stream over several
+ * rolling windows. Volatility is the standard deviation of consecutive
+ * price changes, in cents - the same unit the rest of the engine prices in.
+ *
+ * Used for:
+ * - Indicators (getIndicators) for logging/strategy decisions
+ * - Volatility-scaled position sizing (see scaleSizeForVolatility in risk.ts)
+ */
+
+import type { MarketDataStore, TradePrint } from "../lib/market-data-store";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** Default windows: 1 min, 5 min, 15 min */
+export const DEFAULT_VOLATILITY_WINDOWS_MS = [60_000, 300_000, 900_000];
+
+/** Minimum price samples in a window before volatility is reported */
+const MIN_SAMPLES = 3;
+
+export interface VolatilityWindow {
+  windowMs: number;
+  /** Std dev of price changes in cents (null if too few samples) */
+  volatilityCents: number | null;
+  samples: number;
+}
+
+export interface VolatilityIndicators {
+  tokenId: string;
+  windows: VolatilityWindow[];
+}
+
+interface PriceSample {
+  priceCents: number;
+  timestamp: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VOLATILITY ESTIMATOR
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class VolatilityEstimator {
+  private samples = new Map<string, PriceSample[]>();
+  private readonly windowsMs: number[];
+  private readonly maxWindowMs: number;
+
+  constructor(
+    windowsMs: number[] = DEFAULT_VOLATILITY_WINDOWS_MS,
+    private readonly maxTokens = 500,
+  ) {
+    this.windowsMs = [...windowsMs].sort((a, b) => a - b);
+    this.maxWindowMs = this.windowsMs[this.windowsMs.length - 1] ?? 0;
+  }
+
+  /**
+   * Record a price observation (decimal 0-1)
+   */
+  record(tokenId: string, price: number, timestamp = Date.now()): void {
+    let series = this.samples.get(tokenId);
+    if (!series) {
+      // Memory protection - drop the oldest token at capacity
+      if (this.samples.size >= this.maxTokens) {
+        const oldest = this.samples.keys().next().value;
+        if (oldest !== undefined) this.samples.delete(oldest);
+      }
+      series = [];
+      this.samples.set(tokenId, series);
+    }
+
+    series.push({ priceCents: price * 100, timestamp });
+
+    // Prune samples older than the largest window
+    const cutoff = timestamp - this.maxWindowMs;
+    while (series.length > 0 && series[0].timestamp < cutoff) {
+      series.shift();
+    }
+  }
+
+  /**
+   * Realized volatility (cents) over a window, or null if too few samples
+   */
+  getVolatility(
+    tokenId: string,
+    windowMs: number,
+    now = Date.now(),
+  ): number | null {
+    const series = this.samples.get(tokenId);
+    if (!series) return null;
+
+    const cutoff = now - windowMs;
+    const inWindow = series.filter((s) => s.timestamp >= cutoff);
+    if (inWindow.length < MIN_SAMPLES) return null;
+
+    const changes: number[] = [];
+    for (let i = 1; i < inWindow.length; i++) {
+      changes.push(inWindow[i].priceCents - inWindow[i - 1].priceCents);
+    }
+
+    const mean = changes.reduce((sum, c) => sum + c, 0) / changes.length;
+    const variance =
+      changes.reduce((sum, c) => sum + (c - mean) ** 2, 0) / changes.length;
+    return Math.sqrt(variance);
+  }
+
+  /**
+   * Volatility for every configured window
+   */
+  getIndicators(tokenId: string, now = Date.now()): VolatilityIndicators {
+    const series = this.samples.get(tokenId) ?? [];
+    return {
+      tokenId,
+      windows: this.windowsMs.map((windowMs) => ({
+        windowMs,
+        volatilityCents: this.getVolatility(tokenId, windowMs, now),
+        samples: series.filter((s) => s.timestamp >= now - windowMs).length,
+      })),
+    };
+  }
+
+  /**
+   * Feed the estimator from a market data store's trade prints
+   * @returns Unsubscribe function
+   */
+  attach(store: MarketDataStore): () => void {
+    return store.onTrade((trade: TradePrint) => {
+      this.record(trade.tokenId, trade.price, trade.timestamp);
+    });
+  }
+
+  clear(): void {
+    this.samples.clear();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: VolatilityEstimator | null = null;
+
+/**
+ * Initialize the global volatility estimator
+ */
+export function initVolatilityEstimator(
+  windowsMs?: number[],
+): VolatilityEstimator {
+  instance = new VolatilityEstimator(windowsMs);
+  return instance;
+}
+
+/**
+ * Get the global volatility estimator (null if not initialized)
+ */
+export function getVolatilityEstimator(): VolatilityEstimator | null {
+  return instance;
+}
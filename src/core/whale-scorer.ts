@@ -0,0 +1,224 @@
+/**
+ * Whale Scorer - Rank whale wallets by how well following them works
+ *
+ * Every whale entry records which tracked wallets bought the token in the
+ * bias window. When the position closes, each of those wallets is credited
+ * with the result, building per-wallet stats on the markets we followed:
+ *
+ * - hit rate:   share of followed trades that closed with a profit
+ * - avg P&L:    average P&L per followed trade (USD and return on size)
+ *
+ * The ranking (whale_rank) is shown in the status log and emitted as a
+ * WHALE_RANK event. With auto-promotion on, wallets that clear the score
+ * thresholds are pinned into the copy list so they keep being followed
+ * after dropping off the weekly leaderboard - up to a fixed cap:
+ *
+ *   WHALE_AUTO_PROMOTE=true
+ *   WHALE_PROMOTE_MIN_TRADES=5
+ *   WHALE_PROMOTE_MIN_HIT_RATE=0.6
+ *   WHALE_PROMOTE_MAX=10
+ */
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface WhaleScorerConfig {
+  /** Pin high scorers into the copy list */
+  autoPromote: boolean;
+  /** Followed trades needed before a wallet is ranked or promoted */
+  minTrades: number;
+  /** Minimum hit rate (0-1) to promote */
+  minHitRate: number;
+  /** Minimum average return on size to promote */
+  minAvgReturn: number;
+  /** Maximum wallets promoted at once */
+  maxPromoted: number;
+}
+
+export const DEFAULT_WHALE_SCORER_CONFIG: WhaleScorerConfig = {
+  autoPromote: false,
+  minTrades: 5,
+  minHitRate: 0.6,
+  minAvgReturn: 0,
+  maxPromoted: 10,
+};
+
+export interface WhaleScore {
+  wallet: string;
+  trades: number;
+  wins: number;
+  /** Fraction of followed trades with positive P&L */
+  hitRate: number;
+  pnlUsd: number;
+  avgPnlUsd: number;
+  /** Average P&L as a fraction of size */
+  avgReturn: number;
+  lastTradeAt: number;
+  promoted: boolean;
+}
+
+interface WalletStats {
+  trades: number;
+  wins: number;
+  pnlUsd: number;
+  sizeUsd: number;
+  returnSum: number;
+  lastTradeAt: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// WHALE SCORER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class WhaleScorer {
+  private readonly config: WhaleScorerConfig;
+  private stats = new Map<string, WalletStats>();
+  private promoted = new Set<string>();
+
+  constructor(config: Partial<WhaleScorerConfig> = {}) {
+    const d = DEFAULT_WHALE_SCORER_CONFIG;
+    this.config = {
+      autoPromote: config.autoPromote ?? d.autoPromote,
+      minTrades: config.minTrades ?? d.minTrades,
+      minHitRate: config.minHitRate ?? d.minHitRate,
+      minAvgReturn: config.minAvgReturn ?? d.minAvgReturn,
+      maxPromoted: config.maxPromoted ?? d.maxPromoted,
+    };
+  }
+
+  /**
+   * Credit a closed followed trade to the wallets that signalled it
+   */
+  recordResult(
+    wallets: string[],
+    pnlUsd: number,
+    sizeUsd: number,
+    now = Date.now(),
+  ): void {
+    if (!Number.isFinite(pnlUsd) || !Number.isFinite(sizeUsd)) return;
+    if (sizeUsd <= 0) return;
+
+    for (const wallet of new Set(wallets.map((w) => w.toLowerCase()))) {
+      const s = this.stats.get(wallet) ?? {
+        trades: 0,
+        wins: 0,
+        pnlUsd: 0,
+        sizeUsd: 0,
+        returnSum: 0,
+        lastTradeAt: 0,
+      };
+      s.trades++;
+      if (pnlUsd > 0) s.wins++;
+      s.pnlUsd += pnlUsd;
+      s.sizeUsd += sizeUsd;
+      s.returnSum += pnlUsd / sizeUsd;
+      s.lastTradeAt = now;
+      this.stats.set(wallet, s);
+    }
+  }
+
+  /**
+   * Score for one wallet (null if it has no followed trades)
+   */
+  getScore(wallet: string): WhaleScore | null {
+    const key = wallet.toLowerCase();
+    const s = this.stats.get(key);
+    if (!s) return null;
+    return {
+      wallet: key,
+      trades: s.trades,
+      wins: s.wins,
+      hitRate: s.wins / s.trades,
+      pnlUsd: s.pnlUsd,
+      avgPnlUsd: s.pnlUsd / s.trades,
+      avgReturn: s.returnSum / s.trades,
+      lastTradeAt: s.lastTradeAt,
+      promoted: this.promoted.has(key),
+    };
+  }
+
+  /**
+   * Wallets ranked best first: wallets with enough trades ahead of the
+   * rest, then by average return, then hit rate
+   */
+  getRanking(limit?: number): WhaleScore[] {
+    const scores = [...this.stats.keys()]
+      .map((w) => this.getScore(w)!)
+      .sort((a, b) => {
+        const aRanked = a.trades >= this.config.minTrades ? 1 : 0;
+        const bRanked = b.trades >= this.config.minTrades ? 1 : 0;
+        return (
+          bRanked - aRanked ||
+          b.avgReturn - a.avgReturn ||
+          b.hitRate - a.hitRate ||
+          b.trades - a.trades
+        );
+      });
+    return limit !== undefined ? scores.slice(0, limit) : scores;
+  }
+
+  /**
+   * Whether a wallet clears the promotion thresholds
+   */
+  qualifies(score: WhaleScore): boolean {
+    return (
+      score.trades >= this.config.minTrades &&
+      score.hitRate >= this.config.minHitRate &&
+      score.avgReturn >= this.config.minAvgReturn
+    );
+  }
+
+  /**
+   * Promote qualifying wallets (best first) until the cap is reached
+   * @returns Newly promoted wallets (empty when auto-promotion is off)
+   */
+  promote(): string[] {
+    if (!this.config.autoPromote) return [];
+
+    const added: string[] = [];
+    for (const score of this.getRanking()) {
+      if (this.promoted.size >= this.config.maxPromoted) break;
+      if (score.promoted || !this.qualifies(score)) continue;
+      this.promoted.add(score.wallet);
+      added.push(score.wallet);
+    }
+    return added;
+  }
+
+  isPromoted(wallet: string): boolean {
+    return this.promoted.has(wallet.toLowerCase());
+  }
+
+  getPromoted(): string[] {
+    return [...this.promoted];
+  }
+
+  clear(): void {
+    this.stats.clear();
+    this.promoted.clear();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: WhaleScorer | null = null;
+
+/**
+ * Initialize the global whale scorer
+ */
+export function initWhaleScorer(
+  config?: Partial<WhaleScorerConfig>,
+): WhaleScorer {
+  instance = new WhaleScorer(config);
+  return instance;
+}
+
+/**
+ * Get the global whale scorer (null if not initialized)
+ */
+export function getWhaleScorer(): WhaleScorer | null {
+  return instance;
+}
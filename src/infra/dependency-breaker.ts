@@ -0,0 +1,307 @@
+/**
+ * Dependency Breaker - Per-dependency circuit breakers for read-only APIs
+ *
+ * Screening (gamma-api) and position/whale data (data-api) are useful but
+ * not required to trade. When one of them goes down, every call waits for a
+ * full timeout before failing, which stalls the cycle for features that
+ * can't work anyway.
+ *
+ * Each dependency gets a circuit breaker hooked into axios:
+ * - CLOSED: requests flow normally
+ * - OPEN: after N consecutive failures (network error, timeout or 5xx),
+ *   requests fail immediately with a DEGRADED error for the cooldown
+ * - HALF_OPEN: after the cooldown one probe request is let through;
+ *   success closes the breaker, failure re-opens it
+ *
+ * The CLOB is deliberately not covered - core order flow is never
+ * short-circuited by this module.
+ */
+
+import axios, {
+  type AxiosInstance,
+  type InternalAxiosRequestConfig,
+} from "axios";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type DependencyName = "gamma-api" | "data-api";
+
+export type CircuitState = "CLOSED" | "OPEN" | "HALF_OPEN";
+
+export interface DependencyStatus {
+  name: DependencyName;
+  state: CircuitState;
+  consecutiveFailures: number;
+  /** When an OPEN breaker will allow a probe (ms epoch), if open */
+  retryAt?: number;
+  lastError?: string;
+}
+
+export interface DependencyBreakerConfig {
+  /** Consecutive failures before the breaker opens */
+  failureThreshold: number;
+  /** How long the breaker stays open before probing (ms) */
+  cooldownMs: number;
+}
+
+export const DEFAULT_DEPENDENCY_BREAKER_CONFIG: DependencyBreakerConfig = {
+  failureThreshold: 5,
+  cooldownMs: 60_000,
+};
+
+const DEPENDENCY_HOSTS: Record<string, DependencyName> = {
+  "gamma-api.polymarket.com": "gamma-api",
+  "data-api.polymarket.com": "data-api",
+};
+
+/** Error code on requests rejected by an open breaker */
+export const DEGRADED_ERROR_CODE = "DEGRADED";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CIRCUIT BREAKER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class CircuitBreaker {
+  private state: CircuitState = "CLOSED";
+  private consecutiveFailures = 0;
+  private openedAt = 0;
+  private probeInFlight = false;
+  private lastError?: string;
+
+  constructor(
+    readonly name: DependencyName,
+    private readonly config: DependencyBreakerConfig,
+  ) {}
+
+  /**
+   * Whether a request may go out. Moves OPEN -> HALF_OPEN once the cooldown
+   * has elapsed and lets exactly one probe through.
+   */
+  allowRequest(now = Date.now()): boolean {
+    if (this.state === "CLOSED") return true;
+
+    if (this.state === "OPEN") {
+      if (now - this.openedAt < this.config.cooldownMs) return false;
+      this.transition("HALF_OPEN");
+    }
+
+    // HALF_OPEN: single probe
+    if (this.probeInFlight) return false;
+    this.probeInFlight = true;
+    return true;
+  }
+
+  recordSuccess(): void {
+    this.consecutiveFailures = 0;
+    this.probeInFlight = false;
+    this.lastError = undefined;
+    if (this.state !== "CLOSED") this.transition("CLOSED");
+  }
+
+  recordFailure(error: string, now = Date.now()): void {
+    this.consecutiveFailures++;
+    this.probeInFlight = false;
+    this.lastError = error;
+
+    if (
+      this.state === "HALF_OPEN" ||
+      (this.state === "CLOSED" &&
+        this.consecutiveFailures >= this.config.failureThreshold)
+    ) {
+      this.openedAt = now;
+      this.transition("OPEN");
+    }
+  }
+
+  getStatus(): DependencyStatus {
+    return {
+      name: this.name,
+      state: this.state,
+      consecutiveFailures: this.consecutiveFailures,
+      retryAt:
+        this.state === "OPEN"
+          ? this.openedAt + this.config.cooldownMs
+          : undefined,
+      lastError: this.lastError,
+    };
+  }
+
+  private transition(next: CircuitState): void {
+    this.state = next;
+    console.log(
+      JSON.stringify({
+        event: "DEPENDENCY_STATUS",
+        dependency: this.name,
+        state: next,
+        consecutiveFailures: this.consecutiveFailures,
+        lastError: this.lastError,
+        timestamp: new Date().toISOString(),
+      }),
+    );
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DEPENDENCY BREAKERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Map a request URL to the dependency it belongs to (null if not covered)
+ */
+export function getDependencyForUrl(url: string): DependencyName | null {
+  try {
+    return DEPENDENCY_HOSTS[new URL(url).hostname.toLowerCase()] ?? null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Whether an axios error means the dependency is down (as opposed to a
+ * client-side problem like a 404 or a rate limit)
+ */
+function isDependencyFailure(err: any): boolean {
+  if (err?.code === DEGRADED_ERROR_CODE) return false;
+  const status: number | undefined = err?.response?.status;
+  return status === undefined || status >= 500;
+}
+
+/**
+ * Create the error thrown for requests rejected by an open breaker
+ */
+function degradedError(name: DependencyName): Error & { code: string } {
+  const err = new Error(
+    `${DEGRADED_ERROR_CODE}: ${name} unavailable (circuit open)`,
+  ) as Error & { code: string };
+  err.code = DEGRADED_ERROR_CODE;
+  return err;
+}
+
+/**
+ * Whether an error came from an open dependency breaker
+ */
+export function isDegradedError(err: unknown): boolean {
+  return (err as { code?: unknown })?.code === DEGRADED_ERROR_CODE;
+}
+
+export class DependencyBreakers {
+  private breakers = new Map<DependencyName, CircuitBreaker>();
+  private interceptorIds: { request: number; response: number } | null = null;
+  private instance: AxiosInstance | null = null;
+
+  constructor(
+    config: DependencyBreakerConfig = DEFAULT_DEPENDENCY_BREAKER_CONFIG,
+  ) {
+    for (const name of new Set(Object.values(DEPENDENCY_HOSTS))) {
+      this.breakers.set(name, new CircuitBreaker(name, config));
+    }
+  }
+
+  getBreaker(name: DependencyName): CircuitBreaker | undefined {
+    return this.breakers.get(name);
+  }
+
+  /**
+   * Status of every dependency
+   */
+  getStatus(): DependencyStatus[] {
+    return [...this.breakers.values()].map((b) => b.getStatus());
+  }
+
+  /**
+   * Whether a dependency is currently short-circuited
+   */
+  isDegraded(name: DependencyName): boolean {
+    return this.breakers.get(name)?.getStatus().state !== "CLOSED";
+  }
+
+  /**
+   * Attach interceptors to an axios instance (default: the global one)
+   */
+  install(instance: AxiosInstance = axios): void {
+    if (this.interceptorIds) return;
+
+    const request = instance.interceptors.request.use(
+      (config: InternalAxiosRequestConfig) => {
+        const breaker = this.breakerFor(axios.getUri(config));
+        if (breaker && !breaker.allowRequest()) {
+          throw degradedError(breaker.name);
+        }
+        return config;
+      },
+    );
+
+    const response = instance.interceptors.response.use(
+      (res) => {
+        this.breakerFor(axios.getUri(res.config))?.recordSuccess();
+        return res;
+      },
+      (err) => {
+        if (err?.config) {
+          const breaker = this.breakerFor(axios.getUri(err.config));
+          if (breaker) {
+            if (isDependencyFailure(err)) {
+              breaker.recordFailure(String(err.code ?? err.message));
+            } else if (!isDegradedError(err)) {
+              // 4xx/429 - the service answered, so it's up
+              breaker.recordSuccess();
+            }
+          }
+        }
+        return Promise.reject(err);
+      },
+    );
+
+    this.interceptorIds = { request, response };
+    this.instance = instance;
+  }
+
+  /**
+   * Detach interceptors
+   */
+  uninstall(): void {
+    if (!this.interceptorIds || !this.instance) return;
+    this.instance.interceptors.request.eject(this.interceptorIds.request);
+    this.instance.interceptors.response.eject(this.interceptorIds.response);
+    this.interceptorIds = null;
+    this.instance = null;
+  }
+
+  private breakerFor(url: string): CircuitBreaker | undefined {
+    const name = getDependencyForUrl(url);
+    return name ? this.breakers.get(name) : undefined;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: DependencyBreakers | null = null;
+
+/**
+ * Initialize global dependency breakers and hook them into axios
+ */
+export function initDependencyBreakers(
+  config?: Partial<DependencyBreakerConfig>,
+): DependencyBreakers {
+  instance?.uninstall();
+  instance = new DependencyBreakers({
+    failureThreshold:
+      config?.failureThreshold ??
+      DEFAULT_DEPENDENCY_BREAKER_CONFIG.failureThreshold,
+    cooldownMs:
+      config?.cooldownMs ?? DEFAULT_DEPENDENCY_BREAKER_CONFIG.cooldownMs,
+  });
+  instance.install();
+  return instance;
+}
+
+/**
+ * Get the global dependency breakers (null if not initialized)
+ */
+export function getDependencyBreakers(): DependencyBreakers | null {
+  return instance;
+}
@@ -0,0 +1,332 @@
+/**
+ * Endpoint Latency - Per-endpoint percentiles and write-path SLO alerts
+ *
+ * The latency monitor probes a few endpoints every 30s; this tracks the
+ * bot's real Polymarket traffic. Every request through axios (CLOB client,
+ * Data API, Gamma) is timed and grouped by endpoint - method, host and
+ * path with IDs collapsed ("POST clob.polymarket.com/order",
+ * "GET clob.polymarket.com/book") - and p50/p95/p99 are kept over a
+ * rolling window.
+ *
+ * Orders are posted and cancelled on the write path (POST/DELETE on the
+ * CLOB). When a write endpoint's p95 stays above SLO_WRITE_P95_MS for
+ * SLO_SUSTAIN_SECONDS, an SLO_BREACH event is emitted - slow writes are
+ * usually a geo/VPN route going bad, and show up well before orders
+ * start failing. SLO_RECOVERED follows once p95 is back under.
+ */
+
+import axios, {
+  type AxiosInstance,
+  type InternalAxiosRequestConfig,
+} from "axios";
+import { emitEvent } from "./event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface EndpointLatencyConfig {
+  /** Samples older than this don't count (ms) */
+  windowMs: number;
+  /** Samples kept per endpoint */
+  maxSamples: number;
+  /** Write-path p95 that counts as a breach (ms, 0 = no alerts) */
+  writeP95Ms: number;
+  /** How long p95 must stay above it before alerting (ms) */
+  sustainMs: number;
+  /** Samples needed before p95 is judged */
+  minSamples: number;
+}
+
+export const DEFAULT_ENDPOINT_LATENCY_CONFIG: EndpointLatencyConfig = {
+  windowMs: 10 * 60_000,
+  maxSamples: 200,
+  writeP95Ms: 1500,
+  sustainMs: 120_000,
+  minSamples: 5,
+};
+
+export interface EndpointLatencyStats {
+  endpoint: string;
+  /** POST/DELETE on the CLOB */
+  write: boolean;
+  count: number;
+  /** Share of requests that failed (no response or 5xx) */
+  errorRate: number;
+  p50Ms: number;
+  p95Ms: number;
+  p99Ms: number;
+}
+
+export interface SloBreachEvent {
+  event: "SLO_BREACH" | "SLO_RECOVERED";
+  timestamp: string;
+  endpoint: string;
+  p95Ms: number;
+  thresholdMs: number;
+  /** How long p95 has been (or was) above the threshold */
+  sustainedMs: number;
+  samples: number;
+}
+
+interface Sample {
+  at: number;
+  latencyMs: number;
+  failed: boolean;
+}
+
+// Start time is stashed on the request config by the request interceptor
+type TimedRequestConfig = InternalAxiosRequestConfig & {
+  __latencyStartMs?: number;
+};
+
+/** Path segments that are IDs, not part of the endpoint */
+const ID_SEGMENT = /^(0x[0-9a-f]+|\d{4,}|[0-9a-f-]{32,})$/i;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// HELPERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Endpoint key for a request ("POST clob.polymarket.com/order"), null for
+ * anything that isn't a Polymarket API
+ */
+export function endpointKey(method: string, url: string): string | null {
+  try {
+    const parsed = new URL(url);
+    const host = parsed.hostname.toLowerCase();
+    if (!host.endsWith("polymarket.com")) return null;
+    const path = parsed.pathname
+      .split("/")
+      .map((s) => (ID_SEGMENT.test(s) ? ":id" : s))
+      .join("/")
+      .replace(/\/+$/, "");
+    return `${method.toUpperCase()} ${host}${path || "/"}`;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Whether an endpoint is on the order write path
+ */
+export function isWriteEndpoint(endpoint: string): boolean {
+  return /^(POST|DELETE) clob\./.test(endpoint);
+}
+
+function percentile(sorted: number[], p: number): number {
+  if (sorted.length === 0) return 0;
+  return sorted[Math.min(sorted.length - 1, Math.floor(sorted.length * p))];
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ENDPOINT LATENCY TRACKER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class EndpointLatencyTracker {
+  private readonly config: EndpointLatencyConfig;
+  private samples = new Map<string, Sample[]>();
+  /** Write endpoint -> when its p95 went over the threshold */
+  private breachSince = new Map<string, number>();
+  private alerted = new Set<string>();
+  private listeners: Array<(event: SloBreachEvent) => void> = [];
+  private interceptorIds: { request: number; response: number } | null = null;
+  private instance: AxiosInstance | null = null;
+
+  constructor(config: Partial<EndpointLatencyConfig> = {}) {
+    this.config = { ...DEFAULT_ENDPOINT_LATENCY_CONFIG, ...config };
+  }
+
+  /**
+   * Record one request
+   */
+  record(
+    endpoint: string,
+    latencyMs: number,
+    failed = false,
+    now = Date.now(),
+  ): void {
+    const samples = this.samples.get(endpoint) ?? [];
+    samples.push({ at: now, latencyMs, failed });
+    if (samples.length > this.config.maxSamples) samples.shift();
+    this.samples.set(endpoint, samples);
+    if (isWriteEndpoint(endpoint)) this.evaluate(now);
+  }
+
+  /**
+   * Percentiles per endpoint over the window, slowest p95 first
+   */
+  getStats(now = Date.now()): EndpointLatencyStats[] {
+    const stats: EndpointLatencyStats[] = [];
+    for (const endpoint of this.samples.keys()) {
+      const s = this.statsFor(endpoint, now);
+      if (s) stats.push(s);
+    }
+    return stats.sort((a, b) => b.p95Ms - a.p95Ms);
+  }
+
+  /**
+   * Check write endpoints against the SLO. Called on every write sample
+   * and periodically, so a breach is also noticed when writes stop.
+   */
+  evaluate(now = Date.now()): void {
+    const { writeP95Ms, sustainMs, minSamples } = this.config;
+    if (writeP95Ms <= 0) return;
+
+    for (const endpoint of this.samples.keys()) {
+      if (!isWriteEndpoint(endpoint)) continue;
+      const stats = this.statsFor(endpoint, now);
+      const over =
+        stats !== null && stats.count >= minSamples && stats.p95Ms > writeP95Ms;
+
+      if (over) {
+        const since = this.breachSince.get(endpoint) ?? now;
+        this.breachSince.set(endpoint, since);
+        if (!this.alerted.has(endpoint) && now - since >= sustainMs) {
+          this.alerted.add(endpoint);
+          this.notify("SLO_BREACH", endpoint, stats, now - since);
+        }
+        continue;
+      }
+
+      const since = this.breachSince.get(endpoint);
+      this.breachSince.delete(endpoint);
+      if (since !== undefined && this.alerted.delete(endpoint)) {
+        this.notify("SLO_RECOVERED", endpoint, stats, now - since);
+      }
+    }
+  }
+
+  /**
+   * Write endpoints currently in breach
+   */
+  getBreaches(): string[] {
+    return [...this.alerted];
+  }
+
+  /**
+   * Subscribe to SLO_BREACH / SLO_RECOVERED
+   * @returns Unsubscribe function
+   */
+  onSloEvent(listener: (event: SloBreachEvent) => void): () => void {
+    this.listeners.push(listener);
+    return () => {
+      this.listeners = this.listeners.filter((l) => l !== listener);
+    };
+  }
+
+  /**
+   * Attach interceptors to an axios instance (default: the global one,
+   * which the CLOB client and the API helpers use)
+   */
+  install(instance: AxiosInstance = axios): void {
+    if (this.interceptorIds) return;
+
+    const request = instance.interceptors.request.use((config) => {
+      (config as TimedRequestConfig).__latencyStartMs = Date.now();
+      return config;
+    });
+
+    const response = instance.interceptors.response.use(
+      (res) => {
+        this.recordRequest(res.config, false);
+        return res;
+      },
+      (err) => {
+        if (err?.config) {
+          const status: number | undefined = err.response?.status;
+          this.recordRequest(err.config, status === undefined || status >= 500);
+        }
+        return Promise.reject(err);
+      },
+    );
+
+    this.interceptorIds = { request, response };
+    this.instance = instance;
+  }
+
+  /**
+   * Detach interceptors
+   */
+  uninstall(): void {
+    if (!this.interceptorIds || !this.instance) return;
+    this.instance.interceptors.request.eject(this.interceptorIds.request);
+    this.instance.interceptors.response.eject(this.interceptorIds.response);
+    this.interceptorIds = null;
+    this.instance = null;
+  }
+
+  private recordRequest(config: TimedRequestConfig, failed: boolean): void {
+    const startMs = config.__latencyStartMs;
+    if (startMs === undefined) return;
+    const endpoint = endpointKey(config.method ?? "get", axios.getUri(config));
+    if (endpoint) this.record(endpoint, Date.now() - startMs, failed);
+  }
+
+  private statsFor(endpoint: string, now: number): EndpointLatencyStats | null {
+    const recent = (this.samples.get(endpoint) ?? []).filter(
+      (s) => now - s.at <= this.config.windowMs,
+    );
+    if (recent.length === 0) return null;
+    const sorted = recent.map((s) => s.latencyMs).sort((a, b) => a - b);
+    return {
+      endpoint,
+      write: isWriteEndpoint(endpoint),
+      count: recent.length,
+      errorRate: recent.filter((s) => s.failed).length / recent.length,
+      p50Ms: percentile(sorted, 0.5),
+      p95Ms: percentile(sorted, 0.95),
+      p99Ms: percentile(sorted, 0.99),
+    };
+  }
+
+  private notify(
+    kind: SloBreachEvent["event"],
+    endpoint: string,
+    stats: EndpointLatencyStats | null,
+    sustainedMs: number,
+  ): void {
+    const event: SloBreachEvent = {
+      event: kind,
+      timestamp: new Date().toISOString(),
+      endpoint,
+      p95Ms: Math.round(stats?.p95Ms ?? 0),
+      thresholdMs: this.config.writeP95Ms,
+      sustainedMs,
+      samples: stats?.count ?? 0,
+    };
+    emitEvent(event);
+    for (const listener of this.listeners) {
+      try {
+        listener(event);
+      } catch {
+        // A failing listener must not break request handling
+      }
+    }
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: EndpointLatencyTracker | null = null;
+
+/**
+ * Initialize the global endpoint latency tracker and hook it into axios
+ */
+export function initEndpointLatency(
+  config?: Partial<EndpointLatencyConfig>,
+): EndpointLatencyTracker {
+  instance?.uninstall();
+  instance = new EndpointLatencyTracker(config);
+  instance.install();
+  return instance;
+}
+
+/**
+ * Get the global endpoint latency tracker (null if not initialized)
+ */
+export function getEndpointLatency(): EndpointLatencyTracker | null {
+  return instance;
+}
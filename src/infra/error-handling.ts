@@ -19,6 +19,8 @@
  * - UNKNOWN: Unclassified error
  */
 
+import { emitEvent } from "./event-bus";
+
 /**
  * Error codes for common failure scenarios
  */
@@ -122,7 +124,7 @@ function safeErrorToString(error: unknown): string {
 /**
  * Recursively redacts values of known sensitive keys in an arbitrary data structure.
  */
-function redactSensitiveData(
+export function redactSensitiveData(
   value: unknown,
   seen: WeakSet<object> = new WeakSet(),
 ): unknown {
@@ -166,7 +168,7 @@ function redactSensitiveData(
 /**
  * Redact obvious credentials in plain text strings (best-effort).
  */
-function redactSensitiveInString(message: string): string {
+export function redactSensitiveInString(message: string): string {
   let result = message;
 
   // Patterns like "Authorization: Bearer abc123", "api_key=abc123", "token: abc123"
@@ -330,7 +332,8 @@ export function parseError(error: unknown): ParsedError {
   ) {
     return {
       code: ErrorCode.AUTH_FAILED,
-      message: "Authentication failed. Check your API credentials.",
+      message:
+        "Authentication failed. Check your API credentials - `npm run test-trade` confirms the account can place orders.",
       recoverable: false,
     };
   }
@@ -478,6 +481,152 @@ export function parseError(error: unknown): ParsedError {
   };
 }
 
+/**
+ * Structured error fields from a CLOB response
+ */
+export interface ClobErrorInfo {
+  /** Classified code - stable across changes to the server's wording */
+  errorCode: ErrorCode;
+  /** The server's own message, e.g. "Unauthorized/Invalid api key" */
+  errorDetail: string;
+  /** The body's `code` field, when the server sent one */
+  serverCode?: string;
+  /** HTTP status of the failed response, when there was one */
+  httpStatus?: number;
+  /** "METHOD /path" of the failed call (no host or query) */
+  endpoint?: string;
+  /** Server request ID from the response headers, for support requests */
+  requestId?: string;
+}
+
+/**
+ * The ClobErrorInfo fields carried on order results
+ */
+export interface ClobErrorFields {
+  errorCode?: string;
+  errorDetail?: string;
+  httpStatus?: number;
+  endpoint?: string;
+  requestId?: string;
+}
+
+/** CLOB order placement (clob-client postOrder) */
+export const CLOB_POST_ORDER_ENDPOINT = "POST /order";
+
+// Checked in order: an explicit request ID first, Cloudflare's ray ID last
+const REQUEST_ID_HEADERS = ["x-request-id", "request-id", "cf-ray"];
+
+/**
+ * Extract the CLOB's error fields from whatever shape the failure took:
+ *
+ * - a rejected postOrder response: { success: false, errorMsg }
+ * - clob-client's swallowed HTTP error: { error: <body>, status }
+ * - a thrown axios error: err.response.{status, data}
+ *
+ * Bodies look like { "error": "Unauthorized/Invalid api key" }, sometimes
+ * with a `code`. Reading the fields (instead of stringifying the whole
+ * payload) keeps "[object Object]" out of logs and lets callers tell an
+ * auth failure from a balance/allowance rejection without string matching.
+ *
+ * The HTTP status, endpoint and request ID are attached when the payload
+ * carries them; `endpoint` names the call when it doesn't (clob-client
+ * drops the request config from swallowed errors). Local errors that never
+ * reached the server get no endpoint.
+ */
+export function parseClobError(
+  payload: unknown,
+  endpoint?: string,
+): ClobErrorInfo {
+  const raw = payload as any;
+  const body = raw?.response?.data ?? raw?.data ?? raw;
+  const status: unknown = raw?.response?.status ?? raw?.status;
+
+  let detail: any =
+    typeof body === "string"
+      ? body
+      : (body?.errorMsg ?? body?.error ?? body?.message);
+  let serverCode: unknown = body?.code;
+  // clob-client nests the HTTP body: { error: { error, code } }
+  if (detail && typeof detail === "object") {
+    serverCode = serverCode ?? detail.code;
+    detail = detail.error ?? detail.errorMsg ?? detail.message;
+  }
+  const errorDetail =
+    typeof detail === "string" && detail
+      ? detail
+      : payload instanceof Error
+        ? payload.message
+        : safeErrorToString(detail ?? payload) || "Unknown error";
+
+  let errorCode: ErrorCode;
+  if (isCloudflareBlock(payload)) {
+    errorCode = ErrorCode.CLOUDFLARE_BLOCKED;
+  } else if (status === 401) {
+    errorCode = ErrorCode.AUTH_FAILED;
+  } else if (status === 429) {
+    errorCode = ErrorCode.RATE_LIMITED;
+  } else {
+    errorCode = parseError(errorDetail).code;
+  }
+
+  return {
+    errorCode,
+    errorDetail,
+    serverCode:
+      serverCode !== undefined && serverCode !== null
+        ? String(serverCode)
+        : undefined,
+    httpStatus: typeof status === "number" ? status : undefined,
+    endpoint:
+      requestEndpoint(raw?.config ?? raw?.response?.config) ??
+      (payload instanceof Error && !raw.response ? undefined : endpoint),
+    requestId: responseRequestId(raw?.response?.headers ?? raw?.headers),
+  };
+}
+
+/**
+ * Order result fields for a CLOB error (empty when there was no error)
+ */
+export function clobErrorFields(error: ClobErrorInfo | null): ClobErrorFields {
+  if (!error) return {};
+  const fields: ClobErrorFields = {
+    errorCode: error.errorCode,
+    errorDetail: error.errorDetail,
+  };
+  if (error.httpStatus !== undefined) fields.httpStatus = error.httpStatus;
+  if (error.endpoint !== undefined) fields.endpoint = error.endpoint;
+  if (error.requestId !== undefined) fields.requestId = error.requestId;
+  return fields;
+}
+
+/**
+ * "METHOD /path" from an axios request config
+ */
+function requestEndpoint(config: any): string | undefined {
+  if (typeof config?.url !== "string" || !config.url) return undefined;
+  let pathname: string;
+  try {
+    pathname = new URL(config.url, "http://localhost").pathname;
+  } catch {
+    pathname = config.url.split("?")[0];
+  }
+  const method = String(config.method ?? "get").toUpperCase();
+  return `${method} ${pathname}`;
+}
+
+/**
+ * Request ID from response headers (axios headers or a plain object)
+ */
+function responseRequestId(headers: any): string | undefined {
+  if (!headers || typeof headers !== "object") return undefined;
+  for (const name of REQUEST_ID_HEADERS) {
+    const value =
+      typeof headers.get === "function" ? headers.get(name) : headers[name];
+    if (typeof value === "string" && value) return value;
+  }
+  return undefined;
+}
+
 /**
  * Format error for logging (strips sensitive data, limits length)
  */
@@ -673,7 +822,7 @@ export function emitCloudflareBlockEvent(
   };
 
   // Emit structured JSON log
-  console.log(JSON.stringify(event));
+  emitEvent(event);
 
   // Emit human-readable log with VPN status
   const vpnStatusStr =
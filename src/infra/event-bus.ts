@@ -0,0 +1,409 @@
+/**
+ * Event Bus - Fan structured events out to pluggable sinks
+ *
+ * Structured events (fills, exit triggers, risk blocks, disconnects...) are
+ * emitted once through emitEvent() and delivered to every sink whose filter
+ * matches the event name:
+ *
+ * - stdout:  JSON lines on stdout (the default, same as before the bus)
+ * - file:    JSON lines appended to a file
+ * - webhook: HTTP POST of each event (fire-and-forget)
+ * - socket:  JSON lines streamed to clients of a TCP port or Unix socket
+ *            (a named pipe on Windows)
+ *
+ * Filters are comma-separated event names; a trailing "*" matches a prefix
+ * and a leading "!" excludes:
+ *
+ *   EVENT_WEBHOOK_FILTER="ENTRY_FILLED,POSITION_CLOSED,RISK_*"
+ *   EVENT_STDOUT_FILTER="*,!PRICE_FORMATION"
+ *
+ * Until initEventBus() is called, emitEvent() writes to stdout so modules
+ * can emit before (or without) the engine starting.
+ *
+ * With EVENT_HEARTBEAT_SECONDS set, a {"event":"heartbeat","seq":N} line
+ * goes out on that interval (through the same filters), so a consumer can
+ * tell a dead pipe or a stalled bot from a quiet market: a gap in seq is
+ * lost lines, no heartbeat at all is a dead producer.
+ */
+
+import axios from "axios";
+import * as fs from "fs";
+import * as net from "net";
+import * as path from "path";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** Any structured event - "event" names it (e.g. "ENTRY_FILLED") */
+export interface BotEvent {
+  event: string;
+}
+
+export interface HeartbeatEvent {
+  event: "heartbeat";
+  /** 1, 2, 3... per bus, so gaps show dropped lines */
+  seq: number;
+  timestamp: string;
+}
+
+export interface EventFilter {
+  include: string[];
+  exclude: string[];
+}
+
+export interface EventSink {
+  readonly name: string;
+  readonly filter: EventFilter;
+  write(event: BotEvent): void;
+  close?(): void;
+}
+
+export interface EventBusConfig {
+  /** Write events to stdout */
+  stdout: boolean;
+  stdoutFilter: string;
+  /** Append JSON lines to this file (empty = disabled) */
+  filePath: string;
+  fileFilter: string;
+  /** POST events to this URL (empty = disabled) */
+  webhookUrl: string;
+  webhookFilter: string;
+  /** TCP port ("9464") or Unix socket path to stream events on */
+  socket: string;
+  socketFilter: string;
+  /** Emit a heartbeat event this often (ms, 0 = never) */
+  heartbeatMs: number;
+}
+
+export const DEFAULT_EVENT_BUS_CONFIG: EventBusConfig = {
+  stdout: true,
+  stdoutFilter: "*",
+  filePath: "",
+  fileFilter: "*",
+  webhookUrl: "",
+  webhookFilter: "*",
+  socket: "",
+  socketFilter: "*",
+  heartbeatMs: 0,
+};
+
+const WEBHOOK_TIMEOUT_MS = 5000;
+/** Minimum time between webhook failure warnings */
+const WEBHOOK_WARN_INTERVAL_MS = 60_000;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FILTERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "A,B_*,!C" into include/exclude patterns. An empty spec or one with
+ * only exclusions matches everything else.
+ */
+export function parseEventFilter(spec: string): EventFilter {
+  const include: string[] = [];
+  const exclude: string[] = [];
+  for (const raw of spec.split(",")) {
+    const pattern = raw.trim();
+    if (!pattern) continue;
+    if (pattern.startsWith("!")) exclude.push(pattern.slice(1));
+    else include.push(pattern);
+  }
+  return { include: include.length > 0 ? include : ["*"], exclude };
+}
+
+function matchesPattern(pattern: string, name: string): boolean {
+  if (pattern.endsWith("*")) return name.startsWith(pattern.slice(0, -1));
+  return pattern === name;
+}
+
+/**
+ * Whether an event name passes a filter
+ */
+export function matchesEventFilter(filter: EventFilter, name: string): boolean {
+  return (
+    filter.include.some((p) => matchesPattern(p, name)) &&
+    !filter.exclude.some((p) => matchesPattern(p, name))
+  );
+}
+
+/**
+ * Resolve a socket sink address: a TCP port, or an IPC path. Windows has no
+ * Unix sockets, so IPC paths there become named pipes (\\.\pipe\<name>).
+ */
+export function resolveSocketAddress(
+  address: string,
+  platform: NodeJS.Platform = process.platform,
+): { port: number } | { path: string } {
+  const port = Number(address);
+  if (Number.isInteger(port) && port > 0) return { port };
+
+  if (platform === "win32" && !address.startsWith("\\\\.\\pipe\\")) {
+    return { path: `\\\\.\\pipe\\${path.win32.basename(address)}` };
+  }
+  return { path: address };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINKS
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class StdoutSink implements EventSink {
+  readonly name = "stdout";
+
+  constructor(readonly filter: EventFilter = parseEventFilter("*")) {}
+
+  write(event: BotEvent): void {
+    console.log(JSON.stringify(event));
+  }
+}
+
+export class FileSink implements EventSink {
+  readonly name: string;
+  private stream: fs.WriteStream;
+
+  constructor(
+    filePath: string,
+    readonly filter: EventFilter = parseEventFilter("*"),
+  ) {
+    this.name = `file:${filePath}`;
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    this.stream = fs.createWriteStream(filePath, { flags: "a" });
+    this.stream.on("error", (err) => {
+      console.warn(`⚠️ [EventBus] File sink error: ${err.message}`);
+    });
+  }
+
+  write(event: BotEvent): void {
+    this.stream.write(`${JSON.stringify(event)}\n`);
+  }
+
+  close(): void {
+    this.stream.end();
+  }
+}
+
+export class WebhookSink implements EventSink {
+  readonly name = "webhook";
+  private lastWarnAt = 0;
+
+  constructor(
+    private readonly url: string,
+    readonly filter: EventFilter = parseEventFilter("*"),
+  ) {}
+
+  write(event: BotEvent): void {
+    axios
+      .post(this.url, event, { timeout: WEBHOOK_TIMEOUT_MS })
+      .catch((err: unknown) => {
+        const now = Date.now();
+        if (now - this.lastWarnAt < WEBHOOK_WARN_INTERVAL_MS) return;
+        this.lastWarnAt = now;
+        console.warn(
+          `⚠️ [EventBus] Webhook delivery failed: ${err instanceof Error ? err.message : err}`,
+        );
+      });
+  }
+}
+
+export class SocketSink implements EventSink {
+  readonly name: string;
+  private server: net.Server;
+  private clients = new Set<net.Socket>();
+
+  /**
+   * @param address - TCP port ("9464") or Unix socket path / pipe name
+   */
+  constructor(
+    address: string,
+    readonly filter: EventFilter = parseEventFilter("*"),
+  ) {
+    this.name = `socket:${address}`;
+    this.server = net.createServer((socket) => {
+      this.clients.add(socket);
+      socket.on("close", () => this.clients.delete(socket));
+      socket.on("error", () => this.clients.delete(socket));
+    });
+    this.server.on("error", (err) => {
+      console.warn(`⚠️ [EventBus] Socket sink error: ${err.message}`);
+    });
+
+    const resolved = resolveSocketAddress(address);
+    if ("port" in resolved) {
+      this.server.listen(resolved.port, "127.0.0.1");
+    } else {
+      // A stale socket file from a previous run blocks listen() (named
+      // pipes disappear with their process). Anything else at the path is
+      // a typo or misused path - never delete it
+      if (process.platform !== "win32" && fs.existsSync(resolved.path)) {
+        if (!fs.lstatSync(resolved.path).isSocket()) {
+          throw new Error(
+            `EVENT_SOCKET path ${resolved.path} exists and is not a socket - refusing to replace it`,
+          );
+        }
+        fs.unlinkSync(resolved.path);
+      }
+      this.server.listen(resolved.path);
+    }
+  }
+
+  getClientCount(): number {
+    return this.clients.size;
+  }
+
+  write(event: BotEvent): void {
+    if (this.clients.size === 0) return;
+    const line = `${JSON.stringify(event)}\n`;
+    for (const client of this.clients) {
+      // Slow clients are dropped rather than buffering without bound
+      if (client.writableLength > 1_000_000) {
+        client.destroy();
+        continue;
+      }
+      client.write(line);
+    }
+  }
+
+  close(): void {
+    for (const client of this.clients) client.destroy();
+    this.clients.clear();
+    this.server.close();
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// EVENT BUS
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class EventBus {
+  private sinks: EventSink[] = [];
+  private heartbeatTimer: ReturnType<typeof setInterval> | null = null;
+  private heartbeatSeq = 0;
+
+  addSink(sink: EventSink): void {
+    this.sinks.push(sink);
+  }
+
+  removeSink(name: string): void {
+    const sink = this.sinks.find((s) => s.name === name);
+    sink?.close?.();
+    this.sinks = this.sinks.filter((s) => s !== sink);
+  }
+
+  getSinkNames(): string[] {
+    return this.sinks.map((s) => s.name);
+  }
+
+  /**
+   * Deliver an event to every sink whose filter matches
+   */
+  emit<T extends BotEvent>(event: T): void {
+    for (const sink of this.sinks) {
+      if (!matchesEventFilter(sink.filter, event.event)) continue;
+      try {
+        sink.write(event);
+      } catch {
+        // A broken sink must not break the caller (or the other sinks)
+      }
+    }
+  }
+
+  /**
+   * Emit a heartbeat every intervalMs (replaces any running one)
+   */
+  startHeartbeat(intervalMs: number): void {
+    this.stopHeartbeat();
+    if (intervalMs <= 0) return;
+    this.heartbeatTimer = setInterval(() => this.heartbeat(), intervalMs);
+    // The heartbeat alone must not keep the process alive
+    this.heartbeatTimer.unref?.();
+  }
+
+  stopHeartbeat(): void {
+    if (this.heartbeatTimer) clearInterval(this.heartbeatTimer);
+    this.heartbeatTimer = null;
+  }
+
+  /**
+   * Emit the next heartbeat now
+   */
+  heartbeat(): HeartbeatEvent {
+    const event: HeartbeatEvent = {
+      event: "heartbeat",
+      seq: ++this.heartbeatSeq,
+      timestamp: new Date().toISOString(),
+    };
+    this.emit(event);
+    return event;
+  }
+
+  close(): void {
+    this.stopHeartbeat();
+    for (const sink of this.sinks) sink.close?.();
+    this.sinks = [];
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: EventBus | null = null;
+
+/**
+ * Initialize the global event bus with the configured sinks
+ */
+export function initEventBus(config: Partial<EventBusConfig> = {}): EventBus {
+  const d = DEFAULT_EVENT_BUS_CONFIG;
+  const c: EventBusConfig = {
+    stdout: config.stdout ?? d.stdout,
+    stdoutFilter: config.stdoutFilter ?? d.stdoutFilter,
+    filePath: config.filePath ?? d.filePath,
+    fileFilter: config.fileFilter ?? d.fileFilter,
+    webhookUrl: config.webhookUrl ?? d.webhookUrl,
+    webhookFilter: config.webhookFilter ?? d.webhookFilter,
+    socket: config.socket ?? d.socket,
+    socketFilter: config.socketFilter ?? d.socketFilter,
+    heartbeatMs: config.heartbeatMs ?? d.heartbeatMs,
+  };
+
+  instance?.close();
+  const bus = new EventBus();
+  if (c.stdout) {
+    bus.addSink(new StdoutSink(parseEventFilter(c.stdoutFilter)));
+  }
+  if (c.filePath) {
+    bus.addSink(new FileSink(c.filePath, parseEventFilter(c.fileFilter)));
+  }
+  if (c.webhookUrl) {
+    bus.addSink(
+      new WebhookSink(c.webhookUrl, parseEventFilter(c.webhookFilter)),
+    );
+  }
+  if (c.socket) {
+    bus.addSink(new SocketSink(c.socket, parseEventFilter(c.socketFilter)));
+  }
+
+  bus.startHeartbeat(c.heartbeatMs);
+
+  instance = bus;
+  return instance;
+}
+
+/**
+ * Get the global event bus (null if not initialized)
+ */
+export function getEventBus(): EventBus | null {
+  return instance;
+}
+
+/**
+ * Emit a structured event (stdout only until the bus is initialized)
+ */
+export function emitEvent<T extends BotEvent>(event: T): void {
+  if (instance) {
+    instance.emit(event);
+  } else {
+    console.log(JSON.stringify(event));
+  }
+}
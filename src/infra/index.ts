@@ -43,6 +43,14 @@ export {
   StoreRegistry,
   getStoreRegistry,
   initStoreRegistry,
+  // Bounded caches
+  LruCache,
+  createBoundedCache,
+  DEFAULT_CACHE_MAX_ENTRIES,
+  // State file snapshots
+  saveStateFile,
+  loadStateFile,
+  STATE_FILE_VERSION,
 } from "./persistence";
 
 export type {
@@ -65,6 +73,10 @@ export type {
   // Store registry
   AggregatedHealthStatus,
   AggregatedMetrics,
+  StoreMetricsSummary,
+  // State file snapshots
+  StateFileEnvelope,
+  StateFileResult,
 } from "./persistence";
 
 // Error handling utilities (moved from /lib for better architecture)
@@ -140,3 +152,82 @@ export type {
   ApiUsageStats,
   MissedTradeStats,
 } from "./api-rate-monitor";
+
+// Request capture - HAR-style request tracing for support bundles
+export {
+  RequestCapture,
+  initRequestCapture,
+  getRequestCapture,
+  buildSupportBundle,
+  writeSupportBundle,
+  sanitizeUrl,
+} from "./request-capture";
+
+export type { CapturedRequest, SupportBundle } from "./request-capture";
+
+// Dependency breakers - fail fast when gamma-api/data-api are down
+export {
+  CircuitBreaker,
+  DependencyBreakers,
+  initDependencyBreakers,
+  getDependencyBreakers,
+  getDependencyForUrl,
+  isDegradedError,
+  DEGRADED_ERROR_CODE,
+  DEFAULT_DEPENDENCY_BREAKER_CONFIG,
+} from "./dependency-breaker";
+
+export type {
+  DependencyName,
+  CircuitState,
+  DependencyStatus,
+  DependencyBreakerConfig,
+} from "./dependency-breaker";
+
+// Endpoint latency - per-endpoint percentiles and write-path SLO alerts
+export {
+  EndpointLatencyTracker,
+  initEndpointLatency,
+  getEndpointLatency,
+  endpointKey,
+  isWriteEndpoint,
+  DEFAULT_ENDPOINT_LATENCY_CONFIG,
+} from "./endpoint-latency";
+
+export type {
+  EndpointLatencyConfig,
+  EndpointLatencyStats,
+  SloBreachEvent,
+} from "./endpoint-latency";
+
+// Startup event - single structured line with the effective environment
+export {
+  buildStartupEvent,
+  readBotVersion,
+  redactEndpoint,
+} from "./startup-event";
+
+export type { StartupInfo, StartupEvent } from "./startup-event";
+
+// Event bus - fan structured events out to stdout/file/webhook/socket sinks
+export {
+  EventBus,
+  StdoutSink,
+  FileSink,
+  WebhookSink,
+  SocketSink,
+  initEventBus,
+  getEventBus,
+  emitEvent,
+  parseEventFilter,
+  matchesEventFilter,
+  DEFAULT_EVENT_BUS_CONFIG,
+} from "./event-bus";
+
+export type {
+  BotEvent,
+  EventFilter,
+  EventSink,
+  EventBusConfig,
+  HeartbeatEvent,
+} from "./event-bus";
@@ -13,6 +13,8 @@
  * - MarketCache: Market token pair caching with multi-key indexing
  * - PositionStore: Position data with staleness tracking
  * - StoreRegistry: Central registry for health check aggregation
+ * - StateFile: Durable JSON snapshots of in-memory bot state
+ * - LruCache: Bounded key/value cache for module-level lookups
  *
  * Note: MarketDataStore remains in lib/market-data-store.ts for backward
  * compatibility, but implements the same health check interface.
@@ -76,4 +78,21 @@ export {
   initStoreRegistry,
   type AggregatedHealthStatus,
   type AggregatedMetrics,
+  type StoreMetricsSummary,
 } from "./store-registry";
+
+// Bounded caches
+export {
+  LruCache,
+  createBoundedCache,
+  DEFAULT_CACHE_MAX_ENTRIES,
+} from "./lru-cache";
+
+// State file snapshots
+export {
+  saveStateFile,
+  loadStateFile,
+  STATE_FILE_VERSION,
+  type StateFileEnvelope,
+  type StateFileResult,
+} from "./state-file";
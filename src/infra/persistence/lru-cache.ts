@@ -0,0 +1,50 @@
+/**
+ * LruCache - Bounded general-purpose cache built on BaseStore
+ *
+ * Module-level lookup caches (markets, tick sizes, market status) used to be
+ * plain Maps that grew for as long as the bot ran. On long runs watching
+ * thousands of tokens that is unbounded memory growth. LruCache gives them
+ * the same LRU eviction, optional TTL and hit/miss/eviction metrics as the
+ * other stores.
+ *
+ * Caches created with createBoundedCache() are registered with the global
+ * StoreRegistry, so their metrics show up in the periodic cache summary.
+ *
+ * Size is controlled by CACHE_MAX_ENTRIES (per cache, default 5000).
+ */
+
+import { envNum } from "../../config/env";
+import { BaseStore } from "./base-store";
+import { getStoreRegistry } from "./store-registry";
+
+/** Default capacity per cache (CACHE_MAX_ENTRIES) */
+export const DEFAULT_CACHE_MAX_ENTRIES = envNum("CACHE_MAX_ENTRIES", 5000);
+
+// ============================================================================
+// LruCache Implementation
+// ============================================================================
+
+/**
+ * Concrete BaseStore for simple key/value caching
+ */
+export class LruCache<K extends string | number, V> extends BaseStore<K, V> {
+  constructor(name: string, options?: { maxEntries?: number; ttlMs?: number }) {
+    super(name, {
+      maxEntries: options?.maxEntries ?? DEFAULT_CACHE_MAX_ENTRIES,
+      ttlMs: options?.ttlMs ?? 0,
+      trackMetrics: true,
+    });
+  }
+}
+
+/**
+ * Create an LruCache and register it with the global StoreRegistry
+ */
+export function createBoundedCache<V, K extends string | number = string>(
+  name: string,
+  options?: { maxEntries?: number; ttlMs?: number },
+): LruCache<K, V> {
+  const cache = new LruCache<K, V>(name, options);
+  getStoreRegistry().register(cache);
+  return cache;
+}
@@ -0,0 +1,115 @@
+/**
+ * StateFile - Durable JSON snapshots of in-memory bot state
+ *
+ * The in-memory stores are rebuilt from the APIs on every start, but some
+ * state only exists inside the bot (armed take-profit / hedge / stop levels,
+ * tracked GTC orders). This module saves and restores that state so a
+ * restart or deploy does not silently drop it.
+ *
+ * Features:
+ * - Versioned envelope so old/foreign files are rejected instead of misread
+ * - Atomic writes (temp file + rename) so a crash never leaves half a file
+ * - 0600 permissions (the snapshot reveals positions and order IDs)
+ * - Never throws - failures are reported through the result object
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/** Current snapshot format version. Bump when the payload shape changes. */
+export const STATE_FILE_VERSION = 1;
+
+/** Envelope written to disk around every payload */
+export interface StateFileEnvelope<T> {
+  version: number;
+  savedAt: number;
+  payload: T;
+}
+
+/** Result of a save/load operation */
+export interface StateFileResult<T> {
+  ok: boolean;
+  payload?: T;
+  savedAt?: number;
+  error?: string;
+}
+
+// ============================================================================
+// Save / Load
+// ============================================================================
+
+/**
+ * Write a snapshot atomically to `filePath`.
+ */
+export function saveStateFile<T>(
+  filePath: string,
+  payload: T,
+): StateFileResult<T> {
+  const tmpPath = `${filePath}.tmp`;
+  try {
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+
+    const envelope: StateFileEnvelope<T> = {
+      version: STATE_FILE_VERSION,
+      savedAt: Date.now(),
+      payload,
+    };
+
+    // `mode` only applies to a new file - a temp file left by a crash
+    // would keep its permissions and pass them on through the rename
+    fs.rmSync(tmpPath, { force: true });
+    fs.writeFileSync(tmpPath, JSON.stringify(envelope, null, 2), {
+      mode: 0o600,
+    });
+    fs.renameSync(tmpPath, filePath);
+
+    return { ok: true, payload, savedAt: envelope.savedAt };
+  } catch (err) {
+    try {
+      if (fs.existsSync(tmpPath)) fs.unlinkSync(tmpPath);
+    } catch {
+      // Best effort cleanup
+    }
+    return {
+      ok: false,
+      error: err instanceof Error ? err.message : String(err),
+    };
+  }
+}
+
+/**
+ * Read a snapshot from `filePath`.
+ *
+ * A missing file is not an error (first run) - it returns ok with no payload.
+ */
+export function loadStateFile<T>(filePath: string): StateFileResult<T> {
+  try {
+    if (!fs.existsSync(filePath)) {
+      return { ok: true };
+    }
+
+    const raw = fs.readFileSync(filePath, "utf8");
+    const parsed = JSON.parse(raw) as Partial<StateFileEnvelope<T>>;
+
+    if (parsed.version !== STATE_FILE_VERSION) {
+      return {
+        ok: false,
+        error: `Unsupported state file version ${parsed.version} (expected ${STATE_FILE_VERSION})`,
+      };
+    }
+    if (parsed.payload === undefined) {
+      return { ok: false, error: "State file has no payload" };
+    }
+
+    return { ok: true, payload: parsed.payload, savedAt: parsed.savedAt };
+  } catch (err) {
+    return {
+      ok: false,
+      error: err instanceof Error ? err.message : String(err),
+    };
+  }
+}
@@ -33,6 +33,14 @@ export interface AggregatedHealthStatus {
   checkedAt: number;
 }
 
+/** Per-store metrics summary */
+export interface StoreMetricsSummary {
+  entryCount: number;
+  hitRatio: number;
+  /** LRU evictions (0 for stores that don't report them) */
+  evictions: number;
+}
+
 /** Metrics summary across all stores */
 export interface AggregatedMetrics {
   /** Total entries across all stores */
@@ -42,7 +50,7 @@ export interface AggregatedMetrics {
   storeCount: number;
 
   /** Breakdown by store */
-  byStore: Record<string, { entryCount: number; hitRatio: number }>;
+  byStore: Record<string, StoreMetricsSummary>;
 }
 
 // ============================================================================
@@ -191,8 +199,7 @@ export class StoreRegistry {
    */
   getMetrics(): AggregatedMetrics {
     let totalEntries = 0;
-    const byStore: Record<string, { entryCount: number; hitRatio: number }> =
-      {};
+    const byStore: Record<string, StoreMetricsSummary> = {};
 
     for (const [name, store] of this.stores) {
       // Check if store has getMetrics method
@@ -200,13 +207,14 @@ export class StoreRegistry {
         try {
           const metrics = (
             store as unknown as {
-              getMetrics: () => { entryCount: number; hitRatio: number };
+              getMetrics: () => Partial<StoreMetricsSummary>;
             }
           ).getMetrics();
           totalEntries += metrics.entryCount ?? 0;
           byStore[name] = {
             entryCount: metrics.entryCount ?? 0,
             hitRatio: metrics.hitRatio ?? 0,
+            evictions: metrics.evictions ?? 0,
           };
         } catch {
           // Store doesn't support metrics, skip
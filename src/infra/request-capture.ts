@@ -0,0 +1,314 @@
+/**
+ * Request Capture - HAR-style outbound request tracing for support bundles
+ *
+ * Records sanitized metadata for the last N HTTP calls made through axios
+ * (CLOB, Data API, Gamma, RPC) so "0 trades" / "orders failing" reports can
+ * be debugged from a file the user attaches to a GitHub issue, instead of
+ * asking them to reproduce with DEBUG on.
+ *
+ * What is captured: method, URL, status, latency, request headers.
+ * What is NOT captured: request/response bodies, credentials (auth headers,
+ * API keys, signatures, passphrases are redacted), RPC URL paths.
+ *
+ * A support bundle is a gzipped JSON file containing the captured requests,
+ * the redacted config, auth attempt stats, and the tail of the diagnostic
+ * trace.
+ */
+
+import axios, {
+  type AxiosInstance,
+  type AxiosResponse,
+  type InternalAxiosRequestConfig,
+} from "axios";
+import * as fs from "fs";
+import * as path from "path";
+import * as zlib from "zlib";
+import { redactSensitiveData, redactSensitiveInString } from "./error-handling";
+import { getDiagTracePath } from "./github-reporter";
+import { getAuthStatus, type AuthStatus } from "../lib/auth-stats";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface CapturedRequest {
+  timestamp: number;
+  method: string;
+  url: string;
+  /** HTTP status (undefined if no response, e.g. timeout/DNS failure) */
+  status?: number;
+  latencyMs: number;
+  requestHeaders: Record<string, unknown>;
+  /** Error code/message for failed requests */
+  error?: string;
+}
+
+export interface SupportBundle {
+  generatedAt: string;
+  nodeVersion: string;
+  platform: string;
+  uptimeSeconds: number;
+  config: unknown;
+  requests: CapturedRequest[];
+  /** Credential derivation attempts and backoff this session */
+  auth: AuthStatus;
+  diagTraceTail: string[];
+}
+
+// Start time is stashed on the request config by the request interceptor
+type TimedRequestConfig = InternalAxiosRequestConfig & {
+  __captureStartMs?: number;
+};
+
+const DIAG_TRACE_TAIL_LINES = 200;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REQUEST CAPTURE
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class RequestCapture {
+  private entries: CapturedRequest[] = [];
+  private interceptorIds: { request: number; response: number } | null = null;
+  private instance: AxiosInstance | null = null;
+
+  constructor(private readonly maxEntries = 200) {}
+
+  /**
+   * Record a request (oldest entries are dropped past maxEntries)
+   */
+  record(entry: CapturedRequest): void {
+    this.entries.push(entry);
+    if (this.entries.length > this.maxEntries) {
+      this.entries.splice(0, this.entries.length - this.maxEntries);
+    }
+  }
+
+  /**
+   * Get captured requests, oldest first
+   */
+  getEntries(): CapturedRequest[] {
+    return [...this.entries];
+  }
+
+  clear(): void {
+    this.entries = [];
+  }
+
+  /**
+   * Attach interceptors to an axios instance (default: the global one,
+   * which the CLOB client and the API helpers use)
+   */
+  install(instance: AxiosInstance = axios): void {
+    if (this.interceptorIds) return;
+
+    const request = instance.interceptors.request.use((config) => {
+      (config as TimedRequestConfig).__captureStartMs = Date.now();
+      return config;
+    });
+
+    const response = instance.interceptors.response.use(
+      (res: AxiosResponse) => {
+        this.record(buildEntry(res.config, res.status));
+        return res;
+      },
+      (err) => {
+        if (err?.config) {
+          const status: number | undefined = err.response?.status;
+          this.record(
+            buildEntry(err.config, status, err.code ?? err.message ?? "ERROR"),
+          );
+        }
+        return Promise.reject(err);
+      },
+    );
+
+    this.interceptorIds = { request, response };
+    this.instance = instance;
+  }
+
+  /**
+   * Detach interceptors
+   */
+  uninstall(): void {
+    if (!this.interceptorIds || !this.instance) return;
+    this.instance.interceptors.request.eject(this.interceptorIds.request);
+    this.instance.interceptors.response.eject(this.interceptorIds.response);
+    this.interceptorIds = null;
+    this.instance = null;
+  }
+}
+
+function buildEntry(
+  config: TimedRequestConfig,
+  status?: number,
+  error?: string,
+): CapturedRequest {
+  const startMs = config.__captureStartMs ?? Date.now();
+  return {
+    timestamp: startMs,
+    method: (config.method ?? "get").toUpperCase(),
+    url: sanitizeUrl(axios.getUri(config)),
+    status,
+    latencyMs: Date.now() - startMs,
+    requestHeaders: sanitizeHeaders(config.headers),
+    error: error ? redactSensitiveInString(String(error)) : undefined,
+  };
+}
+
+/**
+ * Redact credentials from a URL. RPC URLs (infura/alchemy) carry the API key
+ * in the path, so anything that isn't a Polymarket API keeps only its origin.
+ */
+export function sanitizeUrl(url: string): string {
+  try {
+    const parsed = new URL(url);
+    if (!parsed.hostname.endsWith("polymarket.com")) {
+      return `${parsed.origin}/[REDACTED]`;
+    }
+    for (const key of [...parsed.searchParams.keys()]) {
+      const lower = key.toLowerCase();
+      if (
+        lower.includes("key") ||
+        lower.includes("token") ||
+        lower.includes("secret") ||
+        lower.includes("signature")
+      ) {
+        parsed.searchParams.set(key, "[REDACTED]");
+      }
+    }
+    return parsed.toString();
+  } catch {
+    return redactSensitiveInString(url);
+  }
+}
+
+function sanitizeHeaders(headers: unknown): Record<string, unknown> {
+  if (!headers || typeof headers !== "object") return {};
+  const plain =
+    typeof (headers as { toJSON?: () => unknown }).toJSON === "function"
+      ? (headers as { toJSON: () => unknown }).toJSON()
+      : headers;
+  return redactSensitiveData(plain) as Record<string, unknown>;
+}
+
+/**
+ * Redact a config object for the bundle. On top of the key-based redaction,
+ * any *Url field is reduced to its origin (RPC URLs embed API keys).
+ */
+function sanitizeConfig(config: unknown): unknown {
+  const redacted = redactSensitiveData(config);
+  if (!redacted || typeof redacted !== "object" || Array.isArray(redacted)) {
+    return redacted;
+  }
+  const result: Record<string, unknown> = { ...redacted };
+  for (const [key, val] of Object.entries(result)) {
+    if (key.toLowerCase().endsWith("url") && typeof val === "string") {
+      try {
+        result[key] = new URL(val).origin;
+      } catch {
+        result[key] = "[REDACTED]";
+      }
+    }
+  }
+  return result;
+}
+
+function readDiagTraceTail(maxLines: number): string[] {
+  try {
+    const tracePath = getDiagTracePath();
+    if (!fs.existsSync(tracePath)) return [];
+    const lines = fs
+      .readFileSync(tracePath, "utf8")
+      .split("\n")
+      .filter((line) => line.trim().length > 0);
+    return lines.slice(-maxLines).map(redactSensitiveInString);
+  } catch {
+    return [];
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SUPPORT BUNDLE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Build a support bundle from the current capture and config
+ */
+export function buildSupportBundle(config?: unknown): SupportBundle {
+  return {
+    generatedAt: new Date().toISOString(),
+    nodeVersion: process.version,
+    platform: `${process.platform}-${process.arch}`,
+    uptimeSeconds: Math.round(process.uptime()),
+    config: config === undefined ? null : sanitizeConfig(config),
+    requests: instance?.getEntries() ?? [],
+    auth: sanitizeAuthStatus(getAuthStatus()),
+    diagTraceTail: readDiagTraceTail(DIAG_TRACE_TAIL_LINES),
+  };
+}
+
+function sanitizeAuthStatus(status: AuthStatus): AuthStatus {
+  return {
+    ...status,
+    lastError:
+      status.lastError === undefined
+        ? undefined
+        : redactSensitiveInString(status.lastError),
+    recentFailures: status.recentFailures.map((f) => ({
+      at: f.at,
+      error: redactSensitiveInString(f.error),
+    })),
+  };
+}
+
+/**
+ * Write a gzipped support bundle to `outputDir` and return its path.
+ * Returns null on failure (never throws).
+ */
+export function writeSupportBundle(
+  outputDir: string,
+  config?: unknown,
+): string | null {
+  try {
+    fs.mkdirSync(outputDir, { recursive: true });
+    const stamp = new Date().toISOString().replace(/[:.]/g, "-");
+    const filePath = path.join(outputDir, `support-bundle-${stamp}.json.gz`);
+    const json = JSON.stringify(buildSupportBundle(config), null, 2);
+    fs.writeFileSync(filePath, zlib.gzipSync(json), { mode: 0o600 });
+    return filePath;
+  } catch (err) {
+    console.warn(
+      `Failed to write support bundle: ${err instanceof Error ? err.message : err}`,
+    );
+    return null;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: RequestCapture | null = null;
+
+/**
+ * Initialize global request capture and hook it into axios.
+ * Passing maxEntries <= 0 disables capture.
+ */
+export function initRequestCapture(
+  maxEntries: number,
+): RequestCapture | null {
+  instance?.uninstall();
+  instance = null;
+  if (maxEntries <= 0) return null;
+
+  instance = new RequestCapture(maxEntries);
+  instance.install();
+  return instance;
+}
+
+/**
+ * Get the global request capture instance
+ */
+export function getRequestCapture(): RequestCapture | null {
+  return instance;
+}
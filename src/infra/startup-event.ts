@@ -0,0 +1,137 @@
+/**
+ * Startup Event - One structured STARTUP line with the effective environment
+ *
+ * Wrappers and log scrapers used to piece together the bot's environment from
+ * a dozen emoji info lines. This module builds a single JSON event with
+ * everything needed to tell which wallet, signing mode, endpoints and
+ * subsystems a run used. Secrets never appear: RPC URLs keep only their
+ * origin and proxy URLs drop their credentials.
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface StartupInfo {
+  signerAddress: string;
+  /** Address used for balances/positions (funder in proxy/Safe mode) */
+  effectiveAddress: string;
+  signatureType: string;
+  signatureTypeLabel: string;
+  chainId: number;
+  endpoints: Record<string, string>;
+  /** Subsystem name -> enabled */
+  subsystems: Record<string, boolean>;
+  /** Event bus sink names (EventBus.getSinkNames) */
+  eventSinks?: string[];
+  env?: NodeJS.ProcessEnv;
+}
+
+export interface StartupEvent {
+  event: "STARTUP";
+  version: string;
+  nodeVersion: string;
+  signerAddress: string;
+  signatureType: string;
+  signatureTypeLabel: string;
+  funder: string | null;
+  chainId: number;
+  endpoints: Record<string, string>;
+  proxies: Record<string, string>;
+  vpn: "wireguard" | "openvpn" | null;
+  subsystems: Record<string, boolean>;
+  timestamp: string;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// HELPERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Read the bot version from package.json (works from src/ and dist/)
+ */
+export function readBotVersion(): string {
+  try {
+    const pkgPath = path.join(__dirname, "..", "..", "package.json");
+    const pkg = JSON.parse(fs.readFileSync(pkgPath, "utf8"));
+    return typeof pkg.version === "string" ? pkg.version : "unknown";
+  } catch {
+    return process.env.npm_package_version ?? "unknown";
+  }
+}
+
+/**
+ * Reduce a URL to its origin. Polymarket endpoints keep their path; anything
+ * else (RPC providers put API keys in the path) loses it. Userinfo is always
+ * dropped.
+ */
+export function redactEndpoint(url: string): string {
+  try {
+    const parsed = new URL(url);
+    if (parsed.hostname.endsWith("polymarket.com")) {
+      return `${parsed.origin}${parsed.pathname === "/" ? "" : parsed.pathname}`;
+    }
+    return parsed.origin;
+  } catch {
+    return "[REDACTED]";
+  }
+}
+
+function detectVpn(env: NodeJS.ProcessEnv): StartupEvent["vpn"] {
+  if (env.WIREGUARD_ENABLED === "true" || env.WG_CONFIG) return "wireguard";
+  if (env.OPENVPN_ENABLED === "true" || env.OVPN_CONFIG || env.OPENVPN_CONFIG)
+    return "openvpn";
+  return null;
+}
+
+function detectProxies(env: NodeJS.ProcessEnv): Record<string, string> {
+  const proxies: Record<string, string> = {};
+  for (const key of ["HTTPS_PROXY", "HTTP_PROXY", "ALL_PROXY"]) {
+    const value = env[key] ?? env[key.toLowerCase()];
+    if (value) proxies[key] = redactEndpoint(value);
+  }
+  return proxies;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// STARTUP EVENT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Build the STARTUP event from resolved auth/config info
+ */
+export function buildStartupEvent(info: StartupInfo): StartupEvent {
+  const env = info.env ?? process.env;
+  const isProxyMode =
+    info.signerAddress.toLowerCase() !== info.effectiveAddress.toLowerCase();
+
+  const endpoints: Record<string, string> = {};
+  for (const [name, url] of Object.entries(info.endpoints)) {
+    endpoints[name] = redactEndpoint(url);
+  }
+
+  // Each configured sink by kind ("socket:/tmp/bot.sock" -> events.socket)
+  const subsystems = { ...info.subsystems };
+  for (const sink of info.eventSinks ?? []) {
+    subsystems[`events.${sink.split(":")[0]}`] = true;
+  }
+
+  return {
+    event: "STARTUP",
+    version: readBotVersion(),
+    nodeVersion: process.version,
+    signerAddress: info.signerAddress,
+    signatureType: info.signatureType,
+    signatureTypeLabel: info.signatureTypeLabel,
+    funder: isProxyMode ? info.effectiveAddress : null,
+    chainId: info.chainId,
+    endpoints,
+    proxies: detectProxies(env),
+    vpn: detectVpn(env),
+    subsystems,
+    timestamp: new Date().toISOString(),
+  };
+}
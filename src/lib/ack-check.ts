@@ -0,0 +1,223 @@
+/**
+ * Ack Check - Confirm an accepted order actually shows up on the exchange
+ *
+ * postOrder answering success with an orderID is taken as "the order
+ * exists": the fill journal, position tracking and resting-order
+ * bookkeeping all move on from there. If the exchange never really has
+ * it, the bot's view and the exchange's drift apart silently. With the
+ * check on, every accepted order is looked up afterwards - as an order
+ * (open, matched or cancelled) or as a trade - until it's found or the
+ * timeout passes, and an order that never appears is reported as
+ * ACK_NOT_VISIBLE:
+ *
+ *   ACK_CHECK_ENABLED=true
+ *   ACK_CHECK_TIMEOUT_MS=10000    # give up (and flag) after this
+ *   ACK_CHECK_INTERVAL_MS=1000    # time between lookups
+ *
+ * Lookups run in the background through the client wrapper from
+ * withAckCheck(), so every order path gets them and none waits on them.
+ */
+
+import type { ClobClient } from "@polymarket/clob-client";
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export const ACK_NOT_VISIBLE = "ACK_NOT_VISIBLE";
+
+export interface AckCheckConfig {
+  /** How long an accepted order may stay invisible (ms) */
+  timeoutMs: number;
+  /** Time between lookups (ms) */
+  intervalMs: number;
+}
+
+export const DEFAULT_ACK_CHECK_CONFIG: AckCheckConfig = {
+  timeoutMs: 10_000,
+  intervalMs: 1_000,
+};
+
+export interface AckNotVisibleEvent {
+  event: typeof ACK_NOT_VISIBLE;
+  timestamp: string;
+  orderId: string;
+  tokenId?: string;
+  side?: string;
+  /** Order type it was posted with (FOK, GTC, ...) */
+  orderType?: string;
+  elapsedMs: number;
+  lookups: number;
+}
+
+export interface AckCheckStats {
+  checked: number;
+  visible: number;
+  notVisible: number;
+  pending: number;
+}
+
+/** Whether an order is visible on the exchange (as order or trade) */
+export type AckLookup = (
+  orderId: string,
+  tokenId?: string,
+) => Promise<boolean>;
+
+const SIGNED_SIDES: Record<number, string> = { 0: "BUY", 1: "SELL" };
+
+const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
+
+// ═══════════════════════════════════════════════════════════════════════════
+// LOOKUP
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Look an order up by ID, then among the token's recent trades (a FOK
+ * that filled may only be visible as a trade)
+ */
+export function createClientAckLookup(client: ClobClient): AckLookup {
+  return async (orderId, tokenId) => {
+    const order: any = await client.getOrder(orderId).catch(() => null);
+    if (order?.id) return true;
+    if (!tokenId) return false;
+
+    const trades: any[] = await client
+      .getTrades({ asset_id: tokenId }, true)
+      .catch(() => []);
+    return (trades ?? []).some(
+      (t) =>
+        t?.taker_order_id === orderId ||
+        (t?.maker_orders ?? []).some((m: any) => m?.order_id === orderId),
+    );
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ACK CHECKER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class AckChecker {
+  private readonly config: AckCheckConfig;
+  private stats: AckCheckStats = {
+    checked: 0,
+    visible: 0,
+    notVisible: 0,
+    pending: 0,
+  };
+  private listeners: Array<(event: AckNotVisibleEvent) => void> = [];
+
+  constructor(
+    private readonly lookup: AckLookup,
+    config: Partial<AckCheckConfig> = {},
+  ) {
+    this.config = { ...DEFAULT_ACK_CHECK_CONFIG, ...config };
+  }
+
+  /**
+   * Look the order up until it's visible or the timeout passes
+   * @returns Whether it became visible
+   */
+  async verify(
+    orderId: string,
+    details: { tokenId?: string; side?: string; orderType?: string } = {},
+  ): Promise<boolean> {
+    const started = Date.now();
+    let lookups = 0;
+    this.stats.checked++;
+    this.stats.pending++;
+    try {
+      for (;;) {
+        lookups++;
+        if (await this.lookup(orderId, details.tokenId).catch(() => false)) {
+          this.stats.visible++;
+          return true;
+        }
+        const elapsedMs = Date.now() - started;
+        if (elapsedMs + this.config.intervalMs > this.config.timeoutMs) {
+          this.stats.notVisible++;
+          this.notify({
+            event: ACK_NOT_VISIBLE,
+            timestamp: new Date().toISOString(),
+            orderId,
+            ...details,
+            elapsedMs,
+            lookups,
+          });
+          return false;
+        }
+        await sleep(this.config.intervalMs);
+      }
+    } finally {
+      this.stats.pending--;
+    }
+  }
+
+  getStats(): AckCheckStats {
+    return { ...this.stats };
+  }
+
+  /**
+   * Subscribe to ACK_NOT_VISIBLE
+   * @returns Unsubscribe function
+   */
+  onNotVisible(listener: (event: AckNotVisibleEvent) => void): () => void {
+    this.listeners.push(listener);
+    return () => {
+      this.listeners = this.listeners.filter((l) => l !== listener);
+    };
+  }
+
+  private notify(event: AckNotVisibleEvent): void {
+    emitEvent(event);
+    console.warn(
+      `👻 [${ACK_NOT_VISIBLE}] Order ${event.orderId.slice(0, 12)}... accepted but not visible after ${event.elapsedMs}ms (${event.lookups} lookups)`,
+    );
+    for (const listener of this.listeners) {
+      try {
+        listener(event);
+      } catch {
+        // A failing listener must not break the check
+      }
+    }
+  }
+}
+
+/**
+ * Wrap a client so every accepted order is verified in the background.
+ * Responses are returned unchanged and immediately.
+ */
+export function withAckCheck(
+  client: ClobClient,
+  checker: AckChecker,
+): ClobClient {
+  return new Proxy(client, {
+    get(target, prop, receiver) {
+      if (prop === "postOrder") {
+        return async (order: any, ...postArgs: any[]) => {
+          const response: any = await (target.postOrder as any)(
+            order,
+            ...postArgs,
+          );
+          const orderId: string | undefined =
+            response?.orderID ?? response?.orderId;
+          if (response?.success && orderId) {
+            // Signed orders carry side as 0 (BUY) / 1 (SELL)
+            const side = SIGNED_SIDES[order?.side];
+            checker
+              .verify(orderId, {
+                tokenId: order?.tokenId,
+                side,
+                orderType: postArgs[0] ? String(postArgs[0]) : undefined,
+              })
+              .catch(() => {});
+          }
+          return response;
+        };
+      }
+
+      const value = Reflect.get(target, prop, receiver);
+      return typeof value === "function" ? value.bind(target) : value;
+    },
+  });
+}
@@ -0,0 +1,127 @@
+/**
+ * Address Book - Human-readable labels for wallet and contract addresses
+ *
+ * Logs and events show "whale:TheGoat" or "own:main" instead of raw hex so
+ * an operator can tell at a glance who traded. Labels come from (highest
+ * priority first):
+ *
+ * 1. ADDRESS_LABELS="0xabc...=whale:TheGoat,0xdef...=own:cold"
+ * 2. Runtime registrations - our own wallet/proxy, leaderboard usernames
+ * 3. Built-in Polymarket contracts (CTF, exchanges, USDC)
+ *
+ * Unlabeled addresses fall back to the usual shortened hex.
+ */
+
+import { envStr } from "../config/env";
+import { POLYGON } from "./constants";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BUILT-IN LABELS
+// ═══════════════════════════════════════════════════════════════════════════
+
+const CONTRACT_LABELS: Record<string, string> = {
+  [POLYGON.USDC_ADDRESS]: "contract:USDC",
+  [POLYGON.NATIVE_USDC_ADDRESS]: "contract:NativeUSDC",
+  [POLYGON.CTF_ADDRESS]: "contract:CTF",
+  [POLYGON.CTF_EXCHANGE]: "contract:CTFExchange",
+  [POLYGON.NEG_RISK_CTF_EXCHANGE]: "contract:NegRiskExchange",
+  [POLYGON.NEG_RISK_ADAPTER]: "contract:NegRiskAdapter",
+};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Parse "0xabc=whale:TheGoat,0xdef=own:cold" into lowercase address ->
+ * label. Entries without a 0x address or a label are skipped.
+ */
+export function parseAddressLabels(spec: string): Map<string, string> {
+  const labels = new Map<string, string>();
+  for (const entry of spec.split(",")) {
+    const sep = entry.indexOf("=");
+    if (sep === -1) continue;
+    const address = entry.slice(0, sep).trim().toLowerCase();
+    const label = entry.slice(sep + 1).trim();
+    if (/^0x[0-9a-f]{40}$/.test(address) && label) {
+      labels.set(address, label);
+    }
+  }
+  return labels;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ADDRESS BOOK
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class AddressBook {
+  private readonly configured: Map<string, string>;
+  private registered = new Map<string, string>();
+  private readonly builtIn = new Map<string, string>(
+    Object.entries(CONTRACT_LABELS).map(([a, l]) => [a.toLowerCase(), l]),
+  );
+
+  constructor(configured: Map<string, string> = new Map()) {
+    this.configured = configured;
+  }
+
+  /**
+   * Add a runtime label (configured labels always win)
+   */
+  register(address: string, label: string): void {
+    if (!address || !label) return;
+    this.registered.set(address.toLowerCase(), label);
+  }
+
+  /**
+   * Label for an address, or undefined if it has none
+   */
+  getLabel(address: string): string | undefined {
+    const key = address.toLowerCase();
+    return (
+      this.configured.get(key) ??
+      this.registered.get(key) ??
+      this.builtIn.get(key)
+    );
+  }
+
+  /**
+   * Label, or shortened hex ("0x12345678...") for display
+   */
+  format(address: string, shortLength = 10): string {
+    if (!address) return "unknown";
+    return this.getLabel(address) ?? `${address.slice(0, shortLength)}...`;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: AddressBook | null = null;
+
+/**
+ * Get the global address book (loaded from ADDRESS_LABELS on first use)
+ */
+export function getAddressBook(): AddressBook {
+  if (!instance) {
+    instance = new AddressBook(
+      parseAddressLabels(envStr("ADDRESS_LABELS", "")),
+    );
+  }
+  return instance;
+}
+
+/**
+ * Label or shortened hex for an address (global address book)
+ */
+export function labelAddress(address: string, shortLength = 10): string {
+  return getAddressBook().format(address, shortLength);
+}
+
+/**
+ * Register a runtime label in the global address book
+ */
+export function registerAddressLabel(address: string, label: string): void {
+  getAddressBook().register(address, label);
+}
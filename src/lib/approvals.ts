@@ -0,0 +1,155 @@
+/**
+ * Trading Approvals - Exchange allowances for USDC.e and outcome tokens
+ *
+ * The exchange can only settle a wallet's trades once the wallet has
+ * approved, for each of the CTF Exchange, Neg Risk CTF Exchange and Neg
+ * Risk Adapter:
+ *
+ * - USDC.e spending (ERC20 approve) - needed to BUY
+ * - Outcome token transfers (CTF setApprovalForAll) - needed to SELL
+ *
+ * The website sets these for proxy/Safe wallets on first deposit. An EOA
+ * wallet has to send them itself, once; missing approvals surface later as
+ * INSUFFICIENT_ALLOWANCE rejections that are easy to misread.
+ */
+
+import { Contract, MaxUint256, type Wallet } from "ethers";
+import { ERC20_ABI, POLYGON } from "./constants";
+import type { Logger } from "./types";
+import {
+  getGasBudget,
+  estimateTxCostPol,
+  receiptFeePol,
+  GAS_ESTIMATES,
+  GAS_BUDGET_EXCEEDED,
+} from "./gas-budget";
+
+// CTF ABI (minimal for operator approvals)
+const CTF_APPROVAL_ABI = [
+  "function isApprovedForAll(address owner, address operator) view returns (bool)",
+  "function setApprovalForAll(address operator, bool approved)",
+] as const;
+
+/** Contracts that move USDC.e and outcome tokens when trades settle */
+export const EXCHANGE_SPENDERS = [
+  { name: "CTF Exchange", address: POLYGON.CTF_EXCHANGE },
+  { name: "Neg Risk CTF Exchange", address: POLYGON.NEG_RISK_CTF_EXCHANGE },
+  { name: "Neg Risk Adapter", address: POLYGON.NEG_RISK_ADAPTER },
+] as const;
+
+// Allowances below this ($1M) are treated as missing - the website and
+// setTradingApprovals both approve the max
+const MIN_USDC_ALLOWANCE = 10n ** 12n;
+
+export interface ApprovalStatus {
+  name: string;
+  spender: string;
+  usdcApproved: boolean;
+  ctfApproved: boolean;
+}
+
+export interface SetApprovalsResult {
+  success: boolean;
+  txHashes: string[];
+  error?: string;
+}
+
+/**
+ * True when every exchange contract can move both USDC.e and tokens
+ */
+export function hasAllApprovals(statuses: ApprovalStatus[]): boolean {
+  return (
+    statuses.length > 0 &&
+    statuses.every((s) => s.usdcApproved && s.ctfApproved)
+  );
+}
+
+/**
+ * Read the USDC.e allowance and CTF operator approval for each spender
+ */
+export async function getTradingApprovals(
+  wallet: Wallet,
+  owner: string,
+): Promise<ApprovalStatus[]> {
+  const usdc = new Contract(POLYGON.USDC_ADDRESS, ERC20_ABI, wallet.provider);
+  const ctf = new Contract(
+    POLYGON.CTF_ADDRESS,
+    CTF_APPROVAL_ABI,
+    wallet.provider,
+  );
+
+  return Promise.all(
+    EXCHANGE_SPENDERS.map(async ({ name, address }) => {
+      const [allowance, approved] = await Promise.all([
+        usdc.allowance(owner, address) as Promise<bigint>,
+        ctf.isApprovedForAll(owner, address) as Promise<boolean>,
+      ]);
+      return {
+        name,
+        spender: address,
+        usdcApproved: allowance >= MIN_USDC_ALLOWANCE,
+        ctfApproved: approved,
+      };
+    }),
+  );
+}
+
+/**
+ * Send whichever approvals are missing from the signer's own wallet (EOA
+ * mode only - proxy/Safe wallets approve through the website)
+ */
+export async function setTradingApprovals(
+  wallet: Wallet,
+  logger?: Logger,
+): Promise<SetApprovalsResult> {
+  const txHashes: string[] = [];
+  const owner = await wallet.getAddress();
+  const missing = (await getTradingApprovals(wallet, owner)).flatMap((s) => [
+    ...(s.usdcApproved ? [] : [{ kind: "USDC" as const, status: s }]),
+    ...(s.ctfApproved ? [] : [{ kind: "CTF" as const, status: s }]),
+  ]);
+  if (missing.length === 0) return { success: true, txHashes };
+
+  // Gas budget: reserve every approval before sending anything
+  const gasBudget = getGasBudget();
+  const feeData = await wallet.provider?.getFeeData();
+  const reservation = gasBudget.reserve(
+    "approve",
+    estimateTxCostPol(
+      GAS_ESTIMATES.approve * BigInt(missing.length),
+      feeData?.maxFeePerGas ?? feeData?.gasPrice ?? 0n,
+    ),
+  );
+  if (!reservation) {
+    return { success: false, txHashes, error: GAS_BUDGET_EXCEEDED };
+  }
+
+  const usdc = new Contract(POLYGON.USDC_ADDRESS, ERC20_ABI, wallet);
+  const ctf = new Contract(POLYGON.CTF_ADDRESS, CTF_APPROVAL_ABI, wallet);
+  let spentPol = 0;
+  let txSent = false;
+  try {
+    for (const { kind, status } of missing) {
+      logger?.info?.(`Approving ${kind} for ${status.name}...`);
+      txSent = true;
+      const tx =
+        kind === "USDC"
+          ? await usdc.approve(status.spender, MaxUint256)
+          : await ctf.setApprovalForAll(status.spender, true);
+      const receipt = await tx.wait();
+      spentPol += receiptFeePol(receipt);
+      txHashes.push(receipt.hash);
+    }
+    gasBudget.settle(reservation, spentPol);
+    return { success: true, txHashes };
+  } catch (error) {
+    // Count the worst case once a tx went out (its fee may be unknown)
+    gasBudget.settle(
+      reservation,
+      txSent ? Math.max(spentPol, reservation.reservedPol) : spentPol,
+    );
+    const errorMsg = error instanceof Error ? error.message : String(error);
+    logger?.error?.(`Approval failed: ${errorMsg}`);
+    return { success: false, txHashes, error: errorMsg };
+  }
+}
@@ -0,0 +1,229 @@
+/**
+ * Auth Fallback - Retry auth-rejected orders with an alternate signature type
+ *
+ * A wrong POLYMARKET_SIGNATURE_TYPE (EOA vs Proxy vs Safe) passes key
+ * derivation but gets every order rejected as unauthorized. When enabled,
+ * the alternate combinations are probed once at startup and the first one
+ * that authenticates is cached. An order rejected with AUTH_FAILED is then
+ * re-signed with the cached combination and posted once more, emitting an
+ * AUTH_FALLBACK_USED event:
+ *
+ *   AUTH_FALLBACK_ENABLED=true
+ *
+ * The retry goes through the client wrapper from withAuthFallback(), so
+ * every order path (entries, exits, hedges) gets it. Note that a different
+ * signature type trades from a different wallet (the proxy vs the signer) -
+ * the event says which, and fixing the configuration is the real cure.
+ */
+
+import { ClobClient } from "@polymarket/clob-client";
+import type { Wallet } from "ethers";
+import { POLYMARKET_API, POLYGON } from "./constants";
+import type { Logger } from "./types";
+import { ErrorCode, parseClobError } from "../infra/error-handling";
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** A signature type plus the funder it signs for */
+export interface AuthCombo {
+  /** 0 = EOA, 1 = Proxy, 2 = Safe */
+  signatureType: number;
+  /** Proxy/Safe address (signature types 1 and 2) */
+  funderAddress?: string;
+}
+
+export interface AuthFallbackUsedEvent {
+  event: "AUTH_FALLBACK_USED";
+  fromSignatureType: number;
+  toSignatureType: number;
+  funderAddress?: string;
+  /** The auth error that triggered the retry */
+  errorDetail: string;
+  /** Whether the retried order was accepted */
+  success: boolean;
+  timestamp: string;
+}
+
+/** Builds an authenticated client for a combination (null = rejected) */
+export type AuthComboProbe = (combo: AuthCombo) => Promise<ClobClient | null>;
+
+type OrderFactory = "createOrder" | "createMarketOrder";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// COMBINATIONS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Combinations worth trying instead of the configured one. Proxy types need
+ * a funder address, so with none known only EOA is an alternative.
+ */
+export function alternateAuthCombos(
+  configured: AuthCombo,
+  proxyAddress?: string,
+): AuthCombo[] {
+  const funderAddress = configured.funderAddress ?? proxyAddress;
+  const combos: AuthCombo[] = [{ signatureType: 0 }];
+  if (funderAddress) {
+    combos.push({ signatureType: 1, funderAddress });
+    combos.push({ signatureType: 2, funderAddress });
+  }
+  return combos.filter((c) => c.signatureType !== configured.signatureType);
+}
+
+/**
+ * Derive API keys for a combination and confirm they authenticate
+ */
+export function createAuthComboProbe(wallet: Wallet): AuthComboProbe {
+  return async (combo) => {
+    try {
+      const build = (creds?: any) =>
+        new ClobClient(
+          POLYMARKET_API.CLOB,
+          POLYGON.CHAIN_ID,
+          wallet as any,
+          creds,
+          combo.signatureType,
+          combo.funderAddress,
+        );
+      const creds = await build().createOrDeriveApiKey();
+      if (!creds?.key || !creds?.secret || !creds?.passphrase) return null;
+
+      const client = build(creds);
+      const keys: any = await client.getApiKeys();
+      return keys && !keys.error ? client : null;
+    } catch {
+      return null;
+    }
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// AUTH FALLBACK
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class AuthFallback {
+  private fallback: { combo: AuthCombo; client: ClobClient } | null = null;
+
+  constructor(
+    private readonly configured: AuthCombo,
+    private readonly probeCombo: AuthComboProbe,
+  ) {}
+
+  /**
+   * Try the alternates in order and cache the first that authenticates
+   * @returns The cached combination, or null when none works
+   */
+  async probe(
+    alternates: AuthCombo[],
+    logger?: Logger,
+  ): Promise<AuthCombo | null> {
+    for (const combo of alternates) {
+      const client = await this.probeCombo(combo);
+      if (client) {
+        this.fallback = { combo, client };
+        logger?.info?.(
+          `Auth fallback ready: signatureType=${combo.signatureType}${combo.funderAddress ? ` funder=${combo.funderAddress.slice(0, 10)}...` : ""}`,
+        );
+        return combo;
+      }
+    }
+    logger?.info?.("Auth fallback: no alternate signature type authenticates");
+    return null;
+  }
+
+  getFallback(): { combo: AuthCombo; client: ClobClient } | null {
+    return this.fallback;
+  }
+
+  /**
+   * Re-sign and post an order that was rejected as unauthorized
+   * @returns The retried response, or null when there is no fallback
+   */
+  async retry(
+    factory: OrderFactory,
+    args: unknown[],
+    postArgs: unknown[],
+    errorDetail: string,
+  ): Promise<any | null> {
+    if (!this.fallback) return null;
+    const { combo, client } = this.fallback;
+
+    const signed = await (client[factory] as any)(...args);
+    const response: any = await (client.postOrder as any)(signed, ...postArgs);
+
+    const event: AuthFallbackUsedEvent = {
+      event: "AUTH_FALLBACK_USED",
+      fromSignatureType: this.configured.signatureType,
+      toSignatureType: combo.signatureType,
+      funderAddress: combo.funderAddress,
+      errorDetail,
+      success: response?.success === true,
+      timestamp: new Date().toISOString(),
+    };
+    emitEvent(event);
+    console.warn(
+      `🔑 [AuthFallback] Order rejected (${errorDetail}) - retried with signatureType=${combo.signatureType}: ${event.success ? "accepted" : "rejected"}. Check POLYMARKET_SIGNATURE_TYPE.`,
+    );
+    return response;
+  }
+}
+
+/**
+ * Wrap a client so an order rejected with AUTH_FAILED is retried once
+ * through the fallback. Everything else passes straight through.
+ */
+export function withAuthFallback(
+  client: ClobClient,
+  fallback: AuthFallback,
+): ClobClient {
+  // Signed order -> how it was built, so it can be re-signed
+  const origins = new WeakMap<object, { factory: OrderFactory; args: any[] }>();
+
+  return new Proxy(client, {
+    get(target, prop, receiver) {
+      if (prop === "createOrder" || prop === "createMarketOrder") {
+        return async (...args: any[]) => {
+          const signed = await (target[prop] as any)(...args);
+          if (signed && typeof signed === "object") {
+            origins.set(signed, { factory: prop, args });
+          }
+          return signed;
+        };
+      }
+
+      if (prop === "postOrder") {
+        return async (order: any, ...postArgs: any[]) => {
+          let response: any;
+          let thrown: unknown;
+          try {
+            response = await (target.postOrder as any)(order, ...postArgs);
+          } catch (err) {
+            thrown = err;
+          }
+
+          const failure = thrown ?? (response?.success ? null : response);
+          const origin = failure ? origins.get(order) : undefined;
+          const error = failure ? parseClobError(failure) : null;
+          if (origin && error?.errorCode === ErrorCode.AUTH_FAILED) {
+            const retried = await fallback.retry(
+              origin.factory,
+              origin.args,
+              postArgs,
+              error.errorDetail,
+            );
+            if (retried !== null) return retried;
+          }
+
+          if (thrown !== undefined) throw thrown;
+          return response;
+        };
+      }
+
+      const value = Reflect.get(target, prop, receiver);
+      return typeof value === "function" ? value.bind(target) : value;
+    },
+  });
+}
@@ -0,0 +1,227 @@
+/**
+ * Auth Stats - Session counters and backoff for credential derivation
+ *
+ * Every createClobClient() call that reaches the CLOB (API key derivation)
+ * is counted here. Consecutive failures open an exponential backoff:
+ * while it runs, createClobClient() fails fast with AUTH_BACKOFF_ACTIVE
+ * instead of hitting the derivation endpoint again - repeated 401s from
+ * that endpoint only lengthen a lockout.
+ *
+ *   30s, 60s, 120s, ... capped at 15 min; reset by the next success
+ *
+ * getAuthStatus() reports attempts, failures and the current backoff; it
+ * is also included in support bundles.
+ *
+ * The backoff and recent failures are persisted to AUTH_STATE_FILE_PATH
+ * (default ./data/auth-state.json, empty disables), so a supervisor that
+ * restarts a crash-looping bot doesn't reset the backoff every time:
+ *
+ *   AUTH_STATE_FILE_PATH=./data/auth-state.json
+ */
+
+import { envStr } from "../config/env";
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface AuthFailureRecord {
+  at: number;
+  error: string;
+}
+
+export interface AuthStatus {
+  attempts: number;
+  successes: number;
+  failures: number;
+  /** Failures since the last success (drives the backoff) */
+  consecutiveFailures: number;
+  lastError?: string;
+  lastAttemptAt?: number;
+  lastSuccessAt?: number;
+  lastFailureAt?: number;
+  /** Length of the current backoff window (0 = none) */
+  backoffMs: number;
+  /** Attempts are refused until this time (0 = none) */
+  backoffUntil: number;
+  /** Most recent failures, oldest first (kept across restarts) */
+  recentFailures: AuthFailureRecord[];
+}
+
+/** The part of the status that survives a restart */
+export type AuthStatsState = Pick<
+  AuthStatus,
+  | "consecutiveFailures"
+  | "lastError"
+  | "lastFailureAt"
+  | "backoffMs"
+  | "backoffUntil"
+  | "recentFailures"
+>;
+
+export const AUTH_BACKOFF_ACTIVE = "AUTH_BACKOFF_ACTIVE";
+
+export const AUTH_BACKOFF_BASE_MS = 30_000;
+export const AUTH_BACKOFF_MAX_MS = 15 * 60 * 1000;
+
+const MAX_RECENT_FAILURES = 20;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// AUTH STATS
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class AuthStats {
+  private status: AuthStatus = {
+    attempts: 0,
+    successes: 0,
+    failures: 0,
+    consecutiveFailures: 0,
+    backoffMs: 0,
+    backoffUntil: 0,
+    recentFailures: [],
+  };
+  private filePath: string | null = null;
+
+  constructor(
+    private readonly baseMs = AUTH_BACKOFF_BASE_MS,
+    private readonly maxMs = AUTH_BACKOFF_MAX_MS,
+  ) {}
+
+  /**
+   * Milliseconds left in the current backoff (0 = an attempt may proceed)
+   */
+  remainingBackoffMs(now = Date.now()): number {
+    return Math.max(0, this.status.backoffUntil - now);
+  }
+
+  recordAttempt(now = Date.now()): void {
+    this.status.attempts++;
+    this.status.lastAttemptAt = now;
+  }
+
+  recordSuccess(now = Date.now()): void {
+    this.status.successes++;
+    this.status.consecutiveFailures = 0;
+    this.status.lastSuccessAt = now;
+    this.status.backoffMs = 0;
+    this.status.backoffUntil = 0;
+    this.save();
+  }
+
+  /**
+   * Count a failure and extend the backoff
+   * @returns The new backoff length in ms
+   */
+  recordFailure(error: string, now = Date.now()): number {
+    this.status.failures++;
+    this.status.consecutiveFailures++;
+    this.status.lastError = error;
+    this.status.lastFailureAt = now;
+    this.status.recentFailures = [
+      ...this.status.recentFailures,
+      { at: now, error },
+    ].slice(-MAX_RECENT_FAILURES);
+
+    const exponent = Math.min(this.status.consecutiveFailures - 1, 30);
+    this.status.backoffMs = Math.min(this.maxMs, this.baseMs * 2 ** exponent);
+    this.status.backoffUntil = now + this.status.backoffMs;
+    this.save();
+    return this.status.backoffMs;
+  }
+
+  getStatus(): AuthStatus {
+    return { ...this.status, recentFailures: [...this.status.recentFailures] };
+  }
+
+  exportState(): AuthStatsState {
+    const { consecutiveFailures, lastError, lastFailureAt } = this.status;
+    return {
+      consecutiveFailures,
+      lastError,
+      lastFailureAt,
+      backoffMs: this.status.backoffMs,
+      backoffUntil: this.status.backoffUntil,
+      recentFailures: [...this.status.recentFailures],
+    };
+  }
+
+  /**
+   * Restore a saved backoff. A deadline further out than the cap (clock
+   * change) is pulled in to now + cap.
+   */
+  importState(saved: AuthStatsState, now = Date.now()): void {
+    this.status.consecutiveFailures = Math.max(0, saved.consecutiveFailures);
+    this.status.lastError = saved.lastError;
+    this.status.lastFailureAt = saved.lastFailureAt;
+    this.status.backoffMs = Math.min(this.maxMs, saved.backoffMs);
+    this.status.backoffUntil = Math.min(saved.backoffUntil, now + this.maxMs);
+    this.status.recentFailures = (saved.recentFailures ?? []).slice(
+      -MAX_RECENT_FAILURES,
+    );
+  }
+
+  /**
+   * Restore from `filePath` and save there after every attempt from now on
+   */
+  attachStateFile(filePath: string, now = Date.now()): void {
+    this.filePath = filePath;
+    const result = loadStateFile<AuthStatsState>(filePath);
+    if (!result.ok) {
+      console.warn(`⚠️ [Auth] Failed to load ${filePath}: ${result.error}`);
+      return;
+    }
+    if (!result.payload) return;
+
+    this.importState(result.payload, now);
+    const remainingMs = this.remainingBackoffMs(now);
+    if (remainingMs > 0) {
+      console.warn(
+        `⚠️ [Auth] Backoff restored: ${Math.ceil(remainingMs / 1000)}s left after ${this.status.consecutiveFailures} consecutive failure(s)`,
+      );
+    }
+  }
+
+  private save(): void {
+    if (!this.filePath) return;
+    const result = saveStateFile(this.filePath, this.exportState());
+    if (!result.ok) {
+      console.warn(
+        `⚠️ [Auth] Failed to save ${this.filePath}: ${result.error}`,
+      );
+    }
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: AuthStats | null = null;
+
+/**
+ * Get the global auth stats (created on first use, restored from
+ * AUTH_STATE_FILE_PATH)
+ */
+export function getAuthStats(): AuthStats {
+  if (!instance) {
+    instance = new AuthStats();
+    const filePath = envStr("AUTH_STATE_FILE_PATH", "./data/auth-state.json");
+    if (filePath) instance.attachStateFile(filePath);
+  }
+  return instance;
+}
+
+/**
+ * Replace the global auth stats (tests)
+ */
+export function setAuthStats(stats: AuthStats): void {
+  instance = stats;
+}
+
+/**
+ * Attempts, failures and backoff of credential derivation this session
+ */
+export function getAuthStatus(): AuthStatus {
+  return getAuthStats().getStatus();
+}
@@ -3,12 +3,31 @@
  *
  * DEFAULTS TO EOA MODE (signatureType=0) - uses wallet address directly.
  * Set POLYMARKET_SIGNATURE_TYPE and POLYMARKET_PROXY_ADDRESS for proxy/Safe mode.
+ * Set EXPECTED_ADDRESS to refuse a key that doesn't sign as that address.
+ * Set API_CREDS_CACHE_PATH (or --creds-cache) to reuse derived credentials
+ * across runs, or IMPORT_API_CREDS=true with POLY_API_KEY/SECRET/PASSPHRASE
+ * to use existing API keys.
  */
 
-import { JsonRpcProvider, Wallet } from "ethers";
-import { ClobClient } from "@polymarket/clob-client";
+import { getAddress, isAddress, JsonRpcProvider, Wallet } from "ethers";
+import { ClobClient, type ApiKeyCreds } from "@polymarket/clob-client";
 import { POLYMARKET_API, POLYGON } from "./constants";
 import { applyEthersV6Shim } from "./ethers-compat";
+import { AUTH_BACKOFF_ACTIVE, getAuthStats } from "./auth-stats";
+import {
+  checkFunderAddress,
+  FUNDER_MISMATCH,
+  type FunderDiagnostic,
+} from "./funder-check";
+import {
+  loadCachedCreds,
+  readImportedCreds,
+  resolveCredsCachePath,
+  saveCachedCreds,
+  sessionIdentity,
+  verifyCreds,
+  withSessionRefresh,
+} from "./clob-session";
 import type { Logger } from "./types";
 
 export interface AuthResult {
@@ -17,9 +36,18 @@ export interface AuthResult {
   wallet?: Wallet;
   address?: string;
   effectiveAddress?: string;
+  /** Signature type the client signs with (0 = EOA, 1 = Proxy, 2 = Safe) */
+  signatureType?: number;
+  /** Funder (proxy/Safe) address, when signing in proxy mode */
+  funderAddress?: string;
+  /** Set when the funder isn't the signer's proxy/Safe wallet */
+  funderDiagnostic?: FunderDiagnostic;
   error?: string;
 }
 
+export const SIGNER_MISMATCH = "SIGNER_MISMATCH";
+export const CREDS_REFUSED = "CREDS_REFUSED";
+
 // Private key with 0x prefix should be exactly 66 chars (2 for '0x' + 64 hex chars)
 const PRIVATE_KEY_LENGTH_WITH_PREFIX = 66;
 // Regex to validate hex private key format
@@ -40,6 +68,14 @@ export async function createClobClient(
   rpcUrl: string,
   logger?: Logger,
 ): Promise<AuthResult> {
+  // Only failures that reached the derivation endpoint count toward backoff
+  let derivationAttempted = false;
+  // A funder that isn't the signer's wallet explains a 401 - say so
+  let funderDiagnostic: FunderDiagnostic | null = null;
+  const withDiagnostic = (error: string) =>
+    funderDiagnostic
+      ? `${error} (${FUNDER_MISMATCH}: ${funderDiagnostic.message})`
+      : error;
   try {
     // Normalize private key
     const normalizedKey = privateKey?.startsWith("0x")
@@ -58,12 +94,40 @@ export async function createClobClient(
       return { success: false, error: "RPC_URL is required" };
     }
 
+    const { creds: imported, missing } = readImportedCreds();
+    if (missing.length > 0) {
+      return {
+        success: false,
+        error: `${missing.join(", ")} not set - POLY_API_KEY, POLY_API_SECRET and POLY_PASSPHRASE go together`,
+      };
+    }
+
+    // Don't hit the derivation endpoint again while failures back off
+    // (imported keys never touch it)
+    const backoffMs = imported ? 0 : getAuthStats().remainingBackoffMs();
+    if (backoffMs > 0) {
+      return {
+        success: false,
+        error: `${AUTH_BACKOFF_ACTIVE}: retry in ${Math.ceil(backoffMs / 1000)}s`,
+      };
+    }
+
     const provider = new JsonRpcProvider(rpcUrl);
     const rawWallet = new Wallet(normalizedKey, provider);
     // Apply ethers v6 → v5 compatibility shim for @polymarket/clob-client
     const wallet = applyEthersV6Shim(rawWallet);
     const address = wallet.address;
 
+    // A wrong or rotated key fails here, before anything is signed
+    const signerError = checkExpectedSigner(
+      address,
+      process.env.EXPECTED_ADDRESS,
+    );
+    if (signerError) {
+      logger?.error?.(signerError);
+      return { success: false, error: signerError };
+    }
+
     // Read signature type from env - default to 0 (EOA)
     // Handle NaN by falling back to 0
     const signatureTypeStr =
@@ -97,33 +161,114 @@ export async function createClobClient(
       );
     }
 
+    // Warn if the funder isn't the signer's proxy/Safe wallet
+    funderDiagnostic = checkFunderAddress(
+      address,
+      funderAddress,
+      effectiveSignatureType,
+    );
+    if (funderDiagnostic) {
+      logger?.warn?.(`${FUNDER_MISMATCH}: ${funderDiagnostic.message}`);
+    }
+
     // Derive credentials first
+    const funder = effectiveSignatureType > 0 ? funderAddress : undefined;
     const tempClient = new ClobClient(
       POLYMARKET_API.CLOB,
       POLYGON.CHAIN_ID,
       wallet as any,
       undefined, // No creds yet
       effectiveSignatureType,
-      effectiveSignatureType > 0 ? funderAddress : undefined,
+      funder,
     );
 
-    // Derive API credentials
-    const creds = await tempClient.createOrDeriveApiKey();
+    const cachePath = resolveCredsCachePath();
+    const identity = sessionIdentity(address, effectiveSignatureType, funder);
+
+    // Derive API credentials (null = the endpoint returned nothing usable)
+    const deriveCreds = async (): Promise<ApiKeyCreds | null> => {
+      getAuthStats().recordAttempt();
+      derivationAttempted = true;
+      const derived = await tempClient.createOrDeriveApiKey();
+      if (!derived?.key || !derived?.secret || !derived?.passphrase) {
+        recordAuthFailure(
+          withDiagnostic("Failed to derive API credentials"),
+          logger,
+        );
+        return null;
+      }
+      getAuthStats().recordSuccess();
+      if (cachePath) saveCachedCreds(cachePath, identity, derived);
+      return derived;
+    };
 
-    if (!creds?.key || !creds?.secret || !creds?.passphrase) {
-      return { success: false, error: "Failed to derive API credentials" };
+    const buildClient = (apiCreds: ApiKeyCreds) =>
+      new ClobClient(
+        POLYMARKET_API.CLOB,
+        POLYGON.CHAIN_ID,
+        wallet as any,
+        apiCreds,
+        effectiveSignatureType,
+        funder,
+      );
+
+    // Keys generated elsewhere replace derivation - if the exchange takes
+    // them (no fallback: deriving would quietly trade on other keys)
+    if (imported) {
+      if (!(await verifyCreds(buildClient(imported)))) {
+        const error = withDiagnostic(
+          `${CREDS_REFUSED}: the exchange refused POLY_API_KEY ` +
+            `...${imported.key.slice(-6)} for this signer/funder`,
+        );
+        logger?.error?.(error);
+        return { success: false, error };
+      }
+      if (cachePath) saveCachedCreds(cachePath, identity, imported);
     }
 
-    logger?.info?.(`Credentials obtained: key=...${creds.key.slice(-6)}`);
+    // A previous run's credentials skip derivation - if still accepted
+    let cachedCreds =
+      !imported && cachePath ? loadCachedCreds(cachePath, identity) : null;
+    if (cachedCreds && !(await verifyCreds(buildClient(cachedCreds)))) {
+      logger?.warn?.("Cached API credentials refused - deriving new ones");
+      cachedCreds = null;
+    }
+    const creds = imported ?? cachedCreds ?? (await deriveCreds());
+    if (!creds) {
+      return {
+        success: false,
+        error: withDiagnostic("Failed to derive API credentials"),
+      };
+    }
 
-    // Create client WITH credentials
-    const client = new ClobClient(
-      POLYMARKET_API.CLOB,
-      POLYGON.CHAIN_ID,
-      wallet as any,
-      creds, // Pass the derived credentials
-      effectiveSignatureType,
-      effectiveSignatureType > 0 ? funderAddress : undefined,
+    const source = imported
+      ? "Using imported credentials"
+      : cachedCreds
+        ? "Reusing cached credentials"
+        : "Credentials obtained";
+    logger?.info?.(`${source}: key=...${creds.key.slice(-6)}`);
+
+    // Create client WITH credentials. If the exchange drops the session
+    // mid-run, a 401 re-derives (imported keys: re-checks) and retries
+    const credsClient = buildClient(creds);
+    const client = withSessionRefresh(
+      credsClient,
+      async () => {
+        if (imported) {
+          const accepted = await verifyCreds(buildClient(imported));
+          return accepted ? { ...imported } : null;
+        }
+        // Leave the derivation endpoint alone while failures back off
+        if (getAuthStats().remainingBackoffMs() > 0) return null;
+        try {
+          return await deriveCreds();
+        } catch (err) {
+          const msg = err instanceof Error ? err.message : String(err);
+          recordAuthFailure(withDiagnostic(msg), logger);
+          return null;
+        }
+      },
+      logger,
     );
 
     logger?.info?.("Authentication successful");
@@ -137,14 +282,55 @@ export async function createClobClient(
       wallet,
       address: normalizedEffectiveAddress,
       effectiveAddress: normalizedEffectiveAddress,
+      signatureType: effectiveSignatureType,
+      funderAddress: funder,
+      funderDiagnostic: funderDiagnostic ?? undefined,
     };
   } catch (err) {
-    const msg = err instanceof Error ? err.message : String(err);
+    const msg = withDiagnostic(
+      err instanceof Error ? err.message : String(err),
+    );
     logger?.error?.(`Auth failed: ${msg}`);
+    if (derivationAttempted) recordAuthFailure(msg, logger);
     return { success: false, error: msg };
   }
 }
 
+/**
+ * Check the signer derived from PRIVATE_KEY against EXPECTED_ADDRESS
+ *
+ * @returns A SIGNER_MISMATCH error, or null if they match (or no address
+ *   is expected)
+ */
+export function checkExpectedSigner(
+  signerAddress: string,
+  expectedAddress: string | undefined,
+): string | null {
+  const expected = expectedAddress?.trim();
+  if (!expected) return null;
+  if (!isAddress(expected)) {
+    return `${SIGNER_MISMATCH}: EXPECTED_ADDRESS=${expected} is not an address`;
+  }
+  if (expected.toLowerCase() === signerAddress.toLowerCase()) return null;
+  return (
+    `${SIGNER_MISMATCH}: PRIVATE_KEY signs as ${getAddress(signerAddress)} ` +
+    `but EXPECTED_ADDRESS is ${getAddress(expected)} - wrong or rotated ` +
+    `key, refusing to trade`
+  );
+}
+
+/**
+ * Count a failed derivation and log the backoff it opened
+ */
+function recordAuthFailure(error: string, logger?: Logger): void {
+  const stats = getAuthStats();
+  const backoffMs = stats.recordFailure(error);
+  const { consecutiveFailures } = stats.getStatus();
+  logger?.warn?.(
+    `Auth backoff increased to ${Math.round(backoffMs / 1000)}s after ${consecutiveFailures} consecutive failure(s)`,
+  );
+}
+
 /**
  * Check if live trading is enabled
  */
@@ -9,7 +9,8 @@
  */
 
 import { Contract, type Wallet } from "ethers";
-import { POLYGON, ERC20_ABI } from "./constants";
+import { AssetType, type ClobClient } from "@polymarket/clob-client";
+import { POLYGON, ERC20_ABI, CTF_ABI } from "./constants";
 
 // ═══════════════════════════════════════════════════════════════════════════
 // RAW BALANCE FETCHES (direct RPC calls)
@@ -121,6 +122,115 @@ export async function getUsdcAllowance(
   }
 }
 
+/** Outcome tokens use the same 6 decimals as USDC */
+const SHARE_DECIMALS = 6;
+
+/**
+ * Get the outcome-token (CTF) balance of one token in shares (direct RPC call)
+ * Returns result object with success flag to distinguish 0 balance from errors
+ */
+export async function getTokenBalanceWithStatus(
+  wallet: Wallet,
+  address: string,
+  tokenId: string,
+): Promise<BalanceFetchResult> {
+  try {
+    const contract = new Contract(
+      POLYGON.CTF_ADDRESS,
+      CTF_ABI,
+      wallet.provider,
+    );
+    const balance = await contract.balanceOf(address, tokenId);
+    return { value: Number(balance) / 10 ** SHARE_DECIMALS, success: true };
+  } catch (err) {
+    return {
+      value: 0,
+      success: false,
+      error: err instanceof Error ? err.message : "Unknown error",
+    };
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CLOB BALANCE / ALLOWANCE - The exchange's view of what can be traded
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * COLLATERAL = USDC.e (what a BUY spends)
+ * CONDITIONAL = shares of one token_id (what a SELL spends)
+ */
+export type BalanceAssetType = "COLLATERAL" | "CONDITIONAL";
+
+export interface ClobBalanceAllowance {
+  assetType: BalanceAssetType;
+  /** Set for CONDITIONAL */
+  tokenId?: string;
+  /** USDC (COLLATERAL) or shares (CONDITIONAL) */
+  balance: number;
+  /**
+   * Smallest allowance across the exchange contracts - a conditional
+   * token's is either 0 or unlimited (setApprovalForAll)
+   */
+  allowance: number;
+}
+
+/**
+ * Convert a CLOB balance-allowance response (base units) to USDC / shares.
+ * Older servers return a single `allowance`, newer ones an `allowances`
+ * map keyed by exchange contract.
+ */
+export function parseClobBalanceAllowance(
+  response: any,
+  assetType: BalanceAssetType,
+  tokenId?: string,
+): ClobBalanceAllowance {
+  const units = (value: unknown) =>
+    (parseFloat(String(value ?? "0")) || 0) / 10 ** SHARE_DECIMALS;
+
+  const perSpender = Object.values(response?.allowances ?? {});
+  const allowance =
+    response?.allowance !== undefined
+      ? units(response.allowance)
+      : perSpender.length > 0
+        ? Math.min(...perSpender.map(units))
+        : 0;
+
+  return {
+    assetType,
+    tokenId: assetType === "CONDITIONAL" ? tokenId : undefined,
+    balance: units(response?.balance),
+    allowance,
+  };
+}
+
+/**
+ * Ask the CLOB what it will let the funder trade - USDC.e by default, or
+ * the shares of one token_id (the exact token about to be sold).
+ * Throws when the CLOB returns an error.
+ */
+export async function getClobBalanceAllowance(
+  client: ClobClient,
+  assetType: BalanceAssetType = "COLLATERAL",
+  tokenId?: string,
+): Promise<ClobBalanceAllowance> {
+  if (assetType === "CONDITIONAL" && !tokenId) {
+    throw new Error("token_id is required for CONDITIONAL balances");
+  }
+  const conditional = assetType === "CONDITIONAL";
+  const response: any = await client.getBalanceAllowance({
+    asset_type: conditional ? AssetType.CONDITIONAL : AssetType.COLLATERAL,
+    token_id: conditional ? tokenId : undefined,
+  });
+  if (response?.error) {
+    throw new Error(
+      typeof response.error === "string"
+        ? response.error
+        : JSON.stringify(response.error),
+    );
+  }
+  return parseClobBalanceAllowance(response, assetType, tokenId);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // BALANCE CACHE - Throttled balance fetching to reduce RPC spam
 // ═══════════════════════════════════════════════════════════════════════════
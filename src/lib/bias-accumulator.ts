@@ -8,6 +8,7 @@
 import axios from "axios";
 import { type BiasDirection } from "../core";
 import { reportError } from "../infra/github-reporter";
+import { labelAddress, registerAddressLabel } from "./address-book";
 
 // ═══════════════════════════════════════════════════════════════════════════
 // DEBUG LOGGING - Uses DEBUG env var from environment
@@ -77,6 +78,8 @@ export interface BiasAccumulatorConfig {
 export class BiasAccumulator {
   private trades: Map<string, LeaderboardTrade[]> = new Map();
   private leaderboardWallets: Set<string> = new Set();
+  // Wallets kept in the copy list across leaderboard refreshes
+  private pinnedWallets: Set<string> = new Set();
   private lastLeaderboardFetch = 0;
   private readonly config: BiasAccumulatorConfig;
   private biasChangeCallbacks: ((event: BiasChangeEvent) => void)[] = [];
@@ -244,6 +247,9 @@ export class BiasAccumulator {
 
       if (allEntries.length > 0) {
         this.leaderboardWallets.clear();
+        for (const wallet of this.pinnedWallets) {
+          this.leaderboardWallets.add(wallet);
+        }
 
         // Show top 10 at startup to verify it's working, sorted by last traded
         const isFirstFetch = this.lastLeaderboardFetch === 0;
@@ -267,7 +273,7 @@ export class BiasAccumulator {
               // Activity fetch failed - trader may have no activity or API issue
               // Continue gracefully - they'll show with N/A timestamp
               console.debug?.(
-                `   Activity fetch for ${wallet.slice(0, 10)}... failed: ${err instanceof Error ? err.message : "Unknown"}`,
+                `   Activity fetch for ${labelAddress(wallet)} failed: ${err instanceof Error ? err.message : "Unknown"}`,
               );
               return { ...entry, lastTraded: 0 };
             }
@@ -308,6 +314,9 @@ export class BiasAccumulator {
           const wallet = entry.proxyWallet || entry.address;
           if (wallet) {
             this.leaderboardWallets.add(wallet.toLowerCase());
+            if (entry.userName) {
+              registerAddressLabel(wallet, `whale:${entry.userName}`);
+            }
           }
         }
         this.lastLeaderboardFetch = now;
@@ -447,7 +456,7 @@ export class BiasAccumulator {
           // Reject if tokenId is empty or invalid
           if (!tokenId || tokenId.trim() === "") {
             debug(
-              `[Whale Trade] Rejected: empty tokenId | conditionId: ${conditionId || "N/A"} | outcome: ${outcome || "N/A"} | wallet: ${(wallet ?? "unknown").slice(0, 10)}...`,
+              `[Whale Trade] Rejected: empty tokenId | conditionId: ${conditionId || "N/A"} | outcome: ${outcome || "N/A"} | wallet: ${labelAddress(wallet ?? "")}`,
             );
             continue;
           }
@@ -484,10 +493,10 @@ export class BiasAccumulator {
         // Only log to console if it's not a timeout/network issue (those are noisy)
         if (!errMsg.includes("timeout") && !errMsg.includes("ECONNRESET")) {
           console.warn(
-            `[API] Fetch failed for ${wallet.slice(0, 10)}...: ${errMsg}`,
+            `[API] Fetch failed for ${labelAddress(wallet)}: ${errMsg}`,
           );
         } else {
-          debug(`[API] Fetch failed for ${wallet.slice(0, 10)}...: ${errMsg}`);
+          debug(`[API] Fetch failed for ${labelAddress(wallet)}: ${errMsg}`);
         }
         return [];
       }
@@ -516,7 +525,7 @@ export class BiasAccumulator {
     if (DEBUG && newTrades.length > 0) {
       for (const trade of newTrades.slice(0, 5)) {
         debug(
-          `  Trade: ${trade.tokenId.slice(0, 12)}... | $${trade.sizeUsd.toFixed(0)} | wallet: ${trade.wallet.slice(0, 10)}...`,
+          `  Trade: ${trade.tokenId.slice(0, 12)}... | $${trade.sizeUsd.toFixed(0)} | wallet: ${labelAddress(trade.wallet)}`,
         );
       }
       if (newTrades.length > 5) {
@@ -787,6 +796,48 @@ export class BiasAccumulator {
     }
   }
 
+  /**
+   * Wallets whose buys make up the current bias window for a token
+   * (the whales an entry on this token is following)
+   */
+  getRecentWallets(tokenId: string): string[] {
+    const windowStart = Date.now() - this.config.biasWindowSeconds * 1000;
+    const wallets = new Set<string>();
+    for (const trade of this.trades.get(tokenId) || []) {
+      if (trade.timestamp >= windowStart) {
+        wallets.add(trade.wallet.toLowerCase());
+      }
+    }
+    return Array.from(wallets);
+  }
+
+  /**
+   * Most recent whale buy on a token within the bias window (the fill an
+   * entry would copy)
+   */
+  getLatestTrade(tokenId: string): LeaderboardTrade | undefined {
+    const windowStart = Date.now() - this.config.biasWindowSeconds * 1000;
+    let latest: LeaderboardTrade | undefined;
+    for (const trade of this.trades.get(tokenId) || []) {
+      if (trade.timestamp < windowStart) continue;
+      if (!latest || trade.timestamp > latest.timestamp) latest = trade;
+    }
+    return latest;
+  }
+
+  /**
+   * Keep a wallet in the copy list even when it drops off the leaderboard
+   */
+  pinWallet(wallet: string): void {
+    const key = wallet.toLowerCase();
+    this.pinnedWallets.add(key);
+    this.leaderboardWallets.add(key);
+  }
+
+  getPinnedWallets(): string[] {
+    return Array.from(this.pinnedWallets);
+  }
+
   /**
    * Add wallet to leaderboard manually (for testing)
    */
@@ -0,0 +1,292 @@
+/**
+ * Bootstrap - Guided first-run check that a wallet can trade
+ *
+ * Onboarding has several steps that each fail in their own confusing way
+ * (funds in native USDC, no POL for gas, missing exchange approvals, API
+ * keys that were never activated). The bootstrap runs them in order and
+ * ends with a single verdict:
+ *
+ * 1. CREDENTIALS - derive the CLOB API key (re-derived identically on every
+ *    start, so there is nothing to store); a funder that isn't the
+ *    signer's proxy/Safe wallet is flagged (FUNDER_MISMATCH)
+ * 2. BALANCES    - USDC.e to trade with, POL for gas
+ * 3. APPROVALS   - exchange allowances; sent automatically in EOA mode
+ * 4. TEST_ORDER  - the smallest valid BUY far below the mid (it rests
+ *    instead of filling), canceled right away - see test-trade.ts
+ *
+ *   npm run bootstrap
+ *   npm run bootstrap -- --no-approve --keep-test-order --token <id>
+ *
+ * READY means every step passed; NOT_READY lists what to fix.
+ */
+
+import type { ClobClient } from "@polymarket/clob-client";
+import type { Wallet } from "ethers";
+import { createClobClient, getAuthDiagnostics } from "./auth";
+import { getPolBalance } from "./balance";
+import { getUsdcHoldings, checkUsdcHoldings } from "./usdc-bridge";
+import {
+  getTradingApprovals,
+  hasAllApprovals,
+  setTradingApprovals,
+} from "./approvals";
+import { placeTestTrade } from "./test-trade";
+import type { Logger } from "./types";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type BootstrapStepName =
+  | "CREDENTIALS"
+  | "BALANCES"
+  | "APPROVALS"
+  | "TEST_ORDER";
+
+/**
+ * - OK:      already in order
+ * - FIXED:   was missing and the bootstrap fixed it
+ * - WARN:    works, but worth a look
+ * - FAIL:    blocks trading
+ * - SKIPPED: not run (an earlier step failed, or disabled)
+ */
+export type BootstrapStepStatus = "OK" | "FIXED" | "WARN" | "FAIL" | "SKIPPED";
+
+export interface BootstrapStep {
+  step: BootstrapStepName;
+  status: BootstrapStepStatus;
+  detail: string;
+}
+
+export type BootstrapVerdict = "READY" | "NOT_READY";
+
+export interface BootstrapReport {
+  steps: BootstrapStep[];
+  verdict: BootstrapVerdict;
+}
+
+export interface BootstrapOptions {
+  privateKey: string;
+  rpcUrl: string;
+  /** Send missing approvals (EOA mode) (default true) */
+  setApprovals?: boolean;
+  /** Place and cancel a test order (default true) */
+  testOrder?: boolean;
+  /** Leave the test order resting instead of canceling it */
+  keepTestOrder?: boolean;
+  /** Token for the test order (default: first active sampling market) */
+  testTokenId?: string;
+  /** POL below this can't pay for approvals (default 0.1) */
+  minPol?: number;
+  logger?: Logger;
+}
+
+// Enough POL for the approval transactions and a few redemptions
+const DEFAULT_MIN_POL = 0.1;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VERDICT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * READY when no step failed
+ */
+export function bootstrapVerdict(steps: BootstrapStep[]): BootstrapVerdict {
+  return steps.length > 0 && steps.every((s) => s.status !== "FAIL")
+    ? "READY"
+    : "NOT_READY";
+}
+
+/**
+ * Balance check: USDC.e is required; low POL only blocks in EOA mode,
+ * where the signer sends its own approvals
+ */
+export function checkBootstrapBalances(
+  balances: { bridgedUsdc: number; nativeUsdc: number; pol: number },
+  needsGas: boolean,
+  minPol: number,
+): Omit<BootstrapStep, "step"> {
+  const funds = `$${balances.bridgedUsdc.toFixed(2)} USDC.e, ${balances.pol.toFixed(3)} POL`;
+  const issue = checkUsdcHoldings(
+    { bridged: balances.bridgedUsdc, native: balances.nativeUsdc },
+    1,
+  );
+  if (issue === "NATIVE_ONLY" || balances.bridgedUsdc <= 0) {
+    return {
+      status: "FAIL",
+      detail: `${funds} - deposit USDC.e (native USDC is not accepted)`,
+    };
+  }
+  if (balances.pol < minPol) {
+    return {
+      status: needsGas ? "FAIL" : "WARN",
+      detail: `${funds} - send at least ${minPol} POL to the signer for gas`,
+    };
+  }
+  if (issue === "NATIVE_IDLE") {
+    return {
+      status: "WARN",
+      detail: `${funds} - $${balances.nativeUsdc.toFixed(2)} native USDC is idle`,
+    };
+  }
+  return { status: "OK", detail: funds };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FLOW
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Run the bootstrap. Never throws - every failure ends up in a step.
+ */
+export async function runBootstrap(
+  options: BootstrapOptions,
+): Promise<BootstrapReport> {
+  const { logger } = options;
+  const steps: BootstrapStep[] = [];
+  const record = (
+    step: BootstrapStepName,
+    result: Omit<BootstrapStep, "step">,
+  ) => {
+    steps.push({ step, ...result });
+    const log = result.status === "FAIL" ? logger?.error : logger?.info;
+    log?.(`[${step}] ${result.status} - ${result.detail}`);
+    return result.status !== "FAIL";
+  };
+  const skipRest = (from: number) => {
+    const order: BootstrapStepName[] = [
+      "CREDENTIALS",
+      "BALANCES",
+      "APPROVALS",
+      "TEST_ORDER",
+    ];
+    for (const step of order.slice(from)) {
+      record(step, { status: "SKIPPED", detail: "earlier step failed" });
+    }
+    return { steps, verdict: bootstrapVerdict(steps) };
+  };
+
+  // 1. Credentials
+  const auth = await createClobClient(
+    options.privateKey,
+    options.rpcUrl,
+    logger,
+  );
+  if (!auth.success || !auth.client || !auth.wallet || !auth.address) {
+    record("CREDENTIALS", {
+      status: "FAIL",
+      detail: auth.error ?? "authentication failed",
+    });
+    return skipRest(1);
+  }
+  const { client, wallet, address } = auth;
+  const diag = getAuthDiagnostics(wallet.address, address);
+  const isEoa = !diag.isProxyMode;
+  const derived = `API key derived (${diag.signatureTypeLabel}, trading as ${address.slice(0, 10)}...)`;
+  record(
+    "CREDENTIALS",
+    auth.funderDiagnostic
+      ? {
+          status: "WARN",
+          detail: `${derived} - ${auth.funderDiagnostic.code}: ${auth.funderDiagnostic.message}`,
+        }
+      : { status: "OK", detail: derived },
+  );
+
+  // 2. Balances (gas is always paid by the signer)
+  try {
+    const [holdings, pol] = await Promise.all([
+      getUsdcHoldings(wallet, address),
+      getPolBalance(wallet, wallet.address),
+    ]);
+    const balances = checkBootstrapBalances(
+      { bridgedUsdc: holdings.bridged, nativeUsdc: holdings.native, pol },
+      isEoa,
+      options.minPol ?? DEFAULT_MIN_POL,
+    );
+    if (!record("BALANCES", balances)) return skipRest(2);
+  } catch (err) {
+    record("BALANCES", { status: "FAIL", detail: errorMessage(err) });
+    return skipRest(2);
+  }
+
+  // 3. Approvals
+  const approvals = await ensureApprovals(wallet, address, isEoa, options);
+  if (!record("APPROVALS", approvals)) return skipRest(3);
+
+  // 4. Test order
+  if (options.testOrder === false) {
+    record("TEST_ORDER", { status: "SKIPPED", detail: "disabled" });
+  } else {
+    record("TEST_ORDER", await placeTestOrder(client, options));
+  }
+
+  return { steps, verdict: bootstrapVerdict(steps) };
+}
+
+async function ensureApprovals(
+  wallet: Wallet,
+  address: string,
+  isEoa: boolean,
+  options: BootstrapOptions,
+): Promise<Omit<BootstrapStep, "step">> {
+  try {
+    const statuses = await getTradingApprovals(wallet, address);
+    if (hasAllApprovals(statuses)) {
+      return { status: "OK", detail: "exchange approvals in place" };
+    }
+    const missing = statuses
+      .filter((s) => !s.usdcApproved || !s.ctfApproved)
+      .map((s) => s.name)
+      .join(", ");
+    if (!isEoa) {
+      return {
+        status: "FAIL",
+        detail: `missing for ${missing} - enable trading for this proxy wallet on polymarket.com`,
+      };
+    }
+    if (options.setApprovals === false) {
+      return {
+        status: "FAIL",
+        detail: `missing for ${missing} - rerun without --no-approve to send them`,
+      };
+    }
+    const result = await setTradingApprovals(wallet, options.logger);
+    return result.success
+      ? {
+          status: "FIXED",
+          detail: `approved ${missing} (${result.txHashes.length} tx)`,
+        }
+      : { status: "FAIL", detail: `approval failed: ${result.error}` };
+  } catch (err) {
+    return { status: "FAIL", detail: errorMessage(err) };
+  }
+}
+
+/**
+ * Test trade mapped to a step - a test order that can't be canceled still
+ * proved order permission, so it only warns
+ */
+async function placeTestOrder(
+  client: ClobClient,
+  options: BootstrapOptions,
+): Promise<Omit<BootstrapStep, "step">> {
+  const result = await placeTestTrade(client, {
+    tokenId: options.testTokenId,
+    keepOrder: options.keepTestOrder,
+  });
+  if (!result.orderId) {
+    return { status: "FAIL", detail: `test order failed: ${result.error}` };
+  }
+
+  const placed = `BUY ${result.size} @ ${((result.price ?? 0) * 100).toFixed(1)}¢ (orderId=${result.orderId.slice(0, 12)}...)`;
+  if (result.success) {
+    const outcome = options.keepTestOrder ? "left resting" : "canceled";
+    return { status: "OK", detail: `${placed} placed and ${outcome}` };
+  }
+  return { status: "WARN", detail: `${placed} placed - ${result.error}` };
+}
+
+function errorMessage(err: unknown): string {
+  return err instanceof Error ? err.message : String(err);
+}
@@ -0,0 +1,310 @@
+/**
+ * CLOB Session - Reuse derived API credentials across runs
+ *
+ * Every run of the bot or a script (balance, redeem, test-trade...) derives
+ * its API credentials from the key before doing anything, which costs a
+ * round trip to the auth endpoint per command, and repeated derivations
+ * are what trips its rate limits. With a cache path set, the credentials
+ * are saved after the first derivation and later runs start straight from
+ * them:
+ *
+ *   API_CREDS_CACHE_PATH=./data/api-creds.json
+ *   npm start -- --creds-cache ./data/api-creds.json   (overrides the env)
+ *
+ * Cached credentials belong to one signer, signature type and funder; a
+ * change to any of them derives afresh. On startup they're checked with
+ * one authenticated call, and only derived again if the exchange refuses
+ * them.
+ *
+ * If the exchange stops accepting a session mid-run (invalidated, revoked,
+ * rotated), a 401 re-derives the credentials (imported ones are checked
+ * again instead), saves them and retries the call once. Refreshes that
+ * don't help back off exponentially - see withSessionRefresh().
+ *
+ * Keys generated elsewhere (the website, py-clob-client) can be used
+ * instead of derived ones - all three or none, and only with the opt-in
+ * (the same variables are also the user websocket's fallback keys, which
+ * must not silently replace derivation):
+ *
+ *   IMPORT_API_CREDS=true
+ *   POLY_API_KEY=...  POLY_API_SECRET=...  POLY_PASSPHRASE=...
+ *
+ * They're checked with one authenticated call before anything else, and
+ * a refusal fails authentication rather than falling back to deriving.
+ * `npm run set-creds` checks a set and saves it to the cache instead.
+ *
+ * `npm run export-creds` prints the credentials in use as JSON, for reuse
+ * elsewhere - only with ALLOW_CREDS_EXPORT=true.
+ *
+ * The file holds the API secret and passphrase. It's written 0600, like
+ * the other state files; anyone who can read it can already read
+ * PRIVATE_KEY from .env.
+ */
+
+import type { ApiKeyCreds, ClobClient } from "@polymarket/clob-client";
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+import type { Logger } from "./types";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+interface CachedApiCreds {
+  /** Whose credentials these are (see sessionIdentity) */
+  identity: string;
+  creds: ApiKeyCreds;
+}
+
+/** Derives or re-verifies credentials (null = failed) */
+export type CredsRefresher = () => Promise<ApiKeyCreds | null>;
+
+export interface SessionRefreshOptions {
+  /** Wait after the first refresh that didn't help (doubles each time) */
+  backoffMs?: number;
+  maxBackoffMs?: number;
+  /** Clock (tests) */
+  now?: () => number;
+}
+
+export const SESSION_REFRESH_BACKOFF_MS = 5_000;
+export const SESSION_REFRESH_MAX_BACKOFF_MS = 5 * 60 * 1000;
+
+/** Must be true for POLY_API_* to replace derived credentials */
+export const IMPORT_CREDS_ENV = "IMPORT_API_CREDS";
+
+/** Variables that import L2 credentials, in ApiKeyCreds order */
+export const IMPORTED_CREDS_ENV = [
+  "POLY_API_KEY",
+  "POLY_API_SECRET",
+  "POLY_PASSPHRASE",
+] as const;
+
+/** Must be true for `npm run export-creds` to print credentials */
+export const CREDS_EXPORT_ENV = "ALLOW_CREDS_EXPORT";
+
+/** Methods that derive credentials themselves - never retried */
+const DERIVATION_METHODS = new Set<PropertyKey>([
+  "createApiKey",
+  "deriveApiKey",
+  "createOrDeriveApiKey",
+]);
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CREDENTIAL CACHE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Who a set of credentials belongs to: signer, signature type and funder
+ */
+export function sessionIdentity(
+  signerAddress: string,
+  signatureType: number,
+  funderAddress?: string,
+): string {
+  return [signerAddress, signatureType, funderAddress ?? ""]
+    .join(":")
+    .toLowerCase();
+}
+
+/**
+ * Cache path from `--creds-cache <path>` / `--creds-cache=<path>`, else
+ * API_CREDS_CACHE_PATH (undefined = no cache)
+ */
+export function resolveCredsCachePath(
+  argv: string[] = process.argv.slice(2),
+  env: NodeJS.ProcessEnv = process.env,
+): string | undefined {
+  let fromArg: string | undefined;
+  for (let i = 0; i < argv.length; i++) {
+    const arg = argv[i];
+    if (arg === "--creds-cache") fromArg = argv[i + 1];
+    else if (arg.startsWith("--creds-cache=")) {
+      fromArg = arg.slice("--creds-cache=".length);
+    }
+  }
+  return (fromArg ?? env.API_CREDS_CACHE_PATH)?.trim() || undefined;
+}
+
+/**
+ * Credentials imported through POLY_API_KEY / POLY_API_SECRET /
+ * POLY_PASSPHRASE, when IMPORT_API_CREDS is on
+ *
+ * @returns creds (null when none are set or importing is off), and which
+ *   variables are missing when only some are
+ */
+export function readImportedCreds(env: NodeJS.ProcessEnv = process.env): {
+  creds: ApiKeyCreds | null;
+  missing: string[];
+} {
+  const optIn = env[IMPORT_CREDS_ENV]?.trim().toLowerCase() ?? "";
+  if (!["true", "1", "yes"].includes(optIn)) {
+    return { creds: null, missing: [] };
+  }
+  const [key, secret, passphrase] = IMPORTED_CREDS_ENV.map(
+    (name) => env[name]?.trim() ?? "",
+  );
+  const missing = IMPORTED_CREDS_ENV.filter((name) => !env[name]?.trim());
+  if (missing.length === IMPORTED_CREDS_ENV.length) {
+    return { creds: null, missing: [] };
+  }
+  return missing.length > 0
+    ? { creds: null, missing }
+    : { creds: { key, secret, passphrase }, missing };
+}
+
+/**
+ * Cached credentials for an identity (null if none, or someone else's)
+ */
+export function loadCachedCreds(
+  filePath: string,
+  identity: string,
+): ApiKeyCreds | null {
+  const result = loadStateFile<CachedApiCreds>(filePath);
+  if (!result.ok) {
+    console.warn(`⚠️ [Session] Failed to load ${filePath}: ${result.error}`);
+    return null;
+  }
+  const cached = result.payload;
+  if (cached?.identity !== identity) return null;
+  const { key, secret, passphrase } = cached.creds ?? {};
+  return key && secret && passphrase ? { key, secret, passphrase } : null;
+}
+
+/**
+ * Save credentials for an identity (replacing whatever was cached)
+ */
+export function saveCachedCreds(
+  filePath: string,
+  identity: string,
+  creds: ApiKeyCreds,
+): boolean {
+  const result = saveStateFile<CachedApiCreds>(filePath, { identity, creds });
+  if (!result.ok) {
+    console.warn(`⚠️ [Session] Failed to save ${filePath}: ${result.error}`);
+  }
+  return result.ok;
+}
+
+/**
+ * L2 credentials a client is signing with right now (including ones
+ * re-derived after a 401), or null if it has none
+ */
+export function activeCreds(client: ClobClient): ApiKeyCreds | null {
+  const creds: Partial<ApiKeyCreds> = (client as any).creds ?? {};
+  const { key, secret, passphrase } = creds;
+  return key && secret && passphrase ? { key, secret, passphrase } : null;
+}
+
+function isUnauthorized(payload: unknown): boolean {
+  const raw = payload as any;
+  return (raw?.response?.status ?? raw?.status) === 401;
+}
+
+/**
+ * Whether the exchange accepts a client's credentials (one authenticated
+ * call - a failed or refused one counts as no)
+ */
+export async function verifyCreds(client: ClobClient): Promise<boolean> {
+  try {
+    const keys: any = await client.getApiKeys();
+    return Boolean(keys) && !keys.error && !isUnauthorized(keys);
+  } catch {
+    return false;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SESSION REFRESH
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Wrap a client so a call answered 401 (session invalidated, credentials
+ * revoked or rotated) refreshes the credentials and is retried once; if
+ * the retry fails too, its answer is what the caller gets.
+ *
+ * Concurrent 401s share one refresh. Each refresh whose retry doesn't get
+ * through doubles the wait before the next one may run (base `backoffMs`,
+ * capped at `maxBackoffMs`) - a 401 inside that window is returned as-is
+ * rather than hammering the auth endpoint. A retry that gets through
+ * resets the backoff.
+ */
+export function withSessionRefresh(
+  client: ClobClient,
+  refresh: CredsRefresher,
+  logger?: Logger,
+  options: SessionRefreshOptions = {},
+): ClobClient {
+  const {
+    backoffMs = SESSION_REFRESH_BACKOFF_MS,
+    maxBackoffMs = SESSION_REFRESH_MAX_BACKOFF_MS,
+    now = Date.now,
+  } = options;
+  let refreshing: Promise<unknown> | null = null;
+  /** Refreshes since a retry last got through */
+  let failures = 0;
+  let retryAt = 0;
+
+  /** Whether a call rejected with these creds can go again on newer ones */
+  const renew = async (usedCreds: unknown): Promise<boolean> => {
+    if (!refreshing && (client as any).creds === usedCreds) {
+      if (now() < retryAt) {
+        const waitSec = Math.ceil((retryAt - now()) / 1000);
+        logger?.warn?.(
+          `API credentials rejected (401) - next refresh in ${waitSec}s`,
+        );
+        return false;
+      }
+      retryAt = now() + Math.min(backoffMs * 2 ** failures, maxBackoffMs);
+      failures++;
+      logger?.warn?.(
+        `API credentials rejected (401) - refreshing (attempt ${failures})`,
+      );
+      refreshing = refresh()
+        .catch(() => null)
+        .then((creds) => {
+          // Every authenticated call reads the client's creds
+          if (creds) (client as any).creds = creds;
+          refreshing = null;
+        });
+    }
+    await refreshing;
+    return (client as any).creds !== usedCreds;
+  };
+
+  return new Proxy(client, {
+    get(target, prop, receiver) {
+      const value = Reflect.get(target, prop, receiver);
+      if (typeof value !== "function" || DERIVATION_METHODS.has(prop)) {
+        return typeof value === "function" ? value.bind(target) : value;
+      }
+
+      return (...args: unknown[]) => {
+        const usedCreds = (target as any).creds;
+        const call = () => value.apply(target, args);
+        const result = call();
+        if (!(result instanceof Promise)) return result;
+
+        /** The one retry - getting through means the refresh worked */
+        const retry = async () => {
+          const response = await call();
+          if (!isUnauthorized(response)) {
+            failures = 0;
+            retryAt = 0;
+          }
+          return response;
+        };
+
+        return result.then(
+          async (response: unknown) => {
+            if (!isUnauthorized(response)) return response;
+            return (await renew(usedCreds)) ? retry() : response;
+          },
+          async (err: unknown) => {
+            if (!isUnauthorized(err) || !(await renew(usedCreds))) throw err;
+            return retry();
+          },
+        );
+      };
+    },
+  });
+}
@@ -0,0 +1,281 @@
+/**
+ * Concurrent Scan - Fetch books for many markets in parallel
+ *
+ * The volume scanner lists markets by 24h volume, but whether a market is
+ * tradeable depends on its book. Fetching books one after another takes
+ * ~N × round-trip (visible in the diag SCAN_BUY step), so this fans the
+ * fetches out across a bounded pool of workers:
+ *
+ * - at most `concurrency` book fetches in flight
+ * - every fetch waits on a shared rate limiter (CLOB: 10 req/s)
+ * - a 429 pauses ALL workers for a backoff, then the fetch is retried
+ * - qualifying candidates are streamed to onCandidate as they're found,
+ *   and progress to onProgress, before the final summary is returned
+ *
+ *   const summary = await scanMarketsConcurrently(markets, fetchBook, {
+ *     maxSpreadCents: 4,
+ *     minDepthUsd: 50,
+ *   }, { concurrency: 8, onCandidate: (c) => console.log(c.tokenId) });
+ */
+
+import {
+  READ_POLICY,
+  calculateBackoff,
+  isRateLimitError,
+  rateLimiters,
+  sleep,
+  type RateLimiter,
+  type RetryConfig,
+} from "../services/polymarket/rate-limit";
+import { normalizeRestOrderbook } from "./orderbook-utils";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** A market to scan */
+export interface ScanTarget {
+  tokenId: string;
+  marketId?: string;
+  question?: string;
+}
+
+/** Book conditions a market must meet to be a candidate */
+export interface ScanFilters {
+  /** Lowest acceptable mid price in cents (default: 20) */
+  minPriceCents?: number;
+  /** Highest acceptable mid price in cents (default: 80) */
+  maxPriceCents?: number;
+  /** Widest acceptable spread in cents (default: 6) */
+  maxSpreadCents?: number;
+  /** Minimum USD on each side within depthWindowCents of the best price
+   *  (default: 25) */
+  minDepthUsd?: number;
+  /** How far from the best price depth is counted, in cents (default: 5) */
+  depthWindowCents?: number;
+}
+
+export type ScanRejectReason =
+  | "EMPTY_BOOK"
+  | "PRICE_OUT_OF_RANGE"
+  | "SPREAD_TOO_WIDE"
+  | "THIN_DEPTH";
+
+export interface ScanCandidate extends ScanTarget {
+  bestBid: number;
+  bestAsk: number;
+  midPriceCents: number;
+  spreadCents: number;
+  bidDepthUsd: number;
+  askDepthUsd: number;
+}
+
+export interface ScanProgress {
+  done: number;
+  total: number;
+  candidates: number;
+  failed: number;
+}
+
+export interface ScanSummary {
+  scanned: number;
+  candidates: ScanCandidate[];
+  rejected: Record<ScanRejectReason, number>;
+  /** Book fetches that failed after retries */
+  failed: number;
+  /** 429 responses seen (each paused every worker) */
+  rateLimited: number;
+  durationMs: number;
+}
+
+export interface ConcurrentScanOptions {
+  /** Book fetches in flight at once (default: 8) */
+  concurrency?: number;
+  /** Shared limiter every fetch waits on (default: the CLOB limiter) */
+  rateLimiter?: RateLimiter;
+  /** Retries and 429 backoff per market (default: the read policy) */
+  retry?: Partial<RetryConfig>;
+  onCandidate?: (candidate: ScanCandidate) => void;
+  onProgress?: (progress: ScanProgress) => void;
+}
+
+/** Raw REST book, as returned by client.getOrderBook() */
+export interface RawOrderbook {
+  bids?: Array<{ price: string; size: string }>;
+  asks?: Array<{ price: string; size: string }>;
+}
+
+export const DEFAULT_SCAN_FILTERS: Required<ScanFilters> = {
+  minPriceCents: 20,
+  maxPriceCents: 80,
+  maxSpreadCents: 6,
+  minDepthUsd: 25,
+  depthWindowCents: 5,
+};
+
+const DEFAULT_CONCURRENCY = 8;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// EVALUATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Check one market's book against the filters
+ * @returns The candidate, or why the market was rejected
+ */
+export function evaluateScanBook(
+  target: ScanTarget,
+  book: RawOrderbook,
+  filters: ScanFilters = {},
+): { candidate: ScanCandidate } | { rejected: ScanRejectReason } {
+  const f = { ...DEFAULT_SCAN_FILTERS, ...filters };
+  const { bids, asks } = normalizeRestOrderbook(book);
+  if (bids.length === 0 || asks.length === 0) {
+    return { rejected: "EMPTY_BOOK" };
+  }
+
+  const bestBid = bids[0].price;
+  const bestAsk = asks[0].price;
+  const midPriceCents = ((bestBid + bestAsk) / 2) * 100;
+  const spreadCents = (bestAsk - bestBid) * 100;
+  if (midPriceCents < f.minPriceCents || midPriceCents > f.maxPriceCents) {
+    return { rejected: "PRICE_OUT_OF_RANGE" };
+  }
+  if (spreadCents > f.maxSpreadCents) {
+    return { rejected: "SPREAD_TOO_WIDE" };
+  }
+
+  const window = f.depthWindowCents / 100;
+  const bidDepthUsd = bids
+    .filter((l) => l.price >= bestBid - window)
+    .reduce((sum, l) => sum + l.price * l.size, 0);
+  const askDepthUsd = asks
+    .filter((l) => l.price <= bestAsk + window)
+    .reduce((sum, l) => sum + l.price * l.size, 0);
+  if (bidDepthUsd < f.minDepthUsd || askDepthUsd < f.minDepthUsd) {
+    return { rejected: "THIN_DEPTH" };
+  }
+
+  return {
+    candidate: {
+      ...target,
+      bestBid,
+      bestAsk,
+      midPriceCents,
+      spreadCents,
+      bidDepthUsd,
+      askDepthUsd,
+    },
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SCAN
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Fetch and evaluate every target's book across a bounded worker pool
+ *
+ * Candidates are returned in the order they were found, not target order.
+ */
+export async function scanMarketsConcurrently(
+  targets: ScanTarget[],
+  fetchBook: (tokenId: string) => Promise<RawOrderbook>,
+  filters: ScanFilters = {},
+  options: ConcurrentScanOptions = {},
+): Promise<ScanSummary> {
+  const startedAt = Date.now();
+  const concurrency = Math.max(1, options.concurrency ?? DEFAULT_CONCURRENCY);
+  const limiter = options.rateLimiter ?? rateLimiters.clob;
+  const retry = { ...READ_POLICY, ...options.retry };
+
+  const summary: ScanSummary = {
+    scanned: 0,
+    candidates: [],
+    rejected: {
+      EMPTY_BOOK: 0,
+      PRICE_OUT_OF_RANGE: 0,
+      SPREAD_TOO_WIDE: 0,
+      THIN_DEPTH: 0,
+    },
+    failed: 0,
+    rateLimited: 0,
+    durationMs: 0,
+  };
+
+  // Set by whichever worker hits a 429; every worker waits it out
+  let pausedUntil = 0;
+  let next = 0;
+
+  const fetchWithBackoff = async (tokenId: string): Promise<RawOrderbook> => {
+    for (let attempt = 0; ; attempt++) {
+      const pause = pausedUntil - Date.now();
+      if (pause > 0) await sleep(pause);
+      await limiter.waitAndRecord();
+      try {
+        return await fetchBook(tokenId);
+      } catch (err) {
+        const rateLimited = isRateLimitError(err);
+        if (rateLimited) summary.rateLimited++;
+        if (attempt >= retry.maxRetries) throw err;
+        if (rateLimited) {
+          pausedUntil = Math.max(
+            pausedUntil,
+            Date.now() + calculateBackoff(attempt, retry),
+          );
+        }
+      }
+    }
+  };
+
+  const worker = async (): Promise<void> => {
+    while (next < targets.length) {
+      const target = targets[next++];
+      try {
+        const result = evaluateScanBook(
+          target,
+          await fetchWithBackoff(target.tokenId),
+          filters,
+        );
+        if ("candidate" in result) {
+          summary.candidates.push(result.candidate);
+          options.onCandidate?.(result.candidate);
+        } else {
+          summary.rejected[result.rejected]++;
+        }
+      } catch {
+        summary.failed++;
+      }
+      summary.scanned++;
+      options.onProgress?.({
+        done: summary.scanned,
+        total: targets.length,
+        candidates: summary.candidates.length,
+        failed: summary.failed,
+      });
+    }
+  };
+
+  await Promise.all(
+    Array.from({ length: Math.min(concurrency, targets.length) }, worker),
+  );
+  summary.durationMs = Date.now() - startedAt;
+  return summary;
+}
+
+/**
+ * One-line summary for logs
+ */
+export function formatScanSummary(summary: ScanSummary): string {
+  const rejected = Object.entries(summary.rejected)
+    .filter(([, n]) => n > 0)
+    .map(([reason, n]) => `${reason.toLowerCase()}=${n}`)
+    .join(", ");
+  return (
+    `${summary.scanned} scanned, ${summary.candidates.length} candidate(s)` +
+    (rejected ? `, rejected: ${rejected}` : "") +
+    (summary.failed > 0 ? `, ${summary.failed} failed` : "") +
+    (summary.rateLimited > 0 ? `, ${summary.rateLimited} rate-limited` : "") +
+    ` in ${(summary.durationMs / 1000).toFixed(1)}s`
+  );
+}
@@ -59,12 +59,21 @@ export const POLYMARKET_WS = {
   // Staleness threshold - data older than this triggers REST fallback
   STALE_MS: envNum("WS_STALE_MS", 2000),
 
+  // Max book age to trade against - older (or crossed) books return STALE_BOOK
+  MAX_TRADE_BOOK_AGE_MS: envNum("MAX_TRADE_BOOK_AGE_MS", 5000),
+
   // REST fallback rate limiting
   REST_FALLBACK_MIN_INTERVAL_MS: envNum("REST_FALLBACK_MIN_INTERVAL_MS", 500),
 
   // Memory protection - cap tracked tokens
   MAX_TOKENS: envNum("MARKETDATA_MAX_TOKENS", 500),
 
+  // Trade prints kept per token (last_trade_price events)
+  MAX_TRADES_PER_TOKEN: envNum("MARKETDATA_MAX_TRADES_PER_TOKEN", 200),
+
+  // L2 snapshots kept per token for fill replay (changed books only)
+  MAX_BOOK_HISTORY_PER_TOKEN: envNum("MARKETDATA_BOOK_HISTORY_PER_TOKEN", 120),
+
   // Depth window for shallow depth calculation (cents from touch)
   DEPTH_WINDOW_CENTS: envNum("MARKETDATA_DEPTH_WINDOW_CENTS", 5),
 
@@ -97,12 +106,19 @@ export function getUserWsUrl(): string {
 // Polygon Network
 export const POLYGON = {
   CHAIN_ID: 137,
+  // Bridged USDC.e - the collateral the exchange trades in
   USDC_ADDRESS: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174",
+  // Circle's native USDC - NOT accepted by the exchange
+  NATIVE_USDC_ADDRESS: "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359",
   USDC_DECIMALS: 6,
   CTF_ADDRESS: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045",
   CTF_EXCHANGE: "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E",
   NEG_RISK_CTF_EXCHANGE: "0xC5d563A36AE78145C45a50134d48A1215220f80a",
   NEG_RISK_ADAPTER: "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296",
+  // CREATE2 factories of the Polymarket proxy (signatureType=1) and Safe
+  // (signatureType=2) wallets
+  PROXY_WALLET_FACTORY: "0xaB45c5A4B0c941a2F231C04C3f49182e1A254052",
+  SAFE_WALLET_FACTORY: "0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b",
 } as const;
 
 // Order Settings
@@ -115,6 +131,7 @@ export const ORDER = {
   DEFAULT_SLIPPAGE_PCT: 3,
   COOLDOWN_MS: 1000,
   MARKET_COOLDOWN_MS: 5000,
+  MARKET_STATUS_TTL_MS: 30000,
 } as const;
 
 /**
@@ -261,6 +278,7 @@ export const CTF_ABI = [
   "function redeemPositions(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] indexSets)",
   "function payoutDenominator(bytes32 conditionId) view returns (uint256)",
   "function payoutNumerators(bytes32 conditionId, uint256 index) view returns (uint256)",
+  "function safeTransferFrom(address from, address to, uint256 id, uint256 amount, bytes data)",
 ] as const;
 
 // Proxy Wallet ABI (minimal for redemption via proxy)
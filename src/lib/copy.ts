@@ -22,6 +22,7 @@
 
 import axios from "axios";
 import { POLYMARKET_API } from "./constants";
+import { labelAddress } from "./address-book";
 import type { TradeSignal } from "./types";
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -117,6 +118,11 @@ export async function fetchRecentTrades(
     batchSize?: number;
     /** Delay between batches in ms (default: 100ms) */
     batchDelayMs?: number;
+    /**
+     * Values for fields a trade is missing. Without them such trades are
+     * skipped (MISSING_FIELD) - a missing side must never become a BUY copy.
+     */
+    defaults?: { side?: "BUY" | "SELL" };
   } = {},
 ): Promise<TradeSignal[]> {
   const {
@@ -124,6 +130,7 @@ export async function fetchRecentTrades(
     maxAgeMs = 60000,
     batchSize = 10,
     batchDelayMs = 100,
+    defaults = {},
   } = options;
 
   const trades: TradeSignal[] = [];
@@ -190,7 +197,20 @@ export async function fetchRecentTrades(
           } else {
             // Skip trade if we can't determine any outcome info
             console.warn(
-              `[copy] Skipping trade with no outcome info for wallet ${addr.slice(0, 10)}...`,
+              `[copy] Skipping trade with no outcome info for wallet ${labelAddress(addr)}`,
+            );
+            continue;
+          }
+
+          // Side, size and price are required - no silent BUY / $0 fallbacks
+          const rawSide = String(t.side ?? "").toUpperCase();
+          const side =
+            rawSide === "BUY" || rawSide === "SELL" ? rawSide : defaults.side;
+          const size = Number(t.size);
+          const price = Number(t.price);
+          if (!side || !(size > 0) || !(price > 0)) {
+            console.warn(
+              `[copy] MISSING_FIELD: skipping trade from ${labelAddress(addr)} (side=${t.side}, size=${t.size}, price=${t.price})`,
             );
             continue;
           }
@@ -201,10 +221,10 @@ export async function fetchRecentTrades(
             conditionId: t.conditionId || "",
             marketId: t.marketId || "",
             outcome: outcomeLabel, // Use actual outcome label (YES/NO or team name, etc.)
-            side: t.side?.toUpperCase() === "SELL" ? "SELL" : "BUY",
+            side,
             // Calculate USD value
-            sizeUsd: Number(t.size) * Number(t.price) || 0,
-            price: Number(t.price) || 0,
+            sizeUsd: size * price,
+            price,
             trader: addr,
             timestamp: ts,
           });
@@ -218,7 +238,7 @@ export async function fetchRecentTrades(
       } catch (error) {
         // Log error but continue - individual wallet failure shouldn't stop others
         console.error(
-          `[copy] Error fetching trades for wallet ${addr.slice(0, 10)}...:`,
+          `[copy] Error fetching trades for wallet ${labelAddress(addr)}:`,
           error instanceof Error ? error.message : error,
         );
       }
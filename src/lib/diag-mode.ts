@@ -84,6 +84,7 @@ export type DiagReason =
   | "hard_stop_triggered"
   // Candidate pre-filter issues (skipped at candidate stage, not execution)
   | "skipped_bad_book"
+  | "below_liquidity_floor"
   | "candidate_cooldown"
   // Other
   | "unknown_error";
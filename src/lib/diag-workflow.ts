@@ -41,6 +41,15 @@ import {
   DEAD_BOOK_THRESHOLDS,
   type BookHealthResult,
 } from "./price-safety";
+import { normalizeRestOrderbook } from "./orderbook-utils";
+import {
+  checkLiquidityFloor,
+  formatLiquidityFloorCheck,
+  getLiquidityFloor,
+  type LiquidityFloor,
+  type LiquidityFloorCheck,
+} from "../core/liquidity-floor";
+import type { EntryStrategy } from "../core/strategy-schedule";
 
 // ═══════════════════════════════════════════════════════════════════════════
 // TYPES
@@ -543,6 +552,39 @@ async function runWhaleBuyStep(
           lastRejectedCandidate = signal;
           continue; // Try next candidate (dead_book triggers immediate retry)
         }
+
+        const depthFailure = checkDiagLiquidityFloor(
+          "whale",
+          orderbook,
+          cfg.forceShares,
+        );
+        if (depthFailure) {
+          const { floor, check } = depthFailure;
+          tracer.trace({
+            step,
+            action: "liquidity_floor_failed",
+            result: "SKIPPED",
+            reason: "below_liquidity_floor",
+            marketId: signal.marketId,
+            tokenId: signal.tokenId,
+            detail: {
+              attemptNumber: attemptCount,
+              sizeMultiple: floor.sizeMultiple,
+              withinCents: floor.withinCents,
+              availableShares: check.availableShares,
+              requiredShares: check.requiredShares,
+            },
+          });
+
+          console.log(
+            `   ⏭️ BELOW_LIQUIDITY_FLOOR: ${formatLiquidityFloorCheck(floor, check)}`,
+          );
+          console.log(`   → Trying next candidate...`);
+
+          lastRejectionReason = "below_liquidity_floor";
+          lastRejectedCandidate = signal;
+          continue;
+        }
       } else if (orderbookFetchFailed) {
         // Pre-filter couldn't run due to orderbook fetch failure
         // attemptDiagBuy will re-fetch and handle validation
@@ -623,6 +665,7 @@ async function runWhaleBuyStep(
     // ─────────────────────────────────────────────────────────────────────────
     const candidateStageReasons: DiagReason[] = [
       "skipped_bad_book",
+      "below_liquidity_floor",
       "candidate_cooldown",
     ];
     const wasExecutionStageRejection =
@@ -1021,6 +1064,39 @@ async function runScanBuyStep(
           lastRejectedCandidate = scanResult;
           continue; // Try next candidate (dead_book triggers immediate retry)
         }
+
+        const depthFailure = checkDiagLiquidityFloor(
+          "scan",
+          orderbook,
+          cfg.forceShares,
+        );
+        if (depthFailure) {
+          const { floor, check } = depthFailure;
+          tracer.trace({
+            step,
+            action: "liquidity_floor_failed",
+            result: "SKIPPED",
+            reason: "below_liquidity_floor",
+            marketId: scanResult.marketId,
+            tokenId: scanResult.tokenId,
+            detail: {
+              attemptNumber: attemptCount,
+              sizeMultiple: floor.sizeMultiple,
+              withinCents: floor.withinCents,
+              availableShares: check.availableShares,
+              requiredShares: check.requiredShares,
+            },
+          });
+
+          console.log(
+            `   ⏭️ BELOW_LIQUIDITY_FLOOR: ${formatLiquidityFloorCheck(floor, check)}`,
+          );
+          console.log(`   → Trying next candidate...`);
+
+          lastRejectionReason = "below_liquidity_floor";
+          lastRejectedCandidate = scanResult;
+          continue;
+        }
       } else if (orderbookFetchFailed) {
         // Pre-filter couldn't run due to orderbook fetch failure
         // attemptDiagBuy will re-fetch and handle validation
@@ -1107,6 +1183,7 @@ async function runScanBuyStep(
     // ─────────────────────────────────────────────────────────────────────────
     const candidateStageReasons: DiagReason[] = [
       "skipped_bad_book",
+      "below_liquidity_floor",
       "candidate_cooldown",
     ];
     const wasExecutionStageRejection =
@@ -1560,6 +1637,25 @@ export function performBookSanityCheck(
   return { passed: true, detail };
 }
 
+/**
+ * Check a candidate's book against the strategy's liquidity floor
+ * (STRATEGY_LIQUIDITY_FLOOR) for the diag order size, so a book that is
+ * sane at the top but too thin behind it is skipped at candidate stage.
+ *
+ * @returns The failed check, or null if it passes or no floor is set
+ */
+export function checkDiagLiquidityFloor(
+  strategy: EntryStrategy,
+  orderbook: OrderbookData,
+  shares: number,
+): { floor: LiquidityFloor; check: LiquidityFloorCheck } | null {
+  const floor = getLiquidityFloor(strategy);
+  if (!floor) return null;
+  const { asks } = normalizeRestOrderbook(orderbook);
+  const check = checkLiquidityFloor(floor, asks, "BUY", shares);
+  return check.ok ? null : { floor, check };
+}
+
 /**
  * Log candidate evaluation for diagnostics
  */
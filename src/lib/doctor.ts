@@ -0,0 +1,350 @@
+/**
+ * Doctor - Inspect the environment and list what to fix, worst first
+ *
+ * The bootstrap checks that the wallet can trade; the doctor checks what
+ * sits around it, without placing or signing anything:
+ *
+ * - PRIVATE_KEY: present, 64 hex characters, no stray quotes/whitespace,
+ *   signs as EXPECTED_ADDRESS when that's set
+ * - conflicting settings: both names of an aliased setting set to
+ *   different values, or a setting the bot never reads
+ *   (POLYMARKET_PRIVATE_KEY instead of PRIVATE_KEY)
+ * - proxy/Safe signature type without a funder, or a funder that isn't
+ *   the signer's wallet (FUNDER_MISMATCH, see funder-check.ts)
+ * - misspelled settings (see known-env.ts)
+ * - decimal commas in numeric settings ("0,5" is rejected and the
+ *   default used instead)
+ * - open-file limit (every WebSocket and pooled HTTP connection is one)
+ * - clock skew against the CLOB (signed requests carry a timestamp)
+ *
+ *   npm run doctor
+ *   npm run doctor -- --json
+ *
+ * Findings are sorted CRITICAL, then WARNING, then INFO; ok is false
+ * when any is CRITICAL.
+ */
+
+import * as fs from "fs";
+import axios from "axios";
+import { Wallet } from "ethers";
+import { findEnvTypos } from "../config";
+import { checkExpectedSigner, SIGNER_MISMATCH } from "./auth";
+import { POLYMARKET_API } from "./constants";
+import { checkFunderAddress } from "./funder-check";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type DoctorSeverity = "CRITICAL" | "WARNING" | "INFO";
+
+export interface DoctorFinding {
+  code: string;
+  severity: DoctorSeverity;
+  /** What's wrong */
+  message: string;
+  /** What to do about it */
+  fix: string;
+}
+
+export interface DoctorReport {
+  ok: boolean;
+  findings: DoctorFinding[];
+  checkedAt: string;
+}
+
+export interface DoctorOptions {
+  env?: Record<string, string | undefined>;
+  /** CLOB server time (ms), null if unreachable */
+  fetchServerTime?: () => Promise<number | null>;
+  /** Contents of /proc/self/limits, null where there is none */
+  readLimits?: () => string | null;
+}
+
+type Env = Record<string, string | undefined>;
+
+/** Settings read under two names - the first one wins */
+const ENV_ALIASES: Array<[string, string]> = [
+  ["POLYMARKET_SIGNATURE_TYPE", "CLOB_SIGNATURE_TYPE"],
+  ["POLYMARKET_PROXY_ADDRESS", "CLOB_FUNDER_ADDRESS"],
+  ["LIVE_TRADING", "ARB_LIVE_TRADING"],
+  ["TELEGRAM_BOT_TOKEN", "TELEGRAM_TOKEN"],
+  ["TELEGRAM_CHAT_ID", "TELEGRAM_CHAT"],
+];
+
+/** Names other tools use that the bot ignores */
+const IGNORED_ENV_NAMES: Record<string, string> = {
+  POLYMARKET_PRIVATE_KEY: "PRIVATE_KEY",
+  PK: "PRIVATE_KEY",
+  CLOB_API_KEY: "POLY_API_KEY",
+  POLYGON_RPC_URL: "RPC_URL",
+};
+
+/** Settings whose names say they're a number */
+const NUMERIC_SETTING =
+  /_(USD|PCT|CENTS|SECONDS|SEC|MS|MIN|MINUTES|HOURS|RATIO|RATE|WEIGHT|MULTIPLIER|THRESHOLD|FRACTION|PRICE|RESERVE|TARGET)$/;
+
+const MIN_OPEN_FILES = 1024;
+const CLOCK_SKEW_WARN_MS = 2_000;
+const CLOCK_SKEW_CRITICAL_MS = 30_000;
+
+const SEVERITY_ORDER: Record<DoctorSeverity, number> = {
+  CRITICAL: 0,
+  WARNING: 1,
+  INFO: 2,
+};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHECKS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Everything that can be checked from the environment variables alone
+ */
+export function diagnoseEnv(env: Env): DoctorFinding[] {
+  const findings: DoctorFinding[] = [];
+  const signer = checkPrivateKey(env, findings);
+  if (!env.RPC_URL) {
+    findings.push({
+      code: "RPC_URL_MISSING",
+      severity: "CRITICAL",
+      message: "RPC_URL is not set",
+      fix: "Set RPC_URL to a Polygon RPC endpoint",
+    });
+  }
+
+  for (const [primary, alias] of ENV_ALIASES) {
+    const a = env[primary];
+    const b = env[alias];
+    if (a !== undefined && b !== undefined && a.trim() !== b.trim()) {
+      findings.push({
+        code: "ENV_CONFLICT",
+        severity: "WARNING",
+        message: `${primary} and ${alias} are both set, to different values - ${alias} is ignored`,
+        fix: `Remove one of ${primary} / ${alias}`,
+      });
+    }
+  }
+  for (const [name, used] of Object.entries(IGNORED_ENV_NAMES)) {
+    if (env[name] === undefined) continue;
+    const conflict = env[used] !== undefined && env[used] !== env[name];
+    findings.push({
+      code: "ENV_IGNORED",
+      severity: conflict ? "CRITICAL" : "WARNING",
+      message: conflict
+        ? `${name} and ${used} are both set, to different values - only ${used} is read`
+        : `${name} is set but only ${used} is read`,
+      fix: `Use ${used} and remove ${name}`,
+    });
+  }
+
+  checkFunder(env, signer, findings);
+
+  for (const typo of findEnvTypos(env)) {
+    findings.push({
+      code: "ENV_TYPO",
+      severity: "WARNING",
+      message: `${typo.key} is not a setting`,
+      fix: `Rename it to ${typo.suggestion}`,
+    });
+  }
+
+  for (const [key, value] of Object.entries(env)) {
+    if (!value || !NUMERIC_SETTING.test(key)) continue;
+    if (/^\s*[+-]?\d*,\d+\s*$/.test(value)) {
+      findings.push({
+        code: "DECIMAL_COMMA",
+        severity: "WARNING",
+        message: `${key}=${value} uses a decimal comma - it's rejected and the default is used`,
+        fix: `Write it with a decimal point: ${key}=${value.trim().replace(",", ".")}`,
+      });
+    }
+  }
+  return findings;
+}
+
+function checkPrivateKey(
+  env: Env,
+  findings: DoctorFinding[],
+): string | null {
+  const raw = env.PRIVATE_KEY;
+  if (!raw) {
+    findings.push({
+      code: "PRIVATE_KEY_MISSING",
+      severity: "CRITICAL",
+      message: "PRIVATE_KEY is not set",
+      fix: "Set PRIVATE_KEY to the signer's 64-character hex key",
+    });
+    return null;
+  }
+  const trimmed = raw.trim().replace(/^["']|["']$/g, "");
+  if (trimmed !== raw) {
+    findings.push({
+      code: "PRIVATE_KEY_FORMAT",
+      severity: "CRITICAL",
+      message: "PRIVATE_KEY has surrounding whitespace or quotes",
+      fix: "Remove the quotes/whitespace around the key in .env",
+    });
+  }
+  const key = trimmed.startsWith("0x") ? trimmed : `0x${trimmed}`;
+  if (!/^0x[0-9a-fA-F]{64}$/.test(key)) {
+    findings.push({
+      code: "PRIVATE_KEY_INVALID",
+      severity: "CRITICAL",
+      message: `PRIVATE_KEY is not 64 hex characters (got ${key.length - 2})`,
+      fix: "Export the key again from the wallet - it's 64 hex characters, optionally prefixed with 0x",
+    });
+    return null;
+  }
+  let signer: string;
+  try {
+    signer = new Wallet(key).address;
+  } catch {
+    return null;
+  }
+  const signerError = checkExpectedSigner(signer, env.EXPECTED_ADDRESS);
+  if (signerError) {
+    findings.push({
+      code: SIGNER_MISMATCH,
+      severity: "CRITICAL",
+      message: signerError,
+      fix: "Use the key of EXPECTED_ADDRESS, or update EXPECTED_ADDRESS after a deliberate key rotation",
+    });
+  }
+  return signer;
+}
+
+function checkFunder(
+  env: Env,
+  signer: string | null,
+  findings: DoctorFinding[],
+): void {
+  const typeRaw = env.POLYMARKET_SIGNATURE_TYPE ?? env.CLOB_SIGNATURE_TYPE;
+  const funder = env.POLYMARKET_PROXY_ADDRESS ?? env.CLOB_FUNDER_ADDRESS;
+  const signatureType = typeRaw === undefined ? 0 : Number(typeRaw);
+  if (![0, 1, 2].includes(signatureType)) {
+    findings.push({
+      code: "SIGNATURE_TYPE_INVALID",
+      severity: "CRITICAL",
+      message: `POLYMARKET_SIGNATURE_TYPE=${typeRaw} is not 0, 1 or 2`,
+      fix: "Use 0 (EOA), 1 (Polymarket proxy, email login) or 2 (Safe, browser wallet)",
+    });
+    return;
+  }
+  if (signatureType > 0 && !funder) {
+    findings.push({
+      code: "FUNDER_MISSING",
+      severity: "CRITICAL",
+      message: `POLYMARKET_SIGNATURE_TYPE=${signatureType} without POLYMARKET_PROXY_ADDRESS - the bot falls back to EOA mode and trades from the signer`,
+      fix: "Set POLYMARKET_PROXY_ADDRESS to the address shown on your Polymarket profile",
+    });
+    return;
+  }
+  if (signer && funder) {
+    const mismatch = checkFunderAddress(signer, funder, signatureType);
+    if (mismatch) {
+      findings.push({
+        code: mismatch.code,
+        severity: "CRITICAL",
+        message: mismatch.message,
+        fix:
+          mismatch.matchingSignatureType !== null
+            ? `Set POLYMARKET_SIGNATURE_TYPE=${mismatch.matchingSignatureType}`
+            : `Use the key of the account that owns ${funder}, or set POLYMARKET_PROXY_ADDRESS=${signatureType === 1 ? mismatch.expectedProxy : mismatch.expectedSafe}`,
+      });
+    }
+  }
+}
+
+/**
+ * Soft open-file limit from /proc/self/limits
+ */
+export function checkOpenFileLimit(limits: string): DoctorFinding | null {
+  const match = limits.match(/^Max open files\s+(\S+)\s+(\S+)/m);
+  if (!match || match[1] === "unlimited") return null;
+  const soft = Number(match[1]);
+  if (!Number.isFinite(soft) || soft >= MIN_OPEN_FILES) return null;
+  return {
+    code: "NOFILE_LIMIT_LOW",
+    severity: "WARNING",
+    message: `Open-file limit is ${soft} - sockets fail with EMFILE under load`,
+    fix: `Raise it to at least ${MIN_OPEN_FILES} (ulimit -n ${MIN_OPEN_FILES}, or nofile in the container/service config)`,
+  };
+}
+
+/**
+ * Local clock vs the CLOB's (positive = local clock ahead)
+ */
+export function checkClockSkew(skewMs: number): DoctorFinding | null {
+  const abs = Math.abs(skewMs);
+  if (abs < CLOCK_SKEW_WARN_MS) return null;
+  return {
+    code: "CLOCK_SKEW",
+    severity: abs >= CLOCK_SKEW_CRITICAL_MS ? "CRITICAL" : "WARNING",
+    message: `Local clock is ${(abs / 1000).toFixed(1)}s ${skewMs > 0 ? "ahead of" : "behind"} the CLOB - signed requests can be rejected`,
+    fix: "Enable NTP time sync (timedatectl set-ntp true) on the host",
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DOCTOR
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Run every check. Never throws - what can't be checked is an INFO finding.
+ */
+export async function runDoctor(
+  options: DoctorOptions = {},
+): Promise<DoctorReport> {
+  const env = options.env ?? process.env;
+  const findings = diagnoseEnv(env);
+
+  const limits = (options.readLimits ?? readProcLimits)();
+  const limitFinding = limits ? checkOpenFileLimit(limits) : null;
+  if (limitFinding) findings.push(limitFinding);
+
+  const started = Date.now();
+  const serverTime = await (options.fetchServerTime ?? fetchClobTime)();
+  if (serverTime === null) {
+    findings.push({
+      code: "CLOCK_UNCHECKED",
+      severity: "INFO",
+      message: "Couldn't reach the CLOB to compare clocks",
+      fix: "Check network/VPN access to clob.polymarket.com",
+    });
+  } else {
+    // Compare against the middle of the round trip
+    const local = (started + Date.now()) / 2;
+    const clockFinding = checkClockSkew(local - serverTime);
+    if (clockFinding) findings.push(clockFinding);
+  }
+
+  findings.sort(
+    (a, b) => SEVERITY_ORDER[a.severity] - SEVERITY_ORDER[b.severity],
+  );
+  return {
+    ok: findings.every((f) => f.severity !== "CRITICAL"),
+    findings,
+    checkedAt: new Date().toISOString(),
+  };
+}
+
+function readProcLimits(): string | null {
+  try {
+    return fs.readFileSync("/proc/self/limits", "utf8");
+  } catch {
+    return null;
+  }
+}
+
+async function fetchClobTime(): Promise<number | null> {
+  try {
+    const { data } = await axios.get(`${POLYMARKET_API.CLOB}/time`, {
+      timeout: 5000,
+    });
+    const seconds = Number(data);
+    return Number.isFinite(seconds) && seconds > 0 ? seconds * 1000 : null;
+  } catch {
+    return null;
+  }
+}
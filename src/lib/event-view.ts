@@ -0,0 +1,170 @@
+/**
+ * Event View - All markets under one Polymarket event, priced together
+ *
+ * Multi-outcome events ("Who wins the election?") are a set of binary
+ * markets, exactly one of which resolves YES. Looking at them together
+ * answers two questions single-market code can't:
+ *
+ * - Neg-risk arb: the YES asks should sum to ~1. A sum below 1 means every
+ *   outcome can be bought for less than the guaranteed payout; YES bids
+ *   summing above 1 means the reverse.
+ * - Exposure: positions in sibling markets are one bet, not several.
+ *
+ * Prices come from the live book mirror when fresh, otherwise from Gamma's
+ * bestBid/bestAsk for the market.
+ */
+
+import axios from "axios";
+import { POLYMARKET_API } from "./constants";
+import { getMarketDataStore } from "./market-data-store";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface EventQuote {
+  bestBid: number;
+  bestAsk: number;
+}
+
+/** Book quote for a token (null = no usable book) */
+export type EventQuoteSource = (tokenId: string) => Promise<EventQuote | null>;
+
+export interface EventMarketView {
+  conditionId: string;
+  question: string;
+  yesTokenId: string;
+  noTokenId: string;
+  /** YES best bid/ask (0-1, null = no price) */
+  bestBid: number | null;
+  bestAsk: number | null;
+  mid: number | null;
+  source: "BOOK" | "GAMMA" | "NONE";
+}
+
+export interface EventView {
+  eventId: string;
+  title: string;
+  negRisk: boolean;
+  markets: EventMarketView[];
+  /** Sum of YES asks - cost to buy every outcome (null if any is missing) */
+  sumAsks: number | null;
+  /** Sum of YES bids - proceeds from selling every outcome */
+  sumBids: number | null;
+  /** Sum of YES mids - the event's total implied probability */
+  sumMids: number | null;
+}
+
+/** The subset of a Gamma event the view reads */
+export interface GammaEventDetail {
+  id?: string | number;
+  title?: string;
+  negRisk?: boolean;
+  markets?: {
+    conditionId?: string;
+    question?: string;
+    groupItemTitle?: string;
+    clobTokenIds?: string;
+    bestBid?: number | string;
+    bestAsk?: number | string;
+    closed?: boolean;
+  }[];
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VIEW
+// ═══════════════════════════════════════════════════════════════════════════
+
+function toPrice(value: unknown): number | null {
+  const n = typeof value === "number" ? value : parseFloat(String(value));
+  return Number.isFinite(n) && n > 0 && n < 1 ? n : null;
+}
+
+function sumOrNull(values: (number | null)[]): number | null {
+  if (values.length === 0 || values.some((v) => v === null)) return null;
+  return values.reduce<number>((sum, v) => sum + v!, 0);
+}
+
+/**
+ * Live book quote from the market data store, when fresh
+ */
+export const storeQuoteSource: EventQuoteSource = async (tokenId) => {
+  const store = getMarketDataStore();
+  const data = store.get(tokenId);
+  if (!data || store.isStale(tokenId)) return null;
+  return { bestBid: data.bestBid, bestAsk: data.bestAsk };
+};
+
+/**
+ * Build the view for a Gamma event (closed markets are left out)
+ */
+export async function buildEventView(
+  event: GammaEventDetail,
+  quote: EventQuoteSource = storeQuoteSource,
+): Promise<EventView> {
+  const markets: EventMarketView[] = [];
+
+  for (const market of event.markets ?? []) {
+    if (!market.conditionId || market.closed) continue;
+    let tokenIds: string[] = [];
+    try {
+      const parsed = JSON.parse(market.clobTokenIds || "[]");
+      if (Array.isArray(parsed)) tokenIds = parsed.map(String);
+    } catch {
+      // Priced from Gamma only
+    }
+    const [yesTokenId = "", noTokenId = ""] = tokenIds;
+
+    const book = yesTokenId ? await quote(yesTokenId) : null;
+    const bestBid = book ? book.bestBid : toPrice(market.bestBid);
+    const bestAsk = book ? book.bestAsk : toPrice(market.bestAsk);
+    markets.push({
+      conditionId: market.conditionId,
+      question: market.groupItemTitle || market.question || "",
+      yesTokenId,
+      noTokenId,
+      bestBid,
+      bestAsk,
+      mid:
+        bestBid !== null && bestAsk !== null ? (bestBid + bestAsk) / 2 : null,
+      source: book
+        ? "BOOK"
+        : bestBid !== null || bestAsk !== null
+          ? "GAMMA"
+          : "NONE",
+    });
+  }
+
+  return {
+    eventId: String(event.id ?? ""),
+    title: event.title ?? "",
+    negRisk: event.negRisk === true,
+    markets,
+    sumAsks: sumOrNull(markets.map((m) => m.bestAsk)),
+    sumBids: sumOrNull(markets.map((m) => m.bestBid)),
+    sumMids: sumOrNull(markets.map((m) => m.mid)),
+  };
+}
+
+/**
+ * Fetch an event from Gamma and build its view
+ * @returns null if the event couldn't be fetched
+ */
+export async function fetchEventView(
+  eventId: string,
+  quote: EventQuoteSource = storeQuoteSource,
+): Promise<EventView | null> {
+  try {
+    const url = `${POLYMARKET_API.GAMMA}/events/${encodeURIComponent(eventId)}`;
+    const { data } = await axios.get<GammaEventDetail>(url, {
+      timeout: 10000,
+    });
+    if (!data || typeof data !== "object") return null;
+    return await buildEventView(data, quote);
+  } catch (err) {
+    console.warn(
+      `[EventView] Failed to fetch event ${eventId}: ${err instanceof Error ? err.message : err}`,
+    );
+    return null;
+  }
+}
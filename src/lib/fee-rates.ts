@@ -0,0 +1,126 @@
+/**
+ * Fee Rates - Per-market maker/taker fee lookup and caching
+ *
+ * Polymarket fee rates vary by market, so a single global churn estimate
+ * under- or over-counts costs. This module resolves the fee rate (bps) for a
+ * token and caches it:
+ * - Taker: CLOB /fee-rate (the rate orders are actually signed with),
+ *   falling back to the Gamma market's takerBaseFee
+ * - Maker: the Gamma market's makerBaseFee
+ * - Unknown rates default to 0 (most markets are fee-free)
+ *
+ * Fees are charged on the smaller side of the price:
+ *   fee per share = rate * min(price, 1 - price)
+ */
+
+import axios from "axios";
+import { POLYMARKET_API } from "./constants";
+import { fetchMarketByTokenId } from "./market";
+import { createBoundedCache } from "../infra/persistence/lru-cache";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface FeeRates {
+  makerBps: number;
+  takerBps: number;
+}
+
+/** Fee rates used when a market publishes none */
+export const ZERO_FEE_RATES: FeeRates = { makerBps: 0, takerBps: 0 };
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CACHE
+// ═══════════════════════════════════════════════════════════════════════════
+
+// Fee rates change rarely; refresh hourly like the market cache
+const FEE_RATE_TTL_MS = 60 * 60 * 1000;
+
+const feeRateCache = createBoundedCache<FeeRates>("FeeRateCache", {
+  ttlMs: FEE_RATE_TTL_MS,
+});
+
+/**
+ * Fetch the taker fee rate the CLOB signs orders with (null on failure)
+ */
+async function fetchClobFeeRateBps(tokenId: string): Promise<number | null> {
+  try {
+    const url = `${POLYMARKET_API.CLOB}/fee-rate?token_id=${tokenId}`;
+    const { data } = await axios.get<{ base_fee?: number | string }>(url, {
+      timeout: 10000,
+    });
+    const bps = Number(data?.base_fee);
+    return data?.base_fee != null && Number.isFinite(bps) ? bps : null;
+  } catch {
+    return null;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// LOOKUP
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Resolve maker/taker fee rates for a token (cached)
+ */
+export async function getFeeRates(tokenId: string): Promise<FeeRates> {
+  const cached = feeRateCache.get(tokenId);
+  if (cached) return cached;
+
+  const [clobTakerBps, market] = await Promise.all([
+    fetchClobFeeRateBps(tokenId),
+    fetchMarketByTokenId(tokenId),
+  ]);
+
+  const rates: FeeRates = {
+    makerBps: market?.makerFeeBps ?? 0,
+    takerBps: clobTakerBps ?? market?.takerFeeBps ?? 0,
+  };
+  feeRateCache.set(tokenId, rates);
+  return rates;
+}
+
+/**
+ * Get cached fee rates without fetching (null if not resolved yet)
+ */
+export function getCachedFeeRates(tokenId: string): FeeRates | null {
+  return feeRateCache.get(tokenId) ?? null;
+}
+
+/**
+ * Clear the fee rate cache (for testing)
+ */
+export function clearFeeRateCache(): void {
+  feeRateCache.clear();
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FEE MATH
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Fee per share in cents for one fill
+ *
+ * @param priceCents - Fill price in cents (0-100)
+ * @param feeBps - Fee rate in basis points
+ */
+export function estimateFeeCents(priceCents: number, feeBps: number): number {
+  if (!Number.isFinite(priceCents) || !(feeBps > 0)) return 0;
+  const p = Math.max(0, Math.min(100, priceCents));
+  return (feeBps / 10_000) * Math.min(p, 100 - p);
+}
+
+/**
+ * Round-trip taker fees per share in cents (entry fill + exit fill)
+ */
+export function estimateRoundTripFeeCents(
+  entryPriceCents: number,
+  exitPriceCents: number,
+  rates: FeeRates,
+): number {
+  return (
+    estimateFeeCents(entryPriceCents, rates.takerBps) +
+    estimateFeeCents(exitPriceCents, rates.takerBps)
+  );
+}
@@ -0,0 +1,150 @@
+/**
+ * Funder Check - Is the funder address really the signer's wallet?
+ *
+ * In proxy (1) and Safe (2) mode, orders are signed by the EOA on behalf
+ * of the funder address. A funder that belongs to a different account -
+ * copied from another login, or the right address with the wrong
+ * signature type - still gets API keys derived, but every order is
+ * rejected as unauthorized (401) and balances read 0, with nothing
+ * pointing at the cause.
+ *
+ * Both wallet kinds are deployed with CREATE2 from fixed factories, so
+ * the funder a signer can use is derivable from the signer alone:
+ *
+ *   Proxy (1)  PROXY_WALLET_FACTORY, salt keccak256(signer)
+ *   Safe  (2)  SAFE_WALLET_FACTORY,  salt keccak256(abi.encode(signer))
+ *
+ * A funder that isn't the derived wallet for the configured signature
+ * type is reported as FUNDER_MISMATCH, saying which type it does match if
+ * it matches the other one. Wallets deployed outside these factories
+ * can't be derived, so this warns and never blocks.
+ */
+
+import {
+  AbiCoder,
+  getAddress,
+  getCreate2Address,
+  keccak256,
+  solidityPacked,
+} from "ethers";
+import { POLYGON } from "./constants";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export const FUNDER_MISMATCH = "FUNDER_MISMATCH";
+
+/** Init code hashes of the wallets the factories deploy */
+const PROXY_WALLET_INIT_CODE_HASH =
+  "0xd21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b";
+const SAFE_WALLET_INIT_CODE_HASH =
+  "0x2bce2127ff07fb632d16c8347c4ebf501f4841168bed00d9e6ef715ddb6fcecf";
+
+export interface FunderDiagnostic {
+  code: typeof FUNDER_MISMATCH;
+  signerAddress: string;
+  funderAddress: string;
+  /** Configured signature type (1 = Proxy, 2 = Safe) */
+  signatureType: number;
+  /** The signer's proxy and Safe wallets */
+  expectedProxy: string;
+  expectedSafe: string;
+  /** Signature type the funder does belong to (null = neither) */
+  matchingSignatureType: 1 | 2 | null;
+  message: string;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DERIVATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * The Polymarket proxy wallet (signatureType=1) of a signer
+ */
+export function deriveProxyWallet(signerAddress: string): string {
+  const salt = keccak256(
+    solidityPacked(["address"], [getAddress(signerAddress)]),
+  );
+  return getCreate2Address(
+    POLYGON.PROXY_WALLET_FACTORY,
+    salt,
+    PROXY_WALLET_INIT_CODE_HASH,
+  );
+}
+
+/**
+ * The Polymarket Safe wallet (signatureType=2) of a signer
+ */
+export function deriveSafeWallet(signerAddress: string): string {
+  const salt = keccak256(
+    AbiCoder.defaultAbiCoder().encode(
+      ["address"],
+      [getAddress(signerAddress)],
+    ),
+  );
+  return getCreate2Address(
+    POLYGON.SAFE_WALLET_FACTORY,
+    salt,
+    SAFE_WALLET_INIT_CODE_HASH,
+  );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHECK
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Check the funder against the signer's derived wallets
+ *
+ * @returns A FUNDER_MISMATCH diagnostic, or null if the funder is the
+ *   signer's wallet for the signature type (or no funder is used)
+ */
+export function checkFunderAddress(
+  signerAddress: string,
+  funderAddress: string | undefined,
+  signatureType: number,
+): FunderDiagnostic | null {
+  if (signatureType <= 0 || !funderAddress) return null;
+
+  const funder = funderAddress.toLowerCase();
+  const expectedProxy = deriveProxyWallet(signerAddress);
+  const expectedSafe = deriveSafeWallet(signerAddress);
+  const matchingSignatureType =
+    funder === expectedProxy.toLowerCase()
+      ? 1
+      : funder === expectedSafe.toLowerCase()
+        ? 2
+        : null;
+  if (matchingSignatureType === signatureType) return null;
+
+  let message: string;
+  if (matchingSignatureType !== null) {
+    message =
+      `funder ${funderAddress} is the signer's ` +
+      `${matchingSignatureType === 1 ? "proxy" : "Safe"} wallet but ` +
+      `POLYMARKET_SIGNATURE_TYPE=${signatureType} - set it to ` +
+      `${matchingSignatureType}`;
+  } else if (funder === signerAddress.toLowerCase()) {
+    message =
+      `funder ${funderAddress} is the signer itself - use ` +
+      `POLYMARKET_SIGNATURE_TYPE=0 (EOA) or set the proxy/Safe address`;
+  } else {
+    message =
+      `funder ${funderAddress} is not a wallet of signer ` +
+      `${getAddress(signerAddress)} (proxy ${expectedProxy}, Safe ` +
+      `${expectedSafe}) - orders will be rejected (401) and balances ` +
+      `read 0`;
+  }
+
+  return {
+    code: FUNDER_MISMATCH,
+    signerAddress: getAddress(signerAddress),
+    funderAddress,
+    signatureType,
+    expectedProxy,
+    expectedSafe,
+    matchingSignatureType,
+    message,
+  };
+}
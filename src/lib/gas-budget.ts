@@ -0,0 +1,175 @@
+/**
+ * Gas Budget - Token bucket for on-chain transaction spend
+ *
+ * Caps the POL the bot may spend on gas per day (approvals, redeems, POL
+ * reserve swaps, token transfers) so a misbehaving loop can't drain the gas reserve:
+ *
+ *   GAS_BUDGET_DAILY_POL=2
+ *
+ * The bucket holds up to one day's budget and refills continuously. Before a
+ * transaction is sent its worst-case cost (gas estimate * maxFeePerGas) is
+ * reserved; once the receipt arrives the reservation is settled to the
+ * actual fee. Transactions that don't fit are refused with
+ * GAS_BUDGET_EXCEEDED - redeems are picked up again on the next redeem run,
+ * once the bucket has refilled.
+ *
+ * Budget 0 (the default) disables the limit.
+ */
+
+import { envNum } from "../config/env";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type GasTxKind =
+  | "approve"
+  | "redeem"
+  | "swap"
+  | "unwrap"
+  | "transfer";
+
+/** Conservative gas limits used to reserve budget before sending */
+export const GAS_ESTIMATES: Record<GasTxKind, bigint> = {
+  approve: 60_000n,
+  redeem: 300_000n,
+  swap: 250_000n,
+  unwrap: 60_000n,
+  transfer: 120_000n,
+};
+
+export const GAS_BUDGET_EXCEEDED = "GAS_BUDGET_EXCEEDED";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+export interface GasReservation {
+  kind: GasTxKind;
+  reservedPol: number;
+}
+
+export interface GasBudgetStatus {
+  enabled: boolean;
+  dailyBudgetPol: number;
+  availablePol: number;
+  spentTodayPol: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// HELPERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Worst-case POL cost of a transaction (gas units * max fee per gas in wei)
+ */
+export function estimateTxCostPol(gasUnits: bigint, maxFeeWei: bigint): number {
+  return Number(gasUnits * maxFeeWei) / 1e18;
+}
+
+/**
+ * Actual POL fee paid by a mined transaction (ethers v6 receipt.fee, wei)
+ */
+export function receiptFeePol(receipt: { fee?: bigint } | null): number {
+  return Number(receipt?.fee ?? 0n) / 1e18;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// GAS BUDGET
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class GasBudget {
+  private availablePol: number;
+  private lastRefillAt: number;
+  private spent: { pol: number; at: number }[] = [];
+
+  constructor(
+    private readonly dailyBudgetPol: number,
+    now = Date.now(),
+  ) {
+    this.availablePol = dailyBudgetPol;
+    this.lastRefillAt = now;
+  }
+
+  isEnabled(): boolean {
+    return this.dailyBudgetPol > 0;
+  }
+
+  /**
+   * Reserve budget for a transaction before sending it
+   * @returns The reservation, or null if the budget can't cover it
+   */
+  reserve(
+    kind: GasTxKind,
+    costPol: number,
+    now = Date.now(),
+  ): GasReservation | null {
+    if (!this.isEnabled()) return { kind, reservedPol: 0 };
+
+    this.refill(now);
+    if (costPol > this.availablePol) {
+      console.warn(
+        `⛽ [GasBudget] ${GAS_BUDGET_EXCEEDED}: ${kind} needs ~${costPol.toFixed(4)} POL, ${this.availablePol.toFixed(4)} of ${this.dailyBudgetPol} POL/day left`,
+      );
+      return null;
+    }
+    this.availablePol -= costPol;
+    return { kind, reservedPol: costPol };
+  }
+
+  /**
+   * Settle a reservation to the actual fee (refunds the difference). Pass
+   * no fee when the transaction was never sent.
+   */
+  settle(reservation: GasReservation, actualPol = 0, now = Date.now()): void {
+    if (!this.isEnabled()) return;
+
+    this.refill(now);
+    this.availablePol = Math.min(
+      this.dailyBudgetPol,
+      this.availablePol + reservation.reservedPol - actualPol,
+    );
+    if (actualPol > 0) this.spent.push({ pol: actualPol, at: now });
+  }
+
+  getStatus(now = Date.now()): GasBudgetStatus {
+    this.refill(now);
+    this.spent = this.spent.filter((s) => now - s.at < DAY_MS);
+    return {
+      enabled: this.isEnabled(),
+      dailyBudgetPol: this.dailyBudgetPol,
+      availablePol: this.availablePol,
+      spentTodayPol: this.spent.reduce((sum, s) => sum + s.pol, 0),
+    };
+  }
+
+  private refill(now: number): void {
+    const elapsed = Math.max(0, now - this.lastRefillAt);
+    this.availablePol = Math.min(
+      this.dailyBudgetPol,
+      this.availablePol + (this.dailyBudgetPol * elapsed) / DAY_MS,
+    );
+    this.lastRefillAt = now;
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: GasBudget | null = null;
+
+/**
+ * Get the global gas budget (created from GAS_BUDGET_DAILY_POL on first use)
+ */
+export function getGasBudget(): GasBudget {
+  if (!instance) {
+    instance = new GasBudget(envNum("GAS_BUDGET_DAILY_POL", 0));
+  }
+  return instance;
+}
+
+/**
+ * Replace the global gas budget (tests / runtime reconfiguration)
+ */
+export function setGasBudget(budget: GasBudget): void {
+  instance = budget;
+}
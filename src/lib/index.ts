@@ -132,3 +132,78 @@ export * from "./market-cooldown";
 
 // Orderbook normalization utilities
 export * from "./orderbook-utils";
+
+// Market allow/deny lists for new entries
+export * from "./market-filter";
+
+// Per-market maker/taker fee rates
+export * from "./fee-rates";
+
+// Stale resting order sweeper
+export * from "./order-sweeper";
+
+// Native USDC vs bridged USDC.e detection and swap
+export * from "./usdc-bridge";
+
+// Daily gas budget for approvals/redeems/swaps
+export * from "./gas-budget";
+
+// Private relay submission for on-chain transactions
+export * from "./private-tx";
+
+// Address labels for logs (whales, own wallets, contracts)
+export * from "./address-book";
+
+// Replay orders against recorded book snapshots (post-mortems)
+export * from "./replay-fill";
+
+// Local full-text search over market questions
+export * from "./market-search";
+
+// Gamma tag taxonomy (stable tag IDs, per-tag market aggregates)
+export * from "./tag-taxonomy";
+
+// Event-level view across sibling markets (neg-risk pricing, exposure)
+export * from "./event-view";
+
+// Exchange approvals (USDC.e allowance, CTF operator approval)
+export * from "./approvals";
+
+// Minimal place-and-cancel order to confirm trade permission
+export * from "./test-trade";
+
+// Guided first-run check (credentials, balances, approvals, test order)
+export * from "./bootstrap";
+
+// Environment inspection with a prioritized fix list
+export * from "./doctor";
+
+// Retry auth-rejected orders with an alternate signature type
+export * from "./auth-fallback";
+
+// Credential derivation attempt stats and backoff
+export * from "./auth-stats";
+
+// Funder address vs the signer's derived proxy/Safe wallets
+export * from "./funder-check";
+
+// Held-shares check before SELL orders
+export * from "./share-check";
+
+// Explicit USDC vs share order sizing
+export * from "./order-size";
+
+// Minimal cancel/post diff for a full quote refresh
+export * from "./requote";
+
+// ERC-1155 outcome-token transfers between EOA and proxy
+export * from "./token-transfer";
+
+// Parallel book fetches for scan candidates
+export * from "./concurrent-scan";
+
+// Post-order visibility check (ACK_NOT_VISIBLE)
+export * from "./ack-check";
+
+// Cross-run API credential cache and 401 re-derivation
+export * from "./clob-session";
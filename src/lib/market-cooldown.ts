@@ -11,7 +11,8 @@ export type MarketDataFailureReason =
   | "PARSE_ERROR" // Response parsing failed
   | "INVALID_LIQUIDITY" // Spread too wide (permanent market condition)
   | "DUST_BOOK" // 1¢/99¢ spreads - no room to trade (permanent)
-  | "INVALID_PRICES"; // Missing/zero/NaN prices (permanent)
+  | "INVALID_PRICES" // Missing/zero/NaN prices (permanent)
+  | "STALE_BOOK"; // Book too old or crossed to trade against (transient)
 
 /** Check if failure reason warrants long cooldown (market legitimately inactive) */
 export function shouldApplyLongCooldown(
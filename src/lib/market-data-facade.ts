@@ -20,6 +20,7 @@ import {
   type TokenMarketData,
   type OrderbookLevel,
   type MarketDataMode,
+  type BookTradability,
 } from "./market-data-store";
 import { isDeadBook } from "./price-safety";
 import {
@@ -397,6 +398,18 @@ export class MarketDataFacade {
     return store.has(tokenId) && !store.isStale(tokenId);
   }
 
+  /**
+   * Check whether the current book for a token is safe to trade against.
+   * Call after getOrderbookState() so stale data has had a chance to refresh;
+   * a book that is still too old or is crossed returns STALE_BOOK.
+   */
+  checkBookTradable(
+    tokenId: string,
+    maxAgeMs: number = POLYMARKET_WS.MAX_TRADE_BOOK_AGE_MS,
+  ): BookTradability {
+    return getMarketDataStore().checkTradable(tokenId, maxAgeMs);
+  }
+
   /**
    * Get current market data mode
    */
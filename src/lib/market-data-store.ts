@@ -8,6 +8,10 @@
  * - Stores best bid/ask/mid/spread per tokenId
  * - Optional shallow depth tracking
  * - Staleness detection per tokenId
+ * - Crossed-book guard (crossed WS updates are dropped, not mirrored)
+ * - REST snapshot validation (counts WS/REST divergence)
+ * - Recent trade prints per tokenId (last N executions + live listeners)
+ * - Recent L2 book history per tokenId (for replaying fills after the fact)
  * - Memory protection (max tokens cap)
  * - Deduplication of updates
  */
@@ -45,6 +49,30 @@ export interface OrderbookSnapshot {
   timestamp: number;
 }
 
+/** A single executed trade (from the WS last_trade_price event) */
+export interface TradePrint {
+  tokenId: string;
+  price: number;
+  size: number;
+  side: "BUY" | "SELL";
+  timestamp: number; // Unix timestamp ms
+}
+
+/** Recorded book history and trade prints per tokenId (for fill replay) */
+export interface MarketDataHistory {
+  books: Record<string, OrderbookSnapshot[]>;
+  trades: Record<string, TradePrint[]>;
+}
+
+/** Listener for streamed trade prints */
+export type TradeListener = (trade: TradePrint) => void;
+
+/** Listener for top-of-book updates (WS or REST) */
+export type QuoteListener = (data: TokenMarketData) => void;
+
+/** Listener for every stored L2 book, including size-only changes */
+export type BookListener = (book: OrderbookSnapshot) => void;
+
 /** Market data mode for observability */
 export type MarketDataMode = "WS_OK" | "WS_STALE_FALLBACK" | "REST_ONLY";
 
@@ -57,8 +85,17 @@ export interface StoreMetrics {
   mode: MarketDataMode;
   oldestUpdateMs: number;
   newestUpdateMs: number;
+  /** WS updates dropped because best bid >= best ask */
+  crossedRejects: number;
+  /** REST snapshots whose top of book disagreed with the WS mirror */
+  restDivergences: number;
 }
 
+/** Result of checking whether a book is safe to trade against */
+export type BookTradability =
+  | { ok: true; data: TokenMarketData }
+  | { ok: false; reason: "STALE_BOOK"; detail: string };
+
 // ============================================================================
 // MarketDataStore Implementation
 // ============================================================================
@@ -66,24 +103,39 @@ export interface StoreMetrics {
 export class MarketDataStore {
   private store = new Map<string, TokenMarketData>();
   private orderbooks = new Map<string, OrderbookSnapshot>();
+  private bookHistory = new Map<string, OrderbookSnapshot[]>();
+  private trades = new Map<string, TradePrint[]>();
+  private tradeListeners = new Set<TradeListener>();
+  private quoteListeners = new Set<QuoteListener>();
+  private bookListeners = new Set<BookListener>();
   private accessOrder: string[] = []; // LRU tracking
   private wsUpdates = 0;
   private restFallbacks = 0;
+  private crossedRejects = 0;
+  private restDivergences = 0;
   private wsConnected = false;
 
   private readonly maxTokens: number;
   private readonly staleMs: number;
   private readonly depthWindowCents: number;
+  private readonly maxTradesPerToken: number;
+  private readonly maxBookHistory: number;
 
   constructor(options?: {
     maxTokens?: number;
     staleMs?: number;
     depthWindowCents?: number;
+    maxTradesPerToken?: number;
+    maxBookHistory?: number;
   }) {
     this.maxTokens = options?.maxTokens ?? POLYMARKET_WS.MAX_TOKENS;
     this.staleMs = options?.staleMs ?? POLYMARKET_WS.STALE_MS;
     this.depthWindowCents =
       options?.depthWindowCents ?? POLYMARKET_WS.DEPTH_WINDOW_CENTS;
+    this.maxTradesPerToken =
+      options?.maxTradesPerToken ?? POLYMARKET_WS.MAX_TRADES_PER_TOKEN;
+    this.maxBookHistory =
+      options?.maxBookHistory ?? POLYMARKET_WS.MAX_BOOK_HISTORY_PER_TOKEN;
   }
 
   // ═══════════════════════════════════════════════════════════════════════════
@@ -149,6 +201,45 @@ export class MarketDataStore {
     return count;
   }
 
+  /**
+   * Check if the stored book for a token is crossed (bid >= ask)
+   */
+  isCrossed(tokenId: string): boolean {
+    const data = this.store.get(tokenId);
+    if (!data) return false;
+    return data.bestBid >= data.bestAsk;
+  }
+
+  /**
+   * Check whether the mirrored book is safe to trade against.
+   * Refuses books older than maxAgeMs or crossed books, so we never size
+   * an order off phantom liquidity.
+   */
+  checkTradable(tokenId: string, maxAgeMs: number): BookTradability {
+    const data = this.store.get(tokenId);
+    if (!data) {
+      return { ok: false, reason: "STALE_BOOK", detail: "no book" };
+    }
+
+    const ageMs = Date.now() - data.updatedAt;
+    if (ageMs > maxAgeMs) {
+      return {
+        ok: false,
+        reason: "STALE_BOOK",
+        detail: `book age ${ageMs}ms > ${maxAgeMs}ms`,
+      };
+    }
+    if (data.bestBid >= data.bestAsk) {
+      return {
+        ok: false,
+        reason: "STALE_BOOK",
+        detail: `crossed book (bid ${data.bestBid} >= ask ${data.bestAsk})`,
+      };
+    }
+
+    return { ok: true, data };
+  }
+
   /**
    * Get full L2 orderbook for a token (if available)
    */
@@ -156,6 +247,105 @@ export class MarketDataStore {
     return this.orderbooks.get(tokenId) ?? null;
   }
 
+  /**
+   * Get the L2 orderbook as it stood at a point in time: the latest recorded
+   * snapshot taken at or before `at` (ms)
+   * @returns Snapshot or null if none was recorded that early
+   */
+  getOrderbookAt(tokenId: string, at: number): OrderbookSnapshot | null {
+    const history = this.bookHistory.get(tokenId) ?? [];
+    for (let i = history.length - 1; i >= 0; i--) {
+      if (history[i].timestamp <= at) return history[i];
+    }
+    return null;
+  }
+
+  /**
+   * Get the most recent trade prints for a token, newest first
+   */
+  getLastTrades(tokenId: string, limit = 50): TradePrint[] {
+    const prints = this.trades.get(tokenId);
+    if (!prints) return [];
+    return prints.slice(-limit).reverse();
+  }
+
+  /**
+   * Copy of the recorded book history and trade prints, oldest first
+   */
+  exportHistory(): MarketDataHistory {
+    const history: MarketDataHistory = { books: {}, trades: {} };
+    for (const [tokenId, books] of this.bookHistory) {
+      history.books[tokenId] = [...books];
+    }
+    for (const [tokenId, prints] of this.trades) {
+      history.trades[tokenId] = [...prints];
+    }
+    return history;
+  }
+
+  /**
+   * Recent price range for a token from the mirrored midpoint and the VWAP
+   * of trade prints, both limited to the last windowMs
+   * @returns { low, high } or null if there is no recent data
+   */
+  getRecentPriceRange(
+    tokenId: string,
+    windowMs: number,
+  ): { low: number; high: number } | null {
+    const cutoff = Date.now() - windowMs;
+    const refs: number[] = [];
+
+    const data = this.store.get(tokenId);
+    if (data && data.updatedAt >= cutoff && data.bestBid < data.bestAsk) {
+      refs.push(data.mid);
+    }
+
+    const recent = (this.trades.get(tokenId) ?? []).filter(
+      (t) => t.timestamp >= cutoff,
+    );
+    const volume = recent.reduce((sum, t) => sum + t.size, 0);
+    if (volume > 0) {
+      refs.push(recent.reduce((sum, t) => sum + t.price * t.size, 0) / volume);
+    }
+
+    if (refs.length === 0) return null;
+    return { low: Math.min(...refs), high: Math.max(...refs) };
+  }
+
+  /**
+   * Stream trade prints as they arrive
+   * @returns Unsubscribe function
+   */
+  onTrade(listener: TradeListener): () => void {
+    this.tradeListeners.add(listener);
+    return () => {
+      this.tradeListeners.delete(listener);
+    };
+  }
+
+  /**
+   * Stream top-of-book updates as they're stored
+   * @returns Unsubscribe function
+   */
+  onQuote(listener: QuoteListener): () => void {
+    this.quoteListeners.add(listener);
+    return () => {
+      this.quoteListeners.delete(listener);
+    };
+  }
+
+  /**
+   * Stream L2 books as they're stored - unlike quotes, also when only
+   * sizes changed
+   * @returns Unsubscribe function
+   */
+  onBook(listener: BookListener): () => void {
+    this.bookListeners.add(listener);
+    return () => {
+      this.bookListeners.delete(listener);
+    };
+  }
+
   // ═══════════════════════════════════════════════════════════════════════════
   // Public API - Write Operations
   // ═══════════════════════════════════════════════════════════════════════════
@@ -176,6 +366,13 @@ export class MarketDataStore {
     const bestBid = bids[0].price;
     const bestAsk = asks[0].price;
 
+    // A crossed book means we missed an update - drop it and let the
+    // existing entry age into staleness so the next read refreshes via REST
+    if (bestBid >= bestAsk) {
+      this.crossedRejects++;
+      return false;
+    }
+
     // Deduplication: check if data has actually changed
     const existing = this.store.get(tokenId);
     if (
@@ -184,9 +381,11 @@ export class MarketDataStore {
       existing.bestAsk === bestAsk &&
       existing.source === "WS"
     ) {
-      // Data hasn't changed, just update timestamp and LRU access order
+      // Top of book hasn't changed, just update timestamp and LRU access
+      // order - and the book, whose sizes may have
       existing.updatedAt = Date.now();
       this.touchToken(tokenId);
+      this.setOrderbook(tokenId, bids, asks);
       return false;
     }
 
@@ -210,14 +409,10 @@ export class MarketDataStore {
 
     this.setData(tokenId, data);
     this.wsUpdates++;
+    this.notifyQuote(data);
 
     // Store full orderbook for detailed analysis
-    this.orderbooks.set(tokenId, {
-      tokenId,
-      bids,
-      asks,
-      timestamp: Date.now(),
-    });
+    this.setOrderbook(tokenId, bids, asks);
 
     return true;
   }
@@ -239,6 +434,15 @@ export class MarketDataStore {
     const mid = (bestBid + bestAsk) / 2;
     const spreadCents = (bestAsk - bestBid) * 100;
 
+    // Validate the WS mirror against the REST snapshot
+    const existing = this.store.get(tokenId);
+    if (
+      existing?.source === "WS" &&
+      (existing.bestBid !== bestBid || existing.bestAsk !== bestAsk)
+    ) {
+      this.restDivergences++;
+    }
+
     const { bidDepthUsd, askDepthUsd } = this.calculateDepth(bids, asks, mid);
 
     const data: TokenMarketData = {
@@ -255,23 +459,65 @@ export class MarketDataStore {
 
     this.setData(tokenId, data);
     this.restFallbacks++;
+    this.notifyQuote(data);
 
     // Store full orderbook
-    this.orderbooks.set(tokenId, {
-      tokenId,
-      bids,
-      asks,
-      timestamp: Date.now(),
-    });
+    this.setOrderbook(tokenId, bids, asks);
 
     return true;
   }
 
+  /**
+   * Record an executed trade and notify listeners
+   */
+  recordTrade(trade: TradePrint): void {
+    let prints = this.trades.get(trade.tokenId);
+    if (!prints) {
+      // Memory protection - drop the oldest token's prints at capacity
+      if (this.trades.size >= this.maxTokens) {
+        const oldest = this.trades.keys().next().value;
+        if (oldest !== undefined) this.trades.delete(oldest);
+      }
+      prints = [];
+      this.trades.set(trade.tokenId, prints);
+    }
+
+    prints.push(trade);
+    if (prints.length > this.maxTradesPerToken) {
+      prints.splice(0, prints.length - this.maxTradesPerToken);
+    }
+
+    for (const listener of this.tradeListeners) {
+      try {
+        listener(trade);
+      } catch {
+        // A bad listener must not break the WS handler
+      }
+    }
+  }
+
+  /**
+   * Replace the book history and trade prints of the tokens in `history`
+   * (from exportHistory), keeping the newest within this store's limits
+   */
+  importHistory(history: MarketDataHistory): void {
+    const newest = <T>(items: T[], max: number) =>
+      items.slice(Math.max(0, items.length - max));
+    for (const [tokenId, books] of Object.entries(history.books ?? {})) {
+      this.bookHistory.set(tokenId, newest(books, this.maxBookHistory));
+    }
+    for (const [tokenId, prints] of Object.entries(history.trades ?? {})) {
+      this.trades.set(tokenId, newest(prints, this.maxTradesPerToken));
+    }
+  }
+
   /**
    * Remove a token from the store
    */
   remove(tokenId: string): boolean {
     this.orderbooks.delete(tokenId);
+    this.bookHistory.delete(tokenId);
+    this.trades.delete(tokenId);
     this.accessOrder = this.accessOrder.filter((id) => id !== tokenId);
     return this.store.delete(tokenId);
   }
@@ -282,9 +528,13 @@ export class MarketDataStore {
   clear(): void {
     this.store.clear();
     this.orderbooks.clear();
+    this.bookHistory.clear();
+    this.trades.clear();
     this.accessOrder = [];
     this.wsUpdates = 0;
     this.restFallbacks = 0;
+    this.crossedRejects = 0;
+    this.restDivergences = 0;
   }
 
   // ═══════════════════════════════════════════════════════════════════════════
@@ -342,6 +592,8 @@ export class MarketDataStore {
       mode: this.getMode(),
       oldestUpdateMs: this.store.size > 0 ? Date.now() - oldest : 0,
       newestUpdateMs: this.store.size > 0 ? Date.now() - newest : 0,
+      crossedRejects: this.crossedRejects,
+      restDivergences: this.restDivergences,
     };
   }
 
@@ -355,7 +607,9 @@ export class MarketDataStore {
         `Tokens: ${metrics.totalTokens} | ` +
         `Stale: ${metrics.staleTokens} | ` +
         `WS updates: ${metrics.wsUpdates} | ` +
-        `REST fallbacks: ${metrics.restFallbacks}`,
+        `REST fallbacks: ${metrics.restFallbacks} | ` +
+        `Crossed: ${metrics.crossedRejects} | ` +
+        `REST divergences: ${metrics.restDivergences}`,
     );
   }
 
@@ -443,6 +697,19 @@ export class MarketDataStore {
     return { bidDepthUsd, askDepthUsd };
   }
 
+  /**
+   * Notify quote listeners of a stored top-of-book update
+   */
+  private notifyQuote(data: TokenMarketData): void {
+    for (const listener of this.quoteListeners) {
+      try {
+        listener(data);
+      } catch {
+        // A bad listener must not break the WS handler
+      }
+    }
+  }
+
   /**
    * Set data with LRU eviction
    */
@@ -453,6 +720,7 @@ export class MarketDataStore {
       if (lruToken) {
         this.store.delete(lruToken);
         this.orderbooks.delete(lruToken);
+        this.bookHistory.delete(lruToken);
       }
     }
 
@@ -460,6 +728,36 @@ export class MarketDataStore {
     this.touchToken(tokenId);
   }
 
+  /**
+   * Store the current L2 book and append it to the token's history
+   */
+  private setOrderbook(
+    tokenId: string,
+    bids: OrderbookLevel[],
+    asks: OrderbookLevel[],
+  ): void {
+    const snapshot = { tokenId, bids, asks, timestamp: Date.now() };
+    this.orderbooks.set(tokenId, snapshot);
+    for (const listener of this.bookListeners) {
+      try {
+        listener(snapshot);
+      } catch {
+        // A bad listener must not break the WS handler
+      }
+    }
+
+    if (this.maxBookHistory <= 0) return;
+    let history = this.bookHistory.get(tokenId);
+    if (!history) {
+      history = [];
+      this.bookHistory.set(tokenId, history);
+    }
+    history.push(snapshot);
+    if (history.length > this.maxBookHistory) {
+      history.splice(0, history.length - this.maxBookHistory);
+    }
+  }
+
   /**
    * Update LRU access order
    */
@@ -495,6 +793,8 @@ export function initMarketDataStore(options?: {
   maxTokens?: number;
   staleMs?: number;
   depthWindowCents?: number;
+  maxTradesPerToken?: number;
+  maxBookHistory?: number;
 }): MarketDataStore {
   globalStore = new MarketDataStore(options);
   return globalStore;
@@ -0,0 +1,135 @@
+/**
+ * Market Filter - Config-driven allow/deny lists for new entries
+ *
+ * Restricts which markets the bot will BUY into, by condition ID or by CLOB
+ * market tag (e.g. deny "Sports", allow only "Politics"). Exits are never
+ * filtered - a position opened before a list changed must still be sellable.
+ *
+ * Rules (evaluated in order):
+ * 1. Condition ID on the deny list -> rejected
+ * 2. Any tag on the deny list -> rejected
+ * 3. Allow lists configured and the market matches neither -> rejected
+ *
+ * Tag matching is case-insensitive, and also matches the stable tag IDs and
+ * slugs from the synced tag taxonomy (see tag-taxonomy.ts). Configure via:
+ *   MARKET_ALLOW_IDS, MARKET_DENY_IDS, MARKET_ALLOW_TAGS, MARKET_DENY_TAGS
+ * (comma-separated)
+ */
+
+import { envList } from "../config/env";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface MarketFilterConfig {
+  allowConditionIds: string[];
+  denyConditionIds: string[];
+  allowTags: string[];
+  denyTags: string[];
+}
+
+/** The subset of a CLOB market the filter looks at */
+export interface FilterableMarket {
+  condition_id?: string;
+  /** Undefined when tags couldn't be fetched */
+  tags?: string[];
+  /** Synced tag IDs and slugs (undefined when not in the taxonomy) */
+  tagRefs?: string[];
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONFIG
+// ═══════════════════════════════════════════════════════════════════════════
+
+function normalize(values: string[]): string[] {
+  return values.map((v) => v.toLowerCase());
+}
+
+/**
+ * Load allow/deny lists from the environment
+ */
+export function loadMarketFilterConfig(): MarketFilterConfig {
+  return {
+    allowConditionIds: normalize(envList("MARKET_ALLOW_IDS")),
+    denyConditionIds: normalize(envList("MARKET_DENY_IDS")),
+    allowTags: normalize(envList("MARKET_ALLOW_TAGS")),
+    denyTags: normalize(envList("MARKET_DENY_TAGS")),
+  };
+}
+
+let activeConfig: MarketFilterConfig = loadMarketFilterConfig();
+
+/**
+ * Get the active filter config
+ */
+export function getMarketFilterConfig(): MarketFilterConfig {
+  return activeConfig;
+}
+
+/**
+ * Replace the active filter config (lists are normalized to lowercase)
+ */
+export function setMarketFilterConfig(config: MarketFilterConfig): void {
+  activeConfig = {
+    allowConditionIds: normalize(config.allowConditionIds),
+    denyConditionIds: normalize(config.denyConditionIds),
+    allowTags: normalize(config.allowTags),
+    denyTags: normalize(config.denyTags),
+  };
+}
+
+/**
+ * Whether any list is configured
+ */
+export function isMarketFilterActive(
+  config: MarketFilterConfig = activeConfig,
+): boolean {
+  return (
+    config.allowConditionIds.length > 0 ||
+    config.denyConditionIds.length > 0 ||
+    config.allowTags.length > 0 ||
+    config.denyTags.length > 0
+  );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FILTER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Check a market against the allow/deny lists.
+ *
+ * Returns why the market is not allowed, or null if it is. When an allow-tag
+ * list is configured and the market's tags are unknown, the market is
+ * rejected (fail closed); deny-tag lists pass unknown tags.
+ */
+export function getMarketFilterReason(
+  market: FilterableMarket,
+  config: MarketFilterConfig = activeConfig,
+): string | null {
+  const conditionId = market.condition_id?.toLowerCase();
+  const tags =
+    market.tags || market.tagRefs
+      ? normalize([...(market.tags ?? []), ...(market.tagRefs ?? [])])
+      : undefined;
+
+  if (conditionId && config.denyConditionIds.includes(conditionId)) {
+    return "condition id denied";
+  }
+
+  const deniedTag = tags?.find((t) => config.denyTags.includes(t));
+  if (deniedTag) return `tag denied: ${deniedTag}`;
+
+  const hasAllowLists =
+    config.allowConditionIds.length > 0 || config.allowTags.length > 0;
+  if (!hasAllowLists) return null;
+
+  if (conditionId && config.allowConditionIds.includes(conditionId)) {
+    return null;
+  }
+  if (tags?.some((t) => config.allowTags.includes(t))) return null;
+
+  if (config.allowTags.length > 0 && !tags) return "tags unknown";
+  return "not on allow list";
+}
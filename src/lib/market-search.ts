@@ -0,0 +1,266 @@
+/**
+ * Market Search - Local full-text index over market questions
+ *
+ * Gamma's search is slow and rate-limited, which hurts during scans. Active
+ * markets are pulled from Gamma in pages and indexed in memory (question,
+ * description and slug); keyword queries are ranked locally with BM25:
+ *
+ *   const index = getMarketSearchIndex();
+ *   await index.refreshFromGamma();            // e.g. every 15 minutes
+ *   index.search("fed rate cut december", 5);
+ *
+ * Question terms count double, so a market whose question names the topic
+ * outranks one that only mentions it in the description. A failed refresh
+ * keeps the previous index.
+ *
+ * The bot refreshes the index every MARKET_SEARCH_REFRESH_MIN and saves it
+ * to MARKET_SEARCH_PATH, so `npm run search` answers from the saved copy
+ * without going to Gamma.
+ */
+
+import axios from "axios";
+import { envStr } from "../config/env";
+import { loadStateFile, saveStateFile } from "../infra/persistence";
+import { POLYMARKET_API } from "./constants";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface MarketSearchDoc {
+  marketId: string;
+  conditionId: string;
+  question: string;
+  description: string;
+  slug: string;
+  tokenIds: string[];
+}
+
+export interface MarketSearchHit extends MarketSearchDoc {
+  score: number;
+}
+
+export interface MarketSearchRefreshOptions {
+  /** Markets per Gamma request */
+  pageSize?: number;
+  /** Stop after this many markets */
+  maxMarkets?: number;
+}
+
+interface IndexedDoc {
+  doc: MarketSearchDoc;
+  length: number;
+}
+
+// BM25 parameters (the usual defaults)
+const K1 = 1.2;
+const B = 0.75;
+
+// Question terms are indexed this many times
+const QUESTION_WEIGHT = 2;
+
+const STOP_WORDS = new Set(
+  "a an and are at be by for in is it of on or the to will with".split(" "),
+);
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TOKENIZER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Lowercase alphanumeric terms, without stop words
+ */
+export function tokenizeSearchText(text: string): string[] {
+  return text
+    .toLowerCase()
+    .split(/[^a-z0-9]+/)
+    .filter((term) => term.length > 0 && !STOP_WORDS.has(term));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// INDEX
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class MarketSearchIndex {
+  private docs = new Map<string, IndexedDoc>();
+  /** term -> (marketId -> term frequency) */
+  private postings = new Map<string, Map<string, number>>();
+  private totalLength = 0;
+  private lastRefreshAt = 0;
+  private filePath: string | null = null;
+
+  /**
+   * Load the index saved at `filePath` and save every successful refresh
+   * there
+   */
+  attachStateFile(filePath: string): void {
+    this.filePath = filePath;
+    const result = loadStateFile<MarketSearchDoc[]>(filePath);
+    if (!result.ok) {
+      console.warn(
+        `[MarketSearch] Failed to load ${filePath}: ${result.error}`,
+      );
+      return;
+    }
+    if (Array.isArray(result.payload)) {
+      this.replace(result.payload, result.savedAt);
+    }
+  }
+
+  /**
+   * Replace the index contents
+   */
+  replace(docs: MarketSearchDoc[], now = Date.now()): void {
+    this.docs.clear();
+    this.postings.clear();
+    this.totalLength = 0;
+
+    for (const doc of docs) {
+      if (this.docs.has(doc.marketId)) continue;
+      const terms = tokenizeSearchText(`${doc.description} ${doc.slug}`);
+      const questionTerms = tokenizeSearchText(doc.question);
+      for (let i = 0; i < QUESTION_WEIGHT; i++) terms.push(...questionTerms);
+      for (const term of terms) {
+        let posting = this.postings.get(term);
+        if (!posting) {
+          posting = new Map();
+          this.postings.set(term, posting);
+        }
+        posting.set(doc.marketId, (posting.get(doc.marketId) ?? 0) + 1);
+      }
+      this.docs.set(doc.marketId, { doc, length: terms.length });
+      this.totalLength += terms.length;
+    }
+    this.lastRefreshAt = now;
+  }
+
+  /**
+   * Ranked keyword search - every query term adds to the score, so markets
+   * matching more terms rank higher
+   */
+  search(query: string, limit = 10): MarketSearchHit[] {
+    const terms = [...new Set(tokenizeSearchText(query))];
+    if (terms.length === 0 || this.docs.size === 0) return [];
+
+    const n = this.docs.size;
+    const avgLength = this.totalLength / n;
+    const scores = new Map<string, number>();
+
+    for (const term of terms) {
+      const posting = this.postings.get(term);
+      if (!posting) continue;
+      const idf = Math.log(1 + (n - posting.size + 0.5) / (posting.size + 0.5));
+      for (const [marketId, tf] of posting) {
+        const length = this.docs.get(marketId)!.length;
+        const norm = tf + K1 * (1 - B + (B * length) / avgLength);
+        const score = (idf * tf * (K1 + 1)) / norm;
+        scores.set(marketId, (scores.get(marketId) ?? 0) + score);
+      }
+    }
+
+    return [...scores]
+      .sort((a, b) => b[1] - a[1])
+      .slice(0, limit)
+      .map(([marketId, score]) => ({
+        ...this.docs.get(marketId)!.doc,
+        score,
+      }));
+  }
+
+  size(): number {
+    return this.docs.size;
+  }
+
+  /**
+   * Age of the index in ms (Infinity before the first refresh)
+   */
+  getAge(now = Date.now()): number {
+    return this.lastRefreshAt > 0 ? now - this.lastRefreshAt : Infinity;
+  }
+
+  /**
+   * Rebuild the index from Gamma's active markets
+   * @returns Number of markets indexed, or null if the fetch failed
+   */
+  async refreshFromGamma(
+    options: MarketSearchRefreshOptions = {},
+  ): Promise<number | null> {
+    const pageSize = options.pageSize ?? 500;
+    const maxMarkets = options.maxMarkets ?? 5000;
+    const docs: MarketSearchDoc[] = [];
+
+    try {
+      for (let offset = 0; offset < maxMarkets; offset += pageSize) {
+        const url = `${POLYMARKET_API.GAMMA}/markets?active=true&closed=false&limit=${pageSize}&offset=${offset}`;
+        const { data } = await axios.get(url, { timeout: 10000 });
+        if (!Array.isArray(data)) break;
+
+        for (const market of data) {
+          const doc = toMarketSearchDoc(market);
+          if (doc) docs.push(doc);
+        }
+        if (data.length < pageSize) break;
+      }
+    } catch (err) {
+      console.warn(
+        `[MarketSearch] Refresh failed: ${err instanceof Error ? err.message : err}`,
+      );
+      return null;
+    }
+
+    this.replace(docs.slice(0, maxMarkets));
+    if (this.filePath) {
+      const saved = saveStateFile(
+        this.filePath,
+        [...this.docs.values()].map((d) => d.doc),
+      );
+      if (!saved.ok) {
+        console.warn(
+          `[MarketSearch] Failed to save ${this.filePath}: ${saved.error}`,
+        );
+      }
+    }
+    return this.docs.size;
+  }
+}
+
+/**
+ * Convert a Gamma market to a search document (null if unusable)
+ */
+export function toMarketSearchDoc(market: any): MarketSearchDoc | null {
+  if (!market?.id || typeof market.question !== "string") return null;
+  let tokenIds: string[] = [];
+  try {
+    const parsed = JSON.parse(market.clobTokenIds || "[]");
+    if (Array.isArray(parsed)) tokenIds = parsed.map(String);
+  } catch {
+    // Keep the market searchable without token IDs
+  }
+  return {
+    marketId: String(market.id),
+    conditionId: market.conditionId ?? "",
+    question: market.question,
+    description: market.description ?? "",
+    slug: market.slug ?? "",
+    tokenIds,
+  };
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: MarketSearchIndex | null = null;
+
+/**
+ * Get the global market search index (created on first use, restored from
+ * MARKET_SEARCH_PATH)
+ */
+export function getMarketSearchIndex(): MarketSearchIndex {
+  if (!instance) {
+    instance = new MarketSearchIndex();
+    const filePath = envStr("MARKET_SEARCH_PATH", "./data/market-search.json");
+    if (filePath) instance.attachStateFile(filePath);
+  }
+  return instance;
+}
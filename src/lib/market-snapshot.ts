@@ -158,6 +158,8 @@ export function createMarketSnapshot(params: {
   source: MarketSnapshot["source"];
   attemptId?: string;
   maxSpreadCents?: number;
+  /** When the book data was produced (default: now, i.e. a fresh fetch) */
+  bookUpdatedAtMs?: number;
 }): MarketSnapshot {
   const {
     tokenId,
@@ -168,6 +170,7 @@ export function createMarketSnapshot(params: {
     attemptId = generateAttemptId(),
     maxSpreadCents,
   } = params;
+  const fetchedAtMs = Date.now();
 
   const mid = (bestBid + bestAsk) / 2;
   const spreadCents = (bestAsk - bestBid) * 100;
@@ -186,7 +189,8 @@ export function createMarketSnapshot(params: {
     spreadCents,
     bookStatus: status,
     source,
-    fetchedAtMs: Date.now(),
+    fetchedAtMs,
+    bookUpdatedAtMs: params.bookUpdatedAtMs ?? fetchedAtMs,
     attemptId,
     unhealthyReason: status !== "HEALTHY" ? reason : undefined,
   };
@@ -237,6 +241,7 @@ export async function fetchMarketSnapshot(
         bestAsk: asks[0].price,
         source: "WS_CACHE",
         attemptId: resolvedAttemptId,
+        bookUpdatedAtMs: cached.updatedAt,
       });
 
       logBookSnapshotSelected(snapshot, Date.now() - startTime);
@@ -399,6 +404,7 @@ export function logBookSnapshotSelected(
       source: snapshot.source,
       bookStatus: snapshot.bookStatus,
       fetchedAtMs: snapshot.fetchedAtMs,
+      quoteAgeMs: getQuoteAgeMs(snapshot),
       latencyMs,
       timestamp: new Date().toISOString(),
     }),
@@ -452,6 +458,17 @@ export function assertSnapshotIntegrity(
   return true;
 }
 
+/**
+ * Age of the book data behind a snapshot (ms). Includes both how old the
+ * cached book was when the snapshot was taken and time spent since.
+ */
+export function getQuoteAgeMs(
+  snapshot: MarketSnapshot,
+  now: number = Date.now(),
+): number {
+  return Math.max(0, now - snapshot.bookUpdatedAtMs);
+}
+
 /**
  * Check if a snapshot is healthy for execution.
  */
@@ -16,11 +16,14 @@
  * - Fetch market data by condition ID or token ID
  * - Get the opposite token ID for hedging (works with any 2-outcome market)
  * - Get outcomeIndex (1 or 2) and outcomeLabel for any token
+ * - Resolve a token's complement with current prices (hedge/arb sizing)
+ * - Expose per-market maker/taker fee rates (bps)
  * - Cache market data to reduce API calls
  */
 
 import axios from "axios";
 import { POLYMARKET_API } from "./constants";
+import { createBoundedCache } from "../infra/persistence/lru-cache";
 
 // ============================================================================
 // Types
@@ -53,6 +56,27 @@ export interface MarketTokenPair {
   question?: string;
   endDate?: string;
   active?: boolean;
+  /** Maker fee rate in basis points (from Gamma, if published) */
+  makerFeeBps?: number;
+  /** Taker fee rate in basis points (from Gamma, if published) */
+  takerFeeBps?: number;
+  /** Gamma event the market belongs to */
+  eventId?: string;
+  /** The UMA resolution was disputed */
+  resolutionDisputed?: boolean;
+}
+
+/**
+ * A token and its complement with current CLOB midpoint prices.
+ * Prices are null when the midpoint could not be fetched.
+ */
+export interface ComplementQuote {
+  tokenId: string;
+  outcomeLabel: string;
+  price: number | null;
+  complementTokenId: string;
+  complementLabel: string;
+  complementPrice: number | null;
 }
 
 interface GammaMarketResponse {
@@ -66,50 +90,50 @@ interface GammaMarketResponse {
   active?: boolean;
   closed?: boolean;
   acceptingOrders?: boolean;
+  makerBaseFee?: number; // bps
+  takerBaseFee?: number; // bps
+  events?: { id?: string | number }[];
+  umaResolutionStatuses?: string; // JSON string: '["proposed", "disputed"]'
 }
 
 // ============================================================================
 // Cache
 // ============================================================================
 
+// Cache TTL: 1 hour (market token IDs don't change)
+const CACHE_TTL_MS = 60 * 60 * 1000;
+
 // Cache market data by token ID for quick lookups
 // Key: tokenId, Value: MarketTokenPair
-const tokenToMarketCache = new Map<string, MarketTokenPair>();
+const tokenToMarketCache = createBoundedCache<MarketTokenPair>(
+  "TokenMarketCache",
+  { ttlMs: CACHE_TTL_MS },
+);
 
 // Cache by condition ID as well
-const conditionToMarketCache = new Map<string, MarketTokenPair>();
-
-// Cache TTL: 1 hour (market token IDs don't change)
-const CACHE_TTL_MS = 60 * 60 * 1000;
-const cacheTimestamps = new Map<string, number>();
+const conditionToMarketCache = createBoundedCache<MarketTokenPair>(
+  "ConditionMarketCache",
+  { ttlMs: CACHE_TTL_MS },
+);
 
 /**
- * Check if cache entry is still valid
- * Verifies both timestamp exists, is within TTL, and the cache entry exists
+ * Check if cache entry is still valid (present and within TTL)
  */
 function isCacheValid(key: string): boolean {
-  const timestamp = cacheTimestamps.get(key);
-  if (!timestamp) return false;
-  if (Date.now() - timestamp >= CACHE_TTL_MS) return false;
-  // Also verify the cache entry actually exists
   return tokenToMarketCache.has(key) || conditionToMarketCache.has(key);
 }
 
 /**
- * Store in cache with timestamp
+ * Store in cache
  */
 function cacheMarket(market: MarketTokenPair): void {
-  const now = Date.now();
-
   // Cache by all token IDs from tokens array
   for (const token of market.tokens) {
     tokenToMarketCache.set(token.tokenId, market);
-    cacheTimestamps.set(token.tokenId, now);
   }
 
   // Cache by condition ID
   conditionToMarketCache.set(market.conditionId, market);
-  cacheTimestamps.set(market.conditionId, now);
 }
 
 // ============================================================================
@@ -123,10 +147,8 @@ export async function fetchMarketByConditionId(
   conditionId: string,
 ): Promise<MarketTokenPair | null> {
   // Check cache first
-  if (isCacheValid(conditionId)) {
-    const cached = conditionToMarketCache.get(conditionId);
-    if (cached) return cached;
-  }
+  const cached = conditionToMarketCache.get(conditionId);
+  if (cached) return cached;
 
   try {
     const url = `${POLYMARKET_API.GAMMA}/markets?condition_id=${conditionId}`;
@@ -159,10 +181,8 @@ export async function fetchMarketByTokenId(
   tokenId: string,
 ): Promise<MarketTokenPair | null> {
   // Check cache first
-  if (isCacheValid(tokenId)) {
-    const cached = tokenToMarketCache.get(tokenId);
-    if (cached) return cached;
-  }
+  const cached = tokenToMarketCache.get(tokenId);
+  if (cached) return cached;
 
   try {
     // Gamma API allows searching by clob_token_ids
@@ -315,6 +335,13 @@ function parseMarketResponse(
       endDate: market.endDate,
       active:
         market.active && !market.closed && market.acceptingOrders !== false,
+      makerFeeBps: parseFeeBps(market.makerBaseFee),
+      takerFeeBps: parseFeeBps(market.takerBaseFee),
+      eventId:
+        market.events?.[0]?.id !== undefined
+          ? String(market.events[0].id)
+          : undefined,
+      resolutionDisputed: isDisputed(market.umaResolutionStatuses),
     };
 
     // Cache for future lookups
@@ -329,6 +356,30 @@ function parseMarketResponse(
   }
 }
 
+/**
+ * Parse a fee rate field (bps) from Gamma API (undefined if absent/invalid)
+ */
+function parseFeeBps(value: unknown): number | undefined {
+  const bps = Number(value);
+  return value != null && Number.isFinite(bps) && bps >= 0 ? bps : undefined;
+}
+
+/**
+ * Whether Gamma's UMA resolution history includes a dispute
+ */
+function isDisputed(statusesJson: string | undefined): boolean {
+  if (!statusesJson) return false;
+  try {
+    const statuses = JSON.parse(statusesJson);
+    return (
+      Array.isArray(statuses) &&
+      statuses.some((s) => String(s).toLowerCase() === "disputed")
+    );
+  } catch {
+    return false;
+  }
+}
+
 /**
  * Parse the outcomes JSON string from Gamma API
  * @returns Array of outcome strings, or null if parsing fails
@@ -410,6 +461,56 @@ export async function getOppositeTokenId(
   return oppositeToken?.tokenId ?? null;
 }
 
+/**
+ * Resolve a token's complement (the other outcome) with current prices
+ *
+ * The token mapping comes from the market cache; prices are always fetched
+ * fresh from the CLOB midpoint endpoint since they move constantly.
+ *
+ * @param tokenId - Either outcome token of a 2-outcome market
+ * @returns Both tokens with labels and midpoints, or null if not found
+ */
+export async function getComplementQuote(
+  tokenId: string,
+): Promise<ComplementQuote | null> {
+  const market = await fetchMarketByTokenId(tokenId);
+  if (!market) return null;
+
+  const token = market.tokens?.find((t) => t.tokenId === tokenId);
+  const complement = market.tokens?.find((t) => t.tokenId !== tokenId);
+  if (!token || !complement) return null;
+
+  const [price, complementPrice] = await Promise.all([
+    fetchMidpoint(token.tokenId),
+    fetchMidpoint(complement.tokenId),
+  ]);
+
+  return {
+    tokenId: token.tokenId,
+    outcomeLabel: token.outcomeLabel,
+    price,
+    complementTokenId: complement.tokenId,
+    complementLabel: complement.outcomeLabel,
+    complementPrice,
+  };
+}
+
+/**
+ * Fetch the CLOB midpoint for a token (null on failure)
+ */
+async function fetchMidpoint(tokenId: string): Promise<number | null> {
+  try {
+    const url = `${POLYMARKET_API.CLOB}/midpoint?token_id=${tokenId}`;
+    const { data } = await axios.get<{ mid?: string }>(url, {
+      timeout: 10000,
+    });
+    const mid = parseFloat(data?.mid ?? "");
+    return Number.isFinite(mid) ? mid : null;
+  } catch {
+    return null;
+  }
+}
+
 /**
  * Get market token pair from cache or fetch
  * Returns both token IDs for a market given any one of them
@@ -493,19 +594,13 @@ export async function prefetchMarkets(tokenIds: string[]): Promise<void> {
 export function clearMarketCache(): void {
   tokenToMarketCache.clear();
   conditionToMarketCache.clear();
-  cacheTimestamps.clear();
 }
 
 /**
  * Get cache stats (for debugging)
  */
 export function getMarketCacheStats(): { size: number; validEntries: number } {
-  let validEntries = 0;
-  for (const key of tokenToMarketCache.keys()) {
-    if (isCacheValid(key)) validEntries++;
-  }
-  return {
-    size: tokenToMarketCache.size,
-    validEntries,
-  };
+  // Expired entries are dropped on access, so every counted entry is valid
+  const size = tokenToMarketCache.size();
+  return { size, validEntries: size };
 }
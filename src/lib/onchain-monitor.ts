@@ -53,6 +53,7 @@
 
 import { ethers } from "ethers";
 import { POLYGON } from "./constants";
+import { labelAddress } from "./address-book";
 
 // ═══════════════════════════════════════════════════════════════════════════
 // TYPES
@@ -628,7 +629,7 @@ export class OnChainMonitor {
       );
 
       console.log(
-        `📡 Position monitoring enabled for ${this.config.ourWallet.slice(0, 8)}...`,
+        `📡 Position monitoring enabled for ${labelAddress(this.config.ourWallet, 8)}`,
       );
     }
 
@@ -837,7 +838,7 @@ export class OnChainMonitor {
       };
 
       console.log(
-        `📡 Whale ${side} | ${whaleWallet.slice(0, 8)}... | ` +
+        `📡 Whale ${side} | ${labelAddress(whaleWallet, 8)} | ` +
           `$${sizeUsd.toFixed(0)} @ ${(price * 100).toFixed(1)}¢ | ` +
           `token:${tokenId.slice(0, 8)}...`,
       );
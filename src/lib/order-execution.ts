@@ -10,6 +10,7 @@
  * - Comprehensive rejection diagnostics
  * - Tick size handling with dynamic lookup
  * - Pre-placement invariant enforcement
+ * - Post-only (maker) limit orders that never cross the book
  */
 
 import type { ClobClient } from "@polymarket/clob-client";
@@ -30,6 +31,17 @@ import {
   type ComputeExecutionPriceResult,
   type OrderRejectionDiagnostic,
 } from "./price-safety";
+import {
+  parseClobError,
+  clobErrorFields,
+  CLOB_POST_ORDER_ENDPOINT,
+} from "../infra/error-handling";
+import {
+  convertOrderSize,
+  marketOrderAmount,
+  type OrderSizeConversion,
+  type OrderSizeInput,
+} from "./order-size";
 
 // ═══════════════════════════════════════════════════════════════════════════
 // TYPES
@@ -85,10 +97,57 @@ export interface PlaceOrderResult {
   reason?: string;
   /** Order type used */
   orderType: "FOK" | "GTC";
+  /** Classified exchange error (AUTH_FAILED, INSUFFICIENT_BALANCE, ...) */
+  errorCode?: string;
+  /** The exchange's own error message, verbatim */
+  errorDetail?: string;
+  /** HTTP status of the failed CLOB call */
+  httpStatus?: number;
+  /** Failed CLOB call as "METHOD /path" */
+  endpoint?: string;
+  /** Server request ID (response headers) */
+  requestId?: string;
+  /** Requested size in USDC and shares at the FOK limit price */
+  sizeConversion?: OrderSizeConversion;
+  /** Post-only order moved off the book's far side: the price asked for */
+  repricedFrom?: number;
   /** Diagnostic info for rejections */
   diagnostic?: OrderRejectionDiagnostic;
 }
 
+/**
+ * What a post-only order does when its price would cross the book
+ * - REJECT: don't post (POST_ONLY_WOULD_CROSS)
+ * - REPRICE: move to one tick inside the spread (best ask - tick for a BUY,
+ *   best bid + tick for a SELL)
+ */
+export type PostOnlyCrossAction = "REJECT" | "REPRICE";
+
+/**
+ * Input for a post-only limit order
+ */
+export interface PostOnlyOrderInput {
+  client: ClobClient;
+  tokenId: string;
+  /** Market ID (optional, for logging) */
+  marketId?: string;
+  side: "BUY" | "SELL";
+  /** Size in USDC or shares (converted at the resting price) */
+  size: OrderSizeInput;
+  /** Limit price (0-1 scale) */
+  price: number;
+  /** Best bid price (0-1 scale, 0 = no bids) */
+  bestBid: number;
+  /** Best ask price (0-1 scale, 0 = no asks) */
+  bestAsk: number;
+  /** Tick size (optional - will be fetched if not provided) */
+  tickSize?: number;
+  /** Default: REJECT */
+  onCross?: PostOnlyCrossAction;
+}
+
+export const POST_ONLY_WOULD_CROSS = "POST_ONLY_WOULD_CROSS";
+
 /**
  * Orderbook snapshot for execution validation
  */
@@ -292,14 +351,16 @@ export async function placeOrderWithFallback(
   }
 
   // Calculate shares
-  const shares = sizeUsd / fokPrice;
+  const sizeConversion = convertOrderSize({ usdc: sizeUsd }, fokPrice);
+  const shares = sizeConversion.sizeShares;
 
-  // Step 4: Try FOK order
+  // Step 4: Try FOK order (market orders take USDC for a BUY, shares for
+  // a SELL)
   try {
     const fokOrder = await client.createMarketOrder({
       side: side === "BUY" ? Side.BUY : Side.SELL,
       tokenID: tokenId,
-      amount: shares,
+      amount: marketOrderAmount(side, sizeConversion),
       price: fokPrice,
     });
 
@@ -315,11 +376,13 @@ export async function placeOrderWithFallback(
         filledUsd: sizeUsd,
         filledPriceCents: basePrice * 100,
         orderType: "FOK",
+        sizeConversion,
       };
     }
 
     // FOK failed - log diagnostic
-    const fokErrorMsg = fokResponse.errorMsg || "Unknown FOK error";
+    const fokError = parseClobError(fokResponse, CLOB_POST_ORDER_ENDPOINT);
+    const fokErrorMsg = fokError.errorDetail;
     console.log(`⏳ FOK missed: ${fokErrorMsg}, trying GTC fallback...`);
 
     // Step 5: Try GTC fallback if enabled
@@ -337,6 +400,7 @@ export async function placeOrderWithFallback(
         limitPrice: fokPrice,
         orderType: "FOK",
         errorMessage: fokErrorMsg,
+        errorCode: fokError.errorCode,
       });
       logOrderRejection(diagnostic);
 
@@ -346,6 +410,8 @@ export async function placeOrderWithFallback(
         filledPriceCents: 0,
         reason: "FOK_REJECTED",
         orderType: "FOK",
+        ...clobErrorFields(fokError),
+        sizeConversion,
         diagnostic,
       };
     }
@@ -421,7 +487,8 @@ export async function placeOrderWithFallback(
       }
 
       // GTC also failed
-      const gtcErrorMsg = gtcResponse.errorMsg || "Unknown GTC error";
+      const gtcError = parseClobError(gtcResponse, CLOB_POST_ORDER_ENDPOINT);
+      const gtcErrorMsg = gtcError.errorDetail;
       const diagnostic = createRejectionDiagnostic({
         tokenId,
         marketId,
@@ -435,6 +502,7 @@ export async function placeOrderWithFallback(
         limitPrice: gtcPrice,
         orderType: "GTC",
         errorMessage: `FOK: ${fokErrorMsg} | GTC: ${gtcErrorMsg}`,
+        errorCode: gtcError.errorCode,
       });
       logOrderRejection(diagnostic);
 
@@ -444,11 +512,12 @@ export async function placeOrderWithFallback(
         filledPriceCents: 0,
         reason: "FOK_AND_GTC_REJECTED",
         orderType: "GTC",
+        ...clobErrorFields(gtcError),
         diagnostic,
       };
     } catch (gtcErr) {
-      const gtcErrorMsg =
-        gtcErr instanceof Error ? gtcErr.message : String(gtcErr);
+      const gtcError = parseClobError(gtcErr, CLOB_POST_ORDER_ENDPOINT);
+      const gtcErrorMsg = gtcError.errorDetail;
       console.warn(`⚠️ GTC fallback error: ${gtcErrorMsg}`);
 
       const diagnostic = createRejectionDiagnostic({
@@ -464,6 +533,7 @@ export async function placeOrderWithFallback(
         limitPrice: fokPrice,
         orderType: "GTC",
         errorMessage: `FOK: ${fokErrorMsg} | GTC error: ${gtcErrorMsg}`,
+        errorCode: gtcError.errorCode,
       });
       logOrderRejection(diagnostic);
 
@@ -473,11 +543,13 @@ export async function placeOrderWithFallback(
         filledPriceCents: 0,
         reason: `GTC_ERROR: ${gtcErrorMsg}`,
         orderType: "GTC",
+        ...clobErrorFields(gtcError),
         diagnostic,
       };
     }
   } catch (err) {
-    const errorMsg = err instanceof Error ? err.message : String(err);
+    const error = parseClobError(err, CLOB_POST_ORDER_ENDPOINT);
+    const errorMsg = error.errorDetail;
 
     const diagnostic = createRejectionDiagnostic({
       tokenId,
@@ -492,6 +564,7 @@ export async function placeOrderWithFallback(
       limitPrice: fokPrice,
       orderType: "FOK",
       errorMessage: errorMsg,
+      errorCode: error.errorCode,
     });
     logOrderRejection(diagnostic);
 
@@ -501,11 +574,191 @@ export async function placeOrderWithFallback(
       filledPriceCents: 0,
       reason: errorMsg,
       orderType: "FOK",
+      ...clobErrorFields(error),
       diagnostic,
     };
   }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// POST-ONLY ORDERS
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Market making needs orders that rest on the book: a maker fill pays the
+// maker fee and counts toward liquidity rewards, a taker fill does neither.
+// clob-client (4.x) has no post-only flag, so the book is checked before
+// posting and the order goes out as a plain GTC limit. A book that moves
+// between the check and the post can still take the order; the exchange's
+// own post-only rejection is classified as POST_ONLY_WOULD_TRADE.
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Resting price for a post-only order (repriced: moved off the far side),
+ * or null when it would cross and onCross is REJECT - or when the only
+ * resting price is outside the HARD bounds.
+ *
+ * The price is rounded to tick away from the far side (BUY down, SELL up).
+ * It crosses when a BUY is at/above the best ask or a SELL at/below the
+ * best bid; an empty far side (0) can't be crossed.
+ */
+export function computePostOnlyPrice(input: {
+  side: "BUY" | "SELL";
+  price: number;
+  bestBid: number;
+  bestAsk: number;
+  tickSize: number;
+  onCross?: PostOnlyCrossAction;
+}): { price: number; repriced: boolean } | null {
+  const { side, bestBid, bestAsk, tickSize, onCross = "REJECT" } = input;
+
+  // Round toward our own side: roundToTick floors for SELL, ceils for BUY
+  const away = side === "BUY" ? "SELL" : "BUY";
+  let price = roundToTick(input.price, tickSize, away);
+
+  const crosses =
+    side === "BUY"
+      ? bestAsk > 0 && price >= bestAsk
+      : bestBid > 0 && price <= bestBid;
+  if (crosses) {
+    if (onCross === "REJECT") return null;
+    price =
+      side === "BUY"
+        ? roundToTick(bestAsk - tickSize, tickSize, away)
+        : roundToTick(bestBid + tickSize, tickSize, away);
+  }
+
+  if (price < HARD_MIN_PRICE || price > HARD_MAX_PRICE) return null;
+  return { price, repriced: crosses };
+}
+
+/**
+ * Place a limit order that only ever rests on the book (maker).
+ *
+ * Prices that would cross are rejected or repriced per `onCross`; the order
+ * is never sent as a taker. The result is pending (isPending) on success.
+ */
+export async function placePostOnlyOrder(
+  input: PostOnlyOrderInput,
+): Promise<PlaceOrderResult> {
+  const { client, tokenId, marketId, side, bestBid, bestAsk } = input;
+  const { tickSize, isDefault: tickSizeIsDefault } = getTickSizeForToken(
+    tokenId,
+    input.tickSize,
+  );
+
+  const resting = computePostOnlyPrice({
+    side,
+    price: input.price,
+    bestBid,
+    bestAsk,
+    tickSize,
+    onCross: input.onCross,
+  });
+
+  if (resting === null) {
+    const diagnostic = createRejectionDiagnostic({
+      tokenId,
+      marketId,
+      side,
+      bestBid,
+      bestAsk,
+      tickSize,
+      tickSizeIsDefault,
+      limitPrice: input.price,
+      orderType: "GTC",
+      postOnly: true,
+      errorMessage: `post-only ${side} @ ${(input.price * 100).toFixed(2)}¢ would cross the book`,
+    });
+    logOrderRejection(diagnostic);
+    return {
+      success: false,
+      filledUsd: 0,
+      filledPriceCents: 0,
+      reason: POST_ONLY_WOULD_CROSS,
+      orderType: "GTC",
+      diagnostic,
+    };
+  }
+
+  const { price } = resting;
+  const repricedFrom = resting.repriced ? input.price : undefined;
+  const sizeConversion = convertOrderSize(input.size, price);
+
+  try {
+    const order = await client.createOrder({
+      side: side === "BUY" ? Side.BUY : Side.SELL,
+      tokenID: tokenId,
+      size: sizeConversion.sizeShares,
+      price,
+    });
+    const response = await client.postOrder(order, OrderType.GTC);
+
+    if (response.success) {
+      const orderId =
+        (response as any).orderId || (response as any).orderHashes?.[0];
+      console.log(
+        `📋 Post-only ${side} resting @ ${(price * 100).toFixed(1)}¢ ` +
+          `(orderId=${orderId?.slice(0, 12) || "unknown"}...` +
+          `${repricedFrom !== undefined ? `, repriced from ${(repricedFrom * 100).toFixed(1)}¢` : ""})`,
+      );
+      return {
+        success: true,
+        orderId,
+        filledUsd: 0, // Not filled yet
+        filledPriceCents: price * 100,
+        isPending: true,
+        orderType: "GTC",
+        sizeConversion,
+        repricedFrom,
+      };
+    }
+
+    const error = parseClobError(response, CLOB_POST_ORDER_ENDPOINT);
+    const diagnostic = createRejectionDiagnostic({
+      tokenId,
+      marketId,
+      side,
+      sizeUsd: sizeConversion.sizeUsdc,
+      shares: sizeConversion.sizeShares,
+      bestBid,
+      bestAsk,
+      tickSize,
+      tickSizeIsDefault,
+      limitPrice: price,
+      orderType: "GTC",
+      postOnly: true,
+      errorMessage: error.errorDetail,
+      errorCode: error.errorCode,
+    });
+    logOrderRejection(diagnostic);
+
+    return {
+      success: false,
+      filledUsd: 0,
+      filledPriceCents: 0,
+      reason:
+        diagnostic.rejectionClass === "POST_ONLY_WOULD_TRADE"
+          ? POST_ONLY_WOULD_CROSS
+          : "POST_ONLY_REJECTED",
+      orderType: "GTC",
+      ...clobErrorFields(error),
+      sizeConversion,
+      diagnostic,
+    };
+  } catch (err) {
+    const error = parseClobError(err, CLOB_POST_ORDER_ENDPOINT);
+    return {
+      success: false,
+      filledUsd: 0,
+      filledPriceCents: 0,
+      reason: error.errorDetail,
+      orderType: "GTC",
+      ...clobErrorFields(error),
+      sizeConversion,
+    };
+  }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // HELPER FUNCTIONS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -609,6 +862,7 @@ function createRejectionDiagnostic(input: {
   orderType: "FOK" | "GTC" | "FAK" | "GTD";
   postOnly?: boolean;
   errorMessage: string;
+  errorCode?: string;
 }): OrderRejectionDiagnostic {
   return {
     tokenId: input.tokenId,
@@ -624,7 +878,7 @@ function createRejectionDiagnostic(input: {
     finalLimitPriceApiUnits: input.limitPrice, // Same for Polymarket
     orderType: input.orderType,
     postOnly: input.postOnly,
-    errorCode: undefined,
+    errorCode: input.errorCode,
     errorMessage: input.errorMessage,
     rejectionClass: classifyRejectionReason(input.errorMessage),
     timestamp: new Date().toISOString(),
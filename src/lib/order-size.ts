@@ -0,0 +1,86 @@
+/**
+ * Order Size - Explicit USDC vs share sizing for orders
+ *
+ * clob-client's createMarketOrder() takes a single `amount` whose unit
+ * depends on the side: USDC for a BUY, shares for a SELL (limit orders
+ * always take shares). Passing shares to a market BUY spends `shares`
+ * dollars instead of `shares * price`.
+ *
+ * Sizes are therefore given with their unit - { usdc } or { shares } -
+ * converted at the live price, and the conversion is echoed back on the
+ * order result so the caller can see what was actually sent:
+ *
+ *   const size = convertOrderSize({ usdc: 10 }, 0.4);  // 25 shares
+ *   createMarketOrder({ side, amount: marketOrderAmount("BUY", size), ... })
+ */
+
+import type { OrderSide } from "./types";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** An order size in exactly one unit */
+export type OrderSizeInput = { usdc: number } | { shares: number };
+
+export interface OrderSizeConversion {
+  /** The unit the size was given in */
+  from: "USDC" | "SHARES";
+  sizeUsdc: number;
+  sizeShares: number;
+  /** Price the conversion used (0-1) */
+  price: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONVERSION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Convert a size to both units at `price`
+ */
+export function convertOrderSize(
+  size: OrderSizeInput,
+  price: number,
+): OrderSizeConversion {
+  if (!Number.isFinite(price) || price <= 0) {
+    throw new Error(`Cannot convert an order size at price ${price}`);
+  }
+  if ("usdc" in size) {
+    return {
+      from: "USDC",
+      sizeUsdc: size.usdc,
+      sizeShares: size.usdc / price,
+      price,
+    };
+  }
+  return {
+    from: "SHARES",
+    sizeUsdc: size.shares * price,
+    sizeShares: size.shares,
+    price,
+  };
+}
+
+/**
+ * The `amount` createMarketOrder() expects: USDC for a BUY, shares for
+ * a SELL
+ */
+export function marketOrderAmount(
+  side: OrderSide,
+  size: OrderSizeConversion,
+): number {
+  return side === "BUY" ? size.sizeUsdc : size.sizeShares;
+}
+
+/**
+ * One-line description for logs, e.g. "$10.00 → 25.00 shares @ 40.0¢"
+ */
+export function formatOrderSizeConversion(size: OrderSizeConversion): string {
+  const usdc = `$${size.sizeUsdc.toFixed(2)}`;
+  const shares = `${size.sizeShares.toFixed(2)} shares`;
+  const price = `${(size.price * 100).toFixed(1)}¢`;
+  return size.from === "USDC"
+    ? `${usdc} → ${shares} @ ${price}`
+    : `${shares} → ${usdc} @ ${price}`;
+}
@@ -0,0 +1,256 @@
+/**
+ * Order Sweeper - Cancel resting orders that are too old or sit in dead books
+ *
+ * GTC fallbacks rest on the book until filled or cancelled. One left behind
+ * (the bot restarted, the market went quiet) can fill hours later at a price
+ * that no longer makes sense. The sweeper periodically lists the account's
+ * open orders on the CLOB and cancels any that are:
+ *
+ * - MAX_AGE:      older than ORDER_SWEEP_MAX_AGE_MIN
+ * - MARKET_STALE: the token no longer has an orderbook (closed/resolved)
+ * - ILLIQUID:     one side of the book is empty or the spread is wider than
+ *                 ORDER_SWEEP_MAX_SPREAD_CENTS
+ *
+ * Totals are kept per UTC day and an ORDER_SWEEP_SUMMARY event is emitted
+ * once the day rolls over.
+ *
+ * NOTE: every open order on the account is swept, including ones placed by
+ * hand - leave the sweeper off (the default) when trading manually on the
+ * same wallet.
+ */
+
+import type { ClobClient } from "@polymarket/clob-client";
+import { getBestPricesFromRaw } from "./orderbook-utils";
+import { gtcOrderTracker } from "./order";
+import { emitEvent } from "../infra/event-bus";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type SweepReason = "MAX_AGE" | "MARKET_STALE" | "ILLIQUID";
+
+export interface OrderSweeperConfig {
+  /** Cancel orders older than this (ms, 0 = no age limit) */
+  maxAgeMs: number;
+  /** Cancel when the book spread is wider than this (cents, 0 = off) */
+  maxSpreadCents: number;
+}
+
+export const DEFAULT_ORDER_SWEEPER_CONFIG: OrderSweeperConfig = {
+  maxAgeMs: 60 * 60 * 1000,
+  maxSpreadCents: 20,
+};
+
+/** Fields of a CLOB open order the sweeper uses */
+export interface SweepableOrder {
+  id: string;
+  asset_id: string;
+  side: string;
+  price: string;
+  /** Unix seconds */
+  created_at: number;
+}
+
+/**
+ * Book state of an order's token: null = no orderbook exists, undefined =
+ * unknown (the fetch failed, so only the age check applies)
+ */
+export type SweepBook =
+  | { bestBidCents: number; bestAskCents: number }
+  | null
+  | undefined;
+
+export interface SweepResult {
+  scanned: number;
+  cancelled: number;
+  failed: number;
+  byReason: Record<SweepReason, number>;
+}
+
+export interface OrderSweepSummaryEvent {
+  event: "ORDER_SWEEP_SUMMARY";
+  day: string;
+  sweeps: number;
+  scanned: number;
+  cancelled: number;
+  failed: number;
+  byReason: Record<SweepReason, number>;
+  timestamp: string;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// HELPERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+function emptyByReason(): Record<SweepReason, number> {
+  return { MAX_AGE: 0, MARKET_STALE: 0, ILLIQUID: 0 };
+}
+
+/**
+ * Why an order should be swept, or null to leave it resting
+ */
+export function getSweepReason(
+  order: SweepableOrder,
+  book: SweepBook,
+  config: OrderSweeperConfig,
+  now = Date.now(),
+): SweepReason | null {
+  const ageMs = now - order.created_at * 1000;
+  if (config.maxAgeMs > 0 && ageMs > config.maxAgeMs) return "MAX_AGE";
+
+  if (book === undefined) return null;
+  if (book === null) return "MARKET_STALE";
+
+  if (config.maxSpreadCents > 0) {
+    if (book.bestBidCents <= 0 || book.bestAskCents <= 0) return "ILLIQUID";
+    const spreadCents = book.bestAskCents - book.bestBidCents;
+    if (spreadCents > config.maxSpreadCents) return "ILLIQUID";
+  }
+
+  return null;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ORDER SWEEPER
+// ═══════════════════════════════════════════════════════════════════════════
+
+export class OrderSweeper {
+  private readonly config: OrderSweeperConfig;
+  private running = false;
+  private day: string;
+  private daily = {
+    sweeps: 0,
+    scanned: 0,
+    cancelled: 0,
+    failed: 0,
+    byReason: emptyByReason(),
+  };
+
+  constructor(
+    private readonly client: ClobClient,
+    config: Partial<OrderSweeperConfig> = {},
+    now = Date.now(),
+  ) {
+    const d = DEFAULT_ORDER_SWEEPER_CONFIG;
+    this.config = {
+      maxAgeMs: config.maxAgeMs ?? d.maxAgeMs,
+      maxSpreadCents: config.maxSpreadCents ?? d.maxSpreadCents,
+    };
+    this.day = new Date(now).toISOString().slice(0, 10);
+  }
+
+  /**
+   * List open orders and cancel the stale ones. Overlapping calls are
+   * skipped (returns null).
+   */
+  async sweep(now = Date.now()): Promise<SweepResult | null> {
+    if (this.running) return null;
+    this.running = true;
+    try {
+      this.rollDay(now);
+      const result = await this.runSweep(now);
+      this.daily.sweeps++;
+      this.daily.scanned += result.scanned;
+      this.daily.cancelled += result.cancelled;
+      this.daily.failed += result.failed;
+      for (const reason of Object.keys(result.byReason) as SweepReason[]) {
+        this.daily.byReason[reason] += result.byReason[reason];
+      }
+      return result;
+    } finally {
+      this.running = false;
+    }
+  }
+
+  private async runSweep(now: number): Promise<SweepResult> {
+    const result: SweepResult = {
+      scanned: 0,
+      cancelled: 0,
+      failed: 0,
+      byReason: emptyByReason(),
+    };
+
+    const orders = (await this.client.getOpenOrders()) as SweepableOrder[];
+    result.scanned = orders.length;
+    if (orders.length === 0) return result;
+
+    const books = new Map<string, SweepBook>();
+    const toCancel: { order: SweepableOrder; reason: SweepReason }[] = [];
+
+    for (const order of orders) {
+      if (!books.has(order.asset_id)) {
+        books.set(order.asset_id, await this.fetchBook(order.asset_id));
+      }
+      const reason = getSweepReason(
+        order,
+        books.get(order.asset_id),
+        this.config,
+        now,
+      );
+      if (reason) toCancel.push({ order, reason });
+    }
+    if (toCancel.length === 0) return result;
+
+    try {
+      await this.client.cancelOrders(toCancel.map((c) => c.order.id));
+      for (const { order, reason } of toCancel) {
+        gtcOrderTracker.untrack(order.id);
+        result.cancelled++;
+        result.byReason[reason]++;
+        console.log(
+          `🧹 [Sweeper] Cancelled ${order.side} ${order.id.slice(0, 12)}... @ ${(Number(order.price) * 100).toFixed(1)}¢ (${reason})`,
+        );
+      }
+    } catch (err) {
+      result.failed += toCancel.length;
+      console.warn(
+        `⚠️ [Sweeper] Failed to cancel ${toCancel.length} order(s): ${err instanceof Error ? err.message : err}`,
+      );
+    }
+
+    return result;
+  }
+
+  private async fetchBook(tokenId: string): Promise<SweepBook> {
+    try {
+      const book = await this.client.getOrderBook(tokenId);
+      const { bestBidCents, bestAskCents } = getBestPricesFromRaw(book);
+      return { bestBidCents, bestAskCents };
+    } catch (err) {
+      // "No orderbook exists" - the market closed or resolved
+      const msg = err instanceof Error ? err.message : String(err);
+      return /no orderbook/i.test(msg) ? null : undefined;
+    }
+  }
+
+  /**
+   * Emit the previous day's summary once the UTC day changes
+   */
+  private rollDay(now: number): void {
+    const day = new Date(now).toISOString().slice(0, 10);
+    if (day === this.day) return;
+
+    if (this.daily.sweeps > 0) {
+      const summary: OrderSweepSummaryEvent = {
+        event: "ORDER_SWEEP_SUMMARY",
+        day: this.day,
+        ...this.daily,
+        timestamp: new Date(now).toISOString(),
+      };
+      emitEvent(summary);
+      console.log(
+        `🧹 [Sweeper] ${this.day}: ${summary.sweeps} sweeps, ${summary.cancelled}/${summary.scanned} orders cancelled (age ${summary.byReason.MAX_AGE}, stale ${summary.byReason.MARKET_STALE}, illiquid ${summary.byReason.ILLIQUID})`,
+      );
+    }
+
+    this.day = day;
+    this.daily = {
+      sweeps: 0,
+      scanned: 0,
+      cancelled: 0,
+      failed: 0,
+      byReason: emptyByReason(),
+    };
+  }
+}
@@ -21,20 +21,59 @@ import { OrderType, Side } from "@polymarket/clob-client";
 import { ORDER, BUY, SELL } from "./constants";
 import type { OrderSide, OrderOutcome, OrderResult, Logger } from "./types";
 import { isLiveTradingEnabled } from "./auth";
-import { isCloudflareBlock, formatErrorForLog } from "../infra/error-handling";
+import {
+  ErrorCode,
+  isCloudflareBlock,
+  formatErrorForLog,
+  parseClobError,
+  clobErrorFields,
+  CLOB_POST_ORDER_ENDPOINT,
+  type ClobErrorInfo,
+} from "../infra/error-handling";
 import { getBestPricesFromRaw } from "./orderbook-utils";
-import { HARD_MIN_PRICE, HARD_MAX_PRICE } from "./price-safety";
+import {
+  HARD_MIN_PRICE,
+  HARD_MAX_PRICE,
+  PRICE_BAND_WINDOW_MS,
+  checkPriceBand,
+  checkSidePriceCap,
+} from "./price-safety";
+import { getMarketDataStore } from "./market-data-store";
+import { createBoundedCache } from "../infra/persistence/lru-cache";
+import { getMarketFilterReason, isMarketFilterActive } from "./market-filter";
+import { getTagTaxonomy } from "./tag-taxonomy";
+import { fetchMarketByTokenId } from "./market";
+import {
+  convertOrderSize,
+  formatOrderSizeConversion,
+  marketOrderAmount,
+  type OrderSizeConversion,
+  type OrderSizeInput,
+} from "./order-size";
+import {
+  checkSellableShares,
+  formatShareShortfall,
+  INSUFFICIENT_SHARES,
+} from "./share-check";
 
 // In-flight tracking to prevent duplicate orders
 const inFlight = new Map<string, number>();
 const marketCooldown = new Map<string, number>();
 
+// Market status cache (marketId -> CLOB market), refreshed on a short TTL
+const marketStatusCache = createBoundedCache<any>("MarketStatusCache", {
+  ttlMs: ORDER.MARKET_STATUS_TTL_MS,
+});
+
 export interface PostOrderInput {
   client: ClobClient;
   tokenId: string;
   outcome: OrderOutcome;
   side: OrderSide;
-  sizeUsd: number;
+  /** Size in USDC - set exactly one of sizeUsd / sizeShares */
+  sizeUsd?: number;
+  /** Size in shares, converted to USDC at the best price */
+  sizeShares?: number;
   marketId?: string;
   /**
    * Optional maximum acceptable price for price protection.
@@ -69,6 +108,11 @@ export interface PostOrderInput {
    * may take on-chain prices into account for pricing decisions.
    */
   onChainPrice?: number;
+  /**
+   * Optional: Skip the price-band sanity check (PRICE_BAND_PCT).
+   * The check is also skipped in NUCLEAR mode (maxAcceptablePrice undefined).
+   */
+  force?: boolean;
 }
 
 /**
@@ -87,7 +131,24 @@ export interface PostOrderInput {
  * Use GTC if you want a limit order that waits for your price.
  */
 export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
-  const { client, tokenId, side, sizeUsd, logger, maxAcceptablePrice } = input;
+  const { client, tokenId, side, logger, maxAcceptablePrice } = input;
+
+  let size: OrderSizeInput;
+  if (input.sizeUsd !== undefined && input.sizeShares === undefined) {
+    size = { usdc: input.sizeUsd };
+  } else if (input.sizeShares !== undefined && input.sizeUsd === undefined) {
+    size = { shares: input.sizeShares };
+  } else {
+    return {
+      success: false,
+      reason: "INVALID_SIZE",
+      detail: "set exactly one of sizeUsd / sizeShares",
+    };
+  }
+  const requestedSize =
+    "usdc" in size
+      ? `${size.usdc.toFixed(2)} USD`
+      : `${size.shares.toFixed(2)} shares`;
 
   // Determine order type - use override, or default based on side
   // Priority: explicit override > side-specific env > master ORDER_TYPE env > FOK
@@ -98,15 +159,15 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
   // Check live trading
   if (!isLiveTradingEnabled()) {
     logger?.warn?.(
-      `[SIM] ${side} ${sizeUsd.toFixed(2)} USD (${orderType}) - live trading disabled`,
+      `[SIM] ${side} ${requestedSize} (${orderType}) - live trading disabled`,
     );
     return { success: true, reason: "SIMULATED" };
   }
 
-  // Check minimum size
-  if (sizeUsd < ORDER.MIN_ORDER_USD) {
+  // Check minimum size (share sizes are checked once they have a price)
+  if (input.sizeUsd !== undefined && input.sizeUsd < ORDER.MIN_ORDER_USD) {
     logger?.debug?.(
-      `Order rejected: ORDER_TOO_SMALL (${sizeUsd.toFixed(4)} < ${ORDER.MIN_ORDER_USD})`,
+      `Order rejected: ORDER_TOO_SMALL (${input.sizeUsd.toFixed(4)} < ${ORDER.MIN_ORDER_USD})`,
     );
     return { success: false, reason: "ORDER_TOO_SMALL" };
   }
@@ -140,16 +201,48 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
   }
 
   try {
-    // Validate market exists if marketId provided
+    // Validate market exists and is tradable if marketId provided
+    // (cheaper than signing + posting and letting the exchange reject)
     if (input.marketId) {
       try {
-        const market = await client.getMarket(input.marketId);
+        const market = await getMarketStatus(client, input.marketId);
         if (!market) {
           logger?.debug?.(
             `Order rejected: MARKET_NOT_FOUND (${input.marketId})`,
           );
           return { success: false, reason: "MARKET_NOT_FOUND" };
         }
+
+        const notTradable = getNotTradableReason(market, side);
+        if (notTradable) {
+          logger?.debug?.(
+            `Order rejected: MARKET_NOT_TRADABLE (${notTradable}) for ${input.marketId.slice(0, 8)}...`,
+          );
+          return {
+            success: false,
+            reason: "MARKET_NOT_TRADABLE",
+            detail: notTradable,
+          };
+        }
+
+        // Allow/deny lists only gate new entries
+        const notAllowed =
+          side === "BUY"
+            ? getMarketFilterReason({
+                ...market,
+                tagRefs: getTagTaxonomy().getTagRefsForMarket(input.marketId),
+              })
+            : null;
+        if (notAllowed) {
+          logger?.debug?.(
+            `Order rejected: MARKET_NOT_ALLOWED (${notAllowed}) for ${input.marketId.slice(0, 8)}...`,
+          );
+          return {
+            success: false,
+            reason: "MARKET_NOT_ALLOWED",
+            detail: notAllowed,
+          };
+        }
       } catch {
         // Continue even if market fetch fails - we'll catch any real issues on orderbook fetch
       }
@@ -254,14 +347,53 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
       }
     }
 
+    // Both units at the best price - echoed back on the result
+    const sizeConversion: OrderSizeConversion = convertOrderSize(
+      size,
+      bestPrice,
+    );
+    const sizeUsd = sizeConversion.sizeUsdc;
+    if (sizeConversion.from === "SHARES") {
+      logger?.debug?.(
+        `Order size: ${formatOrderSizeConversion(sizeConversion)}`,
+      );
+      if (sizeUsd < ORDER.MIN_ORDER_USD) {
+        logger?.debug?.(
+          `Order rejected: ORDER_TOO_SMALL (${sizeUsd.toFixed(4)} < ${ORDER.MIN_ORDER_USD})`,
+        );
+        return { success: false, reason: "ORDER_TOO_SMALL", sizeConversion };
+      }
+    }
+
+    // Don't post a SELL for shares the funder doesn't hold
+    if (!isBuy) {
+      const sharesNeeded =
+        input.shares ?? input.sizeShares ?? sizeConversion.sizeShares;
+      const check = await checkSellableShares(client, tokenId, sharesNeeded);
+      if (!check.ok) {
+        const detail = formatShareShortfall(check, sharesNeeded);
+        logger?.warn?.(
+          `Order rejected: ${INSUFFICIENT_SHARES} (${detail}) for ${tokenId.slice(0, 8)}...`,
+        );
+        return {
+          success: false,
+          reason: INSUFFICIENT_SHARES,
+          detail,
+          heldShares: check.heldShares,
+        };
+      }
+    }
+
     // Execute order with retry logic
     const orderSide = isBuy ? Side.BUY : Side.SELL;
     let remaining = sizeUsd;
-    let remainingShares = input.shares; // Track remaining shares for SELL orders
+    // Track remaining shares for SELL orders
+    let remainingShares = input.shares ?? input.sizeShares;
     let totalFilled = 0;
     let totalShares = 0; // Track total shares for accurate avgPrice calculation
     let retryCount = 0;
     let lastErrorReason = "NO_ERROR"; // Track last error for better reporting
+    let lastError: ClobErrorInfo | null = null;
 
     // For SELL orders with shares specified, also check if remainingShares is exhausted
     const shouldContinue = () => {
@@ -313,6 +445,42 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
         }
       }
 
+      // Per-side price caps (MAX_BUY_PRICE / MIN_SELL_PRICE) - applied even
+      // when forced, since they are the operator's absolute limits
+      const capViolation = checkSidePriceCap(levelPrice, side);
+      if (capViolation) {
+        logger?.warn?.(
+          `⚠️ [ORDER] Price cap for ${tokenId.slice(0, 8)}...: ${capViolation}`,
+        );
+        return {
+          success: false,
+          reason: "PRICE_CAP_EXCEEDED",
+          detail: capViolation,
+        };
+      }
+
+      // Price-band sanity check against recent midpoint/VWAP history
+      // (skipped when forced or in NUCLEAR mode - emergency exits must go out)
+      if (!input.force && maxAcceptablePrice !== undefined) {
+        const band = checkPriceBand(
+          levelPrice,
+          getMarketDataStore().getRecentPriceRange(
+            tokenId,
+            PRICE_BAND_WINDOW_MS,
+          ),
+        );
+        if (!band.ok) {
+          logger?.warn?.(
+            `⚠️ [ORDER] Price out of band for ${tokenId.slice(0, 8)}...: ${band.detail}`,
+          );
+          return {
+            success: false,
+            reason: "PRICE_OUT_OF_BAND",
+            detail: band.detail,
+          };
+        }
+      }
+
       // Calculate order size based on available liquidity
       // Convert USD amount to shares using current price
       const levelValue = levelSize * levelPrice;
@@ -326,11 +494,16 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
         amount = Math.min(remainingShares, orderShares);
       }
 
+      let submitted = false;
       try {
+        // Market orders take USDC for a BUY and shares for a SELL
         const signedOrder = await client.createMarketOrder({
           side: orderSide,
           tokenID: tokenId,
-          amount: amount,
+          amount: marketOrderAmount(
+            side,
+            convertOrderSize({ shares: amount }, levelPrice),
+          ),
           price: levelPrice,
         });
 
@@ -339,6 +512,7 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
         // GTC = Post as limit order and WAIT for fill (sits on orderbook)
         const clobOrderType =
           orderType === "GTC" ? OrderType.GTC : OrderType.FOK;
+        submitted = true;
         const response = await client.postOrder(signedOrder, clobOrderType);
 
         if (response.success) {
@@ -371,107 +545,69 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
           retryCount = 0; // Reset retry count on success
         } else {
           retryCount++;
-          // Extract clean error message from response
-          const errorObj = response as any;
-          let cleanMessage = "";
-          let reasonCode = "";
-
-          if (errorObj?.errorMsg) {
-            cleanMessage = errorObj.errorMsg;
-          } else if (errorObj?.error) {
-            cleanMessage = errorObj.error;
-          } else {
-            cleanMessage = "Unknown error";
-          }
+          lastError = parseClobError(response, CLOB_POST_ORDER_ENDPOINT);
 
           // Check for Cloudflare block
-          if (isCloudflareBlock(cleanMessage) || isCloudflareBlock(response)) {
+          if (lastError.errorCode === ErrorCode.CLOUDFLARE_BLOCKED) {
             logger?.error?.(
               `Order blocked by Cloudflare (403). Your IP may be geo-blocked. Consider using a VPN.`,
             );
-            return { success: false, reason: "CLOUDFLARE_BLOCKED" };
-          }
-
-          // Check for specific error types
-          const lowerMsg = cleanMessage.toLowerCase();
-
-          if (
-            lowerMsg.includes("not enough balance") ||
-            lowerMsg.includes("insufficient balance")
-          ) {
-            reasonCode = "INSUFFICIENT_BALANCE";
-          } else if (
-            lowerMsg.includes("not enough allowance") ||
-            lowerMsg.includes("insufficient allowance")
-          ) {
-            reasonCode = "INSUFFICIENT_ALLOWANCE";
-          } else if (
-            lowerMsg.includes("price exceeds max") ||
-            lowerMsg.includes("slippage")
-          ) {
-            reasonCode = "PRICE_SLIPPAGE";
+            return {
+              success: false,
+              reason: "CLOUDFLARE_BLOCKED",
+              ...clobErrorFields(lastError),
+            };
           }
 
-          lastErrorReason = reasonCode || cleanMessage; // Track for final return
+          // Track for final return
+          lastErrorReason = clobErrorReason(lastError);
           logger?.warn?.(`Order attempt failed: ${lastErrorReason}`);
         }
       } catch (err) {
         retryCount++;
+        // Read the CLOB's error fields instead of stringifying the error
+        lastError = parseClobError(err, CLOB_POST_ORDER_ENDPOINT);
+
         // Check for Cloudflare block in error
         if (isCloudflareBlock(err)) {
           logger?.error?.(
             `Order blocked by Cloudflare (403). Your IP may be geo-blocked. Consider using a VPN.`,
           );
-          return { success: false, reason: "CLOUDFLARE_BLOCKED" };
-        }
-
-        // Extract clean error message
-        const errorObj = err as any;
-        let cleanMessage = "";
-        let reasonCode = "";
-
-        // Try to extract the actual error message from common CLOB response structures
-        if (errorObj?.response?.data?.error) {
-          cleanMessage = errorObj.response.data.error;
-        } else if (errorObj?.data?.error) {
-          cleanMessage = errorObj.data.error;
-        } else if (errorObj?.message) {
-          cleanMessage = errorObj.message;
-        } else {
-          cleanMessage = String(err);
-        }
-
-        // Check for specific error types and assign reason codes
-        const lowerMsg = cleanMessage.toLowerCase();
-
-        if (
-          lowerMsg.includes("not enough balance") ||
-          lowerMsg.includes("insufficient balance")
-        ) {
-          reasonCode = "INSUFFICIENT_BALANCE";
-          cleanMessage = `Insufficient balance: ${cleanMessage}`;
-        } else if (
-          lowerMsg.includes("not enough allowance") ||
-          lowerMsg.includes("insufficient allowance")
-        ) {
-          reasonCode = "INSUFFICIENT_ALLOWANCE";
-          cleanMessage = `Insufficient allowance: ${cleanMessage}`;
-        } else if (
-          lowerMsg.includes("price exceeds max") ||
-          lowerMsg.includes("slippage")
-        ) {
-          reasonCode = "PRICE_SLIPPAGE";
-          cleanMessage = `Price slippage: ${cleanMessage}`;
-        } else {
-          reasonCode = formatErrorForLog(err);
+          return {
+            success: false,
+            reason: "CLOUDFLARE_BLOCKED",
+            ...clobErrorFields(lastError),
+          };
         }
 
-        logger?.warn?.(`Order execution error: ${cleanMessage}`);
+        const reasonCode = clobErrorReason(lastError);
+        // Unclassified errors keep the redacted, length-limited form
+        lastErrorReason =
+          reasonCode === lastError.errorDetail
+            ? formatErrorForLog(err)
+            : reasonCode;
 
-        lastErrorReason = reasonCode || cleanMessage; // Track for final return
+        logger?.warn?.(`Order execution error: ${lastError.errorDetail}`);
 
         if (retryCount >= ORDER.MAX_RETRIES) {
-          return { success: false, reason: lastErrorReason };
+          return {
+            success: false,
+            reason: lastErrorReason,
+            ...clobErrorFields(lastError),
+            sizeConversion,
+          };
+        }
+
+        // The submit threw after it was sent - the order may be live, and a
+        // resend is re-signed with a new salt, so it would be a second order
+        if (submitted) {
+          logger?.warn?.(`Order submit outcome unknown - not retrying`);
+          return {
+            success: false,
+            reason: lastErrorReason,
+            ...clobErrorFields(lastError),
+            sizeConversion,
+          };
         }
       }
     }
@@ -484,6 +620,7 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
         success: true,
         filledUsd: totalFilled,
         avgPrice: totalShares > 0 ? totalFilled / totalShares : 0,
+        sizeConversion,
       };
     }
 
@@ -493,6 +630,8 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
     return {
       success: false,
       reason: lastErrorReason !== "NO_ERROR" ? lastErrorReason : "NO_FILLS",
+      ...clobErrorFields(lastError),
+      sizeConversion,
     };
   } catch (err) {
     // Check for Cloudflare block
@@ -517,11 +656,109 @@ export async function postOrder(input: PostOrderInput): Promise<OrderResult> {
 }
 
 /**
- * Clear cooldowns (for testing)
+ * Order failure reason for a CLOB error - the reason codes callers act on
+ * for balance/allowance/slippage rejections, the server's message otherwise
+ */
+function clobErrorReason(error: ClobErrorInfo): string {
+  if (
+    error.errorCode === ErrorCode.INSUFFICIENT_BALANCE ||
+    error.errorCode === ErrorCode.INSUFFICIENT_ALLOWANCE
+  ) {
+    return error.errorCode;
+  }
+  const lowerMsg = error.errorDetail.toLowerCase();
+  if (lowerMsg.includes("price exceeds max") || lowerMsg.includes("slippage")) {
+    return "PRICE_SLIPPAGE";
+  }
+  return error.errorDetail;
+}
+
+/**
+ * Fetch a CLOB market, cached for ORDER.MARKET_STATUS_TTL_MS
+ */
+async function getMarketStatus(
+  client: ClobClient,
+  marketId: string,
+): Promise<any> {
+  const cached = marketStatusCache.get(marketId);
+  if (cached) return cached;
+
+  const market = await client.getMarket(marketId);
+  if (market) {
+    marketStatusCache.set(marketId, market);
+  }
+  return market;
+}
+
+/**
+ * Check a CLOB market's status fields before signing an order.
+ *
+ * Returns why the market can't take the order, or null if it can.
+ * The end-date check only blocks BUYs - markets often keep trading past
+ * their end date until resolution, and exits should still be attempted.
+ */
+export function getNotTradableReason(
+  market: {
+    closed?: boolean;
+    active?: boolean;
+    accepting_orders?: boolean;
+    end_date_iso?: string;
+  },
+  side: OrderSide,
+  now = Date.now(),
+): string | null {
+  if (market.closed === true) return "market closed";
+  if (market.active === false) return "market inactive";
+  if (market.accepting_orders === false) return "not accepting orders";
+
+  if (side === "BUY" && market.end_date_iso) {
+    const endMs = Date.parse(market.end_date_iso);
+    if (Number.isFinite(endMs) && endMs <= now) return "past end date";
+  }
+
+  return null;
+}
+
+/**
+ * Check the market allow/deny lists for an entry on `tokenId`.
+ *
+ * Resolves the condition ID from the token when `marketId` is missing, and
+ * fetches the CLOB market (cached) for its tags. Returns why the entry is
+ * not allowed, or null if it is (always null when no lists are configured).
+ */
+export async function checkMarketAllowed(
+  client: ClobClient,
+  tokenId: string,
+  marketId?: string,
+): Promise<string | null> {
+  if (!isMarketFilterActive()) return null;
+
+  const conditionId =
+    marketId ?? (await fetchMarketByTokenId(tokenId))?.conditionId;
+  if (!conditionId) return "market unknown";
+
+  let tags: string[] | undefined;
+  try {
+    const market = await getMarketStatus(client, conditionId);
+    if (Array.isArray(market?.tags)) tags = market.tags;
+  } catch {
+    // Tags unknown - allow-tag lists fail closed, deny-tag lists pass
+  }
+
+  return getMarketFilterReason({
+    condition_id: conditionId,
+    tags,
+    tagRefs: getTagTaxonomy().getTagRefsForMarket(conditionId),
+  });
+}
+
+/**
+ * Clear cooldowns and cached market status (for testing)
  */
 export function clearCooldowns(): void {
   inFlight.clear();
   marketCooldown.clear();
+  marketStatusCache.clear();
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -744,6 +981,31 @@ export class GtcOrderTracker {
     return this.cancelOrders(client, orders);
   }
 
+  /**
+   * Export tracked orders for persistence (state snapshots)
+   */
+  exportOrders(): TrackedGtcOrder[] {
+    return this.getOrders().map((o) => ({ ...o }));
+  }
+
+  /**
+   * Restore tracked orders from a state snapshot
+   * Orders already past their expiry are skipped - the expiry check would
+   * cancel them immediately anyway.
+   *
+   * @returns Number of orders restored
+   */
+  importOrders(orders: TrackedGtcOrder[]): number {
+    const now = Date.now();
+    let restored = 0;
+    for (const order of orders) {
+      if (this.orders.has(order.orderId) || order.expiresAt <= now) continue;
+      this.orders.set(order.orderId, { ...order });
+      restored++;
+    }
+    return restored;
+  }
+
   /**
    * Get summary stats for logging
    */
@@ -8,6 +8,15 @@ import { Contract, type Wallet, parseUnits, formatUnits } from "ethers";
 import { POLYGON } from "./constants";
 import type { Logger } from "./types";
 import type { PresetConfig } from "./presets";
+import {
+  getGasBudget,
+  estimateTxCostPol,
+  receiptFeePol,
+  GAS_ESTIMATES,
+  GAS_BUDGET_EXCEEDED,
+  type GasReservation,
+} from "./gas-budget";
+import { getTxWallet } from "./private-tx";
 
 // QuickSwap V3 Router on Polygon
 const QUICKSWAP_ROUTER = "0xf5b509bB0909a69B1c207E495f687a596C168E12";
@@ -144,26 +153,48 @@ export function calculateMinOutput(
 
 /**
  * Swap USDC to POL via QuickSwap
+ *
+ * @param privateTx - Send via the private relay (default PRIVATE_TX_DEFAULT)
  */
 export async function swapUsdcToPol(
   wallet: Wallet,
   usdcAmount: number,
   slippagePct: number,
   logger?: Logger,
+  privateTx?: boolean,
 ): Promise<SwapResult> {
+  const gasBudget = getGasBudget();
+  let reservation: GasReservation | null = null;
+  let txSent = false;
+  let spentPol = 0;
   try {
     const address = await wallet.getAddress();
 
+    // Gas budget: reserve approve + swap + unwrap before sending anything
+    const feeData = await wallet.provider?.getFeeData();
+    reservation = gasBudget.reserve(
+      "swap",
+      estimateTxCostPol(
+        GAS_ESTIMATES.approve + GAS_ESTIMATES.swap + GAS_ESTIMATES.unwrap,
+        feeData?.maxFeePerGas ?? feeData?.gasPrice ?? 0n,
+      ),
+    );
+    if (!reservation) {
+      return { success: false, error: GAS_BUDGET_EXCEEDED };
+    }
+
     // Convert USDC amount to wei (6 decimals)
     const amountIn = parseUnits(usdcAmount.toFixed(6), POLYGON.USDC_DECIMALS);
 
-    // Create contract instances
-    const usdcContract = new Contract(POLYGON.USDC_ADDRESS, ERC20_ABI, wallet);
-    const wpolContract = new Contract(WPOL_ADDRESS, WPOL_ABI, wallet);
+    // Create contract instances (broadcasting via the private relay when
+    // enabled - a public swap can be sandwiched)
+    const txWallet = getTxWallet(wallet, privateTx);
+    const usdcContract = new Contract(POLYGON.USDC_ADDRESS, ERC20_ABI, txWallet);
+    const wpolContract = new Contract(WPOL_ADDRESS, WPOL_ABI, txWallet);
     const routerContract = new Contract(
       QUICKSWAP_ROUTER,
       QUICKSWAP_ROUTER_ABI,
-      wallet,
+      txWallet,
     );
 
     // Check and set allowance if needed - approve max to avoid repeated approval transactions
@@ -175,11 +206,12 @@ export async function swapUsdcToPol(
       logger?.info?.(`Approving USDC for QuickSwap...`);
       const maxUint256 =
         "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+      txSent = true;
       const approveTx = await usdcContract.approve(
         QUICKSWAP_ROUTER,
         maxUint256,
       );
-      await approveTx.wait();
+      spentPol += receiptFeePol(await approveTx.wait());
       logger?.info?.(`USDC approved`);
     }
 
@@ -209,8 +241,10 @@ export async function swapUsdcToPol(
       limitSqrtPrice: 0, // No price limit
     };
 
+    txSent = true;
     const swapTx = await routerContract.exactInputSingle(swapParams);
     const receipt = await swapTx.wait();
+    spentPol += receiptFeePol(receipt);
 
     // Get actual WPOL received by comparing balances
     const wpolBalanceAfter = await wpolContract.balanceOf(address);
@@ -221,7 +255,8 @@ export async function swapUsdcToPol(
     // WPOL.withdraw() burns WPOL from caller's balance and sends native POL
     logger?.info?.(`Unwrapping WPOL to native POL...`);
     const unwrapTx = await wpolContract.withdraw(wpolReceived);
-    await unwrapTx.wait();
+    spentPol += receiptFeePol(await unwrapTx.wait());
+    gasBudget.settle(reservation, spentPol);
 
     logger?.info?.(
       `✅ POL Swap complete | ${usdcAmount.toFixed(2)} USDC → ${polReceived.toFixed(2)} POL`,
@@ -234,6 +269,14 @@ export async function swapUsdcToPol(
       txHash: receipt.hash,
     };
   } catch (error) {
+    // Count the worst case once a tx went out (its fee may be unknown)
+    if (reservation) {
+      gasBudget.settle(
+        reservation,
+        txSent ? Math.max(spentPol, reservation.reservedPol) : spentPol,
+      );
+    }
+
     const errorMsg = error instanceof Error ? error.message : String(error);
     logger?.error?.(`POL swap failed: ${errorMsg}`);
     return {
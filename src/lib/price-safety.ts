@@ -28,6 +28,8 @@
  */
 
 import { isGitHubActions, ghWarning, ghError } from "./diag-mode";
+import { createBoundedCache } from "../infra/persistence/lru-cache";
+import { emitEvent } from "../infra/event-bus";
 
 // ═══════════════════════════════════════════════════════════════════════════
 // CONSTANTS - Two-layer bounds system
@@ -74,6 +76,14 @@ export const HARD_MAX_PRICE = 0.99;
 export const STRATEGY_MIN_PRICE = envNum("ORDER_MIN_PRICE", 0.35);
 export const STRATEGY_MAX_PRICE = envNum("ORDER_MAX_PRICE", 0.65);
 
+/**
+ * SIDE PRICE CAPS - Absolute per-side limits (never clamped, always rejected)
+ * Never buy above MAX_BUY_PRICE, never sell below MIN_SELL_PRICE.
+ * Defaults are the HARD bounds, i.e. no extra restriction.
+ */
+export const MAX_BUY_PRICE = envNum("MAX_BUY_PRICE", HARD_MAX_PRICE);
+export const MIN_SELL_PRICE = envNum("MIN_SELL_PRICE", HARD_MIN_PRICE);
+
 // Aliases for backward compatibility
 export const MIN_PRICE = STRATEGY_MIN_PRICE;
 export const MAX_PRICE = STRATEGY_MAX_PRICE;
@@ -612,7 +622,7 @@ export function logPriceFormation(
   };
 
   // Emit structured JSON log
-  console.log(JSON.stringify(logEvent));
+  emitEvent(logEvent);
 
   // Emit GitHub Actions warnings for price clamping or rejections
   if (result.rejectionReason) {
@@ -850,7 +860,8 @@ export interface ComputeExecutionPriceResult {
     | "PRICE_NAN"
     | "ASK_ABOVE_MAX"
     | "BID_BELOW_MIN"
-    | "CROSSED_BOOK";
+    | "CROSSED_BOOK"
+    | "PRICE_CAP_EXCEEDED";
 }
 
 /**
@@ -1231,6 +1242,38 @@ export function computeExecutionLimitPrice(
   const clampDirection =
     finalClampDirection || hardClampDirection || strategyClampDirection;
 
+  // Step 8b: Per-side price caps - reject rather than clamp, since a clamped
+  // BUY below the ask (or SELL above the bid) can never fill anyway
+  const capViolation = checkSidePriceCap(limitPrice, side);
+  if (capViolation) {
+    console.log(
+      JSON.stringify({
+        event: "ORDER_PRICE_DEBUG",
+        result: "REJECTED",
+        reason: "PRICE_CAP_EXCEEDED",
+        tokenIdPrefix,
+        side,
+        bestBid: bestBid.toFixed(4),
+        bestAsk: bestAsk.toFixed(4),
+        limitPrice: limitPrice.toFixed(4),
+        maxBuyPrice: MAX_BUY_PRICE,
+        minSellPrice: MIN_SELL_PRICE,
+        message: capViolation,
+        timestamp: new Date().toISOString(),
+      }),
+    );
+
+    return {
+      success: false,
+      limitPrice: 0,
+      basePrice,
+      rawPrice,
+      wasClamped,
+      clampDirection,
+      rejectionReason: "PRICE_CAP_EXCEEDED",
+    };
+  }
+
   // Step 9: Detailed logging with all bounds info
   const mid = (bestBid + bestAsk) / 2;
   const spreadCents = (bestAsk - bestBid) * 100;
@@ -1304,10 +1347,11 @@ export function computeExecutionLimitPrice(
  * Key: tokenId or marketId
  * Value: { tickSize: number, isDefault: boolean, fetchedAt: number }
  */
-const tickSizeCache = new Map<
-  string,
-  { tickSize: number; isDefault: boolean; fetchedAt: number }
->();
+const tickSizeCache = createBoundedCache<{
+  tickSize: number;
+  isDefault: boolean;
+  fetchedAt: number;
+}>("TickSizeCache");
 
 /** Cache TTL for tick sizes (5 minutes) */
 const TICK_SIZE_CACHE_TTL_MS = 5 * 60 * 1000;
@@ -1421,6 +1465,28 @@ export function fromApiPriceUnits(apiPrice: number): number {
   return apiPrice;
 }
 
+/**
+ * Check a price against the per-side caps (MAX_BUY_PRICE / MIN_SELL_PRICE).
+ *
+ * @param price - Limit price in dollars
+ * @param side - Order side ("BUY" or "SELL")
+ * @returns Description of the violation, or null if the price is allowed
+ */
+export function checkSidePriceCap(
+  price: number,
+  side: "BUY" | "SELL",
+  maxBuyPrice: number = MAX_BUY_PRICE,
+  minSellPrice: number = MIN_SELL_PRICE,
+): string | null {
+  if (side === "BUY" && price > maxBuyPrice) {
+    return `BUY price ${price} above MAX_BUY_PRICE ${maxBuyPrice}`;
+  }
+  if (side === "SELL" && price < minSellPrice) {
+    return `SELL price ${price} below MIN_SELL_PRICE ${minSellPrice}`;
+  }
+  return null;
+}
+
 /**
  * Assert that a final limit price is within both HARD bounds and strategy bounds.
  *
@@ -1447,6 +1513,11 @@ export function assertValidLimitPrice(
     );
   }
 
+  const capViolation = checkSidePriceCap(limitPrice, side);
+  if (capViolation) {
+    throw new Error(`Invalid limit price: ${capViolation}${ctx}`);
+  }
+
   // Strategy bounds check (warning, not error - already clamped)
   if (side === "BUY" && limitPrice > STRATEGY_MAX_PRICE) {
     console.warn(
@@ -1665,3 +1736,66 @@ export function logOrderRejection(diag: OrderRejectionDiagnostic): void {
       `error="${diag.errorMessage}"`,
   );
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRICE BAND - Soft sanity check against recent history
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Catches fat-finger prices and decimal-place mistakes (e.g. 0.045 instead
+// of 0.45) before they reach the exchange. An order is out of band if it is
+// more than PRICE_BAND_PCT away from the recent range of midpoint/VWAP, with
+// a floor of PRICE_BAND_MIN_CENTS so one-tick moves on cheap tokens pass.
+// Callers can bypass with `force`.
+// ═══════════════════════════════════════════════════════════════════════════
+
+/** Max % distance from the recent price range (0 = disabled) */
+export const PRICE_BAND_PCT = envNum("PRICE_BAND_PCT", 25);
+
+/** Minimum allowed distance in cents regardless of PRICE_BAND_PCT */
+export const PRICE_BAND_MIN_CENTS = envNum("PRICE_BAND_MIN_CENTS", 5);
+
+/** How far back midpoint/VWAP history counts as "recent" (ms) */
+export const PRICE_BAND_WINDOW_MS = envNum("PRICE_BAND_WINDOW_MS", 300000);
+
+/** Recent reference prices for a token (decimal 0-1) */
+export interface RecentPriceRange {
+  low: number;
+  high: number;
+}
+
+export interface PriceBandResult {
+  ok: boolean;
+  /** Allowed band (decimal) - undefined when there is no reference */
+  minAllowed?: number;
+  maxAllowed?: number;
+  detail?: string;
+}
+
+/**
+ * Check an order price against the recent price range.
+ * With no reference range (no recent data) the check passes.
+ */
+export function checkPriceBand(
+  price: number,
+  range: RecentPriceRange | null,
+  bandPct: number = PRICE_BAND_PCT,
+  minBandCents: number = PRICE_BAND_MIN_CENTS,
+): PriceBandResult {
+  if (!range || bandPct <= 0) return { ok: true };
+
+  const pct = bandPct / 100;
+  const minBand = minBandCents / 100;
+  const minAllowed = range.low - Math.max(range.low * pct, minBand);
+  const maxAllowed = range.high + Math.max(range.high * pct, minBand);
+
+  if (price < minAllowed || price > maxAllowed) {
+    return {
+      ok: false,
+      minAllowed,
+      maxAllowed,
+      detail: `${(price * 100).toFixed(2)}¢ outside ${(minAllowed * 100).toFixed(2)}-${(maxAllowed * 100).toFixed(2)}¢ (recent ${(range.low * 100).toFixed(2)}-${(range.high * 100).toFixed(2)}¢)`,
+    };
+  }
+
+  return { ok: true, minAllowed, maxAllowed };
+}
@@ -0,0 +1,116 @@
+/**
+ * Private Transactions - Submit on-chain txs through a private relay
+ *
+ * Approvals, redeems and POL reserve swaps broadcast to the public mempool
+ * by default, where a large redeem or swap can be spotted and targeted.
+ * With a private relay configured, signed transactions are sent to the
+ * relay instead (eth_sendRawTransaction) while every read - nonce, gas,
+ * receipts - still goes through the normal RPC:
+ *
+ *   PRIVATE_TX_RPC_URL=https://<relay>/...
+ *   PRIVATE_TX_DEFAULT=true      # use the relay unless a tx opts out
+ *
+ * Each transaction can override the default (redeemPosition / swapUsdcToPol
+ * take a privateTx flag). If the relay rejects a transaction it fails - it
+ * is never silently re-sent through the public mempool.
+ */
+
+import {
+  JsonRpcProvider,
+  Transaction,
+  type TransactionResponse,
+  type TransactionResponseParams,
+  type Wallet,
+} from "ethers";
+import { envBool, envStr } from "../config/env";
+import { POLYGON } from "./constants";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONFIG
+// ═══════════════════════════════════════════════════════════════════════════
+
+export const PRIVATE_TX_RPC_URL = envStr("PRIVATE_TX_RPC_URL", "");
+export const PRIVATE_TX_DEFAULT = envBool("PRIVATE_TX_DEFAULT", true);
+
+/**
+ * Whether a transaction goes through the private relay
+ *
+ * @param privateTx - Per-tx flag (undefined = PRIVATE_TX_DEFAULT)
+ */
+export function shouldUsePrivateTx(
+  privateTx?: boolean,
+  relayUrl: string = PRIVATE_TX_RPC_URL,
+  defaultPrivate: boolean = PRIVATE_TX_DEFAULT,
+): boolean {
+  if (!relayUrl) return false;
+  return privateTx ?? defaultPrivate;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRIVATE RELAY PROVIDER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * JSON-RPC provider that reads from the regular RPC but broadcasts signed
+ * transactions to a private relay
+ */
+export class PrivateRelayProvider extends JsonRpcProvider {
+  private readonly relay: JsonRpcProvider;
+
+  constructor(rpcUrl: string, relayUrl: string) {
+    super(rpcUrl, POLYGON.CHAIN_ID, { staticNetwork: true });
+    this.relay = new JsonRpcProvider(relayUrl, POLYGON.CHAIN_ID, {
+      staticNetwork: true,
+    });
+  }
+
+  async broadcastTransaction(signedTx: string): Promise<TransactionResponse> {
+    const tx = Transaction.from(signedTx);
+    const hash: string = await this.relay.send("eth_sendRawTransaction", [
+      signedTx,
+    ]);
+    if (hash.toLowerCase() !== tx.hash?.toLowerCase()) {
+      throw new Error(`Private relay returned unexpected tx hash ${hash}`);
+    }
+
+    console.log(`🔒 [PrivateTx] Submitted ${hash} via private relay`);
+    const [network, blockNumber] = await Promise.all([
+      this.getNetwork(),
+      this.getBlockNumber(),
+    ]);
+    // Same wrapping as AbstractProvider.broadcastTransaction
+    return this._wrapTransactionResponse(
+      tx as unknown as TransactionResponseParams,
+      network,
+    ).replaceableTransaction(blockNumber);
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SIGNER SELECTION
+// ═══════════════════════════════════════════════════════════════════════════
+
+const relayProviders = new Map<string, PrivateRelayProvider>();
+
+/**
+ * Wallet to send a transaction with: the wallet itself, or the same key
+ * connected through the private relay
+ *
+ * @param privateTx - Per-tx flag (undefined = PRIVATE_TX_DEFAULT)
+ */
+export function getTxWallet(wallet: Wallet, privateTx?: boolean): Wallet {
+  if (!shouldUsePrivateTx(privateTx)) return wallet;
+
+  const provider = wallet.provider;
+  if (!(provider instanceof JsonRpcProvider)) {
+    throw new Error("Private relay needs a JSON-RPC wallet provider");
+  }
+  const rpcUrl = provider._getConnection().url;
+
+  let relayProvider = relayProviders.get(rpcUrl);
+  if (!relayProvider) {
+    relayProvider = new PrivateRelayProvider(rpcUrl, PRIVATE_TX_RPC_URL);
+    relayProviders.set(rpcUrl, relayProvider);
+  }
+  return wallet.connect(relayProvider);
+}
@@ -8,6 +8,15 @@ import { ethers, type Wallet } from "ethers";
 import axios from "axios";
 import { POLYGON, POLYMARKET_API, CTF_ABI, PROXY_ABI } from "./constants";
 import type { Logger } from "./types";
+import {
+  getGasBudget,
+  estimateTxCostPol,
+  receiptFeePol,
+  GAS_ESTIMATES,
+  GAS_BUDGET_EXCEEDED,
+  type GasReservation,
+} from "./gas-budget";
+import { getTxWallet } from "./private-tx";
 
 export interface RedeemablePosition {
   conditionId: string;
@@ -16,6 +25,8 @@ export interface RedeemablePosition {
   outcome: string;
   size: number;
   value: number;
+  /** What the position cost (initialValue, if the API gave one) */
+  costUsd?: number;
 }
 
 export interface RedeemResult {
@@ -160,11 +171,13 @@ export async function fetchRedeemablePositions(
       // Value might be 0 for losing positions - that's OK, we still need to redeem them!
       const value =
         Number(pos.value) || Number(pos.size) * Number(pos.curPrice || 0) || 0;
+      const costUsd = Number(pos.initialValue) || 0;
 
       if (existing) {
         // Aggregate positions in same market
         existing.size += size;
         existing.value += value;
+        existing.costUsd = (existing.costUsd ?? 0) + costUsd;
       } else {
         conditionMap.set(pos.conditionId, {
           conditionId: pos.conditionId,
@@ -173,6 +186,7 @@ export async function fetchRedeemablePositions(
           outcome: pos.outcome || "Unknown",
           size: size,
           value: value,
+          costUsd,
         });
       }
     }
@@ -198,13 +212,19 @@ export async function fetchRedeemablePositions(
 /**
  * Redeem a single position using CTF contract
  * Based on Milan's proven logic
+ *
+ * @param privateTx - Send via the private relay (default PRIVATE_TX_DEFAULT)
  */
 export async function redeemPosition(
   conditionId: string,
   wallet: Wallet,
   address: string,
   logger?: Logger,
+  privateTx?: boolean,
 ): Promise<RedeemResult> {
+  const gasBudget = getGasBudget();
+  let reservation: GasReservation | null = null;
+  let txSent = false;
   try {
     console.log(
       `🎁 [Redeem] Starting redemption for conditionId: ${conditionId.slice(0, 16)}...`,
@@ -215,6 +235,8 @@ export async function redeemPosition(
     if (!provider) {
       throw new Error("Wallet has no provider");
     }
+    // Same key, but broadcasting through the private relay when enabled
+    const txWallet = getTxWallet(wallet, privateTx);
 
     // Check for proxy wallet (cached)
     const proxyAddress = await getProxyAddress(address, logger);
@@ -238,6 +260,15 @@ export async function redeemPosition(
     if (maxPriorityFee) txDetails.maxPriorityFeePerGas = maxPriorityFee;
     if (maxFee) txDetails.maxFeePerGas = maxFee;
 
+    // Gas budget: reserve the worst-case cost before sending
+    reservation = gasBudget.reserve(
+      "redeem",
+      estimateTxCostPol(GAS_ESTIMATES.redeem, maxFee ?? feeData.gasPrice ?? 0n),
+    );
+    if (!reservation) {
+      return { success: false, conditionId, error: GAS_BUDGET_EXCEEDED };
+    }
+
     // Create CTF interface
     const ctfInterface = new ethers.Interface(CTF_ABI);
 
@@ -261,7 +292,7 @@ export async function redeemPosition(
       const proxyContract = new ethers.Contract(
         proxyAddress,
         PROXY_ABI,
-        wallet,
+        txWallet,
       );
       tx = await proxyContract.proxy(
         POLYGON.CTF_ADDRESS,
@@ -275,7 +306,7 @@ export async function redeemPosition(
       const ctfContract = new ethers.Contract(
         POLYGON.CTF_ADDRESS,
         CTF_ABI,
-        wallet,
+        txWallet,
       );
       tx = await ctfContract.redeemPositions(
         POLYGON.USDC_ADDRESS,
@@ -286,6 +317,7 @@ export async function redeemPosition(
       );
     }
 
+    txSent = true;
     console.log(`🎁 [Redeem] ⏳ Transaction sent: ${tx.hash}`);
     logger?.info?.(`⏳ Transaction sent: ${tx.hash.slice(0, 16)}...`);
 
@@ -301,6 +333,7 @@ export async function redeemPosition(
 
       const receipt = await Promise.race([tx.wait(), timeoutPromise]);
 
+      gasBudget.settle(reservation, receiptFeePol(receipt));
       console.log(`🎁 [Redeem] ✅ Confirmed in block ${receipt.blockNumber}`);
       logger?.info?.(`✅ Confirmed in block ${receipt.blockNumber}`);
 
@@ -314,6 +347,12 @@ export async function redeemPosition(
       if (timeoutId) clearTimeout(timeoutId);
     }
   } catch (error) {
+    // Refund the reservation unless the tx went out (then its fee is
+    // unknown - count the worst case)
+    if (reservation) {
+      gasBudget.settle(reservation, txSent ? reservation.reservedPol : 0);
+    }
+
     const errorMsg = error instanceof Error ? error.message : String(error);
     console.error(
       `🎁 [Redeem] ❌ Redemption failed for ${conditionId.slice(0, 16)}...: ${errorMsg}`,
@@ -331,11 +370,14 @@ export async function redeemPosition(
 /**
  * Redeem all redeemable positions
  * Returns total value redeemed
+ *
+ * @param privateTx - Send via the private relay (default PRIVATE_TX_DEFAULT)
  */
 export async function redeemAllPositions(
   wallet: Wallet,
   address: string,
   logger?: Logger,
+  privateTx?: boolean,
 ): Promise<{ redeemed: number; failed: number; totalValue: number }> {
   console.log(`━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━`);
   console.log(`🎁 AUTO-REDEEM: Checking for resolved positions...`);
@@ -390,11 +432,17 @@ export async function redeemAllPositions(
       wallet,
       address,
       logger,
+      privateTx,
     );
 
     if (result.success) {
       redeemed++;
       console.log(`🎁 [Redeem] ✅ Success! (${redeemed}/${positions.length})`);
+    } else if (result.error === GAS_BUDGET_EXCEEDED) {
+      // Remaining redemptions wait for the next run (bucket refills)
+      failed += positions.length - redeemed - failed;
+      console.log(`🎁 [Redeem] ⛽ Gas budget exhausted - deferring the rest`);
+      break;
     } else {
       failed++;
       console.log(`🎁 [Redeem] ❌ Failed: ${result.error}`);
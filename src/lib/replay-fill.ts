@@ -0,0 +1,143 @@
+/**
+ * Fill Replay - Would this order have filled, and at what price?
+ *
+ * Post-mortems on missed or bad snipes need the book as it stood when the
+ * order would have gone out, not as it is now. The market data store keeps
+ * a short history of L2 snapshots per token (MARKETDATA_BOOK_HISTORY_PER_TOKEN)
+ * plus recent trade prints; this walks the snapshot in force at a given time
+ * to find the immediate fill, then checks later prints for the remainder:
+ *
+ *   replayFillAt(tokenId, Date.parse("2026-01-15T12:00:00Z"), {
+ *     side: "BUY",
+ *     price: 0.62,
+ *     shares: 50,
+ *   });
+ *
+ * Only what the store recorded can be replayed - history older than the
+ * retained snapshots returns NO_BOOK.
+ */
+
+import {
+  getMarketDataStore,
+  type MarketDataStore,
+  type OrderbookSnapshot,
+  type TradePrint,
+} from "./market-data-store";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface ReplayOrder {
+  side: "BUY" | "SELL";
+  /** Limit price (0-1) */
+  price: number;
+  shares: number;
+}
+
+export type ReplayFillStatus = "FILLED" | "PARTIAL" | "UNFILLED" | "NO_BOOK";
+
+export interface ReplayFillResult {
+  status: ReplayFillStatus;
+  /** Timestamp of the snapshot replayed against (null = none recorded) */
+  bookAt: number | null;
+  /** Shares that would have crossed the book immediately */
+  filledShares: number;
+  /** VWAP of the immediate fill (null when nothing crossed) */
+  avgPrice: number | null;
+  /** USD spent (BUY) or received (SELL) on the immediate fill */
+  notionalUsd: number;
+  /** Best opposite price in the snapshot (null = empty side) */
+  touchPrice: number | null;
+  /** Shares left resting at the limit after the immediate fill */
+  restingShares: number;
+  /**
+   * Shares printed at or through the limit after the snapshot, capped at
+   * restingShares - a lower bound on what would likely have filled later
+   */
+  tradedThroughShares: number;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REPLAY
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Replay an order against a single book snapshot, optionally checking
+ * trade prints that came after it for the unfilled remainder
+ */
+export function replayFill(
+  book: OrderbookSnapshot | null,
+  order: ReplayOrder,
+  laterTrades: TradePrint[] = [],
+): ReplayFillResult {
+  if (!book) {
+    return {
+      status: "NO_BOOK",
+      bookAt: null,
+      filledShares: 0,
+      avgPrice: null,
+      notionalUsd: 0,
+      touchPrice: null,
+      restingShares: order.shares,
+      tradedThroughShares: 0,
+    };
+  }
+
+  const isBuy = order.side === "BUY";
+  const levels = [...(isBuy ? book.asks : book.bids)].sort((a, b) =>
+    isBuy ? a.price - b.price : b.price - a.price,
+  );
+  const crosses = (price: number) =>
+    isBuy ? price <= order.price : price >= order.price;
+
+  let filledShares = 0;
+  let notionalUsd = 0;
+  for (const level of levels) {
+    if (filledShares >= order.shares || !crosses(level.price)) break;
+    const take = Math.min(level.size, order.shares - filledShares);
+    filledShares += take;
+    notionalUsd += take * level.price;
+  }
+
+  const restingShares = Math.max(0, order.shares - filledShares);
+  let tradedThroughShares = 0;
+  if (restingShares > 0) {
+    const printed = laterTrades
+      .filter((t) => t.timestamp >= book.timestamp && crosses(t.price))
+      .reduce((sum, t) => sum + t.size, 0);
+    tradedThroughShares = Math.min(printed, restingShares);
+  }
+
+  return {
+    status:
+      filledShares === 0
+        ? "UNFILLED"
+        : restingShares > 0
+          ? "PARTIAL"
+          : "FILLED",
+    bookAt: book.timestamp,
+    filledShares,
+    avgPrice: filledShares > 0 ? notionalUsd / filledShares : null,
+    notionalUsd,
+    touchPrice: levels[0]?.price ?? null,
+    restingShares,
+    tradedThroughShares,
+  };
+}
+
+/**
+ * Replay an order against the recorded book for a token at time `at` (ms)
+ */
+export function replayFillAt(
+  tokenId: string,
+  at: number,
+  order: ReplayOrder,
+  store: MarketDataStore = getMarketDataStore(),
+): ReplayFillResult {
+  const book = store.getOrderbookAt(tokenId, at);
+  const laterTrades = store
+    .getLastTrades(tokenId, Number.MAX_SAFE_INTEGER)
+    .filter((t) => t.timestamp >= at);
+  return replayFill(book, order, laterTrades);
+}
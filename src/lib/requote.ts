@@ -0,0 +1,220 @@
+/**
+ * Requote - Refresh a full set of resting quotes with the fewest changes
+ *
+ * Given the quotes wanted per token, the account's open orders on those
+ * tokens are compared with them:
+ *
+ * - KEEP:   a resting order already matches a wanted quote (same side and
+ *           price, remaining size within tolerance) - left untouched, so it
+ *           keeps its queue position
+ * - CANCEL: a resting order no wanted quote matches
+ * - POST:   a wanted quote no resting order matches
+ *
+ * The CLOB has no amend, so a changed price or size is a cancel plus a
+ * post. All cancels go out in one cancelOrders() call, then the posts are
+ * placed post-only (see placePostOnlyOrder) against one book fetch per
+ * token. If the cancels fail nothing is posted - the old quotes may still
+ * be live and posting on top of them would double the exposure.
+ *
+ * A token listed with no quotes has all of its orders pulled. Tokens not
+ * listed are left alone.
+ */
+
+import type { ClobClient } from "@polymarket/clob-client";
+import { getBestPricesFromRaw } from "./orderbook-utils";
+import { gtcOrderTracker } from "./order";
+import {
+  placePostOnlyOrder,
+  type PostOnlyCrossAction,
+} from "./order-execution";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface Quote {
+  tokenId: string;
+  side: "BUY" | "SELL";
+  /** Limit price (0-1) */
+  price: number;
+  sizeShares: number;
+}
+
+export interface RestingQuote extends Quote {
+  orderId: string;
+}
+
+/** Wanted quotes per token ID (empty list = pull every quote) */
+export type DesiredQuotes = Record<
+  string,
+  Array<Pick<Quote, "side" | "price" | "sizeShares">>
+>;
+
+export interface RequoteDiff {
+  keep: RestingQuote[];
+  cancel: RestingQuote[];
+  post: Quote[];
+}
+
+export interface RequoteResult {
+  diff: RequoteDiff;
+  cancelled: number;
+  posted: number;
+  /** Cancels and posts that failed (or were skipped after a failed cancel) */
+  failed: number;
+  /** Order IDs of the new quotes */
+  postedOrderIds: string[];
+}
+
+export interface RequoteOptions {
+  /** Remaining size within this many shares counts as a match (default 0.01) */
+  sizeTolerance?: number;
+  /** What a new quote that would cross does (default REJECT) */
+  onCross?: PostOnlyCrossAction;
+}
+
+/** Fields of a CLOB open order the requote uses */
+export interface OpenOrderFields {
+  id: string;
+  asset_id: string;
+  side: string;
+  price: string;
+  original_size: string;
+  size_matched: string;
+}
+
+const PRICE_EPSILON = 1e-9;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DIFF
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Open order as a resting quote (remaining size, not original)
+ */
+export function toRestingQuote(order: OpenOrderFields): RestingQuote {
+  return {
+    orderId: order.id,
+    tokenId: order.asset_id,
+    side: order.side.toUpperCase() === "SELL" ? "SELL" : "BUY",
+    price: Number(order.price),
+    sizeShares: Number(order.original_size) - Number(order.size_matched),
+  };
+}
+
+/**
+ * Keep / cancel / post needed to turn `resting` into `desired`.
+ * Resting orders on tokens not in `desired` are ignored.
+ */
+export function computeRequoteDiff(
+  desired: DesiredQuotes,
+  resting: RestingQuote[],
+  sizeTolerance = 0.01,
+): RequoteDiff {
+  const diff: RequoteDiff = { keep: [], cancel: [], post: [] };
+  const unmatched = resting.filter((r) => r.tokenId in desired);
+
+  for (const [tokenId, quotes] of Object.entries(desired)) {
+    for (const quote of quotes) {
+      const idx = unmatched.findIndex(
+        (r) =>
+          r.tokenId === tokenId &&
+          r.side === quote.side &&
+          Math.abs(r.price - quote.price) < PRICE_EPSILON &&
+          Math.abs(r.sizeShares - quote.sizeShares) <= sizeTolerance,
+      );
+      if (idx >= 0) {
+        diff.keep.push(unmatched[idx]);
+        unmatched.splice(idx, 1);
+      } else {
+        diff.post.push({ tokenId, ...quote });
+      }
+    }
+  }
+
+  diff.cancel = unmatched;
+  return diff;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REQUOTE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Apply the minimal diff between the account's open orders and `desired`
+ */
+export async function requote(
+  client: ClobClient,
+  desired: DesiredQuotes,
+  options: RequoteOptions = {},
+): Promise<RequoteResult> {
+  const orders = (await client.getOpenOrders()) as OpenOrderFields[];
+  const diff = computeRequoteDiff(
+    desired,
+    orders.map(toRestingQuote),
+    options.sizeTolerance,
+  );
+  const result: RequoteResult = {
+    diff,
+    cancelled: 0,
+    posted: 0,
+    failed: 0,
+    postedOrderIds: [],
+  };
+
+  if (diff.cancel.length > 0) {
+    try {
+      await client.cancelOrders(diff.cancel.map((q) => q.orderId));
+      for (const q of diff.cancel) gtcOrderTracker.untrack(q.orderId);
+      result.cancelled = diff.cancel.length;
+    } catch (err) {
+      result.failed = diff.cancel.length + diff.post.length;
+      console.warn(
+        `⚠️ [Requote] Failed to cancel ${diff.cancel.length} order(s), nothing posted: ${err instanceof Error ? err.message : err}`,
+      );
+      return result;
+    }
+  }
+
+  const books = new Map<string, { bestBid: number; bestAsk: number }>();
+  for (const quote of diff.post) {
+    let book = books.get(quote.tokenId);
+    if (!book) {
+      try {
+        const prices = getBestPricesFromRaw(
+          await client.getOrderBook(quote.tokenId),
+        );
+        book = { bestBid: prices.bestBid ?? 0, bestAsk: prices.bestAsk ?? 0 };
+        books.set(quote.tokenId, book);
+      } catch (err) {
+        result.failed++;
+        console.warn(
+          `⚠️ [Requote] No book for ${quote.tokenId.slice(0, 12)}..., quote skipped: ${err instanceof Error ? err.message : err}`,
+        );
+        continue;
+      }
+    }
+
+    const placed = await placePostOnlyOrder({
+      client,
+      tokenId: quote.tokenId,
+      side: quote.side,
+      size: { shares: quote.sizeShares },
+      price: quote.price,
+      ...book,
+      onCross: options.onCross,
+    });
+    if (placed.success) {
+      result.posted++;
+      if (placed.orderId) result.postedOrderIds.push(placed.orderId);
+    } else {
+      result.failed++;
+    }
+  }
+
+  console.log(
+    `🔁 [Requote] kept ${diff.keep.length}, cancelled ${result.cancelled}, posted ${result.posted}` +
+      (result.failed > 0 ? `, failed ${result.failed}` : ""),
+  );
+  return result;
+}
@@ -0,0 +1,116 @@
+/**
+ * Share Check - Confirm the funder holds the shares before a SELL
+ *
+ * A SELL for shares the funder doesn't hold (an entry that never filled,
+ * a position already sold or redeemed) is rejected by the exchange with a
+ * generic "not enough balance / allowance". Checking first turns that into
+ * INSUFFICIENT_SHARES with the amount actually held.
+ *
+ * Holdings are the larger of the CLOB's conditional balance and the
+ * on-chain CTF balance - right after a fill one of them can lag the other.
+ * A lookup that fails never blocks the sell: the exchange still has the
+ * final say.
+ */
+
+import type { ClobClient } from "@polymarket/clob-client";
+import type { Wallet } from "ethers";
+import { getClobBalanceAllowance, getTokenBalanceWithStatus } from "./balance";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export const INSUFFICIENT_SHARES = "INSUFFICIENT_SHARES";
+
+export interface ShareCheckResult {
+  /** False only when the funder provably holds too few shares */
+  ok: boolean;
+  /** Shares held (the larger of both sources), undefined if both failed */
+  heldShares?: number;
+  clobShares?: number;
+  onChainShares?: number;
+}
+
+/** Where on-chain holdings are read from */
+export interface ShareHolder {
+  wallet: Wallet;
+  /** Funder address (proxy in proxy mode) */
+  address: string;
+}
+
+// Rounding slack - share amounts are rounded to 2 decimals when orders
+// are built, so a hair under the requested size still sells
+const SHARE_TOLERANCE = 0.01;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHECK
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Held shares vs the shares a SELL needs
+ */
+export function evaluateShareCheck(
+  sharesNeeded: number,
+  clobShares?: number,
+  onChainShares?: number,
+): ShareCheckResult {
+  const known = [clobShares, onChainShares].filter(
+    (v): v is number => v !== undefined && Number.isFinite(v),
+  );
+  if (known.length === 0) return { ok: true };
+
+  const heldShares = Math.max(...known);
+  return {
+    ok: heldShares + SHARE_TOLERANCE >= sharesNeeded,
+    heldShares,
+    clobShares,
+    onChainShares,
+  };
+}
+
+/**
+ * Look up the funder's holdings of `tokenId` and compare with the SELL size.
+ * Never throws.
+ */
+export async function checkSellableShares(
+  client: ClobClient,
+  tokenId: string,
+  sharesNeeded: number,
+  holder: ShareHolder | null = shareHolder,
+): Promise<ShareCheckResult> {
+  const [clob, onChain] = await Promise.all([
+    getClobBalanceAllowance(client, "CONDITIONAL", tokenId)
+      .then((b) => b.balance)
+      .catch(() => undefined),
+    holder
+      ? getTokenBalanceWithStatus(holder.wallet, holder.address, tokenId).then(
+          (r) => (r.success ? r.value : undefined),
+        )
+      : Promise.resolve(undefined),
+  ]);
+  return evaluateShareCheck(sharesNeeded, clob, onChain);
+}
+
+/**
+ * INSUFFICIENT_SHARES detail, e.g. "held 3.20 shares, need 10.00"
+ */
+export function formatShareShortfall(
+  check: ShareCheckResult,
+  sharesNeeded: number,
+): string {
+  return `held ${(check.heldShares ?? 0).toFixed(2)} shares, need ${sharesNeeded.toFixed(2)}`;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON HOLDER
+// ═══════════════════════════════════════════════════════════════════════════
+
+let shareHolder: ShareHolder | null = null;
+
+/**
+ * Set the funder whose on-chain holdings back the check (once, after auth).
+ * Without one only the CLOB balance is checked.
+ */
+export function initShareCheck(holder: ShareHolder | null): void {
+  shareHolder = holder;
+}
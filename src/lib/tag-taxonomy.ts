@@ -0,0 +1,262 @@
+/**
+ * Tag Taxonomy - Local copy of Polymarket's tags and which markets carry them
+ *
+ * Tag labels get renamed ("US Politics" -> "Politics") and keyword lists
+ * drift, but Gamma tag IDs and slugs are stable. Active events are synced
+ * from Gamma periodically; each event's tags apply to all of its markets.
+ * The taxonomy then answers:
+ *
+ *   getTags()             every tag with market counts and liquidity totals
+ *   getMarketsByTag(ref)  markets under a tag (by ID, slug or label)
+ *
+ * Market allow/deny tag lists match synced tag IDs and slugs as well as the
+ * CLOB labels, so configs can say MARKET_DENY_TAGS=1,sports.
+ *
+ *   TAG_SYNC_INTERVAL_MIN=60   # 0 = disabled
+ */
+
+import axios from "axios";
+import { POLYMARKET_API } from "./constants";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface MarketTag {
+  id: string;
+  label: string;
+  slug: string;
+}
+
+export interface TaggedMarket {
+  conditionId: string;
+  question: string;
+  eventId: string;
+  liquidityUsd: number;
+  volume24hUsd: number;
+  tokenIds: string[];
+}
+
+export interface TagSummary extends MarketTag {
+  marketCount: number;
+  liquidityUsd: number;
+  volume24hUsd: number;
+}
+
+/** The subset of a Gamma event the taxonomy reads */
+export interface GammaEvent {
+  id?: string | number;
+  tags?: { id?: string | number; label?: string; slug?: string }[];
+  markets?: {
+    conditionId?: string;
+    question?: string;
+    liquidity?: string | number;
+    liquidityNum?: number;
+    volume24hr?: string | number;
+    clobTokenIds?: string;
+    closed?: boolean;
+  }[];
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TAXONOMY
+// ═══════════════════════════════════════════════════════════════════════════
+
+function toNumber(value: unknown): number {
+  const n = typeof value === "number" ? value : parseFloat(String(value));
+  return Number.isFinite(n) ? n : 0;
+}
+
+export class TagTaxonomy {
+  private tags = new Map<string, MarketTag>();
+  private markets = new Map<string, TaggedMarket>();
+  /** tag ID -> condition IDs */
+  private marketsByTag = new Map<string, Set<string>>();
+  /** condition ID -> tag IDs */
+  private tagsByMarket = new Map<string, Set<string>>();
+  private lastSyncAt = 0;
+
+  /**
+   * Replace the taxonomy from a list of Gamma events
+   */
+  ingest(events: GammaEvent[], now = Date.now()): void {
+    this.tags.clear();
+    this.markets.clear();
+    this.marketsByTag.clear();
+    this.tagsByMarket.clear();
+
+    for (const event of events) {
+      const tagIds: string[] = [];
+      for (const tag of event.tags ?? []) {
+        if (tag.id === undefined || tag.id === null) continue;
+        const id = String(tag.id);
+        this.tags.set(id, {
+          id,
+          label: tag.label ?? id,
+          slug: tag.slug ?? "",
+        });
+        tagIds.push(id);
+      }
+
+      for (const market of event.markets ?? []) {
+        if (!market.conditionId || market.closed) continue;
+        const conditionId = market.conditionId.toLowerCase();
+        let tokenIds: string[] = [];
+        try {
+          const parsed = JSON.parse(market.clobTokenIds || "[]");
+          if (Array.isArray(parsed)) tokenIds = parsed.map(String);
+        } catch {
+          // Token IDs are informational here
+        }
+        this.markets.set(conditionId, {
+          conditionId: market.conditionId,
+          question: market.question ?? "",
+          eventId: String(event.id ?? ""),
+          liquidityUsd: toNumber(market.liquidityNum ?? market.liquidity),
+          volume24hUsd: toNumber(market.volume24hr),
+          tokenIds,
+        });
+
+        for (const tagId of tagIds) {
+          let byTag = this.marketsByTag.get(tagId);
+          if (!byTag) {
+            byTag = new Set();
+            this.marketsByTag.set(tagId, byTag);
+          }
+          byTag.add(conditionId);
+
+          let byMarket = this.tagsByMarket.get(conditionId);
+          if (!byMarket) {
+            byMarket = new Set();
+            this.tagsByMarket.set(conditionId, byMarket);
+          }
+          byMarket.add(tagId);
+        }
+      }
+    }
+    this.lastSyncAt = now;
+  }
+
+  /**
+   * Every synced tag with its market count and liquidity/volume totals,
+   * most markets first
+   */
+  getTags(): TagSummary[] {
+    return [...this.tags.values()]
+      .map((tag) => {
+        const markets = this.getMarketsForTagId(tag.id);
+        return {
+          ...tag,
+          marketCount: markets.length,
+          liquidityUsd: markets.reduce((sum, m) => sum + m.liquidityUsd, 0),
+          volume24hUsd: markets.reduce((sum, m) => sum + m.volume24hUsd, 0),
+        };
+      })
+      .sort(
+        (a, b) =>
+          b.marketCount - a.marketCount || b.liquidityUsd - a.liquidityUsd,
+      );
+  }
+
+  /**
+   * Find a tag by ID, slug or label (case-insensitive)
+   */
+  resolveTag(ref: string): MarketTag | null {
+    const key = ref.trim().toLowerCase();
+    const byId = this.tags.get(key);
+    if (byId) return byId;
+    for (const tag of this.tags.values()) {
+      if (tag.slug.toLowerCase() === key || tag.label.toLowerCase() === key) {
+        return tag;
+      }
+    }
+    return null;
+  }
+
+  /**
+   * Markets under a tag (by ID, slug or label), most liquid first
+   */
+  getMarketsByTag(ref: string): TaggedMarket[] {
+    const tag = this.resolveTag(ref);
+    if (!tag) return [];
+    return this.getMarketsForTagId(tag.id).sort(
+      (a, b) => b.liquidityUsd - a.liquidityUsd,
+    );
+  }
+
+  /**
+   * IDs and slugs of a market's synced tags, for allow/deny list matching
+   * @returns undefined when the market isn't in the taxonomy
+   */
+  getTagRefsForMarket(conditionId: string): string[] | undefined {
+    const tagIds = this.tagsByMarket.get(conditionId.toLowerCase());
+    if (!tagIds) return undefined;
+    const refs: string[] = [];
+    for (const id of tagIds) {
+      refs.push(id);
+      const slug = this.tags.get(id)?.slug;
+      if (slug) refs.push(slug);
+    }
+    return refs;
+  }
+
+  size(): { tags: number; markets: number } {
+    return { tags: this.tags.size, markets: this.markets.size };
+  }
+
+  /**
+   * Age of the taxonomy in ms (Infinity before the first sync)
+   */
+  getAge(now = Date.now()): number {
+    return this.lastSyncAt > 0 ? now - this.lastSyncAt : Infinity;
+  }
+
+  /**
+   * Re-sync from Gamma's active events. A failed sync keeps the old data.
+   * @returns Number of tags synced, or null if the fetch failed
+   */
+  async syncFromGamma(
+    pageSize = 200,
+    maxEvents = 5000,
+  ): Promise<number | null> {
+    const events: GammaEvent[] = [];
+    try {
+      for (let offset = 0; offset < maxEvents; offset += pageSize) {
+        const url = `${POLYMARKET_API.GAMMA}/events?active=true&closed=false&limit=${pageSize}&offset=${offset}`;
+        const { data } = await axios.get(url, { timeout: 10000 });
+        if (!Array.isArray(data)) break;
+        events.push(...data);
+        if (data.length < pageSize) break;
+      }
+    } catch (err) {
+      console.warn(
+        `[TagTaxonomy] Sync failed: ${err instanceof Error ? err.message : err}`,
+      );
+      return null;
+    }
+
+    this.ingest(events);
+    return this.tags.size;
+  }
+
+  private getMarketsForTagId(tagId: string): TaggedMarket[] {
+    const conditionIds = this.marketsByTag.get(tagId) ?? new Set<string>();
+    return [...conditionIds].map((id) => this.markets.get(id)!);
+  }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SINGLETON INSTANCE
+// ═══════════════════════════════════════════════════════════════════════════
+
+let instance: TagTaxonomy | null = null;
+
+/**
+ * Get the global tag taxonomy
+ */
+export function getTagTaxonomy(): TagTaxonomy {
+  if (!instance) {
+    instance = new TagTaxonomy();
+  }
+  return instance;
+}
@@ -0,0 +1,175 @@
+/**
+ * Test Trade - Prove end-to-end order permission without trading
+ *
+ * An account that has never placed an order can hold valid API keys and
+ * still have its first orders rejected; the usual advice is "make one
+ * small trade on the website". The test trade does that programmatically:
+ * it posts the smallest valid BUY far below the mid, then cancels it.
+ *
+ * The order must rest, never fill. clob-client has no post-only flag, so
+ * the price is kept below the best bid (and always below the best ask)
+ * against a fresh book right before posting; after posting, the order is
+ * checked for fills before it is canceled.
+ *
+ *   npm run test-trade
+ *   npm run test-trade -- --token <id> --distance 50
+ */
+
+import { OrderType, Side } from "@polymarket/clob-client";
+import type { ClobClient, TickSize } from "@polymarket/clob-client";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface TestTradeOptions {
+  /** Token to trade (default: first active sampling market) */
+  tokenId?: string;
+  /** How far below the mid to bid, % of mid (default 50) */
+  distancePct?: number;
+  /** Leave the order resting instead of canceling it */
+  keepOrder?: boolean;
+}
+
+export interface TestTradeResult {
+  success: boolean;
+  tokenId?: string;
+  orderId?: string;
+  price?: number;
+  size?: number;
+  /** The order was canceled (confirmed by the exchange) */
+  canceled: boolean;
+  error?: string;
+}
+
+export const DEFAULT_TEST_TRADE_DISTANCE_PCT = 50;
+
+// Used when the book doesn't report a minimum order size
+const DEFAULT_MIN_ORDER_SHARES = 5;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRICING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Test order price: distancePct below the mid, rounded down to the tick,
+ * at least one tick below the best bid and never below the lowest tick
+ *
+ * @returns Price (0-1), or null when no price can rest (asked at 1 tick)
+ */
+export function computeTestOrderPrice(input: {
+  bestBid?: number;
+  bestAsk?: number;
+  tickSize: number;
+  distancePct: number;
+}): number | null {
+  const { bestBid, bestAsk, tickSize } = input;
+  const ticks = (price: number) => Math.floor(price / tickSize + 1e-9);
+
+  let price = tickSize;
+  if (bestBid !== undefined && bestAsk !== undefined) {
+    const mid = (bestBid + bestAsk) / 2;
+    const target = mid * (1 - input.distancePct / 100);
+    const belowBid = (ticks(bestBid) - 1) * tickSize;
+    price = Math.max(tickSize, ticks(Math.min(target, belowBid)) * tickSize);
+  } else if (bestBid !== undefined) {
+    price = Math.max(tickSize, (ticks(bestBid) - 1) * tickSize);
+  }
+
+  if (bestAsk !== undefined && price >= bestAsk) return null;
+  return Number(price.toFixed(6));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEST TRADE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Place the smallest valid resting BUY and cancel it. Never throws.
+ */
+export async function placeTestTrade(
+  client: ClobClient,
+  options: TestTradeOptions = {},
+): Promise<TestTradeResult> {
+  const result: TestTradeResult = { success: false, canceled: false };
+  try {
+    const tokenId = options.tokenId ?? (await pickTestToken(client));
+    if (!tokenId) {
+      return { ...result, error: "no active market for a test order" };
+    }
+    result.tokenId = tokenId;
+
+    const book = await client.getOrderBook(tokenId);
+    const bestBid = bestLevel(book.bids, Math.max);
+    const bestAsk = bestLevel(book.asks, Math.min);
+    const price = computeTestOrderPrice({
+      bestBid,
+      bestAsk,
+      tickSize: parseFloat(book.tick_size) || 0.01,
+      distancePct: options.distancePct ?? DEFAULT_TEST_TRADE_DISTANCE_PCT,
+    });
+    if (price === null) {
+      return { ...result, error: "book is asked at the lowest tick" };
+    }
+    const size = parseFloat(book.min_order_size) || DEFAULT_MIN_ORDER_SHARES;
+    result.price = price;
+    result.size = size;
+
+    const order = await client.createOrder(
+      { side: Side.BUY, tokenID: tokenId, size, price },
+      { tickSize: book.tick_size as TickSize, negRisk: book.neg_risk },
+    );
+    const response = await client.postOrder(order, OrderType.GTC);
+    const orderId: string | undefined =
+      response?.orderID ?? response?.orderId ?? response?.orderHashes?.[0];
+    if (!response?.success || !orderId) {
+      const reason = response?.errorMsg ?? response?.error ?? "unknown error";
+      return { ...result, error: `order rejected: ${reason}` };
+    }
+    result.orderId = orderId;
+
+    // A fill here means the book moved through us - report it, still cancel
+    const placed = await client.getOrder(orderId).catch(() => null);
+    const matched = parseFloat(placed?.size_matched ?? "0");
+    if (matched > 0) {
+      result.error = `order filled ${matched} shares before cancel`;
+    }
+
+    if (!options.keepOrder) {
+      const cancel = await client.cancelOrder({ orderID: orderId });
+      result.canceled = (cancel?.canceled ?? []).includes(orderId);
+      if (!result.canceled && result.error === undefined) {
+        result.error = "cancel not confirmed - cancel the order manually";
+      }
+    }
+
+    result.success = result.error === undefined;
+    return result;
+  } catch (err) {
+    const error = err instanceof Error ? err.message : String(err);
+    return { ...result, error };
+  }
+}
+
+/**
+ * First token of the first active, open sampling market
+ */
+export async function pickTestToken(
+  client: ClobClient,
+): Promise<string | null> {
+  const page = await client.getSamplingSimplifiedMarkets();
+  for (const market of page?.data ?? []) {
+    if (!market.active || market.closed) continue;
+    const token = market.tokens?.[0]?.token_id;
+    if (token) return token;
+  }
+  return null;
+}
+
+function bestLevel(
+  levels: { price: string }[] | undefined,
+  pick: (...values: number[]) => number,
+): number | undefined {
+  if (!levels?.length) return undefined;
+  return pick(...levels.map((l) => parseFloat(l.price)));
+}
@@ -0,0 +1,217 @@
+/**
+ * Token Transfer - Move ERC-1155 outcome tokens between wallets
+ *
+ * Positions opened under the wrong signature type sit in the wrong wallet:
+ * shares bought in EOA mode are held by the EOA, and a bot restarted in
+ * proxy mode can't see or sell them (and vice versa). This moves outcome
+ * tokens between the EOA and the proxy wallet, or out to another address:
+ *
+ * - from EOA:   CTF.safeTransferFrom(eoa, to, ...) signed by the EOA
+ * - from PROXY: the same call routed through the proxy's proxy(dest, data),
+ *               as redemptions are
+ *
+ * planTokenTransfer() checks the request against the on-chain balance
+ * before anything is signed; the transfer script prints the plan and asks
+ * for confirmation. Gas is reserved from the gas budget like any other
+ * on-chain transaction.
+ */
+
+import { ethers, type Wallet } from "ethers";
+import { POLYGON, CTF_ABI, PROXY_ABI } from "./constants";
+import {
+  getGasBudget,
+  estimateTxCostPol,
+  receiptFeePol,
+  GAS_ESTIMATES,
+  GAS_BUDGET_EXCEEDED,
+  type GasReservation,
+} from "./gas-budget";
+import { getTxWallet } from "./private-tx";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export type TransferSource = "EOA" | "PROXY";
+
+export interface TokenTransferRequest {
+  tokenId: string;
+  from: TransferSource;
+  /** Destination address */
+  to: string;
+  /** Shares to move, or "ALL" for the whole balance */
+  shares: number | "ALL";
+}
+
+export interface TokenTransferPlan {
+  tokenId: string;
+  from: TransferSource;
+  /** Address the tokens leave */
+  fromAddress: string;
+  to: string;
+  /** Amount in base units (6 decimals) */
+  amount: bigint;
+  shares: number;
+  /** Shares held by fromAddress before the transfer */
+  heldShares: number;
+}
+
+export interface TokenTransferResult {
+  success: boolean;
+  txHash?: string;
+  error?: string;
+}
+
+// Outcome tokens use the same 6 decimals as USDC.e
+const SHARE_DECIMALS = 6;
+
+const TX_TIMEOUT_MS = 45_000;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PLAN
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Validate a transfer against the wallets and the held balance
+ * @returns The plan, or an error explaining why it can't be sent
+ */
+export function planTokenTransfer(
+  request: TokenTransferRequest,
+  wallets: { eoa: string; proxy?: string },
+  heldBase: bigint,
+): { plan: TokenTransferPlan } | { error: string } {
+  const fromAddress = request.from === "EOA" ? wallets.eoa : wallets.proxy;
+  if (!fromAddress) {
+    return { error: "no proxy address configured (POLYMARKET_PROXY_ADDRESS)" };
+  }
+  if (!ethers.isAddress(request.to) || request.to === ethers.ZeroAddress) {
+    return { error: `invalid destination address: ${request.to}` };
+  }
+  if (request.to.toLowerCase() === fromAddress.toLowerCase()) {
+    return { error: "destination is the source wallet" };
+  }
+
+  let amount: bigint;
+  if (request.shares === "ALL") {
+    amount = heldBase;
+  } else if (Number.isFinite(request.shares) && request.shares > 0) {
+    amount = ethers.parseUnits(
+      request.shares.toFixed(SHARE_DECIMALS),
+      SHARE_DECIMALS,
+    );
+  } else {
+    return { error: `invalid share amount: ${request.shares}` };
+  }
+
+  const heldShares = Number(ethers.formatUnits(heldBase, SHARE_DECIMALS));
+  if (amount <= 0n) {
+    return { error: `${request.from} holds no shares of this token` };
+  }
+  if (amount > heldBase) {
+    const shares = Number(ethers.formatUnits(amount, SHARE_DECIMALS));
+    return {
+      error: `${request.from} holds ${heldShares.toFixed(2)} shares, asked to move ${shares.toFixed(2)}`,
+    };
+  }
+
+  return {
+    plan: {
+      tokenId: request.tokenId,
+      from: request.from,
+      fromAddress,
+      to: ethers.getAddress(request.to),
+      amount,
+      shares: Number(ethers.formatUnits(amount, SHARE_DECIMALS)),
+      heldShares,
+    },
+  };
+}
+
+/**
+ * Outcome-token balance of `address` in base units
+ */
+export async function getTokenBalanceBase(
+  wallet: Wallet,
+  address: string,
+  tokenId: string,
+): Promise<bigint> {
+  const ctf = new ethers.Contract(POLYGON.CTF_ADDRESS, CTF_ABI, wallet);
+  return BigInt(await ctf.balanceOf(address, tokenId));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TRANSFER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Send a planned transfer and wait for it to confirm
+ *
+ * @param wallet - The EOA (signs directly, or drives the proxy)
+ * @param privateTx - Send via the private relay (default PRIVATE_TX_DEFAULT)
+ */
+export async function executeTokenTransfer(
+  plan: TokenTransferPlan,
+  wallet: Wallet,
+  privateTx?: boolean,
+): Promise<TokenTransferResult> {
+  const gasBudget = getGasBudget();
+  let reservation: GasReservation | null = null;
+  let txSent = false;
+  try {
+    const provider = wallet.provider;
+    if (!provider) throw new Error("Wallet has no provider");
+    const txWallet = getTxWallet(wallet, privateTx);
+
+    const feeData = await provider.getFeeData();
+    reservation = gasBudget.reserve(
+      "transfer",
+      estimateTxCostPol(
+        GAS_ESTIMATES.transfer,
+        feeData.maxFeePerGas ?? feeData.gasPrice ?? 0n,
+      ),
+    );
+    if (!reservation) return { success: false, error: GAS_BUDGET_EXCEEDED };
+
+    const args = [plan.fromAddress, plan.to, plan.tokenId, plan.amount, "0x"];
+    let tx;
+    if (plan.from === "PROXY") {
+      const data = new ethers.Interface(CTF_ABI).encodeFunctionData(
+        "safeTransferFrom",
+        args,
+      );
+      const proxy = new ethers.Contract(plan.fromAddress, PROXY_ABI, txWallet);
+      tx = await proxy.proxy(POLYGON.CTF_ADDRESS, data);
+    } else {
+      const ctf = new ethers.Contract(POLYGON.CTF_ADDRESS, CTF_ABI, txWallet);
+      tx = await ctf.safeTransferFrom(...args);
+    }
+    txSent = true;
+    console.log(`📦 [Transfer] ⏳ Transaction sent: ${tx.hash}`);
+
+    let timeoutId: ReturnType<typeof setTimeout> | undefined;
+    try {
+      const timeout = new Promise<never>((_, reject) => {
+        timeoutId = setTimeout(
+          () => reject(new Error("Transaction timeout after 45s")),
+          TX_TIMEOUT_MS,
+        );
+      });
+      const receipt = await Promise.race([tx.wait(), timeout]);
+      gasBudget.settle(reservation, receiptFeePol(receipt));
+      console.log(
+        `📦 [Transfer] ✅ Confirmed in block ${receipt.blockNumber}`,
+      );
+      return { success: true, txHash: tx.hash };
+    } finally {
+      if (timeoutId) clearTimeout(timeoutId);
+    }
+  } catch (error) {
+    // A sent tx's fee is unknown - count the worst case
+    if (reservation) {
+      gasBudget.settle(reservation, txSent ? reservation.reservedPol : 0);
+    }
+    const errorMsg = error instanceof Error ? error.message : String(error);
+    console.error(`📦 [Transfer] ❌ Transfer failed: ${errorMsg}`);
+    return { success: false, error: errorMsg };
+  }
+}
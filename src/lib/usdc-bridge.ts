@@ -0,0 +1,238 @@
+/**
+ * USDC Bridge Awareness - Native USDC vs bridged USDC.e
+ *
+ * Polygon has two USDCs: Circle's native USDC and the older bridged USDC.e.
+ * The exchange only trades USDC.e, so funds withdrawn from an exchange or
+ * bridged in as native USDC show up on-chain but the CLOB reports a $0
+ * balance. At startup (and periodically) the funder's holdings of both are
+ * checked and a warning is logged when native USDC is sitting idle.
+ *
+ * Optionally, native USDC is swapped to USDC.e through a DEX router
+ * (QuickSwap V3 by default - any router with the same exactInputSingle
+ * interface works):
+ *
+ *   USDC_NATIVE_AUTO_SWAP=true
+ *   USDC_NATIVE_MIN_SWAP_USD=5      # ignore dust below this
+ *   USDC_SWAP_ROUTER=0x...          # defaults to QuickSwap V3
+ *   USDC_SWAP_SLIPPAGE_PCT=0.5
+ */
+
+import { Contract, type Wallet, parseUnits, formatUnits } from "ethers";
+import { POLYGON, ERC20_ABI } from "./constants";
+import type { Logger } from "./types";
+import {
+  getGasBudget,
+  estimateTxCostPol,
+  receiptFeePol,
+  GAS_ESTIMATES,
+  GAS_BUDGET_EXCEEDED,
+  type GasReservation,
+} from "./gas-budget";
+import { getTxWallet } from "./private-tx";
+
+// QuickSwap V3 Router on Polygon (same as the POL reserve swap)
+export const DEFAULT_USDC_SWAP_ROUTER =
+  "0xf5b509bB0909a69B1c207E495f687a596C168E12";
+
+const SWAP_ROUTER_ABI = [
+  "function exactInputSingle(tuple(address tokenIn, address tokenOut, address recipient, uint256 deadline, uint256 amountIn, uint256 amountOutMinimum, uint160 limitSqrtPrice) params) external payable returns (uint256 amountOut)",
+];
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+export interface UsdcHoldings {
+  /** USDC.e - tradeable on the exchange */
+  bridged: number;
+  /** Native USDC - not accepted by the exchange */
+  native: number;
+}
+
+export type UsdcHoldingsIssue = "NATIVE_ONLY" | "NATIVE_IDLE";
+
+export interface UsdcBridgeConfig {
+  /** Swap native USDC to USDC.e automatically */
+  autoSwap: boolean;
+  /** Native balances below this are ignored (USD) */
+  minSwapUsd: number;
+  router: string;
+  slippagePct: number;
+}
+
+export const DEFAULT_USDC_BRIDGE_CONFIG: UsdcBridgeConfig = {
+  autoSwap: false,
+  minSwapUsd: 5,
+  router: DEFAULT_USDC_SWAP_ROUTER,
+  slippagePct: 0.5,
+};
+
+export interface UsdcBridgeSwapResult {
+  success: boolean;
+  amountIn?: number;
+  amountOut?: number;
+  txHash?: string;
+  error?: string;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHECKS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Fetch both USDC balances for an address
+ */
+export async function getUsdcHoldings(
+  wallet: Wallet,
+  address: string,
+): Promise<UsdcHoldings> {
+  const bridged = new Contract(
+    POLYGON.USDC_ADDRESS,
+    ERC20_ABI,
+    wallet.provider,
+  );
+  const native = new Contract(
+    POLYGON.NATIVE_USDC_ADDRESS,
+    ERC20_ABI,
+    wallet.provider,
+  );
+  const [bridgedRaw, nativeRaw] = await Promise.all([
+    bridged.balanceOf(address),
+    native.balanceOf(address),
+  ]);
+  return {
+    bridged: Number(formatUnits(bridgedRaw, POLYGON.USDC_DECIMALS)),
+    native: Number(formatUnits(nativeRaw, POLYGON.USDC_DECIMALS)),
+  };
+}
+
+/**
+ * Classify holdings: NATIVE_ONLY when all the funds are in native USDC
+ * (the CLOB shows $0), NATIVE_IDLE when some are - null when fine
+ */
+export function checkUsdcHoldings(
+  holdings: UsdcHoldings,
+  minNativeUsd: number,
+): UsdcHoldingsIssue | null {
+  if (!(holdings.native > 0) || holdings.native < minNativeUsd) return null;
+  // Under $1 of USDC.e counts as nothing tradeable
+  return holdings.bridged < 1 ? "NATIVE_ONLY" : "NATIVE_IDLE";
+}
+
+/**
+ * One-line warning for a holdings issue
+ */
+export function formatUsdcHoldingsWarning(
+  holdings: UsdcHoldings,
+  issue: UsdcHoldingsIssue,
+): string {
+  const native = `$${holdings.native.toFixed(2)}`;
+  return issue === "NATIVE_ONLY"
+    ? `Funds are in native USDC (${native}), not USDC.e - the exchange only accepts USDC.e, so the CLOB balance shows $0. Swap to USDC.e (USDC_NATIVE_AUTO_SWAP=true) to trade.`
+    : `${native} native USDC is idle (not tradeable) alongside $${holdings.bridged.toFixed(2)} USDC.e - swap it to USDC.e to trade with it.`;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SWAP
+// ═══════════════════════════════════════════════════════════════════════════
+
+/**
+ * Swap native USDC to USDC.e through the configured router
+ *
+ * @param privateTx - Send via the private relay (default PRIVATE_TX_DEFAULT)
+ */
+export async function swapNativeToBridgedUsdc(
+  wallet: Wallet,
+  amount: number,
+  config: Pick<UsdcBridgeConfig, "router" | "slippagePct">,
+  logger?: Logger,
+  privateTx?: boolean,
+): Promise<UsdcBridgeSwapResult> {
+  const gasBudget = getGasBudget();
+  let reservation: GasReservation | null = null;
+  let txSent = false;
+  let spentPol = 0;
+  try {
+    const address = await wallet.getAddress();
+
+    const feeData = await wallet.provider?.getFeeData();
+    reservation = gasBudget.reserve(
+      "swap",
+      estimateTxCostPol(
+        GAS_ESTIMATES.approve + GAS_ESTIMATES.swap,
+        feeData?.maxFeePerGas ?? feeData?.gasPrice ?? 0n,
+      ),
+    );
+    if (!reservation) {
+      return { success: false, error: GAS_BUDGET_EXCEEDED };
+    }
+
+    const amountIn = parseUnits(amount.toFixed(6), POLYGON.USDC_DECIMALS);
+    const txWallet = getTxWallet(wallet, privateTx);
+    const nativeUsdc = new Contract(
+      POLYGON.NATIVE_USDC_ADDRESS,
+      ERC20_ABI,
+      txWallet,
+    );
+    const bridgedUsdc = new Contract(
+      POLYGON.USDC_ADDRESS,
+      ERC20_ABI,
+      wallet.provider,
+    );
+    const router = new Contract(config.router, SWAP_ROUTER_ABI, txWallet);
+
+    const allowance = await nativeUsdc.allowance(address, config.router);
+    if (allowance < amountIn) {
+      logger?.info?.(`Approving native USDC for swap router...`);
+      txSent = true;
+      const approveTx = await nativeUsdc.approve(config.router, amountIn);
+      spentPol += receiptFeePol(await approveTx.wait());
+    }
+
+    const before = await bridgedUsdc.balanceOf(address);
+
+    // Stable-to-stable: expect ~1:1
+    const minOut = parseUnits(
+      (amount * (1 - config.slippagePct / 100)).toFixed(6),
+      POLYGON.USDC_DECIMALS,
+    );
+
+    logger?.info?.(`Swapping $${amount.toFixed(2)} native USDC → USDC.e...`);
+    txSent = true;
+    const swapTx = await router.exactInputSingle({
+      tokenIn: POLYGON.NATIVE_USDC_ADDRESS,
+      tokenOut: POLYGON.USDC_ADDRESS,
+      recipient: address,
+      deadline: Math.floor(Date.now() / 1000) + 600,
+      amountIn,
+      amountOutMinimum: minOut,
+      limitSqrtPrice: 0,
+    });
+    const receipt = await swapTx.wait();
+    spentPol += receiptFeePol(receipt);
+    gasBudget.settle(reservation, spentPol);
+
+    const after = await bridgedUsdc.balanceOf(address);
+    const amountOut = Number(
+      formatUnits(after - before, POLYGON.USDC_DECIMALS),
+    );
+
+    logger?.info?.(
+      `✅ USDC swap complete | ${amount.toFixed(2)} USDC → ${amountOut.toFixed(2)} USDC.e`,
+    );
+
+    return { success: true, amountIn: amount, amountOut, txHash: receipt.hash };
+  } catch (error) {
+    // Count the worst case once a tx went out (its fee may be unknown)
+    if (reservation) {
+      gasBudget.settle(
+        reservation,
+        txSent ? Math.max(spentPol, reservation.reservedPol) : spentPol,
+      );
+    }
+
+    const errorMsg = error instanceof Error ? error.message : String(error);
+    logger?.error?.(`USDC swap failed: ${errorMsg}`);
+    return { success: false, error: errorMsg };
+  }
+}
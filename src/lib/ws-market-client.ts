@@ -23,6 +23,7 @@ import WebSocket from "ws";
 import { POLYMARKET_WS, getMarketWsUrl } from "./constants";
 import { getMarketDataStore, type OrderbookLevel } from "./market-data-store";
 import { sortBidsDescending, sortAsksAscending } from "./orderbook-utils";
+import { emitEvent } from "../infra/event-bus";
 
 // ============================================================================
 // Types
@@ -84,6 +85,9 @@ interface MarketChannelMessage {
   // For price_change events
   price?: string;
   changes?: Array<{ price: string; size: string; side: "BUY" | "SELL" }>;
+  // For last_trade_price events (price is shared with price_change)
+  size?: string;
+  side?: "BUY" | "SELL";
 }
 
 // ============================================================================
@@ -419,7 +423,7 @@ export class WebSocketMarketClient {
         vpnActive: this.lastDisconnectVpnActive,
         vpnType: this.lastDisconnectVpnType,
       };
-      console.log(JSON.stringify(disconnectEvent));
+      emitEvent(disconnectEvent);
 
       console.log(
         `[WS-Market] Connection closed: code=${code}, reason="${reasonStr}", lastMessageAgeMs=${lastMsgAge}, lastPongAgeMs=${lastPongAge}, disconnectCount=${this.disconnectCount}`,
@@ -561,8 +565,22 @@ export class WebSocketMarketClient {
         );
         this.orderbooks.delete(tokenId);
       }
+    } else if (update.event_type === "last_trade_price") {
+      // Executed trade - keep the print for momentum/whale logic
+      const price = parseFloat(update.price ?? "");
+      const size = parseFloat(update.size ?? "");
+      if (isNaN(price) || isNaN(size) || size <= 0) return;
+
+      const ts = Number(update.timestamp);
+      store.recordTrade({
+        tokenId,
+        price,
+        size,
+        side: update.side === "SELL" ? "SELL" : "BUY",
+        timestamp: Number.isFinite(ts) && ts > 0 ? ts : Date.now(),
+      });
     }
-    // Ignore other event types (last_trade_price, tick_size_change) for now
+    // Ignore other event types (tick_size_change) for now
   }
 
   private parseOrderbookLevels(
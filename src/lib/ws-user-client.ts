@@ -16,6 +16,7 @@
 import WebSocket from "ws";
 import type { ClobClient } from "@polymarket/clob-client";
 import { POLYMARKET_WS, getUserWsUrl } from "./constants";
+import { emitEvent } from "../infra/event-bus";
 
 // ============================================================================
 // Types
@@ -126,6 +127,7 @@ export interface TrackedOrder {
 export interface TrackedTrade {
   tradeId: string;
   orderId: string;
+  makerOrderId?: string;
   tokenId: string;
   side: "BUY" | "SELL";
   price: number;
@@ -187,6 +189,7 @@ export class OrderStateStore {
     const trade: TrackedTrade = {
       tradeId: event.id,
       orderId: event.taker_order_id,
+      makerOrderId: event.maker_order_id,
       tokenId: event.asset_id,
       side: event.side,
       price: parseFloat(event.price),
@@ -675,7 +678,7 @@ export class WebSocketUserClient {
         vpnActive: this.lastDisconnectVpnActive,
         vpnType: this.lastDisconnectVpnType,
       };
-      console.log(JSON.stringify(disconnectEvent));
+      emitEvent(disconnectEvent);
 
       console.log(
         `[WS-User] Connection closed: code=${code}, reason="${reasonStr}", disconnectCount=${this.disconnectCount}`,
@@ -31,6 +31,35 @@ export interface OrderResult {
   /** Reason for failure (if success is false) */
   reason?: string;
 
+  /** Specifics behind the reason (e.g. why a market is not tradable) */
+  detail?: string;
+
+  /** Classified exchange error (AUTH_FAILED, INSUFFICIENT_BALANCE, ...) */
+  errorCode?: string;
+
+  /** The exchange's own error message, verbatim */
+  errorDetail?: string;
+
+  /** HTTP status of the failed CLOB call */
+  httpStatus?: number;
+
+  /** Failed CLOB call as "METHOD /path" */
+  endpoint?: string;
+
+  /** Server request ID (response headers) - quote it to Polymarket support */
+  requestId?: string;
+
+  /** Shares the funder held (INSUFFICIENT_SHARES rejections) */
+  heldShares?: number;
+
+  /** Requested size in USDC and shares, at the price it was converted at */
+  sizeConversion?: {
+    from: "USDC" | "SHARES";
+    sizeUsdc: number;
+    sizeShares: number;
+    price: number;
+  };
+
   /** Amount filled in USD (for partial fills) */
   filledUsd?: number;
 
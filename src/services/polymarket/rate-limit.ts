@@ -11,6 +11,8 @@
  * - Error classification (retryable vs non-retryable)
  */
 
+import { envNum } from "../../config/env";
+
 // ============================================================================
 // Configuration
 // ============================================================================
@@ -35,6 +37,27 @@ export const DEFAULT_RETRY_CONFIG: Readonly<RetryConfig> = {
   jitterFactor: 0.3,
 };
 
+/** Retry configuration plus per-request timeout */
+export interface RequestPolicy extends RetryConfig {
+  /** Per-request timeout in ms */
+  timeoutMs: number;
+}
+
+/**
+ * Retry policy for reads - markets, books, prices, positions (env-configurable)
+ *
+ * Writes (orders, cancels) have no retry policy on purpose: a write that
+ * timed out may have reached the exchange, and resending it - re-signed
+ * with a new salt - places a second order.
+ */
+export const READ_POLICY: Readonly<RequestPolicy> = {
+  maxRetries: envNum("READ_MAX_RETRIES", 3),
+  baseDelayMs: envNum("READ_RETRY_BASE_MS", 1000),
+  maxDelayMs: envNum("READ_RETRY_MAX_MS", 30000),
+  jitterFactor: 0.3,
+  timeoutMs: envNum("READ_TIMEOUT_MS", 10000),
+};
+
 /** Rate limit configuration */
 export interface RateLimitConfig {
   /** Maximum requests per window */
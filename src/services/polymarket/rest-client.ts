@@ -23,6 +23,7 @@ import {
   withRetry,
   withRateLimitAndRetry,
   rateLimiters,
+  READ_POLICY,
   type RetryConfig,
   type RetryResult,
 } from "./rate-limit";
@@ -54,12 +55,14 @@ export {
   fetchMarketByConditionId,
   fetchMarketByTokenId,
   getOppositeTokenId,
+  getComplementQuote,
   getMarketTokenPair,
   getTokenOutcome,
   prefetchMarkets,
   clearMarketCache,
   getMarketCacheStats,
   type MarketTokenPair,
+  type ComplementQuote,
 } from "../../lib/market";
 
 // API endpoints from constants
@@ -79,8 +82,10 @@ export {
   type RetryConfig,
   type RateLimitConfig,
   type RetryResult,
+  type RequestPolicy,
   DEFAULT_RETRY_CONFIG,
   DEFAULT_RATE_LIMIT_CONFIG,
+  READ_POLICY,
 } from "./rate-limit";
 
 // ============================================================================
@@ -101,7 +106,8 @@ export interface RestRequestOptions {
   headers?: Record<string, string>;
 }
 
-const DEFAULT_TIMEOUT = 10000;
+// All helpers below are reads - they use the read policy unless overridden
+const DEFAULT_TIMEOUT = READ_POLICY.timeoutMs;
 
 /**
  * Make a GET request to the CLOB API with retry and rate limiting
@@ -113,11 +119,8 @@ export async function clobGet<T>(
   path: string,
   options: RestRequestOptions = {},
 ): Promise<RetryResult<T>> {
-  const {
-    timeout = DEFAULT_TIMEOUT,
-    retryConfig = {},
-    useRateLimit = true,
-  } = options;
+  const { timeout = DEFAULT_TIMEOUT, useRateLimit = true } = options;
+  const retryConfig = { ...READ_POLICY, ...options.retryConfig };
 
   const url = `${POLYMARKET_API.CLOB}${path.startsWith("/") ? path : `/${path}`}`;
 
@@ -158,11 +161,8 @@ export async function dataApiGet<T>(
   path: string,
   options: RestRequestOptions = {},
 ): Promise<RetryResult<T>> {
-  const {
-    timeout = DEFAULT_TIMEOUT,
-    retryConfig = {},
-    useRateLimit = true,
-  } = options;
+  const { timeout = DEFAULT_TIMEOUT, useRateLimit = true } = options;
+  const retryConfig = { ...READ_POLICY, ...options.retryConfig };
 
   const url = `${POLYMARKET_API.DATA}${path.startsWith("/") ? path : `/${path}`}`;
 
@@ -203,11 +203,8 @@ export async function gammaApiGet<T>(
   path: string,
   options: RestRequestOptions = {},
 ): Promise<RetryResult<T>> {
-  const {
-    timeout = DEFAULT_TIMEOUT,
-    retryConfig = {},
-    useRateLimit = true,
-  } = options;
+  const { timeout = DEFAULT_TIMEOUT, useRateLimit = true } = options;
+  const retryConfig = { ...READ_POLICY, ...options.retryConfig };
 
   const url = `${POLYMARKET_API.GAMMA}${path.startsWith("/") ? path : `/${path}`}`;
 
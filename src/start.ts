@@ -37,10 +37,13 @@
  * ═══════════════════════════════════════════════════════════════════════════
  */
 
-import "dotenv/config";
+import "./config/load-env";
 import axios from "axios";
 import type { ClobClient } from "@polymarket/clob-client";
 
+// Numeric settings are parsed strictly ("0,5" / "10 USDC" are rejected)
+import { envNum, parseDecimal, findEnvTypos } from "./config";
+
 // Keep essential lib modules
 import {
   createClobClient,
@@ -82,7 +85,6 @@ import {
   OnChainMonitor,
   createOnChainMonitorConfig,
   // Market utilities for hedge token lookup and mapping verification
-  getOppositeTokenId,
   fetchMarketByTokenId,
   // GitHub error reporting
   initGitHubReporter,
@@ -164,20 +166,13 @@ import {
   SimpleLogger,
   type ChurnEngineDeps,
   type ChurnConfig,
+  parseCompoundSchedule,
 } from "./core";
 
 // ═══════════════════════════════════════════════════════════════════════════
 // CONFIGURATION
 // ═══════════════════════════════════════════════════════════════════════════
 
-// Helper to read numeric env vars
-const envNum = (key: string, defaultValue: number): number => {
-  const value = process.env[key];
-  if (value === undefined) return defaultValue;
-  const parsed = parseFloat(value);
-  return isNaN(parsed) ? defaultValue : parsed;
-};
-
 // Helper to read boolean env vars
 const envBool = (key: string, defaultValue: boolean): boolean => {
   const value = process.env[key];
@@ -255,8 +250,8 @@ function parseOptionalFloatWithDefault(
   // If env var is explicitly set to empty string, disable (return undefined)
   if (value === "") return undefined;
 
-  const parsed = parseFloat(value);
-  if (isNaN(parsed)) return defaultValue;
+  const parsed = parseDecimal(value);
+  if (parsed === null) return defaultValue;
 
   // Warn if value seems like a percentage (outside [0,1] range)
   if (parsed < 0 || parsed > 1) {
@@ -387,6 +382,7 @@ function loadConfig(): ChurnConfig {
     scanMinVolumeUsd: envNum("SCAN_MIN_VOLUME_USD", 10000), // $10k minimum 24h volume
     scanTopNMarkets: envNum("SCAN_TOP_N_MARKETS", 20), // Top 20 most active markets
     scanIntervalSeconds: envNum("SCAN_INTERVAL_SECONDS", 300), // Refresh every 5 minutes
+    scanConcurrency: envNum("SCAN_CONCURRENCY", 8), // Book fetches in flight per scan
 
     // Dynamic Reserves - Self-balancing reserve system
     // Automatically adjusts reserves based on missed opportunities and hedge needs
@@ -447,6 +443,228 @@ function loadConfig(): ChurnConfig {
       process.env.WHALE_PRICE_MAX,
       0.65,
     ),
+
+    // State Snapshots - persist open positions (TP/hedge/stop levels) and
+    // tracked GTC orders so a restart doesn't drop them
+    // Set to empty string to disable
+    stateFilePath: envStr("STATE_FILE_PATH", "./data/bot-state.json"),
+
+    // Fill Replay - recorded books and trade prints, saved with each status
+    // update for `npm run replay-fill` (empty = disabled)
+    bookHistoryPath: envStr("BOOK_HISTORY_PATH", ""),
+
+    // Support Bundles - keep sanitized metadata for the last N API calls;
+    // `kill -USR2 <pid>` (Ctrl+Break on Windows) writes them (plus redacted
+    // config) to a .json.gz
+    // Set REQUEST_CAPTURE_SIZE=0 to disable
+    requestCaptureSize: envNum("REQUEST_CAPTURE_SIZE", 200),
+    supportBundleDir: envStr("SUPPORT_BUNDLE_DIR", "./data"),
+
+    // Volatility Sizing - shrink entries when realized volatility (std dev of
+    // trade-to-trade price changes) exceeds the target; never sizes up
+    volSizingEnabled: envBool("VOL_SIZING_ENABLED", false),
+    volTargetCents: envNum("VOL_TARGET_CENTS", 1),
+    volSizingWindowMs: envNum("VOL_SIZING_WINDOW_MS", 300000),
+
+    // Dependency Breakers - after N consecutive failures (timeout/5xx),
+    // gamma-api/data-api calls fail fast with DEGRADED for the cooldown.
+    // CLOB order flow is never affected.
+    dependencyFailureThreshold: envNum("DEPENDENCY_FAILURE_THRESHOLD", 5),
+    dependencyCooldownMs: envNum("DEPENDENCY_COOLDOWN_MS", 60000),
+
+    // Endpoint Latency SLO - emit SLO_BREACH when an order write endpoint's
+    // p95 stays above SLO_WRITE_P95_MS for SLO_SUSTAIN_SECONDS (0 = off)
+    sloWriteP95Ms: envNum("SLO_WRITE_P95_MS", 1500),
+    sloSustainSeconds: envNum("SLO_SUSTAIN_SECONDS", 120),
+    latencyWindowMinutes: envNum("LATENCY_WINDOW_MINUTES", 10),
+
+    // Ack Check - after an order is accepted, look it up (as an order or a
+    // trade) until it's visible; flag ACK_NOT_VISIBLE after the timeout
+    ackCheckEnabled: envBool("ACK_CHECK_ENABLED", false),
+    ackCheckTimeoutMs: envNum("ACK_CHECK_TIMEOUT_MS", 10000),
+    ackCheckIntervalMs: envNum("ACK_CHECK_INTERVAL_MS", 1000),
+
+    // Correlated Move Detection - emit CORRELATED_MOVE when one leg of a
+    // configured group moves sharply while the others lag.
+    // Format: "name:tokA,-tokB;name2:tokC,tokD" ("-" = moves inversely)
+    correlationGroups: envStr("CORRELATION_GROUPS", ""),
+    correlationMoveCents: envNum("CORRELATION_MOVE_CENTS", 5),
+    correlationLagRatio: envNum("CORRELATION_LAG_RATIO", 0.3),
+    correlationWindowMs: envNum("CORRELATION_WINDOW_MS", 60000),
+
+    // Capital Allocation - divide capital across entry strategies by weight
+    // and shift weight toward the better performer.
+    // Format: "whale:0.7,scan:0.3" (empty = no per-strategy limits)
+    strategyWeights: envStr("STRATEGY_WEIGHTS", ""),
+    allocationRebalance: envBool("ALLOCATION_REBALANCE", true),
+
+    // Lot Policy - round each strategy's entry sizes down to whole lots
+    // so exits don't leave dust. Format: "whale:shares=1;scan:usd=5"
+    strategyLotPolicy: envStr("STRATEGY_LOT_POLICY", ""),
+
+    // Liquidity Floor - reject entries unless the book holds a multiple of
+    // the order near the touch. Format: "whale:mult=5,within=2;scan:mult=3"
+    strategyLiquidityFloor: envStr("STRATEGY_LIQUIDITY_FLOOR", ""),
+
+    // Dust Cleaner - sell (or redeem, if resolved) wallet positions worth
+    // less than DUST_MAX_USD (0 interval = only when triggered)
+    dustMaxUsd: envNum("DUST_MAX_USD", 1),
+    dustCleanIntervalMin: envNum("DUST_CLEAN_INTERVAL_MIN", 0),
+
+    // Auth Fallback - when an order is rejected as unauthorized, re-sign
+    // it once with the alternate signature type found at startup
+    authFallbackEnabled: envBool("AUTH_FALLBACK_ENABLED", false),
+
+    // Shadow Mode - run strategies on the paper engine while the others
+    // trade live; shadow vs live results appear in the status log.
+    // Format: "scan" or "whale,scan" (empty = everything trades live)
+    shadowStrategies: envStr("SHADOW_STRATEGIES", ""),
+
+    // Whale Scoring - track hit rate / average P&L of each whale we follow
+    // (ranked in the status log); optionally pin top scorers into the
+    // copy list so they're followed even after leaving the leaderboard.
+    whaleAutoPromote: envBool("WHALE_AUTO_PROMOTE", false),
+    whalePromoteMinTrades: envNum("WHALE_PROMOTE_MIN_TRADES", 5),
+    whalePromoteMinHitRate: envNum("WHALE_PROMOTE_MIN_HIT_RATE", 0.6),
+    whalePromoteMaxWallets: envNum("WHALE_PROMOTE_MAX", 10),
+
+    // Copy Guard - avoid buying the top of a whale's own price impact.
+    // Per-target format: "0xabc:delay=0,drift=1;0xdef:moved=5" (0 = off)
+    copyDelayMs: envNum("COPY_DELAY_MS", 0),
+    copyMaxDriftCents: envNum("COPY_MAX_DRIFT_CENTS", 0),
+    copySkipIfMovedCents: envNum("COPY_SKIP_IF_MOVED_CENTS", 0),
+    copyTargetRules: envStr("COPY_TARGET_RULES", ""),
+
+    // Anti-Chase Throttle - block entries on a token for a while after it
+    // moves more than CHASE_MOVE_PCT within the window (0 = disabled)
+    chaseMovePct: envNum("CHASE_MOVE_PCT", 0),
+    chaseWindowSeconds: envNum("CHASE_WINDOW_SECONDS", 30),
+    chaseBlockSeconds: envNum("CHASE_BLOCK_SECONDS", 120),
+
+    // Order Race - whale and scan entries on the same market within the
+    // window: "priority" lets ORDER_RACE_PRIORITY's first strategy win,
+    // "cancel" drops both and alerts, "off" lets both through
+    orderRaceMode: envStr("ORDER_RACE_MODE", "priority"),
+    orderRaceWindowSeconds: envNum("ORDER_RACE_WINDOW_SECONDS", 10),
+    orderRacePriority: envStr("ORDER_RACE_PRIORITY", "whale,scan"),
+
+    // Quote Report - mid this long after each maker fill measures how
+    // much the fill was picked off (adverse selection)
+    quoteMarkoutSeconds: envNum("QUOTE_MARKOUT_SECONDS", 60),
+
+    // Order Governor - at most this many orders (entries, exits, hedges)
+    // per market per window; only entries are blocked (0 = unlimited)
+    maxOrdersPerMarketPerHour: envNum("MAX_ORDERS_PER_MARKET_PER_HOUR", 0),
+    orderGovernorWindowMinutes: envNum("ORDER_GOVERNOR_WINDOW_MINUTES", 60),
+
+    // Market Blacklist - markets are blacklisted (persisted, checked before
+    // every entry) after a resolution against a position that lost at
+    // least BLACKLIST_RESOLVED_LOSS_USD, BLACKLIST_FAILED_FILLS rejected
+    // entries within the window, or a disputed resolution (0 = off)
+    marketBlacklistPath: envStr(
+      "MARKET_BLACKLIST_PATH",
+      "./data/market-blacklist.json",
+    ),
+    blacklistResolvedLossUsd: envNum("BLACKLIST_RESOLVED_LOSS_USD", 50),
+    blacklistFailedFills: envNum("BLACKLIST_FAILED_FILLS", 3),
+    blacklistFailedFillsWindowMinutes: envNum(
+      "BLACKLIST_FAILED_FILLS_WINDOW_MINUTES",
+      60,
+    ),
+    blacklistDisputed: envBool("BLACKLIST_DISPUTED", true),
+    blacklistScope: envStr("BLACKLIST_SCOPE", "event"),
+
+    // Hedge Targets - "<marketId|tokenId|*>:ratio" keeps that fraction of
+    // a position's shares hedged in the complement instead of hedging on
+    // the trigger; runtime overrides in HEDGE_TARGETS_PATH (empty = off)
+    hedgeTargets: envStr("HEDGE_TARGETS", ""),
+    hedgeTargetsPath: envStr("HEDGE_TARGETS_PATH", "./data/hedge-targets.json"),
+    hedgeTargetMinUsd: envNum("HEDGE_TARGET_MIN_USD", 1),
+
+    // Scheduled Jobs - housekeeping at set UTC times ("<job>@<when>;..."),
+    // runtime changes in JOB_SCHEDULE_PATH (empty = memory only)
+    jobSchedule: envStr("JOB_SCHEDULE", ""),
+    jobSchedulePath: envStr("JOB_SCHEDULE_PATH", "./data/jobs.json"),
+
+    // Signal Queue - entries rejected because every position slot is
+    // taken wait up to SIGNAL_QUEUE_TTL_SEC for a free one (0 = off)
+    signalQueueMax: envNum("SIGNAL_QUEUE_MAX", 0),
+    signalQueueTtlSec: envNum("SIGNAL_QUEUE_TTL_SEC", 300),
+    signalQueuePath: envStr("SIGNAL_QUEUE_PATH", "./data/signal-queue.json"),
+
+    // Profit Compounding - "pnl:cap" steps: realized P&L raises (and
+    // losses lower) the per-trade cap from MAX_TRADE_USD (empty = off)
+    compoundSchedule: envStr("COMPOUND_SCHEDULE", ""),
+    compoundStatePath: envStr("COMPOUND_STATE_PATH", "./data/compounding.json"),
+
+    // Market Staleness - markets with no trade or quote change for
+    // STALE_MARKET_DEAD_HOURS, or with no orderbook, are skipped by scans
+    staleMarketQuietMinutes: envNum("STALE_MARKET_QUIET_MINUTES", 60),
+    staleMarketDeadHours: envNum("STALE_MARKET_DEAD_HOURS", 6),
+
+    // Feed Validation - every FEED_CHECK_INTERVAL_SEC, compare a few
+    // streamed best prices against REST; FEED_DIVERGENCE past tolerance
+    feedCheckIntervalSec: envNum("FEED_CHECK_INTERVAL_SEC", 60),
+    feedCheckSample: envNum("FEED_CHECK_SAMPLE", 3),
+    feedDivergenceCents: envNum("FEED_DIVERGENCE_CENTS", 1),
+
+    // Order Sweeper - cancel open orders that are too old or whose book is
+    // gone/illiquid; daily summary as ORDER_SWEEP_SUMMARY (0 = disabled)
+    orderSweepIntervalSec: envNum("ORDER_SWEEP_INTERVAL_SEC", 0),
+    orderSweepMaxAgeMin: envNum("ORDER_SWEEP_MAX_AGE_MIN", 60),
+    orderSweepMaxSpreadCents: envNum("ORDER_SWEEP_MAX_SPREAD_CENTS", 20),
+
+    // Tag Taxonomy - sync Gamma tag IDs/slugs so MARKET_*_TAGS can reference
+    // stable IDs instead of labels (0 = disabled)
+    tagSyncIntervalMin: envNum("TAG_SYNC_INTERVAL_MIN", 60),
+
+    // Market Search - local full-text index of active markets, rebuilt from
+    // Gamma for `npm run search` (0 = disabled)
+    marketSearchRefreshMin: envNum("MARKET_SEARCH_REFRESH_MIN", 15),
+
+    // Native USDC - the exchange only accepts bridged USDC.e; native USDC in
+    // the funder wallet is flagged, and swapped when auto-swap is on
+    usdcNativeAutoSwap: envBool("USDC_NATIVE_AUTO_SWAP", false),
+    usdcNativeMinSwapUsd: envNum("USDC_NATIVE_MIN_SWAP_USD", 5),
+    usdcSwapRouter: envStr("USDC_SWAP_ROUTER", ""),
+    usdcSwapSlippagePct: envNum("USDC_SWAP_SLIPPAGE_PCT", 0.5),
+
+    // Balance Watcher - emit BALANCE_ANOMALY when USDC or a position moves
+    // without a journaled fill/exit/redeem/swap to explain it
+    balanceWatchEnabled: envBool("BALANCE_WATCH_ENABLED", true),
+    balanceWatchToleranceUsd: envNum("BALANCE_WATCH_TOLERANCE_USD", 1),
+    balanceWatchGraceSeconds: envNum("BALANCE_WATCH_GRACE_SECONDS", 120),
+
+    // Overround Monitor - alert when a neg-risk event's YES prices stop
+    // summing to ~1 (arbitrage or a stale leg); empty event IDs = disabled
+    overroundEventIds: envStr("OVERROUND_EVENT_IDS", ""),
+    overroundAlertPct: envNum("OVERROUND_ALERT_PCT", 3),
+    overroundArbEdgePct: envNum("OVERROUND_ARB_EDGE_PCT", 0.5),
+    overroundCheckIntervalSec: envNum("OVERROUND_CHECK_INTERVAL_SEC", 60),
+
+    // Price Alerts - PRICE_ALERT event + Telegram when a token's mid goes
+    // above/below a level; "<tokenId>:above=0.6,below=0.3,repeat;..."
+    priceAlerts: envStr("PRICE_ALERTS", ""),
+
+    // Imbalance Triggers - IMBALANCE_TRIGGER event + Telegram when one
+    // side's size near the mid outweighs the other for a hold time;
+    // "<tokenId>:bid=5,band=2,hold=1500,repeat;..."
+    imbalanceTriggers: envStr("IMBALANCE_TRIGGERS", ""),
+
+    // Event Sinks - structured events (fills, exits, risk blocks, ...) are
+    // fanned out to each sink, filtered independently.
+    // Filter format: "ENTRY_FILLED,RISK_*,!PRICE_FORMATION" (* = all)
+    eventStdout: envBool("EVENT_STDOUT", true),
+    eventStdoutFilter: envStr("EVENT_STDOUT_FILTER", "*"),
+    eventFilePath: envStr("EVENT_FILE_PATH", ""),
+    eventFileFilter: envStr("EVENT_FILE_FILTER", "*"),
+    eventWebhookUrl: envStr("EVENT_WEBHOOK_URL", ""),
+    eventWebhookFilter: envStr("EVENT_WEBHOOK_FILTER", "*"),
+    eventSocket: envStr("EVENT_SOCKET", ""),
+    eventSocketFilter: envStr("EVENT_SOCKET_FILTER", "*"),
+    // {"event":"heartbeat","seq":N} every N seconds, so consumers can tell
+    // a dead pipe from a quiet market (0 = off)
+    eventHeartbeatSeconds: envNum("EVENT_HEARTBEAT_SECONDS", 0),
   };
 }
 
@@ -495,9 +713,45 @@ interface ValidationError {
   message: string;
 }
 
-function validateConfig(config: ChurnConfig): ValidationError[] {
+/**
+ * Report a numeric setting outside [min, max] (either bound optional) with
+ * the expected range and the value received. Unset settings are skipped.
+ */
+function checkRange(
+  errors: ValidationError[],
+  field: string,
+  value: number | undefined,
+  min?: number,
+  max?: number,
+): void {
+  if (value === undefined) return;
+  const aboveMin = min === undefined || value >= min;
+  const belowMax = max === undefined || value <= max;
+  if (aboveMin && belowMax && Number.isFinite(value)) return;
+
+  const expected =
+    min !== undefined && max !== undefined
+      ? `between ${min} and ${max}`
+      : min !== undefined
+        ? `at least ${min}`
+        : `at most ${max}`;
+  errors.push({ field, message: `Must be ${expected} (got ${value})` });
+}
+
+function validateConfig(
+  config: ChurnConfig,
+  env: Record<string, string | undefined> = process.env,
+): ValidationError[] {
   const errors: ValidationError[] = [];
 
+  // Misspelled settings are otherwise silently ignored (default used)
+  for (const { key, suggestion } of findEnvTypos(env)) {
+    errors.push({
+      field: key,
+      message: `Unknown setting - did you mean ${suggestion}?`,
+    });
+  }
+
   // Required: wallet key
   if (!config.privateKey) {
     errors.push({ field: "PRIVATE_KEY", message: "Required" });
@@ -505,17 +759,177 @@ function validateConfig(config: ChurnConfig): ValidationError[] {
 
   // User-configurable: bet size must be positive
   if (config.maxTradeUsd <= 0) {
-    errors.push({ field: "MAX_TRADE_USD", message: "Must be positive" });
+    errors.push({
+      field: "MAX_TRADE_USD",
+      message: `Must be positive (got ${config.maxTradeUsd})`,
+    });
   }
 
   // User-configurable: min bet size must be positive
   if (config.minTradeUsd <= 0) {
-    errors.push({ field: "MIN_TRADE_USD", message: "Must be positive" });
+    errors.push({
+      field: "MIN_TRADE_USD",
+      message: `Must be positive (got ${config.minTradeUsd})`,
+    });
   }
 
   // Min trade should not exceed max trade
   if (config.minTradeUsd > config.maxTradeUsd) {
-    errors.push({ field: "MIN_TRADE_USD", message: "Must not exceed MAX_TRADE_USD" });
+    errors.push({
+      field: "MIN_TRADE_USD",
+      message: `Must not exceed MAX_TRADE_USD (got ${config.minTradeUsd} > ${config.maxTradeUsd})`,
+    });
+  }
+
+  // Sizing and EV math
+  checkRange(errors, "TRADE_FRACTION", config.tradeFraction, 0, 1);
+  checkRange(errors, "MIN_PROFIT_FACTOR", config.minProfitFactor, 1);
+
+  // Prices are in cents on a 0-100 book
+  const { minEntryPriceCents, maxEntryPriceCents } = config;
+  checkRange(errors, "MIN_ENTRY_PRICE_CENTS", minEntryPriceCents, 1, 99);
+  checkRange(errors, "MAX_ENTRY_PRICE_CENTS", maxEntryPriceCents, 1, 99);
+  if (minEntryPriceCents >= maxEntryPriceCents) {
+    errors.push({
+      field: "MIN_ENTRY_PRICE_CENTS",
+      message: `Must be below MAX_ENTRY_PRICE_CENTS (got ${minEntryPriceCents} >= ${maxEntryPriceCents})`,
+    });
+  }
+  checkRange(
+    errors,
+    "PREFERRED_ENTRY_LOW_CENTS",
+    config.preferredEntryLowCents,
+    1,
+    99,
+  );
+  checkRange(
+    errors,
+    "PREFERRED_ENTRY_HIGH_CENTS",
+    config.preferredEntryHighCents,
+    1,
+    99,
+  );
+  checkRange(errors, "MIN_SPREAD_CENTS", config.minSpreadCents, 0, 100);
+
+  // Percentages and fractions
+  checkRange(
+    errors,
+    "LIQUIDATION_MAX_SLIPPAGE_PCT",
+    config.liquidationMaxSlippagePct,
+    0,
+    100,
+  );
+  checkRange(errors, "MAX_RESERVE_FRACTION", config.maxReserveFraction, 0, 1);
+  checkRange(
+    errors,
+    "RESERVE_ADAPTATION_RATE",
+    config.reserveAdaptationRate,
+    0,
+    1,
+  );
+  checkRange(
+    errors,
+    "WHALE_PROMOTE_MIN_HIT_RATE",
+    config.whalePromoteMinHitRate,
+    0,
+    1,
+  );
+  checkRange(
+    errors,
+    "USDC_SWAP_SLIPPAGE_PCT",
+    config.usdcSwapSlippagePct,
+    0,
+    100,
+  );
+
+  // Thresholds where negative values make no sense (0 = off)
+  checkRange(errors, "COPY_DELAY_MS", config.copyDelayMs, 0);
+  checkRange(errors, "COPY_MAX_DRIFT_CENTS", config.copyMaxDriftCents, 0);
+  checkRange(errors, "CHASE_MOVE_PCT", config.chaseMovePct, 0);
+  checkRange(
+    errors,
+    "ORDER_RACE_WINDOW_SECONDS",
+    config.orderRaceWindowSeconds,
+    0,
+  );
+  checkRange(errors, "QUOTE_MARKOUT_SECONDS", config.quoteMarkoutSeconds, 1);
+  checkRange(
+    errors,
+    "MAX_ORDERS_PER_MARKET_PER_HOUR",
+    config.maxOrdersPerMarketPerHour,
+    0,
+  );
+  checkRange(
+    errors,
+    "ORDER_GOVERNOR_WINDOW_MINUTES",
+    config.orderGovernorWindowMinutes,
+    1,
+  );
+  checkRange(
+    errors,
+    "BLACKLIST_RESOLVED_LOSS_USD",
+    config.blacklistResolvedLossUsd,
+    0,
+  );
+  checkRange(errors, "BLACKLIST_FAILED_FILLS", config.blacklistFailedFills, 0);
+  checkRange(errors, "HEDGE_TARGET_MIN_USD", config.hedgeTargetMinUsd, 0);
+  checkRange(errors, "SIGNAL_QUEUE_MAX", config.signalQueueMax, 0);
+  checkRange(errors, "SIGNAL_QUEUE_TTL_SEC", config.signalQueueTtlSec, 1);
+  checkRange(
+    errors,
+    "BLACKLIST_FAILED_FILLS_WINDOW_MINUTES",
+    config.blacklistFailedFillsWindowMinutes,
+    1,
+  );
+  checkRange(
+    errors,
+    "STALE_MARKET_DEAD_HOURS",
+    config.staleMarketDeadHours,
+    0,
+  );
+  checkRange(errors, "FEED_CHECK_INTERVAL_SEC", config.feedCheckIntervalSec, 0);
+  checkRange(errors, "FEED_CHECK_SAMPLE", config.feedCheckSample, 1);
+  checkRange(errors, "FEED_DIVERGENCE_CENTS", config.feedDivergenceCents, 0);
+  checkRange(
+    errors,
+    "ORDER_SWEEP_INTERVAL_SEC",
+    config.orderSweepIntervalSec,
+    0,
+  );
+  checkRange(
+    errors,
+    "BALANCE_WATCH_TOLERANCE_USD",
+    config.balanceWatchToleranceUsd,
+    0,
+  );
+  checkRange(errors, "DUST_MAX_USD", config.dustMaxUsd, 0);
+  checkRange(
+    errors,
+    "DUST_CLEAN_INTERVAL_MIN",
+    config.dustCleanIntervalMin,
+    0,
+  );
+  checkRange(errors, "OVERROUND_ALERT_PCT", config.overroundAlertPct, 0);
+  checkRange(errors, "OVERROUND_ARB_EDGE_PCT", config.overroundArbEdgePct, 0);
+  checkRange(errors, "SLO_WRITE_P95_MS", config.sloWriteP95Ms, 0);
+  checkRange(errors, "SLO_SUSTAIN_SECONDS", config.sloSustainSeconds, 0);
+  checkRange(errors, "LATENCY_WINDOW_MINUTES", config.latencyWindowMinutes, 1);
+  checkRange(errors, "ACK_CHECK_TIMEOUT_MS", config.ackCheckTimeoutMs, 1000);
+  checkRange(errors, "ACK_CHECK_INTERVAL_MS", config.ackCheckIntervalMs, 100);
+  checkRange(
+    errors,
+    "EVENT_HEARTBEAT_SECONDS",
+    config.eventHeartbeatSeconds,
+    0,
+  );
+
+  // A schedule with no valid step would silently leave compounding off
+  const schedule = config.compoundSchedule ?? "";
+  if (schedule.trim() && parseCompoundSchedule(schedule).length === 0) {
+    errors.push({
+      field: "COMPOUND_SCHEDULE",
+      message: `No valid "pnl:cap" step (got "${schedule}")`,
+    });
   }
 
   return errors;
@@ -1054,6 +1468,14 @@ async function main(): Promise<void> {
     engine.stop();
   });
 
+  // Write a support bundle on demand without stopping. Windows has no
+  // SIGUSR2 - use Ctrl+Break (SIGBREAK) in the bot's console instead.
+  const bundleSignal = process.platform === "win32" ? "SIGBREAK" : "SIGUSR2";
+  process.on(bundleSignal, () => {
+    console.log(`\nReceived ${bundleSignal}, writing support bundle...`);
+    engine.writeSupportBundle();
+  });
+
   // Initialize
   const initialized = await engine.initialize();
   if (!initialized) {
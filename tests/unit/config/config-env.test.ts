@@ -0,0 +1,47 @@
+import assert from "node:assert";
+import { afterEach, describe, it, mock } from "node:test";
+import { envNum, parseDecimal } from "../../../src/config/env";
+
+describe("parseDecimal", () => {
+  it("accepts plain decimals", () => {
+    assert.strictEqual(parseDecimal("12"), 12);
+    assert.strictEqual(parseDecimal(" -0.5 "), -0.5);
+    assert.strictEqual(parseDecimal(".25"), 0.25);
+    assert.strictEqual(parseDecimal("1e-3"), 0.001);
+  });
+
+  it("rejects what parseFloat would silently truncate", () => {
+    assert.strictEqual(parseDecimal("0,5"), null);
+    assert.strictEqual(parseDecimal("10 USDC"), null);
+    assert.strictEqual(parseDecimal("5min"), null);
+    assert.strictEqual(parseDecimal("1_000"), null);
+    assert.strictEqual(parseDecimal(""), null);
+  });
+});
+
+describe("envNum", () => {
+  const KEY = "TEST_ENV_NUM_VALUE";
+
+  afterEach(() => {
+    delete process.env[KEY];
+    mock.restoreAll();
+  });
+
+  it("uses the default for unset or empty values", () => {
+    assert.strictEqual(envNum(KEY, 3), 3);
+    process.env[KEY] = "";
+    assert.strictEqual(envNum(KEY, 3), 3);
+  });
+
+  it("warns and falls back on a locale comma", () => {
+    const warn = mock.method(console, "warn", () => {});
+    process.env[KEY] = "0,5";
+    assert.strictEqual(envNum(KEY, 0.25), 0.25);
+    assert.match(String(warn.mock.calls[0].arguments[0]), /decimal separator/);
+  });
+
+  it("parses valid numbers", () => {
+    process.env[KEY] = "0.29";
+    assert.strictEqual(envNum(KEY, 0), 0.29);
+  });
+});
@@ -0,0 +1,52 @@
+import assert from "node:assert";
+import { readFileSync } from "node:fs";
+import { join } from "node:path";
+import { describe, it } from "node:test";
+import {
+  KNOWN_ENV_KEYS,
+  findEnvTypos,
+  suggestEnvKey,
+} from "../../../src/config/known-env";
+
+describe("suggestEnvKey", () => {
+  it("suggests the setting a typo was meant to be", () => {
+    assert.strictEqual(suggestEnvKey("MAXTRADEUSD"), "MAX_TRADE_USD");
+    assert.strictEqual(suggestEnvKey("max_trade_usd"), "MAX_TRADE_USD");
+    assert.strictEqual(
+      suggestEnvKey("MIN_ENTRY_PRICE_CENT"),
+      "MIN_ENTRY_PRICE_CENTS",
+    );
+    assert.strictEqual(suggestEnvKey("PRIVAT_KEY"), "PRIVATE_KEY");
+  });
+
+  it("leaves unrelated variables alone", () => {
+    assert.strictEqual(suggestEnvKey("PATH"), null);
+    assert.strictEqual(suggestEnvKey("HOSTNAME"), null);
+    assert.strictEqual(suggestEnvKey("NODE_VERSION"), null);
+  });
+});
+
+describe("findEnvTypos", () => {
+  it("reports only near-misses of known settings", () => {
+    const typos = findEnvTypos({
+      MAX_TRADE_USD: "25",
+      LIVE_TRADNG: "I_UNDERSTAND_THE_RISKS",
+      HOME: "/root",
+    });
+    assert.deepStrictEqual(typos, [
+      { key: "LIVE_TRADNG", suggestion: "LIVE_TRADING" },
+    ]);
+  });
+
+  it("knows every setting documented in .env.example", () => {
+    const example = readFileSync(
+      join(__dirname, "../../../.env.example"),
+      "utf8",
+    );
+    const documented = [...example.matchAll(/^# ?([A-Z][A-Z0-9_]{2,})=/gm)];
+    const missing = documented
+      .map((m) => m[1])
+      .filter((key) => !KNOWN_ENV_KEYS.has(key));
+    assert.deepStrictEqual(missing, []);
+  });
+});
@@ -0,0 +1,74 @@
+import assert from "node:assert";
+import { mkdtempSync, writeFileSync } from "node:fs";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import { describe, it } from "node:test";
+import {
+  loadEnv,
+  parseProfileArg,
+  withoutProfileArg,
+} from "../../../src/config/profile";
+
+function envDir(files: Record<string, string>): string {
+  const dir = mkdtempSync(join(tmpdir(), "profile-"));
+  for (const [name, content] of Object.entries(files)) {
+    writeFileSync(join(dir, name), content);
+  }
+  return dir;
+}
+
+describe("parseProfileArg", () => {
+  it("reads both flag forms", () => {
+    assert.strictEqual(parseProfileArg(["--profile", "paper"]), "paper");
+    assert.strictEqual(parseProfileArg(["--json", "--profile=prod"]), "prod");
+    assert.strictEqual(parseProfileArg(["--json"]), undefined);
+  });
+
+  it("strips the flag for positional arguments", () => {
+    assert.deepStrictEqual(
+      withoutProfileArg(["--profile", "paper", "add", "123", "--profile=x"]),
+      ["add", "123"],
+    );
+  });
+});
+
+describe("loadEnv", () => {
+  const dir = envDir({
+    ".env": "RPC_URL=https://base\nMAX_TRADE_USD=25\n",
+    ".env.paper": "MAX_TRADE_USD=5\nFILL_JOURNAL_PATH=./paper.jsonl\n",
+  });
+
+  it("loads .env alone without a profile", () => {
+    const env: NodeJS.ProcessEnv = {};
+    const loaded = loadEnv({ argv: [], env, dir });
+    assert.strictEqual(loaded.profile, undefined);
+    assert.strictEqual(env.MAX_TRADE_USD, "25");
+  });
+
+  it("overlays the profile on .env, under the shell", () => {
+    const env: NodeJS.ProcessEnv = { FILL_JOURNAL_PATH: "./shell.jsonl" };
+    const loaded = loadEnv({ argv: ["--profile", "paper"], env, dir });
+    assert.strictEqual(loaded.profile, "paper");
+    assert.strictEqual(env.MAX_TRADE_USD, "5");
+    assert.strictEqual(env.RPC_URL, "https://base");
+    assert.strictEqual(env.FILL_JOURNAL_PATH, "./shell.jsonl");
+    assert.strictEqual(env.BOT_PROFILE, "paper");
+  });
+
+  it("takes the profile from BOT_PROFILE", () => {
+    const env: NodeJS.ProcessEnv = { BOT_PROFILE: "paper" };
+    loadEnv({ argv: [], env, dir });
+    assert.strictEqual(env.MAX_TRADE_USD, "5");
+  });
+
+  it("refuses a missing or unsafe profile", () => {
+    assert.throws(
+      () => loadEnv({ argv: ["--profile", "prod"], env: {}, dir }),
+      /Profile "prod" not found/,
+    );
+    assert.throws(
+      () => loadEnv({ argv: ["--profile", "../x"], env: {}, dir }),
+      /Invalid profile/,
+    );
+  });
+});
@@ -0,0 +1,76 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { BalanceWatcher } from "../../../src/core/balance-watcher";
+
+describe("BalanceWatcher", () => {
+  const config = { toleranceUsd: 1, toleranceShares: 0.5, graceMs: 60_000 };
+
+  it("alerts on a USDC change nothing explains once the grace expires", () => {
+    const watcher = new BalanceWatcher(config);
+    assert.deepStrictEqual(watcher.observeUsdc(100, 0), []);
+    assert.deepStrictEqual(watcher.observeUsdc(80, 10_000), []);
+
+    const [anomaly] = watcher.observeUsdc(80, 70_000);
+    assert.strictEqual(anomaly.event, "BALANCE_ANOMALY");
+    assert.strictEqual(anomaly.asset, "USDC");
+    assert.strictEqual(anomaly.delta, -20);
+    // Reported once
+    assert.deepStrictEqual(watcher.observeUsdc(80, 80_000), []);
+  });
+
+  it("ignores changes within tolerance", () => {
+    const watcher = new BalanceWatcher(config);
+    watcher.observeUsdc(100, 0);
+    watcher.observeUsdc(100.5, 10_000);
+    assert.deepStrictEqual(watcher.observeUsdc(100.5, 100_000), []);
+  });
+
+  it("accepts journal entries that arrive after the balance moved", () => {
+    const watcher = new BalanceWatcher(config);
+    watcher.observeUsdc(100, 0);
+    watcher.observeUsdc(80, 10_000);
+    watcher.journal({ kind: "FILL", id: "t1", tokenId: "tok", at: 5_000 });
+    assert.deepStrictEqual(watcher.observeUsdc(80, 70_000), []);
+  });
+
+  it("doesn't let stale activity explain a later change", () => {
+    const watcher = new BalanceWatcher(config);
+    watcher.journal({ kind: "SWAP", at: 0 });
+    watcher.observeUsdc(100, 100_000);
+    watcher.observeUsdc(80, 200_000);
+    assert.strictEqual(watcher.observeUsdc(80, 300_000).length, 1);
+  });
+
+  it("matches position changes to the token's activity", () => {
+    const watcher = new BalanceWatcher(config);
+    // First sweep is the baseline
+    const baseline = new Map([["a", 10]]);
+    assert.deepStrictEqual(watcher.observePositions(baseline, 0), []);
+
+    watcher.journal({ kind: "ENTRY", tokenId: "b" }, 5_000);
+    const next = new Map([["b", 20]]);
+    assert.deepStrictEqual(watcher.observePositions(next, 10_000), []);
+
+    // "a" vanished with no exit journaled; "b" was bought
+    const anomalies = watcher.observePositions(next, 80_000);
+    assert.deepStrictEqual(
+      anomalies.map((a) => [a.asset, a.previous, a.current]),
+      [["a", 10, 0]],
+    );
+  });
+
+  it("lets a redemption explain any position change", () => {
+    const watcher = new BalanceWatcher(config);
+    watcher.observePositions(new Map([["a", 10]]), 0);
+    watcher.journal({ kind: "REDEEM" }, 5_000);
+    watcher.observePositions(new Map(), 10_000);
+    assert.deepStrictEqual(watcher.observePositions(new Map(), 80_000), []);
+  });
+
+  it("does nothing when disabled", () => {
+    const disabled = new BalanceWatcher({ ...config, enabled: false });
+    disabled.observeUsdc(100, 0);
+    disabled.observeUsdc(0, 1000);
+    assert.deepStrictEqual(disabled.observeUsdc(0, 100_000), []);
+  });
+});
@@ -0,0 +1,77 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  CapitalAllocator,
+  parseStrategyWeights,
+} from "../../../src/core/capital-allocator";
+
+describe("parseStrategyWeights", () => {
+  it("parses strategy:weight pairs", () => {
+    assert.deepStrictEqual(parseStrategyWeights("whale:0.7, scan:0.3"), {
+      whale: 0.7,
+      scan: 0.3,
+    });
+  });
+
+  it("drops unknown strategies and non-positive weights", () => {
+    assert.deepStrictEqual(parseStrategyWeights("whale:2,foo:1,scan:0"), {
+      whale: 2,
+    });
+    assert.deepStrictEqual(parseStrategyWeights(""), {});
+  });
+});
+
+describe("CapitalAllocator", () => {
+  it("splits capital by normalized weight", () => {
+    const allocator = new CapitalAllocator({ weights: { whale: 3, scan: 1 } });
+    const [whale, scan] = allocator.getAllocations(100, { whale: 20 });
+
+    assert.strictEqual(whale.strategy, "whale");
+    assert.strictEqual(whale.budgetUsd, 75);
+    assert.strictEqual(whale.remainingUsd, 55);
+    assert.strictEqual(scan.budgetUsd, 25);
+    assert.strictEqual(scan.remainingUsd, 25);
+  });
+
+  it("shifts weight toward the profitable strategy", () => {
+    const allocator = new CapitalAllocator({
+      weights: { whale: 0.5, scan: 0.5 },
+      minTradesForRebalance: 2,
+    });
+    for (let i = 0; i < 2; i++) {
+      allocator.recordResult("whale", 1, 10); // +10%
+      allocator.recordResult("scan", -1, 10); // -10%
+    }
+
+    const weights = allocator.getWeights();
+    assert.ok(weights.get("whale")!.weight > 0.5);
+    assert.ok(weights.get("scan")!.weight < 0.5);
+    assert.strictEqual(weights.get("whale")!.baseWeight, 0.5);
+  });
+
+  it("keeps base weights until enough trades or when rebalance is off", () => {
+    const few = new CapitalAllocator({ weights: { whale: 1, scan: 1 } });
+    few.recordResult("whale", 5, 10);
+    assert.strictEqual(few.getWeights().get("whale")!.weight, 0.5);
+
+    const fixed = new CapitalAllocator({
+      weights: { whale: 1, scan: 1 },
+      rebalance: false,
+      minTradesForRebalance: 1,
+    });
+    fixed.recordResult("whale", 5, 10);
+    assert.strictEqual(fixed.getWeights().get("whale")!.weight, 0.5);
+  });
+
+  it("gives unweighted strategies nothing", () => {
+    const allocator = new CapitalAllocator({ weights: { whale: 1 } });
+    assert.strictEqual(allocator.getRemainingUsd("scan", 100), 0);
+    assert.strictEqual(allocator.getRemainingUsd("whale", 100), 100);
+  });
+
+  it("does not limit entries when no weights are configured", () => {
+    const allocator = new CapitalAllocator();
+    assert.strictEqual(allocator.isEnabled(), false);
+    assert.strictEqual(allocator.getRemainingUsd("whale", 100), Infinity);
+  });
+});
@@ -0,0 +1,49 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { ChaseThrottle } from "../../../src/core/chase-throttle";
+
+describe("ChaseThrottle", () => {
+  const config = { movePct: 10, windowMs: 30_000, blockMs: 60_000 };
+
+  it("is disabled by default", () => {
+    const throttle = new ChaseThrottle();
+    assert.strictEqual(throttle.isEnabled(), false);
+    assert.strictEqual(throttle.record("tok", 0.5, 0), null);
+    assert.strictEqual(throttle.record("tok", 0.9, 1000), null);
+    assert.strictEqual(throttle.getBlockRemainingMs("tok", 1000), 0);
+  });
+
+  it("blocks a token that spikes within the window", () => {
+    const throttle = new ChaseThrottle(config);
+    assert.strictEqual(throttle.record("tok", 0.5, 0), null);
+    assert.strictEqual(throttle.record("tok", 0.54, 10_000), null);
+
+    const event = throttle.record("tok", 0.56, 20_000)!;
+    assert.strictEqual(event.event, "CHASE_BLOCKED");
+    assert.ok(Math.abs(event.movePct - 12) < 1e-9);
+    assert.strictEqual(event.fromPriceCents, 50);
+    assert.strictEqual(throttle.getBlockRemainingMs("tok", 30_000), 50_000);
+    assert.strictEqual(throttle.getBlockRemainingMs("other", 30_000), 0);
+  });
+
+  it("treats a crash like a spike", () => {
+    const throttle = new ChaseThrottle(config);
+    throttle.record("tok", 0.5, 0);
+    const event = throttle.record("tok", 0.4, 5000)!;
+    assert.ok(event.movePct < -10);
+  });
+
+  it("ignores moves spread over more than the window", () => {
+    const throttle = new ChaseThrottle(config);
+    throttle.record("tok", 0.5, 0);
+    assert.strictEqual(throttle.record("tok", 0.6, 40_000), null);
+  });
+
+  it("doesn't re-announce during a block and expires it", () => {
+    const throttle = new ChaseThrottle(config);
+    throttle.record("tok", 0.5, 0);
+    assert.ok(throttle.record("tok", 0.6, 1000));
+    assert.strictEqual(throttle.record("tok", 0.7, 2000), null);
+    assert.strictEqual(throttle.getBlockRemainingMs("tok", 61_000), 0);
+  });
+});
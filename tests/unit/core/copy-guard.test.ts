@@ -0,0 +1,71 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { CopyGuard, parseCopyTargetRules } from "../../../src/core/copy-guard";
+
+const WHALE = "0xaaaa111111111111111111111111111111111111";
+const OTHER = "0x2222222222222222222222222222222222222222";
+
+describe("parseCopyTargetRules", () => {
+  it("parses per-target overrides and skips invalid entries", () => {
+    const rules = parseCopyTargetRules(
+      "0xAAAA111111111111111111111111111111111111:delay=0,drift=1.5,foo=3;" +
+        `0xbad:drift=1;${OTHER}:moved=abc`,
+    );
+    assert.strictEqual(rules.size, 1);
+    assert.deepStrictEqual(rules.get(WHALE), {
+      delayMs: 0,
+      maxDriftCents: 1.5,
+    });
+    assert.strictEqual(parseCopyTargetRules("").size, 0);
+  });
+});
+
+describe("CopyGuard", () => {
+  const fill = { wallet: WHALE, price: 0.5, timestamp: 1_000_000 };
+
+  it("is disabled with no checks configured", () => {
+    assert.strictEqual(new CopyGuard().isEnabled(), false);
+    assert.strictEqual(new CopyGuard().check(fill, 60, 60, 1_000_000), null);
+  });
+
+  it("waits out the copy delay", () => {
+    const guard = new CopyGuard({ delayMs: 5000 });
+    assert.match(guard.check(fill, 50, 50, 1_002_000)!, /^COPY_DELAY/);
+    assert.strictEqual(guard.check(fill, 50, 50, 1_005_000), null);
+  });
+
+  it("blocks entries priced too far above the whale's fill", () => {
+    const guard = new CopyGuard({ maxDriftCents: 2 });
+    assert.strictEqual(guard.check(fill, 52, 51, 1_000_000), null);
+    assert.match(guard.check(fill, 53, 51, 1_000_000)!, /^COPY_DRIFT/);
+  });
+
+  it("skips when the book has already moved", () => {
+    const guard = new CopyGuard({ maxMoveCents: 3 });
+    assert.strictEqual(guard.check(fill, 50, 53, 1_000_000), null);
+    assert.match(guard.check(fill, 50, 54, 1_000_000)!, /^COPY_BOOK_MOVED/);
+  });
+
+  it("skips price checks when the whale's fill price is unknown", () => {
+    const guard = new CopyGuard({ maxDriftCents: 1, maxMoveCents: 1 });
+    const noPrice = { wallet: WHALE, timestamp: 1_000_000 };
+    assert.strictEqual(guard.check(noPrice, 90, 90, 1_000_000), null);
+  });
+
+  it("applies per-target overrides on top of the defaults", () => {
+    const guard = new CopyGuard(
+      { delayMs: 5000, maxDriftCents: 2 },
+      new Map([[WHALE, { delayMs: 0 }]]),
+    );
+    assert.deepStrictEqual(guard.getRule(WHALE), {
+      delayMs: 0,
+      maxDriftCents: 2,
+      maxMoveCents: 0,
+    });
+    assert.strictEqual(guard.check(fill, 51, 51, 1_000_000), null);
+    assert.match(
+      guard.check({ ...fill, wallet: OTHER }, 51, 51, 1_000_000)!,
+      /^COPY_DELAY/,
+    );
+  });
+});
@@ -0,0 +1,112 @@
+/**
+ * Correlation Detector Tests
+ *
+ * Tests for:
+ * - Parsing CORRELATION_GROUPS
+ * - Detecting a sharp move with lagging legs (same and inverse direction)
+ * - Cooldown between events
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+
+import {
+  CorrelationDetector,
+  parseCorrelationGroups,
+} from "../../../src/core/correlation-detector";
+
+const config = { windowMs: 60_000, moveCents: 5, lagRatio: 0.3 };
+
+describe("parseCorrelationGroups", () => {
+  it("should parse groups with inverse legs", () => {
+    const groups = parseCorrelationGroups("fed:a, b;event:c,-d");
+    assert.deepStrictEqual(groups, [
+      {
+        name: "fed",
+        legs: [
+          { tokenId: "a", inverse: false },
+          { tokenId: "b", inverse: false },
+        ],
+      },
+      {
+        name: "event",
+        legs: [
+          { tokenId: "c", inverse: false },
+          { tokenId: "d", inverse: true },
+        ],
+      },
+    ]);
+  });
+
+  it("should drop malformed and single-leg groups", () => {
+    assert.deepStrictEqual(parseCorrelationGroups(""), []);
+    assert.deepStrictEqual(parseCorrelationGroups("solo:a;nocolon"), []);
+  });
+});
+
+describe("CorrelationDetector", () => {
+  const now = 1_000_000;
+
+  it("should flag a leg that lags the leader", () => {
+    const detector = new CorrelationDetector(
+      parseCorrelationGroups("fed:a,b"),
+      config,
+    );
+    detector.record("b", 0.4, now - 30_000);
+    detector.record("a", 0.5, now - 30_000);
+
+    const events = detector.record("a", 0.58, now);
+    assert.strictEqual(events.length, 1);
+    assert.strictEqual(events[0].leaderTokenId, "a");
+    assert.ok(Math.abs(events[0].leaderMoveCents - 8) < 1e-9);
+    assert.strictEqual(events[0].laggards[0].tokenId, "b");
+    assert.ok(Math.abs(events[0].laggards[0].expectedMoveCents - 8) < 1e-9);
+  });
+
+  it("should not flag a leg that already followed", () => {
+    const detector = new CorrelationDetector(
+      parseCorrelationGroups("fed:a,b"),
+      config,
+    );
+    detector.record("a", 0.5, now - 30_000);
+    detector.record("b", 0.4, now - 30_000);
+    detector.record("b", 0.46, now - 1000);
+
+    assert.strictEqual(detector.record("a", 0.58, now).length, 0);
+  });
+
+  it("should expect inverse legs to move the other way", () => {
+    const detector = new CorrelationDetector(
+      parseCorrelationGroups("event:yes,-no"),
+      config,
+    );
+    detector.record("yes", 0.5, now - 30_000);
+    detector.record("no", 0.5, now - 30_000);
+    // "no" already fell by the full move - not a laggard
+    detector.record("no", 0.42, now - 1000);
+    assert.strictEqual(detector.record("yes", 0.58, now).length, 0);
+
+    // "no" rising with "yes" is lagging its inverse expectation
+    const other = new CorrelationDetector(
+      parseCorrelationGroups("event:yes,-no"),
+      config,
+    );
+    other.record("yes", 0.5, now - 30_000);
+    other.record("no", 0.5, now - 30_000);
+    const events = other.record("yes", 0.58, now);
+    assert.strictEqual(events.length, 1);
+    assert.ok(events[0].laggards[0].expectedMoveCents < 0);
+  });
+
+  it("should respect the cooldown per group and leader", () => {
+    const detector = new CorrelationDetector(
+      parseCorrelationGroups("fed:a,b"),
+      { ...config, cooldownMs: 60_000 },
+    );
+    detector.record("b", 0.4, now - 30_000);
+    detector.record("a", 0.5, now - 30_000);
+
+    assert.strictEqual(detector.record("a", 0.58, now).length, 1);
+    assert.strictEqual(detector.record("a", 0.6, now + 1000).length, 0);
+  });
+});
@@ -0,0 +1,70 @@
+/**
+ * Decision Log Tests
+ *
+ * Tests for:
+ * - Strictly increasing sequence numbers within a run
+ * - Book hash from the levels held for the token
+ * - Recent decision buffer
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { DecisionLog, hashBook } from "../../../src/core/decision-log";
+import { getMarketDataStore } from "../../../src/lib/market-data-store";
+
+const bids = [{ price: 0.48, size: 100 }];
+const asks = [{ price: 0.52, size: 80 }];
+
+describe("DecisionLog", () => {
+  it("should sequence decisions and hash the token's book", () => {
+    getMarketDataStore().updateFromRest("decision-tok", bids, asks);
+    const log = new DecisionLog("run-1");
+
+    const accepted = log.record({
+      action: "ENTRY",
+      accepted: true,
+      tokenId: "decision-tok",
+      strategy: "whale",
+      checks: [{ name: "schedule", passed: true }],
+    });
+    const rejected = log.record({
+      action: "ENTRY",
+      accepted: false,
+      reason: "COOLDOWN",
+      tokenId: "unseen-tok",
+      checks: [{ name: "cooldown", passed: false }],
+    });
+
+    assert.strictEqual(accepted.event, "DECISION");
+    assert.strictEqual(accepted.seq, 1);
+    assert.strictEqual(rejected.seq, 2);
+    assert.strictEqual(rejected.runId, "run-1");
+    assert.strictEqual(accepted.bookHash, hashBook(bids, asks));
+    assert.strictEqual(rejected.bookHash, null);
+    assert.strictEqual(log.getSeq(), 2);
+  });
+
+  it("should keep only the most recent decisions", () => {
+    const log = new DecisionLog("run-2", 2);
+    for (const tokenId of ["a", "b", "c"]) {
+      log.record({ action: "EXIT", accepted: true, tokenId, checks: [] });
+    }
+    assert.deepStrictEqual(
+      log.getRecent().map((d) => d.seq),
+      [2, 3],
+    );
+    assert.deepStrictEqual(
+      log.getRecent(1).map((d) => d.tokenId),
+      ["c"],
+    );
+  });
+});
+
+describe("hashBook", () => {
+  it("should change when a level changes", () => {
+    const hash = hashBook(bids, asks);
+    assert.match(hash, /^[0-9a-f]{16}$/);
+    assert.strictEqual(hash, hashBook([...bids], [...asks]));
+    assert.notStrictEqual(hash, hashBook(bids, [{ price: 0.52, size: 79 }]));
+  });
+});
@@ -0,0 +1,84 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  DustCleaner,
+  findDustPositions,
+  type DustCleanerDeps,
+} from "../../../src/core/dust-cleaner";
+import type { Position } from "../../../src/models";
+import type { RedeemablePosition } from "../../../src/lib/redeem";
+
+function position(tokenId: string, size: number, curPrice: number): Position {
+  return {
+    tokenId,
+    conditionId: `c-${tokenId}`,
+    outcome: "YES",
+    size,
+    avgPrice: curPrice,
+    curPrice,
+    pnlPct: 0,
+    pnlUsd: 0,
+    gainCents: 0,
+    value: size * curPrice,
+  };
+}
+
+function redeemable(conditionId: string, value: number): RedeemablePosition {
+  return { conditionId, marketId: "", outcome: "YES", size: value, value };
+}
+
+describe("findDustPositions", () => {
+  it("keeps positions under the threshold that aren't excluded", () => {
+    const dust = findDustPositions(
+      [
+        position("small", 2, 0.2),
+        position("big", 10, 0.5),
+        position("managed", 1, 0.1),
+        position("empty", 0, 0.5),
+      ],
+      1,
+      new Set(["managed"]),
+    );
+    assert.deepStrictEqual(dust.map((p) => p.tokenId), ["small"]);
+  });
+});
+
+describe("DustCleaner", () => {
+  it("redeems resolved dust and sells the rest", async () => {
+    const sold: string[] = [];
+    const redeemed: string[] = [];
+    const deps: DustCleanerDeps = {
+      fetchPositions: async () => [
+        position("a", 2, 0.3),
+        position("b", 0.5, 0.1),
+        position("big", 100, 0.5),
+      ],
+      fetchRedeemable: async () => [
+        redeemable("won", 0.8),
+        redeemable("large", 40),
+      ],
+      sell: async (p) => {
+        sold.push(p.tokenId);
+        return p.tokenId === "a"
+          ? { success: true, filledUsd: 0.58 }
+          : { success: false, reason: "POSITION_TOO_SMALL" };
+      },
+      redeem: async (conditionId) => {
+        redeemed.push(conditionId);
+        return { success: true };
+      },
+    };
+
+    const report = (await new DustCleaner(deps).cleanup())!;
+    assert.deepStrictEqual(redeemed, ["won"]);
+    assert.deepStrictEqual(sold, ["a", "b"]);
+    assert.strictEqual(report.redeemed, 1);
+    assert.strictEqual(report.sold, 1);
+    assert.strictEqual(report.failed, 1);
+    assert.ok(Math.abs(report.recoveredUsd - 1.38) < 1e-9);
+    assert.strictEqual(
+      report.items.find((i) => i.id === "b")?.reason,
+      "POSITION_TOO_SMALL",
+    );
+  });
+});
@@ -0,0 +1,128 @@
+/**
+ * Feed Validator Tests
+ *
+ * Tests for:
+ * - Matching WS and REST quotes pass, divergent ones emit FEED_DIVERGENCE
+ * - The REST book replaces a divergent WS quote
+ * - Quotes that move mid-fetch, REST-sourced quotes and failed fetches
+ *   are skipped
+ * - Round-robin sampling
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  FeedValidator,
+  type FeedDivergenceEvent,
+} from "../../../src/core/feed-validator";
+import { MarketDataStore } from "../../../src/lib/market-data-store";
+
+function restBook(bid: number, ask: number) {
+  // REST lists the worst prices first
+  return {
+    bids: [
+      { price: "0.01", size: "100" },
+      { price: String(bid), size: "50" },
+    ],
+    asks: [
+      { price: "0.99", size: "100" },
+      { price: String(ask), size: "50" },
+    ],
+  };
+}
+
+function wsQuote(store: MarketDataStore, tokenId: string, bid: number) {
+  store.updateFromWs(
+    tokenId,
+    [{ price: bid, size: 50 }],
+    [{ price: bid + 0.02, size: 50 }],
+  );
+}
+
+describe("FeedValidator", () => {
+  it("should pass quotes within tolerance", async () => {
+    const store = new MarketDataStore();
+    wsQuote(store, "a", 0.5);
+    const validator = new FeedValidator(store, async () =>
+      restBook(0.51, 0.52),
+    );
+
+    const result = await validator.check();
+    assert.strictEqual(result.checked, 1);
+    assert.strictEqual(result.diverged.length, 0);
+  });
+
+  it("should flag a wedged quote and switch the token to REST", async () => {
+    const store = new MarketDataStore();
+    wsQuote(store, "a", 0.4);
+    const validator = new FeedValidator(store, async () =>
+      restBook(0.55, 0.57),
+    );
+    const events: FeedDivergenceEvent[] = [];
+    validator.onDivergence((e) => events.push(e));
+
+    const result = await validator.check();
+    assert.strictEqual(result.diverged.length, 1);
+    assert.strictEqual(events[0].event, "FEED_DIVERGENCE");
+    assert.strictEqual(events[0].wsBid, 0.4);
+    assert.strictEqual(events[0].restBid, 0.55);
+    assert.strictEqual(events[0].diffCents, 15);
+
+    const data = store.get("a")!;
+    assert.strictEqual(data.source, "REST");
+    assert.strictEqual(data.bestBid, 0.55);
+    assert.strictEqual(validator.getStats().divergences, 1);
+  });
+
+  it("should skip a quote that moved while REST was fetched", async () => {
+    const store = new MarketDataStore();
+    wsQuote(store, "a", 0.4);
+    const validator = new FeedValidator(store, async () => {
+      wsQuote(store, "a", 0.55);
+      return restBook(0.55, 0.57);
+    });
+
+    const result = await validator.check();
+    assert.strictEqual(result.skipped, 1);
+    assert.strictEqual(result.diverged.length, 0);
+  });
+
+  it("should skip REST-sourced quotes and failed fetches", async () => {
+    const store = new MarketDataStore();
+    store.updateFromRest(
+      "rest",
+      [{ price: 0.4, size: 1 }],
+      [{ price: 0.42, size: 1 }],
+    );
+    wsQuote(store, "ws", 0.4);
+    let fetches = 0;
+    const validator = new FeedValidator(store, async () => {
+      fetches++;
+      throw new Error("timeout");
+    });
+
+    const result = await validator.check();
+    assert.strictEqual(fetches, 1);
+    assert.strictEqual(result.checked, 0);
+    assert.strictEqual(result.skipped, 1);
+  });
+
+  it("should sample tokens round-robin", async () => {
+    const store = new MarketDataStore();
+    for (const id of ["a", "b", "c"]) wsQuote(store, id, 0.5);
+    const fetched: string[] = [];
+    const validator = new FeedValidator(
+      store,
+      async (tokenId) => {
+        fetched.push(tokenId);
+        return restBook(0.5, 0.52);
+      },
+      { sampleSize: 2 },
+    );
+
+    await validator.check();
+    await validator.check();
+    assert.strictEqual(fetched.length, 4);
+    assert.deepStrictEqual(new Set(fetched), new Set(["a", "b", "c"]));
+  });
+});
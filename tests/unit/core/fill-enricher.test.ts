@@ -0,0 +1,131 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { FillEnricher, type RawFill } from "../../../src/core/fill-enricher";
+
+function fill(overrides: Partial<RawFill> = {}): RawFill {
+  return {
+    tradeId: "t1",
+    orderIds: ["o1"],
+    tokenId: "tok",
+    side: "BUY",
+    price: 0.52,
+    shares: 10,
+    timestamp: 10_000,
+    source: "WS",
+    ...overrides,
+  };
+}
+
+describe("FillEnricher", () => {
+  it("attaches the order's strategy, intended price and slippage", () => {
+    const enricher = new FillEnricher();
+    enricher.registerOrder({
+      orderId: "o1",
+      tokenId: "tok",
+      side: "BUY",
+      strategy: "whale",
+      intendedPrice: 0.5,
+      orderType: "FOK",
+      meta: { attemptId: "a1" },
+      placedAt: 9_000,
+    });
+
+    const event = enricher.record(fill())!;
+    assert.strictEqual(event.event, "ORDER_FILL");
+    assert.strictEqual(event.matchedBy, "ORDER_ID");
+    assert.strictEqual(event.strategy, "whale");
+    assert.strictEqual(event.intendedPrice, 0.5);
+    assert.ok(Math.abs(event.slippageCents! - 2) < 1e-9);
+    assert.deepStrictEqual(event.meta, { attemptId: "a1" });
+    assert.strictEqual(event.positionShares, 10);
+  });
+
+  it("counts a better-than-intended sell as negative slippage", () => {
+    const enricher = new FillEnricher();
+    enricher.registerOrder({
+      orderId: "o2",
+      tokenId: "tok",
+      side: "SELL",
+      intendedPrice: 0.6,
+      placedAt: 9_000,
+    });
+
+    const event = enricher.record(
+      fill({ orderIds: ["taker", "o2"], side: "BUY", price: 0.62 }),
+    )!;
+    // Maker fill: the trade reports the taker's side, ours is the intent's
+    assert.strictEqual(event.side, "SELL");
+    assert.ok(Math.abs(event.slippageCents! + 2) < 1e-9);
+  });
+
+  it("tracks the running position from the seeded holdings", () => {
+    const enricher = new FillEnricher({ positionSource: () => 20 });
+
+    const buy = enricher.record(fill({ tradeId: "t1" }))!;
+    const sell = enricher.record(
+      fill({ tradeId: "t2", side: "SELL", shares: 25 }),
+    )!;
+    assert.strictEqual(buy.positionShares, 30);
+    assert.strictEqual(sell.positionShares, 5);
+    assert.strictEqual(sell.matchedBy, "NONE");
+    assert.strictEqual(sell.strategy, null);
+  });
+
+  it("falls back to the latest intent on the same token and side", () => {
+    const enricher = new FillEnricher();
+    for (const [orderId, placedAt] of [
+      ["old", 1_000],
+      ["new", 2_000],
+    ] as const) {
+      enricher.registerOrder({
+        orderId,
+        tokenId: "tok",
+        side: "BUY",
+        strategy: orderId,
+        intendedPrice: 0.5,
+        placedAt,
+      });
+    }
+
+    const event = enricher.record(fill({ orderIds: ["unknown"] }))!;
+    assert.strictEqual(event.matchedBy, "TOKEN");
+    assert.strictEqual(event.orderId, "new");
+  });
+
+  it("emits each trade once across sources", () => {
+    const enricher = new FillEnricher();
+    assert.ok(enricher.record(fill({ source: "WS" })));
+    assert.strictEqual(enricher.record(fill({ source: "POLL" })), null);
+    assert.strictEqual(enricher.getPosition("tok"), 10);
+  });
+
+  it("totals price improvement vs the quote per strategy and order type", () => {
+    const enricher = new FillEnricher();
+    const register = (orderId: string, side: "BUY" | "SELL", quote: number) =>
+      enricher.registerOrder({
+        orderId,
+        tokenId: "tok",
+        side,
+        strategy: "whale",
+        intendedPrice: quote,
+        quotedPrice: quote,
+        orderType: "FOK",
+        placedAt: 9_000,
+      });
+    register("o1", "BUY", 0.53);
+    register("o2", "SELL", 0.5);
+
+    // BUY 1¢ under the ask, SELL 1¢ under the bid
+    const buy = enricher.record(fill({ orderIds: ["o1"], price: 0.52 }))!;
+    enricher.record(
+      fill({ tradeId: "t2", orderIds: ["o2"], side: "SELL", price: 0.49 }),
+    );
+
+    assert.ok(Math.abs(buy.priceImprovementCents! - 1) < 1e-9);
+    const [stats] = enricher.getPriceImprovement();
+    assert.strictEqual(stats.strategy, "whale");
+    assert.strictEqual(stats.orderType, "FOK");
+    assert.strictEqual(stats.fills, 2);
+    assert.ok(Math.abs(stats.improvementUsd) < 1e-9);
+  });
+});
@@ -0,0 +1,136 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  FillJournal,
+  tradeToFill,
+  type JournalFill,
+} from "../../../src/core/fill-journal";
+
+const ME = "0xAbC0000000000000000000000000000000000001";
+
+function fill(overrides: Partial<JournalFill> = {}): JournalFill {
+  return {
+    tradeId: "t1",
+    tokenId: "tok-1",
+    side: "BUY",
+    price: 0.4,
+    shares: 10,
+    timestamp: 1_000,
+    source: "WS",
+    ...overrides,
+  };
+}
+
+describe("tradeToFill", () => {
+  it("takes the trade's own side and size as taker", () => {
+    const result = tradeToFill(
+      {
+        id: "t1",
+        asset_id: "tok-1",
+        side: "SELL",
+        price: "0.55",
+        size: "20",
+        match_time: "1700000000",
+        trader_side: "TAKER",
+      },
+      ME,
+    );
+    assert.deepStrictEqual(result, {
+      tradeId: "t1",
+      tokenId: "tok-1",
+      side: "SELL",
+      price: 0.55,
+      shares: 20,
+      timestamp: 1_700_000_000_000,
+      source: "BACKFILL",
+      liquidity: "TAKER",
+    });
+  });
+
+  it("keeps only the account's maker orders as maker", () => {
+    const maker = (address: string, amount: string, price: string) => ({
+      maker_address: address,
+      matched_amount: amount,
+      price,
+      side: "BUY",
+      asset_id: "tok-1",
+    });
+    const result = tradeToFill(
+      {
+        id: "t2",
+        asset_id: "tok-2",
+        side: "SELL",
+        price: "0.5",
+        size: "30",
+        match_time: "1700000000",
+        trader_side: "MAKER",
+        maker_orders: [
+          maker(ME.toLowerCase(), "10", "0.4"),
+          maker("0x9999999999999999999999999999999999999999", "10", "0.5"),
+          maker(ME, "10", "0.5"),
+        ],
+      },
+      ME,
+    );
+    assert.strictEqual(result?.side, "BUY");
+    assert.strictEqual(result?.tokenId, "tok-1");
+    assert.strictEqual(result?.shares, 20);
+    assert.strictEqual(result?.liquidity, "MAKER");
+    assert.ok(Math.abs(result!.price - 0.45) < 1e-9);
+  });
+});
+
+describe("FillJournal", () => {
+  it("merges history without double counting live fills", () => {
+    const journal = new FillJournal();
+    journal.add(fill({ tradeId: "t2", timestamp: 2_000 }));
+
+    const result = journal.merge([
+      fill({ tradeId: "t1", source: "BACKFILL" }),
+      fill({ tradeId: "t2", timestamp: 2_000, source: "BACKFILL" }),
+    ]);
+
+    assert.deepStrictEqual(result, { added: 1, duplicates: 1 });
+    assert.strictEqual(journal.size(), 2);
+  });
+
+  it("computes average cost and realized PnL in time order", () => {
+    const journal = new FillJournal();
+    journal.merge([
+      fill({
+        tradeId: "t3",
+        side: "SELL",
+        price: 0.6,
+        shares: 10,
+        timestamp: 3,
+      }),
+      fill({ tradeId: "t1", price: 0.4, shares: 10, timestamp: 1 }),
+      fill({ tradeId: "t2", price: 0.5, shares: 10, timestamp: 2 }),
+    ]);
+
+    const [basis] = journal.getCostBasis();
+    assert.strictEqual(basis.shares, 10);
+    assert.ok(Math.abs(basis.avgCost - 0.45) < 1e-9);
+    assert.ok(Math.abs(basis.realizedPnlUsd - 1.5) < 1e-9);
+  });
+
+  it("restores the journal from its file", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "fill-journal-"));
+    const filePath = path.join(dir, "fill-journal.json");
+    try {
+      const before = new FillJournal();
+      before.attachStateFile(filePath);
+      before.add(fill());
+
+      const after = new FillJournal();
+      after.attachStateFile(filePath);
+      assert.strictEqual(after.size(), 1);
+      assert.strictEqual(after.add(fill()), false);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
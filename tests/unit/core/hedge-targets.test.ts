@@ -0,0 +1,147 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  HedgeTargets,
+  hedgeExposure,
+  parseHedgeTargets,
+  planHedgeAdjustment,
+} from "../../../src/core/hedge-targets";
+import { PositionManager } from "../../../src/core/position-manager";
+import type { ManagedPosition } from "../../../src/core/decision-engine";
+
+/** 20 shares at 50¢, hedged with `hedgeUsd` of complement at 50¢ */
+function position(hedgeUsd = 0): ManagedPosition {
+  return {
+    id: "pos-1",
+    tokenId: "tok-yes",
+    marketId: "mkt-1",
+    side: "LONG",
+    state: "OPEN",
+    entryPriceCents: 50,
+    entrySizeUsd: 10,
+    entryTime: 0,
+    currentPriceCents: 50,
+    unrealizedPnlCents: 0,
+    unrealizedPnlUsd: 0,
+    takeProfitPriceCents: 64,
+    hedgeTriggerPriceCents: 34,
+    hardExitPriceCents: 20,
+    hedges: hedgeUsd
+      ? [
+          {
+            tokenId: "tok-no",
+            sizeUsd: hedgeUsd,
+            entryPriceCents: 50,
+            entryTime: 0,
+            pnlCents: 0,
+          },
+        ]
+      : [],
+    totalHedgeRatio: hedgeUsd / 10,
+    oppositeTokenId: "tok-no",
+    referencePriceCents: 50,
+    transitions: [],
+    lastUpdateTime: 0,
+  };
+}
+
+describe("parseHedgeTargets", () => {
+  it("parses ratios per ID and skips ones outside 0-1", () => {
+    assert.deepStrictEqual(
+      parseHedgeTargets("mkt-1:0.3; *:0 ;tok:1.5;bad;:0.2"),
+      { "mkt-1": 0.3, "*": 0 },
+    );
+  });
+});
+
+describe("planHedgeAdjustment", () => {
+  it("buys the complement up to the target", () => {
+    const plan = planHedgeAdjustment(position(), 0.3, 50, 1);
+    assert.strictEqual(plan?.side, "BUY");
+    assert.strictEqual(plan?.shares, 6);
+    assert.strictEqual(plan?.sizeUsd, 3);
+    assert.strictEqual(plan?.currentRatio, 0);
+  });
+
+  it("sells the complement down to a lowered target", () => {
+    // 10 of 20 shares hedged, target 30% = 6
+    const plan = planHedgeAdjustment(position(5), 0.3, 40, 1);
+    assert.strictEqual(plan?.side, "SELL");
+    assert.strictEqual(plan?.shares, 4);
+    assert.strictEqual(plan?.currentRatio, 0.5);
+  });
+
+  it("leaves adjustments under the minimum alone", () => {
+    assert.strictEqual(planHedgeAdjustment(position(2.9), 0.3, 50, 1), null);
+  });
+});
+
+describe("HedgeTargets", () => {
+  it("prefers the market's target, then the token's, then *", () => {
+    const targets = new HedgeTargets({ "*": 0.1, "tok-yes": 0.2 });
+    assert.strictEqual(targets.targetFor(position()), 0.2);
+    targets.set("mkt-1", 0.4);
+    assert.strictEqual(targets.targetFor(position()), 0.4);
+    assert.strictEqual(targets.targetFor({ tokenId: "other" }), 0.1);
+    assert.strictEqual(new HedgeTargets().targetFor(position()), undefined);
+  });
+
+  it("picks up overrides saved by another instance", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "hedge-targets-"));
+    const filePath = path.join(dir, "targets.json");
+    const bot = new HedgeTargets({ "mkt-1": 0.3 });
+    bot.attachStateFile(filePath);
+
+    const script = new HedgeTargets({ "mkt-1": 0.3 });
+    script.attachStateFile(filePath);
+    script.set("mkt-1", 0.5);
+    bot.refresh();
+    assert.strictEqual(bot.targetFor(position()), 0.5);
+
+    // Without the override, HEDGE_TARGETS applies again
+    script.clear("mkt-1");
+    const restarted = new HedgeTargets({ "mkt-1": 0.3 });
+    restarted.attachStateFile(filePath);
+    assert.strictEqual(restarted.targetFor(position()), 0.3);
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+});
+
+describe("PositionManager.trimHedges", () => {
+  it("takes shares off the newest hedge legs first", () => {
+    const manager = new PositionManager({
+      tpCents: 14,
+      hedgeTriggerCents: 16,
+      maxAdverseCents: 30,
+      maxHoldSeconds: 3600,
+      hedgeRatio: 0.4,
+      maxHedgeRatio: 0.7,
+    });
+    const { id } = manager.openPosition({
+      tokenId: "tok-yes",
+      side: "LONG",
+      entryPriceCents: 50,
+      sizeUsd: 10,
+      referencePriceCents: 50,
+      evSnapshot: null,
+      biasDirection: "LONG",
+    });
+    for (const entryPriceCents of [50, 25]) {
+      manager.recordHedge(
+        id,
+        { tokenId: "tok-no", sizeUsd: 2, entryPriceCents, entryTime: 0 },
+        null,
+        "LONG",
+      );
+    }
+
+    // Legs: 4 shares @ 50¢, 8 shares @ 25¢ - trim 10
+    assert.strictEqual(manager.trimHedges(id, 10), 3);
+    const trimmed = manager.getPosition(id)!;
+    assert.strictEqual(trimmed.hedges.length, 1);
+    assert.strictEqual(hedgeExposure(trimmed).hedgedShares, 2);
+  });
+});
@@ -0,0 +1,167 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  ImbalanceTriggerManager,
+  measureImbalance,
+  parseImbalanceTriggers,
+} from "../../../src/core/imbalance-trigger";
+import { MarketDataStore } from "../../../src/lib/market-data-store";
+
+/** Book around a 0.50 mid: bidSize at 0.49, askSize at 0.51, far levels */
+function book(bidSize: number, askSize: number) {
+  return {
+    bids: [
+      { price: 0.49, size: bidSize },
+      { price: 0.4, size: 10_000 },
+    ],
+    asks: [
+      { price: 0.51, size: askSize },
+      { price: 0.6, size: 10_000 },
+    ],
+  };
+}
+
+describe("parseImbalanceTriggers", () => {
+  it("parses side, ratio, band, hold and repeat with defaults", () => {
+    assert.deepStrictEqual(
+      parseImbalanceTriggers("tok1:bid=5,band=3,hold=1500,repeat; tok2:ask=4"),
+      [
+        {
+          tokenId: "tok1",
+          side: "BID",
+          ratio: 5,
+          bandCents: 3,
+          holdMs: 1500,
+          repeat: true,
+        },
+        {
+          tokenId: "tok2",
+          side: "ASK",
+          ratio: 4,
+          bandCents: 2,
+          holdMs: 1000,
+          repeat: false,
+        },
+      ],
+    );
+  });
+
+  it("skips entries without a ratio above 1", () => {
+    assert.deepStrictEqual(
+      parseImbalanceTriggers("tok:bid=1;tok:band=2;:ask=3"),
+      [],
+    );
+  });
+});
+
+describe("measureImbalance", () => {
+  it("only counts levels within the band of the mid", () => {
+    const { bids, asks } = book(500, 100);
+    assert.deepStrictEqual(measureImbalance(bids, asks, 2), {
+      bidSize: 500,
+      askSize: 100,
+      mid: 0.5,
+    });
+    assert.strictEqual(measureImbalance([], asks, 2), null);
+  });
+});
+
+describe("ImbalanceTriggerManager", () => {
+  const spec = {
+    tokenId: "tok",
+    side: "BID" as const,
+    ratio: 5,
+    bandCents: 2,
+    holdMs: 1000,
+    repeat: false,
+  };
+
+  it("fires once the ratio has held for the hold time", () => {
+    const manager = new ImbalanceTriggerManager();
+    manager.add(spec);
+    const { bids, asks } = book(600, 100);
+
+    assert.deepStrictEqual(manager.observe("tok", bids, asks, 0), []);
+    assert.deepStrictEqual(manager.observe("tok", bids, asks, 500), []);
+    const [event] = manager.observe("tok", bids, asks, 1000);
+    assert.strictEqual(event.event, "IMBALANCE_TRIGGER");
+    assert.strictEqual(event.ratio, 6);
+    assert.strictEqual(event.heldMs, 1000);
+
+    // One-shot: done, and no longer watched
+    assert.deepStrictEqual(manager.observe("tok", bids, asks, 5000), []);
+    assert.deepStrictEqual(manager.getWatchedTokens(), []);
+  });
+
+  it("restarts the hold when the imbalance breaks", () => {
+    const manager = new ImbalanceTriggerManager();
+    manager.add(spec);
+    const heavy = book(600, 100);
+    const even = book(100, 100);
+
+    manager.observe("tok", heavy.bids, heavy.asks, 0);
+    manager.observe("tok", even.bids, even.asks, 600);
+    assert.deepStrictEqual(
+      manager.observe("tok", heavy.bids, heavy.asks, 1200),
+      [],
+    );
+    assert.strictEqual(
+      manager.observe("tok", heavy.bids, heavy.asks, 2200).length,
+      1,
+    );
+  });
+
+  it("fires from tick() when the hold ends between books", () => {
+    const manager = new ImbalanceTriggerManager();
+    manager.add(spec);
+    const { bids, asks } = book(600, 100);
+
+    manager.observe("tok", bids, asks, 0);
+    assert.deepStrictEqual(manager.tick(500), []);
+    assert.strictEqual(manager.tick(1000).length, 1);
+  });
+
+  it("re-arms a repeating trigger once the imbalance is gone", () => {
+    const manager = new ImbalanceTriggerManager();
+    manager.add({ ...spec, holdMs: 0, repeat: true });
+    const heavy = book(600, 100);
+    const even = book(100, 100);
+    const fire = () => manager.observe("tok", heavy.bids, heavy.asks).length;
+
+    assert.strictEqual(fire(), 1);
+    assert.strictEqual(fire(), 0);
+    manager.observe("tok", even.bids, even.asks);
+    assert.strictEqual(fire(), 1);
+  });
+
+  it("restores fired state for configured triggers", () => {
+    const manager = new ImbalanceTriggerManager();
+    manager.add({ ...spec, holdMs: 0 });
+    const { bids, asks } = book(600, 100);
+    manager.observe("tok", bids, asks);
+    const saved = manager.exportState();
+    assert.strictEqual(saved[0].done, true);
+
+    const restarted = new ImbalanceTriggerManager();
+    restarted.add({ ...spec, holdMs: 0 });
+    assert.strictEqual(restarted.importState(saved), 1);
+    assert.deepStrictEqual(restarted.observe("tok", bids, asks), []);
+  });
+
+  it("follows books streamed into a market data store", () => {
+    const store = new MarketDataStore();
+    const manager = new ImbalanceTriggerManager();
+    manager.add({ ...spec, holdMs: 0 });
+    const fired: string[] = [];
+    manager.onTrigger((e) => fired.push(e.tokenId));
+    manager.attach(store);
+
+    const even = book(100, 100);
+    store.updateFromWs("tok", even.bids, even.asks);
+    // Same top of book, only sizes changed
+    const heavy = book(600, 100);
+    store.updateFromWs("tok", heavy.bids, heavy.asks);
+
+    assert.deepStrictEqual(fired, ["tok"]);
+  });
+});
@@ -0,0 +1,149 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  JobScheduler,
+  nextJobRun,
+  parseJobSchedule,
+  parseJobSpec,
+} from "../../../src/core/job-scheduler";
+
+const MIN = 60 * 1000;
+/** Friday 2026-10-16 05:00 UTC */
+const FRIDAY_5AM = Date.UTC(2026, 9, 16, 5, 0);
+
+describe("parseJobSchedule", () => {
+  it("parses intervals and UTC times with optional days", () => {
+    const jobs = parseJobSchedule(
+      "rebalance@every 30m; Dust @ mon-fri  04:00;report@21:00;" +
+        "sweep@every 5m;report@25:00;dust@every 0h",
+    );
+    assert.deepStrictEqual(jobs, [
+      {
+        id: "rebalance@every 30m",
+        job: "rebalance",
+        timing: { kind: "every", intervalMs: 30 * MIN },
+      },
+      {
+        id: "dust@mon-fri 04:00",
+        job: "dust",
+        timing: { kind: "at", days: [1, 2, 3, 4, 5], minute: 240 },
+      },
+      {
+        id: "report@21:00",
+        job: "report",
+        timing: { kind: "at", days: [0, 1, 2, 3, 4, 5, 6], minute: 1260 },
+      },
+    ]);
+  });
+});
+
+describe("nextJobRun", () => {
+  it("skips to the next allowed day", () => {
+    const { timing } = parseJobSpec("dust@mon-fri 04:00")!;
+    assert.strictEqual(
+      nextJobRun(timing, FRIDAY_5AM),
+      Date.UTC(2026, 9, 19, 4, 0),
+    );
+    const daily = parseJobSpec("report@21:00")!.timing;
+    assert.strictEqual(
+      nextJobRun(daily, FRIDAY_5AM),
+      Date.UTC(2026, 9, 16, 21, 0),
+    );
+  });
+});
+
+describe("JobScheduler", () => {
+  it("runs due jobs once and records how they went", async () => {
+    const scheduler = new JobScheduler(
+      parseJobSchedule("report@every 30m; dust@every 30m"),
+      FRIDAY_5AM,
+    );
+    scheduler.setHandler("report", async () => "sent");
+    scheduler.setHandler("dust", async () => {
+      throw new Error("no client");
+    });
+
+    assert.strictEqual(scheduler.tick(FRIDAY_5AM + 29 * MIN).length, 0);
+    await Promise.all(scheduler.tick(FRIDAY_5AM + 30 * MIN));
+
+    const [report, dust] = scheduler.list();
+    assert.strictEqual(report.runs, 1);
+    assert.strictEqual(report.lastResult, "sent");
+    assert.strictEqual(report.nextRunAt, FRIDAY_5AM + 60 * MIN);
+    assert.strictEqual(dust.ok, false);
+    assert.strictEqual(dust.lastResult, "no client");
+  });
+
+  it("doesn't start a job again while it's still running", async () => {
+    const scheduler = new JobScheduler(
+      parseJobSchedule("cancel-stale@every 1m"),
+      FRIDAY_5AM,
+    );
+    let finish = () => {};
+    let starts = 0;
+    scheduler.setHandler("cancel-stale", () => {
+      starts++;
+      return new Promise<void>((resolve) => (finish = () => resolve()));
+    });
+
+    const runs = scheduler.tick(FRIDAY_5AM + MIN);
+    assert.strictEqual(scheduler.list()[0].running, true);
+    assert.strictEqual(scheduler.tick(FRIDAY_5AM + 5 * MIN).length, 0);
+    finish();
+    await Promise.all(runs);
+    assert.strictEqual(starts, 1);
+  });
+
+  it("doesn't catch up times missed while the bot was down", async () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "job-scheduler-"));
+    const filePath = path.join(dir, "jobs.json");
+    const configured = parseJobSchedule("report@21:00");
+    const thursday9pm = Date.UTC(2026, 9, 15, 21, 0);
+
+    const before = new JobScheduler(configured, thursday9pm - 60 * MIN);
+    before.attachStateFile(filePath);
+    before.setHandler("report", async () => "sent");
+    await Promise.all(before.tick(thursday9pm));
+
+    // Down through Friday 21:00, back up at 22:00
+    const friday10pm = Date.UTC(2026, 9, 16, 22, 0);
+    const restarted = new JobScheduler(configured, friday10pm);
+    restarted.attachStateFile(filePath);
+    restarted.setHandler("report", async () => "sent");
+    assert.strictEqual(restarted.list()[0].runs, 1);
+    assert.strictEqual(restarted.tick(friday10pm).length, 0);
+    assert.strictEqual(
+      restarted.list()[0].nextRunAt,
+      Date.UTC(2026, 9, 17, 21, 0),
+    );
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("picks up schedule changes saved by another instance", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "job-scheduler-"));
+    const filePath = path.join(dir, "jobs.json");
+    const configured = parseJobSchedule("report@21:00");
+    const bot = new JobScheduler(configured);
+    bot.attachStateFile(filePath);
+
+    const script = new JobScheduler(configured);
+    script.attachStateFile(filePath);
+    assert.strictEqual(script.add(parseJobSpec("rebalance@every 1h")!), true);
+    assert.strictEqual(script.add(parseJobSpec("report@21:00")!), false);
+    bot.refresh();
+    assert.strictEqual(bot.hasJob("rebalance"), true);
+
+    // Removing a JOB_SCHEDULE job sticks across restarts
+    assert.strictEqual(script.remove("report@21:00"), true);
+    const restarted = new JobScheduler(configured);
+    restarted.attachStateFile(filePath);
+    assert.deepStrictEqual(
+      restarted.list().map((j) => j.id),
+      ["rebalance@every 1h"],
+    );
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+});
@@ -0,0 +1,73 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  checkLiquidityFloor,
+  formatLiquidityFloorCheck,
+  parseLiquidityFloors,
+} from "../../../src/core/liquidity-floor";
+
+describe("parseLiquidityFloors", () => {
+  it("should parse per-strategy floors with a default window", () => {
+    assert.deepStrictEqual(
+      parseLiquidityFloors("whale:mult=5,within=2; scan:mult=3"),
+      {
+        whale: { sizeMultiple: 5, withinCents: 2 },
+        scan: { sizeMultiple: 3, withinCents: 2 },
+      },
+    );
+  });
+
+  it("should drop unknown strategies and entries without a multiple", () => {
+    assert.deepStrictEqual(
+      parseLiquidityFloors("arb:mult=5;whale:within=1;scan:mult=0"),
+      {},
+    );
+  });
+});
+
+describe("checkLiquidityFloor", () => {
+  const asks = [
+    { price: 0.5, size: 20 },
+    { price: 0.51, size: 15 },
+    { price: 0.52, size: 10 },
+    { price: 0.55, size: 500 },
+  ];
+  const bids = [
+    { price: 0.48, size: 30 },
+    { price: 0.46, size: 40 },
+    { price: 0.4, size: 500 },
+  ];
+
+  it("should count asks within the window above the best ask", () => {
+    const floor = { sizeMultiple: 5, withinCents: 2 };
+    const check = checkLiquidityFloor(floor, asks, "BUY", 9);
+    // 0.50 + 0.51 + 0.52 (the 0.52 level sits exactly on the edge)
+    assert.strictEqual(check.availableShares, 45);
+    assert.strictEqual(check.requiredShares, 45);
+    assert.ok(check.ok);
+    assert.ok(!checkLiquidityFloor(floor, asks, "BUY", 10).ok);
+  });
+
+  it("should count bids within the window below the best bid", () => {
+    const floor = { sizeMultiple: 3, withinCents: 1 };
+    const check = checkLiquidityFloor(floor, bids, "SELL", 20);
+    assert.strictEqual(check.bestPrice, 0.48);
+    assert.strictEqual(check.availableShares, 30);
+    assert.ok(!check.ok);
+    assert.match(
+      formatLiquidityFloorCheck(floor, check),
+      /30\.0 shares within 1¢ of 48\.0¢, need 60\.0 \(3x 20\.0\)/,
+    );
+  });
+
+  it("should fail an empty side", () => {
+    const check = checkLiquidityFloor(
+      { sizeMultiple: 1, withinCents: 2 },
+      [],
+      "BUY",
+      1,
+    );
+    assert.ok(!check.ok);
+    assert.strictEqual(check.bestPrice, null);
+  });
+});
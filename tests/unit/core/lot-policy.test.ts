@@ -0,0 +1,44 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  applyLotPolicy,
+  parseLotPolicies,
+} from "../../../src/core/lot-policy";
+
+describe("parseLotPolicies", () => {
+  it("parses share and $ steps per strategy", () => {
+    assert.deepStrictEqual(
+      parseLotPolicies("whale:shares=1; scan:usd=5,shares=10"),
+      {
+        whale: { shareStep: 1 },
+        scan: { usdStep: 5, shareStep: 10 },
+      },
+    );
+  });
+
+  it("drops unknown strategies and non-positive steps", () => {
+    assert.deepStrictEqual(
+      parseLotPolicies("arb:shares=1;whale:usd=0;scan"),
+      {},
+    );
+  });
+});
+
+describe("applyLotPolicy", () => {
+  it("rounds shares down to whole lots", () => {
+    assert.strictEqual(applyLotPolicy({ shareStep: 1 }, 14.68, 0.5), 14);
+    assert.strictEqual(applyLotPolicy({ shareStep: 5 }, 14.68, 0.5), 10);
+    // Float error doesn't cost a lot
+    assert.strictEqual(applyLotPolicy({ shareStep: 1 }, 2.9999999999, 0.5), 3);
+  });
+
+  it("rounds notional down to $ steps", () => {
+    // $7.34 at 40¢ -> $5 -> 12.5 shares
+    assert.strictEqual(applyLotPolicy({ usdStep: 5 }, 18.35, 0.4), 12.5);
+  });
+
+  it("returns 0 below one lot and leaves sizes alone without a policy", () => {
+    assert.strictEqual(applyLotPolicy({ shareStep: 10 }, 9.9, 0.5), 0);
+    assert.strictEqual(applyLotPolicy(undefined, 9.9, 0.5), 9.9);
+  });
+});
@@ -0,0 +1,102 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  MarketBlacklist,
+  parseBlacklistScope,
+} from "../../../src/core/market-blacklist";
+
+describe("MarketBlacklist", () => {
+  it("blocks entries on any of a market's IDs", () => {
+    const blacklist = new MarketBlacklist();
+    blacklist.add(["cond-1", "tok-yes", "tok-no"], "DISPUTED", "Q?");
+
+    assert.strictEqual(
+      blacklist.check(["tok-no", undefined]),
+      "BLACKLISTED: DISPUTED",
+    );
+    assert.strictEqual(blacklist.check(["tok-other", "mkt-2"]), null);
+  });
+
+  it("merges IDs into an existing entry instead of adding another", () => {
+    const blacklist = new MarketBlacklist();
+    blacklist.add(["cond-1", "tok-yes"], "FAILED_FILLS");
+
+    assert.strictEqual(blacklist.add(["tok-yes", "mkt-1"], "MANUAL"), null);
+    assert.strictEqual(blacklist.list().length, 1);
+    assert.ok(blacklist.isBlacklisted("mkt-1"));
+    assert.strictEqual(blacklist.list()[0].reason, "FAILED_FILLS");
+  });
+
+  it("removes every entry matching an ID", () => {
+    const blacklist = new MarketBlacklist();
+    blacklist.add(["cond-1", "tok-yes"], "MANUAL");
+    assert.strictEqual(blacklist.remove("tok-yes"), 1);
+    assert.strictEqual(blacklist.isBlacklisted("cond-1"), false);
+    assert.strictEqual(blacklist.remove("tok-yes"), 0);
+  });
+
+  it("trips on failed fills only within the window", () => {
+    const blacklist = new MarketBlacklist({
+      maxFailedFills: 3,
+      failedFillWindowMs: 60_000,
+    });
+    assert.strictEqual(blacklist.recordFailedFill("mkt-1", 0), false);
+    assert.strictEqual(blacklist.recordFailedFill("mkt-1", 30_000), false);
+    // The first has aged out
+    assert.strictEqual(blacklist.recordFailedFill("mkt-1", 70_000), false);
+    assert.strictEqual(blacklist.recordFailedFill("mkt-1", 80_000), true);
+    // Counting starts over once tripped
+    assert.strictEqual(blacklist.recordFailedFill("mkt-1", 90_000), false);
+  });
+
+  it("only counts resolutions that lost enough", () => {
+    const blacklist = new MarketBlacklist({ resolvedLossUsd: 50 });
+    assert.strictEqual(blacklist.isBadResolution(80, 0), true);
+    assert.strictEqual(blacklist.isBadResolution(30, 0), false);
+    assert.strictEqual(blacklist.isBadResolution(80, 100), false);
+    assert.strictEqual(
+      new MarketBlacklist({ resolvedLossUsd: 0 }).isBadResolution(80, 0),
+      false,
+    );
+  });
+
+  it("blacklists an ID it can't look up as is", async () => {
+    const blacklist = new MarketBlacklist();
+    const seen: string[] = [];
+    blacklist.onBlacklisted((e) => seen.push(e.reason));
+
+    const entry = await blacklist.blacklist("mkt-1", "MANUAL", "bad book");
+    assert.deepStrictEqual(entry?.keys, ["mkt-1"]);
+    assert.strictEqual(await blacklist.blacklist("mkt-1", "MANUAL"), null);
+    assert.deepStrictEqual(seen, ["MANUAL"]);
+  });
+
+  it("picks up changes another process saved", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "market-blacklist-"));
+    const filePath = path.join(dir, "market-blacklist.json");
+    try {
+      const bot = new MarketBlacklist();
+      bot.attachStateFile(filePath);
+
+      const script = new MarketBlacklist();
+      script.attachStateFile(filePath);
+      script.add(["cond-1"], "MANUAL");
+
+      assert.strictEqual(bot.isBlacklisted("cond-1"), false);
+      bot.refresh();
+      assert.strictEqual(bot.isBlacklisted("cond-1"), true);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
+
+describe("parseBlacklistScope", () => {
+  it("falls back to event scope", () => {
+    assert.strictEqual(parseBlacklistScope(" Market "), "market");
+    assert.strictEqual(parseBlacklistScope("bogus"), "event");
+  });
+});
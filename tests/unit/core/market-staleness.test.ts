@@ -0,0 +1,133 @@
+/**
+ * Market Staleness Index Tests
+ *
+ * Tests for:
+ * - ACTIVE / QUIET / DEAD classification from trades and quote changes
+ * - Delisting on "no orderbook", cleared by a live book
+ * - Scan exclusion and the staleness report
+ * - Feeding from MarketDataStore trades and quotes
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  MarketStalenessIndex,
+  isDelistedError,
+} from "../../../src/core/market-staleness";
+import { MarketDataStore } from "../../../src/lib/market-data-store";
+
+const MIN = 60_000;
+const HOUR = 60 * MIN;
+
+describe("MarketStalenessIndex", () => {
+  const t0 = 1_000_000_000;
+
+  it("should go ACTIVE → QUIET → DEAD as a market stops moving", () => {
+    const index = new MarketStalenessIndex({
+      quietAfterMs: HOUR,
+      deadAfterMs: 6 * HOUR,
+    });
+    index.observeQuote("t", 0.48, 0.52, t0);
+    index.observeTrade("t", t0 + 10 * MIN);
+
+    assert.strictEqual(index.getStatus("t", t0 + 30 * MIN), "ACTIVE");
+    assert.strictEqual(index.getStatus("t", t0 + 2 * HOUR), "QUIET");
+    assert.strictEqual(index.getStatus("t", t0 + 7 * HOUR), "DEAD");
+    assert.ok(index.isExcluded("t", t0 + 7 * HOUR));
+
+    // A requote brings it back
+    index.observeQuote("t", 0.49, 0.52, t0 + 7 * HOUR);
+    assert.strictEqual(index.getStatus("t", t0 + 7 * HOUR), "ACTIVE");
+  });
+
+  it("should only count changed quotes as updates", () => {
+    const index = new MarketStalenessIndex({ deadAfterMs: HOUR });
+    index.observeQuote("t", 0.48, 0.52, t0);
+    // Same book re-fetched for an hour - nothing is happening
+    index.observeQuote("t", 0.48, 0.52, t0 + 30 * MIN);
+    index.observeQuote("t", 0.48, 0.52, t0 + HOUR);
+
+    const [entry] = index.getReport(undefined, t0 + HOUR);
+    assert.strictEqual(entry.status, "DEAD");
+    assert.strictEqual(entry.quoteUpdatesPerHour, 0);
+    assert.strictEqual(entry.lastQuoteChangeAt, null);
+  });
+
+  it("should flag delisted tokens until a live book shows up", () => {
+    const index = new MarketStalenessIndex();
+    index.markDelisted("t", "No orderbook exists for the requested token id");
+
+    assert.strictEqual(index.getStatus("t"), "DELISTED");
+    assert.ok(index.isExcluded("t"));
+    assert.match(index.getReport()[0].delistedReason!, /No orderbook/);
+
+    index.observeQuote("t", 0.3, 0.32);
+    assert.strictEqual(index.getStatus("t"), "ACTIVE");
+  });
+
+  it("should never exclude an unseen token", () => {
+    const index = new MarketStalenessIndex();
+    assert.strictEqual(index.getStatus("unknown"), null);
+    assert.strictEqual(index.isExcluded("unknown"), false);
+  });
+
+  it("should report the most stale markets first", () => {
+    const index = new MarketStalenessIndex({
+      quietAfterMs: HOUR,
+      deadAfterMs: 6 * HOUR,
+    });
+    index.observeTrade("active", t0 + 9 * HOUR);
+    index.observeTrade("dead", t0);
+    index.observeTrade("quiet", t0 + 7 * HOUR);
+    index.markDelisted("gone", "404", t0);
+    const now = t0 + 9 * HOUR;
+
+    assert.deepStrictEqual(
+      index.getReport(undefined, now).map((e) => e.tokenId),
+      ["gone", "dead", "quiet", "active"],
+    );
+    assert.deepStrictEqual(
+      index.getReport(["DEAD", "DELISTED"], now).map((e) => e.status),
+      ["DELISTED", "DEAD"],
+    );
+    assert.deepStrictEqual(index.getCounts(now), {
+      ACTIVE: 1,
+      QUIET: 1,
+      DEAD: 1,
+      DELISTED: 1,
+    });
+  });
+
+  it("should feed from MarketDataStore trades and quotes", () => {
+    const store = new MarketDataStore();
+    const index = new MarketStalenessIndex();
+    const unsubscribe = index.attach(store);
+
+    const ask = [{ price: 0.52, size: 100 }];
+    store.updateFromWs("t", [{ price: 0.48, size: 100 }], ask);
+    store.updateFromWs("t", [{ price: 0.49, size: 100 }], ask);
+    store.recordTrade({
+      tokenId: "t",
+      price: 0.5,
+      size: 10,
+      side: "BUY",
+      timestamp: Date.now(),
+    });
+
+    const [entry] = index.getReport();
+    assert.strictEqual(entry.quoteUpdatesPerHour, 1);
+    assert.notStrictEqual(entry.lastTradeAt, null);
+
+    unsubscribe();
+    store.updateFromWs("t", [{ price: 0.5, size: 100 }], ask);
+    assert.strictEqual(index.getReport()[0].quoteUpdatesPerHour, 1);
+  });
+});
+
+describe("isDelistedError", () => {
+  it("should match missing-orderbook and 404 responses only", () => {
+    assert.ok(isDelistedError("No orderbook exists for the requested token"));
+    assert.ok(isDelistedError("Request failed with status code 404"));
+    assert.ok(!isDelistedError("Request failed with status code 429"));
+  });
+});
@@ -314,6 +314,105 @@ describe("Order Execution Module", () => {
     });
   });
 
+  describe("Post-only orders", () => {
+    const {
+      computePostOnlyPrice,
+      placePostOnlyOrder,
+    } = require("../../../src/lib/order-execution");
+
+    const book = { bestBid: 0.48, bestAsk: 0.5, tickSize: 0.01 };
+
+    it("rests a price inside the spread unchanged", () => {
+      assert.deepStrictEqual(
+        computePostOnlyPrice({ ...book, side: "BUY", price: 0.49 }),
+        { price: 0.49, repriced: false },
+      );
+    });
+
+    it("rejects a crossing price by default", () => {
+      assert.strictEqual(
+        computePostOnlyPrice({ ...book, side: "BUY", price: 0.5 }),
+        null,
+      );
+      assert.strictEqual(
+        computePostOnlyPrice({ ...book, side: "SELL", price: 0.48 }),
+        null,
+      );
+    });
+
+    it("reprices one tick inside the spread when asked", () => {
+      assert.deepStrictEqual(
+        computePostOnlyPrice({
+          ...book,
+          side: "BUY",
+          price: 0.55,
+          onCross: "REPRICE",
+        }),
+        { price: 0.49, repriced: true },
+      );
+      assert.deepStrictEqual(
+        computePostOnlyPrice({
+          ...book,
+          side: "SELL",
+          price: 0.4,
+          onCross: "REPRICE",
+        }),
+        { price: 0.49, repriced: true },
+      );
+    });
+
+    it("never posts a crossing order", async () => {
+      let posted = 0;
+      const client = {
+        createOrder: async (args: any) => args,
+        postOrder: async () => {
+          posted++;
+          return { success: true, orderID: "0xabc" };
+        },
+      };
+
+      const result = await placePostOnlyOrder({
+        client,
+        tokenId: "test-token",
+        side: "BUY",
+        size: { usdc: 10 },
+        price: 0.52,
+        ...book,
+      });
+
+      assert.strictEqual(result.success, false);
+      assert.strictEqual(result.reason, "POST_ONLY_WOULD_CROSS");
+      assert.strictEqual(posted, 0);
+    });
+
+    it("posts a repriced order as a resting GTC", async () => {
+      const created: any[] = [];
+      const client = {
+        createOrder: async (args: any) => {
+          created.push(args);
+          return args;
+        },
+        postOrder: async () => ({ success: true, orderId: "0xabc" }),
+      };
+
+      const result = await placePostOnlyOrder({
+        client,
+        tokenId: "test-token",
+        side: "SELL",
+        size: { shares: 20 },
+        price: 0.45,
+        onCross: "REPRICE",
+        ...book,
+      });
+
+      assert.strictEqual(result.success, true);
+      assert.strictEqual(result.isPending, true);
+      assert.strictEqual(result.repricedFrom, 0.45);
+      assert.strictEqual(created[0].price, 0.49);
+      assert.strictEqual(created[0].size, 20);
+    });
+  });
+
   describe("Acceptance criteria", () => {
     const {
       computeExecutionLimitPrice,
@@ -0,0 +1,66 @@
+/**
+ * Order Flow Tracker Tests
+ *
+ * Tests for:
+ * - Buy vs sell aggressor imbalance over rolling windows
+ * - Indicators across all configured windows
+ * - Feeding from MarketDataStore trade prints
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+
+import { OrderFlowTracker } from "../../../src/core/order-flow";
+import { MarketDataStore } from "../../../src/lib/market-data-store";
+
+function print(side: "BUY" | "SELL", size: number, timestamp: number) {
+  return { tokenId: "t", price: 0.5, size, side, timestamp };
+}
+
+describe("OrderFlowTracker", () => {
+  const now = 1_000_000;
+
+  it("should return null with too few prints", () => {
+    const flow = new OrderFlowTracker([60_000]);
+    flow.record(print("BUY", 10, now - 1000));
+    flow.record(print("BUY", 10, now));
+
+    assert.strictEqual(flow.getImbalance("t", 60_000, now), null);
+  });
+
+  it("should weigh buyer vs seller initiated volume", () => {
+    const flow = new OrderFlowTracker([60_000]);
+    flow.record(print("BUY", 30, now - 3000));
+    flow.record(print("BUY", 30, now - 2000));
+    flow.record(print("SELL", 20, now - 1000));
+
+    // buy $30, sell $10 -> (30 - 10) / 40
+    const imbalance = flow.getImbalance("t", 60_000, now);
+    assert.ok(imbalance !== null && Math.abs(imbalance - 0.5) < 1e-9);
+  });
+
+  it("should only count prints inside each window", () => {
+    const flow = new OrderFlowTracker([10_000, 60_000]);
+    for (let i = 0; i < 3; i++) flow.record(print("SELL", 10, now - 50_000));
+    for (let i = 0; i < 3; i++) flow.record(print("BUY", 10, now - 1000));
+
+    const indicators = flow.getIndicators("t", now);
+    assert.strictEqual(indicators.windows[0].imbalance, 1);
+    assert.strictEqual(indicators.windows[0].prints, 3);
+    assert.strictEqual(indicators.windows[1].imbalance, 0);
+    assert.strictEqual(indicators.windows[1].prints, 6);
+  });
+
+  it("should feed from MarketDataStore trade prints", () => {
+    const store = new MarketDataStore();
+    const flow = new OrderFlowTracker([60_000]);
+    const unsubscribe = flow.attach(store);
+
+    const t = Date.now();
+    for (let i = 0; i < 3; i++) store.recordTrade(print("SELL", 5, t));
+    unsubscribe();
+    store.recordTrade(print("BUY", 500, t));
+
+    assert.strictEqual(flow.getImbalance("t", 60_000), -1);
+  });
+});
@@ -0,0 +1,51 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { OrderGovernor } from "../../../src/core/order-governor";
+
+const MIN = 60_000;
+
+describe("OrderGovernor", () => {
+  const t0 = 1_000_000_000;
+
+  it("should block entries once a market hits its cap", () => {
+    const governor = new OrderGovernor({ maxOrders: 3, windowMs: 60 * MIN });
+    governor.record("m1", "ENTRY", t0);
+    governor.record("m1", "EXIT", t0 + MIN);
+    assert.strictEqual(governor.checkEntry("m1", t0 + 2 * MIN), null);
+
+    governor.record("m1", "ENTRY", t0 + 2 * MIN);
+    assert.strictEqual(
+      governor.checkEntry("m1", t0 + 3 * MIN),
+      "ORDER_RATE_LIMITED: 3 orders in 60m",
+    );
+    // Other markets have their own budget
+    assert.strictEqual(governor.checkEntry("m2", t0 + 3 * MIN), null);
+  });
+
+  it("should free the budget as orders age out of the window", () => {
+    const governor = new OrderGovernor({ maxOrders: 2, windowMs: 60 * MIN });
+    governor.record("m1", "ENTRY", t0);
+    governor.record("m1", "EXIT", t0 + 30 * MIN);
+    assert.notStrictEqual(governor.checkEntry("m1", t0 + 59 * MIN), null);
+    assert.strictEqual(governor.checkEntry("m1", t0 + 60 * MIN), null);
+    assert.strictEqual(governor.getCount("m1", t0 + 60 * MIN), 1);
+  });
+
+  it("should rank the busiest markets", () => {
+    const governor = new OrderGovernor({ maxOrders: 10 });
+    governor.record("quiet", "ENTRY", t0);
+    governor.record("busy", "ENTRY", t0);
+    governor.record("busy", "HEDGE", t0);
+    assert.deepStrictEqual(governor.getBusiestMarkets(10, t0), [
+      { market: "busy", count: 2 },
+      { market: "quiet", count: 1 },
+    ]);
+  });
+
+  it("should not count or block when disabled", () => {
+    const governor = new OrderGovernor();
+    governor.record("m1", "ENTRY", t0);
+    assert.strictEqual(governor.getCount("m1", t0), 0);
+    assert.strictEqual(governor.checkEntry("m1", t0), null);
+  });
+});
@@ -0,0 +1,100 @@
+/**
+ * Order Race Arbiter Tests
+ *
+ * Tests for:
+ * - Same-token and opposite-side race detection across strategies
+ * - Priority arbitration: rejecting the loser or preempting its claim
+ * - Cancel mode dropping both entries
+ * - Claims expiring after the window
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  OrderRaceArbiter,
+  parseOrderRaceMode,
+  parseOrderRacePriority,
+  type RaceIntent,
+} from "../../../src/core/order-race";
+
+const whale: RaceIntent = {
+  tokenId: "yes",
+  marketId: "m1",
+  side: "LONG",
+  strategy: "whale",
+};
+const scanSame: RaceIntent = { ...whale, strategy: "scan" };
+const scanOpposite: RaceIntent = { ...scanSame, tokenId: "no" };
+
+describe("OrderRaceArbiter", () => {
+  const t0 = 1_000_000;
+
+  it("should reject a lower-priority strategy racing for the same token", () => {
+    const arbiter = new OrderRaceArbiter();
+    const first = arbiter.claim(whale, t0);
+    const second = arbiter.claim(scanSame, t0 + 1000);
+
+    assert.ok(first.allowed);
+    assert.ok(!second.allowed);
+    assert.strictEqual(second.reason, "ORDER_RACE_LOST: SAME_TOKEN to whale");
+    assert.ok(arbiter.isHeld(first.claimId));
+  });
+
+  it("should preempt a lower-priority claim on the other outcome", () => {
+    const arbiter = new OrderRaceArbiter();
+    const scan = arbiter.claim(scanOpposite, t0);
+    const whaleClaim = arbiter.claim(whale, t0 + 500);
+
+    assert.ok(scan.allowed && whaleClaim.allowed);
+    assert.ok(!arbiter.isHeld(scan.claimId));
+    assert.ok(arbiter.isHeld(whaleClaim.claimId));
+    const [race] = arbiter.getRecentRaces();
+    assert.strictEqual(race.kind, "OPPOSITE_SIDE");
+    assert.strictEqual(race.resolution, "EXISTING_PREEMPTED");
+    assert.strictEqual(race.gapMs, 500);
+  });
+
+  it("should cancel both entries in cancel mode", () => {
+    const arbiter = new OrderRaceArbiter({ mode: "cancel" });
+    const races: string[] = [];
+    arbiter.onRace((r) => races.push(r.resolution));
+    const first = arbiter.claim(whale, t0);
+    const second = arbiter.claim(scanOpposite, t0 + 100);
+
+    assert.ok(first.allowed);
+    assert.ok(!second.allowed);
+    assert.match(second.reason, /^ORDER_RACE_CANCELLED/);
+    assert.ok(!arbiter.isHeld(first.claimId));
+    assert.deepStrictEqual(races, ["BOTH_CANCELLED"]);
+  });
+
+  it("should ignore same-strategy repeats, other markets and old claims", () => {
+    const arbiter = new OrderRaceArbiter({ windowMs: 10_000 });
+    arbiter.claim(whale, t0);
+
+    assert.ok(arbiter.claim({ ...whale, tokenId: "other" }, t0).allowed);
+    assert.ok(
+      arbiter.claim({ ...scanSame, tokenId: "x", marketId: "m2" }, t0).allowed,
+    );
+    assert.ok(arbiter.claim(scanSame, t0 + 11_000).allowed);
+    assert.strictEqual(arbiter.getRecentRaces().length, 0);
+  });
+
+  it("should let everything through when off", () => {
+    const arbiter = new OrderRaceArbiter({ mode: "off" });
+    arbiter.claim(whale, t0);
+    assert.ok(arbiter.claim(scanSame, t0).allowed);
+  });
+});
+
+describe("order race config parsing", () => {
+  it("should parse modes and priority lists with defaults", () => {
+    assert.strictEqual(parseOrderRaceMode(" Cancel "), "cancel");
+    assert.strictEqual(parseOrderRaceMode("bogus"), "priority");
+    assert.deepStrictEqual(parseOrderRacePriority("scan, whale"), [
+      "scan",
+      "whale",
+    ]);
+    assert.deepStrictEqual(parseOrderRacePriority("arb"), ["whale", "scan"]);
+  });
+});
@@ -0,0 +1,97 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  OverroundMonitor,
+  getOverroundFlag,
+} from "../../../src/core/overround-monitor";
+import type { EventView } from "../../../src/lib/event-view";
+
+function view(quotes: [number, number][], negRisk = true): EventView {
+  const markets = quotes.map(([bestBid, bestAsk], i) => ({
+    conditionId: `0x${i}`,
+    question: `Outcome ${i}`,
+    yesTokenId: `yes-${i}`,
+    noTokenId: `no-${i}`,
+    bestBid,
+    bestAsk,
+    mid: (bestBid + bestAsk) / 2,
+    source: "BOOK" as const,
+  }));
+  const sum = (values: number[]) => values.reduce((a, b) => a + b, 0);
+  return {
+    eventId: "903",
+    title: "Who wins?",
+    negRisk,
+    markets,
+    sumAsks: sum(markets.map((m) => m.bestAsk)),
+    sumBids: sum(markets.map((m) => m.bestBid)),
+    sumMids: sum(markets.map((m) => m.mid)),
+  };
+}
+
+const config = { alertPct: 3, arbEdgePct: 0.5 };
+
+describe("getOverroundFlag", () => {
+  it("passes events that sum to about 1", () => {
+    const fair = view([
+      [0.49, 0.51],
+      [0.49, 0.51],
+    ]);
+    assert.strictEqual(getOverroundFlag(fair, config), null);
+  });
+
+  it("flags tradeable arbs before mid drift", () => {
+    const cheap = view([
+      [0.4, 0.45],
+      [0.48, 0.5],
+    ]);
+    assert.strictEqual(getOverroundFlag(cheap, config), "ARB_BUY_ALL");
+    const rich = view([
+      [0.55, 0.57],
+      [0.5, 0.52],
+    ]);
+    assert.strictEqual(getOverroundFlag(rich, config), "ARB_SELL_ALL");
+  });
+
+  it("flags mid drift past the threshold", () => {
+    const wide = view([
+      [0.45, 0.6],
+      [0.45, 0.6],
+    ]);
+    assert.strictEqual(getOverroundFlag(wide, config), "OVERROUND");
+  });
+
+  it("ignores events that aren't neg-risk", () => {
+    const independent = view(
+      [
+        [0.7, 0.72],
+        [0.7, 0.72],
+      ],
+      false,
+    );
+    assert.strictEqual(getOverroundFlag(independent, config), null);
+  });
+});
+
+describe("OverroundMonitor", () => {
+  it("alerts once per flag and re-arms after normalizing", () => {
+    const monitor = new OverroundMonitor(config);
+    const cheap = view([
+      [0.4, 0.45],
+      [0.48, 0.5],
+    ]);
+    const fair = view([
+      [0.49, 0.51],
+      [0.49, 0.51],
+    ]);
+
+    const alert = monitor.evaluate(cheap, 0);
+    assert.strictEqual(alert?.event, "OVERROUND_ALERT");
+    assert.strictEqual(alert?.flag, "ARB_BUY_ALL");
+    assert.strictEqual(monitor.evaluate(cheap, 1_000), null);
+
+    assert.strictEqual(monitor.evaluate(fair, 2_000), null);
+    assert.strictEqual(monitor.getReports()[0].flag, null);
+    assert.strictEqual(monitor.evaluate(cheap, 3_000)?.flag, "ARB_BUY_ALL");
+  });
+});
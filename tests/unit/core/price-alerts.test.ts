@@ -0,0 +1,67 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  PriceAlertManager,
+  parsePriceAlerts,
+} from "../../../src/core/price-alerts";
+
+describe("parsePriceAlerts", () => {
+  it("parses levels and the repeat flag per token", () => {
+    assert.deepStrictEqual(
+      parsePriceAlerts("tok1:above=0.6,below=0.3,repeat; tok2:below=0.2"),
+      [
+        { tokenId: "tok1", repeat: true, above: 0.6, below: 0.3 },
+        { tokenId: "tok2", repeat: false, below: 0.2 },
+      ],
+    );
+  });
+
+  it("skips entries without a valid level", () => {
+    assert.deepStrictEqual(
+      parsePriceAlerts("tok:above=1.5;tok;:below=0.2"),
+      [],
+    );
+  });
+});
+
+describe("PriceAlertManager", () => {
+  it("fires a one-shot alert once", () => {
+    const manager = new PriceAlertManager();
+    manager.add({ tokenId: "tok", above: 0.6, repeat: false });
+
+    assert.deepStrictEqual(manager.check("tok", 0.5, 0), []);
+    const [fired] = manager.check("tok", 0.61, 1_000);
+    assert.strictEqual(fired.event, "PRICE_ALERT");
+    assert.strictEqual(fired.direction, "ABOVE");
+    assert.strictEqual(fired.level, 0.6);
+
+    manager.check("tok", 0.5, 2_000);
+    assert.deepStrictEqual(manager.check("tok", 0.7, 3_000), []);
+    assert.deepStrictEqual(manager.getWatchedTokens(), []);
+  });
+
+  it("re-arms a repeating alert inside the band", () => {
+    const manager = new PriceAlertManager();
+    manager.add({ tokenId: "tok", above: 0.6, below: 0.3, repeat: true });
+
+    assert.strictEqual(manager.check("tok", 0.25, 0).length, 1);
+    // Still below - no repeat until the price comes back
+    assert.strictEqual(manager.check("tok", 0.2, 1_000).length, 0);
+    manager.check("tok", 0.45, 2_000);
+    assert.strictEqual(manager.check("tok", 0.65, 3_000)[0].direction, "ABOVE");
+  });
+
+  it("keeps fired state across an export/import", () => {
+    const manager = new PriceAlertManager();
+    const spec = { tokenId: "tok", below: 0.3, repeat: false };
+    manager.add(spec);
+    manager.check("tok", 0.2, 0);
+
+    const restarted = new PriceAlertManager();
+    restarted.add(spec);
+    assert.strictEqual(restarted.importState(manager.exportState()), 1);
+    assert.deepStrictEqual(restarted.check("tok", 0.1, 1_000), []);
+    // Same spec added again is the same alert
+    assert.strictEqual(restarted.list().length, 1);
+  });
+});
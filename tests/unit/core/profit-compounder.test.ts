@@ -0,0 +1,84 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  ProfitCompounder,
+  parseCompoundSchedule,
+} from "../../../src/core/profit-compounder";
+
+const SCHEDULE = "-50:15,100:35,250:50";
+
+function compounder(spec = SCHEDULE): ProfitCompounder {
+  return new ProfitCompounder({
+    baseCapUsd: 25,
+    steps: parseCompoundSchedule(spec),
+  });
+}
+
+describe("parseCompoundSchedule", () => {
+  it("sorts steps and drops invalid ones", () => {
+    assert.deepStrictEqual(parseCompoundSchedule("250:50, -50:15,x:5,10:0"), [
+      { pnlUsd: -50, capUsd: 15 },
+      { pnlUsd: 250, capUsd: 50 },
+    ]);
+    assert.deepStrictEqual(parseCompoundSchedule(""), []);
+  });
+});
+
+describe("ProfitCompounder", () => {
+  it("keeps MAX_TRADE_USD when no schedule is set", () => {
+    const c = compounder("");
+    assert.strictEqual(c.isEnabled(), false);
+    assert.strictEqual(c.recordRealized(500), false);
+    assert.strictEqual(c.getCapUsd(), 25);
+  });
+
+  it("raises the cap step by step with profit, up to the last step", () => {
+    const c = compounder();
+    assert.strictEqual(c.getCapUsd(), 25);
+    c.recordRealized(99);
+    assert.strictEqual(c.getCapUsd(), 25);
+    c.recordRealized(1);
+    assert.strictEqual(c.getCapUsd(), 35);
+    assert.deepStrictEqual(c.getStatus().nextStep, {
+      pnlUsd: 250,
+      capUsd: 50,
+    });
+    c.recordRealized(1000);
+    assert.strictEqual(c.getCapUsd(), 50);
+    assert.strictEqual(c.getStatus().nextStep, null);
+  });
+
+  it("lowers the cap with losses, to the lowest step below it", () => {
+    const c = compounder();
+    c.recordRealized(-20);
+    assert.strictEqual(c.getCapUsd(), 25);
+    c.recordRealized(-30);
+    assert.strictEqual(c.getCapUsd(), 15);
+    c.recordRealized(-500);
+    assert.strictEqual(c.getCapUsd(), 15);
+  });
+
+  it("counts a keyed resolution once", () => {
+    const c = compounder();
+    assert.strictEqual(c.recordRealized(120, "cond-1:Yes"), true);
+    assert.strictEqual(c.recordRealized(120, "cond-1:Yes"), false);
+    assert.strictEqual(c.getStatus().realizedPnlUsd, 120);
+  });
+
+  it("restores realized P&L from its state file", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "compounding-"));
+    const file = path.join(dir, "compounding.json");
+
+    const first = compounder();
+    first.attachStateFile(file);
+    first.recordRealized(150, "cond-1:Yes");
+
+    const second = compounder();
+    second.attachStateFile(file);
+    assert.strictEqual(second.getCapUsd(), 35);
+    assert.strictEqual(second.recordRealized(150, "cond-1:Yes"), false);
+  });
+});
@@ -0,0 +1,106 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { FillJournal, type JournalFill } from "../../../src/core/fill-journal";
+import {
+  annotateMarkouts,
+  buildQuoteReport,
+  formatQuoteReport,
+} from "../../../src/core/quote-report";
+
+function fill(overrides: Partial<JournalFill>): JournalFill {
+  return {
+    tradeId: "t1",
+    tokenId: "tok-1",
+    side: "BUY",
+    price: 0.49,
+    shares: 100,
+    timestamp: 1_000,
+    source: "WS",
+    liquidity: "MAKER",
+    ...overrides,
+  };
+}
+
+const near = (a: number | null, b: number) =>
+  assert.ok(a !== null && Math.abs(a - b) < 1e-9, `${a} != ${b}`);
+
+describe("buildQuoteReport", () => {
+  it("nets spread capture against adverse selection", () => {
+    const [report] = buildQuoteReport([
+      // Bought 1¢ under the mid, mid then fell 2¢
+      fill({ tradeId: "t1", midAtFill: 0.5, midAfter: 0.48 }),
+      // Sold 1¢ over the mid, mid held
+      fill({
+        tradeId: "t2",
+        side: "SELL",
+        price: 0.51,
+        timestamp: 2_000,
+        midAtFill: 0.5,
+        midAfter: 0.5,
+      }),
+    ]);
+
+    assert.strictEqual(report.makerFills, 2);
+    near(report.captureUsd, 2);
+    near(report.adverseUsd, 2);
+    near(report.netUsd, 0);
+    near(report.captureCentsPerShare, 1);
+    near(report.adverseCentsPerShare, 1);
+    // Inventory 100 then 0 → average 50, 200 shares traded
+    near(report.turnover, 4);
+    assert.strictEqual(report.inventoryShares, 0);
+  });
+
+  it("counts fills without a mid toward volume only", () => {
+    const [report] = buildQuoteReport([
+      fill({ tradeId: "t1", source: "BACKFILL" }),
+      fill({ tradeId: "t2", liquidity: "TAKER", midAtFill: 0.5 }),
+    ]);
+    assert.strictEqual(report.makerFills, 1);
+    assert.strictEqual(report.pricedFills, 0);
+    assert.strictEqual(report.captureCentsPerShare, null);
+    near(report.volumeUsd, 49);
+  });
+
+  it("leaves out tokens that were never quoted", () => {
+    assert.deepStrictEqual(
+      buildQuoteReport([fill({ liquidity: "TAKER" })]),
+      [],
+    );
+  });
+
+  it("formats one row per market", () => {
+    const rows = formatQuoteReport(
+      buildQuoteReport([fill({ midAtFill: 0.5, midAfter: 0.5 })]),
+    );
+    assert.match(rows[0], /1 fills \| \$49\.00 \| capture \+1\.00¢/);
+    assert.match(rows[0], /net \+\$1\.00/);
+  });
+});
+
+describe("annotateMarkouts", () => {
+  it("records the markout mid once the markout has passed", () => {
+    const journal = new FillJournal();
+    journal.merge([
+      fill({ tradeId: "due", timestamp: 1_000, midAtFill: 0.5 }),
+      fill({ tradeId: "early", timestamp: 50_000, midAtFill: 0.5 }),
+      fill({ tradeId: "no-mid", timestamp: 1_000 }),
+    ]);
+
+    const asked: number[] = [];
+    const updated = annotateMarkouts(
+      journal,
+      (_tokenId, at) => {
+        asked.push(at);
+        return 0.47;
+      },
+      60_000,
+      70_000,
+    );
+
+    assert.strictEqual(updated, 1);
+    assert.deepStrictEqual(asked, [61_000]);
+    const due = journal.getFills().find((f) => f.tradeId === "due");
+    assert.strictEqual(due?.midAfter, 0.47);
+  });
+});
@@ -0,0 +1,85 @@
+/**
+ * Risk Sizing Tests
+ *
+ * Tests for calculateKellySize:
+ * - Full Kelly fraction for binary tokens
+ * - Fractional Kelly and caps
+ * - No edge / invalid inputs size to zero
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+
+import { calculateKellySize } from "../../../src/core/risk";
+
+describe("calculateKellySize", () => {
+  it("should compute full Kelly as edge / (1 - price)", () => {
+    const result = calculateKellySize({
+      edge: 0.05,
+      price: 0.5,
+      bankrollUsd: 1000,
+      kellyFraction: 1,
+      bankrollFractionCap: 1,
+    });
+
+    assert.ok(Math.abs(result.fullKellyFraction - 0.1) < 1e-9);
+    assert.ok(Math.abs(result.sizeUsd - 100) < 1e-9);
+  });
+
+  it("should default to quarter Kelly", () => {
+    const result = calculateKellySize({
+      edge: 0.05,
+      price: 0.5,
+      bankrollUsd: 1000,
+      bankrollFractionCap: 1,
+    });
+
+    assert.ok(Math.abs(result.appliedFraction - 0.025) < 1e-9);
+    assert.ok(Math.abs(result.sizeUsd - 25) < 1e-9);
+  });
+
+  it("should respect the bankroll fraction cap", () => {
+    const result = calculateKellySize({
+      edge: 0.05,
+      price: 0.42,
+      bankrollUsd: 1000,
+      bankrollFractionCap: 0.02,
+    });
+
+    assert.strictEqual(result.appliedFraction, 0.02);
+    assert.strictEqual(result.sizeUsd, 20);
+  });
+
+  it("should respect maxTradeUsd", () => {
+    const result = calculateKellySize({
+      edge: 0.2,
+      price: 0.3,
+      bankrollUsd: 10000,
+      bankrollFractionCap: 0.5,
+      maxTradeUsd: 25,
+    });
+
+    assert.strictEqual(result.sizeUsd, 25);
+  });
+
+  it("should size to zero without an edge or with invalid prices", () => {
+    const base = { bankrollUsd: 1000, bankrollFractionCap: 0.02 };
+
+    assert.strictEqual(
+      calculateKellySize({ ...base, edge: 0, price: 0.5 }).sizeUsd,
+      0,
+    );
+    assert.strictEqual(
+      calculateKellySize({ ...base, edge: -0.05, price: 0.5 }).sizeUsd,
+      0,
+    );
+    assert.strictEqual(
+      calculateKellySize({ ...base, edge: 0.05, price: 1 }).sizeUsd,
+      0,
+    );
+    assert.strictEqual(
+      calculateKellySize({ ...base, edge: 0.05, price: NaN }).sizeUsd,
+      0,
+    );
+  });
+});
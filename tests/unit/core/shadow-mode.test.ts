@@ -0,0 +1,58 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  ShadowTracker,
+  parseShadowStrategies,
+} from "../../../src/core/shadow-mode";
+
+describe("parseShadowStrategies", () => {
+  it("parses known strategies and skips unknown or duplicate ones", () => {
+    assert.deepStrictEqual(parseShadowStrategies(" Scan, foo, scan"), [
+      "scan",
+    ]);
+    assert.deepStrictEqual(parseShadowStrategies("whale,scan"), [
+      "whale",
+      "scan",
+    ]);
+    assert.deepStrictEqual(parseShadowStrategies(""), []);
+  });
+});
+
+describe("ShadowTracker", () => {
+  it("only shadows configured strategies", () => {
+    const tracker = new ShadowTracker(["scan"]);
+    assert.strictEqual(tracker.isEnabled(), true);
+    assert.strictEqual(tracker.isShadow("scan"), true);
+    assert.strictEqual(tracker.isShadow("whale"), false);
+    assert.strictEqual(new ShadowTracker().isEnabled(), false);
+  });
+
+  it("reports shadow and live results separately", () => {
+    const tracker = new ShadowTracker(["scan"]);
+    tracker.recordResult("scan", "shadow", 2, 10);
+    tracker.recordResult("scan", "shadow", -1, 10);
+    tracker.recordResult("whale", "live", 1, 20);
+
+    const report = tracker.getReport();
+    assert.strictEqual(report.length, 2);
+
+    const whale = report.find((r) => r.strategy === "whale")!;
+    assert.strictEqual(whale.mode, "live");
+    assert.strictEqual(whale.returnPct, 0.05);
+
+    const scan = report.find((r) => r.strategy === "scan")!;
+    assert.strictEqual(scan.mode, "shadow");
+    assert.strictEqual(scan.trades, 2);
+    assert.strictEqual(scan.winRate, 0.5);
+    assert.strictEqual(scan.pnlUsd, 1);
+  });
+
+  it("lists configured shadow strategies before they trade", () => {
+    const tracker = new ShadowTracker(["whale"]);
+    const [row] = tracker.getReport();
+    assert.strictEqual(row.strategy, "whale");
+    assert.strictEqual(row.mode, "shadow");
+    assert.strictEqual(row.trades, 0);
+    assert.match(tracker.formatReport()[0], /^whale shadow: 0 trades/);
+  });
+});
@@ -0,0 +1,61 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  SignalEdgeTracker,
+  entryCostCents,
+  realizedEdgeCents,
+} from "../../../src/core/signal-edge";
+
+describe("realizedEdgeCents / entryCostCents", () => {
+  it("measures against the entry mid in the position's favor", () => {
+    const long = {
+      side: "LONG" as const,
+      entryMidCents: 50,
+      entryPriceCents: 51,
+      exitPriceCents: 54,
+      pnlUsd: 1,
+    };
+    assert.strictEqual(realizedEdgeCents(long), 4);
+    assert.strictEqual(entryCostCents(long), 1);
+
+    const short = { ...long, side: "SHORT" as const, entryPriceCents: 49 };
+    assert.strictEqual(realizedEdgeCents(short), -4);
+    assert.strictEqual(entryCostCents(short), 1);
+  });
+});
+
+describe("SignalEdgeTracker", () => {
+  it("averages edge per signal, best first", () => {
+    const tracker = new SignalEdgeTracker();
+    const base = {
+      side: "LONG" as const,
+      entryMidCents: 50,
+      entryPriceCents: 51,
+    };
+    tracker.recordResult("whale", { ...base, exitPriceCents: 54, pnlUsd: 2 });
+    tracker.recordResult("whale", { ...base, exitPriceCents: 50, pnlUsd: -1 });
+    tracker.recordResult("scan", { ...base, exitPriceCents: 49, pnlUsd: -2 });
+
+    const report = tracker.getReport();
+    assert.deepStrictEqual(report.map((r) => r.signal), ["whale", "scan"]);
+    assert.strictEqual(report[0].trades, 2);
+    assert.strictEqual(report[0].winRate, 0.5);
+    assert.strictEqual(report[0].avgEdgeCents, 2);
+    assert.strictEqual(report[0].avgEntryCostCents, 1);
+    assert.strictEqual(report[0].pnlUsd, 1);
+    assert.strictEqual(report[1].avgEdgeCents, -1);
+    assert.strictEqual(tracker.formatReport().length, 2);
+  });
+
+  it("skips trades without an entry mid", () => {
+    const tracker = new SignalEdgeTracker();
+    tracker.recordResult("whale", {
+      side: "LONG",
+      entryMidCents: 0,
+      entryPriceCents: 50,
+      exitPriceCents: 55,
+      pnlUsd: 1,
+    });
+    assert.deepStrictEqual(tracker.getReport(), []);
+  });
+});
@@ -0,0 +1,105 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  SignalQueue,
+  isPositionLimitReason,
+} from "../../../src/core/signal-queue";
+
+const TTL = 60_000;
+
+describe("isPositionLimitReason", () => {
+  it("matches only the max-positions rejection", () => {
+    assert.strictEqual(isPositionLimitReason("Max positions (12)"), true);
+    assert.strictEqual(isPositionLimitReason("Max deployed (30%)"), false);
+    assert.strictEqual(isPositionLimitReason(undefined), false);
+  });
+});
+
+describe("SignalQueue", () => {
+  it("hands out the oldest signals, one per free slot", () => {
+    const queue = new SignalQueue({ maxSize: 5, ttlMs: TTL });
+    queue.enqueue({ tokenId: "a", strategy: "whale" }, 0);
+    queue.enqueue({ tokenId: "b", strategy: "scan" }, 1000);
+    queue.enqueue({ tokenId: "c", strategy: "whale" }, 2000);
+
+    assert.deepStrictEqual(queue.take(0, 3000), []);
+    const taken = queue.take(2, 3000);
+    assert.deepStrictEqual(
+      taken.map((s) => s.tokenId),
+      ["a", "b"],
+    );
+    assert.deepStrictEqual(
+      queue.list(3000).map((s) => s.tokenId),
+      ["c"],
+    );
+
+    // A signal beaten to the slot goes back to the front
+    queue.restore([taken[1]], 4000);
+    assert.strictEqual(queue.take(1, 4000)[0].tokenId, "b");
+  });
+
+  it("restores several taken signals oldest first", () => {
+    const queue = new SignalQueue({ maxSize: 5, ttlMs: TTL });
+    queue.enqueue({ tokenId: "a", strategy: "whale" }, 0);
+    queue.enqueue({ tokenId: "b", strategy: "scan" }, 1000);
+    queue.enqueue({ tokenId: "c", strategy: "whale" }, 2000);
+    queue.enqueue({ tokenId: "d", strategy: "scan" }, 3000);
+
+    const taken = queue.take(3, 4000);
+    queue.restore(taken, 5000);
+    assert.deepStrictEqual(
+      queue.list(5000).map((s) => s.tokenId),
+      ["a", "b", "c", "d"],
+    );
+  });
+
+  it("keeps a re-signalled token's place with a fresh expiry", () => {
+    const queue = new SignalQueue({ maxSize: 5, ttlMs: TTL });
+    queue.enqueue({ tokenId: "a", strategy: "whale" }, 0);
+    queue.enqueue({ tokenId: "b", strategy: "whale" }, 1000);
+    queue.enqueue({ tokenId: "a", strategy: "whale" }, 50_000);
+
+    const [a, b] = queue.list(50_000);
+    assert.strictEqual(a.tokenId, "a");
+    assert.strictEqual(a.queuedAt, 0);
+    assert.strictEqual(a.expiresAt, 50_000 + TTL);
+    assert.strictEqual(b.tokenId, "b");
+    // b expires, a is still waiting
+    assert.deepStrictEqual(
+      queue.take(5, 70_000).map((s) => s.tokenId),
+      ["a"],
+    );
+  });
+
+  it("refuses signals when full or off", () => {
+    const queue = new SignalQueue({ maxSize: 1, ttlMs: TTL });
+    assert.strictEqual(queue.enqueue({ tokenId: "a", strategy: "scan" }), true);
+    assert.strictEqual(
+      queue.enqueue({ tokenId: "b", strategy: "scan" }),
+      false,
+    );
+
+    const off = new SignalQueue({ maxSize: 0, ttlMs: TTL });
+    assert.strictEqual(off.enqueue({ tokenId: "a", strategy: "scan" }), false);
+  });
+
+  it("survives a restart, minus expired signals", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "signal-queue-"));
+    const filePath = path.join(dir, "queue.json");
+    const queue = new SignalQueue({ maxSize: 5, ttlMs: TTL });
+    queue.attachStateFile(filePath, 0);
+    queue.enqueue({ tokenId: "a", strategy: "whale", marketId: "m" }, 0);
+    queue.enqueue({ tokenId: "b", strategy: "scan" }, 30_000);
+
+    const restarted = new SignalQueue({ maxSize: 5, ttlMs: TTL });
+    restarted.attachStateFile(filePath, 70_000);
+    assert.deepStrictEqual(
+      restarted.list(70_000).map((s) => s.tokenId),
+      ["b"],
+    );
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+});
@@ -0,0 +1,102 @@
+/**
+ * Strategy Schedule Tests
+ *
+ * Tests for:
+ * - Parsing UTC windows and blackout periods
+ * - Active window checks (including windows that wrap midnight)
+ * - Global and strategy-scoped blackouts
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+
+import {
+  getScheduleBlockReason,
+  parseBlackouts,
+  parseTimeWindows,
+  type StrategyScheduleConfig,
+} from "../../../src/core/strategy-schedule";
+
+// 2026-10-14 is a Wednesday
+const wed = (hhmm: string) => Date.parse(`2026-10-14T${hhmm}:00Z`);
+
+describe("parseTimeWindows", () => {
+  it("should parse day ranges, single days and every-day windows", () => {
+    const windows = parseTimeWindows("mon-fri 13:30-20:00, sat 10:00-14:00");
+    assert.deepStrictEqual(windows[0].days, [1, 2, 3, 4, 5]);
+    assert.strictEqual(windows[0].startMinute, 13 * 60 + 30);
+    assert.strictEqual(windows[0].endMinute, 20 * 60);
+    assert.deepStrictEqual(windows[1].days, [6]);
+
+    const daily = parseTimeWindows("22:00-02:00");
+    assert.deepStrictEqual(daily[0].days, [0, 1, 2, 3, 4, 5, 6]);
+  });
+
+  it("should skip invalid windows", () => {
+    assert.deepStrictEqual(parseTimeWindows("funday 10:00-12:00"), []);
+    assert.deepStrictEqual(parseTimeWindows("10:00-10:00, 25:99-26:00"), []);
+  });
+});
+
+describe("parseBlackouts", () => {
+  it("should parse global and scoped intervals", () => {
+    const blackouts = parseBlackouts(
+      "2026-10-14T17:45Z/2026-10-14T19:00Z, scan@2026-11-04T00:00Z/2026-11-05T00:00Z",
+    );
+    assert.strictEqual(blackouts.length, 2);
+    assert.strictEqual(blackouts[0].strategies, undefined);
+    assert.strictEqual(blackouts[0].startMs, wed("17:45"));
+    assert.deepStrictEqual(blackouts[1].strategies, ["scan"]);
+  });
+});
+
+describe("getScheduleBlockReason", () => {
+  const config: StrategyScheduleConfig = {
+    windows: {
+      whale: parseTimeWindows("mon-fri 13:30-20:00"),
+      scan: parseTimeWindows("wed 22:00-02:00"),
+    },
+    blackouts: parseBlackouts("whale@2026-10-14T17:45Z/2026-10-14T19:00Z"),
+  };
+
+  it("should allow strategies inside their window", () => {
+    assert.strictEqual(
+      getScheduleBlockReason("whale", config, wed("14:00")),
+      null,
+    );
+  });
+
+  it("should block strategies outside their window", () => {
+    assert.strictEqual(
+      getScheduleBlockReason("whale", config, wed("21:00")),
+      "outside active window",
+    );
+  });
+
+  it("should treat windows that wrap midnight as the starting day's", () => {
+    // Wednesday 22:00 -> Thursday 02:00
+    assert.strictEqual(
+      getScheduleBlockReason("scan", config, wed("23:00")),
+      null,
+    );
+    const thu = Date.parse("2026-10-15T01:00:00Z");
+    assert.strictEqual(getScheduleBlockReason("scan", config, thu), null);
+    const thuLate = Date.parse("2026-10-15T23:00:00Z");
+    assert.ok(getScheduleBlockReason("scan", config, thuLate));
+  });
+
+  it("should enforce blackouts only for the scoped strategy", () => {
+    assert.match(
+      getScheduleBlockReason("whale", config, wed("18:00")) ?? "",
+      /^blackout until 2026-10-14T19:00/,
+    );
+  });
+
+  it("should leave strategies without windows always active", () => {
+    const empty: StrategyScheduleConfig = { windows: {}, blackouts: [] };
+    assert.strictEqual(
+      getScheduleBlockReason("scan", empty, wed("03:00")),
+      null,
+    );
+  });
+});
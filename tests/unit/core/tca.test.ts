@@ -0,0 +1,91 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  TcaJournal,
+  buildTcaRecord,
+  type TcaInput,
+} from "../../../src/core/tca";
+
+const close = (a: number, b: number) => Math.abs(a - b) < 1e-9;
+
+function input(overrides: Partial<TcaInput> = {}): TcaInput {
+  return {
+    id: "a1",
+    kind: "ENTRY",
+    strategy: "whale",
+    tokenId: "tok",
+    side: "BUY",
+    arrivalMid: 0.5,
+    avgFillPrice: 0.51,
+    filledShares: 100,
+    startedAt: 1_000,
+    completedAt: 1_800,
+    ...overrides,
+  };
+}
+
+describe("buildTcaRecord", () => {
+  it("measures slippage against the arrival mid", () => {
+    const buy = buildTcaRecord(input());
+    assert.ok(close(buy.slippageCents, 1));
+    assert.ok(close(buy.slippageBps, 200));
+    assert.ok(close(buy.notionalUsd, 51));
+    assert.strictEqual(buy.durationMs, 800);
+
+    const sell = buildTcaRecord(input({ side: "SELL", avgFillPrice: 0.49 }));
+    assert.ok(close(sell.slippageCents, 1));
+  });
+
+  it("estimates taker fees on the fill price", () => {
+    // 100 bps on min(0.51, 0.49) per share
+    const record = buildTcaRecord(input({ feeBps: 100 }));
+    assert.ok(close(record.feesUsd, 0.49));
+  });
+});
+
+describe("TcaJournal", () => {
+  it("queries most recent first and summarizes per strategy", () => {
+    const journal = new TcaJournal();
+    journal.record(input({ id: "a1", completedAt: 2_000 }));
+    journal.record(
+      input({
+        id: "a2",
+        avgFillPrice: 0.5,
+        filledShares: 300,
+        completedAt: 3_000,
+      }),
+    );
+    journal.record(input({ id: "a3", strategy: "scan", completedAt: 4_000 }));
+
+    assert.deepStrictEqual(
+      journal.query({ strategy: "whale" }).map((r) => r.id),
+      ["a2", "a1"],
+    );
+    assert.deepStrictEqual(
+      journal.query({ since: 3_000, limit: 1 }).map((r) => r.id),
+      ["a3"],
+    );
+
+    const [whale, scan] = journal.summarize();
+    assert.strictEqual(whale.strategy, "whale");
+    assert.strictEqual(whale.count, 2);
+    // $51 at 200 bps and $150 at 0 bps
+    assert.ok(close(whale.avgSlippageBps, (200 * 51) / 201));
+    assert.strictEqual(scan.count, 1);
+  });
+
+  it("keeps restored records ahead of new ones, within the cap", () => {
+    const journal = new TcaJournal(2);
+    journal.record(input({ id: "new" }));
+    const restored = journal.importState([
+      buildTcaRecord(input({ id: "old1" })),
+      buildTcaRecord(input({ id: "old2" })),
+    ]);
+
+    assert.strictEqual(restored, 2);
+    assert.deepStrictEqual(
+      journal.exportState().map((r) => r.id),
+      ["old2", "new"],
+    );
+  });
+});
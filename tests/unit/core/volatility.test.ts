@@ -0,0 +1,96 @@
+/**
+ * Volatility Estimator Tests
+ *
+ * Tests for:
+ * - Realized volatility over rolling windows
+ * - Indicators across all configured windows
+ * - Feeding from MarketDataStore trade prints
+ * - Volatility-scaled sizing
+ */
+
+import assert from "node:assert";
+import { describe, it } from "node:test";
+
+import { VolatilityEstimator } from "../../../src/core/volatility";
+import { scaleSizeForVolatility } from "../../../src/core/risk";
+import { MarketDataStore } from "../../../src/lib/market-data-store";
+
+describe("VolatilityEstimator", () => {
+  const now = 1_000_000;
+
+  it("should return null with too few samples", () => {
+    const est = new VolatilityEstimator([60_000]);
+    est.record("t", 0.5, now - 1000);
+    est.record("t", 0.51, now);
+
+    assert.strictEqual(est.getVolatility("t", 60_000, now), null);
+  });
+
+  it("should return zero for a steady trend", () => {
+    const est = new VolatilityEstimator([60_000]);
+    for (let i = 0; i < 5; i++) {
+      est.record("t", 0.5 + i * 0.01, now - (5 - i) * 1000);
+    }
+
+    const vol = est.getVolatility("t", 60_000, now);
+    assert.ok(vol !== null && Math.abs(vol) < 1e-9);
+  });
+
+  it("should measure choppy prices in cents", () => {
+    const est = new VolatilityEstimator([60_000]);
+    const prices = [0.5, 0.52, 0.5, 0.52, 0.5];
+    prices.forEach((p, i) => est.record("t", p, now - (5 - i) * 1000));
+
+    // Changes are +2, -2, +2, -2 cents → std dev 2
+    const vol = est.getVolatility("t", 60_000, now);
+    assert.ok(vol !== null && Math.abs(vol - 2) < 1e-9);
+  });
+
+  it("should only use samples inside the window", () => {
+    const est = new VolatilityEstimator([10_000, 60_000]);
+    [0.5, 0.6, 0.5].forEach((p, i) => est.record("t", p, now - 50_000 + i));
+    [0.5, 0.5, 0.5].forEach((p, i) => est.record("t", p, now - 5000 + i));
+
+    const indicators = est.getIndicators("t", now);
+    assert.strictEqual(indicators.windows[0].windowMs, 10_000);
+    assert.strictEqual(indicators.windows[0].volatilityCents, 0);
+    assert.ok((indicators.windows[1].volatilityCents ?? 0) > 0);
+  });
+
+  it("should feed from market data store trade prints", () => {
+    const est = new VolatilityEstimator([60_000]);
+    const store = new MarketDataStore();
+    const detach = est.attach(store);
+
+    [0.5, 0.52, 0.5].forEach((price, i) =>
+      store.recordTrade({
+        tokenId: "t",
+        price,
+        size: 10,
+        side: "BUY",
+        timestamp: now - 3000 + i,
+      }),
+    );
+    detach();
+
+    assert.strictEqual(est.getIndicators("t", now).windows[0].samples, 3);
+  });
+});
+
+describe("scaleSizeForVolatility", () => {
+  it("should leave size unchanged when volatility is unknown", () => {
+    assert.strictEqual(scaleSizeForVolatility(10, null, 1), 10);
+  });
+
+  it("should shrink size when volatility exceeds the target", () => {
+    assert.strictEqual(scaleSizeForVolatility(10, 2, 1), 5);
+  });
+
+  it("should never size above the base size by default", () => {
+    assert.strictEqual(scaleSizeForVolatility(10, 0.5, 1), 10);
+  });
+
+  it("should floor the scale at minScale", () => {
+    assert.strictEqual(scaleSizeForVolatility(10, 100, 1), 2.5);
+  });
+});
@@ -0,0 +1,80 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { WhaleScorer } from "../../../src/core/whale-scorer";
+
+const A = "0xAAAA000000000000000000000000000000000001";
+const B = "0xbbbb000000000000000000000000000000000002";
+
+describe("WhaleScorer", () => {
+  it("tracks hit rate and average P&L per wallet", () => {
+    const scorer = new WhaleScorer();
+    scorer.recordResult([A, B], 2, 10);
+    scorer.recordResult([A], -1, 10);
+
+    const a = scorer.getScore(A.toLowerCase())!;
+    assert.strictEqual(a.trades, 2);
+    assert.strictEqual(a.wins, 1);
+    assert.strictEqual(a.hitRate, 0.5);
+    assert.strictEqual(a.avgPnlUsd, 0.5);
+    assert.ok(Math.abs(a.avgReturn - 0.05) < 1e-9);
+
+    const b = scorer.getScore(B)!;
+    assert.strictEqual(b.trades, 1);
+    assert.strictEqual(b.hitRate, 1);
+    assert.strictEqual(scorer.getScore("0xdead"), null);
+  });
+
+  it("counts a wallet once per trade and ignores invalid results", () => {
+    const scorer = new WhaleScorer();
+    scorer.recordResult([A, A.toLowerCase()], 1, 10);
+    scorer.recordResult([A], NaN, 10);
+    scorer.recordResult([A], 1, 0);
+    assert.strictEqual(scorer.getScore(A)!.trades, 1);
+  });
+
+  it("ranks wallets with enough trades first, then by average return", () => {
+    const scorer = new WhaleScorer({ minTrades: 2 });
+    scorer.recordResult([A], 5, 10); // one great trade - not enough history
+    scorer.recordResult([B], 1, 10);
+    scorer.recordResult([B], 1, 10);
+
+    const ranking = scorer.getRanking();
+    assert.deepStrictEqual(ranking.map((r) => r.wallet), [B, A.toLowerCase()]);
+    assert.strictEqual(scorer.getRanking(1).length, 1);
+  });
+
+  it("promotes qualifying wallets only when enabled", () => {
+    const off = new WhaleScorer({ minTrades: 1 });
+    off.recordResult([A], 1, 10);
+    assert.deepStrictEqual(off.promote(), []);
+
+    const scorer = new WhaleScorer({
+      autoPromote: true,
+      minTrades: 2,
+      minHitRate: 0.6,
+    });
+    scorer.recordResult([A, B], 1, 10);
+    scorer.recordResult([A, B], 1, 10);
+    scorer.recordResult([B], -1, 10);
+    scorer.recordResult([B], -1, 10);
+
+    assert.deepStrictEqual(scorer.promote(), [A.toLowerCase()]);
+    assert.strictEqual(scorer.isPromoted(A), true);
+    assert.strictEqual(scorer.isPromoted(B), false);
+    // Already promoted wallets aren't returned again
+    assert.deepStrictEqual(scorer.promote(), []);
+  });
+
+  it("caps the number of promoted wallets", () => {
+    const scorer = new WhaleScorer({
+      autoPromote: true,
+      minTrades: 1,
+      maxPromoted: 1,
+    });
+    scorer.recordResult([A], 1, 10);
+    scorer.recordResult([B], 2, 10);
+
+    assert.deepStrictEqual(scorer.promote(), [B]);
+    assert.deepStrictEqual(scorer.getPromoted(), [B]);
+  });
+});
@@ -0,0 +1,143 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import axios from "axios";
+import {
+  CircuitBreaker,
+  DependencyBreakers,
+  getDependencyForUrl,
+  isDegradedError,
+} from "../../../src/infra/dependency-breaker";
+
+// axios instance that never touches the network; counts adapter calls
+function createStubAxios(status: number) {
+  const stub = { calls: 0, status };
+  const client = axios.create({
+    adapter: async (config) => {
+      stub.calls++;
+      const response = {
+        data: {},
+        status: stub.status,
+        statusText: String(stub.status),
+        headers: {},
+        config,
+      };
+      if (stub.status >= 400) {
+        const err: any = new Error(
+          `Request failed with status ${stub.status}`,
+        );
+        err.config = config;
+        err.response = response;
+        throw err;
+      }
+      return response;
+    },
+  });
+  return { client, stub };
+}
+
+const config = { failureThreshold: 3, cooldownMs: 1000 };
+
+describe("CircuitBreaker", () => {
+  it("opens after the failure threshold and probes after the cooldown", () => {
+    const breaker = new CircuitBreaker("gamma-api", config);
+
+    breaker.recordFailure("ETIMEDOUT", 0);
+    breaker.recordFailure("ETIMEDOUT", 0);
+    assert.strictEqual(breaker.getStatus().state, "CLOSED");
+    breaker.recordFailure("ETIMEDOUT", 0);
+    assert.strictEqual(breaker.getStatus().state, "OPEN");
+    assert.strictEqual(breaker.getStatus().retryAt, 1000);
+
+    assert.strictEqual(breaker.allowRequest(500), false);
+
+    // Cooldown elapsed: exactly one probe
+    assert.strictEqual(breaker.allowRequest(1000), true);
+    assert.strictEqual(breaker.getStatus().state, "HALF_OPEN");
+    assert.strictEqual(breaker.allowRequest(1001), false);
+  });
+
+  it("closes on a successful probe and re-opens on a failed one", () => {
+    const breaker = new CircuitBreaker("data-api", config);
+    for (let i = 0; i < 3; i++) breaker.recordFailure("503", 0);
+
+    breaker.allowRequest(1000);
+    breaker.recordFailure("503", 1000);
+    assert.strictEqual(breaker.getStatus().state, "OPEN");
+    assert.strictEqual(breaker.allowRequest(1500), false);
+
+    breaker.allowRequest(2000);
+    breaker.recordSuccess();
+    assert.strictEqual(breaker.getStatus().state, "CLOSED");
+    assert.strictEqual(breaker.getStatus().consecutiveFailures, 0);
+  });
+});
+
+describe("DependencyBreakers", () => {
+  it("maps only gamma-api and data-api hosts", () => {
+    assert.strictEqual(
+      getDependencyForUrl("https://gamma-api.polymarket.com/markets"),
+      "gamma-api",
+    );
+    assert.strictEqual(
+      getDependencyForUrl("https://data-api.polymarket.com/positions"),
+      "data-api",
+    );
+    assert.strictEqual(
+      getDependencyForUrl("https://clob.polymarket.com/order"),
+      null,
+    );
+  });
+
+  it("fails fast with DEGRADED once a dependency is down", async () => {
+    const breakers = new DependencyBreakers(config);
+    const { client, stub } = createStubAxios(503);
+    breakers.install(client);
+
+    for (let i = 0; i < 3; i++) {
+      await assert.rejects(() =>
+        client.get("https://gamma-api.polymarket.com/markets"),
+      );
+    }
+    assert.strictEqual(stub.calls, 3);
+    assert.strictEqual(breakers.isDegraded("gamma-api"), true);
+
+    await assert.rejects(
+      () => client.get("https://gamma-api.polymarket.com/markets"),
+      (err) => isDegradedError(err),
+    );
+    assert.strictEqual(stub.calls, 3, "open breaker should not hit network");
+
+    // Other dependencies are unaffected
+    assert.strictEqual(breakers.isDegraded("data-api"), false);
+    breakers.uninstall();
+  });
+
+  it("never short-circuits CLOB requests", async () => {
+    const breakers = new DependencyBreakers(config);
+    const { client, stub } = createStubAxios(503);
+    breakers.install(client);
+
+    for (let i = 0; i < 5; i++) {
+      await assert.rejects(
+        () => client.get("https://clob.polymarket.com/book"),
+        (err) => !isDegradedError(err),
+      );
+    }
+    assert.strictEqual(stub.calls, 5);
+    breakers.uninstall();
+  });
+
+  it("treats 4xx responses as the service being up", async () => {
+    const breakers = new DependencyBreakers(config);
+    const { client } = createStubAxios(404);
+    breakers.install(client);
+
+    for (let i = 0; i < 5; i++) {
+      await assert.rejects(() =>
+        client.get("https://data-api.polymarket.com/positions"),
+      );
+    }
+    assert.strictEqual(breakers.isDegraded("data-api"), false);
+    breakers.uninstall();
+  });
+});
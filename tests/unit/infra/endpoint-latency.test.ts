@@ -0,0 +1,120 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import axios from "axios";
+import {
+  EndpointLatencyTracker,
+  endpointKey,
+  isWriteEndpoint,
+  type SloBreachEvent,
+} from "../../../src/infra/endpoint-latency";
+
+const POST_ORDER = "POST clob.polymarket.com/order";
+
+function tracker(): EndpointLatencyTracker {
+  return new EndpointLatencyTracker({
+    writeP95Ms: 1000,
+    sustainMs: 60_000,
+    windowMs: 10 * 60_000,
+    minSamples: 3,
+  });
+}
+
+describe("endpointKey", () => {
+  it("collapses IDs and drops the query", () => {
+    assert.strictEqual(
+      endpointKey("get", "https://clob.polymarket.com/book?token_id=123"),
+      "GET clob.polymarket.com/book",
+    );
+    assert.strictEqual(
+      endpointKey(
+        "delete",
+        `https://clob.polymarket.com/order/0x${"ab".repeat(32)}`,
+      ),
+      "DELETE clob.polymarket.com/order/:id",
+    );
+    assert.strictEqual(
+      endpointKey("get", "https://gamma-api.polymarket.com/markets/512345"),
+      "GET gamma-api.polymarket.com/markets/:id",
+    );
+  });
+
+  it("ignores non-Polymarket hosts (RPC)", () => {
+    assert.strictEqual(endpointKey("post", "https://polygon-rpc.com/"), null);
+  });
+
+  it("treats only CLOB POST/DELETE as the write path", () => {
+    assert.ok(isWriteEndpoint(POST_ORDER));
+    assert.ok(!isWriteEndpoint("GET clob.polymarket.com/book"));
+    assert.ok(!isWriteEndpoint("POST data-api.polymarket.com/x"));
+  });
+});
+
+describe("EndpointLatencyTracker", () => {
+  it("computes percentiles over the window", () => {
+    const t = tracker();
+    for (let i = 1; i <= 100; i++) {
+      t.record("GET x.polymarket.com/a", i, false, 0);
+    }
+    const [stats] = t.getStats(1000);
+    assert.strictEqual(stats.count, 100);
+    assert.strictEqual(stats.p50Ms, 51);
+    assert.strictEqual(stats.p95Ms, 96);
+    assert.strictEqual(stats.p99Ms, 100);
+    assert.deepStrictEqual(t.getStats(11 * 60_000), []);
+  });
+
+  it("alerts only after p95 stays above the SLO, then recovers", () => {
+    const t = tracker();
+    const events: SloBreachEvent[] = [];
+    t.onSloEvent((e) => events.push(e));
+
+    for (let i = 0; i < 3; i++) t.record(POST_ORDER, 2000, false, i * 1000);
+    assert.strictEqual(events.length, 0);
+
+    t.record(POST_ORDER, 2000, false, 62_000);
+    assert.deepStrictEqual(
+      events.map((e) => e.event),
+      ["SLO_BREACH"],
+    );
+    assert.deepStrictEqual(t.getBreaches(), [POST_ORDER]);
+
+    // Once the slow samples age out of the window, it recovers
+    t.evaluate(62_000 + 10 * 60_000 + 1);
+    assert.deepStrictEqual(
+      events.map((e) => e.event),
+      ["SLO_BREACH", "SLO_RECOVERED"],
+    );
+    assert.deepStrictEqual(t.getBreaches(), []);
+  });
+
+  it("forgets a short spike without alerting", () => {
+    const t = tracker();
+    const events: SloBreachEvent[] = [];
+    t.onSloEvent((e) => events.push(e));
+    for (let i = 0; i < 3; i++) t.record(POST_ORDER, 2000, false, i);
+    for (let i = 0; i < 60; i++) t.record(POST_ORDER, 100, false, 10_000 + i);
+    t.evaluate(120_000);
+    assert.strictEqual(events.length, 0);
+  });
+
+  it("times requests through axios interceptors", async () => {
+    const t = tracker();
+    const client = axios.create({
+      adapter: async (config) => ({
+        data: {},
+        status: 200,
+        statusText: "OK",
+        headers: {},
+        config,
+      }),
+    });
+    t.install(client);
+    await client.post("https://clob.polymarket.com/order", {});
+    t.uninstall();
+    await client.post("https://clob.polymarket.com/order", {});
+
+    const [stats] = t.getStats();
+    assert.strictEqual(stats.endpoint, POST_ORDER);
+    assert.strictEqual(stats.count, 1);
+  });
+});
@@ -0,0 +1,183 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  EventBus,
+  FileSink,
+  SocketSink,
+  parseEventFilter,
+  matchesEventFilter,
+  resolveSocketAddress,
+  type BotEvent,
+  type EventSink,
+} from "../../../src/infra/event-bus";
+
+// In-memory sink recording what it receives
+function createMemorySink(name: string, filter: string) {
+  const received: BotEvent[] = [];
+  const sink: EventSink = {
+    name,
+    filter: parseEventFilter(filter),
+    write: (event) => received.push(event),
+  };
+  return { sink, received };
+}
+
+describe("parseEventFilter", () => {
+  it("matches exact names, prefixes and exclusions", () => {
+    const filter = parseEventFilter("ENTRY_FILLED, RISK_*, !RISK_NOISY");
+    assert.strictEqual(matchesEventFilter(filter, "ENTRY_FILLED"), true);
+    assert.strictEqual(matchesEventFilter(filter, "RISK_BLOCKED"), true);
+    assert.strictEqual(matchesEventFilter(filter, "RISK_NOISY"), false);
+    assert.strictEqual(matchesEventFilter(filter, "POSITION_CLOSED"), false);
+  });
+
+  it("matches everything when empty or exclusion-only", () => {
+    assert.strictEqual(matchesEventFilter(parseEventFilter(""), "X"), true);
+    const filter = parseEventFilter("!PRICE_FORMATION");
+    assert.strictEqual(matchesEventFilter(filter, "STARTUP"), true);
+    assert.strictEqual(matchesEventFilter(filter, "PRICE_FORMATION"), false);
+  });
+});
+
+describe("EventBus", () => {
+  it("fans events out to each sink by its own filter", () => {
+    const bus = new EventBus();
+    const all = createMemorySink("all", "*");
+    const risk = createMemorySink("risk", "RISK_*");
+    bus.addSink(all.sink);
+    bus.addSink(risk.sink);
+
+    bus.emit({ event: "ENTRY_FILLED", sizeUsd: 5 });
+    bus.emit({ event: "RISK_BLOCKED", reason: "drawdown" });
+
+    assert.deepStrictEqual(
+      all.received.map((e) => e.event),
+      ["ENTRY_FILLED", "RISK_BLOCKED"],
+    );
+    assert.deepStrictEqual(risk.received.map((e) => e.event), ["RISK_BLOCKED"]);
+  });
+
+  it("keeps delivering when one sink throws", () => {
+    const bus = new EventBus();
+    const good = createMemorySink("good", "*");
+    bus.addSink({
+      name: "broken",
+      filter: parseEventFilter("*"),
+      write: () => {
+        throw new Error("boom");
+      },
+    });
+    bus.addSink(good.sink);
+
+    bus.emit({ event: "STARTUP" });
+    assert.strictEqual(good.received.length, 1);
+  });
+
+  it("removes sinks by name", () => {
+    const bus = new EventBus();
+    const sink = createMemorySink("memory", "*");
+    bus.addSink(sink.sink);
+    bus.removeSink("memory");
+
+    bus.emit({ event: "STARTUP" });
+    assert.strictEqual(sink.received.length, 0);
+    assert.deepStrictEqual(bus.getSinkNames(), []);
+  });
+
+  it("numbers heartbeats and routes them through sink filters", () => {
+    const bus = new EventBus();
+    const all = createMemorySink("all", "*");
+    const fills = createMemorySink("fills", "ENTRY_FILLED");
+    bus.addSink(all.sink);
+    bus.addSink(fills.sink);
+
+    bus.heartbeat();
+    bus.heartbeat();
+
+    assert.deepStrictEqual(
+      all.received.map((e) => [e.event, (e as any).seq]),
+      [
+        ["heartbeat", 1],
+        ["heartbeat", 2],
+      ],
+    );
+    assert.strictEqual(fills.received.length, 0);
+  });
+
+  it("emits heartbeats on the interval until closed", async () => {
+    const bus = new EventBus();
+    const sink = createMemorySink("memory", "heartbeat");
+    bus.addSink(sink.sink);
+
+    bus.startHeartbeat(10);
+    await new Promise((r) => setTimeout(r, 45));
+    bus.close();
+    const count = sink.received.length;
+    assert.ok(count >= 2, `expected heartbeats, got ${count}`);
+
+    await new Promise((r) => setTimeout(r, 30));
+    assert.strictEqual(sink.received.length, count);
+  });
+});
+
+describe("FileSink", () => {
+  it("appends events as JSON lines", async () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "event-bus-"));
+    const filePath = path.join(dir, "events.jsonl");
+    const sink = new FileSink(filePath);
+
+    sink.write({ event: "ENTRY_FILLED" });
+    sink.write({ event: "POSITION_CLOSED" });
+    await new Promise<void>((resolve) => {
+      sink.close();
+      setTimeout(resolve, 50);
+    });
+
+    const lines = fs.readFileSync(filePath, "utf8").trim().split("\n");
+    assert.deepStrictEqual(
+      lines.map((l) => JSON.parse(l).event),
+      ["ENTRY_FILLED", "POSITION_CLOSED"],
+    );
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+});
+
+describe("resolveSocketAddress", () => {
+  it("treats numeric addresses as TCP ports", () => {
+    assert.deepStrictEqual(resolveSocketAddress("9464", "linux"), {
+      port: 9464,
+    });
+  });
+
+  it("keeps socket paths on POSIX and maps them to pipes on Windows", () => {
+    assert.deepStrictEqual(resolveSocketAddress("/tmp/bot.sock", "linux"), {
+      path: "/tmp/bot.sock",
+    });
+    assert.deepStrictEqual(
+      resolveSocketAddress("C:\\bot\\events.sock", "win32"),
+      { path: "\\\\.\\pipe\\events.sock" },
+    );
+    assert.deepStrictEqual(resolveSocketAddress("\\\\.\\pipe\\bot", "win32"), {
+      path: "\\\\.\\pipe\\bot",
+    });
+  });
+});
+
+describe("SocketSink", () => {
+  it(
+    "refuses to replace a path that isn't a socket",
+    { skip: process.platform === "win32" },
+    () => {
+      const dir = fs.mkdtempSync(path.join(os.tmpdir(), "event-socket-"));
+      const filePath = path.join(dir, "events.jsonl");
+      fs.writeFileSync(filePath, "keep me\n");
+
+      assert.throws(() => new SocketSink(filePath), /is not a socket/);
+      assert.strictEqual(fs.readFileSync(filePath, "utf8"), "keep me\n");
+      fs.rmSync(dir, { recursive: true, force: true });
+    },
+  );
+});
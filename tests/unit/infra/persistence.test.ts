@@ -6,6 +6,7 @@
  * - MarketCache: Market caching with multi-key indexing
  * - PositionStore: Position storage with staleness tracking
  * - StoreRegistry: Health check aggregation
+ * - LruCache: Bounded caches registered with the StoreRegistry
  */
 
 import assert from "node:assert";
@@ -22,7 +23,9 @@ import {
   initPositionStore,
   getStoreRegistry,
   initStoreRegistry,
-} from "../../src/infra/persistence";
+  LruCache,
+  createBoundedCache,
+} from "../../../src/infra/persistence";
 
 // ============================================================================
 // Test Helpers
@@ -668,6 +671,19 @@ describe("StoreRegistry", () => {
       assert.strictEqual(metrics.totalEntries, 3);
       assert.strictEqual(metrics.storeCount, 2);
     });
+
+    it("should include eviction counts per store", () => {
+      const store = new TestStore("EvictingStore", 2);
+      store.set("key1", "value1");
+      store.set("key2", "value2");
+      store.set("key3", "value3");
+
+      registry.register(store);
+
+      const metrics = registry.getMetrics();
+      assert.strictEqual(metrics.byStore.EvictingStore.entryCount, 2);
+      assert.strictEqual(metrics.byStore.EvictingStore.evictions, 1);
+    });
   });
 
   describe("Lifecycle", () => {
@@ -711,3 +727,53 @@ describe("StoreRegistry", () => {
     });
   });
 });
+
+// ============================================================================
+// LruCache Tests
+// ============================================================================
+
+describe("LruCache", () => {
+  beforeEach(() => {
+    initStoreRegistry();
+  });
+
+  it("should evict least recently used entries at capacity", () => {
+    const cache = new LruCache<string, number>("TestLru", { maxEntries: 2 });
+    cache.set("a", 1);
+    cache.set("b", 2);
+    cache.get("a"); // "b" is now least recently used
+    cache.set("c", 3);
+
+    assert.strictEqual(cache.get("a"), 1);
+    assert.strictEqual(cache.get("b"), null);
+    assert.strictEqual(cache.get("c"), 3);
+
+    const metrics = cache.getMetrics();
+    assert.strictEqual(metrics.evictions, 1);
+    assert.strictEqual(metrics.hits, 3);
+    assert.strictEqual(metrics.misses, 1);
+  });
+
+  it("should expire entries past their TTL", async () => {
+    const cache = new LruCache<string, number>("TestLruTtl", { ttlMs: 20 });
+    cache.set("a", 1);
+    await new Promise((resolve) => setTimeout(resolve, 30));
+
+    assert.strictEqual(cache.get("a"), null);
+    assert.strictEqual(cache.getMetrics().expirations, 1);
+  });
+
+  it("should register caches created with createBoundedCache", () => {
+    const cache = createBoundedCache<number>("RegisteredLru", {
+      maxEntries: 10,
+    });
+    cache.set("a", 1);
+
+    const registry = getStoreRegistry();
+    assert.strictEqual(registry.getStore("RegisteredLru"), cache);
+    assert.strictEqual(
+      registry.getMetrics().byStore.RegisteredLru.entryCount,
+      1,
+    );
+  });
+});
@@ -0,0 +1,137 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import axios from "axios";
+import {
+  RequestCapture,
+  sanitizeUrl,
+  buildSupportBundle,
+} from "../../../src/infra/request-capture";
+
+// axios instance that never touches the network
+function createStubAxios(status: number) {
+  return axios.create({
+    adapter: async (config) => {
+      const response = {
+        data: {},
+        status,
+        statusText: String(status),
+        headers: {},
+        config,
+      };
+      if (status >= 400) {
+        const err: any = new Error(`Request failed with status ${status}`);
+        err.config = config;
+        err.response = response;
+        err.code = "ERR_BAD_RESPONSE";
+        throw err;
+      }
+      return response;
+    },
+  });
+}
+
+describe("RequestCapture", () => {
+  it("records method, url, status and latency", async () => {
+    const capture = new RequestCapture(10);
+    const client = createStubAxios(200);
+    capture.install(client);
+
+    await client.get("https://clob.polymarket.com/book?token_id=123");
+
+    const [entry] = capture.getEntries();
+    assert.strictEqual(entry.method, "GET");
+    assert.strictEqual(entry.status, 200);
+    assert.ok(entry.url.includes("token_id=123"));
+    assert.ok(entry.latencyMs >= 0);
+    assert.strictEqual(entry.error, undefined);
+  });
+
+  it("records failed requests with their status", async () => {
+    const capture = new RequestCapture(10);
+    const client = createStubAxios(500);
+    capture.install(client);
+
+    await assert.rejects(() => client.get("https://clob.polymarket.com/x"));
+
+    const [entry] = capture.getEntries();
+    assert.strictEqual(entry.status, 500);
+    assert.ok(entry.error);
+  });
+
+  it("redacts credential headers", async () => {
+    const capture = new RequestCapture(10);
+    const client = createStubAxios(200);
+    capture.install(client);
+
+    await client.post(
+      "https://clob.polymarket.com/order",
+      {},
+      {
+        headers: {
+          POLY_API_KEY: "my-key",
+          POLY_PASSPHRASE: "my-pass",
+          POLY_SIGNATURE: "my-sig",
+        },
+      },
+    );
+
+    const serialized = JSON.stringify(capture.getEntries());
+    assert.ok(!serialized.includes("my-key"));
+    assert.ok(!serialized.includes("my-pass"));
+    assert.ok(!serialized.includes("my-sig"));
+  });
+
+  it("keeps only the last N entries", async () => {
+    const capture = new RequestCapture(3);
+    const client = createStubAxios(200);
+    capture.install(client);
+
+    for (let i = 0; i < 5; i++) {
+      await client.get(`https://clob.polymarket.com/req/${i}`);
+    }
+
+    const entries = capture.getEntries();
+    assert.strictEqual(entries.length, 3);
+    assert.ok(entries[0].url.endsWith("/req/2"));
+  });
+
+  it("stops recording after uninstall", async () => {
+    const capture = new RequestCapture(10);
+    const client = createStubAxios(200);
+    capture.install(client);
+    capture.uninstall();
+
+    await client.get("https://clob.polymarket.com/x");
+    assert.strictEqual(capture.getEntries().length, 0);
+  });
+});
+
+describe("sanitizeUrl", () => {
+  it("hides RPC URL paths that embed API keys", () => {
+    const url = sanitizeUrl("https://polygon-mainnet.infura.io/v3/abc123");
+    assert.ok(!url.includes("abc123"));
+    assert.ok(url.startsWith("https://polygon-mainnet.infura.io"));
+  });
+
+  it("redacts key-like query params", () => {
+    const url = sanitizeUrl("https://gamma-api.polymarket.com/m?api_key=zzz");
+    assert.ok(!url.includes("zzz"));
+  });
+});
+
+describe("buildSupportBundle", () => {
+  it("redacts secrets and RPC URLs from config", () => {
+    const bundle = buildSupportBundle({
+      privateKey: "0xdeadbeef",
+      telegramBotToken: "bot-token",
+      rpcUrl: "https://polygon-mainnet.infura.io/v3/abc123",
+      maxTradeUsd: 25,
+    });
+
+    const serialized = JSON.stringify(bundle.config);
+    assert.ok(!serialized.includes("0xdeadbeef"));
+    assert.ok(!serialized.includes("bot-token"));
+    assert.ok(!serialized.includes("abc123"));
+    assert.ok(serialized.includes("25"));
+  });
+});
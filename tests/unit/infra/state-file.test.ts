@@ -0,0 +1,78 @@
+import assert from "node:assert";
+import { describe, test, beforeEach, afterEach } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  saveStateFile,
+  loadStateFile,
+  STATE_FILE_VERSION,
+} from "../../../src/infra/persistence/state-file";
+
+describe("StateFile", () => {
+  let dir: string;
+  let filePath: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "state-file-"));
+    filePath = path.join(dir, "nested", "state.json");
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  test("round-trips a payload", () => {
+    const payload = { positions: [{ id: "a", tpCents: 64 }], orders: [] };
+    const saved = saveStateFile(filePath, payload);
+    assert.strictEqual(saved.ok, true);
+
+    const loaded = loadStateFile<typeof payload>(filePath);
+    assert.strictEqual(loaded.ok, true);
+    assert.deepStrictEqual(loaded.payload, payload);
+    assert.strictEqual(loaded.savedAt, saved.savedAt);
+  });
+
+  test("missing file is ok with no payload", () => {
+    const loaded = loadStateFile(filePath);
+    assert.strictEqual(loaded.ok, true);
+    assert.strictEqual(loaded.payload, undefined);
+  });
+
+  test("rejects a file with a different version", () => {
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(
+      filePath,
+      JSON.stringify({ version: STATE_FILE_VERSION + 1, payload: {} }),
+    );
+    const loaded = loadStateFile(filePath);
+    assert.strictEqual(loaded.ok, false);
+    assert.ok(loaded.error?.includes("Unsupported"));
+  });
+
+  test("reports corrupt JSON instead of throwing", () => {
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(filePath, "{not json");
+    const loaded = loadStateFile(filePath);
+    assert.strictEqual(loaded.ok, false);
+    assert.ok(loaded.error);
+  });
+
+  test("does not leave a temp file behind", () => {
+    saveStateFile(filePath, { a: 1 });
+    assert.strictEqual(fs.existsSync(`${filePath}.tmp`), false);
+  });
+
+  test(
+    "writes 0600 even over a leftover temp file",
+    { skip: process.platform === "win32" },
+    () => {
+      fs.mkdirSync(path.dirname(filePath), { recursive: true });
+      fs.writeFileSync(`${filePath}.tmp`, "partial", { mode: 0o644 });
+      fs.chmodSync(`${filePath}.tmp`, 0o644);
+
+      assert.strictEqual(saveStateFile(filePath, { a: 1 }).ok, true);
+      assert.strictEqual(fs.statSync(filePath).mode & 0o777, 0o600);
+    },
+  );
+});
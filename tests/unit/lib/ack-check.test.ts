@@ -0,0 +1,112 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  ACK_NOT_VISIBLE,
+  AckChecker,
+  createClientAckLookup,
+  withAckCheck,
+  type AckNotVisibleEvent,
+} from "../../../src/lib/ack-check";
+
+const FAST = { timeoutMs: 50, intervalMs: 10 };
+
+describe("AckChecker", () => {
+  it("passes an order that becomes visible after a few lookups", async () => {
+    let lookups = 0;
+    const checker = new AckChecker(async () => ++lookups >= 3, FAST);
+    const events: AckNotVisibleEvent[] = [];
+    checker.onNotVisible((e) => events.push(e));
+
+    assert.strictEqual(await checker.verify("0xorder"), true);
+    assert.strictEqual(lookups, 3);
+    assert.strictEqual(events.length, 0);
+    assert.deepStrictEqual(checker.getStats(), {
+      checked: 1,
+      visible: 1,
+      notVisible: 0,
+      pending: 0,
+    });
+  });
+
+  it("flags ACK_NOT_VISIBLE once the timeout passes", async () => {
+    const checker = new AckChecker(async () => false, FAST);
+    const events: AckNotVisibleEvent[] = [];
+    checker.onNotVisible((e) => events.push(e));
+
+    const visible = await checker.verify("0xorder", {
+      tokenId: "tok",
+      side: "BUY",
+    });
+    assert.strictEqual(visible, false);
+    assert.strictEqual(events.length, 1);
+    assert.strictEqual(events[0].event, ACK_NOT_VISIBLE);
+    assert.strictEqual(events[0].orderId, "0xorder");
+    assert.strictEqual(events[0].tokenId, "tok");
+    assert.ok(events[0].lookups > 1);
+    assert.strictEqual(checker.getStats().notVisible, 1);
+  });
+
+  it("treats a failing lookup as not visible yet", async () => {
+    let calls = 0;
+    const checker = new AckChecker(async () => {
+      if (++calls === 1) throw new Error("timeout");
+      return true;
+    }, FAST);
+    assert.strictEqual(await checker.verify("0xorder"), true);
+  });
+});
+
+describe("createClientAckLookup", () => {
+  it("finds an order by ID", async () => {
+    const client: any = { getOrder: async (id: string) => ({ id }) };
+    assert.strictEqual(await createClientAckLookup(client)("0xa"), true);
+  });
+
+  it("finds a filled order among the token's trades", async () => {
+    const client: any = {
+      getOrder: async () => {
+        throw new Error("not found");
+      },
+      getTrades: async () => [
+        { taker_order_id: "0xother", maker_orders: [{ order_id: "0xa" }] },
+      ],
+    };
+    const lookup = createClientAckLookup(client);
+    assert.strictEqual(await lookup("0xa", "tok"), true);
+    assert.strictEqual(await lookup("0xb", "tok"), false);
+  });
+});
+
+describe("withAckCheck", () => {
+  it("verifies accepted orders without delaying the response", async () => {
+    const verified: string[] = [];
+    const checker = new AckChecker(async (id) => {
+      verified.push(id);
+      return true;
+    }, FAST);
+    const client: any = {
+      postOrder: async () => ({ success: true, orderID: "0xa" }),
+      getTickSize: () => "0.01",
+    };
+    const wrapped = withAckCheck(client, checker);
+
+    const response = await wrapped.postOrder(
+      { tokenId: "tok", side: 0 } as any,
+      "GTC" as any,
+    );
+    assert.deepStrictEqual(response, { success: true, orderID: "0xa" });
+    assert.strictEqual((wrapped as any).getTickSize(), "0.01");
+
+    await new Promise((r) => setTimeout(r, 20));
+    assert.deepStrictEqual(verified, ["0xa"]);
+  });
+
+  it("skips rejected orders", async () => {
+    const checker = new AckChecker(async () => true, FAST);
+    const client: any = {
+      postOrder: async () => ({ success: false, errorMsg: "rejected" }),
+    };
+    await withAckCheck(client, checker).postOrder({} as any);
+    assert.strictEqual(checker.getStats().checked, 0);
+  });
+});
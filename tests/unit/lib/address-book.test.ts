@@ -0,0 +1,42 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  AddressBook,
+  parseAddressLabels,
+} from "../../../src/lib/address-book";
+import { POLYGON } from "../../../src/lib/constants";
+
+const WHALE = "0x1111111111111111111111111111111111111111";
+const OTHER = "0x2222222222222222222222222222222222222222";
+
+describe("parseAddressLabels", () => {
+  it("parses address=label pairs, lowercasing addresses", () => {
+    const labels = parseAddressLabels(
+      `${WHALE.toUpperCase().replace("0X", "0x")}=whale:TheGoat, bad=x,${OTHER}=`,
+    );
+    assert.deepStrictEqual([...labels], [[WHALE, "whale:TheGoat"]]);
+  });
+});
+
+describe("AddressBook", () => {
+  it("prefers configured labels over runtime registrations", () => {
+    const book = new AddressBook(new Map([[WHALE, "whale:Configured"]]));
+    book.register(WHALE, "whale:Leaderboard");
+    book.register(OTHER, "own:wallet");
+
+    assert.strictEqual(book.getLabel(WHALE), "whale:Configured");
+    assert.strictEqual(book.getLabel(OTHER.toUpperCase()), "own:wallet");
+  });
+
+  it("labels Polymarket contracts out of the box", () => {
+    const book = new AddressBook();
+    assert.strictEqual(book.format(POLYGON.CTF_ADDRESS), "contract:CTF");
+  });
+
+  it("falls back to shortened hex", () => {
+    const book = new AddressBook();
+    assert.strictEqual(book.format(WHALE), "0x11111111...");
+    assert.strictEqual(book.format(WHALE, 8), "0x111111...");
+    assert.strictEqual(book.format(""), "unknown");
+  });
+});
@@ -0,0 +1,121 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  AuthFallback,
+  alternateAuthCombos,
+  withAuthFallback,
+} from "../../../src/lib/auth-fallback";
+
+const FUNDER = "0xfunder";
+
+/** Minimal client: signs orders as plain objects, posts with a canned reply */
+function fakeClient(reply: any, label: string) {
+  const posted: any[] = [];
+  const client: any = {
+    label,
+    createOrder: async (args: any) => ({ signedBy: label, args }),
+    postOrder: async (order: any) => {
+      posted.push(order);
+      return reply;
+    },
+    getLabel() {
+      return this.label;
+    },
+  };
+  return { client, posted };
+}
+
+describe("alternateAuthCombos", () => {
+  it("only offers EOA when no funder is known", () => {
+    assert.deepStrictEqual(alternateAuthCombos({ signatureType: 1 }), [
+      { signatureType: 0 },
+    ]);
+    assert.deepStrictEqual(alternateAuthCombos({ signatureType: 0 }), []);
+  });
+
+  it("offers proxy types for a known funder, minus the configured one", () => {
+    assert.deepStrictEqual(
+      alternateAuthCombos({ signatureType: 0 }, FUNDER),
+      [
+        { signatureType: 1, funderAddress: FUNDER },
+        { signatureType: 2, funderAddress: FUNDER },
+      ],
+    );
+    assert.deepStrictEqual(
+      alternateAuthCombos({ signatureType: 2, funderAddress: FUNDER }),
+      [{ signatureType: 0 }, { signatureType: 1, funderAddress: FUNDER }],
+    );
+  });
+});
+
+describe("AuthFallback", () => {
+  it("caches the first alternate that authenticates", async () => {
+    const tried: number[] = [];
+    const fallback = new AuthFallback({ signatureType: 0 }, async (combo) => {
+      tried.push(combo.signatureType);
+      return combo.signatureType === 2 ? ({} as any) : null;
+    });
+
+    const combo = await fallback.probe(
+      alternateAuthCombos({ signatureType: 0 }, FUNDER),
+    );
+    assert.deepStrictEqual(combo, { signatureType: 2, funderAddress: FUNDER });
+    assert.deepStrictEqual(tried, [1, 2]);
+    assert.strictEqual(fallback.getFallback()?.combo.signatureType, 2);
+  });
+
+  it("retries nothing without a working alternate", async () => {
+    const fallback = new AuthFallback({ signatureType: 0 }, async () => null);
+    assert.strictEqual(await fallback.probe([{ signatureType: 1 }]), null);
+    assert.strictEqual(await fallback.retry("createOrder", [], [], "x"), null);
+  });
+});
+
+describe("withAuthFallback", () => {
+  async function setup(primaryReply: any) {
+    const primary = fakeClient(primaryReply, "primary");
+    const alternate = fakeClient({ success: true, orderID: "alt-1" }, "alt");
+    const fallback = new AuthFallback(
+      { signatureType: 0 },
+      async () => alternate.client,
+    );
+    await fallback.probe([{ signatureType: 1, funderAddress: FUNDER }]);
+    return {
+      client: withAuthFallback(primary.client, fallback) as any,
+      primary,
+      alternate,
+    };
+  }
+
+  it("re-signs and reposts an order rejected as unauthorized", async () => {
+    const { client, primary, alternate } = await setup({
+      success: false,
+      errorMsg: "Unauthorized/Invalid api key",
+    });
+
+    const order = await client.createOrder({ tokenID: "t", size: 5 });
+    const response = await client.postOrder(order, "GTC");
+
+    assert.strictEqual(response.orderID, "alt-1");
+    assert.strictEqual(primary.posted.length, 1);
+    assert.strictEqual(alternate.posted.length, 1);
+    assert.deepStrictEqual(alternate.posted[0], {
+      signedBy: "alt",
+      args: { tokenID: "t", size: 5 },
+    });
+  });
+
+  it("passes other rejections through untouched", async () => {
+    const rejected = { success: false, errorMsg: "not enough balance" };
+    const { client, alternate } = await setup(rejected);
+
+    const order = await client.createOrder({ tokenID: "t", size: 5 });
+    assert.strictEqual(await client.postOrder(order, "GTC"), rejected);
+    assert.strictEqual(alternate.posted.length, 0);
+  });
+
+  it("keeps other methods bound to the wrapped client", async () => {
+    const { client } = await setup({ success: true });
+    assert.strictEqual(client.getLabel(), "primary");
+  });
+});
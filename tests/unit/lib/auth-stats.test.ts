@@ -0,0 +1,94 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import { AuthStats } from "../../../src/lib/auth-stats";
+
+describe("AuthStats", () => {
+  it("starts with no backoff", () => {
+    const stats = new AuthStats(1000, 8000);
+    assert.strictEqual(stats.remainingBackoffMs(0), 0);
+    assert.strictEqual(stats.getStatus().attempts, 0);
+  });
+
+  it("doubles the backoff per consecutive failure up to the cap", () => {
+    const stats = new AuthStats(1000, 8000);
+    const backoffs = [1, 2, 3, 4, 5].map((i) => {
+      stats.recordAttempt(i);
+      return stats.recordFailure("401 Unauthorized", i);
+    });
+
+    assert.deepStrictEqual(backoffs, [1000, 2000, 4000, 8000, 8000]);
+    assert.strictEqual(stats.remainingBackoffMs(5), 8000);
+    assert.strictEqual(stats.remainingBackoffMs(5 + 8000), 0);
+
+    const status = stats.getStatus();
+    assert.strictEqual(status.attempts, 5);
+    assert.strictEqual(status.failures, 5);
+    assert.strictEqual(status.consecutiveFailures, 5);
+    assert.strictEqual(status.lastError, "401 Unauthorized");
+  });
+
+  it("clears the backoff on success but keeps the totals", () => {
+    const stats = new AuthStats(1000, 8000);
+    stats.recordAttempt(0);
+    stats.recordFailure("timeout", 0);
+    stats.recordAttempt(2000);
+    stats.recordSuccess(2000);
+
+    const status = stats.getStatus();
+    assert.strictEqual(stats.remainingBackoffMs(2000), 0);
+    assert.strictEqual(status.consecutiveFailures, 0);
+    assert.strictEqual(status.failures, 1);
+    assert.strictEqual(status.successes, 1);
+    assert.strictEqual(status.backoffMs, 0);
+
+    // The next failure starts over at the base
+    assert.strictEqual(stats.recordFailure("timeout", 3000), 1000);
+  });
+});
+
+describe("AuthStats persistence", () => {
+  it("restores the backoff and failure history from the state file", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "auth-stats-"));
+    const filePath = path.join(dir, "auth-state.json");
+    try {
+      const before = new AuthStats(1000, 8000);
+      before.attachStateFile(filePath, 0);
+      before.recordFailure("401 Unauthorized", 100);
+      before.recordFailure("401 Unauthorized", 200);
+
+      // A fresh process picks up where the last one stopped
+      const after = new AuthStats(1000, 8000);
+      after.attachStateFile(filePath, 500);
+      assert.strictEqual(after.remainingBackoffMs(500), 1700);
+      assert.strictEqual(after.getStatus().consecutiveFailures, 2);
+      assert.strictEqual(after.getStatus().recentFailures.length, 2);
+      assert.strictEqual(after.recordFailure("401 Unauthorized", 3000), 4000);
+
+      // Success clears the saved backoff too
+      after.recordSuccess(9000);
+      const cleared = new AuthStats(1000, 8000);
+      cleared.attachStateFile(filePath, 9000);
+      assert.strictEqual(cleared.getStatus().consecutiveFailures, 0);
+      assert.strictEqual(cleared.remainingBackoffMs(9000), 0);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+
+  it("caps a restored deadline at the maximum backoff", () => {
+    const stats = new AuthStats(1000, 8000);
+    stats.importState(
+      {
+        consecutiveFailures: 3,
+        backoffMs: 60_000,
+        backoffUntil: 1_000_000,
+        recentFailures: [],
+      },
+      0,
+    );
+    assert.strictEqual(stats.remainingBackoffMs(0), 8000);
+  });
+});
@@ -1,6 +1,9 @@
 import assert from "node:assert";
 import { describe, it, beforeEach, afterEach } from "node:test";
-import { getAuthDiagnostics } from "../../../src/lib/auth";
+import {
+  checkExpectedSigner,
+  getAuthDiagnostics,
+} from "../../../src/lib/auth";
 
 describe("getAuthDiagnostics", () => {
   // Store original env vars
@@ -140,3 +143,32 @@ describe("getAuthDiagnostics", () => {
     });
   });
 });
+
+describe("checkExpectedSigner", () => {
+  const SIGNER = "0x19e7e376e7c213b7e7e7e46cc70a5dd086daff2a";
+
+  it("passes when no address is expected", () => {
+    assert.strictEqual(checkExpectedSigner(SIGNER, undefined), null);
+    assert.strictEqual(checkExpectedSigner(SIGNER, " "), null);
+  });
+
+  it("matches regardless of case", () => {
+    const upper = `0x${SIGNER.slice(2).toUpperCase()}`;
+    assert.strictEqual(checkExpectedSigner(SIGNER, ` ${upper} `), null);
+  });
+
+  it("refuses a different signer", () => {
+    const error = checkExpectedSigner(
+      SIGNER,
+      "0x2222222222222222222222222222222222222222",
+    );
+    assert.match(error ?? "", /^SIGNER_MISMATCH: .* signs as 0x19e7e376/i);
+  });
+
+  it("refuses an expected address that isn't one", () => {
+    assert.match(
+      checkExpectedSigner(SIGNER, "0x1234") ?? "",
+      /EXPECTED_ADDRESS=0x1234 is not an address/,
+    );
+  });
+});
@@ -8,6 +8,8 @@ import {
   getPolBalanceWithStatus,
   BalanceCache,
   DEFAULT_BALANCE_REFRESH_INTERVAL_MS,
+  getClobBalanceAllowance,
+  parseClobBalanceAllowance,
 } from "../../../src/lib/balance";
 import { POLYGON } from "../../../src/lib/constants";
 
@@ -179,3 +181,59 @@ describe("getPolBalanceWithStatus", () => {
     assert.ok(result.error);
   });
 });
+
+describe("parseClobBalanceAllowance", () => {
+  it("converts base units to USDC", () => {
+    const result = parseClobBalanceAllowance(
+      { balance: "12500000", allowance: "5000000" },
+      "COLLATERAL",
+      "ignored",
+    );
+    assert.deepStrictEqual(result, {
+      assetType: "COLLATERAL",
+      tokenId: undefined,
+      balance: 12.5,
+      allowance: 5,
+    });
+  });
+
+  it("takes the smallest per-exchange allowance for a token", () => {
+    const result = parseClobBalanceAllowance(
+      {
+        balance: "3000000",
+        allowances: { "0xExchange": "1000000000", "0xNegRisk": "0" },
+      },
+      "CONDITIONAL",
+      "123",
+    );
+    assert.strictEqual(result.tokenId, "123");
+    assert.strictEqual(result.balance, 3);
+    assert.strictEqual(result.allowance, 0);
+  });
+});
+
+describe("getClobBalanceAllowance", () => {
+  it("queries the exact token for CONDITIONAL", async () => {
+    const getBalanceAllowance = mock.fn(async () => ({
+      balance: "7000000",
+      allowance: "7000000",
+    }));
+    const result = await getClobBalanceAllowance(
+      { getBalanceAllowance } as any,
+      "CONDITIONAL",
+      "456",
+    );
+    assert.deepStrictEqual(getBalanceAllowance.mock.calls[0].arguments[0], {
+      asset_type: "CONDITIONAL",
+      token_id: "456",
+    });
+    assert.strictEqual(result.balance, 7);
+  });
+
+  it("requires a token_id for CONDITIONAL", async () => {
+    await assert.rejects(
+      getClobBalanceAllowance({} as any, "CONDITIONAL"),
+      /token_id is required/,
+    );
+  });
+});
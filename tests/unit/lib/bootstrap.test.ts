@@ -0,0 +1,52 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  bootstrapVerdict,
+  checkBootstrapBalances,
+} from "../../../src/lib/bootstrap";
+
+describe("checkBootstrapBalances", () => {
+  const funded = { bridgedUsdc: 50, nativeUsdc: 0, pol: 1 };
+
+  it("passes a funded wallet", () => {
+    assert.strictEqual(checkBootstrapBalances(funded, true, 0.1).status, "OK");
+  });
+
+  it("fails without USDC.e, even when native USDC is there", () => {
+    const result = checkBootstrapBalances(
+      { ...funded, bridgedUsdc: 0, nativeUsdc: 50 },
+      true,
+      0.1,
+    );
+    assert.strictEqual(result.status, "FAIL");
+    assert.match(result.detail, /USDC\.e/);
+  });
+
+  it("only fails on low POL when the signer pays for approvals", () => {
+    const low = { ...funded, pol: 0.01 };
+    assert.strictEqual(checkBootstrapBalances(low, true, 0.1).status, "FAIL");
+    assert.strictEqual(checkBootstrapBalances(low, false, 0.1).status, "WARN");
+  });
+});
+
+describe("bootstrapVerdict", () => {
+  it("is READY unless a step failed", () => {
+    assert.strictEqual(
+      bootstrapVerdict([
+        { step: "CREDENTIALS", status: "OK", detail: "" },
+        { step: "APPROVALS", status: "FIXED", detail: "" },
+        { step: "TEST_ORDER", status: "WARN", detail: "" },
+      ]),
+      "READY",
+    );
+    assert.strictEqual(
+      bootstrapVerdict([
+        { step: "CREDENTIALS", status: "OK", detail: "" },
+        { step: "BALANCES", status: "FAIL", detail: "" },
+        { step: "APPROVALS", status: "SKIPPED", detail: "" },
+      ]),
+      "NOT_READY",
+    );
+    assert.strictEqual(bootstrapVerdict([]), "NOT_READY");
+  });
+});
@@ -0,0 +1,240 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  activeCreds,
+  loadCachedCreds,
+  readImportedCreds,
+  resolveCredsCachePath,
+  saveCachedCreds,
+  sessionIdentity,
+  verifyCreds,
+  withSessionRefresh,
+} from "../../../src/lib/clob-session";
+
+const OLD = { key: "old", secret: "s1", passphrase: "p1" };
+const NEW = { key: "new", secret: "s2", passphrase: "p2" };
+
+/** Client whose authenticated calls 401 unless it holds the NEW creds */
+function fakeClient() {
+  const calls: string[] = [];
+  const client: any = {
+    creds: OLD,
+    getOpenOrders: async () => {
+      calls.push(client.creds.key);
+      if (client.creds !== NEW) return { status: 401, error: "Unauthorized" };
+      return [];
+    },
+    postOrder: async () => {
+      calls.push(client.creds.key);
+      if (client.creds !== NEW) throw { response: { status: 401 } };
+      return { success: true };
+    },
+    getAddress: () => "0xsigner",
+  };
+  return { client, calls };
+}
+
+describe("credential cache", () => {
+  it("round-trips credentials for the same identity only", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "clob-session-"));
+    const filePath = path.join(dir, "creds.json");
+    const me = sessionIdentity("0xABC", 1, "0xFunder");
+    assert.strictEqual(me, "0xabc:1:0xfunder");
+
+    assert.strictEqual(loadCachedCreds(filePath, me), null);
+    assert.strictEqual(saveCachedCreds(filePath, me, OLD), true);
+    assert.deepStrictEqual(loadCachedCreds(filePath, me), OLD);
+    assert.strictEqual(
+      loadCachedCreds(filePath, sessionIdentity("0xabc", 0)),
+      null,
+    );
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+});
+
+describe("resolveCredsCachePath", () => {
+  it("prefers --creds-cache over API_CREDS_CACHE_PATH", () => {
+    const env = { API_CREDS_CACHE_PATH: " ./env.json " };
+    assert.strictEqual(resolveCredsCachePath([], env), "./env.json");
+    assert.strictEqual(
+      resolveCredsCachePath(["--creds-cache", "./arg.json"], env),
+      "./arg.json",
+    );
+    assert.strictEqual(
+      resolveCredsCachePath(["--profile=x", "--creds-cache=./a.json"], {}),
+      "./a.json",
+    );
+    assert.strictEqual(resolveCredsCachePath([], {}), undefined);
+  });
+});
+
+describe("readImportedCreds", () => {
+  const creds = {
+    POLY_API_KEY: "k",
+    POLY_API_SECRET: " s ",
+    POLY_PASSPHRASE: "p",
+  };
+
+  it("takes all three variables or none", () => {
+    const on = { IMPORT_API_CREDS: "true" };
+    assert.deepStrictEqual(readImportedCreds({ ...on, ...creds }), {
+      creds: { key: "k", secret: "s", passphrase: "p" },
+      missing: [],
+    });
+    assert.deepStrictEqual(readImportedCreds(on), { creds: null, missing: [] });
+    assert.deepStrictEqual(readImportedCreds({ ...on, POLY_API_KEY: "k" }), {
+      creds: null,
+      missing: ["POLY_API_SECRET", "POLY_PASSPHRASE"],
+    });
+  });
+
+  it("leaves the websocket's POLY_API_* alone without the opt-in", () => {
+    assert.deepStrictEqual(readImportedCreds(creds), {
+      creds: null,
+      missing: [],
+    });
+    assert.deepStrictEqual(readImportedCreds({ POLY_API_KEY: "k" }), {
+      creds: null,
+      missing: [],
+    });
+  });
+});
+
+describe("verifyCreds", () => {
+  it("accepts credentials only when the exchange answers", async () => {
+    const answering = (reply: any) =>
+      ({ getApiKeys: async () => reply }) as any;
+    assert.strictEqual(await verifyCreds(answering({ apiKeys: [] })), true);
+    assert.strictEqual(await verifyCreds(answering({ status: 401 })), false);
+    assert.strictEqual(await verifyCreds(answering({ error: "x" })), false);
+    const failing: any = {
+      getApiKeys: async () => {
+        throw new Error("timeout");
+      },
+    };
+    assert.strictEqual(await verifyCreds(failing), false);
+  });
+});
+
+describe("activeCreds", () => {
+  it("follows credentials re-derived after a 401", async () => {
+    const { client } = fakeClient();
+    const session = withSessionRefresh(client, async () => NEW);
+    assert.deepStrictEqual(activeCreds(session), OLD);
+    await session.getOpenOrders();
+    assert.deepStrictEqual(activeCreds(session), NEW);
+    assert.strictEqual(activeCreds({} as any), null);
+  });
+});
+
+describe("withSessionRefresh", () => {
+  it("re-derives on a 401 response and retries the call", async () => {
+    const { client, calls } = fakeClient();
+    let refreshes = 0;
+    const session = withSessionRefresh(client, async () => {
+      refreshes++;
+      return NEW;
+    });
+
+    assert.deepStrictEqual(await session.getOpenOrders(), []);
+    assert.deepStrictEqual(calls, ["old", "new"]);
+    assert.strictEqual(refreshes, 1);
+  });
+
+  it("re-derives on a thrown 401 and retries the call", async () => {
+    const { client } = fakeClient();
+    const session = withSessionRefresh(client, async () => NEW);
+
+    assert.deepStrictEqual(await session.postOrder({} as any), {
+      success: true,
+    });
+  });
+
+  it("refreshes once for concurrent 401s and retries both", async () => {
+    const { client, calls } = fakeClient();
+    let refreshes = 0;
+    const session = withSessionRefresh(client, async () => {
+      refreshes++;
+      return NEW;
+    });
+
+    await Promise.all([session.getOpenOrders(), session.getOpenOrders()]);
+    assert.strictEqual(refreshes, 1);
+    assert.deepStrictEqual(calls, ["old", "old", "new", "new"]);
+  });
+
+  it("gives up after one failed refresh", async () => {
+    const { client, calls } = fakeClient();
+    let refreshes = 0;
+    const session = withSessionRefresh(client, async () => {
+      refreshes++;
+      return null;
+    });
+
+    const first: any = await session.getOpenOrders();
+    assert.strictEqual(first.status, 401);
+    await assert.rejects(session.postOrder({} as any));
+    assert.strictEqual(refreshes, 1);
+    assert.deepStrictEqual(calls, ["old", "old"]);
+  });
+
+  it("backs off exponentially while refreshes don't help", async () => {
+    const { client } = fakeClient();
+    let clock = 0;
+    let refreshes = 0;
+    const session = withSessionRefresh(
+      client,
+      async () => {
+        refreshes++;
+        return null;
+      },
+      undefined,
+      { backoffMs: 1000, now: () => clock },
+    );
+    const refreshesAt = async (t: number) => {
+      clock = t;
+      await session.getOpenOrders();
+      return refreshes;
+    };
+
+    assert.strictEqual(await refreshesAt(0), 1);
+    assert.strictEqual(await refreshesAt(999), 1);
+    assert.strictEqual(await refreshesAt(1000), 2);
+    // Doubled: next one from 3000
+    assert.strictEqual(await refreshesAt(2999), 2);
+    assert.strictEqual(await refreshesAt(3000), 3);
+  });
+
+  it("resets the backoff once a retry gets through", async () => {
+    const NEWER = { key: "newer", secret: "s3", passphrase: "p3" };
+    let accepted = "new";
+    const client: any = {
+      creds: OLD,
+      getOpenOrders: async () =>
+        client.creds.key === accepted ? [] : { status: 401 },
+    };
+    const fresh = [NEW, NEWER];
+    let refreshes = 0;
+    const session = withSessionRefresh(
+      client,
+      async () => fresh[refreshes++],
+      undefined,
+      { now: () => 0 },
+    );
+
+    assert.deepStrictEqual(await session.getOpenOrders(), []);
+    // Session invalidated again straight away: refreshed, not backed off
+    accepted = "newer";
+    assert.deepStrictEqual(await session.getOpenOrders(), []);
+    assert.strictEqual(refreshes, 2);
+  });
+
+  it("passes synchronous methods straight through", () => {
+    const { client } = fakeClient();
+    const session = withSessionRefresh(client, async () => NEW);
+    assert.strictEqual((session as any).getAddress(), "0xsigner");
+  });
+});
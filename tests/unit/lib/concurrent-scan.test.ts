@@ -0,0 +1,116 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  evaluateScanBook,
+  formatScanSummary,
+  scanMarketsConcurrently,
+  type RawOrderbook,
+} from "../../../src/lib/concurrent-scan";
+import { RateLimiter } from "../../../src/services/polymarket/rate-limit";
+
+// REST order: bids ascending, asks descending (worst first)
+function book(bid: number, ask: number, size = 100): RawOrderbook {
+  return {
+    bids: [
+      { price: (bid - 0.1).toFixed(2), size: "1000" },
+      { price: bid.toFixed(2), size: String(size) },
+    ],
+    asks: [
+      { price: (ask + 0.1).toFixed(2), size: "1000" },
+      { price: ask.toFixed(2), size: String(size) },
+    ],
+  };
+}
+
+const unlimited = () => new RateLimiter({ maxRequests: 1000, windowMs: 1000 });
+
+describe("evaluateScanBook", () => {
+  it("accepts a tight, deep book and reports its best prices", () => {
+    const result = evaluateScanBook({ tokenId: "tok-1" }, book(0.48, 0.5));
+    assert.ok("candidate" in result);
+    assert.strictEqual(result.candidate.bestBid, 0.48);
+    assert.strictEqual(result.candidate.bestAsk, 0.5);
+    assert.ok(Math.abs(result.candidate.spreadCents - 2) < 1e-9);
+    // Only the best level is within 5¢ - the far level doesn't count
+    assert.ok(Math.abs(result.candidate.bidDepthUsd - 48) < 1e-9);
+  });
+
+  it("rejects with the first failing rule", () => {
+    const reject = (b: RawOrderbook, filters = {}) => {
+      const result = evaluateScanBook({ tokenId: "tok-1" }, b, filters);
+      return "rejected" in result ? result.rejected : null;
+    };
+    assert.strictEqual(reject({ bids: [], asks: [] }), "EMPTY_BOOK");
+    assert.strictEqual(reject(book(0.9, 0.92)), "PRICE_OUT_OF_RANGE");
+    assert.strictEqual(reject(book(0.4, 0.5)), "SPREAD_TOO_WIDE");
+    assert.strictEqual(reject(book(0.48, 0.5, 10)), "THIN_DEPTH");
+    assert.strictEqual(
+      reject(book(0.48, 0.5), { minDepthUsd: 100 }),
+      "THIN_DEPTH",
+    );
+  });
+});
+
+describe("scanMarketsConcurrently", () => {
+  it("never has more than `concurrency` fetches in flight", async () => {
+    let inFlight = 0;
+    let peak = 0;
+    const targets = Array.from({ length: 12 }, (_, i) => ({
+      tokenId: `tok-${i}`,
+    }));
+    const found: string[] = [];
+    const summary = await scanMarketsConcurrently(
+      targets,
+      async (tokenId) => {
+        inFlight++;
+        peak = Math.max(peak, inFlight);
+        await new Promise((r) => setTimeout(r, 5));
+        inFlight--;
+        return tokenId.endsWith("1") ? book(0.4, 0.5) : book(0.48, 0.5);
+      },
+      {},
+      {
+        concurrency: 3,
+        rateLimiter: unlimited(),
+        onCandidate: (c) => found.push(c.tokenId),
+      },
+    );
+
+    assert.strictEqual(peak, 3);
+    assert.strictEqual(summary.scanned, 12);
+    // tok-1 and tok-11 have a 10¢ spread
+    assert.strictEqual(summary.candidates.length, 10);
+    assert.strictEqual(summary.rejected.SPREAD_TOO_WIDE, 2);
+    assert.deepStrictEqual(found, summary.candidates.map((c) => c.tokenId));
+  });
+
+  it("backs off and retries after a 429, counting failures", async () => {
+    const calls = new Map<string, number>();
+    const progress: number[] = [];
+    const summary = await scanMarketsConcurrently(
+      [{ tokenId: "limited" }, { tokenId: "broken" }],
+      async (tokenId) => {
+        const n = (calls.get(tokenId) ?? 0) + 1;
+        calls.set(tokenId, n);
+        if (tokenId === "broken") throw new Error("boom");
+        if (n === 1) throw { response: { status: 429 } };
+        return book(0.48, 0.5);
+      },
+      {},
+      {
+        concurrency: 2,
+        rateLimiter: unlimited(),
+        retry: { maxRetries: 1, baseDelayMs: 1, maxDelayMs: 1 },
+        onProgress: (p) => progress.push(p.done),
+      },
+    );
+
+    assert.strictEqual(calls.get("limited"), 2);
+    assert.strictEqual(calls.get("broken"), 2);
+    assert.strictEqual(summary.rateLimited, 1);
+    assert.strictEqual(summary.failed, 1);
+    assert.strictEqual(summary.candidates.length, 1);
+    assert.deepStrictEqual(progress, [1, 2]);
+    assert.match(formatScanSummary(summary), /2 scanned, 1 candidate\(s\)/);
+  });
+});
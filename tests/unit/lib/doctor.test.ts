@@ -0,0 +1,120 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  checkClockSkew,
+  checkOpenFileLimit,
+  diagnoseEnv,
+  runDoctor,
+} from "../../../src/lib/doctor";
+
+const KEY = `0x${"1".repeat(64)}`;
+const BASE = { PRIVATE_KEY: KEY, RPC_URL: "https://polygon-rpc.com" };
+
+const codes = (env: Record<string, string>) =>
+  diagnoseEnv(env).map((f) => f.code);
+
+describe("diagnoseEnv", () => {
+  it("finds nothing wrong with a plain EOA setup", () => {
+    assert.deepStrictEqual(codes(BASE), []);
+  });
+
+  it("checks the private key format", () => {
+    assert.deepStrictEqual(codes({ RPC_URL: "x" }), ["PRIVATE_KEY_MISSING"]);
+    assert.deepStrictEqual(codes({ ...BASE, PRIVATE_KEY: "0x1234" }), [
+      "PRIVATE_KEY_INVALID",
+    ]);
+    assert.deepStrictEqual(codes({ ...BASE, PRIVATE_KEY: `"${KEY}"` }), [
+      "PRIVATE_KEY_FORMAT",
+    ]);
+  });
+
+  it("checks the signer against EXPECTED_ADDRESS", () => {
+    const other = "0x2222222222222222222222222222222222222222";
+    assert.deepStrictEqual(codes({ ...BASE, EXPECTED_ADDRESS: other }), [
+      "SIGNER_MISMATCH",
+    ]);
+  });
+
+  it("flags conflicting and ignored settings", () => {
+    const findings = diagnoseEnv({
+      ...BASE,
+      POLYMARKET_PRIVATE_KEY: `0x${"2".repeat(64)}`,
+      TELEGRAM_BOT_TOKEN: "a",
+      TELEGRAM_TOKEN: "b",
+    });
+    const ignored = findings.find((f) => f.code === "ENV_IGNORED");
+    assert.strictEqual(ignored?.severity, "CRITICAL");
+    assert.ok(findings.some((f) => f.code === "ENV_CONFLICT"));
+  });
+
+  it("requires a funder for proxy/Safe signature types", () => {
+    assert.deepStrictEqual(codes({ ...BASE, POLYMARKET_SIGNATURE_TYPE: "2" }), [
+      "FUNDER_MISSING",
+    ]);
+    assert.deepStrictEqual(codes({ ...BASE, POLYMARKET_SIGNATURE_TYPE: "3" }), [
+      "SIGNATURE_TYPE_INVALID",
+    ]);
+  });
+
+  it("flags a funder that isn't the signer's wallet", () => {
+    assert.deepStrictEqual(
+      codes({
+        ...BASE,
+        POLYMARKET_SIGNATURE_TYPE: "2",
+        POLYMARKET_PROXY_ADDRESS: "0x2222222222222222222222222222222222222222",
+      }),
+      ["FUNDER_MISMATCH"],
+    );
+  });
+
+  it("flags decimal commas in numeric settings only", () => {
+    const findings = diagnoseEnv({
+      ...BASE,
+      MAX_TRADE_USD: "2,5",
+      MARKET_ALLOW_IDS: "1,2",
+    });
+    assert.deepStrictEqual(
+      findings.map((f) => f.code),
+      ["DECIMAL_COMMA"],
+    );
+    assert.match(findings[0].fix, /MAX_TRADE_USD=2\.5/);
+  });
+});
+
+describe("checkOpenFileLimit", () => {
+  const limits = (soft: string) =>
+    `Limit            Soft Limit   Hard Limit   Units\n` +
+    `Max open files   ${soft}   4096   files\n`;
+
+  it("warns below 1024", () => {
+    assert.strictEqual(
+      checkOpenFileLimit(limits("256"))?.code,
+      "NOFILE_LIMIT_LOW",
+    );
+    assert.strictEqual(checkOpenFileLimit(limits("1024")), null);
+    assert.strictEqual(checkOpenFileLimit(limits("unlimited")), null);
+  });
+});
+
+describe("checkClockSkew", () => {
+  it("grades skew in either direction", () => {
+    assert.strictEqual(checkClockSkew(500), null);
+    assert.strictEqual(checkClockSkew(-5_000)?.severity, "WARNING");
+    assert.strictEqual(checkClockSkew(60_000)?.severity, "CRITICAL");
+  });
+});
+
+describe("runDoctor", () => {
+  it("sorts findings worst first and fails on a critical one", async () => {
+    const report = await runDoctor({
+      env: { RPC_URL: "x", MAX_TRADE_USD: "2,5" },
+      readLimits: () => null,
+      fetchServerTime: async () => null,
+    });
+    assert.strictEqual(report.ok, false);
+    assert.deepStrictEqual(
+      report.findings.map((f) => f.severity),
+      ["CRITICAL", "WARNING", "INFO"],
+    );
+  });
+});
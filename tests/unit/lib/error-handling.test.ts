@@ -15,6 +15,8 @@ import {
   ghWarningAnnotation,
   emitCloudflareBlockEvent,
   mapErrorToDiagReason,
+  parseClobError,
+  clobErrorFields,
 } from "../../../src/infra/error-handling";
 
 describe("Error Handling Utilities", () => {
@@ -212,6 +214,100 @@ describe("Error Handling Utilities", () => {
     });
   });
 
+  describe("parseClobError", () => {
+    it("should read the body clob-client nests under error", () => {
+      const result = parseClobError({
+        error: { error: "Unauthorized/Invalid api key" },
+        status: 401,
+      });
+      assert.strictEqual(result.errorCode, ErrorCode.AUTH_FAILED);
+      assert.strictEqual(result.errorDetail, "Unauthorized/Invalid api key");
+    });
+
+    it("should tell balance/allowance rejections from auth failures", () => {
+      const result = parseClobError({
+        success: false,
+        errorMsg: "not enough balance / allowance",
+      });
+      assert.strictEqual(result.errorCode, ErrorCode.INSUFFICIENT_BALANCE);
+      assert.strictEqual(result.errorDetail, "not enough balance / allowance");
+    });
+
+    it("should read thrown axios errors and keep the server code", () => {
+      const err = Object.assign(new Error("Request failed"), {
+        response: {
+          status: 400,
+          data: { error: "invalid order: tick size", code: "INVALID_ORDER" },
+        },
+      });
+      const result = parseClobError(err);
+      assert.strictEqual(result.errorDetail, "invalid order: tick size");
+      assert.strictEqual(result.serverCode, "INVALID_ORDER");
+    });
+
+    it("should fall back to the error message", () => {
+      const result = parseClobError(new Error("socket hang up"));
+      assert.strictEqual(result.errorDetail, "socket hang up");
+      assert.strictEqual(result.errorCode, ErrorCode.UNKNOWN);
+    });
+
+    it("should report status, endpoint and request ID of axios errors", () => {
+      const err = Object.assign(new Error("Request failed"), {
+        config: {
+          method: "post",
+          url: "https://clob.polymarket.com/order?x=1",
+        },
+        response: {
+          status: 500,
+          headers: { "cf-ray": "8a1b2c3d4e5f-AMS", "x-request-id": "req-42" },
+          data: { error: "internal error" },
+        },
+      });
+      const result = parseClobError(err, "GET /ignored");
+      assert.strictEqual(result.httpStatus, 500);
+      assert.strictEqual(result.endpoint, "POST /order");
+      assert.strictEqual(result.requestId, "req-42");
+    });
+
+    it("should name the endpoint when the response has no config", () => {
+      const result = parseClobError(
+        { error: { error: "Unauthorized/Invalid api key" }, status: 401 },
+        "POST /order",
+      );
+      assert.strictEqual(result.httpStatus, 401);
+      assert.strictEqual(result.endpoint, "POST /order");
+      assert.strictEqual(result.requestId, undefined);
+    });
+
+    it("should not attach an endpoint to local errors", () => {
+      const result = parseClobError(new Error("bad tick"), "POST /order");
+      assert.strictEqual(result.endpoint, undefined);
+      assert.strictEqual(result.httpStatus, undefined);
+    });
+
+    it("should ignore the order status string of a rejected response", () => {
+      const result = parseClobError({
+        success: false,
+        status: "unmatched",
+        errorMsg: "order couldn't be fully filled",
+      });
+      assert.strictEqual(result.httpStatus, undefined);
+    });
+  });
+
+  describe("clobErrorFields", () => {
+    it("should leave out fields the error didn't carry", () => {
+      const fields = clobErrorFields(
+        parseClobError({ success: false, errorMsg: "not enough balance" }),
+      );
+      assert.deepStrictEqual(Object.keys(fields).sort(), [
+        "errorCode",
+        "errorDetail",
+      ]);
+      assert.deepStrictEqual(clobErrorFields(null), {});
+    });
+  });
+
   describe("mapErrorToDiagReason", () => {
     it("should map Cloudflare block to cloudflare_blocked", () => {
       const reason = mapErrorToDiagReason("Sorry, you have been blocked");
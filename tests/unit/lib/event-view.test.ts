@@ -0,0 +1,68 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { buildEventView } from "../../../src/lib/event-view";
+
+const event = {
+  id: 903,
+  title: "Who wins?",
+  negRisk: true,
+  markets: [
+    {
+      conditionId: "0xa",
+      question: "Will A win?",
+      groupItemTitle: "A",
+      clobTokenIds: '["a-yes","a-no"]',
+      bestBid: "0.5",
+      bestAsk: "0.52",
+    },
+    {
+      conditionId: "0xb",
+      question: "Will B win?",
+      clobTokenIds: '["b-yes","b-no"]',
+      bestBid: 0.3,
+      bestAsk: 0.33,
+    },
+    {
+      conditionId: "0xc",
+      question: "Will C win?",
+      clobTokenIds: '["c-yes","c-no"]',
+      closed: true,
+    },
+  ],
+};
+
+describe("buildEventView", () => {
+  it("prices every open market and sums implied probability", async () => {
+    const view = await buildEventView(event, async () => null);
+    assert.strictEqual(view.eventId, "903");
+    assert.strictEqual(view.negRisk, true);
+    assert.deepStrictEqual(
+      view.markets.map((m) => [m.question, m.yesTokenId, m.source]),
+      [
+        ["A", "a-yes", "GAMMA"],
+        ["Will B win?", "b-yes", "GAMMA"],
+      ],
+    );
+    assert.ok(Math.abs(view.sumAsks! - 0.85) < 1e-9);
+    assert.ok(Math.abs(view.sumBids! - 0.8) < 1e-9);
+    assert.ok(Math.abs(view.sumMids! - 0.825) < 1e-9);
+  });
+
+  it("prefers live book quotes", async () => {
+    const view = await buildEventView(event, async (tokenId) =>
+      tokenId === "a-yes" ? { bestBid: 0.6, bestAsk: 0.61 } : null,
+    );
+    assert.strictEqual(view.markets[0].source, "BOOK");
+    assert.strictEqual(view.markets[0].bestAsk, 0.61);
+  });
+
+  it("leaves sums empty when a market has no price", async () => {
+    const unpriced = {
+      ...event,
+      markets: [...event.markets, { conditionId: "0xd", question: "D?" }],
+    };
+    const view = await buildEventView(unpriced, async () => null);
+    assert.strictEqual(view.markets[2].source, "NONE");
+    assert.strictEqual(view.sumAsks, null);
+  });
+});
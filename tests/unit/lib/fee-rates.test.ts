@@ -0,0 +1,44 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  estimateFeeCents,
+  estimateRoundTripFeeCents,
+} from "../../../src/lib/fee-rates";
+import { createTradeResult } from "../../../src/core/ev-tracker";
+
+describe("estimateFeeCents", () => {
+  it("charges the rate on the smaller side of the price", () => {
+    // 200 bps at 30¢ -> 2% of 30¢
+    assert.ok(Math.abs(estimateFeeCents(30, 200) - 0.6) < 1e-9);
+    // 200 bps at 70¢ -> 2% of 30¢ (1 - p)
+    assert.ok(Math.abs(estimateFeeCents(70, 200) - 0.6) < 1e-9);
+  });
+
+  it("returns 0 for fee-free markets and invalid inputs", () => {
+    assert.strictEqual(estimateFeeCents(50, 0), 0);
+    assert.strictEqual(estimateFeeCents(NaN, 200), 0);
+    assert.strictEqual(estimateFeeCents(50, -10), 0);
+  });
+
+  it("sums entry and exit fills for a round trip", () => {
+    const fees = estimateRoundTripFeeCents(40, 50, {
+      makerBps: 0,
+      takerBps: 100,
+    });
+    assert.ok(Math.abs(fees - 0.9) < 1e-9);
+  });
+});
+
+describe("createTradeResult with fees", () => {
+  it("deducts fees from P&L", () => {
+    const trade = createTradeResult("token-1", "LONG", 50, 51, 25, 1.5);
+    assert.strictEqual(trade.pnlCents, -0.5);
+    assert.strictEqual(trade.feesCents, 1.5);
+    assert.strictEqual(trade.isWin, false);
+  });
+
+  it("defaults to no fees", () => {
+    const trade = createTradeResult("token-1", "LONG", 50, 51, 25);
+    assert.strictEqual(trade.pnlCents, 1);
+  });
+});
@@ -0,0 +1,55 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  checkFunderAddress,
+  deriveProxyWallet,
+  deriveSafeWallet,
+  FUNDER_MISMATCH,
+} from "../../../src/lib/funder-check";
+
+const SIGNER = "0x1111111111111111111111111111111111111111";
+const OTHER = "0x2222222222222222222222222222222222222222";
+
+describe("deriveProxyWallet / deriveSafeWallet", () => {
+  it("derives distinct wallets per signer and kind", () => {
+    const proxy = deriveProxyWallet(SIGNER);
+    const safe = deriveSafeWallet(SIGNER);
+    assert.match(proxy, /^0x[0-9a-fA-F]{40}$/);
+    assert.notStrictEqual(proxy, safe);
+    assert.notStrictEqual(proxy, deriveProxyWallet(OTHER));
+  });
+});
+
+describe("checkFunderAddress", () => {
+  it("accepts the signer's own wallet for the signature type", () => {
+    const proxy = deriveProxyWallet(SIGNER).toLowerCase();
+    const safe = deriveSafeWallet(SIGNER).toLowerCase();
+    assert.strictEqual(checkFunderAddress(SIGNER, proxy, 1), null);
+    assert.strictEqual(checkFunderAddress(SIGNER, safe, 2), null);
+  });
+
+  it("ignores EOA mode and a missing funder", () => {
+    assert.strictEqual(checkFunderAddress(SIGNER, OTHER, 0), null);
+    assert.strictEqual(checkFunderAddress(SIGNER, undefined, 2), null);
+  });
+
+  it("points at the right signature type", () => {
+    const safe = deriveSafeWallet(SIGNER);
+    const result = checkFunderAddress(SIGNER, safe, 1);
+    assert.strictEqual(result?.code, FUNDER_MISMATCH);
+    assert.strictEqual(result?.matchingSignatureType, 2);
+    assert.match(result!.message, /set it to 2/);
+  });
+
+  it("flags a funder belonging to another signer", () => {
+    const result = checkFunderAddress(SIGNER, deriveSafeWallet(OTHER), 2);
+    assert.strictEqual(result?.matchingSignatureType, null);
+    assert.strictEqual(result?.expectedSafe, deriveSafeWallet(SIGNER));
+    assert.match(result!.message, /401/);
+  });
+
+  it("flags the signer used as its own funder", () => {
+    const result = checkFunderAddress(SIGNER, SIGNER, 1);
+    assert.match(result!.message, /signer itself/);
+  });
+});
@@ -0,0 +1,51 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  GasBudget,
+  estimateTxCostPol,
+  receiptFeePol,
+} from "../../../src/lib/gas-budget";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+describe("estimateTxCostPol", () => {
+  it("multiplies gas units by the max fee", () => {
+    // 100k gas at 100 gwei = 0.01 POL
+    assert.strictEqual(estimateTxCostPol(100_000n, 100_000_000_000n), 0.01);
+    assert.strictEqual(receiptFeePol({ fee: 10n ** 16n }), 0.01);
+    assert.strictEqual(receiptFeePol(null), 0);
+  });
+});
+
+describe("GasBudget", () => {
+  it("refuses transactions beyond the daily budget", () => {
+    const budget = new GasBudget(1, 0);
+    assert.ok(budget.reserve("redeem", 0.6, 0));
+    assert.strictEqual(budget.reserve("redeem", 0.6, 0), null);
+  });
+
+  it("refunds the unused part of a reservation on settle", () => {
+    const budget = new GasBudget(1, 0);
+    const reservation = budget.reserve("redeem", 0.6, 0)!;
+    budget.settle(reservation, 0.1, 0);
+
+    const status = budget.getStatus(0);
+    assert.ok(Math.abs(status.availablePol - 0.9) < 1e-9);
+    assert.ok(Math.abs(status.spentTodayPol - 0.1) < 1e-9);
+    assert.ok(budget.reserve("redeem", 0.6, 0));
+  });
+
+  it("refills continuously up to the daily budget", () => {
+    const budget = new GasBudget(1, 0);
+    budget.reserve("swap", 1, 0);
+    assert.strictEqual(budget.reserve("swap", 0.4, DAY_MS / 4), null);
+    assert.ok(budget.reserve("swap", 0.4, DAY_MS / 2));
+    assert.strictEqual(budget.getStatus(10 * DAY_MS).availablePol, 1);
+  });
+
+  it("never refuses when disabled", () => {
+    const budget = new GasBudget(0);
+    assert.strictEqual(budget.isEnabled(), false);
+    assert.ok(budget.reserve("redeem", 100));
+  });
+});
@@ -126,6 +126,148 @@ describe("MarketDataStore", () => {
     });
   });
 
+  describe("Trade guards", () => {
+    it("should drop crossed WS updates", () => {
+      const tokenId = "test-token-crossed";
+      const { bids, asks } = createTestLevels();
+      store.updateFromWs(tokenId, bids, asks);
+
+      const crossedBids = [{ price: 0.6, size: 100 }];
+      const updated = store.updateFromWs(tokenId, crossedBids, asks);
+
+      assert.strictEqual(updated, false);
+      assert.strictEqual(store.get(tokenId)?.bestBid, 0.55);
+      assert.strictEqual(store.getMetrics().crossedRejects, 1);
+    });
+
+    it("should allow trading off a fresh book", () => {
+      const tokenId = "test-token-fresh";
+      const { bids, asks } = createTestLevels();
+      store.updateFromWs(tokenId, bids, asks);
+
+      assert.strictEqual(store.checkTradable(tokenId, 1000).ok, true);
+    });
+
+    it("should return STALE_BOOK for old or missing books", async () => {
+      const tokenId = "test-token-old";
+      const { bids, asks } = createTestLevels();
+      store.updateFromWs(tokenId, bids, asks);
+
+      await new Promise((resolve) => setTimeout(resolve, 30));
+
+      const old = store.checkTradable(tokenId, 10);
+      assert.strictEqual(old.ok, false);
+      assert.strictEqual(!old.ok && old.reason, "STALE_BOOK");
+
+      const missing = store.checkTradable("unknown", 1000);
+      assert.strictEqual(!missing.ok && missing.reason, "STALE_BOOK");
+    });
+
+    it("should count REST snapshots that disagree with the WS mirror", () => {
+      const tokenId = "test-token-diverge";
+      const { bids, asks } = createTestLevels();
+      store.updateFromWs(tokenId, bids, asks);
+
+      store.updateFromRest(tokenId, [{ price: 0.5, size: 100 }], asks);
+
+      assert.strictEqual(store.getMetrics().restDivergences, 1);
+    });
+  });
+
+  describe("Trade prints", () => {
+    const print = (price: number, timestamp: number) => ({
+      tokenId: "test-token-trades",
+      price,
+      size: 10,
+      side: "BUY" as const,
+      timestamp,
+    });
+
+    it("should return last trades newest first", () => {
+      store.recordTrade(print(0.5, 1));
+      store.recordTrade(print(0.51, 2));
+      store.recordTrade(print(0.52, 3));
+
+      const trades = store.getLastTrades("test-token-trades", 2);
+      assert.deepStrictEqual(trades.map((t) => t.price), [0.52, 0.51]);
+    });
+
+    it("should cap prints per token", () => {
+      const capped = new MarketDataStore({ maxTradesPerToken: 3 });
+      for (let i = 0; i < 5; i++) capped.recordTrade(print(0.5, i));
+
+      const trades = capped.getLastTrades("test-token-trades");
+      assert.strictEqual(trades.length, 3);
+      assert.strictEqual(trades[2].timestamp, 2);
+    });
+
+    it("should build a recent price range from mid and trade VWAP", () => {
+      const { bids, asks } = createTestLevels();
+      store.updateFromWs("test-token-trades", bids, asks); // mid 0.555
+      store.recordTrade({ ...print(0.5, Date.now()), size: 10 });
+      store.recordTrade({ ...print(0.6, Date.now()), size: 30 }); // VWAP 0.575
+
+      const range = store.getRecentPriceRange("test-token-trades", 60_000);
+      assert.ok(range);
+      assert.ok(Math.abs(range.low - 0.555) < 1e-9);
+      assert.ok(Math.abs(range.high - 0.575) < 1e-9);
+      assert.strictEqual(store.getRecentPriceRange("unknown", 60_000), null);
+    });
+
+    it("should stream prints to listeners until unsubscribed", () => {
+      const seen: number[] = [];
+      const unsubscribe = store.onTrade((t) => seen.push(t.price));
+
+      store.recordTrade(print(0.5, 1));
+      unsubscribe();
+      store.recordTrade(print(0.6, 2));
+
+      assert.deepStrictEqual(seen, [0.5]);
+    });
+  });
+
+  describe("Book history", () => {
+    it("should return the book in force at a point in time", async () => {
+      const { bids, asks } = createTestLevels();
+      store.updateFromWs("test-token-history", bids, asks);
+      const first = store.getOrderbook("test-token-history")!;
+      await new Promise((r) => setTimeout(r, 5));
+      store.updateFromRest("test-token-history", [bids[1]], asks);
+      const second = store.getOrderbook("test-token-history")!;
+
+      assert.strictEqual(
+        store.getOrderbookAt("test-token-history", first.timestamp - 1),
+        null,
+      );
+      assert.strictEqual(
+        store.getOrderbookAt("test-token-history", second.timestamp - 1),
+        first,
+      );
+      assert.strictEqual(
+        store.getOrderbookAt("test-token-history", Date.now()),
+        second,
+      );
+    });
+
+    it("should cap snapshots per token", async () => {
+      const capped = new MarketDataStore({ maxBookHistory: 2 });
+      const { bids, asks } = createTestLevels();
+      const timestamps: number[] = [];
+      for (let i = 0; i < 3; i++) {
+        capped.updateFromRest("test-token-history", bids, asks);
+        timestamps.push(capped.getOrderbook("test-token-history")!.timestamp);
+        await new Promise((r) => setTimeout(r, 5));
+      }
+
+      // The first snapshot was dropped
+      assert.strictEqual(
+        capped.getOrderbookAt("test-token-history", timestamps[0]),
+        null,
+      );
+      assert.ok(capped.getOrderbookAt("test-token-history", timestamps[1]));
+    });
+  });
+
   describe("Deduplication", () => {
     it("should not report update when data unchanged", () => {
       const tokenId = "test-token-dedup";
@@ -151,6 +293,21 @@ describe("MarketDataStore", () => {
       const updated = store.updateFromWs(tokenId, newBids, asks);
       assert.strictEqual(updated, true);
     });
+
+    it("should keep and stream the book when only sizes change", () => {
+      const tokenId = "test-token-dedup-sizes";
+      const { bids, asks } = createTestLevels();
+      const seen: number[] = [];
+      const unsubscribe = store.onBook((b) => seen.push(b.bids[0].size));
+
+      store.updateFromWs(tokenId, bids, asks);
+      const resized = [{ ...bids[0], size: 300 }, ...bids.slice(1)];
+      assert.strictEqual(store.updateFromWs(tokenId, resized, asks), false);
+      unsubscribe();
+
+      assert.deepStrictEqual(seen, [100, 300]);
+      assert.strictEqual(store.getOrderbook(tokenId)!.bids[0].size, 300);
+    });
   });
 
   describe("REST fallback", () => {
@@ -0,0 +1,135 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  getMarketFilterReason,
+  isMarketFilterActive,
+  type MarketFilterConfig,
+} from "../../../src/lib/market-filter";
+
+function filterConfig(
+  overrides: Partial<MarketFilterConfig> = {},
+): MarketFilterConfig {
+  return {
+    allowConditionIds: [],
+    denyConditionIds: [],
+    allowTags: [],
+    denyTags: [],
+    ...overrides,
+  };
+}
+
+describe("Market Filter", () => {
+  it("allows everything when no lists are configured", () => {
+    const config = filterConfig();
+    assert.strictEqual(isMarketFilterActive(config), false);
+    assert.strictEqual(
+      getMarketFilterReason(
+        { condition_id: "0xabc", tags: ["Sports"] },
+        config,
+      ),
+      null,
+    );
+  });
+
+  it("rejects denied condition IDs and tags (case-insensitive)", () => {
+    const config = filterConfig({
+      denyConditionIds: ["0xdead"],
+      denyTags: ["sports"],
+    });
+
+    assert.strictEqual(
+      getMarketFilterReason({ condition_id: "0xDEAD" }, config),
+      "condition id denied",
+    );
+    assert.strictEqual(
+      getMarketFilterReason({ condition_id: "0x1", tags: ["Sports"] }, config),
+      "tag denied: sports",
+    );
+    assert.strictEqual(
+      getMarketFilterReason(
+        { condition_id: "0x1", tags: ["Politics"] },
+        config,
+      ),
+      null,
+    );
+  });
+
+  it("only allows markets matching an allow list", () => {
+    const config = filterConfig({
+      allowConditionIds: ["0xgood"],
+      allowTags: ["politics"],
+    });
+
+    assert.strictEqual(
+      getMarketFilterReason({ condition_id: "0xgood", tags: [] }, config),
+      null,
+    );
+    assert.strictEqual(
+      getMarketFilterReason(
+        { condition_id: "0x1", tags: ["Politics"] },
+        config,
+      ),
+      null,
+    );
+    assert.strictEqual(
+      getMarketFilterReason({ condition_id: "0x1", tags: ["Crypto"] }, config),
+      "not on allow list",
+    );
+  });
+
+  it("deny lists win over allow lists", () => {
+    const config = filterConfig({
+      allowTags: ["politics"],
+      denyTags: ["elections"],
+    });
+
+    assert.strictEqual(
+      getMarketFilterReason(
+        { condition_id: "0x1", tags: ["Politics", "Elections"] },
+        config,
+      ),
+      "tag denied: elections",
+    );
+  });
+
+  it("fails closed on unknown tags only for allow-tag lists", () => {
+    assert.strictEqual(
+      getMarketFilterReason(
+        { condition_id: "0x1" },
+        filterConfig({ allowTags: ["politics"] }),
+      ),
+      "tags unknown",
+    );
+    assert.strictEqual(
+      getMarketFilterReason(
+        { condition_id: "0x1" },
+        filterConfig({ denyTags: ["sports"] }),
+      ),
+      null,
+    );
+  });
+
+  it("matches synced tag IDs and slugs", () => {
+    const market = {
+      condition_id: "0x1",
+      tags: ["US Politics"],
+      tagRefs: ["2", "politics"],
+    };
+    assert.strictEqual(
+      getMarketFilterReason(market, filterConfig({ allowTags: ["2"] })),
+      null,
+    );
+    assert.strictEqual(
+      getMarketFilterReason(market, filterConfig({ denyTags: ["politics"] })),
+      "tag denied: politics",
+    );
+    // Synced refs count as known tags
+    assert.strictEqual(
+      getMarketFilterReason(
+        { condition_id: "0x1", tagRefs: ["2"] },
+        filterConfig({ allowTags: ["2"] }),
+      ),
+      null,
+    );
+  });
+});
@@ -0,0 +1,102 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import { saveStateFile } from "../../../src/infra/persistence";
+import {
+  MarketSearchIndex,
+  tokenizeSearchText,
+  toMarketSearchDoc,
+  type MarketSearchDoc,
+} from "../../../src/lib/market-search";
+
+function doc(
+  marketId: string,
+  question: string,
+  description = "",
+): MarketSearchDoc {
+  return {
+    marketId,
+    conditionId: `0x${marketId}`,
+    question,
+    description,
+    slug: "",
+    tokenIds: [],
+  };
+}
+
+describe("tokenizeSearchText", () => {
+  it("lowercases, splits on punctuation and drops stop words", () => {
+    assert.deepStrictEqual(
+      tokenizeSearchText("Will the Fed cut rates in Dec-2026?"),
+      ["fed", "cut", "rates", "dec", "2026"],
+    );
+  });
+});
+
+describe("MarketSearchIndex", () => {
+  const index = new MarketSearchIndex();
+  index.replace([
+    doc("1", "Will the Fed cut rates in December?"),
+    doc("2", "Will Bitcoin hit $150k by December?"),
+    doc("3", "Who wins the election?", "Resolves after the Fed meeting."),
+    doc("4", "Will the Lakers win the NBA title?"),
+  ]);
+
+  it("ranks markets matching more query terms first", () => {
+    const hits = index.search("fed rates december");
+    assert.deepStrictEqual(hits.map((h) => h.marketId), ["1", "2", "3"]);
+  });
+
+  it("ranks question matches above description matches", () => {
+    const hits = index.search("fed");
+    assert.deepStrictEqual(hits.map((h) => h.marketId), ["1", "3"]);
+    assert.ok(hits[0].score > hits[1].score);
+  });
+
+  it("respects the limit and ignores unknown terms", () => {
+    assert.strictEqual(index.search("december", 1).length, 1);
+    assert.deepStrictEqual(index.search("zzz the"), []);
+    assert.strictEqual(index.size(), 4);
+  });
+
+  it("reports its age from the last refresh", () => {
+    const fresh = new MarketSearchIndex();
+    assert.strictEqual(fresh.getAge(), Infinity);
+    fresh.replace([], 1_000);
+    assert.strictEqual(fresh.getAge(5_000), 4_000);
+  });
+});
+
+describe("MarketSearchIndex.attachStateFile", () => {
+  it("restores a saved index", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "market-search-"));
+    try {
+      const filePath = path.join(dir, "market-search.json");
+      saveStateFile(filePath, [doc("1", "Will the Fed cut rates?")]);
+
+      const index = new MarketSearchIndex();
+      index.attachStateFile(filePath);
+      assert.strictEqual(index.size(), 1);
+      assert.strictEqual(index.search("fed")[0]?.marketId, "1");
+      assert.ok(index.getAge() < 60_000);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
+
+describe("toMarketSearchDoc", () => {
+  it("parses Gamma markets and skips ones without a question", () => {
+    const parsed = toMarketSearchDoc({
+      id: 42,
+      question: "Q?",
+      conditionId: "0xabc",
+      clobTokenIds: '["1","2"]',
+    });
+    assert.deepStrictEqual(parsed?.tokenIds, ["1", "2"]);
+    assert.strictEqual(parsed?.marketId, "42");
+    assert.strictEqual(toMarketSearchDoc({ id: 1 }), null);
+  });
+});
@@ -6,6 +6,7 @@
  * - Cache safety: dust/empty books don't overwrite healthy cache
  * - Single fetch per attempt guarantee
  * - Bug detection for book changes during attempt
+ * - Quote age stamping (latency-arb guard)
  */
 
 import assert from "node:assert";
@@ -19,6 +20,7 @@ import {
   assertSnapshotIntegrity,
   isSnapshotHealthy,
   generateAttemptId,
+  getQuoteAgeMs,
   _resetAttemptCounter,
 } from "../../../src/lib/market-snapshot";
 import {
@@ -615,3 +617,25 @@ describe("Single Fetch Per Attempt", () => {
     assert.strictEqual(result, false, "Should detect book change as a bug");
   });
 });
+
+// ============================================================================
+// Quote Age Stamping
+// ============================================================================
+
+describe("Quote Age", () => {
+  it("should treat a fresh fetch as age 0 at fetch time", () => {
+    const snapshot = createHealthySnapshot({ source: "REST" });
+
+    assert.strictEqual(snapshot.bookUpdatedAtMs, snapshot.fetchedAtMs);
+    assert.strictEqual(getQuoteAgeMs(snapshot, snapshot.fetchedAtMs), 0);
+  });
+
+  it("should include how old cached book data already was", () => {
+    const now = Date.now();
+    const snapshot = createHealthySnapshot({ bookUpdatedAtMs: now - 3000 });
+
+    assert.strictEqual(getQuoteAgeMs(snapshot, now), 3000);
+    // Keeps aging while the attempt is in flight
+    assert.strictEqual(getQuoteAgeMs(snapshot, now + 1500), 4500);
+  });
+});
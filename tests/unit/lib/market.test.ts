@@ -7,6 +7,7 @@ import {
   fetchMarketByTokenId,
   fetchMarketByConditionId,
   getOppositeTokenId,
+  getComplementQuote,
   getTokenOutcome,
   clearMarketCache,
   getMarketCacheStats,
@@ -143,6 +144,61 @@ describe("Market Token Lookup", () => {
     });
   });
 
+  describe("getComplementQuote", () => {
+    test("should return the complement token with both midpoints", async () => {
+      const originalGet = axios.get;
+      axios.get = (async (url: string) => {
+        if (url.includes("/midpoint")) {
+          return {
+            data: { mid: url.includes("yes-token") ? "0.62" : "0.38" },
+          };
+        }
+        return { data: [mockMarketResponse] };
+      }) as any;
+
+      try {
+        const quote = await getComplementQuote("yes-token-id-12345");
+        assert.ok(quote);
+        assert.strictEqual(quote.complementTokenId, "no-token-id-67890");
+        assert.strictEqual(quote.outcomeLabel, "Yes");
+        assert.strictEqual(quote.complementLabel, "No");
+        assert.strictEqual(quote.price, 0.62);
+        assert.strictEqual(quote.complementPrice, 0.38);
+      } finally {
+        axios.get = originalGet;
+      }
+    });
+
+    test("should return null prices when midpoints are unavailable", async () => {
+      const originalGet = axios.get;
+      axios.get = (async (url: string) => {
+        if (url.includes("/midpoint")) throw new Error("503");
+        return { data: [mockMarketResponse] };
+      }) as any;
+
+      try {
+        const quote = await getComplementQuote("no-token-id-67890");
+        assert.ok(quote);
+        assert.strictEqual(quote.complementTokenId, "yes-token-id-12345");
+        assert.strictEqual(quote.price, null);
+        assert.strictEqual(quote.complementPrice, null);
+      } finally {
+        axios.get = originalGet;
+      }
+    });
+
+    test("should return null when market not found", async () => {
+      const originalGet = axios.get;
+      axios.get = async () => ({ data: [] });
+
+      try {
+        assert.strictEqual(await getComplementQuote("unknown-token"), null);
+      } finally {
+        axios.get = originalGet;
+      }
+    });
+  });
+
   describe("getTokenOutcome", () => {
     test("should return Yes for the YES token", async () => {
       const originalGet = axios.get;
@@ -0,0 +1,49 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  convertOrderSize,
+  formatOrderSizeConversion,
+  marketOrderAmount,
+} from "../../../src/lib/order-size";
+
+describe("convertOrderSize", () => {
+  it("converts USDC to shares at the price", () => {
+    const size = convertOrderSize({ usdc: 10 }, 0.4);
+    assert.strictEqual(size.from, "USDC");
+    assert.strictEqual(size.sizeUsdc, 10);
+    assert.strictEqual(size.sizeShares, 25);
+  });
+
+  it("converts shares to USDC at the price", () => {
+    const size = convertOrderSize({ shares: 25 }, 0.4);
+    assert.strictEqual(size.from, "SHARES");
+    assert.strictEqual(size.sizeShares, 25);
+    assert.strictEqual(size.sizeUsdc, 10);
+  });
+
+  it("rejects a price it can't divide by", () => {
+    assert.throws(() => convertOrderSize({ usdc: 10 }, 0));
+    assert.throws(() => convertOrderSize({ usdc: 10 }, NaN));
+  });
+});
+
+describe("marketOrderAmount", () => {
+  it("passes USDC for a BUY and shares for a SELL", () => {
+    const size = convertOrderSize({ shares: 25 }, 0.4);
+    assert.strictEqual(marketOrderAmount("BUY", size), 10);
+    assert.strictEqual(marketOrderAmount("SELL", size), 25);
+  });
+});
+
+describe("formatOrderSizeConversion", () => {
+  it("leads with the unit the size was given in", () => {
+    assert.strictEqual(
+      formatOrderSizeConversion(convertOrderSize({ usdc: 10 }, 0.4)),
+      "$10.00 → 25.00 shares @ 40.0¢",
+    );
+    assert.strictEqual(
+      formatOrderSizeConversion(convertOrderSize({ shares: 25 }, 0.4)),
+      "25.00 shares → $10.00 @ 40.0¢",
+    );
+  });
+});
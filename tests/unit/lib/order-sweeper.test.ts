@@ -0,0 +1,120 @@
+import assert from "node:assert";
+import { describe, it, mock } from "node:test";
+import {
+  OrderSweeper,
+  getSweepReason,
+  type SweepableOrder,
+} from "../../../src/lib/order-sweeper";
+
+const NOW = Date.UTC(2026, 0, 15, 12, 0, 0);
+const config = { maxAgeMs: 60 * 60 * 1000, maxSpreadCents: 20 };
+
+function order(overrides: Partial<SweepableOrder> = {}): SweepableOrder {
+  return {
+    id: "order-1",
+    asset_id: "tok-1",
+    side: "BUY",
+    price: "0.5",
+    created_at: NOW / 1000 - 60,
+    ...overrides,
+  };
+}
+
+const liquidBook = { bestBidCents: 49, bestAskCents: 51 };
+
+describe("getSweepReason", () => {
+  it("leaves young orders in liquid books alone", () => {
+    assert.strictEqual(getSweepReason(order(), liquidBook, config, NOW), null);
+  });
+
+  it("sweeps orders past the max age", () => {
+    const old = order({ created_at: NOW / 1000 - 2 * 60 * 60 });
+    assert.strictEqual(getSweepReason(old, liquidBook, config, NOW), "MAX_AGE");
+    const noLimit = { ...config, maxAgeMs: 0 };
+    assert.strictEqual(getSweepReason(old, liquidBook, noLimit, NOW), null);
+  });
+
+  it("sweeps orders whose market has no orderbook", () => {
+    assert.strictEqual(
+      getSweepReason(order(), null, config, NOW),
+      "MARKET_STALE",
+    );
+  });
+
+  it("sweeps orders in one-sided or wide books", () => {
+    const oneSided = { bestBidCents: 0, bestAskCents: 51 };
+    const wide = { bestBidCents: 20, bestAskCents: 60 };
+    assert.strictEqual(
+      getSweepReason(order(), oneSided, config, NOW),
+      "ILLIQUID",
+    );
+    assert.strictEqual(getSweepReason(order(), wide, config, NOW), "ILLIQUID");
+  });
+
+  it("only applies the age check when the book is unknown", () => {
+    assert.strictEqual(getSweepReason(order(), undefined, config, NOW), null);
+  });
+});
+
+describe("OrderSweeper", () => {
+  function createMockClient(orders: SweepableOrder[]) {
+    return {
+      getOpenOrders: mock.fn(async () => orders),
+      getOrderBook: mock.fn(async (tokenId: string) => {
+        if (tokenId === "closed") throw new Error("No orderbook exists");
+        if (tokenId === "flaky") throw new Error("timeout");
+        return {
+          bids: [{ price: "0.49", size: "100" }],
+          asks: [{ price: "0.51", size: "100" }],
+        };
+      }),
+      cancelOrders: mock.fn(async () => ({})),
+    };
+  }
+
+  it("cancels stale orders and keeps the rest", async () => {
+    const client = createMockClient([
+      order({ id: "fresh" }),
+      order({ id: "old", created_at: NOW / 1000 - 3 * 60 * 60 }),
+      order({ id: "closed-1", asset_id: "closed" }),
+      order({ id: "closed-2", asset_id: "closed" }),
+      order({ id: "flaky", asset_id: "flaky" }),
+    ]);
+    const sweeper = new OrderSweeper(client as any, config, NOW);
+
+    const result = (await sweeper.sweep(NOW))!;
+    assert.strictEqual(result.scanned, 5);
+    assert.strictEqual(result.cancelled, 3);
+    assert.deepStrictEqual(result.byReason, {
+      MAX_AGE: 1,
+      MARKET_STALE: 2,
+      ILLIQUID: 0,
+    });
+    assert.deepStrictEqual(client.cancelOrders.mock.calls[0].arguments[0], [
+      "old",
+      "closed-1",
+      "closed-2",
+    ]);
+    // One book fetch per token
+    assert.strictEqual(client.getOrderBook.mock.callCount(), 3);
+  });
+
+  it("does nothing when there are no open orders", async () => {
+    const client = createMockClient([]);
+    const sweeper = new OrderSweeper(client as any, config, NOW);
+    const result = (await sweeper.sweep(NOW))!;
+    assert.strictEqual(result.scanned, 0);
+    assert.strictEqual(client.cancelOrders.mock.callCount(), 0);
+  });
+
+  it("counts failed cancellations", async () => {
+    const client = createMockClient([order({ asset_id: "closed" })]);
+    client.cancelOrders = mock.fn(async () => {
+      throw new Error("boom");
+    });
+    const sweeper = new OrderSweeper(client as any, config, NOW);
+    const result = (await sweeper.sweep(NOW))!;
+    assert.strictEqual(result.cancelled, 0);
+    assert.strictEqual(result.failed, 1);
+  });
+});
@@ -4,8 +4,10 @@ import {
   postOrder,
   clearCooldowns,
   GtcOrderTracker,
+  getNotTradableReason,
 } from "../../../src/lib/order";
 import { ORDER } from "../../../src/lib/constants";
+import { setMarketFilterConfig } from "../../../src/lib/market-filter";
 
 // Mock ClobClient
 function createMockClient(
@@ -19,6 +21,8 @@ function createMockClient(
     getOrderBookError?: Error | null;
     getMarketError?: Error | null;
     createMarketOrderFn?: (args: any) => any;
+    /** CLOB conditional balance in base units (omit: lookup fails) */
+    conditionalBalance?: string;
   } = {},
 ) {
   const mockOrderBook = options.orderBook ?? {
@@ -45,6 +49,12 @@ function createMockClient(
       success: options.postOrderSuccess ?? true,
       errorMsg: options.postOrderErrorMsg ?? "",
     })),
+    getBalanceAllowance: mock.fn(async () => {
+      if (options.conditionalBalance === undefined) {
+        throw new Error("balance lookup failed");
+      }
+      return { balance: options.conditionalBalance, allowance: "0" };
+    }),
   };
 }
 
@@ -428,6 +438,123 @@ describe("postOrder", () => {
       assert.strictEqual(result.success, true);
       assert.ok(result.filledUsd !== undefined && result.filledUsd > 0);
     });
+
+    it("rejects SELL for more shares than the funder holds", async () => {
+      const client = createMockClient({ conditionalBalance: "3000000" });
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "SELL",
+        sizeUsd: 10,
+        shares: 10,
+      });
+
+      assert.strictEqual(result.success, false);
+      assert.strictEqual(result.reason, "INSUFFICIENT_SHARES");
+      assert.strictEqual(result.heldShares, 3);
+      assert.strictEqual(client.postOrder.mock.calls.length, 0);
+    });
+
+    it("sells when the funder holds the shares", async () => {
+      const client = createMockClient({ conditionalBalance: "10000000" });
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "SELL",
+        sizeUsd: 4.8,
+        shares: 10,
+      });
+
+      assert.strictEqual(result.success, true);
+    });
+
+    it("passes USDC as the market BUY amount", async () => {
+      const client = createMockClient();
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "BUY",
+        sizeUsd: 10,
+      });
+
+      assert.strictEqual(result.success, true);
+      const args = (client.createMarketOrder as any).mock.calls[0].arguments[0];
+      // $10 at 50¢, not the 20 shares it buys
+      assert.strictEqual(args.amount, 10);
+    });
+
+    it("passes shares as the market SELL amount", async () => {
+      const client = createMockClient({ conditionalBalance: "10000000" });
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "SELL",
+        sizeUsd: 4.8,
+        shares: 10,
+      });
+
+      assert.strictEqual(result.success, true);
+      const args = (client.createMarketOrder as any).mock.calls[0].arguments[0];
+      assert.strictEqual(args.amount, 10);
+    });
+
+    it("echoes a USDC size converted at the best ask", async () => {
+      const client = createMockClient();
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "BUY",
+        sizeUsd: 10,
+      });
+
+      assert.strictEqual(result.success, true);
+      assert.strictEqual(result.sizeConversion?.from, "USDC");
+      assert.strictEqual(result.sizeConversion?.sizeShares, 20);
+    });
+
+    it("sizes a BUY in shares at the best ask", async () => {
+      const client = createMockClient();
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "BUY",
+        sizeShares: 30,
+      });
+
+      assert.strictEqual(result.success, true);
+      assert.strictEqual(result.sizeConversion?.from, "SHARES");
+      assert.strictEqual(result.sizeConversion?.sizeUsdc, 15);
+      const args = (client.createMarketOrder as any).mock.calls[0].arguments[0];
+      assert.strictEqual(args.amount, 15);
+    });
+
+    it("rejects a size given in both units", async () => {
+      const client = createMockClient();
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "BUY",
+        sizeUsd: 10,
+        sizeShares: 20,
+      });
+
+      assert.strictEqual(result.success, false);
+      assert.strictEqual(result.reason, "INVALID_SIZE");
+    });
   });
 
   describe("error handling", () => {
@@ -486,6 +613,84 @@ describe("postOrder", () => {
       assert.strictEqual(result.success, false);
       assert.strictEqual(result.reason, "Insufficient liquidity");
     });
+
+    it("rejects before signing when the market is not accepting orders", async () => {
+      const client = createMockClient();
+      client.getMarket = mock.fn(async () => ({
+        id: "test-market",
+        accepting_orders: false,
+      }));
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "BUY",
+        sizeUsd: 10,
+        marketId: "test-market",
+      });
+
+      assert.strictEqual(result.success, false);
+      assert.strictEqual(result.reason, "MARKET_NOT_TRADABLE");
+      assert.strictEqual(result.detail, "not accepting orders");
+      assert.strictEqual(
+        (client.createMarketOrder as any).mock.callCount(),
+        0,
+      );
+    });
+
+    it("rejects BUYs on markets with a denied tag", async () => {
+      const client = createMockClient();
+      client.getMarket = mock.fn(async () => ({
+        id: "test-market",
+        tags: ["Sports"],
+      }));
+      setMarketFilterConfig({
+        allowConditionIds: [],
+        denyConditionIds: [],
+        allowTags: [],
+        denyTags: ["sports"],
+      });
+
+      try {
+        const buy = await postOrder({
+          client: client as any,
+          tokenId: "test-token",
+          outcome: "YES",
+          side: "BUY",
+          sizeUsd: 10,
+          marketId: "test-market",
+        });
+        assert.strictEqual(buy.success, false);
+        assert.strictEqual(buy.reason, "MARKET_NOT_ALLOWED");
+        assert.strictEqual(buy.detail, "tag denied: sports");
+      } finally {
+        setMarketFilterConfig({
+          allowConditionIds: [],
+          denyConditionIds: [],
+          allowTags: [],
+          denyTags: [],
+        });
+      }
+    });
+
+    it("does not resend a submit that threw", async () => {
+      const client = createMockClient();
+      client.postOrder = mock.fn(async () => {
+        throw new Error("socket hang up");
+      });
+
+      const result = await postOrder({
+        client: client as any,
+        tokenId: "test-token",
+        outcome: "YES",
+        side: "BUY",
+        sizeUsd: 10,
+      });
+
+      assert.strictEqual(result.success, false);
+      assert.strictEqual(client.postOrder.mock.callCount(), 1);
+    });
   });
 
   describe("clearCooldowns", () => {
@@ -665,3 +870,38 @@ describe("GtcOrderTracker", () => {
     });
   });
 });
+
+describe("getNotTradableReason", () => {
+  const now = Date.parse("2026-01-01T00:00:00Z");
+
+  it("returns null for an open market", () => {
+    assert.strictEqual(
+      getNotTradableReason(
+        { active: true, closed: false, accepting_orders: true },
+        "BUY",
+        now,
+      ),
+      null,
+    );
+  });
+
+  it("reports closed and halted markets", () => {
+    assert.strictEqual(
+      getNotTradableReason({ closed: true }, "SELL", now),
+      "market closed",
+    );
+    assert.strictEqual(
+      getNotTradableReason({ accepting_orders: false }, "SELL", now),
+      "not accepting orders",
+    );
+  });
+
+  it("blocks BUYs but not SELLs past the end date", () => {
+    const market = { end_date_iso: "2025-12-31T00:00:00Z" };
+    assert.strictEqual(
+      getNotTradableReason(market, "BUY", now),
+      "past end date",
+    );
+    assert.strictEqual(getNotTradableReason(market, "SELL", now), null);
+  });
+});
@@ -21,6 +21,10 @@ import {
   STRATEGY_MAX_PRICE,
   HARD_MIN_PRICE,
   HARD_MAX_PRICE,
+  checkPriceBand,
+  checkSidePriceCap,
+  MAX_BUY_PRICE,
+  MIN_SELL_PRICE,
 } from "../../../src/lib/price-safety";
 
 describe("Price Safety Module", () => {
@@ -400,6 +404,28 @@ describe("Price Safety Module", () => {
     });
   });
 
+  describe("checkSidePriceCap", () => {
+    it("should reject BUYs above the max and SELLs below the min", () => {
+      assert.ok(checkSidePriceCap(0.96, "BUY", 0.95, 0.05));
+      assert.ok(checkSidePriceCap(0.04, "SELL", 0.95, 0.05));
+    });
+
+    it("should allow prices at or inside the caps", () => {
+      assert.strictEqual(checkSidePriceCap(0.95, "BUY", 0.95, 0.05), null);
+      assert.strictEqual(checkSidePriceCap(0.05, "SELL", 0.95, 0.05), null);
+      // Caps only apply to their own side
+      assert.strictEqual(checkSidePriceCap(0.02, "BUY", 0.95, 0.05), null);
+      assert.strictEqual(checkSidePriceCap(0.98, "SELL", 0.95, 0.05), null);
+    });
+
+    it("should default to the HARD bounds (no extra restriction)", () => {
+      assert.strictEqual(MAX_BUY_PRICE, HARD_MAX_PRICE);
+      assert.strictEqual(MIN_SELL_PRICE, HARD_MIN_PRICE);
+      assert.strictEqual(checkSidePriceCap(HARD_MAX_PRICE, "BUY"), null);
+      assert.strictEqual(checkSidePriceCap(HARD_MIN_PRICE, "SELL"), null);
+    });
+  });
+
   describe("price formation never exceeds 0.99", () => {
     it("should never return limitPrice >= 1.0 for any slippage", () => {
       const testCases = [
@@ -1288,4 +1314,33 @@ describe("Price Safety Module", () => {
       );
     });
   });
+
+  describe("checkPriceBand", () => {
+    const range = { low: 0.44, high: 0.46 };
+
+    it("should pass when there is no recent reference", () => {
+      assert.strictEqual(checkPriceBand(0.045, null, 25, 5).ok, true);
+    });
+
+    it("should pass prices near the recent range", () => {
+      assert.strictEqual(checkPriceBand(0.5, range, 25, 5).ok, true);
+      assert.strictEqual(checkPriceBand(0.4, range, 25, 5).ok, true);
+    });
+
+    it("should reject decimal-place mistakes", () => {
+      const result = checkPriceBand(0.045, range, 25, 5);
+      assert.strictEqual(result.ok, false);
+      assert.ok(result.detail?.includes("outside"));
+    });
+
+    it("should allow at least minBandCents on cheap tokens", () => {
+      const cheap = { low: 0.02, high: 0.02 };
+      assert.strictEqual(checkPriceBand(0.06, cheap, 25, 5).ok, true);
+      assert.strictEqual(checkPriceBand(0.08, cheap, 25, 5).ok, false);
+    });
+
+    it("should be disabled when bandPct is 0", () => {
+      assert.strictEqual(checkPriceBand(0.99, range, 0, 5).ok, true);
+    });
+  });
 });
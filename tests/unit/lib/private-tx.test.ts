@@ -0,0 +1,25 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { Wallet } from "ethers";
+import { getTxWallet, shouldUsePrivateTx } from "../../../src/lib/private-tx";
+
+describe("shouldUsePrivateTx", () => {
+  it("never uses the relay when none is configured", () => {
+    assert.strictEqual(shouldUsePrivateTx(true, "", true), false);
+  });
+
+  it("follows the default unless the tx flag overrides it", () => {
+    const relay = "https://relay.example";
+    assert.strictEqual(shouldUsePrivateTx(undefined, relay, true), true);
+    assert.strictEqual(shouldUsePrivateTx(undefined, relay, false), false);
+    assert.strictEqual(shouldUsePrivateTx(false, relay, true), false);
+    assert.strictEqual(shouldUsePrivateTx(true, relay, false), true);
+  });
+});
+
+describe("getTxWallet", () => {
+  it("returns the wallet unchanged without a relay", () => {
+    const wallet = Wallet.createRandom();
+    assert.strictEqual(getTxWallet(wallet as unknown as Wallet, true), wallet);
+  });
+});
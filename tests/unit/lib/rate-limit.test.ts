@@ -15,7 +15,8 @@ import {
   DEFAULT_RETRY_CONFIG,
   DEFAULT_RATE_LIMIT_CONFIG,
   rateLimiters,
-} from "../../src/services/polymarket/rate-limit";
+  READ_POLICY,
+} from "../../../src/services/polymarket/rate-limit";
 
 describe("Rate Limit Utilities", async () => {
   describe("RateLimiter", async () => {
@@ -364,4 +365,12 @@ describe("Rate Limit Utilities", async () => {
       assert.strictEqual(rateLimiters.orders.canMakeRequest(), true);
     });
   });
+
+  describe("Read Policy", async () => {
+    it("should retry reads by default", () => {
+      assert.ok(READ_POLICY.maxRetries > 0);
+      assert.ok(READ_POLICY.timeoutMs > 0);
+      assert.ok(READ_POLICY.maxDelayMs > READ_POLICY.baseDelayMs);
+    });
+  });
 });
@@ -0,0 +1,100 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { MarketDataStore } from "../../../src/lib/market-data-store";
+import { replayFill, replayFillAt } from "../../../src/lib/replay-fill";
+
+const book = {
+  tokenId: "tok",
+  bids: [
+    { price: 0.5, size: 100 },
+    { price: 0.49, size: 50 },
+  ],
+  asks: [
+    { price: 0.52, size: 40 },
+    { price: 0.53, size: 60 },
+    { price: 0.55, size: 100 },
+  ],
+  timestamp: 1_000,
+};
+
+const print = (price: number, size: number, timestamp: number) => ({
+  tokenId: "tok",
+  price,
+  size,
+  side: "BUY" as const,
+  timestamp,
+});
+
+describe("replayFill", () => {
+  it("walks the book up to the limit", () => {
+    const result = replayFill(book, { side: "BUY", price: 0.53, shares: 70 });
+    assert.strictEqual(result.status, "FILLED");
+    assert.strictEqual(result.filledShares, 70);
+    // 40 @ 0.52 + 30 @ 0.53
+    assert.ok(Math.abs(result.avgPrice! - 36.7 / 70) < 1e-9);
+    assert.strictEqual(result.touchPrice, 0.52);
+    assert.strictEqual(result.bookAt, 1_000);
+  });
+
+  it("leaves the remainder resting when the limit runs out", () => {
+    const result = replayFill(book, { side: "SELL", price: 0.5, shares: 150 });
+    assert.strictEqual(result.status, "PARTIAL");
+    assert.strictEqual(result.filledShares, 100);
+    assert.strictEqual(result.avgPrice, 0.5);
+    assert.strictEqual(result.restingShares, 50);
+  });
+
+  it("counts later prints through a resting limit", () => {
+    const trades = [
+      print(0.51, 10, 500), // before the snapshot
+      print(0.51, 15, 2_000),
+      print(0.5, 30, 3_000),
+      print(0.52, 100, 4_000), // above the limit
+    ];
+    const result = replayFill(
+      book,
+      { side: "BUY", price: 0.51, shares: 40 },
+      trades,
+    );
+    assert.strictEqual(result.status, "UNFILLED");
+    assert.strictEqual(result.avgPrice, null);
+    assert.strictEqual(result.tradedThroughShares, 40);
+  });
+
+  it("reports NO_BOOK without a snapshot", () => {
+    const result = replayFill(null, { side: "BUY", price: 0.5, shares: 10 });
+    assert.strictEqual(result.status, "NO_BOOK");
+    assert.strictEqual(result.restingShares, 10);
+  });
+});
+
+describe("replayFillAt", () => {
+  it("replays against the store's recorded book", () => {
+    const store = new MarketDataStore();
+    store.updateFromRest("tok", book.bids, book.asks);
+    const at = store.getOrderbook("tok")!.timestamp;
+
+    const order = { side: "BUY" as const, price: 0.55, shares: 10 };
+    assert.strictEqual(replayFillAt("tok", at, order, store).status, "FILLED");
+    assert.strictEqual(
+      replayFillAt("tok", at - 1, order, store).status,
+      "NO_BOOK",
+    );
+  });
+
+  it("replays against history exported from another store", () => {
+    const recorder = new MarketDataStore();
+    recorder.updateFromRest("tok", book.bids, book.asks);
+    const at = recorder.getOrderbook("tok")!.timestamp;
+    recorder.recordTrade(print(0.51, 25, at + 1));
+
+    const store = new MarketDataStore();
+    store.importHistory(JSON.parse(JSON.stringify(recorder.exportHistory())));
+
+    const order = { side: "BUY" as const, price: 0.51, shares: 40 };
+    const result = replayFillAt("tok", at, order, store);
+    assert.strictEqual(result.status, "UNFILLED");
+    assert.strictEqual(result.bookAt, at);
+    assert.strictEqual(result.tradedThroughShares, 25);
+  });
+});
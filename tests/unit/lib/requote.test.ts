@@ -0,0 +1,124 @@
+import assert from "node:assert";
+import { describe, it, mock } from "node:test";
+import {
+  computeRequoteDiff,
+  requote,
+  toRestingQuote,
+  type RestingQuote,
+} from "../../../src/lib/requote";
+
+function resting(overrides: Partial<RestingQuote> = {}): RestingQuote {
+  return {
+    orderId: "order-1",
+    tokenId: "tok-1",
+    side: "BUY",
+    price: 0.48,
+    sizeShares: 100,
+    ...overrides,
+  };
+}
+
+describe("toRestingQuote", () => {
+  it("uses the unfilled remainder as the size", () => {
+    const quote = toRestingQuote({
+      id: "order-1",
+      asset_id: "tok-1",
+      side: "SELL",
+      price: "0.52",
+      original_size: "100",
+      size_matched: "40",
+    });
+    assert.strictEqual(quote.side, "SELL");
+    assert.strictEqual(quote.price, 0.52);
+    assert.strictEqual(quote.sizeShares, 60);
+  });
+});
+
+describe("computeRequoteDiff", () => {
+  it("keeps matching orders and only replaces what changed", () => {
+    const bid = resting();
+    const ask = resting({ orderId: "order-2", side: "SELL", price: 0.52 });
+
+    const diff = computeRequoteDiff(
+      {
+        "tok-1": [
+          { side: "BUY", price: 0.48, sizeShares: 100 },
+          { side: "SELL", price: 0.53, sizeShares: 100 },
+        ],
+      },
+      [bid, ask],
+    );
+
+    assert.deepStrictEqual(diff.keep, [bid]);
+    assert.deepStrictEqual(diff.cancel, [ask]);
+    assert.deepStrictEqual(diff.post, [
+      { tokenId: "tok-1", side: "SELL", price: 0.53, sizeShares: 100 },
+    ]);
+  });
+
+  it("replaces a partially filled order at the same price", () => {
+    const diff = computeRequoteDiff(
+      { "tok-1": [{ side: "BUY", price: 0.48, sizeShares: 100 }] },
+      [resting({ sizeShares: 60 })],
+    );
+    assert.strictEqual(diff.keep.length, 0);
+    assert.strictEqual(diff.cancel.length, 1);
+    assert.strictEqual(diff.post.length, 1);
+  });
+
+  it("pulls every order on a token with no quotes and ignores others", () => {
+    const other = resting({ orderId: "order-2", tokenId: "tok-2" });
+    const diff = computeRequoteDiff({ "tok-1": [] }, [resting(), other]);
+    assert.deepStrictEqual(diff.cancel.map((q) => q.orderId), ["order-1"]);
+  });
+});
+
+describe("requote", () => {
+  function createClient(cancelError?: Error) {
+    return {
+      getOpenOrders: mock.fn(async () => [
+        {
+          id: "order-1",
+          asset_id: "tok-1",
+          side: "BUY",
+          price: "0.47",
+          original_size: "100",
+          size_matched: "0",
+        },
+      ]),
+      cancelOrders: mock.fn(async () => {
+        if (cancelError) throw cancelError;
+        return {};
+      }),
+      getOrderBook: mock.fn(async () => ({
+        bids: [{ price: "0.47", size: "100" }],
+        asks: [{ price: "0.52", size: "100" }],
+      })),
+      createOrder: mock.fn(async (args: any) => args),
+      postOrder: mock.fn(async () => ({ success: true, orderId: "order-9" })),
+    };
+  }
+
+  const desired = {
+    "tok-1": [{ side: "BUY" as const, price: 0.48, sizeShares: 100 }],
+  };
+
+  it("cancels and posts in one pass", async () => {
+    const client = createClient();
+    const result = await requote(client as any, desired);
+
+    assert.strictEqual(result.cancelled, 1);
+    assert.strictEqual(result.posted, 1);
+    assert.deepStrictEqual(result.postedOrderIds, ["order-9"]);
+    assert.strictEqual(client.cancelOrders.mock.calls.length, 1);
+  });
+
+  it("posts nothing when the cancels fail", async () => {
+    const client = createClient(new Error("timeout"));
+    const result = await requote(client as any, desired);
+
+    assert.strictEqual(result.posted, 0);
+    assert.strictEqual(result.failed, 2);
+    assert.strictEqual(client.postOrder.mock.calls.length, 0);
+  });
+});
@@ -0,0 +1,42 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  checkSellableShares,
+  evaluateShareCheck,
+} from "../../../src/lib/share-check";
+
+describe("evaluateShareCheck", () => {
+  it("takes the larger of the CLOB and on-chain balances", () => {
+    const result = evaluateShareCheck(10, 0, 10);
+    assert.strictEqual(result.ok, true);
+    assert.strictEqual(result.heldShares, 10);
+  });
+
+  it("fails with the held amount when both are short", () => {
+    const result = evaluateShareCheck(10, 2, 3.5);
+    assert.strictEqual(result.ok, false);
+    assert.strictEqual(result.heldShares, 3.5);
+  });
+
+  it("allows rounding slack", () => {
+    assert.strictEqual(evaluateShareCheck(10, 9.995).ok, true);
+  });
+
+  it("never blocks when both lookups failed", () => {
+    assert.deepStrictEqual(evaluateShareCheck(10), { ok: true });
+  });
+});
+
+describe("checkSellableShares", () => {
+  it("reads the CLOB conditional balance of the token", async () => {
+    const client = {
+      getBalanceAllowance: async (params: any) => {
+        assert.strictEqual(params.token_id, "token-1");
+        return { balance: "2500000", allowance: "0" };
+      },
+    };
+    const result = await checkSellableShares(client as any, "token-1", 5, null);
+    assert.strictEqual(result.ok, false);
+    assert.strictEqual(result.clobShares, 2.5);
+  });
+});
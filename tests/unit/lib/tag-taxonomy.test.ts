@@ -0,0 +1,71 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { TagTaxonomy, type GammaEvent } from "../../../src/lib/tag-taxonomy";
+
+const politics = { id: 2, label: "Politics", slug: "politics" };
+const elections = { id: "144", label: "Elections", slug: "elections" };
+const sports = { id: 1, label: "Sports", slug: "sports" };
+
+const events: GammaEvent[] = [
+  {
+    id: 10,
+    tags: [politics, elections],
+    markets: [
+      { conditionId: "0xAAA", question: "A?", liquidity: "5000" },
+      { conditionId: "0xbbb", question: "B?", liquidityNum: 1000 },
+      { conditionId: "0xccc", question: "Closed?", closed: true },
+    ],
+  },
+  {
+    id: 11,
+    tags: [politics],
+    markets: [{ conditionId: "0xddd", question: "D?", volume24hr: 300 }],
+  },
+  {
+    id: 12,
+    tags: [sports],
+    markets: [{ conditionId: "0xeee", question: "E?", liquidity: "250" }],
+  },
+];
+
+describe("TagTaxonomy", () => {
+  const taxonomy = new TagTaxonomy();
+  taxonomy.ingest(events, 1_000);
+
+  it("summarizes tags with market counts and liquidity", () => {
+    const tags = taxonomy.getTags();
+    assert.deepStrictEqual(
+      tags.map((t) => [t.id, t.marketCount, t.liquidityUsd]),
+      [
+        ["2", 3, 6000],
+        ["144", 2, 6000],
+        ["1", 1, 250],
+      ],
+    );
+    assert.strictEqual(tags[0].volume24hUsd, 300);
+    assert.deepStrictEqual(taxonomy.size(), { tags: 3, markets: 4 });
+  });
+
+  it("lists markets by tag ID, slug or label, most liquid first", () => {
+    const byId = taxonomy.getMarketsByTag("144");
+    assert.deepStrictEqual(byId.map((m) => m.question), ["A?", "B?"]);
+    assert.strictEqual(taxonomy.getMarketsByTag("POLITICS").length, 3);
+    assert.strictEqual(taxonomy.getMarketsByTag("sports")[0].eventId, "12");
+    assert.deepStrictEqual(taxonomy.getMarketsByTag("crypto"), []);
+  });
+
+  it("exposes a market's tag IDs and slugs for filtering", () => {
+    assert.deepStrictEqual(taxonomy.getTagRefsForMarket("0xaaa"), [
+      "2",
+      "politics",
+      "144",
+      "elections",
+    ]);
+    assert.strictEqual(taxonomy.getTagRefsForMarket("0xccc"), undefined);
+  });
+
+  it("reports its age from the last sync", () => {
+    assert.strictEqual(new TagTaxonomy().getAge(), Infinity);
+    assert.strictEqual(taxonomy.getAge(4_000), 3_000);
+  });
+});
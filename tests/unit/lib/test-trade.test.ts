@@ -0,0 +1,41 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { computeTestOrderPrice } from "../../../src/lib/test-trade";
+
+describe("computeTestOrderPrice", () => {
+  it("bids the distance below the mid, on a tick", () => {
+    // mid 51¢ -> 25.5¢ -> 25¢
+    assert.strictEqual(
+      computeTestOrderPrice({
+        bestBid: 0.5,
+        bestAsk: 0.52,
+        tickSize: 0.01,
+        distancePct: 50,
+      }),
+      0.25,
+    );
+  });
+
+  it("stays at least a tick below the bid", () => {
+    assert.strictEqual(
+      computeTestOrderPrice({
+        bestBid: 0.5,
+        bestAsk: 0.52,
+        tickSize: 0.01,
+        distancePct: 0,
+      }),
+      0.49,
+    );
+  });
+
+  it("falls back to the lowest tick and refuses to cross", () => {
+    assert.strictEqual(
+      computeTestOrderPrice({ tickSize: 0.001, distancePct: 50 }),
+      0.001,
+    );
+    assert.strictEqual(
+      computeTestOrderPrice({ bestAsk: 0.01, tickSize: 0.01, distancePct: 50 }),
+      null,
+    );
+  });
+});
@@ -0,0 +1,65 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import { planTokenTransfer } from "../../../src/lib/token-transfer";
+
+const EOA = "0x1111111111111111111111111111111111111111";
+const PROXY = "0x2222222222222222222222222222222222222222";
+const wallets = { eoa: EOA, proxy: PROXY };
+
+describe("planTokenTransfer", () => {
+  it("plans a partial move from the EOA to the proxy", () => {
+    const result = planTokenTransfer(
+      { tokenId: "123", from: "EOA", to: PROXY, shares: 2.5 },
+      wallets,
+      10_000_000n,
+    );
+    assert.ok("plan" in result);
+    assert.strictEqual(result.plan.fromAddress, EOA);
+    assert.strictEqual(result.plan.amount, 2_500_000n);
+    assert.strictEqual(result.plan.heldShares, 10);
+  });
+
+  it("moves the whole balance for ALL", () => {
+    const result = planTokenTransfer(
+      { tokenId: "123", from: "PROXY", to: EOA, shares: "ALL" },
+      wallets,
+      7_250_000n,
+    );
+    assert.ok("plan" in result);
+    assert.strictEqual(result.plan.fromAddress, PROXY);
+    assert.strictEqual(result.plan.shares, 7.25);
+  });
+
+  it("refuses more shares than are held", () => {
+    const result = planTokenTransfer(
+      { tokenId: "123", from: "EOA", to: PROXY, shares: 20 },
+      wallets,
+      10_000_000n,
+    );
+    assert.ok("error" in result);
+    assert.match(result.error, /holds 10\.00 shares/);
+  });
+
+  it("refuses a bad destination or a missing proxy", () => {
+    const self = planTokenTransfer(
+      { tokenId: "123", from: "EOA", to: EOA, shares: 1 },
+      wallets,
+      10_000_000n,
+    );
+    assert.ok("error" in self);
+
+    const invalid = planTokenTransfer(
+      { tokenId: "123", from: "EOA", to: "0x123", shares: 1 },
+      wallets,
+      10_000_000n,
+    );
+    assert.ok("error" in invalid);
+
+    const noProxy = planTokenTransfer(
+      { tokenId: "123", from: "PROXY", to: EOA, shares: 1 },
+      { eoa: EOA },
+      10_000_000n,
+    );
+    assert.ok("error" in noProxy);
+  });
+});
@@ -0,0 +1,24 @@
+import assert from "node:assert";
+import { describe, it } from "node:test";
+import {
+  checkUsdcHoldings,
+  formatUsdcHoldingsWarning,
+} from "../../../src/lib/usdc-bridge";
+
+describe("checkUsdcHoldings", () => {
+  it("flags funds held only in native USDC", () => {
+    const holdings = { bridged: 0, native: 250 };
+    assert.strictEqual(checkUsdcHoldings(holdings, 5), "NATIVE_ONLY");
+    assert.match(formatUsdcHoldingsWarning(holdings, "NATIVE_ONLY"), /\$0/);
+  });
+
+  it("flags native USDC sitting next to USDC.e", () => {
+    const holdings = { bridged: 100, native: 20 };
+    assert.strictEqual(checkUsdcHoldings(holdings, 5), "NATIVE_IDLE");
+  });
+
+  it("ignores native dust and wallets without native USDC", () => {
+    assert.strictEqual(checkUsdcHoldings({ bridged: 0, native: 2 }, 5), null);
+    assert.strictEqual(checkUsdcHoldings({ bridged: 50, native: 0 }, 0), null);
+  });
+});
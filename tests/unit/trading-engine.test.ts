@@ -636,6 +636,22 @@ describe("Config Validation", () => {
       "Should detect MIN_PROFIT_FACTOR < 1",
     );
   });
+
+  it("reports the expected range and the value received", () => {
+    const config = createTestConfig();
+    config.maxEntryPriceCents = 120;
+    const errors = validateConfig(config);
+    const error = errors.find((e) => e.field === "MAX_ENTRY_PRICE_CENTS");
+    assert.strictEqual(error?.message, "Must be between 1 and 99 (got 120)");
+  });
+
+  it("detects an inverted entry price band", () => {
+    const config = createTestConfig();
+    config.minEntryPriceCents = 70;
+    config.maxEntryPriceCents = 60;
+    const errors = validateConfig(config);
+    assert.ok(errors.some((e) => e.field === "MIN_ENTRY_PRICE_CENTS"));
+  });
 });
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1109,3 +1125,96 @@ describe("Clause Opus Audit Compliance", () => {
     });
   });
 });
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROTECTION RE-ARM TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+describe("Protection Re-arm", () => {
+  const config = createTestConfig();
+
+  function createManager(): PositionManager {
+    return new PositionManager({
+      tpCents: config.tpCents,
+      hedgeTriggerCents: config.hedgeTriggerCents,
+      maxAdverseCents: config.maxAdverseCents,
+      maxHoldSeconds: config.maxHoldSeconds,
+      hedgeRatio: config.hedgeRatio,
+      maxHedgeRatio: config.maxHedgeRatio,
+    });
+  }
+
+  it("re-arms saved TP/hedge/stop levels exactly", () => {
+    const before = createManager();
+    const position = before.openPosition({
+      tokenId: "held-token",
+      side: "LONG",
+      entryPriceCents: 50,
+      sizeUsd: 10,
+      referencePriceCents: 50,
+      evSnapshot: null,
+      biasDirection: "LONG",
+    });
+
+    const after = createManager();
+    const rearmed = after.importState(before.exportState());
+
+    assert.strictEqual(rearmed.length, 1);
+    const restored = after.getPosition(position.id)!;
+    assert.strictEqual(restored.takeProfitPriceCents, 64);
+    assert.strictEqual(restored.hedgeTriggerPriceCents, 34);
+    assert.strictEqual(restored.hardExitPriceCents, 20);
+  });
+
+  it("drops positions no longer held in the wallet", () => {
+    const before = createManager();
+    before.openPosition({
+      tokenId: "held-token",
+      side: "LONG",
+      entryPriceCents: 50,
+      sizeUsd: 10,
+      referencePriceCents: 50,
+      evSnapshot: null,
+      biasDirection: "LONG",
+    });
+    before.openPosition({
+      tokenId: "sold-token",
+      side: "LONG",
+      entryPriceCents: 40,
+      sizeUsd: 10,
+      referencePriceCents: 40,
+      evSnapshot: null,
+      biasDirection: "LONG",
+    });
+
+    const after = createManager();
+    const rearmed = after.importState(
+      before.exportState(),
+      new Set(["held-token"]),
+    );
+
+    assert.deepStrictEqual(
+      rearmed.map((p) => p.tokenId),
+      ["held-token"],
+    );
+    assert.strictEqual(after.getPositionsByToken("sold-token").length, 0);
+  });
+
+  it("re-arms a position that was mid-exit as OPEN", () => {
+    const before = createManager();
+    const position = before.openPosition({
+      tokenId: "held-token",
+      side: "LONG",
+      entryPriceCents: 50,
+      sizeUsd: 10,
+      referencePriceCents: 50,
+      evSnapshot: null,
+      biasDirection: "LONG",
+    });
+    before.beginExit(position.id, "TAKE_PROFIT", null, "LONG");
+
+    const after = createManager();
+    const [restored] = after.importState(before.exportState());
+    assert.strictEqual(restored.state, "OPEN");
+  });
+});